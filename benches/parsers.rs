@@ -0,0 +1,401 @@
+//! Parser and batch throughput: ID3 frame walking, Vorbis comments, grouping
+//! of many distinct tag keys, MP4 atom walking, the Ogg tail granule search,
+//! full per-format parses and a synthetic 1k-file batch.
+//!
+//!     cargo bench --bench parsers
+//!
+//! Small inputs are the committed `test_files` fixtures; large ones are
+//! generated in memory from them. The per-format `full_parse` group does the
+//! same structural parse and tag decode as the batch path, without building
+//! Python objects. Save runs with `-- --save-baseline <name>` and compare two of
+//! them with `python benchmarks/criterion_compare.py <before> <after>`.
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use mutagen_rs::mp4::atom::{find_atom_path, AtomIter};
+use rayon::prelude::*;
+use std::hint::black_box;
+use std::path::PathBuf;
+
+const SMALL_MP3: &[u8] = include_bytes!("../test_files/silence-44-s.mp3");
+const SMALL_FLAC: &[u8] = include_bytes!("../test_files/silence-44-s.flac");
+const SMALL_OGG: &[u8] = include_bytes!("../test_files/multipagecomment.ogg");
+const SMALL_M4A: &[u8] = include_bytes!("../test_files/has-tags.m4a");
+
+/// Files written for the batch benchmark.
+const BATCH_FILES: usize = 1000;
+
+fn syncsafe(n: usize) -> [u8; 4] {
+    [(n >> 21) as u8 & 0x7f, (n >> 14) as u8 & 0x7f, (n >> 7) as u8 & 0x7f, n as u8 & 0x7f]
+}
+
+fn id3v24_frame(id: &[u8; 4], body: &[u8]) -> Vec<u8> {
+    let mut frame = id.to_vec();
+    frame.extend_from_slice(&syncsafe(body.len()));
+    frame.extend_from_slice(&[0, 0]);
+    frame.extend_from_slice(body);
+    frame
+}
+
+/// ID3v2.4 tag with `texts` UTF-8 TXXX frames and a front cover of `cover_len` bytes.
+fn large_id3(texts: usize, cover_len: usize) -> Vec<u8> {
+    let mut body = id3v24_frame(b"TIT2", b"\x03Benchmark Title");
+    body.extend(id3v24_frame(b"TPE1", b"\x03Benchmark Artist"));
+    for i in 0..texts {
+        let text = format!("\x03DESC{i}\x00value number {i} with some length to it");
+        body.extend(id3v24_frame(b"TXXX", text.as_bytes()));
+    }
+    let mut apic = b"\x00image/jpeg\x00\x03cover\x00".to_vec();
+    apic.resize(apic.len() + cover_len, 0xAB);
+    body.extend(id3v24_frame(b"APIC", &apic));
+
+    let mut tag = b"ID3\x04\x00\x00".to_vec();
+    tag.extend_from_slice(&syncsafe(body.len()));
+    tag.extend(body);
+    tag
+}
+
+/// The small MP3's audio frames behind a large generated ID3v2 tag.
+fn large_mp3() -> Vec<u8> {
+    let (_, header) = mutagen_rs::id3::load_id3_from_slice(SMALL_MP3).unwrap();
+    let audio_start = header.map(|h| h.full_size() as usize).unwrap_or(0);
+    let mut data = large_id3(500, 1 << 20);
+    data.extend_from_slice(&SMALL_MP3[audio_start..]);
+    data
+}
+
+fn vorbis_comment(count: usize) -> Vec<u8> {
+    let vendor = b"reference libFLAC 1.4.3 20230623";
+    let mut data = Vec::new();
+    data.extend_from_slice(&(vendor.len() as u32).to_le_bytes());
+    data.extend_from_slice(vendor);
+    data.extend_from_slice(&(count as u32).to_le_bytes());
+    for i in 0..count {
+        let comment = format!("{}={} {i}", if i % 2 == 0 { "TITLE" } else { "artist" }, "Homog\u{e9}nic");
+        data.extend_from_slice(&(comment.len() as u32).to_le_bytes());
+        data.extend_from_slice(comment.as_bytes());
+    }
+    data
+}
+
+/// Vorbis comment block with `count` distinct keys, like a cue sheet stored
+/// one comment per index point.
+fn cue_vorbis_comment(count: usize) -> Vec<u8> {
+    let vendor = b"reference libFLAC 1.4.3 20230623";
+    let mut data = Vec::new();
+    data.extend_from_slice(&(vendor.len() as u32).to_le_bytes());
+    data.extend_from_slice(vendor);
+    data.extend_from_slice(&(count as u32).to_le_bytes());
+    for i in 0..count {
+        let comment = format!("CUEPOINT{i:05}=00:{:02}:{:02}", i / 60 % 60, i % 60);
+        data.extend_from_slice(&(comment.len() as u32).to_le_bytes());
+        data.extend_from_slice(comment.as_bytes());
+    }
+    data
+}
+
+/// STREAMINFO and audio frames of the small FLAC around a generated
+/// `comments`-entry VORBIS_COMMENT block.
+fn large_flac(comments: usize) -> Vec<u8> {
+    let mut pos = 4;
+    let mut streaminfo = None;
+    loop {
+        let header = SMALL_FLAC[pos];
+        let len = u32::from_be_bytes([0, SMALL_FLAC[pos + 1], SMALL_FLAC[pos + 2], SMALL_FLAC[pos + 3]]) as usize;
+        if header & 0x7f == 0 {
+            streaminfo = Some(&SMALL_FLAC[pos + 4..pos + 4 + len]);
+        }
+        pos += 4 + len;
+        if header & 0x80 != 0 {
+            break;
+        }
+    }
+    let streaminfo = streaminfo.expect("fixture has STREAMINFO");
+    let vc = vorbis_comment(comments);
+
+    let mut data = b"fLaC".to_vec();
+    data.push(0);
+    data.extend_from_slice(&(streaminfo.len() as u32).to_be_bytes()[1..]);
+    data.extend_from_slice(streaminfo);
+    data.push(0x80 | 4);
+    data.extend_from_slice(&(vc.len() as u32).to_be_bytes()[1..]);
+    data.extend(vc);
+    data.extend_from_slice(&SMALL_FLAC[pos..]);
+    data
+}
+
+fn mp4_atom(name: &[u8; 4], body: &[u8]) -> Vec<u8> {
+    let mut atom = ((body.len() + 8) as u32).to_be_bytes().to_vec();
+    atom.extend_from_slice(name);
+    atom.extend_from_slice(body);
+    atom
+}
+
+fn mp4_data(type_code: u32, payload: &[u8]) -> Vec<u8> {
+    let mut body = type_code.to_be_bytes().to_vec();
+    body.extend_from_slice(&[0, 0, 0, 0]);
+    body.extend_from_slice(payload);
+    mp4_atom(b"data", &body)
+}
+
+/// The small M4A with `freeform` extra `----` items and a cover of
+/// `cover_len` bytes appended to its `ilst`, parent sizes patched to match.
+fn large_m4a(freeform: usize, cover_len: usize) -> Vec<u8> {
+    let data = SMALL_M4A;
+    let meta = find_atom_path(data, &[b"moov", b"udta", b"meta"]).expect("fixture has moov/udta/meta");
+    let ilst = AtomIter::new(data, meta.data_offset + 4, meta.data_offset + meta.data_size)
+        .find_name(b"ilst")
+        .expect("fixture has ilst");
+
+    let mut extra = Vec::new();
+    for i in 0..freeform {
+        let mut item = mp4_atom(b"mean", b"\x00\x00\x00\x00com.apple.iTunes");
+        item.extend(mp4_atom(b"name", format!("\x00\x00\x00\x00BENCH_{i}").as_bytes()));
+        item.extend(mp4_data(1, format!("freeform value {i}").as_bytes()));
+        extra.extend(mp4_atom(b"----", &item));
+    }
+    extra.extend(mp4_atom(b"covr", &mp4_data(13, &vec![0xAB; cover_len])));
+
+    let insert_at = ilst.offset + ilst.size;
+    let mut out = data[..insert_at].to_vec();
+    out.extend(&extra);
+    out.extend_from_slice(&data[insert_at..]);
+    let moov = find_atom_path(data, &[b"moov"]).unwrap();
+    let udta = find_atom_path(data, &[b"moov", b"udta"]).unwrap();
+    for offset in [moov.offset, udta.offset, meta.offset, ilst.offset] {
+        let size = u32::from_be_bytes(out[offset..offset + 4].try_into().unwrap());
+        out[offset..offset + 4].copy_from_slice(&(size + extra.len() as u32).to_be_bytes());
+    }
+    out
+}
+
+/// `(offset, size)` of each page in an Ogg stream.
+fn ogg_pages(data: &[u8]) -> Vec<(usize, usize)> {
+    let mut pages = Vec::new();
+    let mut pos = 0;
+    while pos + 27 <= data.len() && &data[pos..pos + 4] == b"OggS" {
+        let nseg = data[pos + 26] as usize;
+        let size = 27 + nseg + data[pos + 27..pos + 27 + nseg].iter().map(|&s| s as usize).sum::<usize>();
+        pages.push((pos, size));
+        pos += size;
+    }
+    pages
+}
+
+/// The small Ogg file followed by `count` copies of its last page (sequence
+/// numbers bumped), and optionally `foreign` bytes of pages from another
+/// stream so the granule search has to widen its window.
+fn large_ogg(count: usize, foreign: usize) -> Vec<u8> {
+    let pages = ogg_pages(SMALL_OGG);
+    let (last, size) = *pages.last().unwrap();
+    let template = &SMALL_OGG[last..last + size];
+    let mut data = SMALL_OGG.to_vec();
+    let mut seq = u32::from_le_bytes(template[18..22].try_into().unwrap());
+    for _ in 0..count {
+        seq += 1;
+        let mut page = template.to_vec();
+        page[18..22].copy_from_slice(&seq.to_le_bytes());
+        data.extend(page);
+    }
+    let end = data.len() + foreign;
+    while data.len() < end {
+        let mut page = template.to_vec();
+        page[14..18].copy_from_slice(&0xBEEFu32.to_le_bytes());
+        data.extend(page);
+    }
+    data
+}
+
+fn full_parse_mp3(data: &[u8]) -> usize {
+    let mut f = mutagen_rs::mp3::MP3File::parse(data, "bench.mp3").unwrap();
+    f.ensure_tags_parsed(data);
+    f.tags.values_decoded().len()
+}
+
+fn full_parse_flac(data: &[u8]) -> usize {
+    let mut f = mutagen_rs::flac::FLACFile::parse(data, "bench.flac").unwrap();
+    f.get_tags().map_or(0, |vc| vc.keys().len())
+}
+
+fn full_parse_ogg(data: &[u8]) -> usize {
+    let mut f = mutagen_rs::ogg::OggVorbisFile::parse(data, "bench.ogg").unwrap();
+    f.ensure_full_parse(data);
+    f.ensure_tags();
+    f.tags.keys().len()
+}
+
+fn full_parse_mp4(data: &[u8]) -> usize {
+    let mut f = mutagen_rs::mp4::MP4File::parse(data, "bench.m4a").unwrap();
+    f.ensure_parsed_with_data(data);
+    f.tags.keys().len()
+}
+
+fn full_parse(ext: &str, data: &[u8]) -> usize {
+    match ext {
+        "mp3" => full_parse_mp3(data),
+        "flac" => full_parse_flac(data),
+        "ogg" => full_parse_ogg(data),
+        _ => full_parse_mp4(data),
+    }
+}
+
+fn bench_id3_frames(c: &mut Criterion) {
+    let (_, header) = mutagen_rs::id3::load_id3_from_slice(SMALL_MP3).unwrap();
+    let small = SMALL_MP3[..header.unwrap().full_size() as usize].to_vec();
+    let large = large_id3(500, 1 << 20);
+
+    let mut group = c.benchmark_group("id3_frames");
+    for (name, tag) in [("small", &small), ("large", &large)] {
+        group.throughput(Throughput::Bytes(tag.len() as u64));
+        group.bench_with_input(BenchmarkId::new("walk", name), tag, |b, tag| {
+            b.iter(|| mutagen_rs::id3::load_id3_from_slice(black_box(tag)).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("walk_decode", name), tag, |b, tag| {
+            b.iter(|| {
+                let (mut tags, _) = mutagen_rs::id3::load_id3_from_slice(black_box(tag)).unwrap();
+                tags.values_decoded().len()
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_vorbis_comment(c: &mut Criterion) {
+    let mut group = c.benchmark_group("vorbis_comment");
+    for count in [16, 1000] {
+        let data = vorbis_comment(count);
+        group.throughput(Throughput::Bytes(data.len() as u64));
+        group.bench_with_input(BenchmarkId::new("parse", count), &data, |b, data| {
+            b.iter(|| mutagen_rs::vorbis::VorbisComment::parse(black_box(data), false).unwrap())
+        });
+    }
+    group.finish();
+}
+
+/// Grouping values under keys when every key is distinct, the worst case for
+/// a linear key search.
+fn bench_tag_grouping(c: &mut Criterion) {
+    let mut group = c.benchmark_group("tag_grouping");
+    for count in [100, 10_000] {
+        let vc = cue_vorbis_comment(count);
+        group.throughput(Throughput::Elements(count as u64));
+        group.bench_with_input(BenchmarkId::new("vc_keys", count), &vc, |b, data| {
+            b.iter(|| {
+                let vc = mutagen_rs::vorbis::VorbisComment::parse(black_box(data), false).unwrap();
+                vc.keys().len()
+            })
+        });
+        let m4a = large_m4a(count, 0);
+        group.bench_with_input(BenchmarkId::new("mp4_ilst", count), &m4a, |b, data| {
+            b.iter(|| full_parse_mp4(black_box(data)))
+        });
+    }
+    group.finish();
+}
+
+fn bench_mp4_atoms(c: &mut Criterion) {
+    fn walk(data: &[u8], start: usize, end: usize) -> usize {
+        AtomIter::new(data, start, end)
+            .map(|atom| {
+                let children = match &atom.name {
+                    b"moov" | b"trak" | b"mdia" | b"minf" | b"stbl" | b"udta" | b"ilst" => {
+                        walk(data, atom.data_offset, atom.data_offset + atom.data_size)
+                    }
+                    b"meta" => walk(data, atom.data_offset + 4, atom.data_offset + atom.data_size),
+                    _ => 0,
+                };
+                1 + children
+            })
+            .sum()
+    }
+
+    let large = large_m4a(500, 1 << 20);
+    let mut group = c.benchmark_group("mp4_atoms");
+    for (name, data) in [("small", SMALL_M4A), ("large", large.as_slice())] {
+        group.throughput(Throughput::Bytes(data.len() as u64));
+        group.bench_with_input(BenchmarkId::new("walk", name), data, |b, data| {
+            b.iter(|| walk(black_box(data), 0, data.len()))
+        });
+    }
+    group.finish();
+}
+
+fn bench_ogg_granule(c: &mut Criterion) {
+    let serial = u32::from_le_bytes(SMALL_OGG[14..18].try_into().unwrap());
+    let cases = [
+        ("small", SMALL_OGG.to_vec()),
+        ("large", large_ogg(2000, 0)),
+        ("large_widened", large_ogg(2000, 16 * 1024)),
+    ];
+
+    let mut group = c.benchmark_group("ogg_last_granule");
+    for (name, data) in &cases {
+        group.throughput(Throughput::Bytes(data.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(name), data, |b, data| {
+            b.iter(|| mutagen_rs::ogg::find_last_granule(black_box(data), serial).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn bench_full_parse(c: &mut Criterion) {
+    let cases = [
+        ("mp3", "small", SMALL_MP3.to_vec()),
+        ("mp3", "large", large_mp3()),
+        ("flac", "small", SMALL_FLAC.to_vec()),
+        ("flac", "large", large_flac(1000)),
+        ("ogg", "small", SMALL_OGG.to_vec()),
+        ("ogg", "large", large_ogg(2000, 0)),
+        ("mp4", "small", SMALL_M4A.to_vec()),
+        ("mp4", "large", large_m4a(500, 1 << 20)),
+    ];
+
+    let mut group = c.benchmark_group("full_parse");
+    for (ext, size, data) in &cases {
+        group.throughput(Throughput::ElementsAndBytes { elements: 1, bytes: data.len() as u64 });
+        group.bench_with_input(BenchmarkId::new(*ext, size), data, |b, data| {
+            b.iter(|| full_parse(ext, black_box(data)))
+        });
+    }
+    group.finish();
+}
+
+/// Read and parse `BATCH_FILES` small files on the rayon pool, like `batch_open`
+/// minus the Python dicts. Files stay in the page cache between iterations.
+fn bench_batch(c: &mut Criterion) {
+    let dir = std::env::temp_dir().join(format!("mutagen_rs_bench_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let fixtures: [(&str, &[u8]); 4] = [("mp3", SMALL_MP3), ("flac", SMALL_FLAC), ("ogg", SMALL_OGG), ("m4a", SMALL_M4A)];
+    let mut paths: Vec<(PathBuf, &str)> = Vec::with_capacity(BATCH_FILES);
+    let mut bytes = 0u64;
+    for i in 0..BATCH_FILES {
+        let (ext, data) = fixtures[i % fixtures.len()];
+        let path = dir.join(format!("{i:04}.{ext}"));
+        std::fs::write(&path, data).unwrap();
+        bytes += data.len() as u64;
+        paths.push((path, ext));
+    }
+
+    let mut group = c.benchmark_group("batch");
+    group.throughput(Throughput::ElementsAndBytes { elements: BATCH_FILES as u64, bytes });
+    group.bench_function("synthetic_1k", |b| {
+        b.iter(|| {
+            paths.par_iter()
+                .map(|(path, ext)| full_parse(ext, &std::fs::read(path).unwrap()))
+                .sum::<usize>()
+        })
+    });
+    group.finish();
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+criterion_group!(
+    benches,
+    bench_id3_frames,
+    bench_vorbis_comment,
+    bench_tag_grouping,
+    bench_mp4_atoms,
+    bench_ogg_granule,
+    bench_full_parse,
+    bench_batch
+);
+criterion_main!(benches);
@@ -0,0 +1,71 @@
+//! Text decode throughput for ID3 text frames and Vorbis comments.
+//!
+//!     cargo bench --bench text_decode
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use std::hint::black_box;
+
+/// Text frame bodies: encoding byte followed by `count` null-separated values.
+fn text_frame(encoding: u8, value: &[u8], count: usize) -> Vec<u8> {
+    let mut data = vec![encoding];
+    for i in 0..count {
+        if i > 0 {
+            data.push(0);
+        }
+        data.extend_from_slice(value);
+    }
+    data
+}
+
+fn vorbis_comment(values: &[&str], count: usize) -> Vec<u8> {
+    let vendor = b"reference libFLAC 1.4.3 20230623";
+    let mut data = Vec::new();
+    data.extend_from_slice(&(vendor.len() as u32).to_le_bytes());
+    data.extend_from_slice(vendor);
+    data.extend_from_slice(&(count as u32).to_le_bytes());
+    for i in 0..count {
+        let comment = format!("{}={}", if i % 2 == 0 { "TITLE" } else { "artist" }, values[i % values.len()]);
+        data.extend_from_slice(&(comment.len() as u32).to_le_bytes());
+        data.extend_from_slice(comment.as_bytes());
+    }
+    data
+}
+
+fn bench_id3_text(c: &mut Criterion) {
+    let ascii = b"The Quick Brown Fox Jumps Over The Lazy Dog (Extended Mix)";
+    let utf8 = "Sigur R\u{f3}s \u{2014} \u{c1}g\u{e6}tis byrjun \u{30c6}\u{30b9}\u{30c8}".as_bytes();
+    let latin1: Vec<u8> = b"Bj\xf6rk \xb7 Homog\xe9nic \xb7 J\xf3ga".to_vec();
+    let cases: [(&str, Vec<u8>); 4] = [
+        ("utf8_ascii", text_frame(3, ascii, 16)),
+        ("utf8_multibyte", text_frame(3, utf8, 16)),
+        ("latin1_ascii", text_frame(0, ascii, 16)),
+        ("latin1_high", text_frame(0, &latin1, 16)),
+    ];
+
+    let mut group = c.benchmark_group("id3_text_frame");
+    for (name, data) in &cases {
+        group.throughput(Throughput::Bytes(data.len() as u64));
+        group.bench_function(*name, |b| {
+            b.iter(|| mutagen_rs::id3::frames::parse_text_frame("TIT2", black_box(data)).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn bench_vorbis_comment(c: &mut Criterion) {
+    let cases = [
+        ("ascii", vorbis_comment(&["Homogenic", "Bjork"], 1000)),
+        ("multibyte", vorbis_comment(&["Homog\u{e9}nic", "Bj\u{f6}rk \u{30c6}\u{30b9}\u{30c8}"], 1000)),
+    ];
+
+    let mut group = c.benchmark_group("vorbis_comment");
+    for (name, data) in &cases {
+        group.throughput(Throughput::Bytes(data.len() as u64));
+        group.bench_function(*name, |b| {
+            b.iter(|| mutagen_rs::vorbis::VorbisComment::parse(black_box(data), false).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_id3_text, bench_vorbis_comment);
+criterion_main!(benches);
@@ -0,0 +1,77 @@
+"""Cold-cache batch_open throughput at different io_depth settings.
+
+Point it at a directory on the filesystem you care about (e.g. an NFS mount):
+
+    python benchmarks/batch_io_depth.py /mnt/nfs/music --depths 0 8 32 64
+
+Before each run the page cache is dropped for every file with
+posix_fadvise(DONTNEED). That is enough for local disks and most NFS clients;
+for a fully cold server cache, also drop caches on the server between runs.
+"""
+import argparse
+import os
+import time
+
+import mutagen_rs
+
+EXTENSIONS = {".mp3", ".flac", ".ogg", ".m4a", ".m4b", ".mp4"}
+
+
+def find_files(root):
+    paths = []
+    for dirpath, _, names in os.walk(root):
+        for name in names:
+            if os.path.splitext(name)[1].lower() in EXTENSIONS:
+                paths.append(os.path.join(dirpath, name))
+    return sorted(paths)
+
+
+def drop_cache(paths):
+    if not hasattr(os, "posix_fadvise"):
+        return
+    for p in paths:
+        try:
+            fd = os.open(p, os.O_RDONLY)
+        except OSError:
+            continue
+        try:
+            os.posix_fadvise(fd, 0, 0, os.POSIX_FADV_DONTNEED)
+        finally:
+            os.close(fd)
+
+
+def run(paths, depth, repeat):
+    times = []
+    for _ in range(repeat):
+        mutagen_rs.clear_all_caches()
+        drop_cache(paths)
+        start = time.perf_counter()
+        result = mutagen_rs.batch_open(list(paths), io_depth=depth)
+        times.append(time.perf_counter() - start)
+    return min(times), len(result)
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[0])
+    parser.add_argument("root", help="directory to scan for audio files")
+    parser.add_argument("--depths", type=int, nargs="+", default=[0, 8, 32, 64])
+    parser.add_argument("--repeat", type=int, default=3)
+    args = parser.parse_args()
+
+    paths = find_files(args.root)
+    if not paths:
+        raise SystemExit(f"no audio files under {args.root}")
+    total_bytes = sum(os.path.getsize(p) for p in paths)
+    print(f"{len(paths)} files, {total_bytes / 1e6:.1f} MB")
+
+    baseline = None
+    for depth in args.depths:
+        best, parsed = run(paths, depth, args.repeat)
+        baseline = baseline or best
+        print(f"io_depth={depth:<4} {best * 1000:9.1f} ms  "
+              f"{len(paths) / best:9.0f} files/s  "
+              f"{baseline / best:5.2f}x  ({parsed} parsed)")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,82 @@
+"""Markdown before/after table from two saved criterion baselines.
+
+Save a baseline on each side of the change, then compare them:
+
+    git checkout main
+    cargo bench --bench parsers -- --save-baseline before
+    git checkout my-branch
+    cargo bench --bench parsers -- --save-baseline after
+    python benchmarks/criterion_compare.py before after
+
+The table (median time, change, and throughput in files/s and MB/s where the
+benchmark declares it) is meant to be pasted into a PR description.
+"""
+import argparse
+import json
+import os
+
+
+def load(root, baseline):
+    """Map benchmark id -> (median ns, throughput dict) for one baseline."""
+    results = {}
+    for dirpath, dirnames, filenames in os.walk(root):
+        if os.path.basename(dirpath) != baseline or "estimates.json" not in filenames:
+            continue
+        with open(os.path.join(dirpath, "estimates.json")) as f:
+            median = json.load(f)["median"]["point_estimate"]
+        with open(os.path.join(dirpath, "benchmark.json")) as f:
+            bench = json.load(f)
+        results[bench["full_id"]] = (median, bench.get("throughput") or {})
+    return results
+
+
+def fmt_time(ns):
+    for unit, scale in (("s", 1e9), ("ms", 1e6), ("µs", 1e3)):
+        if ns >= scale:
+            return f"{ns / scale:.2f} {unit}"
+    return f"{ns:.1f} ns"
+
+
+def fmt_throughput(ns, throughput):
+    seconds = ns / 1e9
+    parts = []
+    elements = throughput.get("Elements")
+    nbytes = throughput.get("Bytes")
+    both = throughput.get("ElementsAndBytes")
+    if both:
+        elements, nbytes = both["elements"], both["bytes"]
+    if elements:
+        parts.append(f"{elements / seconds:,.0f} files/s")
+    if nbytes:
+        parts.append(f"{nbytes / seconds / 1e6:,.1f} MB/s")
+    return ", ".join(parts)
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[0])
+    parser.add_argument("before", help="baseline name saved with --save-baseline")
+    parser.add_argument("after", help="baseline name saved with --save-baseline")
+    parser.add_argument("--dir", default=os.path.join("target", "criterion"),
+                        help="criterion output directory")
+    args = parser.parse_args()
+
+    before = load(args.dir, args.before)
+    after = load(args.dir, args.after)
+    if not before or not after:
+        missing = args.before if not before else args.after
+        raise SystemExit(f"no results for baseline {missing!r} under {args.dir}")
+
+    print(f"| Benchmark | {args.before} | {args.after} | Change | Throughput ({args.after}) |")
+    print("|---|---:|---:|---:|---|")
+    for name in sorted(set(before) | set(after)):
+        old = before.get(name)
+        new = after.get(name)
+        old_s = fmt_time(old[0]) if old else "-"
+        new_s = fmt_time(new[0]) if new else "-"
+        change = f"{(new[0] / old[0] - 1) * 100:+.1f}%" if old and new else "-"
+        thrpt = fmt_throughput(*new) if new else ""
+        print(f"| {name} | {old_s} | {new_s} | {change} | {thrpt} |")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,95 @@
+"""Concurrent _fast_read throughput from a Python thread pool.
+
+Each worker opens a different file, so with the GIL released during reads the
+wall time should drop close to linearly with the thread count:
+
+    python benchmarks/threaded_fast_read.py --threads 1 2 4 8
+
+Without a directory argument it writes large FLAC files into a temporary
+directory (test_files/silence-44-s.flac padded with trailing audio data to
+--size MB). Caches are cleared before every run; the page cache is left warm,
+so the numbers measure copying and parsing rather than the disk.
+"""
+import argparse
+import os
+import shutil
+import tempfile
+import time
+from concurrent.futures import ThreadPoolExecutor
+
+import mutagen_rs
+
+EXTENSIONS = {".mp3", ".flac", ".ogg", ".m4a", ".m4b", ".mp4"}
+SEED = os.path.join(os.path.dirname(__file__), "..", "test_files", "silence-44-s.flac")
+
+
+def find_files(root):
+    paths = []
+    for dirpath, _, names in os.walk(root):
+        for name in names:
+            if os.path.splitext(name)[1].lower() in EXTENSIONS:
+                paths.append(os.path.join(dirpath, name))
+    return sorted(paths)
+
+
+def make_files(tmpdir, count, size_mb):
+    with open(SEED, "rb") as f:
+        seed = f.read()
+    padding = os.urandom(max(0, size_mb * 1024 * 1024 - len(seed)))
+    paths = []
+    for i in range(count):
+        path = os.path.join(tmpdir, f"large_{i:03d}.flac")
+        with open(path, "wb") as f:
+            f.write(seed)
+            f.write(padding)
+        paths.append(path)
+    return paths
+
+
+def run(paths, threads, repeat):
+    times = []
+    for _ in range(repeat):
+        mutagen_rs.clear_all_caches()
+        start = time.perf_counter()
+        with ThreadPoolExecutor(max_workers=threads) as pool:
+            results = list(pool.map(mutagen_rs._fast_read, paths))
+        times.append(time.perf_counter() - start)
+    return min(times), len(results)
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[0])
+    parser.add_argument("root", nargs="?", help="directory to scan for audio files")
+    parser.add_argument("--threads", type=int, nargs="+", default=[1, 2, 4, 8])
+    parser.add_argument("--files", type=int, default=32, help="files to generate")
+    parser.add_argument("--size", type=int, default=64, help="generated file size in MB")
+    parser.add_argument("--repeat", type=int, default=3)
+    args = parser.parse_args()
+
+    tmpdir = None
+    if args.root:
+        paths = find_files(args.root)
+        if not paths:
+            raise SystemExit(f"no audio files under {args.root}")
+    else:
+        tmpdir = tempfile.mkdtemp(prefix="mutagen_rs_bench_")
+        paths = make_files(tmpdir, args.files, args.size)
+
+    try:
+        total_bytes = sum(os.path.getsize(p) for p in paths)
+        print(f"{len(paths)} files, {total_bytes / 1e6:.1f} MB")
+
+        baseline = None
+        for threads in args.threads:
+            best, parsed = run(paths, threads, args.repeat)
+            baseline = baseline or best
+            print(f"threads={threads:<3} {best * 1000:9.1f} ms  "
+                  f"{total_bytes / best / 1e9:6.2f} GB/s  "
+                  f"{baseline / best:5.2f}x  ({parsed} parsed)")
+    finally:
+        if tmpdir:
+            shutil.rmtree(tmpdir)
+
+
+if __name__ == "__main__":
+    main()
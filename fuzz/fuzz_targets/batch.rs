@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// The batch path picks a parser from the extension, then sniffs the content,
+// so every input goes through each extension.
+fuzz_target!(|data: &[u8]| {
+    for path in ["fuzz.mp3", "fuzz.flac", "fuzz.ogg", "fuzz.m4a", "fuzz.wv", "fuzz.bin"] {
+        let _ = mutagen_rs::fuzz::batch_parse(data, path);
+    }
+});
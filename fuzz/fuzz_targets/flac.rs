@@ -0,0 +1,16 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use mutagen_rs::flac;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(mut f) = flac::FLACFile::parse(data, "fuzz.flac") {
+        f.ensure_tags();
+        for lp in &f.lazy_pictures {
+            let end = lp.block_offset.saturating_add(lp.block_size);
+            if let Some(block) = data.get(lp.block_offset..end) {
+                let _ = flac::FLACPicture::parse(block);
+            }
+        }
+    }
+});
@@ -0,0 +1,22 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use mutagen_rs::{id3, mp3};
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok((mut tags, _)) = id3::load_id3_from_data(data) {
+        let _ = tags.values_decoded();
+        let _ = tags.seek_offset();
+        let _ = tags.render(4);
+        let _ = tags.render(3);
+    }
+    if let Ok(mut f) = mp3::MP3File::parse(data, "fuzz.mp3") {
+        f.ensure_tags_parsed(data);
+    }
+    // Frame bodies are also parsed on their own when a lazy frame is decoded.
+    if data.len() > 4 {
+        if let Ok(id) = std::str::from_utf8(&data[..4]) {
+            let _ = id3::frames::parse_frame(id, &data[4..]);
+        }
+    }
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use mutagen_rs::mp4;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(mut f) = mp4::MP4File::parse(data, "fuzz.m4a") {
+        f.ensure_parsed_with_data(data);
+    }
+    let _ = mutagen_rs::fuzz::batch_parse(data, "fuzz.m4a");
+});
@@ -0,0 +1,14 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use mutagen_rs::ogg;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(mut f) = ogg::OggVorbisFile::parse(data, "fuzz.ogg") {
+        f.ensure_full_parse(data);
+        f.ensure_tags();
+        let _ = ogg::count_pages(data, f.info.serial);
+    }
+    let _ = ogg::find_last_granule(data, 0);
+    let _ = mutagen_rs::fuzz::ogg_batch(data);
+});
@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use mutagen_rs::vorbis::VorbisComment;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = VorbisComment::parse(data, true);
+    let _ = VorbisComment::parse(data, false);
+    let _ = mutagen_rs::fuzz::vc_batch_tags(data);
+});
@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use mutagen_rs::wavpack;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = wavpack::WavPackFile::parse(data, "fuzz.wv");
+    let _ = mutagen_rs::common::parse::parse_data(data, "fuzz.wv");
+    let _ = mutagen_rs::fuzz::batch_parse(data, "fuzz.wv");
+});
@@ -1,9 +1,12 @@
 """mutagen_rs - High-performance audio metadata library written in Rust.
 
 A drop-in replacement for mutagen with 100x+ performance on read operations.
-Supports MP3/ID3, FLAC, OGG Vorbis, and MP4/M4A formats.
+Supports MP3/ID3, FLAC, OGG Vorbis, Ogg Opus, and MP4/M4A formats.
 """
 
+import base64
+import math
+import struct
 from importlib.metadata import version as _pkg_version
 
 try:
@@ -20,40 +23,86 @@ from .mutagen_rs import (
     MP3 as _RustMP3,
     FLAC as _RustFLAC,
     OggVorbis as _RustOggVorbis,
+    OggOpus as _RustOggOpus,
     MP4 as _RustMP4,
+    WavPack as _RustWavPack,
+    MonkeysAudio as _RustMonkeysAudio,
     file_open as _rust_file_open,
 
+    # Read-only file objects (info + tag dict, no mutable tag objects)
+    open_readonly,
+    ReadOnlyFile,
+
     # Info types (re-exported as-is)
     MPEGInfo,
     StreamInfo,
     OggVorbisInfo,
+    OggOpusInfo,
     MP4Info,
+    WavPackInfo,
+    MonkeysAudioInfo,
 
     # Tag types (re-exported as-is)
     ID3,
     VComment,
     MP4Tags,
+    APEv2,
 
     # Batch API
     batch_open as _rust_batch_open,
+    batch_open_buffers as _rust_batch_open_buffers,
     batch_diag,
+    batch_export_ndjson,
+    batch_open_arrow,
+    batch_total_length,
+    batch_count_by_format,
+    batch_iter as _rust_batch_iter,
     BatchResult,
+    BatchIter,
+    ArrowBatch,
 
     # Fast single-file read (returns dict, minimal PyO3 overhead)
     _fast_read,
 
+    # Fast single-file read returning a JSON document (bytes)
+    _fast_read_json,
+
     # Fast info-only read (no tags, maximum speed)
     _fast_info,
 
+    # Length only, as a float (no dict at all)
+    duration,
+
+    # Tag presence probes (no value decoding)
+    has_tags,
+    tag_summary,
+    batch_tag_summary,
+
+    # Content-based format check against the extension
+    verify_extension,
+
+    # Cross-format tag copy
+    copy_tags as _rust_copy_tags,
+
+    # MP4 tags from the tail bytes alone (moov at the end)
+    mp4_parse_tail,
+
     # Fast sequential batch read (single Rust call, no parallelism)
     _fast_read_seq,
 
     # Fast parallel batch read (rayon + raw FFI dict creation)
     _fast_batch_read,
 
+    # Fast parallel batch read straight to MessagePack bytes
+    _fast_batch_read_msgpack,
+
     # Clear Rust-level caches
     clear_cache as _rust_clear_cache,
     clear_all_caches as _rust_clear_all_caches,
+    _cache_stats,
+
+    # File bytes through the Rust read cache
+    read_cached_bytes,
 
     # Error types (re-exported as-is)
     MutagenError,
@@ -65,13 +114,19 @@ from .mutagen_rs import (
     FLACNoHeaderError,
     OggError,
     MP4Error,
+    CAFError,
+    MonkeysAudioHeaderError,
+    MonkeysAudioTruncatedError,
+    WavPackHeaderError,
+    APEError,
+    APENoHeaderError,
 )
 
 # Module-level cache: filename -> _CachedFile
 _cache = {}
 
-# Last batch call cache: [list_object, result]
-_last_batch = [None, None]
+# Last batch call cache: [list_object, (io_depth, dedupe), result]
+_last_batch = [None, None, None]
 
 
 # ──────────────────────────────────────────────────────────────
@@ -144,20 +199,217 @@ class PaddingInfo:
 # Format name mapping and subclass creation
 # ──────────────────────────────────────────────────────────────
 
-_FORMAT_NAMES = {'mp3': 'MP3', 'flac': 'FLAC', 'ogg': 'OggVorbis', 'mp4': 'MP4'}
+_FORMAT_NAMES = {'mp3': 'MP3', 'flac': 'FLAC', 'ogg': 'OggVorbis', 'opus': 'OggOpus', 'mp4': 'MP4',
+                 'caf': 'CAF', 'ape': 'MonkeysAudio', 'wavpack': 'WavPack'}
+
+# Formats tagged with Vorbis comments, whose keys ignore case
+_VC_FORMATS = ('flac', 'ogg', 'opus')
+
+# File extensions handled as MP4 when the detected format isn't known
+_MP4_EXTS = ('mp4', 'm4a', 'm4b', 'm4v', 'aac', 'aax', 'aaxc')
+
+# Tag keys holding the encoding tool per format, in lookup order
+_ENCODER_TOOL_KEYS = {
+    'mp3': ('TSSE', 'TENC'),
+    'flac': ('encoder',),
+    'ogg': ('encoder',),
+    'opus': ('encoder',),
+    'mp4': ('\xa9too',),
+    'caf': ('encoder',),
+    'ape': ('Encoder', 'Tool Name'),
+}
+
+# Tag keys holding the genre per format, in lookup order. MP4 files may carry
+# both the free-text ``\xa9gen`` and the legacy numeric ``gnre``; the free text wins.
+_GENRE_KEYS = {
+    'mp3': ('TCON',),
+    'flac': ('genre',),
+    'ogg': ('genre',),
+    'opus': ('genre',),
+    'mp4': ('\xa9gen', 'gnre'),
+    'caf': ('genre',),
+    'ape': ('Genre',),
+}
+
+# Tempo keys per format, in order of precedence: the MP4 freeform BPM can hold
+# a fractional tempo that the integer ``tmpo`` atom cannot, so it goes first.
+_BPM_KEYS = (
+    {'mp4': ('----:com.apple.iTunes:BPM',)},
+    {
+        'mp3': ('TBPM',),
+        'flac': ('bpm',),
+        'ogg': ('bpm',),
+        'opus': ('bpm',),
+        'mp4': ('tmpo',),
+        'caf': ('bpm',),
+        'ape': ('BPM',),
+    },
+)
+
+# Track artist keys per format. The album artist (ID3 ``TPE2``, MP4 ``aART``)
+# is a separate field, read by credits().
+_ARTIST_KEYS = {
+    'mp3': ('TPE1',),
+    'flac': ('artist',),
+    'ogg': ('artist',),
+    'opus': ('artist',),
+    'mp4': ('\xa9ART',),
+    'caf': ('artist',),
+    'ape': ('Artist',),
+}
+
+# Tag keys holding unsynchronized lyrics per format; set_lyrics() writes the
+# first and drops the rest. ID3 ``USLT`` keys are qualified (``USLT:desc:lang``).
+_LYRICS_KEYS = {
+    'mp3': ('USLT',),
+    'flac': ('lyrics', 'unsyncedlyrics'),
+    'ogg': ('lyrics', 'unsyncedlyrics'),
+    'opus': ('lyrics', 'unsyncedlyrics'),
+    'mp4': ('\xa9lyr',),
+}
+
+# Where lyrics() looks, in order: the canonical entry, then the places legacy
+# taggers used (an ID3 comment described "lyrics", a Vorbis LYRICS comment)
+_LYRICS_READ_KEYS = {
+    'mp3': ('USLT', 'COMM:lyrics'),
+    'flac': ('unsyncedlyrics', 'lyrics'),
+    'ogg': ('unsyncedlyrics', 'lyrics'),
+    'opus': ('unsyncedlyrics', 'lyrics'),
+    'mp4': ('\xa9lyr',),
+}
+
+# Credit fields returned by credits(), with their tag keys per format in lookup
+# order. MP4 has no standard conductor, performer or lyricist atoms, so those
+# come from the iTunes freeform atoms other taggers write (plus the ``\xa9con``
+# and ``\xa9prf`` atoms some use). ID3 ``TMCL`` pairs yield the performer name.
+_CREDIT_KEYS = {
+    'conductor': {
+        'mp3': ('TPE3',),
+        'flac': ('conductor',),
+        'ogg': ('conductor',),
+        'opus': ('conductor',),
+        'mp4': ('----:com.apple.iTunes:CONDUCTOR', '\xa9con'),
+        'ape': ('Conductor',),
+    },
+    'album_artist': {
+        'mp3': ('TPE2',),
+        'flac': ('albumartist', 'album artist'),
+        'ogg': ('albumartist', 'album artist'),
+        'opus': ('albumartist', 'album artist'),
+        'mp4': ('aART',),
+        'ape': ('Album Artist',),
+    },
+    'composer': {
+        'mp3': ('TCOM',),
+        'flac': ('composer',),
+        'ogg': ('composer',),
+        'opus': ('composer',),
+        'mp4': ('\xa9wrt',),
+        'caf': ('composer',),
+        'ape': ('Composer',),
+    },
+    'lyricist': {
+        'mp3': ('TEXT',),
+        'flac': ('lyricist',),
+        'ogg': ('lyricist',),
+        'opus': ('lyricist',),
+        'mp4': ('----:com.apple.iTunes:LYRICIST',),
+        'caf': ('lyricist',),
+        'ape': ('Lyricist',),
+    },
+    'performer': {
+        'mp3': ('TMCL', 'TXXX:PERFORMER'),
+        'flac': ('performer',),
+        'ogg': ('performer',),
+        'opus': ('performer',),
+        'mp4': ('\xa9prf', '----:com.apple.iTunes:PERFORMER'),
+        'ape': ('Performer',),
+    },
+}
+
+# iTunes SoundCheck: an ID3 comment described ``iTunNORM`` (any language) or the
+# MP4 freeform atom of that name.
+_ITUNNORM_KEYS = {
+    'mp3': ('COMM:iTunNORM',),
+    'mp4': ('----:com.apple.iTunes:iTunNORM',),
+}
+
+
+def _parse_itunnorm(text):
+    """Named fields of an iTunNORM value (ten hex numbers), or None if malformed.
+
+    Volume adjustments are given per channel against a 1000 and a 2500
+    milliwatt basis; peaks are sample values (0x7FFF is full scale for
+    16-bit audio).
+    """
+    try:
+        values = [int(v, 16) for v in text.split()]
+    except ValueError:
+        return None
+    if len(values) != 10:
+        return None
+    return {
+        'volume_left': values[0],
+        'volume_right': values[1],
+        'volume_left_2500': values[2],
+        'volume_right_2500': values[3],
+        'peak_left': values[6],
+        'peak_right': values[7],
+        'values': values,
+    }
+
+
+# ReplayGain fields, stored as Vorbis/APEv2 ``REPLAYGAIN_*`` comments, ID3
+# ``TXXX:REPLAYGAIN_*`` frames or MP4 ``----:com.apple.iTunes:replaygain_*``
+# freeform atoms; names are matched case-insensitively.
+_REPLAYGAIN_FIELDS = ('track_gain', 'album_gain', 'track_peak', 'album_peak')
+_REPLAYGAIN_PREFIXES = ('txxx:', '----:com.apple.itunes:')
+
+
+# R128 gains are relative to -23 LUFS, ReplayGain's to -18 LUFS
+_R128_TO_REPLAYGAIN_DB = 5.0
+
+
+def _parse_gain(text):
+    """Float from a ReplayGain value such as ``"-7.50 dB"`` or ``"0.988"``, or None."""
+    text = str(text).strip()
+    if text[-2:].lower() == 'db':
+        text = text[:-2].strip()
+    try:
+        return float(text)
+    except ValueError:
+        return None
+
+
+def _parse_picture_block(block):
+    """(type, data) of a FLAC picture block, as stored in METADATA_BLOCK_PICTURE."""
+    pic_type, mime_len = struct.unpack_from('>II', block, 0)
+    pos = 8 + mime_len
+    (desc_len,) = struct.unpack_from('>I', block, pos)
+    pos += 4 + desc_len + 16
+    (data_len,) = struct.unpack_from('>I', block, pos)
+    pos += 4
+    if pos + data_len > len(block):
+        raise ValueError('truncated picture block')
+    return pic_type, block[pos:pos + data_len]
 
 
 class _InfoProxy:
     """Lightweight info proxy -- stores attributes directly, no PyO3 dispatch."""
-    __slots__ = ('length', 'channels', 'sample_rate', 'bitrate',
+    __slots__ = ('length', 'length_samples', 'duration_ms', 'channels', 'sample_rate', 'bitrate',
                  'bits_per_sample', 'version', 'layer', 'mode', 'protected',
-                 'bitrate_mode', 'encoder_info', 'encoder_settings',
+                 'bitrate_mode', 'encoder_info', 'encoder_settings', 'xing_frames',
                  'track_gain', 'track_peak', 'album_gain',
                  'total_samples', 'min_block_size', 'max_block_size',
-                 'min_frame_size', 'max_frame_size', 'codec', 'codec_description')
+                 'min_frame_size', 'max_frame_size', 'codec', 'codec_description',
+                 'channels_source', 'serial', 'page_count', 'recovered', 'warnings',
+                 'pre_skip', 'input_sample_rate', 'output_gain',
+                 'is_vbr', 'is_cbr', 'is_abr')
 
     def __init__(self, d):
         self.length = d.get('length', 0.0)
+        self.length_samples = d.get('length_samples')
+        self.duration_ms = d.get('duration_ms')
         self.channels = d.get('channels', 0)
         self.sample_rate = d.get('sample_rate', 0)
         self.bitrate = d.get('bitrate', 0)
@@ -168,16 +420,65 @@ class _InfoProxy:
         self.mode = d.get('mode')
         self.protected = d.get('protected')
         self.bitrate_mode = d.get('bitrate_mode')
+        self.encoder_info = d.get('encoder_info')
+        self.encoder_settings = d.get('encoder_settings')
+        self.xing_frames = d.get('xing_frames')
         # FLAC-specific
         self.bits_per_sample = d.get('bits_per_sample')
         self.total_samples = d.get('total_samples')
         # MP4-specific
         self.codec = d.get('codec')
+        self.codec_description = d.get('codec_description', '' if self.codec is not None else None)
+        self.channels_source = d.get('channels_source')
+        # OGG-specific
+        self.serial = d.get('serial')
+        self.page_count = d.get('page_count')
+        self.recovered = d.get('recovered')
+        # Opus-specific
+        self.pre_skip = d.get('pre_skip')
+        self.input_sample_rate = d.get('input_sample_rate')
+        self.output_gain = d.get('output_gain')
+        # Out-of-range header values that were read as 0
+        self.warnings = d.get('_warnings', [])
+        # Bitrate mode booleans: MP3 from bitrate_mode, FLAC/Ogg always VBR,
+        # None where the format doesn't say
+        if self.bitrate_mode is not None:
+            self.is_vbr = self.bitrate_mode == 2
+            self.is_cbr = self.bitrate_mode == 1
+            self.is_abr = self.bitrate_mode == 3
+        elif d.get('_format') in ('flac', 'ogg'):
+            self.is_vbr, self.is_cbr, self.is_abr = True, False, False
+        else:
+            self.is_vbr = self.is_cbr = self.is_abr = None
 
     def pprint(self):
+        if self.length is None:
+            return f"unknown length, {self.sample_rate} Hz"
         return f"{self.length:.2f} seconds, {self.sample_rate} Hz"
 
 
+def _is_id3_text(key, value):
+    """Whether an MP3 dict entry is a text frame the native setter can write:
+    a ``T***`` frame, ``TXXX:desc``, ``COMM:desc:lang`` or ``USLT:desc:lang``.
+    APEv2 keys read from the same file (``Tool``, ``Title``) are not frames."""
+    if not (key.startswith(('TXXX:', 'COMM:', 'USLT:'))
+            or (len(key) == 4 and key[0] == 'T' and key != 'TXXX'
+                and key.isalnum() and key.isupper())):
+        return False
+    if isinstance(value, str):
+        return True
+    return isinstance(value, list) and all(isinstance(x, str) for x in value)
+
+
+def _key_matches(key, pattern, ignore_case):
+    """Whether ``key`` matches a strip() pattern (see _CachedFile.strip)."""
+    if ignore_case:
+        key, pattern = key.lower(), pattern.lower()
+    if pattern.endswith('*'):
+        return key.startswith(pattern[:-1])
+    return key == pattern or key.startswith(pattern + ':')
+
+
 class _CachedFile(dict):
     """Dict subclass caching an opened audio file.
 
@@ -185,7 +486,8 @@ class _CachedFile(dict):
     Metadata stored as slot attributes for fast access.
     """
     __slots__ = ('info', 'filename', '_native', '_tag_keys', '_pictures',
-                 '_format', '_has_tags')
+                 '_format', '_has_tags', '_vendor', '_raw_items', '_id3_bytes',
+                 '_chapters', '_strip', '_lyrics', '_tag_version')
 
     @property
     def tags(self):
@@ -197,6 +499,13 @@ class _CachedFile(dict):
             return None
         return self
 
+    @property
+    def tag_version(self):
+        """Tag format: the ID3 version for MP3 (e.g. ``(2, 4)``, or ``(1, 1)`` for
+        ID3v1 alone), ``"VorbisComment"`` for FLAC/Ogg, the ``meta`` handler type
+        for MP4 (normally ``"mdir"``); None without a tag."""
+        return getattr(self, '_tag_version', None)
+
     @property
     def pictures(self):
         """FLAC pictures (list of dicts with type, mime, desc, width, height, data)."""
@@ -204,6 +513,18 @@ class _CachedFile(dict):
             return self._native.pictures
         return getattr(self, '_pictures', [])
 
+    @property
+    def vendor(self):
+        """Vorbis comment vendor string (FLAC/OGG); assignable to override it on save."""
+        vendor = getattr(self, '_vendor', None)
+        if vendor is not None:
+            return vendor
+        return self._get_native().tags.vendor
+
+    @vendor.setter
+    def vendor(self, value):
+        self._vendor = value
+
     def _get_native(self):
         """Get or create a native Rust object for mutation operations."""
         if self._native is not None:
@@ -213,24 +534,61 @@ class _CachedFile(dict):
             return _RustMP3(self.filename)
         elif ext == 'flac':
             return _RustFLAC(self.filename)
+        elif ext == 'opus' or (ext == 'ogg' and self._format == 'opus'):
+            return _RustOggOpus(self.filename)
         elif ext == 'ogg':
             return _RustOggVorbis(self.filename)
-        elif ext in ('m4a', 'mp4', 'aac'):
+        elif ext in _MP4_EXTS:
             return _RustMP4(self.filename)
+        elif ext == 'wv':
+            return _RustWavPack(self.filename)
+        elif ext == 'ape':
+            return _RustMonkeysAudio(self.filename)
         raise NotImplementedError(f"Not supported for .{ext}")
 
     def save(self, *args, **kwargs):
         """Save tag changes to the file."""
+        vendor = getattr(self, '_vendor', None)
         if self._native is not None:
+            if vendor is not None:
+                self._native.tags.vendor = vendor
             self._native.save(*args, **kwargs)
             _cache.pop(self.filename, None)
             _rust_clear_cache()
             return
         native = self._get_native()
-        for k in dict.keys(self):
-            v = dict.__getitem__(self, k)
-            if v is not None:
+        strip = getattr(self, '_strip', None)
+        if strip:
+            native.strip(strip)
+            self._strip = None
+        id3_bytes = getattr(self, '_id3_bytes', None)
+        if id3_bytes is not None:
+            native.set_id3_bytes(id3_bytes)
+            self._id3_bytes = None
+        else:
+            id3 = self._format == 'mp3'
+            for k in dict.keys(self):
+                v = dict.__getitem__(self, k)
+                if v is None:
+                    continue
+                # Binary and keyed ID3 frames (APIC, COMM, SEEK, ASPI, ...) are
+                # already in the native tag as read; only text frames go back.
+                if id3 and not _is_id3_text(k, v):
+                    continue
                 native[k] = v
+        for k, raw in getattr(self, '_raw_items', {}).items():
+            native.set_raw(k, raw)
+        self._raw_items = {}
+        chapters = getattr(self, '_chapters', None)
+        if chapters is not None:
+            native.set_chapters(chapters)
+            self._chapters = None
+        lyrics = getattr(self, '_lyrics', None)
+        if lyrics is not None:
+            native.set_lyrics(lyrics)
+            self._lyrics = None
+        if vendor is not None:
+            native.tags.vendor = vendor
         native.save(*args, **kwargs)
         _cache.pop(self.filename, None)
         _rust_clear_cache()
@@ -264,6 +622,372 @@ class _CachedFile(dict):
         """Return list of tag keys."""
         return self._tag_keys
 
+    def to_json(self, indent=None, include_pictures=False):
+        """Serialize stream info and tags to a JSON string."""
+        return self._get_native().to_json(indent, include_pictures)
+
+    def get_raw(self, key):
+        """Exact stored bytes (header + payload) of the ID3 frame ``key``."""
+        raw = getattr(self, '_raw_items', {}).get(key)
+        if raw is not None:
+            return raw
+        return self._get_native().get_raw(key)
+
+    def get_raw_atom(self, key):
+        """Exact stored bytes (header + children) of the MP4 item atom ``key``."""
+        raw = getattr(self, '_raw_items', {}).get(key)
+        if raw is not None:
+            return raw
+        return self._get_native().get_raw_atom(key)
+
+    def get_locales(self, key):
+        """Data atom locale of each value of the MP4 item ``key`` (0 = default)."""
+        return self._get_native().get_locales(key)
+
+    def get_raw_block(self, index):
+        """Exact stored bytes (header + body) of the FLAC metadata block at ``index``."""
+        return self._get_native().get_raw_block(index)
+
+    @property
+    def metadata_blocks(self):
+        """FLAC metadata blocks as dicts with block_type, offset, size and is_last."""
+        return self._get_native().metadata_blocks
+
+    def validate_layout(self):
+        """FLAC block layout spec violations as warning strings (empty if valid)."""
+        return self._get_native().validate_layout()
+
+    def set_raw(self, key, data):
+        """Replace ``key`` with a complete frame/atom written verbatim on save.
+
+        Only the length and ID in the header are checked.
+        """
+        data = bytes(data)
+        if self._native is not None:
+            self._native.set_raw(key, data)
+            return
+        # Validate now so bad input fails here rather than at save().
+        self._get_native().set_raw(key, data)
+        raw_items = getattr(self, '_raw_items', None)
+        if raw_items is None:
+            raw_items = self._raw_items = {}
+        raw_items[key] = data
+        if dict.__contains__(self, key):
+            dict.__delitem__(self, key)
+            self._tag_keys = [k for k in self._tag_keys if k != key]
+
+    def strip(self, patterns):
+        """Remove every frame, comment or atom whose key matches one of ``patterns``.
+
+        ``"APIC"`` matches that key and keys qualified by it (``"APIC:cover"``);
+        a trailing ``*`` matches by prefix (``"COMM*"``). Vorbis comment keys
+        ignore case. save() writes the result. Returns the removed keys.
+        """
+        patterns = [str(p) for p in patterns]
+        if self._native is not None:
+            return self._native.strip(patterns)
+        removed = self._get_native().strip(patterns)
+        fold = self._format in _VC_FORMATS
+        to_del = [k for k in self._tag_keys
+                  if any(_key_matches(k, p, fold) for p in patterns)]
+        for k in to_del:
+            dict.__delitem__(self, k)
+        self._tag_keys = [k for k in self._tag_keys if k not in to_del]
+        self._strip = (getattr(self, '_strip', None) or []) + patterns
+        return removed
+
+    @property
+    def chapters(self):
+        """MP4 Nero chapters as ``(start_seconds, title)`` tuples."""
+        chapters = getattr(self, '_chapters', None)
+        if chapters is not None:
+            return list(chapters)
+        return self._get_native().chapters
+
+    @property
+    def moov_offset(self):
+        """MP4: file offset of the ``moov`` atom header."""
+        return self._get_native().moov_offset
+
+    @property
+    def moov_size(self):
+        """MP4: size of the ``moov`` atom, header included."""
+        return self._get_native().moov_size
+
+    @property
+    def is_faststart(self):
+        """MP4: whether ``moov`` precedes the first ``mdat`` (True without one)."""
+        return self._get_native().is_faststart
+
+    def set_chapters(self, chapters):
+        """Replace the MP4 chapters with ``(start_seconds, title)`` pairs on save.
+
+        Written as a Nero ``chpl`` atom; an empty list removes the chapters.
+        """
+        chapters = [(float(start), str(title)) for start, title in chapters]
+        if self._native is not None:
+            self._native.set_chapters(chapters)
+            return
+        # Validate now so bad input fails here rather than at save().
+        self._get_native().set_chapters(chapters)
+        self._chapters = chapters
+
+    def _all_text(self, keys_by_format):
+        """Non-empty values of the first of the format's keys that has any, as strings."""
+        fmt = self._format or self.filename.rsplit('.', 1)[-1].lower()
+        if fmt in _MP4_EXTS:
+            fmt = 'mp4'
+        elif fmt == 'wavpack':
+            # Same APEv2 tag as Monkey's Audio
+            fmt = 'ape'
+        for key in keys_by_format.get(fmt, ()):
+            for k in self._tag_keys:
+                # Vorbis comment and APEv2 names are case-insensitive; ID3 keys
+                # may be qualified by description and language (``USLT::eng``)
+                if (k == key or (fmt in _VC_FORMATS and k.lower() == key)
+                        or (fmt == 'ape' and k.lower() == key.lower())
+                        or (fmt == 'mp3' and k.startswith(key + ':'))):
+                    value = dict.get(self, k)
+                    values = value if isinstance(value, list) else [value]
+                    # ID3 paired text (TMCL/TIPL) holds (role, name) tuples
+                    values = [str(v[-1] if isinstance(v, tuple) else v) for v in values if v]
+                    if values:
+                        return values
+        return []
+
+    def _first_text(self, keys_by_format):
+        """First non-empty value among the format's keys, as a string, or None."""
+        values = self._all_text(keys_by_format)
+        return values[0] if values else None
+
+    def set_id3_bytes(self, data):
+        """Replace the whole ID3v2 tag with ``data`` (e.g. from ``ID3.render()``).
+
+        The next save() writes it byte for byte. Tag values reflect the new tag
+        right away; set_raw() entries still apply, but other edits made through
+        this object before saving are discarded.
+        """
+        data = bytes(data)
+        if self._native is not None:
+            self._native.set_id3_bytes(data)
+            return
+        native = self._get_native()
+        native.set_id3_bytes(data)
+        dict.clear(self)
+        for k in native.keys():
+            v = native[k]
+            dict.__setitem__(self, k, _ID3Value(v) if isinstance(v, list) else _ID3Value([v]))
+        self._tag_keys = list(native.keys())
+        self._has_tags = True
+        self._id3_bytes = data
+
+    def encoder_tool(self):
+        """Encoder tool string from the format's own field, or None.
+
+        Reads MP4 ``\xa9too``, ID3 ``TSSE`` (then ``TENC``) or Vorbis ``ENCODER``.
+        """
+        return self._first_text(_ENCODER_TOOL_KEYS)
+
+    def genre(self):
+        """Genre string, or None.
+
+        Reads ID3 ``TCON`` or Vorbis ``GENRE``. For MP4, ``\xa9gen`` (free text)
+        takes precedence over ``gnre`` (an ID3v1 genre number, already resolved
+        to its name); both stay available as separate keys.
+        """
+        return self._first_text(_GENRE_KEYS)
+
+    def credits(self):
+        """Credit fields mapped across formats, each a string or None.
+
+        Returns ``{conductor, album_artist, composer, lyricist, performer}``
+        read from e.g. ID3 ``TPE3``/``TPE2``/``TCOM``/``TEXT``/``TMCL``, MP4
+        ``aART``/``\xa9wrt`` and freeform atoms, or the Vorbis comments of the
+        same names. Multi-valued fields give their first value.
+        """
+        return {field: self._first_text(keys) for field, keys in _CREDIT_KEYS.items()}
+
+    def itunes_normalization(self):
+        """Parsed iTunes SoundCheck (``iTunNORM``) values, or None.
+
+        Read from the ID3 ``COMM:iTunNORM`` comment or the MP4 freeform atom,
+        which stay separate from user comments. Returns a dict with
+        ``volume_left``/``volume_right`` (1000 mW basis),
+        ``volume_left_2500``/``volume_right_2500``, ``peak_left``/``peak_right``
+        and all ten raw ``values``.
+        """
+        text = self._first_text(_ITUNNORM_KEYS)
+        return _parse_itunnorm(text) if text is not None else None
+
+    def genres(self):
+        """All genres as a list of strings, empty without any.
+
+        ID3 ``TCON`` values come split and with numeric references such as
+        ``(17)`` expanded to names; Vorbis files give every ``GENRE`` comment.
+        MP4 reads ``\xa9gen``, falling back to ``gnre`` like genre().
+        """
+        return self._all_text(_GENRE_KEYS)
+
+    def bpm(self):
+        """Tempo in beats per minute as a float, or None.
+
+        Reads ID3 ``TBPM`` or Vorbis ``BPM``. For MP4, a number in the
+        ``----:com.apple.iTunes:BPM`` freeform (e.g. ``"128.5"``) wins over the
+        integer ``tmpo`` atom; values that don't parse are skipped.
+        """
+        for keys in _BPM_KEYS:
+            for value in self._all_text(keys):
+                try:
+                    bpm = float(str(value).strip())
+                except ValueError:
+                    continue
+                if math.isfinite(bpm):
+                    return bpm
+        return None
+
+    def artists(self):
+        """All track artists as a list of strings, empty without any.
+
+        Reads MP4 ``\xa9ART`` (one entry per ``data`` atom), ID3 ``TPE1`` or
+        Vorbis ``ARTIST`` comments. The album artist is not included; see
+        credits().
+        """
+        return self._all_text(_ARTIST_KEYS)
+
+    def replay_gain(self):
+        """ReplayGain values as floats (dB for gains), each None when absent.
+
+        Returns ``{track_gain, album_gain, track_peak, album_peak}`` from the
+        format's ``replaygain_*`` fields. Without a track gain, an iTunes
+        SoundCheck value (``iTunNORM``) supplies ``track_gain`` and
+        ``track_peak`` instead. Opus files take their gains from the
+        ``R128_TRACK_GAIN``/``R128_ALBUM_GAIN`` comments (Q7.8 dB on top of
+        the OpusHead output gain, relative to -23 LUFS), moved to the
+        ReplayGain reference.
+        """
+        result = dict.fromkeys(_REPLAYGAIN_FIELDS)
+        for k in self._tag_keys:
+            name = k.lower()
+            for prefix in _REPLAYGAIN_PREFIXES:
+                if name.startswith(prefix):
+                    name = name[len(prefix):]
+                    break
+            if not name.startswith('replaygain_') or name[11:] not in result:
+                continue
+            value = dict.get(self, k)
+            if isinstance(value, list):
+                value = value[0] if value else None
+            if value is not None and result[name[11:]] is None:
+                result[name[11:]] = _parse_gain(value)
+        fmt = self._format or self.filename.rsplit('.', 1)[-1].lower()
+        if fmt == 'opus':
+            output_gain = getattr(self.info, 'output_gain', None) or 0.0
+            for field in ('track_gain', 'album_gain'):
+                value = self._first_text({'opus': ('r128_' + field,)})
+                if result[field] is None and value is not None:
+                    try:
+                        q78 = int(value.strip())
+                    except ValueError:
+                        continue
+                    result[field] = q78 / 256.0 + output_gain + _R128_TO_REPLAYGAIN_DB
+        if result['track_gain'] is None:
+            norm = self.itunes_normalization()
+            if norm is not None:
+                volume = max(norm['volume_left'], norm['volume_right'])
+                if volume > 0:
+                    result['track_gain'] = round(-10 * math.log10(volume / 1000.0), 2)
+                    result['track_peak'] = max(norm['peak_left'], norm['peak_right']) / 32768.0
+        return result
+
+    def lyrics(self):
+        """Unsynchronized lyrics, or None.
+
+        Looks at ID3 ``USLT``, then a ``COMM`` frame described ``lyrics``; MP4
+        ``\xa9lyr``; Vorbis ``UNSYNCEDLYRICS``, then ``LYRICS``, then a
+        ``COMMENT`` of three or more lines (where some old taggers put them).
+        Line breaks are kept as stored.
+        """
+        text = self._first_text(_LYRICS_READ_KEYS)
+        fmt = self._format or self.filename.rsplit('.', 1)[-1].lower()
+        if text is None and fmt in _VC_FORMATS:
+            comments = self._all_text({fmt: ('comment',)})
+            text = next((c for c in comments if len(c.splitlines()) >= 3), None)
+        return text
+
+    def set_lyrics(self, text):
+        """Replace the unsynchronized lyrics with ``text`` on save.
+
+        Writes one ID3 ``USLT`` frame (language ``eng``, no description), the
+        MP4 ``\xa9lyr`` atom or a Vorbis ``LYRICS`` comment; other lyrics
+        entries of the file are removed.
+        """
+        text = str(text)
+        fmt = self._format or self.filename.rsplit('.', 1)[-1].lower()
+        if fmt in _MP4_EXTS:
+            fmt = 'mp4'
+        keys = _LYRICS_KEYS.get(fmt)
+        if keys is None:
+            raise NotImplementedError(f"Lyrics are not supported for {fmt}")
+        if self._native is not None:
+            if fmt == 'mp3':
+                self._native.set_lyrics(text)
+            else:
+                self._native.strip(list(keys))
+                self._native[keys[0]] = [text]
+            return
+        self.strip(list(keys))
+        if fmt == 'mp3':
+            # USLT is not a text frame; save() hands it to the native setter
+            key, value = 'USLT::eng', text
+            self._lyrics = text
+        else:
+            key, value = keys[0], [text]
+        dict.__setitem__(self, key, value)
+        self._tag_keys.append(key)
+
+    def cover(self):
+        """Front-cover image bytes, or None.
+
+        Looks at ID3 ``APIC`` frames, FLAC picture blocks, Vorbis
+        ``METADATA_BLOCK_PICTURE`` comments or MP4 ``covr``. Picture type 3
+        (front cover) wins; otherwise the first picture is returned. MP4 covers
+        carry no type, so the first ``covr`` image is used.
+        """
+        fmt = self._format or self.filename.rsplit('.', 1)[-1].lower()
+        pictures = []  # (type, data) in file order
+        if fmt in _MP4_EXTS:
+            covers = dict.get(self, 'covr')
+            if isinstance(covers, (bytes, bytearray)):
+                return bytes(covers)
+            for c in covers or ():
+                return bytes(c['data'] if isinstance(c, dict) else c)
+            return None
+        if fmt == 'mp3':
+            for k in self._tag_keys:
+                if k == 'APIC' or k.startswith('APIC:'):
+                    value = dict.get(self, k)
+                    for frame in (value if isinstance(value, list) else [value]):
+                        if isinstance(frame, dict):
+                            pictures.append((frame.get('type'), frame.get('data')))
+        else:
+            for pic in self.pictures:
+                if isinstance(pic, dict):
+                    pictures.append((pic.get('type'), pic.get('data')))
+                else:
+                    pictures.append((pic.type, pic.data))
+            for k in self._tag_keys:
+                if k.lower() == 'metadata_block_picture':
+                    value = dict.get(self, k)
+                    for b64 in (value if isinstance(value, list) else [value]):
+                        try:
+                            pictures.append(_parse_picture_block(base64.b64decode(b64)))
+                        except (ValueError, struct.error):
+                            continue
+        for pic_type, data in pictures:
+            if pic_type == 3:
+                return bytes(data)
+        return bytes(pictures[0][1]) if pictures else None
+
     # ── ID3 container methods (matching mutagen.id3.ID3Tags) ──
 
     def getall(self, key):
@@ -329,7 +1053,11 @@ class _CachedFile(dict):
             'mp3': ['audio/mpeg', 'audio/mpg', 'audio/x-mpeg'],
             'flac': ['audio/flac', 'audio/x-flac'],
             'ogg': ['audio/ogg', 'audio/vorbis', 'application/ogg'],
+            'opus': ['audio/ogg', 'audio/ogg; codecs=opus'],
             'mp4': ['audio/mp4', 'audio/x-m4a', 'audio/mpeg4', 'audio/aac'],
+            'caf': ['audio/x-caf'],
+            'ape': ['audio/ape', 'audio/x-ape'],
+            'wavpack': ['audio/x-wavpack'],
         }
         return _mimes.get(self._format, [])
 
@@ -347,8 +1075,16 @@ class _FLACFile(_CachedFile):
     __slots__ = ()
 class _OggVorbisFile(_CachedFile):
     __slots__ = ()
+class _OggOpusFile(_CachedFile):
+    __slots__ = ()
 class _MP4File(_CachedFile):
     __slots__ = ()
+class _CAFFile(_CachedFile):
+    __slots__ = ()
+class _APEFile(_CachedFile):
+    __slots__ = ()
+class _WavPackFile(_CachedFile):
+    __slots__ = ()
 
 # Give them proper names for type().__name__
 _MP3File.__name__ = 'MP3'
@@ -357,14 +1093,26 @@ _FLACFile.__name__ = 'FLAC'
 _FLACFile.__qualname__ = 'FLAC'
 _OggVorbisFile.__name__ = 'OggVorbis'
 _OggVorbisFile.__qualname__ = 'OggVorbis'
+_OggOpusFile.__name__ = 'OggOpus'
+_OggOpusFile.__qualname__ = 'OggOpus'
 _MP4File.__name__ = 'MP4'
 _MP4File.__qualname__ = 'MP4'
+_CAFFile.__name__ = 'CAF'
+_CAFFile.__qualname__ = 'CAF'
+_APEFile.__name__ = 'MonkeysAudio'
+_APEFile.__qualname__ = 'MonkeysAudio'
+_WavPackFile.__name__ = 'WavPack'
+_WavPackFile.__qualname__ = 'WavPack'
 
 _FORMAT_CLASSES = {
     'mp3': _MP3File,
     'flac': _FLACFile,
     'ogg': _OggVorbisFile,
+    'opus': _OggOpusFile,
     'mp4': _MP4File,
+    'caf': _CAFFile,
+    'ape': _APEFile,
+    'wavpack': _WavPackFile,
 }
 
 
@@ -377,6 +1125,7 @@ def _make_cached(native, filename):
     w._pictures = []
     w._format = ''
     w._has_tags = True
+    w._tag_version = getattr(native, 'tag_version', None)
     tag_keys = native.keys()
     w._tag_keys = tag_keys
     for k in tag_keys:
@@ -388,7 +1137,7 @@ def _make_cached(native, filename):
 
 
 def _make_cached_fast(d, filename):
-    """Build a format-specific _CachedFile from a _fast_read dict."""
+    """Build a format-specific _CachedFile from a nested _fast_read dict."""
     fmt = d.get('_format', '')
     cls = _FORMAT_CLASSES.get(fmt, _CachedFile)
     w = cls.__new__(cls)
@@ -399,13 +1148,13 @@ def _make_cached_fast(d, filename):
     w._pictures = d.get('_pictures', [])
     w._format = fmt
     w._has_tags = d.get('_has_tags', True)
-    tag_keys = d.get('_keys', [])
-    w._tag_keys = tag_keys
+    w._tag_version = d.get('tag_version')
+    tags = d.get('tags', {})
+    w._tag_keys = list(tags)
     # ID3 formats (mp3) use _ID3Value so str() returns text, not "['text']"
     is_id3 = (fmt == 'mp3')
     is_mp4 = (fmt == 'mp4')
-    for k in tag_keys:
-        v = d[k]
+    for k, v in tags.items():
         if is_id3:
             w[k] = _ID3Value(v) if isinstance(v, list) else _ID3Value([v])
         elif is_mp4:
@@ -423,24 +1172,30 @@ def _wrap_mp4_value(key, value):
             return [MP4Cover(item) if isinstance(item, bytes) else item
                     for item in value]
         elif isinstance(value, bytes):
-            # Detect format from magic bytes
-            fmt = MP4Cover.FORMAT_PNG if value[:4] == b'\x89PNG' else MP4Cover.FORMAT_JPEG
-            return [MP4Cover(value, imageformat=fmt)]
+            # MP4Cover detects the format from the magic bytes
+            return [MP4Cover(value)]
         return [value]
     elif key.startswith('----:'):
-        # Freeform atoms: wrap in MP4FreeForm
-        if isinstance(value, list):
-            return [MP4FreeForm(item) if isinstance(item, (bytes, str)) else item
-                    for item in value]
-        elif isinstance(value, (bytes, str)):
-            v = value.encode('utf-8') if isinstance(value, str) else value
-            return [MP4FreeForm(v)]
-        return [value]
+        # Freeform atoms: wrap in MP4FreeForm, keeping each value's data type
+        items = value if isinstance(value, list) else [value]
+        return [_wrap_freeform(item) for item in items]
     else:
         # Standard MP4 tags: keep as list
         return value if isinstance(value, list) else [value]
 
 
+def _wrap_freeform(item):
+    """MP4FreeForm for a (data, dataformat) pair as read, or for bytes/str."""
+    if isinstance(item, tuple):
+        data, dataformat = item
+        return MP4FreeForm(data, dataformat=dataformat)
+    if isinstance(item, str):
+        return MP4FreeForm(item.encode('utf-8'))
+    if isinstance(item, bytes) and not isinstance(item, MP4FreeForm):
+        return MP4FreeForm(item)
+    return item
+
+
 # ──────────────────────────────────────────────────────────────
 # Format-specific factory functions
 # ──────────────────────────────────────────────────────────────
@@ -451,7 +1206,7 @@ def MP3(filename):
     if w is not None:
         return w
     try:
-        d = _fast_read(filename)
+        d = _fast_read(filename, nested=True)
     except (ValueError, OSError) as e:
         raise MutagenError(str(e)) from None
     w = _make_cached_fast(d, filename)
@@ -465,7 +1220,7 @@ def FLAC(filename):
     if w is not None:
         return w
     try:
-        d = _fast_read(filename)
+        d = _fast_read(filename, nested=True)
     except (ValueError, OSError) as e:
         raise MutagenError(str(e)) from None
     w = _make_cached_fast(d, filename)
@@ -479,7 +1234,21 @@ def OggVorbis(filename):
     if w is not None:
         return w
     try:
-        d = _fast_read(filename)
+        d = _fast_read(filename, nested=True)
+    except (ValueError, OSError) as e:
+        raise MutagenError(str(e)) from None
+    w = _make_cached_fast(d, filename)
+    _cache[filename] = w
+    return w
+
+
+def OggOpus(filename):
+    """Open an Ogg Opus file and return a file object with info and tags."""
+    w = _cache.get(filename)
+    if w is not None:
+        return w
+    try:
+        d = _fast_read(filename, nested=True)
     except (ValueError, OSError) as e:
         raise MutagenError(str(e)) from None
     w = _make_cached_fast(d, filename)
@@ -493,7 +1262,7 @@ def MP4(filename):
     if w is not None:
         return w
     try:
-        d = _fast_read(filename)
+        d = _fast_read(filename, nested=True)
     except (ValueError, OSError) as e:
         raise MutagenError(str(e)) from None
     w = _make_cached_fast(d, filename)
@@ -501,6 +1270,42 @@ def MP4(filename):
     return w
 
 
+def WavPack(filename):
+    """Open a WavPack file (read-only) and return a file object with info and APEv2 tags."""
+    w = _cache.get(filename)
+    if w is not None:
+        return w
+    try:
+        d = _fast_read(filename, nested=True)
+    except ValueError as e:
+        raise WavPackHeaderError(str(e)) from None
+    except OSError as e:
+        raise MutagenError(str(e)) from None
+    w = _make_cached_fast(d, filename)
+    _cache[filename] = w
+    return w
+
+
+def MonkeysAudio(filename):
+    """Open a Monkey's Audio file (read-only) and return a file object with info and APEv2 tags.
+
+    A header claiming more frames than the file can hold raises
+    MonkeysAudioTruncatedError.
+    """
+    w = _cache.get(filename)
+    if w is not None:
+        return w
+    try:
+        d = _fast_read(filename, nested=True)
+    except ValueError as e:
+        raise MonkeysAudioHeaderError(str(e)) from None
+    except OSError as e:
+        raise MutagenError(str(e)) from None
+    w = _make_cached_fast(d, filename)
+    _cache[filename] = w
+    return w
+
+
 # ──────────────────────────────────────────────────────────────
 # EasyID3 / EasyMP3 / EasyMP4
 # ──────────────────────────────────────────────────────────────
@@ -543,6 +1348,12 @@ _EASY_ID3_MAP = {
 _EASY_ID3_REVERSE = {v: k for k, v in _EASY_ID3_MAP.items()}
 
 
+def _format_pair(pair):
+    """Format an MP4 trkn/disk pair as "N/M" (or "N" when there is no total)."""
+    num, total = pair
+    return f"{num}/{total}" if total else str(num)
+
+
 class _EasyTagView(dict):
     """Dict-like view mapping human-readable keys to actual tag keys."""
 
@@ -559,8 +1370,11 @@ class _EasyTagView(dict):
                 # Normalize to list of strings
                 if isinstance(val, _ID3Value):
                     dict.__setitem__(self, easy_key, list(val))
+                elif isinstance(val, tuple):
+                    dict.__setitem__(self, easy_key, [_format_pair(val)])
                 elif isinstance(val, list):
-                    dict.__setitem__(self, easy_key, val)
+                    dict.__setitem__(self, easy_key,
+                                     [_format_pair(v) if isinstance(v, tuple) else v for v in val])
                 else:
                     dict.__setitem__(self, easy_key, [str(val)])
 
@@ -679,6 +1493,7 @@ class EasyMP3(_MP3File):
         self._pictures = inner._pictures
         self._format = inner._format
         self._has_tags = inner._has_tags
+        self._tag_version = inner.tag_version
         self._tag_keys = list(self._easy_tags.keys())
         for k, v in self._easy_tags.items():
             dict.__setitem__(self, k, v)
@@ -722,6 +1537,7 @@ class EasyMP4(_MP4File):
         self._pictures = inner._pictures
         self._format = inner._format
         self._has_tags = inner._has_tags
+        self._tag_version = inner.tag_version
         self._tag_keys = list(self._easy_tags.keys())
         for k, v in self._easy_tags.items():
             dict.__setitem__(self, k, v)
@@ -754,6 +1570,7 @@ _EASY_CONSTRUCTORS = {
     'mp3': EasyMP3,
     'flac': FLAC,  # FLAC/OGG use vorbis comments which are already "easy"
     'ogg': OggVorbis,
+    'opus': OggOpus,
     'mp4': EasyMP4,
 }
 
@@ -773,7 +1590,7 @@ def File(filename, easy=False):
         if w is not None:
             return w
     try:
-        d = _fast_read(filename)
+        d = _fast_read(filename, nested=True)
     except (MutagenError, ValueError, OSError):
         return None
     fmt = d.get('_format', '')
@@ -793,38 +1610,120 @@ def File(filename, easy=False):
 # batch_open with ID3Value wrapping
 # ──────────────────────────────────────────────────────────────
 
-def batch_open(filenames):
+def batch_open(filenames, easy=False, extra=False, io_depth=0, dedupe='lexical', editable=False):
     """Open multiple audio files in parallel using Rust I/O.
 
     Args:
         filenames: List of file paths to open.
+        easy: If True, tags use easy keys ('title', 'artist', 'tracknumber', ...)
+            regardless of container, and every value is a list of strings.
+        extra: With easy=True, keep tags that have no easy name in an 'extra'
+            sub-dict of 'tags' (native keys and values).
+        io_depth: Number of prefetch threads reading files ahead of the parser.
+            0 (default) reads inside the parser workers. Raise it (e.g. 32-64)
+            on network filesystems, where reads are latency bound.
+        dedupe: How paths naming the same file are detected, so it is read
+            and parsed once: 'lexical' (default; absolute path with '.' and
+            '..' folded, no filesystem access), 'realpath' (symlinks resolved),
+            'inode' (same device and inode) or 'none' (identical strings only).
+        editable: Return a BatchResult instead of a dict. Its set_tag() and
+            del_tag() change text tags (native keys) and save_all() writes
+            every changed file in parallel. Cannot be combined with easy.
 
     Returns:
         A dict mapping filepath -> result dict with 'tags', 'length',
-        'sample_rate', 'channels', etc.
+        'sample_rate', 'channels', etc. Every submitted spelling of a file is a
+        key, and all of them share one result dict.
     """
-    if filenames is _last_batch[0] and _last_batch[1] is not None:
-        return _last_batch[1]
-    result = _rust_batch_open(filenames)
-    # Wrap ID3 tag values in _ID3Value for MP3 files
+    if editable:
+        return _rust_batch_open(filenames, easy, extra, io_depth, dedupe, True)
+    if easy:
+        return _rust_batch_open(filenames, True, extra, io_depth, dedupe)
+    options = (io_depth, dedupe)
+    if filenames is _last_batch[0] and options == _last_batch[1] and _last_batch[2] is not None:
+        return _last_batch[2]
+    result = _rust_batch_open(filenames, io_depth=io_depth, dedupe=dedupe)
+    wrapped = set()
     for path, d in result.items():
-        tags = d.get('tags')
-        if tags and path.lower().endswith('.mp3'):
-            for k, v in tags.items():
-                if isinstance(v, list):
-                    tags[k] = _ID3Value(v)
-                else:
-                    tags[k] = _ID3Value([v])
+        if id(d) not in wrapped:
+            wrapped.add(id(d))
+            _wrap_batch_id3(path, d)
     _last_batch[0] = filenames
-    _last_batch[1] = result
+    _last_batch[1] = options
+    _last_batch[2] = result
     return result
 
 
+def batch_open_buffers(items, easy=False, extra=False):
+    """Parse files already in memory in parallel, without reading from disk.
+
+    Args:
+        items: List of (path, bytes) pairs. The path is only a hint: its
+            extension picks the format and it is the key of the result.
+        easy, extra: As in batch_open().
+
+    Returns:
+        A dict mapping path -> result dict, the same shape as batch_open().
+        Buffers that can't be parsed are left out.
+    """
+    result = _rust_batch_open_buffers(items, easy, extra)
+    if not easy:
+        wrapped = set()
+        for path, d in result.items():
+            if id(d) not in wrapped:
+                wrapped.add(id(d))
+                _wrap_batch_id3(path, d)
+    return result
+
+
+def batch_iter(filenames, threads=None, buffer=1024, ordered=False):
+    """Yield (path, result dict) pairs as parallel workers finish each file.
+
+    Args:
+        filenames: List of file paths to open.
+        threads: Worker thread count (default: the global rayon pool).
+        buffer: Maximum parsed results waiting to be consumed; workers pause
+            when it is full, so memory is bounded by this, not the file count.
+        ordered: Yield in input order instead of completion order. Results
+            that finish early are held until their turn.
+
+    Unreadable or unsupported files are skipped. Each dict has the same shape
+    as a batch_open() entry.
+    """
+    for path, d in _rust_batch_iter(filenames, threads, buffer, ordered):
+        _wrap_batch_id3(path, d)
+        yield path, d
+
+
+def _wrap_batch_id3(path, d):
+    """Wrap ID3 tag values in _ID3Value for MP3 files."""
+    tags = d.get('tags')
+    if tags and path.lower().endswith('.mp3'):
+        for k, v in tags.items():
+            if isinstance(v, list):
+                tags[k] = _ID3Value(v)
+            else:
+                tags[k] = _ID3Value([v])
+
+
+def copy_tags(src, dst):
+    """Copy the tags of ``src`` into ``dst`` across formats and save ``dst``.
+
+    Text fields with an easy name (title, artist, album, date, tracknumber,
+    genre, ...), comment, lyrics and the cover are written as ``dst``'s own
+    frames, atoms or comments. Fields its format has no key for are skipped;
+    its other tags are kept.
+    """
+    _rust_copy_tags(src, dst)
+    _cache.pop(dst, None)
+
+
 def clear_cache():
     """Clear the Python and Rust result caches."""
     _cache.clear()
     _last_batch[0] = None
     _last_batch[1] = None
+    _last_batch[2] = None
     _rust_clear_cache()
 
 
@@ -833,6 +1732,7 @@ def clear_all_caches():
     _cache.clear()
     _last_batch[0] = None
     _last_batch[1] = None
+    _last_batch[2] = None
     _rust_clear_all_caches()
 
 
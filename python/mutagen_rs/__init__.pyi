@@ -1,6 +1,9 @@
 """Type stubs for mutagen_rs."""
 
-from typing import Any, Optional, Sequence, overload
+import os
+from typing import IO, Any, Iterator, Literal, Optional, Sequence, Union, overload
+
+from mutagen_rs._id3frames import Frame
 
 __version__: str
 version: tuple[int, ...]
@@ -18,7 +21,9 @@ class PaddingInfo:
     def __init__(self, padding: int = -1, size: int = 0) -> None: ...
 
 class _InfoProxy:
-    length: float
+    length: Optional[float]
+    length_samples: Optional[int]
+    duration_ms: Optional[int]
     channels: int
     sample_rate: int
     bitrate: int
@@ -28,8 +33,15 @@ class _InfoProxy:
     mode: Optional[int]
     protected: Optional[bool]
     bitrate_mode: Optional[int]
+    is_vbr: Optional[bool]
+    is_cbr: Optional[bool]
+    is_abr: Optional[bool]
     total_samples: Optional[int]
     codec: Optional[str]
+    pre_skip: Optional[int]
+    input_sample_rate: Optional[int]
+    output_gain: Optional[float]
+    warnings: list[str]
     def pprint(self) -> str: ...
 
 class _CachedFile(dict[str, Any]):
@@ -38,18 +50,55 @@ class _CachedFile(dict[str, Any]):
     @property
     def tags(self) -> Optional[dict[str, Any]]: ...
     @property
+    def tag_version(self) -> Union[tuple[int, int], str, None]: ...
+    @property
     def pictures(self) -> list[dict[str, Any]]: ...
+    vendor: str
     def save(self, *args: Any, **kwargs: Any) -> None: ...
     def delete(self) -> None: ...
     def add_tags(self) -> None: ...
     def clear(self) -> None: ...
     def pprint(self) -> str: ...
     def keys(self) -> list[str]: ...
+    def to_json(self, indent: Optional[int] = None, include_pictures: bool = False) -> str: ...
+    def encoder_tool(self) -> Optional[str]: ...
+    def genre(self) -> Optional[str]: ...
+    def genres(self) -> list[str]: ...
+    def artists(self) -> list[str]: ...
+    def bpm(self) -> Optional[float]: ...
+    def cover(self) -> Optional[bytes]: ...
+    def lyrics(self) -> Optional[str]: ...
+    def credits(self) -> dict[str, Optional[str]]: ...
+    def itunes_normalization(self) -> Optional[dict[str, Any]]: ...
+    def replay_gain(self) -> dict[str, Optional[float]]: ...
+    def set_lyrics(self, text: str) -> None: ...
+    def get_raw(self, key: str) -> bytes: ...
+    def get_raw_atom(self, key: str) -> bytes: ...
+    def get_locales(self, key: str) -> list[int]: ...
+    def get_raw_block(self, index: int) -> bytes: ...
+    @property
+    def metadata_blocks(self) -> list[dict[str, Any]]: ...
+    def validate_layout(self) -> list[str]: ...
+    def set_raw(self, key: str, data: bytes) -> None: ...
+    def set_id3_bytes(self, data: bytes) -> None: ...
+    @property
+    def chapters(self) -> list[tuple[float, str]]: ...
+    def set_chapters(self, chapters: list[tuple[float, str]]) -> None: ...
+    @property
+    def moov_offset(self) -> int: ...
+    @property
+    def moov_size(self) -> int: ...
+    @property
+    def is_faststart(self) -> bool: ...
+    def strip(self, patterns: list[str]) -> list[str]: ...
 
 class _MP3File(_CachedFile): ...
 class _FLACFile(_CachedFile): ...
 class _OggVorbisFile(_CachedFile): ...
+class _OggOpusFile(_CachedFile): ...
 class _MP4File(_CachedFile): ...
+class _WavPackFile(_CachedFile): ...
+class _APEFile(_CachedFile): ...
 
 FileType = _CachedFile
 
@@ -91,26 +140,160 @@ class EasyMP4(_MP4File):
 def MP3(filename: str) -> _MP3File: ...
 def FLAC(filename: str) -> _FLACFile: ...
 def OggVorbis(filename: str) -> _OggVorbisFile: ...
+def OggOpus(filename: str) -> _OggOpusFile: ...
 def MP4(filename: str) -> _MP4File: ...
+def WavPack(filename: str) -> _WavPackFile: ...
+def MonkeysAudio(filename: str) -> _APEFile: ...
 def File(filename: str, easy: bool = False) -> Optional[_CachedFile]: ...
-def batch_open(filenames: list[str]) -> dict[str, dict[str, Any]]: ...
+class ReadOnlyFile:
+    info: Any
+    filename: str
+    @property
+    def tags(self) -> dict[str, Any]: ...
+    def keys(self) -> list[str]: ...
+    def get(self, key: str, default: Any = None) -> Any: ...
+    def __getitem__(self, key: str) -> Any: ...
+    def __contains__(self, key: object) -> bool: ...
+    def __len__(self) -> int: ...
+    def __iter__(self) -> Iterator[str]: ...
+def open_readonly(filename: str) -> ReadOnlyFile: ...
+@overload
+def batch_open(filenames: list[str], easy: bool = False, extra: bool = False, io_depth: int = 0, dedupe: Literal["lexical", "realpath", "inode", "none"] = "lexical", editable: Literal[False] = False) -> dict[str, dict[str, Any]]: ...
+@overload
+def batch_open(filenames: list[str], easy: Literal[False] = False, extra: bool = False, io_depth: int = 0, dedupe: Literal["lexical", "realpath", "inode", "none"] = "lexical", *, editable: Literal[True]) -> BatchResult: ...
+def batch_open_buffers(items: list[tuple[str, bytes]], easy: bool = False, extra: bool = False) -> dict[str, dict[str, Any]]: ...
+def batch_iter(filenames: list[str], threads: Optional[int] = None, buffer: int = 1024, ordered: bool = False) -> Iterator[tuple[str, dict[str, Any]]]: ...
 def clear_cache() -> None: ...
 def clear_all_caches() -> None: ...
-def _fast_read(filename: str) -> dict[str, Any]: ...
+def _cache_stats() -> dict[str, int]: ...
+def read_cached_bytes(filename: str) -> bytes: ...
+def _fast_read(filename: str, multi: bool = False, id3v1_encoding: Optional[str] = None, nested: bool = False, bare_ids: bool = False) -> dict[str, Any]: ...
+def _fast_read_json(filename: str, include_pictures: bool = False) -> bytes: ...
 def _fast_info(filename: str) -> dict[str, Any]: ...
-def _fast_read_seq(filenames: list[str]) -> list[dict[str, Any]]: ...
-def _fast_batch_read(filenames: list[str]) -> dict[str, dict[str, Any]]: ...
+def duration(filename: str) -> Optional[float]: ...
+def verify_extension(filename: str) -> Optional[str]: ...
+def copy_tags(src: str, dst: str) -> None: ...
+def mp4_parse_tail(tail: bytes, file_len: int) -> Optional[dict[str, Any]]: ...
+def _fast_read_seq(filenames: list[str], multi: bool = False, nested: bool = False, bare_ids: bool = False) -> list[dict[str, Any]]: ...
+def _fast_batch_read(filenames: list[str], easy: bool = False, extra: bool = False) -> dict[str, dict[str, Any]]: ...
+def _fast_batch_read_msgpack(filenames: list[str]) -> bytes: ...
 
 # Re-exported from Rust
 class MPEGInfo: ...
 class StreamInfo: ...
-class OggVorbisInfo: ...
+class OggVorbisInfo:
+    length: float
+    length_samples: int
+    duration_ms: int
+    channels: int
+    sample_rate: int
+    bitrate: int
+    serial: int
+    page_count: int
+    warnings: list[str]
+    is_vbr: bool
+    is_cbr: bool
+    is_abr: bool
+class OggOpusInfo:
+    length: float
+    length_samples: int
+    duration_ms: int
+    channels: int
+    sample_rate: int
+    input_sample_rate: int
+    pre_skip: int
+    output_gain: float
+    bitrate: int
+    serial: int
+    page_count: int
 class MP4Info: ...
-class ID3: ...
-class VComment: ...
-class MP4Tags: ...
-class BatchResult: ...
-def batch_diag(filenames: list[str]) -> dict[str, Any]: ...
+class WavPackInfo:
+    length: float
+    length_samples: int
+    duration_ms: int
+    sample_rate: int
+    channels: int
+    bits_per_sample: int
+    bitrate: int
+    version: int
+    def pprint(self) -> str: ...
+class MonkeysAudioInfo:
+    length: float
+    length_samples: int
+    duration_ms: int
+    sample_rate: int
+    channels: int
+    bits_per_sample: int
+    bitrate: int
+    version: float
+    warnings: list[str]
+    def pprint(self) -> str: ...
+class ID3:
+    size: int
+    truncated: bool
+    seek_offset: Optional[int]
+    id3v1_encoding: str
+    version: tuple[int, int]
+    def __init__(self, filename: Optional[str] = None, id3v1_encoding: str = "latin-1") -> None: ...
+    def save(self, filename: Optional[str] = None, v2_version: Optional[int] = None) -> None: ...
+    def update_to_v23(self) -> None: ...
+    def update_to_v24(self) -> None: ...
+    @staticmethod
+    def from_bytes(data: bytes) -> ID3: ...
+    def save_to_bytes(self) -> bytes: ...
+    def render(self) -> bytes: ...
+    def get_raw(self, key: str) -> bytes: ...
+    def set_raw(self, key: str, data: bytes) -> None: ...
+    def strip(self, patterns: list[str]) -> list[str]: ...
+    def set_lyrics(self, text: str, lang: str = "eng", desc: str = "") -> str: ...
+    def add(self, frame: Union[dict[str, Any], Frame]) -> str: ...
+    def setall(self, key: str, frames: list[Union[dict[str, Any], Frame]]) -> None: ...
+class VComment:
+    def strip(self, patterns: list[str]) -> list[str]: ...
+class MP4Tags:
+    def strip(self, patterns: list[str]) -> list[str]: ...
+class APEv2:
+    version: int
+    def __init__(self, filename: str) -> None: ...
+    def keys(self) -> list[str]: ...
+    def __getitem__(self, key: str) -> Union[list[str], bytes]: ...
+    def __contains__(self, key: str) -> bool: ...
+    def __len__(self) -> int: ...
+    def __iter__(self) -> Iterator[str]: ...
+    def kind(self, key: str) -> Literal["text", "binary", "external"]: ...
+class BatchIter:
+    def __iter__(self) -> BatchIter: ...
+    def __next__(self) -> tuple[str, dict[str, Any]]: ...
+class BatchResult:
+    def __len__(self) -> int: ...
+    def __contains__(self, path: str) -> bool: ...
+    def __getitem__(self, path: str) -> dict[str, Any]: ...
+    def keys(self) -> list[str]: ...
+    def items(self) -> list[tuple[str, dict[str, Any]]]: ...
+    def total_length(self) -> float: ...
+    def count_by_format(self) -> dict[str, int]: ...
+    def to_msgpack(self) -> bytes: ...
+    def set_tag(self, path: str, key: str, value: Union[str, list[str]]) -> None: ...
+    def del_tag(self, path: str, key: str) -> None: ...
+    def dirty(self) -> list[str]: ...
+    def save_all(self) -> int: ...
+class ArrowBatch:
+    column_names: list[str]
+    def __len__(self) -> int: ...
+    def __arrow_c_stream__(self, requested_schema: Optional[object] = None) -> object: ...
+def batch_open_arrow(filenames: list[str], columns: Optional[list[str]] = None) -> ArrowBatch: ...
+def batch_total_length(result: Union[dict[str, dict[str, Any]], BatchResult]) -> float: ...
+def batch_count_by_format(result: Union[dict[str, dict[str, Any]], BatchResult]) -> dict[str, int]: ...
+def has_tags(filename: str) -> bool: ...
+def tag_summary(filename: str) -> tuple[str, int, bool]: ...
+def batch_tag_summary(filenames: list[str]) -> dict[str, tuple[str, int, bool]]: ...
+def batch_diag(filenames: list[str], pretty: bool = False) -> Union[dict[str, Any], str]: ...
+def batch_export_ndjson(
+    filenames_or_dir: Union[str, os.PathLike[str], list[str]],
+    out: Union[str, os.PathLike[str], IO[Any]],
+    threads: Optional[int] = None,
+    easy: bool = False,
+) -> dict[str, Any]: ...
 
 # Errors
 class MutagenError(Exception): ...
@@ -122,6 +305,12 @@ class FLACError(MutagenError): ...
 class FLACNoHeaderError(FLACError): ...
 class OggError(MutagenError): ...
 class MP4Error(MutagenError): ...
+class CAFError(MutagenError): ...
+class MonkeysAudioHeaderError(MutagenError): ...
+class MonkeysAudioTruncatedError(MonkeysAudioHeaderError): ...
+class WavPackHeaderError(MutagenError): ...
+class APEError(MutagenError): ...
+class APENoHeaderError(APEError): ...
 
 Tags = dict
 Metadata = dict
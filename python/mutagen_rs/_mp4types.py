@@ -18,14 +18,18 @@ AtomDataType = _make_int_enum('AtomDataType', [
 class MP4Cover(bytes):
     """MP4 cover art, a bytes subclass with imageformat attribute.
 
-    Compatible with mutagen.mp4.MP4Cover.
+    Compatible with mutagen.mp4.MP4Cover. Without an ``imageformat`` the
+    format is taken from the data's magic bytes: PNG, else JPEG. Assign a
+    list of covers to ``covr`` to write them.
     """
     FORMAT_JPEG = AtomDataType.JPEG
     FORMAT_PNG = AtomDataType.PNG
 
     def __new__(cls, data=b'', imageformat=None):
         obj = bytes.__new__(cls, data)
-        obj.imageformat = imageformat if imageformat is not None else AtomDataType.JPEG
+        if imageformat is None:
+            imageformat = AtomDataType.PNG if obj[:4] == b'\x89PNG' else AtomDataType.JPEG
+        obj.imageformat = imageformat
         return obj
 
     def __repr__(self):
@@ -46,5 +50,11 @@ class MP4FreeForm(bytes):
         obj.version = version
         return obj
 
+    def __str__(self):
+        # Text values read as their string; binary data keeps the bytes repr
+        if self.dataformat == AtomDataType.UTF8:
+            return self.decode('utf-8', 'replace')
+        return bytes.__repr__(self)
+
     def __repr__(self):
         return f'MP4FreeForm({bytes.__repr__(self)[:50]}..., dataformat={self.dataformat!r})'
@@ -0,0 +1,152 @@
+use crate::common::error::{MutagenError, Result};
+use crate::common::util::{duration_ms, read_bits};
+
+/// Sample rates by `fscod`; E-AC-3 uses `fscod2` for the halved rates.
+const SAMPLE_RATES: [u32; 3] = [48000, 44100, 32000];
+const REDUCED_SAMPLE_RATES: [u32; 3] = [24000, 22050, 16000];
+
+/// Nominal AC-3 bit rates in kbps by `frmsizecod >> 1`.
+const BITRATES: [u32; 19] = [
+    32, 40, 48, 56, 64, 80, 96, 112, 128, 160, 192, 224, 256, 320, 384, 448, 512, 576, 640,
+];
+
+/// Full-bandwidth channels by audio coding mode (`acmod`); 1+1 dual mono counts as two.
+const ACMOD_CHANNELS: [u32; 8] = [2, 1, 2, 3, 3, 4, 4, 5];
+
+/// How far into the stream to look for the first sync frame.
+const SYNC_SEARCH: usize = 64 * 1024;
+
+/// Audio information from the first sync frame of a raw AC-3 or E-AC-3 stream.
+/// Streams are treated as constant bit rate, so the length is the stream
+/// size divided by the bit rate.
+#[derive(Debug, Clone, Default)]
+pub struct AC3Info {
+    pub length: f64,
+    pub length_samples: u64,
+    /// Stream size × 8 / bitrate in whole milliseconds.
+    pub duration_ms: u64,
+    pub sample_rate: u32,
+    /// Including the LFE channel.
+    pub channels: u32,
+    pub bitrate: u32,
+    /// `ac-3` or `ec-3`, as in the MP4 sample entry names.
+    pub codec: String,
+}
+
+/// A raw AC-3 / E-AC-3 elementary stream (`.ac3`, `.eac3`). Carries no tags.
+#[derive(Debug, Clone, Default)]
+pub struct AC3File {
+    pub info: AC3Info,
+    pub path: String,
+}
+
+impl AC3File {
+    /// Open and parse an AC-3 file.
+    pub fn open(path: &str) -> Result<Self> {
+        let data = std::fs::read(path)?;
+        Self::parse(&data, path)
+    }
+
+    /// Parse the first sync frame; `data.len()` is taken as the stream size.
+    pub fn parse(data: &[u8], path: &str) -> Result<Self> {
+        let start = find_sync(data).ok_or_else(|| MutagenError::InvalidData("No AC-3 sync frame".into()))?;
+        let frame = &data[start..];
+        if frame.len() < 8 {
+            return Err(MutagenError::InvalidData("AC-3 sync frame too short".into()));
+        }
+        let bsid = frame[5] >> 3;
+        let (sample_rate, channels, bitrate, codec) = if bsid <= 10 {
+            parse_ac3(frame)?
+        } else if bsid <= 16 {
+            parse_eac3(frame)?
+        } else {
+            return Err(MutagenError::InvalidData(format!("Unknown AC-3 bsid {}", bsid)));
+        };
+
+        let stream_bytes = (data.len() - start) as f64;
+        let length = if bitrate > 0 { stream_bytes * 8.0 / bitrate as f64 } else { 0.0 };
+        let duration = duration_ms((data.len() - start) as u64 * 8, bitrate as u64);
+        Ok(AC3File {
+            info: AC3Info {
+                length,
+                length_samples: (length * sample_rate as f64).round() as u64,
+                duration_ms: duration,
+                sample_rate,
+                channels,
+                bitrate,
+                codec: codec.to_string(),
+            },
+            path: path.to_string(),
+        })
+    }
+
+    /// Score for auto-detection.
+    pub fn score(path: &str, data: &[u8]) -> u32 {
+        let mut score = 0u32;
+
+        let ext = path.rsplit('.').next().unwrap_or("");
+        if ext.eq_ignore_ascii_case("ac3") || ext.eq_ignore_ascii_case("eac3") {
+            score += 2;
+        }
+
+        if data.len() >= 2 && data[0] == 0x0B && data[1] == 0x77 {
+            score += 1;
+        }
+
+        score
+    }
+}
+
+/// Offset of the first AC-3 syncword (0x0B77) in the search window.
+fn find_sync(data: &[u8]) -> Option<usize> {
+    let window = &data[..data.len().min(SYNC_SEARCH)];
+    window.windows(2).position(|w| w == [0x0B, 0x77])
+}
+
+/// AC-3 (bsid <= 10): `fscod`/`frmsizecod` at byte 4, then `bsid`/`bsmod`,
+/// then `acmod` and the optional mix levels ahead of `lfeon`.
+fn parse_ac3(frame: &[u8]) -> Result<(u32, u32, u32, &'static str)> {
+    let fscod = (frame[4] >> 6) as usize;
+    let frmsizecod = (frame[4] & 0x3F) as usize;
+    if fscod >= 3 || frmsizecod >> 1 >= BITRATES.len() {
+        return Err(MutagenError::InvalidData("Invalid AC-3 sync frame".into()));
+    }
+    let acmod = read_bits(frame, 48, 3);
+    let mut bit = 51;
+    if acmod & 1 != 0 && acmod != 1 {
+        bit += 2; // cmixlev
+    }
+    if acmod & 4 != 0 {
+        bit += 2; // surmixlev
+    }
+    if acmod == 2 {
+        bit += 2; // dsurmod
+    }
+    let lfeon = read_bits(frame, bit, 1);
+    Ok((
+        SAMPLE_RATES[fscod],
+        ACMOD_CHANNELS[acmod as usize] + lfeon,
+        BITRATES[frmsizecod >> 1] * 1000,
+        "ac-3",
+    ))
+}
+
+/// E-AC-3 (bsid 11-16): the frame size comes first, so the bit rate follows
+/// from it and the number of audio blocks per frame.
+fn parse_eac3(frame: &[u8]) -> Result<(u32, u32, u32, &'static str)> {
+    let frame_bytes = (read_bits(frame, 21, 11) + 1) * 2;
+    let fscod = read_bits(frame, 32, 2) as usize;
+    let (sample_rate, blocks) = if fscod == 3 {
+        let fscod2 = read_bits(frame, 34, 2) as usize;
+        if fscod2 == 3 {
+            return Err(MutagenError::InvalidData("Invalid E-AC-3 sample rate".into()));
+        }
+        (REDUCED_SAMPLE_RATES[fscod2], 6)
+    } else {
+        (SAMPLE_RATES[fscod], [1, 2, 3, 6][read_bits(frame, 34, 2) as usize])
+    };
+    let acmod = read_bits(frame, 36, 3);
+    let lfeon = read_bits(frame, 39, 1);
+    let bitrate = (frame_bytes as u64 * 8 * sample_rate as u64 / (blocks * 256)) as u32;
+    Ok((sample_rate, ACMOD_CHANNELS[acmod as usize] + lfeon, bitrate, "ec-3"))
+}
@@ -0,0 +1,190 @@
+//! Read-only APEv2 tag reader, shared by the formats that carry APEv2 tags at
+//! the end of the file (Monkey's Audio, and in the wild MP3, WavPack, ...).
+
+/// Size of the APEv2 header and footer.
+const FOOTER_SIZE: usize = 32;
+
+/// Item flags bit 0: the item is marked read-only.
+pub const ITEM_READ_ONLY: u32 = 1;
+
+/// What an item's value holds, from bits 1-2 of its flags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum APEValueKind {
+    /// UTF-8 text; several values are separated by NUL.
+    Text,
+    Binary,
+    /// UTF-8 link to the data.
+    External,
+}
+
+/// One APEv2 item. `flags` is kept whole so a writer can round-trip it.
+#[derive(Debug, Clone)]
+pub struct APEItem {
+    pub key: String,
+    pub flags: u32,
+    pub value: Vec<u8>,
+}
+
+impl APEItem {
+    pub fn kind(&self) -> APEValueKind {
+        match (self.flags >> 1) & 3 {
+            1 => APEValueKind::Binary,
+            2 => APEValueKind::External,
+            // 3 is reserved; mutagen reads it as text too
+            _ => APEValueKind::Text,
+        }
+    }
+
+    /// Text values, split on NUL. `None` for binary items.
+    pub fn text(&self) -> Option<Vec<String>> {
+        if self.kind() == APEValueKind::Binary {
+            return None;
+        }
+        Some(String::from_utf8_lossy(&self.value).split('\0').map(str::to_string).collect())
+    }
+}
+
+/// A parsed APEv2 (or APEv1) tag.
+#[derive(Debug, Clone, Default)]
+pub struct APEv2Tag {
+    /// 1000 for APEv1, 2000 for APEv2.
+    pub version: u32,
+    pub items: Vec<APEItem>,
+    /// Offset of the tag in the file, header included when there is one.
+    pub offset: usize,
+    /// Tag size, header and footer included.
+    pub size: usize,
+}
+
+impl APEv2Tag {
+    /// First item with `key`, compared case-insensitively as APEv2 keys are.
+    pub fn get(&self, key: &str) -> Option<&APEItem> {
+        self.items.iter().find(|item| item.key.eq_ignore_ascii_case(key))
+    }
+}
+
+/// Where an APEv2 tag at the end of `data` has to end: before any ID3v1 tag,
+/// Lyrics3v2 tag or appended ID3v2 tag (one with a `3DI` footer), in any order.
+/// Each gives its size in its last bytes.
+pub fn tag_end(data: &[u8]) -> usize {
+    let mut end = data.len();
+    loop {
+        let tail = &data[..end];
+        let skip = if end >= 128 && &tail[end - 128..end - 125] == b"TAG" {
+            128
+        } else if end >= 15 && &tail[end - 9..] == b"LYRICS200" {
+            let digits = std::str::from_utf8(&tail[end - 15..end - 9]).ok();
+            match digits.and_then(|d| d.parse::<usize>().ok()) {
+                Some(size) => size + 15,
+                None => break,
+            }
+        } else if end >= 10 && &tail[end - 10..end - 7] == b"3DI" {
+            let size = tail[end - 4..].iter().fold(0usize, |n, &b| (n << 7) | (b & 0x7F) as usize);
+            size + 20
+        } else {
+            break;
+        };
+        if skip > end {
+            break;
+        }
+        end -= skip;
+    }
+    end
+}
+
+/// Offset of the APEv2 footer at the end of `data` (see `tag_end`).
+pub fn find_footer(data: &[u8]) -> Option<usize> {
+    let start = tag_end(data).checked_sub(FOOTER_SIZE)?;
+    (&data[start..start + 8] == b"APETAGEX").then_some(start)
+}
+
+/// Parse the APEv2 tag at the end of `data`, if any. Items are read until the
+/// count in the footer or the first malformed one.
+pub fn parse(data: &[u8]) -> Option<APEv2Tag> {
+    let footer_at = find_footer(data)?;
+    let footer = &data[footer_at..footer_at + FOOTER_SIZE];
+    let u32_at = |o: usize| u32::from_le_bytes(footer[o..o + 4].try_into().unwrap());
+    let version = u32_at(8);
+    // Tag size counts the items and the footer, never the header
+    let tag_size = u32_at(12) as usize;
+    let count = u32_at(16) as usize;
+    let has_header = u32_at(20) & (1 << 31) != 0;
+    if tag_size < FOOTER_SIZE || tag_size > footer_at + FOOTER_SIZE {
+        return None;
+    }
+    let items_at = footer_at + FOOTER_SIZE - tag_size;
+    let body = &data[items_at..footer_at];
+
+    let mut items = Vec::with_capacity(count.min(64));
+    let mut pos = 0;
+    for _ in 0..count {
+        if pos + 8 > body.len() {
+            break;
+        }
+        let size = u32::from_le_bytes(body[pos..pos + 4].try_into().unwrap()) as usize;
+        let flags = u32::from_le_bytes(body[pos + 4..pos + 8].try_into().unwrap());
+        pos += 8;
+        let Some(nul) = memchr::memchr(0, &body[pos..]) else { break };
+        let key = &body[pos..pos + nul];
+        pos += nul + 1;
+        // Keys are 2-255 printable ASCII characters
+        if key.len() < 2 || key.len() > 255 || !key.iter().all(|&b| (0x20..0x7F).contains(&b)) {
+            break;
+        }
+        if size > body.len() - pos {
+            break;
+        }
+        items.push(APEItem {
+            key: String::from_utf8_lossy(key).into_owned(),
+            flags,
+            value: body[pos..pos + size].to_vec(),
+        });
+        pos += size;
+    }
+
+    let offset = if has_header { items_at.saturating_sub(FOOTER_SIZE) } else { items_at };
+    Some(APEv2Tag { version, items, offset, size: footer_at + FOOTER_SIZE - offset })
+}
+
+/// Standard APEv2 keys and the ID3 frame holding the same field.
+const ID3_FRAMES: &[(&str, &str)] = &[
+    ("Title", "TIT2"),
+    ("Subtitle", "TIT3"),
+    ("Artist", "TPE1"),
+    ("Album", "TALB"),
+    ("Album Artist", "TPE2"),
+    ("AlbumArtist", "TPE2"),
+    ("Conductor", "TPE3"),
+    ("Composer", "TCOM"),
+    ("Year", "TDRC"),
+    ("Track", "TRCK"),
+    ("Disc", "TPOS"),
+    ("Genre", "TCON"),
+    ("Publisher", "TPUB"),
+    ("Copyright", "TCOP"),
+    ("ISRC", "TSRC"),
+    ("BPM", "TBPM"),
+    ("Language", "TLAN"),
+    ("Mood", "TMOO"),
+    ("Comment", "COMM"),
+    ("Lyrics", "USLT"),
+    ("Cover Art (Front)", "APIC"),
+];
+
+/// ID3 frame ID for a standard APEv2 `key` (compared case-insensitively).
+pub fn id3_frame_for(key: &str) -> Option<&'static str> {
+    ID3_FRAMES.iter().find(|(k, _)| k.eq_ignore_ascii_case(key)).map(|&(_, id)| id)
+}
+
+/// Items of `tag` that no ID3 frame in `id3_keys` (hash keys such as `TIT2`,
+/// `COMM::eng`, `TXXX:MOOD`) already covers: a standard key is covered by its
+/// frame under any description, any other key by `TXXX:<key>`.
+pub fn uncovered_by_id3<'a, S: AsRef<str>>(tag: &'a APEv2Tag, id3_keys: &[S]) -> Vec<&'a APEItem> {
+    tag.items.iter().filter(|item| {
+        let covered = |key: &str| match id3_frame_for(&item.key) {
+            Some(id) => key == id || key.strip_prefix(id).is_some_and(|rest| rest.starts_with(':')),
+            None => key.strip_prefix("TXXX:").is_some_and(|desc| desc.eq_ignore_ascii_case(&item.key)),
+        };
+        !id3_keys.iter().any(|k| covered(k.as_ref()))
+    }).collect()
+}
@@ -0,0 +1,155 @@
+pub mod apev2;
+
+use crate::common::error::{MutagenError, Result};
+use crate::common::util::{
+    check_header_value, duration_ms, BITS_PER_SAMPLE_RANGE, CHANNELS_RANGE, SAMPLE_RATE_RANGE,
+};
+
+/// Stream information from the Monkey's Audio header.
+#[derive(Debug, Clone, Default)]
+pub struct APEInfo {
+    pub length: f64,
+    pub length_samples: u64,
+    pub duration_ms: u64,
+    pub sample_rate: u32,
+    pub channels: u32,
+    pub bits_per_sample: u32,
+    /// File size × 8 / length.
+    pub bitrate: u32,
+    /// Encoder version, e.g. 3.99.
+    pub version: f64,
+    /// Header values that were out of range and read as 0 (see `check_header_value`).
+    pub warnings: Vec<String>,
+}
+
+/// A parsed Monkey's Audio file (read-only), with its APEv2 tag if any.
+#[derive(Debug, Clone, Default)]
+pub struct APEFile {
+    pub info: APEInfo,
+    pub tags: Option<apev2::APEv2Tag>,
+    pub path: String,
+}
+
+impl APEFile {
+    /// Open and parse a Monkey's Audio file.
+    pub fn open(path: &str) -> Result<Self> {
+        let data = std::fs::read(path)?;
+        Self::parse(&data, path)
+    }
+
+    /// Parse the `MAC ` (or floating-point `MACF`) header and the trailing APEv2 tag.
+    ///
+    /// From 3.98 on the header follows a descriptor whose size is at offset 8;
+    /// older files have a single header with the WAV header right behind it.
+    /// A frame count whose seek table (4 bytes per frame) can't fit in the
+    /// file is an error rather than a bogus length.
+    pub fn parse(data: &[u8], path: &str) -> Result<Self> {
+        if data.len() < 32 || !is_mac_magic(&data[0..4]) {
+            return Err(MutagenError::MonkeysAudio("Not a Monkey's Audio file".into()));
+        }
+        let u16_at = |o: usize| u16::from_le_bytes([data[o], data[o + 1]]) as u32;
+        let u32_at = |o: usize| u32::from_le_bytes(data[o..o + 4].try_into().unwrap());
+        let version = u16_at(4);
+
+        let (blocks_per_frame, final_frame_blocks, total_frames, bits_per_sample, channels, sample_rate);
+        let seek_table_at;
+        if version >= 3980 {
+            let h = u32_at(8) as usize;
+            if h.checked_add(24).is_none_or(|end| end > data.len()) {
+                return Err(MutagenError::MonkeysAudio("Header too short".into()));
+            }
+            seek_table_at = h as u64 + u32_at(12) as u64;
+            blocks_per_frame = u32_at(h + 4) as u64;
+            final_frame_blocks = u32_at(h + 8) as u64;
+            total_frames = u32_at(h + 12) as u64;
+            bits_per_sample = u16_at(h + 16);
+            channels = u16_at(h + 18);
+            sample_rate = u32_at(h + 20);
+        } else {
+            let compression = u16_at(6);
+            let format_flags = u16_at(8);
+            channels = u16_at(10);
+            sample_rate = u32_at(12);
+            total_frames = u32_at(24) as u64;
+            final_frame_blocks = u32_at(28) as u64;
+            // The stored WAV header sits before the seek table unless the
+            // decoder is to create one
+            seek_table_at = 32 + if format_flags & 32 == 0 { u32_at(16) as u64 } else { 0 };
+            blocks_per_frame = if version >= 3950 {
+                73728 * 4
+            } else if version >= 3900 || (version >= 3800 && compression == 4000) {
+                73728
+            } else {
+                9216
+            };
+            // Bits from the stored WAV header, else from the format flags
+            bits_per_sample = if data.len() >= 76 && data[48..].starts_with(b"WAVEfmt") {
+                u16_at(74)
+            } else if format_flags & 1 != 0 {
+                8
+            } else if format_flags & 8 != 0 {
+                24
+            } else {
+                16
+            };
+        }
+
+        if seek_table_at + total_frames * 4 > data.len() as u64 {
+            return Err(MutagenError::MonkeysAudioTruncated(format!(
+                "Header claims {} frames, more than a {}-byte file can hold",
+                total_frames,
+                data.len()
+            )));
+        }
+
+        let mut warnings = Vec::new();
+        let sample_rate = check_header_value("sample_rate", sample_rate, SAMPLE_RATE_RANGE, &mut warnings);
+        let channels = check_header_value("channels", channels, CHANNELS_RANGE, &mut warnings);
+        let bits_per_sample =
+            check_header_value("bits_per_sample", bits_per_sample, BITS_PER_SAMPLE_RANGE, &mut warnings);
+
+        let length_samples = match total_frames {
+            0 => 0,
+            n => (n - 1) * blocks_per_frame + final_frame_blocks,
+        };
+        let length = if sample_rate > 0 { length_samples as f64 / sample_rate as f64 } else { 0.0 };
+        let bitrate = if length > 0.0 { (data.len() as f64 * 8.0 / length) as u32 } else { 0 };
+
+        Ok(APEFile {
+            info: APEInfo {
+                length,
+                length_samples,
+                duration_ms: duration_ms(length_samples, sample_rate as u64),
+                sample_rate,
+                channels,
+                bits_per_sample,
+                bitrate,
+                version: version as f64 / 1000.0,
+                warnings,
+            },
+            tags: apev2::parse(data),
+            path: path.to_string(),
+        })
+    }
+
+    /// Score for auto-detection.
+    pub fn score(path: &str, data: &[u8]) -> u32 {
+        let mut score = 0u32;
+
+        let ext = path.rsplit('.').next().unwrap_or("");
+        if ext.eq_ignore_ascii_case("ape") {
+            score += 2;
+        }
+
+        if data.len() >= 4 && is_mac_magic(&data[0..4]) {
+            score += 3;
+        }
+
+        score
+    }
+}
+
+/// `MAC ` for integer audio, `MACF` for the floating-point variant.
+fn is_mac_magic(magic: &[u8]) -> bool {
+    magic == b"MAC " || magic == b"MACF"
+}
@@ -0,0 +1,238 @@
+use crate::common::error::{MutagenError, Result};
+use crate::common::util::{
+    check_header_value, duration_ms, BITS_PER_SAMPLE_RANGE, CHANNELS_RANGE, SAMPLE_RATE_RANGE,
+};
+
+/// Standard `info` chunk keys mapped to the Vorbis-style names used for the
+/// other formats. Keys not listed are kept, lowercased.
+const INFO_KEYS: &[(&str, &str)] = &[
+    ("title", "title"),
+    ("artist", "artist"),
+    ("album", "album"),
+    ("genre", "genre"),
+    ("composer", "composer"),
+    ("lyricist", "lyricist"),
+    ("copyright", "copyright"),
+    ("comments", "comment"),
+    ("track number", "tracknumber"),
+    ("year", "date"),
+    ("recorded date", "date"),
+    ("tempo", "bpm"),
+    ("key signature", "key"),
+    ("time signature", "timesignature"),
+    ("encoding application", "encoder"),
+];
+
+/// Normalized tag key for a CAF `info` key.
+pub fn normalize_key(key: &str) -> String {
+    let lower = key.to_ascii_lowercase();
+    match INFO_KEYS.iter().find(|(k, _)| *k == lower) {
+        Some((_, name)) => (*name).to_string(),
+        None => lower,
+    }
+}
+
+/// Audio information from the `desc` chunk, with the duration taken from
+/// `pakt` (compressed formats) or the `data` chunk size (constant bit rate).
+#[derive(Debug, Clone, Default)]
+pub struct CAFInfo {
+    pub length: f64,
+    /// Valid frames from `pakt`, else derived from the packet count or data size.
+    pub length_samples: u64,
+    pub duration_ms: u64,
+    pub sample_rate: u32,
+    pub channels: u32,
+    pub bits_per_sample: u32,
+    pub bitrate: u32,
+    /// Four-character format ID, e.g. `lpcm`, `aac ` or `alac`.
+    pub codec: String,
+    pub frames_per_packet: u32,
+    pub bytes_per_packet: u32,
+    /// Header values that were out of range and read as 0 (see `check_header_value`).
+    pub warnings: Vec<String>,
+}
+
+/// A parsed Core Audio Format file (read-only).
+#[derive(Debug, Clone, Default)]
+pub struct CAFFile {
+    pub info: CAFInfo,
+    /// `info` chunk entries in file order, keys normalized (see `normalize_key`).
+    pub tags: Vec<(String, String)>,
+    pub path: String,
+}
+
+/// `desc` chunk fields needed for the duration.
+struct Desc {
+    sample_rate: f64,
+    format_id: [u8; 4],
+    bytes_per_packet: u32,
+    frames_per_packet: u32,
+    channels: u32,
+    bits_per_channel: u32,
+}
+
+impl CAFFile {
+    /// Open and parse a CAF file.
+    pub fn open(path: &str) -> Result<Self> {
+        let data = std::fs::read(path)?;
+        Self::parse(&data, path)
+    }
+
+    /// Parse a CAF file from its bytes.
+    pub fn parse(data: &[u8], path: &str) -> Result<Self> {
+        if data.len() < 8 || &data[0..4] != b"caff" {
+            return Err(MutagenError::CAF("Not a CAF file".into()));
+        }
+
+        let mut desc: Option<Desc> = None;
+        let mut tags = Vec::new();
+        let mut packets: Option<(u64, u64)> = None;
+        let mut audio_size: Option<u64> = None;
+
+        let mut pos = 8usize;
+        while pos + 12 <= data.len() {
+            let kind = &data[pos..pos + 4];
+            let raw_size = i64::from_be_bytes(data[pos + 4..pos + 12].try_into().unwrap());
+            let body_start = pos + 12;
+            let remaining = (data.len() - body_start) as u64;
+            // Only the data chunk may have size -1 ("runs to the end of the file")
+            let size = if raw_size < 0 {
+                if kind != b"data" {
+                    return Err(MutagenError::CAF("Invalid chunk size".into()));
+                }
+                remaining
+            } else {
+                raw_size as u64
+            };
+            let body = &data[body_start..body_start + size.min(remaining) as usize];
+
+            match kind {
+                b"desc" => {
+                    if body.len() < 32 {
+                        return Err(MutagenError::CAF("desc chunk too short".into()));
+                    }
+                    let u32_at = |o: usize| u32::from_be_bytes(body[o..o + 4].try_into().unwrap());
+                    desc = Some(Desc {
+                        sample_rate: f64::from_be_bytes(body[0..8].try_into().unwrap()),
+                        format_id: body[8..12].try_into().unwrap(),
+                        bytes_per_packet: u32_at(16),
+                        frames_per_packet: u32_at(20),
+                        channels: u32_at(24),
+                        bits_per_channel: u32_at(28),
+                    });
+                }
+                b"info" => tags = parse_info(body),
+                b"pakt" if body.len() >= 16 => {
+                    let n = i64::from_be_bytes(body[0..8].try_into().unwrap());
+                    let valid = i64::from_be_bytes(body[8..16].try_into().unwrap());
+                    packets = Some((n.max(0) as u64, valid.max(0) as u64));
+                }
+                // The edit count precedes the audio
+                b"data" => audio_size = Some(size.min(remaining).saturating_sub(4)),
+                _ => {}
+            }
+
+            if raw_size < 0 {
+                break;
+            }
+            pos = match body_start.checked_add(size as usize) {
+                Some(next) if size <= remaining => next,
+                _ => break,
+            };
+        }
+
+        let desc = desc.ok_or_else(|| MutagenError::CAF("No desc chunk".into()))?;
+        let mut warnings = Vec::new();
+        let sample_rate = if desc.sample_rate.is_finite() && desc.sample_rate > 0.0 {
+            desc.sample_rate
+        } else {
+            0.0
+        };
+        // Range-check the whole-hertz rate, keeping any fraction when it passes
+        let sample_rate = match check_header_value("sample_rate", sample_rate as u32, SAMPLE_RATE_RANGE, &mut warnings) {
+            0 => 0.0,
+            _ => sample_rate,
+        };
+        let channels = check_header_value("channels", desc.channels, CHANNELS_RANGE, &mut warnings);
+        let bits_per_sample =
+            check_header_value("bits_per_sample", desc.bits_per_channel, BITS_PER_SAMPLE_RANGE, &mut warnings);
+
+        // Valid frames from the packet table when recorded, otherwise packets
+        // times the constant frames per packet, otherwise the PCM data size.
+        let frames = match packets {
+            Some((_, valid)) if valid > 0 => valid,
+            Some((n, _)) if desc.frames_per_packet > 0 => n.saturating_mul(desc.frames_per_packet as u64),
+            _ => match audio_size {
+                Some(bytes) if desc.bytes_per_packet > 0 => {
+                    bytes / desc.bytes_per_packet as u64 * desc.frames_per_packet.max(1) as u64
+                }
+                _ => 0,
+            },
+        };
+        let length = if sample_rate > 0.0 { frames as f64 / sample_rate } else { 0.0 };
+
+        let bitrate = if &desc.format_id == b"lpcm" {
+            (sample_rate * channels as f64 * bits_per_sample as f64) as u32
+        } else if length > 0.0 {
+            (audio_size.unwrap_or(0) as f64 * 8.0 / length) as u32
+        } else {
+            0
+        };
+
+        Ok(CAFFile {
+            info: CAFInfo {
+                length,
+                length_samples: frames,
+                duration_ms: duration_ms(frames, sample_rate as u64),
+                sample_rate: sample_rate as u32,
+                channels,
+                bits_per_sample,
+                bitrate,
+                codec: String::from_utf8_lossy(&desc.format_id).into_owned(),
+                frames_per_packet: desc.frames_per_packet,
+                bytes_per_packet: desc.bytes_per_packet,
+                warnings,
+            },
+            tags,
+            path: path.to_string(),
+        })
+    }
+
+    /// Score for auto-detection.
+    pub fn score(path: &str, data: &[u8]) -> u32 {
+        let mut score = 0u32;
+
+        let ext = path.rsplit('.').next().unwrap_or("");
+        if ext.eq_ignore_ascii_case("caf") {
+            score += 2;
+        }
+
+        if data.len() >= 4 && &data[0..4] == b"caff" {
+            score += 3;
+        }
+
+        score
+    }
+}
+
+/// Parse an `info` chunk: an entry count, then that many pairs of
+/// NUL-terminated UTF-8 key and value strings.
+pub fn parse_info(body: &[u8]) -> Vec<(String, String)> {
+    if body.len() < 4 {
+        return Vec::new();
+    }
+    let count = u32::from_be_bytes([body[0], body[1], body[2], body[3]]) as usize;
+    let mut strings = body[4..].split(|&b| b == 0);
+    let mut tags = Vec::with_capacity(count.min(64));
+    for _ in 0..count {
+        let (Some(key), Some(value)) = (strings.next(), strings.next()) else { break };
+        if key.is_empty() {
+            continue;
+        }
+        tags.push((
+            normalize_key(&String::from_utf8_lossy(key)),
+            String::from_utf8_lossy(value).into_owned(),
+        ));
+    }
+    tags
+}
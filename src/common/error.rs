@@ -47,6 +47,21 @@ pub enum MutagenError {
     #[error("MP4 stream info error: {0}")]
     MP4StreamInfo(String),
 
+    #[error("CAF error: {0}")]
+    CAF(String),
+
+    #[error("Monkey's Audio error: {0}")]
+    MonkeysAudio(String),
+
+    #[error("Monkey's Audio truncated: {0}")]
+    MonkeysAudioTruncated(String),
+
+    #[error("WavPack error: {0}")]
+    WavPack(String),
+
+    #[error("APE no header found")]
+    APENoHeader,
+
     #[error("Invalid data: {0}")]
     InvalidData(String),
 
@@ -81,6 +96,12 @@ mod python_errors {
     create_exception!(mutagen_rs, OggError, MutagenPyError);
     create_exception!(mutagen_rs, MP4Error, MutagenPyError);
     create_exception!(mutagen_rs, MP4StreamInfoError, MP4Error);
+    create_exception!(mutagen_rs, CAFError, MutagenPyError);
+    create_exception!(mutagen_rs, MonkeysAudioHeaderError, MutagenPyError);
+    create_exception!(mutagen_rs, MonkeysAudioTruncatedError, MonkeysAudioHeaderError);
+    create_exception!(mutagen_rs, WavPackHeaderError, MutagenPyError);
+    create_exception!(mutagen_rs, APEError, MutagenPyError);
+    create_exception!(mutagen_rs, APENoHeaderError, APEError);
 
     impl From<MutagenError> for pyo3::PyErr {
         fn from(err: MutagenError) -> pyo3::PyErr {
@@ -106,6 +127,11 @@ mod python_errors {
                 MutagenError::Ogg(msg) => self::OggError::new_err(msg),
                 MutagenError::MP4(msg) => self::MP4Error::new_err(msg),
                 MutagenError::MP4StreamInfo(msg) => self::MP4StreamInfoError::new_err(msg),
+                MutagenError::CAF(msg) => self::CAFError::new_err(msg),
+                MutagenError::MonkeysAudio(msg) => self::MonkeysAudioHeaderError::new_err(msg),
+                MutagenError::MonkeysAudioTruncated(msg) => self::MonkeysAudioTruncatedError::new_err(msg),
+                MutagenError::WavPack(msg) => self::WavPackHeaderError::new_err(msg),
+                MutagenError::APENoHeader => self::APENoHeaderError::new_err("No APE tag found"),
                 MutagenError::InvalidData(msg) => pyo3::exceptions::PyValueError::new_err(msg),
                 MutagenError::Encoding(msg) => pyo3::exceptions::PyValueError::new_err(
                     format!("Encoding error: {}", msg),
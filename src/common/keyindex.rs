@@ -0,0 +1,77 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Entry count up to which a linear scan is used instead of the hash index.
+/// Typical files carry 5-15 keys, where scanning beats hashing.
+pub const LINEAR_MAX: usize = 32;
+
+/// Key → position index over an insertion-ordered `Vec<(K, V)>`.
+///
+/// Tag lists stay Vecs for output order and cache locality; this only speeds up
+/// lookups once a list grows past `LINEAR_MAX` entries, so grouping thousands of
+/// comments is O(n) rather than O(n²). The index remembers how many entries it
+/// covers and is ignored (falling back to a scan) if the Vec was changed behind
+/// its back.
+#[derive(Debug, Clone, Default)]
+pub struct KeyIndex<K> {
+    map: Option<HashMap<K, usize>>,
+    len: usize,
+}
+
+impl<K: Hash + Eq + Clone> KeyIndex<K> {
+    #[inline]
+    pub fn new() -> Self {
+        KeyIndex { map: None, len: 0 }
+    }
+
+    /// Position of the first entry with `key`, using the index only if it is
+    /// already built and current.
+    #[inline]
+    pub fn get<V, Q>(&self, entries: &[(K, V)], key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        match &self.map {
+            Some(map) if self.len == entries.len() => map.get(key).copied(),
+            _ => entries.iter().position(|(k, _)| k.borrow() == key),
+        }
+    }
+
+    /// Like `get`, (re)building the index first when `entries` is past `LINEAR_MAX`.
+    #[inline]
+    pub fn find<V, Q>(&mut self, entries: &[(K, V)], key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if entries.len() > LINEAR_MAX && (self.map.is_none() || self.len != entries.len()) {
+            let mut map = HashMap::with_capacity(entries.len() * 2);
+            for (i, (k, _)) in entries.iter().enumerate() {
+                map.entry(k.clone()).or_insert(i);
+            }
+            self.map = Some(map);
+            self.len = entries.len();
+        }
+        self.get(entries, key)
+    }
+
+    /// Record the entry just pushed onto `entries`.
+    #[inline]
+    pub fn pushed<V>(&mut self, entries: &[(K, V)]) {
+        if let (Some(map), Some((k, _))) = (&mut self.map, entries.last()) {
+            if self.len + 1 == entries.len() {
+                map.entry(k.clone()).or_insert(self.len);
+                self.len = entries.len();
+            }
+        }
+    }
+
+    /// Drop the index, e.g. after entries were removed or reordered.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.map = None;
+        self.len = 0;
+    }
+}
@@ -1,2 +1,6 @@
 pub mod error;
+pub mod keyindex;
+pub mod parse;
+pub mod shared;
+pub mod text;
 pub mod util;
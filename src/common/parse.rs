@@ -0,0 +1,145 @@
+//! Format-independent entry point for Rust callers: detect the format of a
+//! file and parse it completely, without the `python` feature.
+
+use crate::common::error::{MutagenError, Result};
+use crate::{ape, flac, mp3, mp4, ogg, wavpack};
+
+/// A fully parsed file: stream info plus decoded tags, in the format's own types.
+pub enum ParsedFile {
+    Mp3(mp3::MP3File),
+    Flac(flac::FLACFile),
+    Ogg(ogg::OggVorbisFile),
+    Opus(ogg::opus::OpusFile),
+    Mp4(mp4::MP4File),
+    WavPack(wavpack::WavPackFile),
+    MonkeysAudio(ape::APEFile),
+}
+
+impl ParsedFile {
+    /// Short format name: `"mp3"`, `"flac"`, `"ogg"`, `"opus"`, `"mp4"`,
+    /// `"wavpack"` or `"ape"` (Monkey's Audio).
+    pub fn format(&self) -> &'static str {
+        match self {
+            ParsedFile::Mp3(_) => "mp3",
+            ParsedFile::Flac(_) => "flac",
+            ParsedFile::Ogg(_) => "ogg",
+            ParsedFile::Opus(_) => "opus",
+            ParsedFile::Mp4(_) => "mp4",
+            ParsedFile::WavPack(_) => "wavpack",
+            ParsedFile::MonkeysAudio(_) => "ape",
+        }
+    }
+
+    /// Duration in seconds; `None` for a FLAC file without a sample count.
+    pub fn length(&self) -> Option<f64> {
+        match self {
+            ParsedFile::Mp3(f) => Some(f.info.length),
+            ParsedFile::Flac(f) => f.info.length,
+            ParsedFile::Ogg(f) => Some(f.info.length),
+            ParsedFile::Opus(f) => Some(f.info.length),
+            ParsedFile::Mp4(f) => Some(f.info.length),
+            ParsedFile::WavPack(f) => Some(f.info.length),
+            ParsedFile::MonkeysAudio(f) => Some(f.info.length),
+        }
+    }
+}
+
+/// Read and parse `path`. See `parse_data`.
+pub fn parse_file(path: &str) -> Result<ParsedFile> {
+    let data = std::fs::read(path)?;
+    parse_data(&data, path)
+}
+
+/// Parse a whole file held in memory. The format comes from the extension of
+/// `path` when it names one, otherwise from the highest content score (ties
+/// go to FLAC, then Ogg Vorbis, then Opus, then MP4, then WavPack, then Monkey's
+/// Audio, then MP3). An `.ogg` file
+/// whose first packet is an `OpusHead` is parsed as Opus.
+pub fn parse_data(data: &[u8], path: &str) -> Result<ParsedFile> {
+    let ext = path.rsplit('.').next().unwrap_or("");
+    if ext.eq_ignore_ascii_case("flac") {
+        return parse_flac(data, path);
+    } else if ext.eq_ignore_ascii_case("ogg") {
+        if ogg::opus::is_opus(data) {
+            return parse_opus(data, path);
+        }
+        return parse_ogg(data, path);
+    } else if ext.eq_ignore_ascii_case("opus") {
+        return parse_opus(data, path);
+    } else if ext.eq_ignore_ascii_case("mp3") {
+        return parse_mp3(data, path);
+    } else if mp4::is_mp4_extension(ext) {
+        return parse_mp4(data, path);
+    } else if ext.eq_ignore_ascii_case("wv") {
+        return parse_wavpack(data, path);
+    } else if ext.eq_ignore_ascii_case("ape") {
+        return parse_monkeys_audio(data, path);
+    }
+
+    let mp3_score = mp3::MP3File::score(path, data);
+    let flac_score = flac::FLACFile::score(path, data);
+    let ogg_score = ogg::OggVorbisFile::score(path, data);
+    let opus_score = ogg::opus::OpusFile::score(path, data);
+    let mp4_score = mp4::MP4File::score(path, data);
+    let wavpack_score = wavpack::WavPackFile::score(path, data);
+    let ape_score = ape::APEFile::score(path, data);
+    let max_score = mp3_score.max(flac_score).max(ogg_score).max(opus_score).max(mp4_score)
+        .max(wavpack_score).max(ape_score);
+
+    if max_score == 0 {
+        Err(MutagenError::ValueError(format!("Unable to detect format for: {}", path)))
+    } else if max_score == flac_score {
+        parse_flac(data, path)
+    } else if max_score == ogg_score {
+        parse_ogg(data, path)
+    } else if max_score == opus_score {
+        parse_opus(data, path)
+    } else if max_score == mp4_score {
+        parse_mp4(data, path)
+    } else if max_score == wavpack_score {
+        parse_wavpack(data, path)
+    } else if max_score == ape_score {
+        parse_monkeys_audio(data, path)
+    } else {
+        parse_mp3(data, path)
+    }
+}
+
+fn parse_mp3(data: &[u8], path: &str) -> Result<ParsedFile> {
+    let mut f = mp3::MP3File::parse(data, path)?;
+    f.ensure_tags_parsed(data);
+    Ok(ParsedFile::Mp3(f))
+}
+
+fn parse_flac(data: &[u8], path: &str) -> Result<ParsedFile> {
+    let mut f = flac::FLACFile::parse(data, path)?;
+    f.ensure_tags();
+    Ok(ParsedFile::Flac(f))
+}
+
+fn parse_ogg(data: &[u8], path: &str) -> Result<ParsedFile> {
+    let mut f = ogg::OggVorbisFile::parse(data, path)?;
+    f.ensure_full_parse(data);
+    f.ensure_tags();
+    Ok(ParsedFile::Ogg(f))
+}
+
+fn parse_opus(data: &[u8], path: &str) -> Result<ParsedFile> {
+    let mut f = ogg::opus::OpusFile::parse(data, path)?;
+    f.ensure_tags();
+    Ok(ParsedFile::Opus(f))
+}
+
+fn parse_mp4(data: &[u8], path: &str) -> Result<ParsedFile> {
+    let mut f = mp4::MP4File::parse(data, path)?;
+    f.ensure_parsed_with_data(data);
+    Ok(ParsedFile::Mp4(f))
+}
+
+fn parse_wavpack(data: &[u8], path: &str) -> Result<ParsedFile> {
+    Ok(ParsedFile::WavPack(wavpack::WavPackFile::parse(data, path)?))
+}
+
+fn parse_monkeys_audio(data: &[u8], path: &str) -> Result<ParsedFile> {
+    Ok(ParsedFile::MonkeysAudio(ape::APEFile::parse(data, path)?))
+}
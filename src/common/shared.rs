@@ -0,0 +1,65 @@
+//! Byte ranges that share one reference-counted buffer.
+//!
+//! Binary tag payloads (pictures, covers, freeform data) are the bulk of a
+//! tag's size. Holding them as ranges of the buffer they were parsed from lets
+//! the batch pipeline move them between threads without copying; the bytes
+//! are copied once, when the Python object is built.
+
+use std::fmt;
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// A range of a shared buffer; cloning and slicing never copy the bytes.
+///
+/// The buffer is an `Arc<Vec<u8>>` rather than `Arc<[u8]>` so that an owned
+/// `Vec` (a decoded tag, a parsed cover) can be adopted without a copy.
+#[derive(Clone)]
+pub struct SharedBytes {
+    buf: Arc<Vec<u8>>,
+    start: usize,
+    end: usize,
+}
+
+impl SharedBytes {
+    /// The sub-range `start..end` of this range, sharing the same buffer.
+    ///
+    /// # Panics
+    /// If `start > end` or `end` is past the end of this range.
+    pub fn slice(&self, start: usize, end: usize) -> SharedBytes {
+        assert!(start <= end && end <= self.len(), "slice {}..{} out of range for {} bytes", start, end, self.len());
+        SharedBytes {
+            buf: Arc::clone(&self.buf),
+            start: self.start + start,
+            end: self.start + end,
+        }
+    }
+}
+
+impl Deref for SharedBytes {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        &self.buf[self.start..self.end]
+    }
+}
+
+impl From<Vec<u8>> for SharedBytes {
+    #[inline]
+    fn from(data: Vec<u8>) -> Self {
+        let end = data.len();
+        SharedBytes { buf: Arc::new(data), start: 0, end }
+    }
+}
+
+impl fmt::Debug for SharedBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedBytes").field("len", &self.len()).finish()
+    }
+}
+
+impl PartialEq for SharedBytes {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
@@ -0,0 +1,19 @@
+//! Text validation for the tag decoders' hot paths.
+//!
+//! `from_utf8` accepts and rejects exactly what `std::str::from_utf8` does, so
+//! callers keep their existing fallbacks (lossy decode, skip) for invalid input.
+
+/// Validate UTF-8 with SIMD (`simdutf8`).
+#[inline]
+pub fn from_utf8(bytes: &[u8]) -> Option<&str> {
+    simdutf8::basic::from_utf8(bytes).ok()
+}
+
+/// Decode UTF-8, replacing invalid sequences exactly as `String::from_utf8_lossy` does.
+#[inline]
+pub fn utf8_lossy(bytes: &[u8]) -> String {
+    match from_utf8(bytes) {
+        Some(s) => s.to_owned(),
+        None => String::from_utf8_lossy(bytes).into_owned(),
+    }
+}
@@ -1,5 +1,6 @@
 use std::fs::{File, OpenOptions};
 use std::io::{Read, Write, Seek, SeekFrom};
+use std::ops::RangeInclusive;
 use crate::common::error::{MutagenError, Result};
 
 /// Insert `count` bytes at `offset` in the file, shifting existing data forward.
@@ -72,3 +73,61 @@ pub fn open_rw(path: &str) -> Result<File> {
 pub fn open_ro(path: &str) -> Result<File> {
     Ok(File::open(path)?)
 }
+
+/// Duration in milliseconds of `units` at `per_second` units per second, rounded
+/// to the nearest millisecond in integer arithmetic; 0 when `per_second` is 0.
+pub fn duration_ms(units: u64, per_second: u64) -> u64 {
+    if per_second == 0 {
+        return 0;
+    }
+    ((units as u128 * 1000 + per_second as u128 / 2) / per_second as u128) as u64
+}
+
+/// Sample rates accepted from a stream header; anything else is damage.
+pub const SAMPLE_RATE_RANGE: RangeInclusive<u32> = 1..=768_000;
+/// Channel counts accepted from a stream header.
+pub const CHANNELS_RANGE: RangeInclusive<u32> = 1..=64;
+/// Sample sizes accepted from a stream header. 0 means "not stated", as
+/// lossy codecs leave it.
+pub const BITS_PER_SAMPLE_RANGE: RangeInclusive<u32> = 0..=64;
+
+/// `value` when it lies in `range`; otherwise 0 ("unknown"), with a warning
+/// naming `field` appended to `warnings`. Keeps a corrupt header from turning
+/// into an infinite or NaN length downstream.
+pub fn check_header_value(field: &str, value: u32, range: RangeInclusive<u32>, warnings: &mut Vec<String>) -> u32 {
+    if range.contains(&value) {
+        return value;
+    }
+    warnings.push(format!(
+        "{} {} out of range {}..={}, using 0",
+        field, value, range.start(), range.end()
+    ));
+    0
+}
+
+/// Match a tag key against a `strip()` pattern. `"COMM*"` matches any key
+/// starting with `COMM`; a bare `"COMM"` matches `COMM` itself and keys
+/// qualified by it (`COMM:desc:eng`, and `----:mean:name` for `"----"`).
+pub fn key_matches_pattern(key: &str, pattern: &str, ignore_case: bool) -> bool {
+    let (prefix, wildcard) = match pattern.strip_suffix('*') {
+        Some(prefix) => (prefix, true),
+        None => (pattern, false),
+    };
+    if key.len() < prefix.len() || !key.is_char_boundary(prefix.len()) {
+        return false;
+    }
+    let (head, rest) = key.split_at(prefix.len());
+    let head_matches = if ignore_case { head.eq_ignore_ascii_case(prefix) } else { head == prefix };
+    head_matches && (wildcard || rest.is_empty() || rest.starts_with(':'))
+}
+
+/// Read `count` (at most 32) bits starting `bit` bits into `data`, most
+/// significant bit first. Bits past the end of `data` read as zero.
+pub fn read_bits(data: &[u8], bit: usize, count: usize) -> u32 {
+    let mut value = 0u32;
+    for i in bit..bit + count {
+        let byte = data.get(i / 8).copied().unwrap_or(0);
+        value = (value << 1) | ((byte >> (7 - i % 8)) & 1) as u32;
+    }
+    value
+}
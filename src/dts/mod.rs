@@ -0,0 +1,112 @@
+use crate::common::error::{MutagenError, Result};
+use crate::common::util::{duration_ms, read_bits};
+
+/// Core sample rates by `SFREQ`; 0 marks invalid codes.
+const SAMPLE_RATES: [u32; 16] = [
+    0, 8000, 16000, 32000, 0, 0, 11025, 22050, 44100, 0, 0, 12000, 24000, 48000, 0, 0,
+];
+
+/// Nominal bit rates in bps by `RATE`; 0 for open, variable and lossless rates.
+const BITRATES: [u32; 32] = [
+    32000, 56000, 64000, 96000, 112000, 128000, 192000, 224000, 256000, 320000, 384000,
+    448000, 512000, 576000, 640000, 768000, 960000, 1024000, 1152000, 1280000, 1344000,
+    1408000, 1411200, 1472000, 1536000, 1920000, 2048000, 3072000, 3840000, 0, 0, 0,
+];
+
+/// Channels by audio channel arrangement (`AMODE`), LFE not included.
+const AMODE_CHANNELS: [u32; 16] = [1, 2, 2, 2, 2, 3, 3, 4, 4, 5, 6, 6, 6, 7, 8, 8];
+
+/// How far into the stream to look for the first core frame.
+const SYNC_SEARCH: usize = 64 * 1024;
+
+/// Audio information from the first core frame header of a raw DTS stream.
+/// Streams are treated as constant bit rate, so the length is the stream
+/// size divided by the bit rate.
+#[derive(Debug, Clone, Default)]
+pub struct DTSInfo {
+    pub length: f64,
+    pub length_samples: u64,
+    /// Stream size × 8 / bitrate in whole milliseconds.
+    pub duration_ms: u64,
+    pub sample_rate: u32,
+    /// Including the LFE channel.
+    pub channels: u32,
+    pub bitrate: u32,
+}
+
+/// A raw DTS elementary stream (`.dts`). Carries no tags.
+#[derive(Debug, Clone, Default)]
+pub struct DTSFile {
+    pub info: DTSInfo,
+    pub path: String,
+}
+
+impl DTSFile {
+    /// Open and parse a DTS file.
+    pub fn open(path: &str) -> Result<Self> {
+        let data = std::fs::read(path)?;
+        Self::parse(&data, path)
+    }
+
+    /// Parse the first core frame header (16-bit big-endian, syncword
+    /// 0x7FFE8001); `data.len()` is taken as the stream size.
+    pub fn parse(data: &[u8], path: &str) -> Result<Self> {
+        let window = &data[..data.len().min(SYNC_SEARCH)];
+        let start = window.windows(4).position(|w| w == [0x7F, 0xFE, 0x80, 0x01])
+            .ok_or_else(|| MutagenError::InvalidData("No DTS sync frame".into()))?;
+        let header = &data[start..];
+        if header.len() < 11 {
+            return Err(MutagenError::InvalidData("DTS frame header too short".into()));
+        }
+
+        // Bit offsets from the start of the frame, syncword included
+        let blocks = read_bits(header, 39, 7) + 1;
+        let frame_bytes = read_bits(header, 46, 14) + 1;
+        let amode = read_bits(header, 60, 6) as usize;
+        let sample_rate = SAMPLE_RATES[read_bits(header, 66, 4) as usize];
+        let rate = read_bits(header, 70, 5) as usize;
+        let lff = read_bits(header, 85, 2);
+        if sample_rate == 0 {
+            return Err(MutagenError::InvalidData("Invalid DTS sample rate".into()));
+        }
+
+        // User-defined arrangements (AMODE >= 16) carry no fixed channel count
+        let channels = AMODE_CHANNELS.get(amode).copied().unwrap_or(0) + u32::from(lff != 0);
+        // Open/variable/lossless rates: derive from the frame size instead
+        let bitrate = match BITRATES[rate] {
+            0 => (frame_bytes as u64 * 8 * sample_rate as u64 / (blocks as u64 * 32)) as u32,
+            nominal => nominal,
+        };
+
+        let stream_bytes = (data.len() - start) as f64;
+        let length = if bitrate > 0 { stream_bytes * 8.0 / bitrate as f64 } else { 0.0 };
+        let duration = duration_ms((data.len() - start) as u64 * 8, bitrate as u64);
+        Ok(DTSFile {
+            info: DTSInfo {
+                length,
+                length_samples: (length * sample_rate as f64).round() as u64,
+                duration_ms: duration,
+                sample_rate,
+                channels,
+                bitrate,
+            },
+            path: path.to_string(),
+        })
+    }
+
+    /// Score for auto-detection.
+    pub fn score(path: &str, data: &[u8]) -> u32 {
+        let mut score = 0u32;
+
+        let ext = path.rsplit('.').next().unwrap_or("");
+        if ext.eq_ignore_ascii_case("dts") {
+            score += 2;
+        }
+
+        if data.len() >= 4 && data[0..4] == [0x7F, 0xFE, 0x80, 0x01] {
+            score += 3;
+        }
+
+        score
+    }
+}
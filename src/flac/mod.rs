@@ -1,5 +1,6 @@
 use std::io::{Write, Seek, SeekFrom, Read};
 use crate::common::error::{MutagenError, Result};
+use crate::common::util::{check_header_value, duration_ms, SAMPLE_RATE_RANGE};
 use crate::vorbis::VorbisComment;
 
 /// FLAC metadata block types.
@@ -52,8 +53,9 @@ pub struct MetadataBlock {
     pub data: Vec<u8>,
 }
 
-/// Parsed FLAC StreamInfo block.
-#[derive(Debug, Clone)]
+/// Parsed FLAC StreamInfo block. A file without one (non-conformant, but its
+/// tags are still read) gets the default: all zero and `length` `None`.
+#[derive(Debug, Clone, Default)]
 pub struct StreamInfo {
     pub min_block_size: u16,
     pub max_block_size: u16,
@@ -64,7 +66,12 @@ pub struct StreamInfo {
     pub bits_per_sample: u8,
     pub total_samples: u64,
     pub md5: [u8; 16],
-    pub length: f64,
+    /// Duration in seconds; `None` when `total_samples` is 0 (unknown, e.g. a live stream).
+    pub length: Option<f64>,
+    /// `total_samples` / `sample_rate` in whole milliseconds; `None` with `length`.
+    pub duration_ms: Option<u64>,
+    /// Header values that were out of range and read as 0 (see `check_header_value`).
+    pub warnings: Vec<String>,
 }
 
 impl StreamInfo {
@@ -80,7 +87,9 @@ impl StreamInfo {
 
         // Bits 10-12: sample rate (20 bits), channels (3 bits), bps (5 bits), total_samples (36 bits)
         let sr_hi = u32::from_be_bytes([0, data[10], data[11], data[12]]);
-        let sample_rate = (sr_hi >> 4) & 0xFFFFF;
+        // The 3-bit channel and 5-bit size fields can't leave their ranges
+        let mut warnings = Vec::new();
+        let sample_rate = check_header_value("sample_rate", (sr_hi >> 4) & 0xFFFFF, SAMPLE_RATE_RANGE, &mut warnings);
 
         let channels = ((data[12] >> 1) & 0x07) + 1 ;
         let bps_hi = (data[12] & 0x01) << 4;
@@ -94,10 +103,12 @@ impl StreamInfo {
         let mut md5 = [0u8; 16];
         md5.copy_from_slice(&data[18..34]);
 
-        let length = if sample_rate > 0 {
-            total_samples as f64 / sample_rate as f64
+        let length = if total_samples == 0 {
+            None
+        } else if sample_rate > 0 {
+            Some(total_samples as f64 / sample_rate as f64)
         } else {
-            0.0
+            Some(0.0)
         };
 
         Ok(StreamInfo {
@@ -111,6 +122,8 @@ impl StreamInfo {
             total_samples,
             md5,
             length,
+            duration_ms: (total_samples > 0).then(|| duration_ms(total_samples, sample_rate as u64)),
+            warnings,
         })
     }
 }
@@ -128,84 +141,109 @@ pub struct FLACPicture {
     pub data: Vec<u8>,
 }
 
-impl FLACPicture {
-    pub fn parse(block_data: &[u8]) -> Result<Self> {
-        if block_data.len() < 32 {
-            return Err(MutagenError::FLAC("Picture block too short".into()));
-        }
+/// FLAC picture fields up to the image data, which is left in place.
+#[derive(Debug, Clone)]
+pub struct PictureHeader {
+    pub pic_type: u32,
+    pub mime: String,
+    pub desc: String,
+    pub width: u32,
+    pub height: u32,
+    pub depth: u32,
+    pub colors: u32,
+    /// Range of the image data within the block.
+    pub data: std::ops::Range<usize>,
+}
 
-        let mut pos = 0;
-        let pic_type = u32::from_be_bytes([
-            block_data[pos], block_data[pos + 1], block_data[pos + 2], block_data[pos + 3],
-        ]);
-        pos += 4;
+/// Parse a PICTURE block up to the image data without copying it.
+pub fn parse_picture_header(block_data: &[u8]) -> Result<PictureHeader> {
+    if block_data.len() < 32 {
+        return Err(MutagenError::FLAC("Picture block too short".into()));
+    }
 
-        let mime_len = u32::from_be_bytes([
-            block_data[pos], block_data[pos + 1], block_data[pos + 2], block_data[pos + 3],
-        ]) as usize;
-        pos += 4;
+    let mut pos = 0;
+    let pic_type = u32::from_be_bytes([
+        block_data[pos], block_data[pos + 1], block_data[pos + 2], block_data[pos + 3],
+    ]);
+    pos += 4;
 
-        if pos + mime_len > block_data.len() {
-            return Err(MutagenError::FLAC("Picture MIME extends past data".into()));
-        }
-        let mime = String::from_utf8_lossy(&block_data[pos..pos + mime_len]).into_owned();
-        pos += mime_len;
+    let mime_len = u32::from_be_bytes([
+        block_data[pos], block_data[pos + 1], block_data[pos + 2], block_data[pos + 3],
+    ]) as usize;
+    pos += 4;
 
-        if pos + 4 > block_data.len() {
-            return Err(MutagenError::FLAC("Picture block too short for desc".into()));
-        }
-        let desc_len = u32::from_be_bytes([
-            block_data[pos], block_data[pos + 1], block_data[pos + 2], block_data[pos + 3],
-        ]) as usize;
-        pos += 4;
+    if pos + mime_len > block_data.len() {
+        return Err(MutagenError::FLAC("Picture MIME extends past data".into()));
+    }
+    let mime = String::from_utf8_lossy(&block_data[pos..pos + mime_len]).into_owned();
+    pos += mime_len;
 
-        if pos + desc_len > block_data.len() {
-            return Err(MutagenError::FLAC("Picture desc extends past data".into()));
-        }
-        let desc = String::from_utf8_lossy(&block_data[pos..pos + desc_len]).into_owned();
-        pos += desc_len;
+    if pos + 4 > block_data.len() {
+        return Err(MutagenError::FLAC("Picture block too short for desc".into()));
+    }
+    let desc_len = u32::from_be_bytes([
+        block_data[pos], block_data[pos + 1], block_data[pos + 2], block_data[pos + 3],
+    ]) as usize;
+    pos += 4;
 
-        if pos + 20 > block_data.len() {
-            return Err(MutagenError::FLAC("Picture block too short for dimensions".into()));
-        }
+    if pos + desc_len > block_data.len() {
+        return Err(MutagenError::FLAC("Picture desc extends past data".into()));
+    }
+    let desc = String::from_utf8_lossy(&block_data[pos..pos + desc_len]).into_owned();
+    pos += desc_len;
 
-        let width = u32::from_be_bytes([
-            block_data[pos], block_data[pos + 1], block_data[pos + 2], block_data[pos + 3],
-        ]);
-        pos += 4;
-        let height = u32::from_be_bytes([
-            block_data[pos], block_data[pos + 1], block_data[pos + 2], block_data[pos + 3],
-        ]);
-        pos += 4;
-        let depth = u32::from_be_bytes([
-            block_data[pos], block_data[pos + 1], block_data[pos + 2], block_data[pos + 3],
-        ]);
-        pos += 4;
-        let colors = u32::from_be_bytes([
-            block_data[pos], block_data[pos + 1], block_data[pos + 2], block_data[pos + 3],
-        ]);
-        pos += 4;
-
-        let data_len = u32::from_be_bytes([
-            block_data[pos], block_data[pos + 1], block_data[pos + 2], block_data[pos + 3],
-        ]) as usize;
-        pos += 4;
-
-        let data = if pos + data_len <= block_data.len() {
-            block_data[pos..pos + data_len].to_vec()
-        } else {
-            block_data[pos..].to_vec()
-        };
+    if pos + 20 > block_data.len() {
+        return Err(MutagenError::FLAC("Picture block too short for dimensions".into()));
+    }
+
+    let width = u32::from_be_bytes([
+        block_data[pos], block_data[pos + 1], block_data[pos + 2], block_data[pos + 3],
+    ]);
+    pos += 4;
+    let height = u32::from_be_bytes([
+        block_data[pos], block_data[pos + 1], block_data[pos + 2], block_data[pos + 3],
+    ]);
+    pos += 4;
+    let depth = u32::from_be_bytes([
+        block_data[pos], block_data[pos + 1], block_data[pos + 2], block_data[pos + 3],
+    ]);
+    pos += 4;
+    let colors = u32::from_be_bytes([
+        block_data[pos], block_data[pos + 1], block_data[pos + 2], block_data[pos + 3],
+    ]);
+    pos += 4;
+
+    let data_len = u32::from_be_bytes([
+        block_data[pos], block_data[pos + 1], block_data[pos + 2], block_data[pos + 3],
+    ]) as usize;
+    pos += 4;
+
+    let end = pos.saturating_add(data_len).min(block_data.len());
+
+    Ok(PictureHeader {
+        pic_type,
+        mime,
+        desc,
+        width,
+        height,
+        depth,
+        colors,
+        data: pos..end,
+    })
+}
 
+impl FLACPicture {
+    pub fn parse(block_data: &[u8]) -> Result<Self> {
+        let header = parse_picture_header(block_data)?;
         Ok(FLACPicture {
-            pic_type,
-            mime,
-            desc,
-            width,
-            height,
-            depth,
-            colors,
-            data,
+            pic_type: header.pic_type,
+            mime: header.mime,
+            desc: header.desc,
+            width: header.width,
+            height: header.height,
+            depth: header.depth,
+            colors: header.colors,
+            data: block_data[header.data].to_vec(),
         })
     }
 
@@ -244,6 +282,23 @@ pub struct BlockDesc {
     pub data_size: usize,
 }
 
+impl BlockDesc {
+    /// Offset of the block's 4-byte header.
+    pub fn header_offset(&self) -> usize {
+        self.data_offset - 4
+    }
+}
+
+/// Whether `pos` holds a plausible metadata block header: a known type and a
+/// size that fits the file. A frame sync (0xFF) never passes the type check.
+fn looks_like_block_header(data: &[u8], pos: usize) -> bool {
+    if pos + 4 > data.len() || data[pos] & 0x7F > 6 {
+        return false;
+    }
+    let size = u32::from_be_bytes([0, data[pos + 1], data[pos + 2], data[pos + 3]]) as usize;
+    pos + 4 + size <= data.len()
+}
+
 /// Compute the actual Vorbis Comment data size from internal length fields.
 /// Returns the total bytes needed to hold the complete VC data.
 pub fn compute_vc_data_size(data: &[u8]) -> Option<usize> {
@@ -368,7 +423,12 @@ impl FLACFile {
             }
         }
 
-        let info = stream_info.ok_or_else(|| MutagenError::FLAC("No StreamInfo block found".into()))?;
+        // STREAMINFO may come after other blocks or be missing; without it
+        // the tags are still read, as long as there are some
+        if stream_info.is_none() && vc_raw.is_none() {
+            return Err(MutagenError::FLAC("No StreamInfo block found".into()));
+        }
+        let info = stream_info.unwrap_or_default();
 
         Ok(FLACFile {
             info,
@@ -383,6 +443,67 @@ impl FLACFile {
         })
     }
 
+    /// Bytes the metadata blocks take after the fLaC magic, headers included.
+    pub fn blocks_size(&self) -> usize {
+        self.block_descs.iter().map(|bd| 4 + bd.data_size).sum()
+    }
+
+    /// Spec violations in the block layout recorded by `parse`: STREAMINFO
+    /// not first, the is-last flag set early or never, and declared sizes
+    /// that overlap other data. `data` must be the bytes that were parsed.
+    pub fn layout_warnings(&self, data: &[u8]) -> Vec<String> {
+        let mut warnings = Vec::new();
+        let descs = &self.block_descs;
+
+        let streaminfo: Vec<usize> = descs.iter().enumerate()
+            .filter(|(_, bd)| bd.block_type == BlockType::StreamInfo)
+            .map(|(i, _)| i)
+            .collect();
+        match streaminfo.first() {
+            Some(0) => {}
+            Some(i) => warnings.push(format!("STREAMINFO is block {} instead of the first block", i)),
+            None => warnings.push("no STREAMINFO block".into()),
+        }
+        if streaminfo.len() > 1 {
+            warnings.push(format!("{} STREAMINFO blocks", streaminfo.len()));
+        }
+
+        for (i, bd) in descs.iter().enumerate() {
+            if bd.block_type != BlockType::VorbisComment {
+                continue;
+            }
+            if let Some(needed) = compute_vc_data_size(&data[bd.data_offset..]) {
+                if needed > bd.data_size {
+                    warnings.push(format!(
+                        "block {} (VORBIS_COMMENT) declares {} bytes but its comments need {}",
+                        i, bd.data_size, needed
+                    ));
+                }
+            }
+        }
+
+        let end = descs.last().map_or(self.flac_offset + 4, |bd| bd.data_offset + bd.data_size);
+        match descs.last() {
+            Some(last) if last.is_last => {
+                if looks_like_block_header(data, end) {
+                    warnings.push(format!(
+                        "block {} has is-last set but another metadata block follows at offset {}",
+                        descs.len() - 1, end
+                    ));
+                }
+            }
+            _ if end + 4 <= data.len() => {
+                let size = u32::from_be_bytes([0, data[end + 1], data[end + 2], data[end + 3]]);
+                warnings.push(format!(
+                    "block {} at offset {} declares {} bytes, past the end of the file",
+                    descs.len(), end, size
+                ));
+            }
+            _ => warnings.push(format!("metadata ends at offset {} without a block marked is-last", end)),
+        }
+        warnings
+    }
+
     /// Lazily parse VorbisComment from raw bytes if not yet parsed.
     pub fn ensure_tags(&mut self) {
         if self.tags.is_none() {
@@ -463,8 +584,21 @@ impl FLACFile {
             }
         }
 
-        // Padding
-        blocks_to_write.push((BlockType::Padding, vec![0u8; 1024]));
+        // Padding: when the new blocks fit where the old ones were, fill the
+        // rest with padding and rewrite the metadata in place; otherwise move
+        // the audio. Like mutagen, leftover space over 10 KiB + 1% of the file
+        // is given back rather than kept.
+        let old_size = self.blocks_size();
+        let audio_start = flac_offset + 4 + old_size;
+        let new_size: usize = blocks_to_write.iter().map(|(_, d)| 4 + d.len()).sum();
+        let max_padding = 10 * 1024 + existing.len() / 100;
+        let in_place = audio_start <= existing.len()
+            && (new_size == old_size || (new_size + 4 <= old_size && old_size - new_size - 4 <= max_padding));
+        if !in_place {
+            blocks_to_write.push((BlockType::Padding, vec![0u8; 1024]));
+        } else if new_size < old_size {
+            blocks_to_write.push((BlockType::Padding, vec![0u8; old_size - new_size - 4]));
+        }
 
         // Write blocks with proper headers
         for (i, (block_type, block_data)) in blocks_to_write.iter().enumerate() {
@@ -482,11 +616,16 @@ impl FLACFile {
             new_metadata.extend_from_slice(block_data);
         }
 
+        file.seek(SeekFrom::Start(flac_offset as u64))?;
+        if in_place {
+            file.write_all(&new_metadata)?;
+            file.flush()?;
+            return Ok(());
+        }
+
         // Audio data starts after original metadata
-        let audio_start = flac_offset + self.metadata_length;
-        let audio_data = &existing[audio_start..];
+        let audio_data = &existing[audio_start.min(existing.len())..];
 
-        file.seek(SeekFrom::Start(flac_offset as u64))?;
         file.set_len(flac_offset as u64)?;
         file.write_all(&new_metadata)?;
         file.write_all(audio_data)?;
@@ -75,7 +75,9 @@ pub enum Frame {
     UserUrl(UserUrlFrame),
     Comment(CommentFrame),
     Lyrics(LyricsFrame),
+    SyncLyrics(SyncLyricsFrame),
     Picture(PictureFrame),
+    Geob(GeobFrame),
     Popularimeter(PopularimeterFrame),
     Binary(BinaryFrame),
     PairedText(PairedTextFrame),
@@ -91,7 +93,9 @@ impl Frame {
             Frame::UserUrl(f) => &f.id,
             Frame::Comment(f) => &f.id,
             Frame::Lyrics(f) => &f.id,
+            Frame::SyncLyrics(f) => &f.id,
             Frame::Picture(f) => &f.id,
+            Frame::Geob(f) => &f.id,
             Frame::Popularimeter(f) => &f.id,
             Frame::Binary(f) => &f.id,
             Frame::PairedText(f) => &f.id,
@@ -107,9 +111,11 @@ impl Frame {
             Frame::UserUrl(f) => HashKey::from_string(format!("WXXX:{}", f.desc)),
             Frame::Comment(f) => HashKey::from_string(format!("COMM:{}:{}", f.desc, f.lang)),
             Frame::Lyrics(f) => HashKey::from_string(format!("USLT:{}:{}", f.desc, f.lang)),
+            Frame::SyncLyrics(f) => HashKey::from_string(format!("SYLT:{}:{}", f.desc, f.lang)),
             Frame::Picture(f) => HashKey::from_string(format!("APIC:{}", f.desc)),
+            Frame::Geob(f) => HashKey::from_string(format!("GEOB:{}", f.desc)),
             Frame::Popularimeter(f) => HashKey::from_string(format!("POPM:{}", f.email)),
-            Frame::Binary(f) => HashKey::new(&f.id),
+            Frame::Binary(f) => binary_hash_key(&f.id, &f.data),
             Frame::PairedText(f) => HashKey::new(&f.id),
         }
     }
@@ -123,7 +129,9 @@ impl Frame {
             Frame::UserUrl(f) => format!("{}={}", f.desc, f.url),
             Frame::Comment(f) => f.text.clone(),
             Frame::Lyrics(f) => f.text.clone(),
+            Frame::SyncLyrics(f) => f.sync.iter().map(|(text, _)| text.as_str()).collect(),
             Frame::Picture(f) => format!("{} ({}, {} bytes)", f.desc, f.mime, f.data.len()),
+            Frame::Geob(f) => format!("{} ({}, {}, {} bytes)", f.desc, f.filename, f.mime, f.data.len()),
             Frame::Popularimeter(f) => format!("{}={}/{}", f.email, f.rating, f.count),
             Frame::Binary(f) => format!("[{} bytes]", f.data.len()),
             Frame::PairedText(f) => {
@@ -156,7 +164,9 @@ impl Frame {
             Frame::UserUrl(f) => write_user_url_frame(f, version),
             Frame::Comment(f) => write_comment_frame(f, version),
             Frame::Lyrics(f) => write_lyrics_frame(f, version),
+            Frame::SyncLyrics(f) => write_sync_lyrics_frame(f, version),
             Frame::Picture(f) => write_picture_frame(f, version),
+            Frame::Geob(f) => write_geob_frame(f, version),
             Frame::Popularimeter(f) => write_popm_frame(f),
             Frame::Binary(f) => Ok(f.data.clone()),
             Frame::PairedText(f) => write_paired_text_frame(f, version),
@@ -217,6 +227,46 @@ pub struct LyricsFrame {
     pub text: String,
 }
 
+/// SYLT timestamp format: MPEG frames since the start of the audio.
+pub const TIMESTAMP_MPEG_FRAMES: u8 = 1;
+/// SYLT timestamp format: milliseconds since the start of the audio.
+pub const TIMESTAMP_MILLISECONDS: u8 = 2;
+
+/// Synchronised lyrics frame (SYLT): text split into syllables or lines, each
+/// with the time it starts.
+#[derive(Debug, Clone)]
+pub struct SyncLyricsFrame {
+    pub id: String,
+    pub encoding: Encoding,
+    pub lang: String,
+    /// Unit of the timestamps in `sync` (`TIMESTAMP_MPEG_FRAMES` or `TIMESTAMP_MILLISECONDS`).
+    pub format: u8,
+    /// 0 other, 1 lyrics, 2 transcription, 3 movement, 4 events, 5 chords, 6 trivia, ...
+    pub content_type: u8,
+    pub desc: String,
+    /// Text and timestamp pairs, in frame order.
+    pub sync: Vec<(String, u32)>,
+}
+
+impl SyncLyricsFrame {
+    /// `sync` with the timestamps in milliseconds. MPEG frame counts are scaled
+    /// by `frame_ms`, the length of one frame of the file's audio; `None` when
+    /// that length is needed but unknown, or the format is not one of the two.
+    pub fn sync_ms(&self, frame_ms: Option<f64>) -> Option<Vec<(String, u32)>> {
+        let scale = match self.format {
+            TIMESTAMP_MILLISECONDS => 1.0,
+            TIMESTAMP_MPEG_FRAMES => frame_ms?,
+            _ => return None,
+        };
+        Some(self.sync.iter().map(|(text, time)| (text.clone(), mpeg_frames_to_ms(*time, scale))).collect())
+    }
+}
+
+/// A timestamp of `frames` MPEG frames of `frame_ms` each, in whole milliseconds.
+pub fn mpeg_frames_to_ms(frames: u32, frame_ms: f64) -> u32 {
+    (frames as f64 * frame_ms).round() as u32
+}
+
 /// Picture frame (APIC).
 #[derive(Debug, Clone)]
 pub struct PictureFrame {
@@ -228,6 +278,18 @@ pub struct PictureFrame {
     pub data: Vec<u8>,
 }
 
+/// General encapsulated object frame (GEOB): a file of any type, with its
+/// MIME type, file name and a description that tells frames apart.
+#[derive(Debug, Clone)]
+pub struct GeobFrame {
+    pub id: String,
+    pub encoding: Encoding,
+    pub mime: String,
+    pub filename: String,
+    pub desc: String,
+    pub data: Vec<u8>,
+}
+
 /// Popularimeter frame (POPM).
 #[derive(Debug, Clone)]
 pub struct PopularimeterFrame {
@@ -351,20 +413,7 @@ pub fn parse_user_url_frame(id: &str, data: &[u8]) -> Result<Frame> {
 
 /// Parse a COMM (comment) frame.
 pub fn parse_comment_frame(id: &str, data: &[u8]) -> Result<Frame> {
-    if data.len() < 4 {
-        return Err(MutagenError::ID3("COMM frame too short".into()));
-    }
-
-    let encoding = Encoding::from_byte(data[0])?;
-    let lang = std::str::from_utf8(&data[1..4])
-        .unwrap_or("XXX")
-        .to_string();
-    let rest = &data[4..];
-
-    let (desc, consumed) = specs::read_encoded_text(rest, encoding)?;
-    let text = specs::decode_text(&rest[consumed..], encoding)?;
-    let text = text.trim_end_matches('\0').to_string();
-
+    let (encoding, lang, desc, text) = parse_lang_desc_text(id, data)?;
     Ok(Frame::Comment(CommentFrame {
         id: id.to_string(),
         encoding,
@@ -376,8 +425,22 @@ pub fn parse_comment_frame(id: &str, data: &[u8]) -> Result<Frame> {
 
 /// Parse a USLT (lyrics) frame.
 pub fn parse_lyrics_frame(id: &str, data: &[u8]) -> Result<Frame> {
+    let (encoding, lang, desc, text) = parse_lang_desc_text(id, data)?;
+    Ok(Frame::Lyrics(LyricsFrame {
+        id: id.to_string(),
+        encoding,
+        lang,
+        desc,
+        text,
+    }))
+}
+
+/// Fields shared by COMM and USLT: encoding, 3-byte language, a null-terminated
+/// description and the text after it. A description without its terminator
+/// (a truncated frame) takes the rest of the frame and the text is empty.
+fn parse_lang_desc_text(id: &str, data: &[u8]) -> Result<(Encoding, String, String, String)> {
     if data.len() < 4 {
-        return Err(MutagenError::ID3("USLT frame too short".into()));
+        return Err(MutagenError::ID3(format!("{} frame too short", id)));
     }
 
     let encoding = Encoding::from_byte(data[0])?;
@@ -387,20 +450,62 @@ pub fn parse_lyrics_frame(id: &str, data: &[u8]) -> Result<Frame> {
     let rest = &data[4..];
 
     let (desc, consumed) = specs::read_encoded_text(rest, encoding)?;
-    let text = specs::decode_text(&rest[consumed..], encoding)?;
+    let text_data = specs::trim_half_terminator(&rest[consumed..], encoding);
+    let text = specs::decode_text(text_data, encoding)?;
     let text = text.trim_end_matches('\0').to_string();
+    Ok((encoding, lang, desc, text))
+}
 
-    Ok(Frame::Lyrics(LyricsFrame {
+/// Parse a SYLT (synchronised lyrics) frame: encoding, language, timestamp
+/// format, content type and a null-terminated description, then text and
+/// 32-bit big-endian timestamp pairs up to the end of the frame. A pair cut
+/// short by the end of the frame is dropped.
+pub fn parse_sync_lyrics_frame(id: &str, data: &[u8]) -> Result<Frame> {
+    if data.len() < 6 {
+        return Err(MutagenError::ID3(format!("{} frame too short", id)));
+    }
+
+    let encoding = Encoding::from_byte(data[0])?;
+    let lang = std::str::from_utf8(&data[1..4])
+        .unwrap_or("XXX")
+        .to_string();
+    let (format, content_type) = (data[4], data[5]);
+    let mut rest = &data[6..];
+
+    let (desc, consumed) = specs::read_encoded_text(rest, encoding)?;
+    rest = &rest[consumed..];
+
+    let mut sync = Vec::new();
+    while !rest.is_empty() {
+        let (text, consumed) = specs::read_encoded_text(rest, encoding)?;
+        let Some(stamp) = rest.get(consumed..consumed + 4) else { break };
+        sync.push((text, u32::from_be_bytes([stamp[0], stamp[1], stamp[2], stamp[3]])));
+        rest = &rest[consumed + 4..];
+    }
+
+    Ok(Frame::SyncLyrics(SyncLyricsFrame {
         id: id.to_string(),
         encoding,
         lang,
+        format,
+        content_type,
         desc,
-        text,
+        sync,
     }))
 }
 
-/// Parse an APIC (picture) frame.
-pub fn parse_picture_frame(id: &str, data: &[u8]) -> Result<Frame> {
+/// APIC fields in front of the picture data.
+pub struct PictureHeader {
+    pub encoding: Encoding,
+    pub mime: String,
+    pub pic_type: PictureType,
+    pub desc: String,
+    /// Offset of the picture data within the frame payload.
+    pub data_offset: usize,
+}
+
+/// Parse the APIC fields up to the picture data, leaving the data itself uncopied.
+pub fn parse_picture_header(data: &[u8]) -> Result<PictureHeader> {
     if data.is_empty() {
         return Err(MutagenError::ID3("Empty APIC frame".into()));
     }
@@ -420,15 +525,48 @@ pub fn parse_picture_frame(id: &str, data: &[u8]) -> Result<Frame> {
     let rest = &rest[1..];
 
     let (desc, consumed) = specs::read_encoded_text(rest, encoding)?;
-    let pic_data = rest[consumed..].to_vec();
+    let data_offset = data.len() - rest.len() + consumed;
+
+    Ok(PictureHeader { encoding, mime, pic_type, desc, data_offset })
+}
 
+/// Parse an APIC (picture) frame.
+pub fn parse_picture_frame(id: &str, data: &[u8]) -> Result<Frame> {
+    let header = parse_picture_header(data)?;
     Ok(Frame::Picture(PictureFrame {
+        id: id.to_string(),
+        encoding: header.encoding,
+        mime: header.mime,
+        pic_type: header.pic_type,
+        desc: header.desc,
+        data: data[header.data_offset..].to_vec(),
+    }))
+}
+
+/// Parse a GEOB (general encapsulated object) frame: encoding, Latin-1 MIME
+/// type, then the file name and description in the frame's encoding, each
+/// null-terminated, and the object data up to the end of the frame.
+pub fn parse_geob_frame(id: &str, data: &[u8]) -> Result<Frame> {
+    if data.is_empty() {
+        return Err(MutagenError::ID3("Empty GEOB frame".into()));
+    }
+
+    let encoding = Encoding::from_byte(data[0])?;
+    let rest = &data[1..];
+
+    let (mime, consumed) = specs::read_latin1_text(rest)?;
+    let rest = &rest[consumed..];
+    let (filename, consumed) = specs::read_encoded_text(rest, encoding)?;
+    let rest = &rest[consumed..];
+    let (desc, consumed) = specs::read_encoded_text(rest, encoding)?;
+
+    Ok(Frame::Geob(GeobFrame {
         id: id.to_string(),
         encoding,
         mime,
-        pic_type,
+        filename,
         desc,
-        data: pic_data,
+        data: rest[consumed..].to_vec(),
     }))
 }
 
@@ -501,10 +639,14 @@ pub fn parse_frame(id: &str, data: &[u8]) -> Result<Frame> {
         // Comment and lyrics
         "COMM" => parse_comment_frame(id, data),
         "USLT" => parse_lyrics_frame(id, data),
+        "SYLT" => parse_sync_lyrics_frame(id, data),
 
         // Picture
         "APIC" => parse_picture_frame(id, data),
 
+        // Encapsulated object
+        "GEOB" => parse_geob_frame(id, data),
+
         // Popularimeter
         "POPM" => parse_popm_frame(id, data),
 
@@ -519,6 +661,40 @@ pub fn parse_frame(id: &str, data: &[u8]) -> Result<Frame> {
     }
 }
 
+/// Hash key for a frame kept as raw bytes. Frames mutagen qualifies get the
+/// same composite key (`PRIV:owner:data`, `UFID:owner`, `GEOB:desc`,
+/// `SYLT:desc:lang`, `RVA2:desc`, `USER:lang`, `CHAP:id`, ...), read from the
+/// leading fields only; everything else uses the bare frame ID.
+pub fn binary_hash_key(id: &str, data: &[u8]) -> HashKey {
+    let latin1 = |d: &[u8]| specs::read_latin1_text(d).ok();
+    let encoded = |d: &[u8], enc: Encoding| specs::read_encoded_text(d, enc).ok();
+    let encoding = || data.first().and_then(|&b| Encoding::from_byte(b).ok());
+    let qualifier = match id {
+        // Private data is part of the key, decoded as Latin-1
+        "PRIV" => latin1(data).map(|(owner, n)| {
+            let rest: String = data[n.min(data.len())..].iter().map(|&b| b as char).collect();
+            format!("{}:{}", owner, rest)
+        }),
+        "UFID" | "AENC" | "ENCR" | "RVA2" | "CHAP" | "CTOC" => latin1(data).map(|(s, _)| s),
+        "GRID" => latin1(data).and_then(|(_, n)| data.get(n)).map(|group| group.to_string()),
+        "USER" => data.get(1..4).map(|lang| String::from_utf8_lossy(lang).into_owned()),
+        "GEOB" => encoding().and_then(|enc| {
+            let (_, mime) = latin1(&data[1..])?;
+            let (_, file) = encoded(data.get(1 + mime..)?, enc)?;
+            encoded(data.get(1 + mime + file..)?, enc).map(|(desc, _)| desc)
+        }),
+        "SYLT" => encoding().and_then(|enc| {
+            let lang = String::from_utf8_lossy(data.get(1..4)?);
+            encoded(data.get(6..)?, enc).map(|(desc, _)| format!("{}:{}", desc, lang))
+        }),
+        _ => None,
+    };
+    match qualifier {
+        Some(q) => HashKey::from_string(format!("{}:{}", id, q)),
+        None => HashKey::new(id),
+    }
+}
+
 // ---- v2.2 to v2.3/v2.4 frame ID mapping ----
 
 /// Convert a v2.2 3-char frame ID to v2.3+ 4-char equivalent.
@@ -724,6 +900,36 @@ fn write_lyrics_frame(f: &LyricsFrame, version: u8) -> Result<Vec<u8>> {
     Ok(data)
 }
 
+fn write_sync_lyrics_frame(f: &SyncLyricsFrame, version: u8) -> Result<Vec<u8>> {
+    let encoding = if version >= 4 {
+        f.encoding
+    } else if f.encoding == Encoding::Utf8 {
+        Encoding::Utf16
+    } else {
+        f.encoding
+    };
+
+    let mut data = vec![encoding as u8];
+    let lang_bytes = f.lang.as_bytes();
+    let lang = if lang_bytes.len() >= 3 {
+        &lang_bytes[..3]
+    } else {
+        b"XXX"
+    };
+    data.extend_from_slice(lang);
+    data.push(f.format);
+    data.push(f.content_type);
+    let term = specs::null_terminator_size(encoding);
+    data.extend_from_slice(&specs::encode_text(&f.desc, encoding));
+    data.extend_from_slice(&vec![0u8; term]);
+    for (text, time) in &f.sync {
+        data.extend_from_slice(&specs::encode_text(text, encoding));
+        data.extend_from_slice(&vec![0u8; term]);
+        data.extend_from_slice(&time.to_be_bytes());
+    }
+    Ok(data)
+}
+
 fn write_picture_frame(f: &PictureFrame, version: u8) -> Result<Vec<u8>> {
     let encoding = if version >= 4 {
         f.encoding
@@ -744,6 +950,27 @@ fn write_picture_frame(f: &PictureFrame, version: u8) -> Result<Vec<u8>> {
     Ok(data)
 }
 
+fn write_geob_frame(f: &GeobFrame, version: u8) -> Result<Vec<u8>> {
+    let encoding = if version >= 4 {
+        f.encoding
+    } else if f.encoding == Encoding::Utf8 {
+        Encoding::Utf16
+    } else {
+        f.encoding
+    };
+
+    let term = specs::null_terminator_size(encoding);
+    let mut data = vec![encoding as u8];
+    data.extend_from_slice(f.mime.as_bytes());
+    data.push(0);
+    for text in [&f.filename, &f.desc] {
+        data.extend_from_slice(&specs::encode_text(text, encoding));
+        data.extend_from_slice(&vec![0u8; term]);
+    }
+    data.extend_from_slice(&f.data);
+    Ok(data)
+}
+
 fn write_popm_frame(f: &PopularimeterFrame) -> Result<Vec<u8>> {
     let mut data = Vec::new();
     data.extend_from_slice(f.email.as_bytes());
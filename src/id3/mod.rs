@@ -33,14 +33,7 @@ pub fn load_id3(path: &str) -> Result<(ID3Tags, Option<ID3Header>)> {
             let mut tag_data = vec![0u8; tag_size];
             file.read_exact(&mut tag_data)?;
 
-            let mut tags = ID3Tags::new();
-
-            // Apply whole-tag unsynchronisation (ID3v2.3 and earlier)
-            if h.flags.unsynchronisation && h.version.0 < 4 {
-                tag_data = unsynch::decode(&tag_data)?;
-            }
-
-            tags.read_frames(&tag_data, &h)?;
+            let mut tags = read_tag_body(&tag_data, &h)?;
 
             // Check for ID3v1 at end - read only last 128 bytes
             let file_len = file.metadata()?.len();
@@ -48,13 +41,7 @@ pub fn load_id3(path: &str) -> Result<(ID3Tags, Option<ID3Header>)> {
                 file.seek(SeekFrom::Start(file_len - 128))?;
                 let mut v1_buf = [0u8; 128];
                 if file.read_exact(&mut v1_buf).is_ok() && &v1_buf[0..3] == b"TAG" {
-                    let v1_frames = id3v1::parse_id3v1(&v1_buf)?;
-                    for frame in v1_frames {
-                        let key = frame.hash_key();
-                        if !tags.contains_key(&key) {
-                            tags.add(frame);
-                        }
-                    }
+                    tags.merge_id3v1(id3v1::parse_id3v1(&v1_buf)?);
                 }
             }
 
@@ -82,11 +69,10 @@ pub fn load_id3(path: &str) -> Result<(ID3Tags, Option<ID3Header>)> {
 
 /// Load ID3v2 tags from a byte slice (used when data is already in memory).
 pub fn load_id3_from_data(data: &[u8]) -> Result<(ID3Tags, Option<ID3Header>)> {
-    let mut tags = ID3Tags::new();
-
     let header = match ID3Header::parse(data, 0) {
         Ok(h) => h,
         Err(MutagenError::ID3NoHeader) => {
+            let mut tags = ID3Tags::new();
             if let Some(_offset) = id3v1::find_id3v1(data) {
                 let v1_frames = id3v1::parse_id3v1(data)?;
                 for frame in v1_frames {
@@ -98,58 +84,113 @@ pub fn load_id3_from_data(data: &[u8]) -> Result<(ID3Tags, Option<ID3Header>)> {
         Err(e) => return Err(e),
     };
 
-    let tag_start = 10;
     let tag_end = (10 + header.size as usize).min(data.len());
-    let mut tag_data = data[tag_start..tag_end].to_vec();
-
-    if header.flags.unsynchronisation && header.version.0 < 4 {
-        tag_data = unsynch::decode(&tag_data)?;
-    }
-
-    tags.read_frames(&tag_data, &header)?;
+    let mut tags = read_tag_body(&data[10..tag_end], &header)?;
 
     if let Some(_offset) = id3v1::find_id3v1(data) {
-        let v1_frames = id3v1::parse_id3v1(data)?;
-        for frame in v1_frames {
-            let key = frame.hash_key();
-            if !tags.contains_key(&key) {
-                tags.add(frame);
-            }
-        }
+        tags.merge_id3v1(id3v1::parse_id3v1(data)?);
     }
 
     Ok((tags, Some(header)))
 }
 
-/// Save ID3v2 tags to a file.
-pub fn save_id3(path: &str, tags: &ID3Tags, v2_version: u8) -> Result<()> {
+/// Load an ID3v2 tag from a slice that starts with the `ID3` header, such as the payload
+/// of a WAV `id3 ` or AIFF `ID3 ` chunk or the first bytes of a network stream. Uses the
+/// same frame walkers as MP3 but does not look for an ID3v1 trailer or MPEG frames.
+/// The slice may be shorter than the declared tag size; frames past its end are skipped
+/// (compare `header.full_size()` with `data.len()` to detect this).
+pub fn load_id3_from_slice(data: &[u8]) -> Result<(ID3Tags, Option<ID3Header>)> {
+    let header = match ID3Header::parse(data, 0) {
+        Ok(h) => h,
+        Err(MutagenError::ID3NoHeader) => return Ok((ID3Tags::new(), None)),
+        Err(e) => return Err(e),
+    };
+    let tag_end = (10 + header.size as usize).min(data.len());
+    let tags = read_tag_body(&data[10..tag_end], &header)?;
+    Ok((tags, Some(header)))
+}
+
+/// Parse the frames of an ID3v2 tag body (the bytes following the 10-byte header).
+fn read_tag_body(body: &[u8], header: &ID3Header) -> Result<ID3Tags> {
+    let mut tags = ID3Tags::new();
+    // Apply whole-tag unsynchronisation (ID3v2.3 and earlier)
+    if header.flags.unsynchronisation && header.version.0 < 4 {
+        let decoded = unsynch::decode(body)?;
+        tags.read_frames(&decoded, header)?;
+    } else {
+        tags.read_frames(body, header)?;
+    }
+    Ok(tags)
+}
+
+/// Open `path` for rewriting and return it with its length and the size of its
+/// existing ID3v2 tag (0 if none).
+fn open_for_tag_write(path: &str) -> Result<(File, usize, usize)> {
     let mut file = std::fs::OpenOptions::new()
         .read(true)
         .write(true)
         .open(path)?;
 
-    let mut existing = Vec::new();
-    file.read_to_end(&mut existing)?;
-
-    let old_tag_size = match ID3Header::parse(&existing, 0) {
-        Ok(h) => h.full_size() as usize,
+    let file_len = file.metadata()?.len() as usize;
+    let mut header_buf = [0u8; 10];
+    let n = file.read(&mut header_buf)?;
+    let old_tag_size = match ID3Header::parse(&header_buf[..n], 0) {
+        Ok(h) => (h.full_size() as usize).min(file_len),
         Err(_) => 0,
     };
+    Ok((file, file_len, old_tag_size))
+}
 
-    let new_tag = writer::render_tag(tags, v2_version)?;
+/// Replace the first `old_tag_size` bytes of the file with `new_tag`.
+fn replace_tag_region(file: &mut File, file_len: usize, old_tag_size: usize, new_tag: &[u8]) -> Result<()> {
+    if new_tag.len() == old_tag_size {
+        file.seek(SeekFrom::Start(0))?;
+        file.write_all(new_tag)?;
+        file.flush()?;
+        return Ok(());
+    }
 
-    let audio_start = old_tag_size;
-    let audio_data = &existing[audio_start..];
+    let mut audio_data = Vec::with_capacity(file_len - old_tag_size);
+    file.seek(SeekFrom::Start(old_tag_size as u64))?;
+    file.read_to_end(&mut audio_data)?;
 
     file.seek(SeekFrom::Start(0))?;
     file.set_len(0)?;
-    file.write_all(&new_tag)?;
-    file.write_all(audio_data)?;
+    file.write_all(new_tag)?;
+    file.write_all(&audio_data)?;
     file.flush()?;
-
     Ok(())
 }
 
+/// Save ID3v2 tags to a file.
+pub fn save_id3(path: &str, tags: &ID3Tags, v2_version: u8) -> Result<()> {
+    let (mut file, file_len, old_tag_size) = open_for_tag_write(path)?;
+    let frame_data = tags.render(v2_version)?;
+
+    // The new tag fits in the old tag's space: overwrite that region only and pad
+    // to the original size, so the audio data is never read or moved.
+    let padding = if old_tag_size > 0 && 10 + frame_data.len() <= old_tag_size {
+        old_tag_size - 10 - frame_data.len()
+    } else {
+        writer::DEFAULT_PADDING
+    };
+    let new_tag = writer::build_tag(&frame_data, v2_version, padding);
+    replace_tag_region(&mut file, file_len, old_tag_size, &new_tag)
+}
+
+/// Replace a file's ID3v2 tag with an already serialized one (header included),
+/// written byte for byte. `tag` must be exactly as long as its header declares.
+pub fn save_id3_bytes(path: &str, tag: &[u8]) -> Result<()> {
+    let header = ID3Header::parse(tag, 0)?;
+    if header.full_size() as usize != tag.len() {
+        return Err(MutagenError::ID3(format!(
+            "tag header declares {} bytes but {} were given", header.full_size(), tag.len()
+        )));
+    }
+    let (mut file, file_len, old_tag_size) = open_for_tag_write(path)?;
+    replace_tag_region(&mut file, file_len, old_tag_size, tag)
+}
+
 /// Delete ID3v2 tags from a file.
 pub fn delete_id3(path: &str) -> Result<()> {
     let mut file = std::fs::OpenOptions::new()
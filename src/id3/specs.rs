@@ -1,4 +1,5 @@
 use crate::common::error::{MutagenError, Result};
+use crate::common::text;
 
 /// Text encoding types used in ID3v2 frames.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -32,11 +33,13 @@ impl Encoding {
 }
 
 /// Decode text from bytes using the specified encoding.
+/// Decode text in the given encoding. UTF-16 is decoded lossily: lone
+/// surrogates and a dangling odd byte become U+FFFD instead of an error.
 pub fn decode_text(data: &[u8], encoding: Encoding) -> Result<String> {
     match encoding {
         Encoding::Latin1 => {
             // Fast path: if all bytes are ASCII, avoid per-char conversion
-            if data.iter().all(|&b| b < 128) {
+            if data.is_ascii() {
                 // SAFETY: all bytes are valid ASCII, which is valid UTF-8
                 Ok(unsafe { String::from_utf8_unchecked(data.to_vec()) })
             } else {
@@ -56,22 +59,17 @@ pub fn decode_text(data: &[u8], encoding: Encoding) -> Result<String> {
                 // Default to LE if no BOM
                 (encoding_rs::UTF_16LE, 0)
             };
-            let (result, _, had_errors) = decoder.decode(&data[start..]);
-            if had_errors {
-                // Still return what we got - mutagen is lenient
-            }
+            // The BOM is already consumed; don't let a second one switch byte order
+            let (result, _) = decoder.decode_without_bom_handling(&data[start..]);
             Ok(result.into_owned())
         }
         Encoding::Utf16Be => {
-            let (result, _, _) = encoding_rs::UTF_16BE.decode(data);
+            let (result, _) = encoding_rs::UTF_16BE.decode_without_bom_handling(data);
             Ok(result.into_owned())
         }
         Encoding::Utf8 => {
             // Try strict first, fall back to lossy
-            match std::str::from_utf8(data) {
-                Ok(s) => Ok(s.to_string()),
-                Err(_) => Ok(String::from_utf8_lossy(data).into_owned()),
-            }
+            Ok(text::utf8_lossy(data))
         }
     }
 }
@@ -133,7 +131,10 @@ pub fn null_terminator_size(encoding: Encoding) -> usize {
 }
 
 /// Read encoded text from data, returning (text, bytes_consumed).
-/// The text is terminated by null or end of data.
+/// The text is terminated by null (for UTF-16, two null bytes on a code unit
+/// boundary) or by the end of data, as in a truncated frame. There, a lone
+/// null byte ending UTF-16 text is the first half of a cut-off terminator
+/// and is dropped.
 pub fn read_encoded_text(data: &[u8], encoding: Encoding) -> Result<(String, usize)> {
     let term_size = null_terminator_size(encoding);
     match find_null_terminator(data, encoding) {
@@ -142,12 +143,20 @@ pub fn read_encoded_text(data: &[u8], encoding: Encoding) -> Result<(String, usi
             Ok((text, pos + term_size))
         }
         None => {
-            let text = decode_text(data, encoding)?;
+            let text = decode_text(trim_half_terminator(data, encoding), encoding)?;
             Ok((text, data.len()))
         }
     }
 }
 
+/// `data` less a trailing odd null byte when the encoding is UTF-16.
+pub fn trim_half_terminator(data: &[u8], encoding: Encoding) -> &[u8] {
+    match (encoding, data.split_last()) {
+        (Encoding::Utf16 | Encoding::Utf16Be, Some((0, head))) if data.len() % 2 == 1 => head,
+        _ => data,
+    }
+}
+
 /// Read Latin1 text (no encoding byte prefix).
 pub fn read_latin1_text(data: &[u8]) -> Result<(String, usize)> {
     match data.iter().position(|&b| b == 0) {
@@ -162,6 +171,81 @@ pub fn read_latin1_text(data: &[u8]) -> Result<(String, usize)> {
     }
 }
 
+/// Codepage for text stored as "latin-1" (ID3v1 fields and encoding-0 frames),
+/// which older taggers filled in with the system codepage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LegacyEncoding {
+    /// ISO-8859-1, as the spec says (default).
+    Latin1,
+    /// Any other codepage known to encoding_rs, e.g. windows-1251.
+    Codepage(&'static encoding_rs::Encoding),
+    /// Pick windows-1251 or latin-1 per tag (see `guess_legacy_encoding`).
+    Auto,
+}
+
+impl LegacyEncoding {
+    /// Parse `"auto"`, a latin-1 spelling or any encoding_rs label. Latin-1
+    /// labels mean real ISO-8859-1, not the windows-1252 that WHATWG maps them to.
+    pub fn from_label(label: &str) -> Option<Self> {
+        let lower = label.trim().to_ascii_lowercase();
+        match lower.as_str() {
+            "auto" => Some(LegacyEncoding::Auto),
+            "latin-1" | "latin1" | "iso-8859-1" | "iso8859-1" | "l1" => Some(LegacyEncoding::Latin1),
+            _ => encoding_rs::Encoding::for_label(lower.as_bytes()).map(LegacyEncoding::Codepage),
+        }
+    }
+
+    /// Name to report: `"latin-1"`, the encoding_rs name (e.g. `"windows-1251"`) or `"auto"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            LegacyEncoding::Latin1 => "latin-1",
+            LegacyEncoding::Codepage(enc) => enc.name(),
+            LegacyEncoding::Auto => "auto",
+        }
+    }
+
+    /// Decode legacy bytes. `Auto` decodes as latin-1; resolve it first.
+    pub fn decode(&self, data: &[u8]) -> String {
+        match self {
+            LegacyEncoding::Codepage(enc) => enc.decode_without_bom_handling(data).0.into_owned(),
+            _ => data.iter().map(|&b| b as char).collect(),
+        }
+    }
+}
+
+/// Guess windows-1251 or latin-1 from legacy text bytes. Cyrillic words are
+/// runs of three or more bytes above 0x7F, while accented Latin letters mostly
+/// stand alone or in pairs next to ASCII letters.
+pub fn guess_legacy_encoding<'a>(samples: impl IntoIterator<Item = &'a [u8]>) -> LegacyEncoding {
+    let (mut cyrillic, mut latin) = (0usize, 0usize);
+    for s in samples {
+        let mut i = 0;
+        while i < s.len() {
+            if s[i] < 0x80 {
+                i += 1;
+                continue;
+            }
+            let start = i;
+            while i < s.len() && s[i] >= 0x80 {
+                i += 1;
+            }
+            let run = i - start;
+            let touches_ascii = (start > 0 && s[start - 1].is_ascii_alphabetic())
+                || s.get(i).is_some_and(|b| b.is_ascii_alphabetic());
+            if run >= 3 {
+                cyrillic += run;
+            } else if touches_ascii {
+                latin += run;
+            }
+        }
+    }
+    if cyrillic > latin {
+        LegacyEncoding::Codepage(encoding_rs::WINDOWS_1251)
+    } else {
+        LegacyEncoding::Latin1
+    }
+}
+
 /// Picture type enum matching ID3v2 APIC frame specification.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
@@ -329,3 +413,45 @@ pub fn parse_genre(text: &str) -> Vec<String> {
 
     genres
 }
+
+/// Genre names of one TCON text value, as mutagen's `TCON.genres` reads them:
+/// a bare number or leading `(N)`/`(RX)`/`(CR)` references are looked up (out
+/// of range is "Unknown"), and text after the references is kept unless it
+/// repeats one of them. A leading `((` escapes a literal parenthesis.
+pub fn tcon_genres(value: &str) -> Vec<String> {
+    let mut genres = Vec::new();
+    if value.is_empty() {
+        return genres;
+    }
+    let lookup = |id: &str| match id {
+        "RX" => "Remix".to_string(),
+        "CR" => "Cover".to_string(),
+        _ => match id.parse::<usize>() {
+            Ok(n) if n < GENRES.len() => GENRES[n].to_string(),
+            _ => "Unknown".to_string(),
+        },
+    };
+    if value.bytes().all(|b| b.is_ascii_digit()) || value == "RX" || value == "CR" {
+        genres.push(lookup(value));
+        return genres;
+    }
+
+    let mut rest = value;
+    while let Some(inner) = rest.strip_prefix('(') {
+        let Some(close) = inner.find(')') else { break };
+        let id = &inner[..close];
+        let is_ref = id == "RX" || id == "CR" || (!id.is_empty() && id.bytes().all(|b| b.is_ascii_digit()));
+        if !is_ref {
+            break;
+        }
+        genres.push(lookup(id));
+        rest = &inner[close + 1..];
+    }
+    if !rest.is_empty() {
+        let name = if rest.starts_with("((") { &rest[1..] } else { rest };
+        if !genres.iter().any(|g| g == name) {
+            genres.push(name.to_string());
+        }
+    }
+    genres
+}
@@ -1,7 +1,8 @@
 use crate::common::error::{MutagenError, Result};
+use crate::common::util::key_matches_pattern;
 use crate::id3::header::{ID3Header, BitPaddedInt, determine_bpi};
 use crate::id3::frames::{self, Frame, HashKey, convert_v22_frame_id, parse_v22_picture_frame};
-use crate::id3::specs;
+use crate::id3::specs::{self, LegacyEncoding};
 use crate::id3::unsynch;
 
 /// A lazy frame that stores raw data and decodes on first access.
@@ -93,6 +94,10 @@ pub struct ID3Tags {
     pub version: (u8, u8),
     pub unknown_frames: Vec<(String, Vec<u8>)>,
     pub(crate) raw_buf: Vec<u8>,
+    /// Start and length (header + payload) of each parsed frame within `raw_buf`.
+    pub frame_spans: Vec<(HashKey, u32, u32)>,
+    /// Complete frames from `set_raw`, written unchanged after the rendered frames.
+    pub verbatim: Vec<(HashKey, Vec<u8>)>,
 }
 
 impl Default for ID3Tags {
@@ -108,6 +113,8 @@ impl ID3Tags {
             version: (4, 0),
             unknown_frames: Vec::new(),
             raw_buf: Vec::new(),
+            frame_spans: Vec::new(),
+            verbatim: Vec::new(),
         }
     }
 
@@ -185,6 +192,218 @@ impl ID3Tags {
     pub fn delall(&mut self, key: &str) {
         let hash_key = HashKey::new(key);
         self.frames.retain(|(k, _)| k != &hash_key);
+        self.verbatim.retain(|(k, _)| k != &hash_key);
+    }
+
+    /// Remove and return the first frame stored under `key`, decoding it.
+    fn pop(&mut self, key: &str) -> Option<Frame> {
+        let frame = self.get_mut(key).cloned();
+        self.delall(key);
+        frame
+    }
+
+    /// Text of the first `key` frame, removing every frame under `key`.
+    fn pop_text(&mut self, key: &str) -> Vec<String> {
+        match self.pop(key) {
+            Some(Frame::Text(f)) => f.text,
+            _ => Vec::new(),
+        }
+    }
+
+    fn add_text(&mut self, id: &str, encoding: specs::Encoding, text: Vec<String>) {
+        self.add(Frame::Text(frames::TextFrame { id: id.to_string(), encoding, text }));
+    }
+
+    /// Convert ID3v2.3 frames to their ID3v2.4 forms, like mutagen's
+    /// `update_to_v24`: TYER/TDAT/TIME become TDRC, TORY becomes TDOR, IPLS
+    /// becomes TIPL, and frames with no v2.4 equivalent are dropped.
+    pub fn update_to_v24(&mut self) {
+        let years = self.pop_text("TYER");
+        let dates = self.pop_text("TDAT");
+        let times = self.pop_text("TIME");
+        let n = years.len().max(dates.len()).max(times.len());
+        let field = |v: &[String], i: usize| v.get(i).map(|s| s.trim().to_string()).unwrap_or_default();
+        let digits = |s: &str, len: Option<usize>| {
+            !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) && len.is_none_or(|l| s.len() == l)
+        };
+        let mut timestamps = Vec::new();
+        for i in 0..n {
+            let (y, d, t) = (field(&years, i), field(&dates, i), field(&times, i));
+            let mut stamp = String::new();
+            if digits(&y, None) {
+                stamp.push_str(&y);
+            }
+            if digits(&d, Some(4)) {
+                stamp.push_str(&format!("-{}-{}", &d[2..4], &d[0..2]));
+            }
+            if digits(&t, Some(4)) {
+                stamp.push_str(&format!("T{}:{}:00", &t[0..2], &t[2..4]));
+            }
+            if !stamp.is_empty() {
+                timestamps.push(stamp);
+            }
+        }
+        if !timestamps.is_empty() && self.get_mut("TDRC").is_none() {
+            self.add_text("TDRC", specs::Encoding::Latin1, timestamps);
+        }
+
+        if let Some(Frame::Text(f)) = self.pop("TORY") {
+            if self.get_mut("TDOR").is_none() {
+                self.add_text("TDOR", specs::Encoding::Latin1, vec![f.text.join("/")]);
+            }
+        }
+        if let Some(Frame::PairedText(f)) = self.pop("IPLS") {
+            if self.get_mut("TIPL").is_none() {
+                self.add(Frame::PairedText(frames::PairedTextFrame { id: "TIPL".into(), ..f }));
+            }
+        }
+        self.strip(&V24_DROPPED);
+    }
+
+    /// Convert ID3v2.4 frames to their ID3v2.3 forms, like mutagen's
+    /// `update_to_v23`: TDRC becomes TYER/TDAT/TIME, TDOR becomes TORY,
+    /// TIPL/TMCL become IPLS, v2.4-only frames are dropped and multi-value
+    /// text frames are joined with "/".
+    pub fn update_to_v23(&mut self) {
+        let mut people = Vec::new();
+        let mut encoding = specs::Encoding::Utf16;
+        for key in ["TIPL", "TMCL"] {
+            if let Some(Frame::PairedText(f)) = self.pop(key) {
+                encoding = f.encoding;
+                people.extend(f.people);
+            }
+        }
+        if !people.is_empty() && self.get_mut("IPLS").is_none() {
+            self.add(Frame::PairedText(frames::PairedTextFrame { id: "IPLS".into(), encoding, people }));
+        }
+
+        // TDOR and TDRC are the current dates; the v2.3 frames loaded with
+        // the tag are stale once they are set
+        if let Some(Frame::Text(f)) = self.pop("TDOR") {
+            self.pop("TORY");
+            let year = f.text.first().and_then(|d| d.get(..4)).filter(|y| y.bytes().all(|b| b.is_ascii_digit()));
+            if let Some(year) = year {
+                self.add_text("TORY", f.encoding, vec![year.to_string()]);
+            }
+        }
+        if let Some(Frame::Text(f)) = self.pop("TDRC") {
+            for key in ["TYER", "TDAT", "TIME"] {
+                self.pop(key);
+            }
+            if let Some(stamp) = f.text.first() {
+                // yyyy[-MM[-dd[THH[:mm[:ss]]]]]
+                let part = |range: std::ops::Range<usize>| {
+                    stamp.get(range).filter(|p| p.bytes().all(|b| b.is_ascii_digit()))
+                };
+                if let Some(year) = part(0..4) {
+                    self.add_text("TYER", f.encoding, vec![year.to_string()]);
+                }
+                if let (Some(month), Some(day)) = (part(5..7), part(8..10)) {
+                    self.add_text("TDAT", f.encoding, vec![format!("{}{}", day, month)]);
+                }
+                if let (Some(hour), Some(minute)) = (part(11..13), part(14..16)) {
+                    self.add_text("TIME", f.encoding, vec![format!("{}{}", hour, minute)]);
+                }
+            }
+        }
+
+        // strip() also catches qualified keys such as RVA2:track
+        self.strip(&[
+            "ASPI", "EQU2", "RVA2", "SEEK", "SIGN", "TDEN", "TDOR", "TDRC", "TDRL",
+            "TDTG", "TIPL", "TMCL", "TMOO", "TPRO", "TSOA", "TSOP", "TSOT", "TSST",
+        ]);
+
+        // v2.3 has no multi-value text frames
+        for (_, frames) in self.frames.iter_mut() {
+            for lf in frames.iter_mut() {
+                if let Ok(Frame::Text(_)) = lf.decode_with_buf(&self.raw_buf) {
+                    if let LazyFrame::Decoded(Frame::Text(f)) = lf {
+                        if f.text.len() > 1 {
+                            f.text = vec![f.text.join("/")];
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Delete every frame whose key matches one of `patterns` (see
+    /// `key_matches_pattern`). Returns the removed keys.
+    pub fn strip(&mut self, patterns: &[&str]) -> Vec<String> {
+        let matches = |key: &str| patterns.iter().any(|p| key_matches_pattern(key, p, false));
+        let mut removed: Vec<String> = Vec::new();
+        for key in self.frames.iter().map(|(k, _)| k).chain(self.verbatim.iter().map(|(k, _)| k)) {
+            if matches(key.as_str()) && !removed.iter().any(|r| r == key.as_str()) {
+                removed.push(key.as_str().to_string());
+            }
+        }
+        self.frames.retain(|(k, _)| !matches(k.as_str()));
+        self.verbatim.retain(|(k, _)| !matches(k.as_str()));
+        self.unknown_frames.retain(|(id, _)| !matches(id));
+        removed
+    }
+
+    /// The bytes of the first frame stored under `key` (header + payload), exactly as
+    /// parsed (after whole-tag unsynchronisation is undone), or as given to `set_raw`.
+    pub fn get_raw(&self, key: &str) -> Option<&[u8]> {
+        let hash_key = HashKey::new(key);
+        if let Some((_, data)) = self.verbatim.iter().find(|(k, _)| k == &hash_key) {
+            return Some(data);
+        }
+        self.frame_spans.iter()
+            .find(|(k, _, _)| k == &hash_key)
+            .and_then(|&(_, start, len)| self.raw_buf.get(start as usize..(start + len) as usize))
+    }
+
+    /// Minimum offset from the end of this tag to the next one, from a v2.4 `SEEK`
+    /// frame. `SEEK` and `ASPI` are otherwise kept as binary frames and written back
+    /// as read.
+    pub fn seek_offset(&self) -> Option<u32> {
+        let hash_key = HashKey::new("SEEK");
+        let (_, frames) = self.frames.iter().find(|(k, _)| k == &hash_key)?;
+        let data: &[u8] = match frames.first()? {
+            LazyFrame::Decoded(Frame::Binary(f)) => &f.data,
+            LazyFrame::Decoded(_) => return None,
+            LazyFrame::Raw { data, .. } => data,
+            LazyFrame::Slice { offset, len, .. } => {
+                self.raw_buf.get(*offset as usize..(*offset + *len) as usize)?
+            }
+        };
+        Some(u32::from_be_bytes(data.get(..4)?.try_into().ok()?))
+    }
+
+    /// Replace all frames under `key` with a complete pre-rendered frame that is written
+    /// verbatim on save. Only the header is checked: the frame ID must match `key` and
+    /// the size field (encoded for `version`) must match the payload length.
+    pub fn set_raw(&mut self, key: &str, frame: Vec<u8>, version: u8) -> Result<()> {
+        if frame.len() <= 10 {
+            return Err(MutagenError::ID3("Raw frame must be a 10-byte header plus payload".into()));
+        }
+        let id = &frame[0..4];
+        if !id.iter().all(|&b| b.is_ascii_uppercase() || b.is_ascii_digit()) {
+            return Err(MutagenError::ID3(format!("Invalid frame ID in raw frame: {:?}", id)));
+        }
+        let key_id = key.split(':').next().unwrap_or(key);
+        if key_id.as_bytes() != id {
+            return Err(MutagenError::ID3(format!(
+                "Raw frame ID {} does not match key {}", String::from_utf8_lossy(id), key
+            )));
+        }
+        let size = if version == 4 {
+            BitPaddedInt::syncsafe(&frame[4..8]) as usize
+        } else {
+            u32::from_be_bytes([frame[4], frame[5], frame[6], frame[7]]) as usize
+        };
+        if size != frame.len() - 10 {
+            return Err(MutagenError::ID3(format!(
+                "Raw frame size field {} does not match payload length {}", size, frame.len() - 10
+            )));
+        }
+        let hash_key = HashKey::new(key);
+        self.frames.retain(|(k, _)| k != &hash_key);
+        self.verbatim.retain(|(k, _)| k != &hash_key);
+        self.verbatim.push((hash_key, frame));
+        Ok(())
     }
 
     /// Get all keys.
@@ -209,6 +428,61 @@ impl ID3Tags {
         self.values()
     }
 
+    /// Re-decode "latin-1" text (encoding-0 frames and ID3v1 fields) with a
+    /// legacy codepage. Only frames with non-ASCII text change; they switch to
+    /// UTF-8 so that a save stores them in a Unicode encoding. `Auto` is
+    /// resolved once from all such text. Returns the encoding used and the
+    /// (old, new) keys of the changed frames.
+    pub fn recode_latin1(&mut self, encoding: LegacyEncoding) -> (LegacyEncoding, Vec<(HashKey, HashKey)>) {
+        if encoding == LegacyEncoding::Latin1 {
+            return (encoding, Vec::new());
+        }
+        for (_, frames) in self.frames.iter_mut() {
+            for lf in frames.iter_mut() {
+                let _ = lf.decode_with_buf(&self.raw_buf);
+            }
+        }
+        let encoding = match encoding {
+            LegacyEncoding::Auto => {
+                let mut samples: Vec<Vec<u8>> = Vec::new();
+                for frame in self.values() {
+                    legacy_texts(frame, &mut |s| {
+                        if !s.is_ascii() {
+                            samples.push(s.chars().map(|c| c as u8).collect());
+                        }
+                    });
+                }
+                specs::guess_legacy_encoding(samples.iter().map(|s| s.as_slice()))
+            }
+            other => other,
+        };
+        if encoding == LegacyEncoding::Latin1 {
+            return (encoding, Vec::new());
+        }
+
+        let mut renamed: Vec<(HashKey, HashKey)> = Vec::new();
+        let mut frames: Vec<(HashKey, Vec<LazyFrame>)> = Vec::with_capacity(self.frames.len());
+        for (key, list) in std::mem::take(&mut self.frames) {
+            for mut lf in list {
+                let mut new_key = key.clone();
+                if let LazyFrame::Decoded(frame) = &mut lf {
+                    if recode_frame(frame, encoding) {
+                        new_key = frame.hash_key();
+                        if !renamed.iter().any(|(o, n)| o == &key && n == &new_key) {
+                            renamed.push((key.clone(), new_key.clone()));
+                        }
+                    }
+                }
+                match frames.iter_mut().find(|(k, _)| k == &new_key) {
+                    Some((_, v)) => v.push(lf),
+                    None => frames.push((new_key, vec![lf])),
+                }
+            }
+        }
+        self.frames = frames;
+        (encoding, renamed)
+    }
+
     /// Number of unique keys.
     pub fn len(&self) -> usize {
         self.frames.len()
@@ -223,6 +497,19 @@ impl ID3Tags {
         self.frames.iter().any(|(k, _)| k == key)
     }
 
+    /// Add ID3v1 frames for keys the ID3v2 tag lacks. A v2.3 frame that
+    /// becomes the same v2.4 key (TYER for TDRC) counts as present, so a
+    /// stale v1 year never shadows it.
+    pub fn merge_id3v1(&mut self, frames: Vec<Frame>) {
+        for frame in frames {
+            let key = frame.hash_key();
+            let present = self.frames.iter().any(|(k, _)| *k == key || v24_key(k.as_str()) == Some(key.as_str()));
+            if !present {
+                self.add(frame);
+            }
+        }
+    }
+
     /// Parse frames from raw tag data.
     pub fn read_frames(&mut self, data: &[u8], header: &ID3Header) -> Result<()> {
         let version = header.version.0;
@@ -294,6 +581,7 @@ impl ID3Tags {
                 | ((data[offset + 4] as usize) << 8)
                 | (data[offset + 5] as usize);
 
+            let frame_start = offset;
             offset += 6;
 
             if size == 0 {
@@ -305,10 +593,14 @@ impl ID3Tags {
 
             let frame_data = &data[offset..offset + size];
             offset += size;
+            let span = |key: HashKey| (key, frame_start as u32, (offset - frame_start) as u32);
 
             // Check for PIC frame directly on bytes (avoid String allocation)
             if id_bytes == b"PIC" {
-                if let Ok(frame) = parse_v22_picture_frame(frame_data) { self.add(frame) }
+                if let Ok(frame) = parse_v22_picture_frame(frame_data) {
+                    self.frame_spans.push(span(frame.hash_key()));
+                    self.add(frame)
+                }
                 continue;
             }
 
@@ -317,12 +609,14 @@ impl ID3Tags {
             let v24_id = match convert_v22_frame_id(id_str) {
                 Some(new_id) => new_id.to_string(),
                 None => {
+                    self.frame_spans.push(span(HashKey::new(id_str)));
                     self.unknown_frames.push((id_str.to_string(), frame_data.to_vec()));
                     continue;
                 }
             };
 
             // Store as lazy (raw) frame
+            self.frame_spans.push(span(quick_hash_key(&v24_id, frame_data)));
             self.add_raw(v24_id, frame_data.to_vec());
         }
 
@@ -363,6 +657,7 @@ impl ID3Tags {
             let size = BitPaddedInt::decode(&data[offset + 4..offset + 8], bpi) as usize;
             let flags = u16::from_be_bytes([data[offset + 8], data[offset + 9]]);
 
+            let frame_start = offset as u32;
             offset += 10;
 
             if size == 0 {
@@ -371,6 +666,7 @@ impl ID3Tags {
             if offset + size > data.len() {
                 break;
             }
+            let span_len = (size + 10) as u32;
 
             // Handle frame-level flags
             let (compressed, encrypted, unsynchronised, has_data_length) = if version == 4 {
@@ -400,6 +696,7 @@ impl ID3Tags {
                 let frame_len = size as u32;
                 // Compute hash key directly from raw data (no full parse)
                 let key = quick_hash_key(id_str, &data[offset..offset + size]);
+                self.frame_spans.push((key.clone(), frame_start, span_len));
                 let lazy = LazyFrame::Slice { id: id_arr, offset: frame_offset, len: frame_len };
                 if let Some((_, frames)) = self.frames.iter_mut().find(|(k, _)| k == &key) {
                     frames.push(lazy);
@@ -415,6 +712,7 @@ impl ID3Tags {
             offset += size;
 
             if encrypted {
+                self.frame_spans.push((HashKey::new(&id), frame_start, span_len));
                 self.unknown_frames.push((id, frame_data));
                 continue;
             }
@@ -431,6 +729,7 @@ impl ID3Tags {
                 match decompress_zlib(&frame_data) {
                     Ok(decompressed) => frame_data = decompressed,
                     Err(_) => {
+                        self.frame_spans.push((HashKey::new(&id), frame_start, span_len));
                         self.unknown_frames.push((id, frame_data));
                         continue;
                     }
@@ -438,6 +737,7 @@ impl ID3Tags {
             }
 
             // Store as lazy (raw) frame - don't decode until accessed
+            self.frame_spans.push((quick_hash_key(&id, &frame_data), frame_start, span_len));
             self.add_raw(id, frame_data);
         }
 
@@ -483,13 +783,146 @@ impl ID3Tags {
             }
         }
 
+        // Verbatim frames keep their bytes, but the size field is re-encoded for
+        // `version` (syncsafe in v2.4, plain in v2.3) in case the tag changed version
+        for (_, frame) in &self.verbatim {
+            let size = (frame.len() - 10) as u32;
+            data.extend_from_slice(&frame[..4]);
+            if version == 4 {
+                data.extend_from_slice(&BitPaddedInt::encode(size, 4, 7));
+            } else {
+                data.extend_from_slice(&size.to_be_bytes());
+            }
+            data.extend_from_slice(&frame[8..]);
+        }
+
         Ok(data)
     }
 }
 
+/// Whether a TYER value is a year `update_to_v24` keeps (digits only).
+pub fn is_year(text: &str) -> bool {
+    let text = text.trim();
+    !text.is_empty() && text.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Frames `update_to_v24` drops: no v2.4 equivalent, or folded into TDRC.
+const V24_DROPPED: [&str; 6] = ["RVAD", "EQUA", "TRDA", "TSIZ", "TDAT", "TIME"];
+
+/// Key a frame stored under `key` ends up with after `update_to_v24`, as
+/// mutagen reports it on load: TYER → TDRC, TORY → TDOR, IPLS → TIPL, `None`
+/// for dropped frames, anything else unchanged. Lets the fast readers match
+/// mutagen's keys without building `ID3Tags`.
+pub fn v24_key(key: &str) -> Option<&str> {
+    match key {
+        "TYER" => Some("TDRC"),
+        "TORY" => Some("TDOR"),
+        "IPLS" => Some("TIPL"),
+        k if V24_DROPPED.contains(&k) => None,
+        k => Some(k),
+    }
+}
+
+/// Call `f` with every text field of a latin-1 encoded frame.
+fn legacy_texts(frame: &Frame, f: &mut impl FnMut(&str)) {
+    match frame {
+        Frame::Text(t) if t.encoding == specs::Encoding::Latin1 => t.text.iter().for_each(|s| f(s)),
+        Frame::UserText(t) if t.encoding == specs::Encoding::Latin1 => {
+            f(&t.desc);
+            t.text.iter().for_each(|s| f(s));
+        }
+        Frame::Comment(c) if c.encoding == specs::Encoding::Latin1 => { f(&c.desc); f(&c.text); }
+        Frame::Lyrics(l) if l.encoding == specs::Encoding::Latin1 => { f(&l.desc); f(&l.text); }
+        Frame::SyncLyrics(l) if l.encoding == specs::Encoding::Latin1 => {
+            f(&l.desc);
+            l.sync.iter().for_each(|(s, _)| f(s));
+        }
+        Frame::UserUrl(u) if u.encoding == specs::Encoding::Latin1 => f(&u.desc),
+        Frame::Picture(p) if p.encoding == specs::Encoding::Latin1 => f(&p.desc),
+        Frame::Geob(g) if g.encoding == specs::Encoding::Latin1 => { f(&g.filename); f(&g.desc); }
+        Frame::PairedText(p) if p.encoding == specs::Encoding::Latin1 => {
+            for (a, b) in &p.people { f(a); f(b); }
+        }
+        _ => {}
+    }
+}
+
+/// Re-decode a latin-1 frame's non-ASCII text with `encoding`, switching the
+/// frame to UTF-8. Returns whether anything changed.
+fn recode_frame(frame: &mut Frame, encoding: LegacyEncoding) -> bool {
+    fn recode(s: &mut String, encoding: LegacyEncoding) -> bool {
+        if s.is_ascii() { return false; }
+        // latin-1 decoding maps each byte to the char of the same value
+        let bytes: Vec<u8> = s.chars().map(|c| c as u8).collect();
+        *s = encoding.decode(&bytes);
+        true
+    }
+    let mut changed = false;
+    let enc = match frame {
+        Frame::Text(t) => {
+            if t.encoding != specs::Encoding::Latin1 { return false; }
+            for s in t.text.iter_mut() { changed |= recode(s, encoding); }
+            &mut t.encoding
+        }
+        Frame::UserText(t) => {
+            if t.encoding != specs::Encoding::Latin1 { return false; }
+            changed |= recode(&mut t.desc, encoding);
+            for s in t.text.iter_mut() { changed |= recode(s, encoding); }
+            &mut t.encoding
+        }
+        Frame::Comment(c) => {
+            if c.encoding != specs::Encoding::Latin1 { return false; }
+            changed |= recode(&mut c.desc, encoding);
+            changed |= recode(&mut c.text, encoding);
+            &mut c.encoding
+        }
+        Frame::Lyrics(l) => {
+            if l.encoding != specs::Encoding::Latin1 { return false; }
+            changed |= recode(&mut l.desc, encoding);
+            changed |= recode(&mut l.text, encoding);
+            &mut l.encoding
+        }
+        Frame::SyncLyrics(l) => {
+            if l.encoding != specs::Encoding::Latin1 { return false; }
+            changed |= recode(&mut l.desc, encoding);
+            for (s, _) in l.sync.iter_mut() { changed |= recode(s, encoding); }
+            &mut l.encoding
+        }
+        Frame::UserUrl(u) => {
+            if u.encoding != specs::Encoding::Latin1 { return false; }
+            changed |= recode(&mut u.desc, encoding);
+            &mut u.encoding
+        }
+        Frame::Picture(p) => {
+            if p.encoding != specs::Encoding::Latin1 { return false; }
+            changed |= recode(&mut p.desc, encoding);
+            &mut p.encoding
+        }
+        Frame::Geob(g) => {
+            if g.encoding != specs::Encoding::Latin1 { return false; }
+            changed |= recode(&mut g.filename, encoding);
+            changed |= recode(&mut g.desc, encoding);
+            &mut g.encoding
+        }
+        Frame::PairedText(p) => {
+            if p.encoding != specs::Encoding::Latin1 { return false; }
+            for (a, b) in p.people.iter_mut() {
+                changed |= recode(a, encoding);
+                changed |= recode(b, encoding);
+            }
+            &mut p.encoding
+        }
+        _ => return false,
+    };
+    if changed {
+        *enc = specs::Encoding::Utf8;
+    }
+    changed
+}
+
 /// Extract hash key from raw frame bytes without full frame parsing.
-/// For special frames (TXXX, WXXX, COMM, USLT, APIC, POPM), reads only
-/// the description/email header bytes to build the key. Avoids copying
+/// For special frames (TXXX, WXXX, COMM, USLT, APIC, POPM, and the binary
+/// ones in `binary_hash_key`), reads only the header bytes that build the key. Avoids copying
 /// large frame data (critical for APIC picture frames which can be 200KB+).
 #[inline]
 fn quick_hash_key(id: &str, data: &[u8]) -> HashKey {
@@ -536,7 +969,7 @@ fn quick_hash_key(id: &str, data: &[u8]) -> HashKey {
             }
             HashKey::new("POPM")
         }
-        _ => HashKey::new(id),
+        _ => frames::binary_hash_key(id, data),
     }
 }
 
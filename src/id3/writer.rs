@@ -2,13 +2,18 @@ use crate::common::error::Result;
 use crate::id3::header::BitPaddedInt;
 use crate::id3::tags::ID3Tags;
 
+/// Padding added after the frames when a tag is written from scratch (like mutagen).
+pub const DEFAULT_PADDING: usize = 1024;
+
 /// Build a complete ID3v2 tag from frames, ready to write to file.
 /// Returns the full tag data including header.
 pub fn render_tag(tags: &ID3Tags, version: u8) -> Result<Vec<u8>> {
     let frame_data = tags.render(version)?;
+    Ok(build_tag(&frame_data, version, DEFAULT_PADDING))
+}
 
-    // Add padding (1024 bytes default, like mutagen)
-    let padding = 1024usize;
+/// Wrap already-rendered frame data in an ID3v2 header followed by `padding` zero bytes.
+pub fn build_tag(frame_data: &[u8], version: u8, padding: usize) -> Vec<u8> {
     let total_size = frame_data.len() + padding;
 
     let mut tag = Vec::with_capacity(10 + total_size);
@@ -25,10 +30,10 @@ pub fn render_tag(tags: &ID3Tags, version: u8) -> Result<Vec<u8>> {
     tag.extend_from_slice(&BitPaddedInt::encode(total_size as u32, 4, 7));
 
     // Frame data
-    tag.extend_from_slice(&frame_data);
+    tag.extend_from_slice(frame_data);
 
     // Padding
     tag.extend(std::iter::repeat_n(0u8, padding));
 
-    Ok(tag)
+    tag
 }
@@ -5,11 +5,17 @@ pub mod flac;
 pub mod ogg;
 pub mod mp4;
 pub mod vorbis;
+pub mod caf;
+pub mod ac3;
+pub mod dts;
+pub mod ape;
+pub mod midi;
+pub mod wavpack;
 
 #[cfg(feature = "python")]
 use std::sync::{Arc, RwLock, OnceLock};
 #[cfg(feature = "python")]
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 #[cfg(feature = "python")]
 static FILE_CACHE: OnceLock<RwLock<HashMap<String, Arc<[u8]>>>> = OnceLock::new();
@@ -48,7 +54,7 @@ fn read_cached(path: &str) -> std::io::Result<Arc<[u8]>> {
 fn fast_file_read(path: &str) -> std::io::Result<Vec<u8>> {
     #[cfg(unix)]
     {
-        fast_file_read_unix(path)
+        fast_file_read_unix(path, None)
     }
     #[cfg(not(unix))]
     {
@@ -56,8 +62,363 @@ fn fast_file_read(path: &str) -> std::io::Result<Vec<u8>> {
     }
 }
 
+/// Like `fast_file_read`, but a large MP3 or MP4 comes back as a sparse buffer
+/// holding only the ranges its parsers read (see `RangeRead`).
+#[cfg(feature = "python")]
+#[inline]
+fn fast_file_read_ranged(path: &str, ext: &str) -> std::io::Result<Vec<u8>> {
+    fast_file_read_with(path, RangeRead::for_ext(ext))
+}
+
+/// Like `fast_file_read_ranged`, but a large FLAC, Ogg or raw AC-3/DTS
+/// file is also trimmed to what the info-only parsers read (see
+/// `RangeRead::for_info_ext`).
+#[cfg(feature = "python")]
+#[inline]
+fn fast_file_read_info(path: &str, ext: &str) -> std::io::Result<Vec<u8>> {
+    fast_file_read_with(path, RangeRead::for_info_ext(ext))
+}
+
+/// Like `fast_file_read_ranged`, but a large FLAC, Ogg, Monkey's Audio or
+/// WavPack file is also trimmed to what the tag probes read (see
+/// `RangeRead::for_tags_ext`).
+#[cfg(feature = "python")]
+#[inline]
+fn fast_file_read_tags(path: &str, ext: &str) -> std::io::Result<Vec<u8>> {
+    fast_file_read_with(path, RangeRead::for_tags_ext(ext))
+}
+
+#[cfg(feature = "python")]
+fn fast_file_read_with(path: &str, ranges: Option<RangeRead>) -> std::io::Result<Vec<u8>> {
+    let Some(ranges) = ranges else {
+        return fast_file_read(path);
+    };
+    #[cfg(unix)]
+    {
+        fast_file_read_unix(path, Some(ranges))
+    }
+    #[cfg(not(unix))]
+    {
+        use std::io::{Read, Seek, SeekFrom};
+        let mut file = std::fs::File::open(path)?;
+        let total = file.metadata()?.len() as usize;
+        if total <= RANGE_READ_MIN {
+            let mut data = Vec::with_capacity(total);
+            file.read_to_end(&mut data)?;
+            return Ok(data);
+        }
+        ranges.read(&[], total, |buf, off| {
+            file.seek(SeekFrom::Start(off as u64))?;
+            file.read_exact(buf)
+        })
+    }
+}
+
+/// MP3 and MP4 files above this size are read by range rather than whole.
+#[cfg(feature = "python")]
+const RANGE_READ_MIN: usize = 256 * 1024;
+
+/// Formats whose parsers only look at a few known ranges of a large file.
+#[cfg(feature = "python")]
+#[derive(Clone, Copy)]
+enum RangeRead {
+    /// ID3v2 tag, MPEG probe window, ID3v1 trailer and APEv2 tag (`mp3_sparse_read`).
+    Mp3,
+    /// Top-level atom headers, `ftyp` and `moov`, never `mdat` (`mp4::sparse_read`).
+    Mp4,
+    /// Info only: the metadata block headers up to and including STREAMINFO
+    /// (`flac_info_sparse_read`).
+    FlacInfo,
+    /// Info only: the identification header at the start and the last page's
+    /// granule position at the end (`ogg_info_sparse_read`).
+    OggInfo,
+    /// Info only: the first 64 KB, where raw AC-3/E-AC-3/DTS streams have their
+    /// first sync frame; the length comes from the size (`head_sparse_read`).
+    HeadInfo,
+    /// Tags only: the metadata block headers and the VORBIS_COMMENT body
+    /// (`flac_tags_sparse_read`).
+    FlacTags,
+    /// Tags only: the pages up to the end of the comment header
+    /// (`ogg_tags_sparse_read`).
+    OggTags,
+}
+
+#[cfg(feature = "python")]
+impl RangeRead {
+    fn for_ext(ext: &str) -> Option<Self> {
+        if ext.eq_ignore_ascii_case("mp3") {
+            Some(RangeRead::Mp3)
+        } else if mp4::is_mp4_extension(ext) {
+            Some(RangeRead::Mp4)
+        } else {
+            None
+        }
+    }
+
+    /// Ranges for `_fast_info`, which never looks at tags past the stream header.
+    fn for_info_ext(ext: &str) -> Option<Self> {
+        if ext.eq_ignore_ascii_case("flac") {
+            Some(RangeRead::FlacInfo)
+        } else if ext.eq_ignore_ascii_case("ogg") || ext.eq_ignore_ascii_case("opus") {
+            Some(RangeRead::OggInfo)
+        } else if ext.eq_ignore_ascii_case("ac3") || ext.eq_ignore_ascii_case("eac3")
+            || ext.eq_ignore_ascii_case("dts") {
+            Some(RangeRead::HeadInfo)
+        } else {
+            Self::for_ext(ext)
+        }
+    }
+
+    /// Ranges for the tag probes (`has_tags`, `tag_summary`), which look at tag
+    /// headers and counts only. APEv2-tagged formats take the MP3 ranges, whose
+    /// tail covers the APEv2 tag.
+    fn for_tags_ext(ext: &str) -> Option<Self> {
+        if ext.eq_ignore_ascii_case("flac") {
+            Some(RangeRead::FlacTags)
+        } else if ext.eq_ignore_ascii_case("ogg") || ext.eq_ignore_ascii_case("oga")
+            || ext.eq_ignore_ascii_case("opus") {
+            Some(RangeRead::OggTags)
+        } else if ext.eq_ignore_ascii_case("ape") || ext.eq_ignore_ascii_case("wv") {
+            Some(RangeRead::Mp3)
+        } else {
+            Self::for_ext(ext)
+        }
+    }
+
+    fn read(
+        self,
+        head: &[u8],
+        total: usize,
+        read_at: impl FnMut(&mut [u8], usize) -> std::io::Result<()>,
+    ) -> std::io::Result<Vec<u8>> {
+        match self {
+            RangeRead::Mp3 => mp3_sparse_read(head, total, read_at),
+            RangeRead::Mp4 => mp4::sparse_read(head, total, read_at),
+            RangeRead::FlacInfo => flac_info_sparse_read(head, total, read_at),
+            RangeRead::OggInfo => ogg_info_sparse_read(head, total, read_at),
+            RangeRead::HeadInfo => head_sparse_read(head, total, read_at),
+            RangeRead::FlacTags => flac_tags_sparse_read(head, total, read_at),
+            RangeRead::OggTags => ogg_tags_sparse_read(head, total, read_at),
+        }
+    }
+}
+
+/// Build a `total`-byte buffer for `fast_info_flac`: the optional ID3v2 header,
+/// then each metadata block header from `fLaC` on until STREAMINFO, whose body
+/// is read too. Other block bodies (comments, pictures, padding) stay zero.
+#[cfg(feature = "python")]
+fn flac_info_sparse_read(
+    head: &[u8],
+    total: usize,
+    read_at: impl FnMut(&mut [u8], usize) -> std::io::Result<()>,
+) -> std::io::Result<Vec<u8>> {
+    flac_sparse_read(head, total, read_at, 0, true)
+}
+
+/// Build a `total`-byte buffer for `probe_flac`: the optional ID3v2 header,
+/// every metadata block header and the VORBIS_COMMENT body. Pictures, padding
+/// and the audio stay zero.
+#[cfg(feature = "python")]
+fn flac_tags_sparse_read(
+    head: &[u8],
+    total: usize,
+    read_at: impl FnMut(&mut [u8], usize) -> std::io::Result<()>,
+) -> std::io::Result<Vec<u8>> {
+    flac_sparse_read(head, total, read_at, 4, false)
+}
+
+/// Walk the FLAC metadata block headers, reading the body of blocks of type
+/// `body_type` and stopping after the first one if `stop_at_body`.
+#[cfg(feature = "python")]
+fn flac_sparse_read(
+    head: &[u8],
+    total: usize,
+    mut read_at: impl FnMut(&mut [u8], usize) -> std::io::Result<()>,
+    body_type: u8,
+    stop_at_body: bool,
+) -> std::io::Result<Vec<u8>> {
+    let mut data = vec![0u8; total];
+    let have = head.len().min(total);
+    data[..have].copy_from_slice(&head[..have]);
+    let mut fill = |data: &mut [u8], start: usize, end: usize| -> std::io::Result<()> {
+        let (start, end) = (start.max(have), end.min(total));
+        if start < end { read_at(&mut data[start..end], start) } else { Ok(()) }
+    };
+    fill(&mut data, 0, 10)?;
+    let mut pos = if total >= 10 && &data[0..3] == b"ID3" {
+        10 + id3::header::BitPaddedInt::syncsafe(&data[6..10]) as usize
+    } else {
+        0
+    };
+    fill(&mut data, pos, pos + 4)?;
+    pos += 4;
+    while pos + 4 <= total {
+        fill(&mut data, pos, pos + 4)?;
+        let header = data[pos];
+        let size = ((data[pos + 1] as usize) << 16) | ((data[pos + 2] as usize) << 8) | data[pos + 3] as usize;
+        pos += 4;
+        if header & 0x7F == body_type {
+            fill(&mut data, pos, pos + size)?;
+            if stop_at_body {
+                break;
+            }
+        }
+        if header & 0x80 != 0 {
+            break;
+        }
+        pos += size;
+    }
+    Ok(data)
+}
+
+/// Build a `total`-byte buffer for `probe_ogg`/`probe_opus`: the pages from the
+/// start of the stream through the one that ends the second packet, the
+/// comment header. Audio pages stay zero.
+#[cfg(feature = "python")]
+fn ogg_tags_sparse_read(
+    head: &[u8],
+    total: usize,
+    mut read_at: impl FnMut(&mut [u8], usize) -> std::io::Result<()>,
+) -> std::io::Result<Vec<u8>> {
+    let mut data = vec![0u8; total];
+    let have = head.len().min(total);
+    data[..have].copy_from_slice(&head[..have]);
+    let mut fill = |data: &mut [u8], start: usize, end: usize| -> std::io::Result<()> {
+        let (start, end) = (start.max(have), end.min(total));
+        if start < end { read_at(&mut data[start..end], start) } else { Ok(()) }
+    };
+    let (mut pos, mut packets) = (0, 0);
+    while packets < 2 && pos + 27 <= total {
+        fill(&mut data, pos, pos + 27)?;
+        if &data[pos..pos + 4] != b"OggS" {
+            break;
+        }
+        let table_start = pos + 27;
+        let table_end = table_start + data[pos + 26] as usize;
+        fill(&mut data, table_start, table_end)?;
+        let Some(table) = data.get(table_start..table_end) else { break };
+        let body: usize = table.iter().map(|&s| s as usize).sum();
+        // A lacing value below 255 ends a packet
+        packets += table.iter().filter(|&&s| s < 255).count();
+        fill(&mut data, table_end, table_end + body)?;
+        pos = table_end + body;
+    }
+    Ok(data)
+}
+
+/// Build a `total`-byte buffer for `fast_info_ogg`: `head` (which holds the
+/// identification header) and the last 64 KB, the window `ogg::find_last_granule`
+/// searches for the final page.
+#[cfg(feature = "python")]
+fn ogg_info_sparse_read(
+    head: &[u8],
+    total: usize,
+    mut read_at: impl FnMut(&mut [u8], usize) -> std::io::Result<()>,
+) -> std::io::Result<Vec<u8>> {
+    let mut data = vec![0u8; total];
+    let have = head.len().min(total);
+    data[..have].copy_from_slice(&head[..have]);
+    let start = total.saturating_sub(65536).max(have);
+    if start < total {
+        read_at(&mut data[start..total], start)?;
+    }
+    Ok(data)
+}
+
+/// Build a `total`-byte buffer holding only the first 64 KB, for the raw
+/// AC-3/DTS parsers that read the first sync frame and take `total` as the
+/// stream size.
+#[cfg(feature = "python")]
+fn head_sparse_read(
+    head: &[u8],
+    total: usize,
+    mut read_at: impl FnMut(&mut [u8], usize) -> std::io::Result<()>,
+) -> std::io::Result<Vec<u8>> {
+    let mut data = vec![0u8; total];
+    let have = head.len().min(total);
+    data[..have].copy_from_slice(&head[..have]);
+    let end = total.min(65536);
+    if have < end {
+        read_at(&mut data[have..end], have)?;
+    }
+    Ok(data)
+}
+
+/// Build a `total`-byte buffer holding only what the MP3 parsers read: the 10-byte
+/// ID3v2 header and the tag behind it, the 8 KB MPEG probe window after the tag,
+/// the 128-byte ID3v1 trailer and an APEv2 tag before it. `head` holds the
+/// file's first bytes (may be empty); anything past it comes from
+/// `read_at(buf, offset)`. The rest stays zero, and being a fresh zeroed
+/// allocation, is never paged in.
+#[cfg(feature = "python")]
+fn mp3_sparse_read(
+    head: &[u8],
+    total: usize,
+    mut read_at: impl FnMut(&mut [u8], usize) -> std::io::Result<()>,
+) -> std::io::Result<Vec<u8>> {
+    let mut data = vec![0u8; total];
+    let mut have = head.len().min(total);
+    data[..have].copy_from_slice(&head[..have]);
+    if have < 10 && total >= 10 {
+        read_at(&mut data[have..10], have)?;
+        have = 10;
+    }
+    let audio_start = match id3::header::ID3Header::parse(&data[..have.min(10)], 0) {
+        Ok(h) if 10 + h.size as usize <= total => (h.full_size() as usize).min(total),
+        _ => 0,
+    };
+    let ranges = [
+        (0, audio_start),
+        (audio_start, (audio_start + 8192).min(total)),
+    ];
+    for (start, end) in ranges {
+        let start = start.max(have);
+        if start < end {
+            read_at(&mut data[start..end], start)?;
+        }
+    }
+    // The ID3v1 trailer, and any Lyrics3v2 or appended ID3v2 tag: each ends
+    // with its size, so read back one at a time to where an APEv2 footer sits
+    let mut end = total;
+    loop {
+        let start = end.saturating_sub(128 + 32).max(have);
+        if start < end {
+            read_at(&mut data[start..end], start)?;
+        }
+        let next = ape::apev2::tag_end(&data);
+        if next >= end {
+            break;
+        }
+        end = next;
+    }
+    if let Some(footer_at) = ape::apev2::find_footer(&data) {
+        // The footer's size field counts the items and the footer, not the header
+        let size = u32::from_le_bytes(data[footer_at + 12..footer_at + 16].try_into().unwrap()) as usize;
+        let start = (footer_at + 32).saturating_sub(size).saturating_sub(32).max(have);
+        if start < footer_at {
+            read_at(&mut data[start..footer_at], start)?;
+        }
+    }
+    Ok(data)
+}
+
 #[cfg(all(feature = "python", unix))]
-fn fast_file_read_unix(path: &str) -> std::io::Result<Vec<u8>> {
+fn pread_exact(fd: i32, buf: &mut [u8], offset: usize) -> std::io::Result<()> {
+    let mut done = 0;
+    while done < buf.len() {
+        let n = unsafe {
+            libc::pread(fd, buf[done..].as_mut_ptr() as *mut libc::c_void,
+                buf.len() - done, (offset + done) as libc::off_t)
+        };
+        if n < 0 { return Err(std::io::Error::last_os_error()); }
+        if n == 0 { return Err(std::io::ErrorKind::UnexpectedEof.into()); }
+        done += n as usize;
+    }
+    Ok(())
+}
+
+#[cfg(all(feature = "python", unix))]
+fn fast_file_read_unix(path: &str, ranges: Option<RangeRead>) -> std::io::Result<Vec<u8>> {
     use std::io;
 
     // Null-terminate path for libc (avoid heap alloc for typical paths < 256 bytes)
@@ -123,6 +484,11 @@ fn fast_file_read_unix(path: &str) -> std::io::Result<Vec<u8>> {
                 }
                 stat.st_size as usize
             };
+            if let Some(ranges) = ranges {
+                let out = ranges.read(&buf[..n], total_size, |b, off| pread_exact(fd, b, off));
+                unsafe { libc::close(fd); }
+                return out;
+            }
             let mut out = Vec::with_capacity(total_size);
             out.extend_from_slice(&buf[..n]);
             // Read remainder
@@ -148,6 +514,8 @@ use super::*;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyBytes, PyTuple};
 use pyo3::exceptions::{PyValueError, PyKeyError, PyIOError};
+use crate::common::shared::SharedBytes;
+use crate::common::parse::ParsedFile;
 
 // ---- Python Classes ----
 
@@ -182,6 +550,15 @@ impl PyPOPM {
 struct PyMPEGInfo {
     #[pyo3(get)]
     length: f64,
+    /// Xing/VBRI frame count × samples per frame, else estimated from `length`.
+    #[pyo3(get)]
+    length_samples: u64,
+    /// Length in whole milliseconds, computed without going through `length`.
+    #[pyo3(get)]
+    duration_ms: u64,
+    /// Total frame count from the Xing/Info or VBRI header; `None` without one.
+    #[pyo3(get)]
+    xing_frames: Option<u32>,
     #[pyo3(get)]
     channels: u32,
     #[pyo3(get)]
@@ -210,8 +587,32 @@ struct PyMPEGInfo {
     album_gain: Option<f32>,
 }
 
+impl PyMPEGInfo {
+    fn frame_ms(&self) -> f64 {
+        mp3::frame_ms(self.version, self.layer, self.sample_rate)
+    }
+}
+
 #[pymethods]
 impl PyMPEGInfo {
+    /// `bitrate_mode` is VBR (2).
+    #[getter]
+    fn is_vbr(&self) -> bool {
+        self.bitrate_mode == 2
+    }
+
+    /// `bitrate_mode` is CBR (1).
+    #[getter]
+    fn is_cbr(&self) -> bool {
+        self.bitrate_mode == 1
+    }
+
+    /// `bitrate_mode` is ABR (3).
+    #[getter]
+    fn is_abr(&self) -> bool {
+        self.bitrate_mode == 3
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "MPEGInfo(length={:.2}, bitrate={}, sample_rate={}, channels={}, version={}, layer={})",
@@ -234,34 +635,121 @@ struct PyID3 {
     tags: id3::tags::ID3Tags,
     path: Option<String>,
     version: (u8, u8),
+    size: u32,
+    truncated: bool,
+    /// Codepage used for "latin-1" text, with `auto` already resolved.
+    legacy: id3::specs::LegacyEncoding,
+}
+
+impl PyID3 {
+    /// Copy of the tags with frames converted for an ID3v2.`version` render.
+    fn converted(&self, version: u8) -> id3::tags::ID3Tags {
+        let mut tags = self.tags.clone();
+        if version == 3 {
+            tags.update_to_v23();
+        } else {
+            tags.update_to_v24();
+        }
+        tags
+    }
+}
+
+/// Parse an `id3v1_encoding` argument (see `LegacyEncoding::from_label`).
+fn legacy_encoding_arg(label: &str) -> PyResult<id3::specs::LegacyEncoding> {
+    id3::specs::LegacyEncoding::from_label(label)
+        .ok_or_else(|| PyValueError::new_err(format!("unknown encoding: {}", label)))
 }
 
 #[pymethods]
 impl PyID3 {
+    /// `id3v1_encoding` decodes ID3v1 fields and encoding-0 frames with another
+    /// codepage (any encoding_rs label, or `"auto"` for windows-1251/latin-1).
     #[new]
-    #[pyo3(signature = (filename=None))]
-    fn new(filename: Option<&str>) -> PyResult<Self> {
+    #[pyo3(signature = (filename=None, id3v1_encoding="latin-1"))]
+    fn new(filename: Option<&str>, id3v1_encoding: &str) -> PyResult<Self> {
+        let encoding = legacy_encoding_arg(id3v1_encoding)?;
         match filename {
             Some(path) => {
-                let (tags, header) = id3::load_id3(path)?;
+                let (mut tags, header) = id3::load_id3(path)?;
                 let version = header.as_ref().map(|h| h.version).unwrap_or((4, 0));
+                let (legacy, _) = tags.recode_latin1(encoding);
                 Ok(PyID3 {
                     tags,
                     path: Some(path.to_string()),
                     version,
+                    size: header.as_ref().map(|h| h.full_size()).unwrap_or(0),
+                    truncated: false,
+                    legacy,
                 })
             }
             None => Ok(PyID3 {
                 tags: id3::tags::ID3Tags::new(),
                 path: None,
                 version: (4, 0),
+                size: 0,
+                truncated: false,
+                legacy: id3::specs::LegacyEncoding::Latin1,
             }),
         }
     }
 
-    fn getall(&self, key: &str) -> PyResult<Vec<Py<PyAny>>> {
+    /// Codepage used for ID3v1 fields and latin-1 frames, e.g. `"windows-1251"`
+    /// when `id3v1_encoding="auto"` picked it. Recoded frames are switched to
+    /// UTF-8, so `save()` rewrites them in a Unicode encoding.
+    #[getter]
+    fn id3v1_encoding(&self) -> &'static str {
+        self.legacy.name()
+    }
+
+    /// Parse an ID3v2 tag from the start of a byte buffer (e.g. the first KB of an HTTP
+    /// range request). No MPEG frames or ID3v1 trailer are looked at. If the buffer ends
+    /// before the declared tag size, the frames that fit are loaded and `truncated` is set.
+    #[staticmethod]
+    fn from_bytes(data: &[u8]) -> PyResult<Self> {
+        let (tags, header) = id3::load_id3_from_slice(data)?;
+        let header = header.ok_or(common::error::MutagenError::ID3NoHeader)?;
+        let size = header.full_size();
+        Ok(PyID3 {
+            tags,
+            path: None,
+            version: header.version,
+            size,
+            truncated: size as usize > data.len(),
+            legacy: id3::specs::LegacyEncoding::Latin1,
+        })
+    }
+
+    /// Exact bytes (header + payload) of the first frame stored under `key`.
+    fn get_raw<'py>(&self, py: Python<'py>, key: &str) -> PyResult<Bound<'py, PyBytes>> {
+        match self.tags.get_raw(key) {
+            Some(raw) => Ok(PyBytes::new(py, raw)),
+            None => Err(PyKeyError::new_err(key.to_string())),
+        }
+    }
+
+    /// Replace `key` with a complete frame (header + payload) written verbatim on save.
+    fn set_raw(&mut self, key: &str, data: Vec<u8>) -> PyResult<()> {
+        self.tags.set_raw(key, data, self.version.0.max(3))?;
+        Ok(())
+    }
+
+    /// Render the tag (header + frames + padding) to bytes without touching any file.
+    fn save_to_bytes<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        let version = self.version.0.max(3);
+        let rendered = id3::writer::render_tag(&self.converted(version), version)?;
+        Ok(PyBytes::new(py, &rendered))
+    }
+
+    /// Serialized ID3v2 tag at this object's version, as `save()` would write it;
+    /// pass it to `MP3.set_id3_bytes()` to transplant the tag into another file.
+    fn render<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        self.save_to_bytes(py)
+    }
+
+    fn getall(&mut self, key: &str) -> PyResult<Vec<Py<PyAny>>> {
         Python::attach(|py| {
-            let frames = self.tags.getall(key);
+            // Frames read from a file are decoded on first access
+            let frames = self.tags.getall_mut(key);
             Ok(frames.iter().map(|f| frame_to_py(py, f)).collect())
         })
     }
@@ -281,17 +769,14 @@ impl PyID3 {
         }
     }
 
+    /// Replace the frames under `key` with one holding `value` (a string or
+    /// list of strings); see `id3_frame_for_key` for `TXXX:`/`COMM:`/`USLT:` keys.
     fn __setitem__(&mut self, key: &str, value: &Bound<'_, PyAny>) -> PyResult<()> {
         let text = value.extract::<Vec<String>>().or_else(|_| {
             value.extract::<String>().map(|s| vec![s])
         })?;
 
-        let frame = id3::frames::Frame::Text(id3::frames::TextFrame {
-            id: key.to_string(),
-            encoding: id3::specs::Encoding::Utf8,
-            text,
-        });
-
+        let frame = id3_frame_for_key(key, text)?;
         let hash_key = frame.hash_key();
         // Replace existing or push new (Vec-based tag storage)
         if let Some((_, frames)) = self.tags.frames.iter_mut().find(|(k, _)| k == &hash_key) {
@@ -307,8 +792,55 @@ impl PyID3 {
         Ok(())
     }
 
+    /// Append a frame (a frame object or dict, see `py_to_id3_frame`) next to any
+    /// frames already stored under its key. Returns that key.
+    fn add(&mut self, frame: &Bound<'_, PyAny>) -> PyResult<String> {
+        let frame = py_to_id3_frame(frame, None)?;
+        let key = frame.hash_key().as_str().to_string();
+        self.tags.add(frame);
+        Ok(key)
+    }
+
+    /// Replace every frame under `key` with `frames`. Dicts without an `"id"`
+    /// take it from `key` (`"APIC:cover"` → `APIC`); an empty list deletes `key`.
+    fn setall(&mut self, key: &str, frames: Vec<Bound<'_, PyAny>>) -> PyResult<()> {
+        let id = key.split(':').next().unwrap_or(key);
+        let frames = frames.iter()
+            .map(|f| py_to_id3_frame(f, Some(id)))
+            .collect::<PyResult<Vec<_>>>()?;
+        self.tags.delall(key);
+        for frame in frames {
+            self.tags.add(frame);
+        }
+        Ok(())
+    }
+
+    /// Remove every frame whose key matches one of `patterns` (`"APIC"`, or
+    /// `"COMM*"` as a prefix). Returns the removed keys.
+    fn strip(&mut self, patterns: Vec<String>) -> Vec<String> {
+        let patterns: Vec<&str> = patterns.iter().map(String::as_str).collect();
+        self.tags.strip(&patterns)
+    }
+
+    /// Replace all `USLT` frames with one holding `text`. Returns its key.
+    #[pyo3(signature = (text, lang="eng", desc=""))]
+    fn set_lyrics(&mut self, text: &str, lang: &str, desc: &str) -> String {
+        self.tags.strip(&["USLT"]);
+        let frame = id3::frames::Frame::Lyrics(id3::frames::LyricsFrame {
+            id: "USLT".to_string(),
+            encoding: id3::specs::Encoding::Utf8,
+            lang: lang.to_string(),
+            desc: desc.to_string(),
+            text: text.to_string(),
+        });
+        let key = frame.hash_key().as_str().to_string();
+        self.tags.add(frame);
+        key
+    }
+
     fn __contains__(&self, key: &str) -> bool {
-        self.tags.get(key).is_some()
+        // Key lookup only: frames that have not been decoded yet still count
+        self.tags.contains_key(&id3::frames::HashKey::new(key))
     }
 
     fn __len__(&self) -> usize {
@@ -325,17 +857,41 @@ impl PyID3 {
         Ok(list.call_method0("__iter__")?.into())
     }
 
-    fn save(&self, filename: Option<&str>) -> PyResult<()> {
+    /// Write the tag as ID3v2.`v2_version` (3 or 4; default: this tag's
+    /// version, at least 3), converting frames to that version first. On
+    /// success the tag holds the converted frames and `version` is updated.
+    #[pyo3(signature = (filename=None, v2_version=None))]
+    fn save(&mut self, filename: Option<&str>, v2_version: Option<u8>) -> PyResult<()> {
         let path = filename
             .map(|s| s.to_string())
             .or_else(|| self.path.clone())
             .ok_or_else(|| PyValueError::new_err("No filename specified"))?;
+        let version = match v2_version {
+            None => self.version.0.max(3),
+            Some(v @ (3 | 4)) => v,
+            Some(v) => return Err(PyValueError::new_err(format!("v2_version must be 3 or 4, not {}", v))),
+        };
 
-        id3::save_id3(&path, &self.tags, self.version.0.max(3))?;
+        let tags = self.converted(version);
+        id3::save_id3(&path, &tags, version)?;
         invalidate_file(&path);
+        self.tags = tags;
+        self.version = (version, 0);
         Ok(())
     }
 
+    /// Convert frames to their ID3v2.3 forms (TDRC to TYER/TDAT/TIME, TIPL/TMCL
+    /// to IPLS, ...) and drop v2.4-only frames.
+    fn update_to_v23(&mut self) {
+        self.tags.update_to_v23();
+    }
+
+    /// Convert frames to their ID3v2.4 forms (TYER/TDAT/TIME to TDRC, IPLS to
+    /// TIPL, ...) and drop frames v2.4 has no place for.
+    fn update_to_v24(&mut self) {
+        self.tags.update_to_v24();
+    }
+
     fn delete(&self, filename: Option<&str>) -> PyResult<()> {
         let path = filename
             .map(|s| s.to_string())
@@ -359,6 +915,35 @@ impl PyID3 {
     fn version(&self) -> (u8, u8) {
         self.version
     }
+
+    /// Version used by later saves; (2, 2) is kept for reporting but saved as v2.3.
+    #[setter]
+    fn set_version(&mut self, version: (u8, u8)) -> PyResult<()> {
+        if !matches!(version, (2, 2) | (3, 0) | (4, 0)) {
+            return Err(PyValueError::new_err(format!(
+                "unsupported ID3 version {:?}; use (2, 2), (3, 0) or (4, 0)", version)));
+        }
+        self.version = version;
+        Ok(())
+    }
+
+    /// Declared tag size in bytes including the header (0 when no tag was read).
+    #[getter]
+    fn size(&self) -> u32 {
+        self.size
+    }
+
+    /// True when the tag came from a buffer shorter than its declared size.
+    #[getter]
+    fn truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Offset to the next tag from a v2.4 `SEEK` frame, or None.
+    #[getter]
+    fn seek_offset(&self) -> Option<u32> {
+        self.tags.seek_offset()
+    }
 }
 
 /// MP3 file (ID3 tags + audio info).
@@ -371,38 +956,141 @@ struct PyMP3 {
     tag_dict: Py<PyDict>,
     tag_keys: Vec<String>,
     id3: PyID3,
+    /// Serialized tag from `set_id3_bytes`, written verbatim by the next save.
+    id3_bytes: Option<Vec<u8>>,
+    /// ID3 version as read, e.g. `(2, 4)`; `(1, 1)` for ID3v1 alone.
+    #[pyo3(get)]
+    tag_version: Option<(u8, u8)>,
+}
+
+/// Python dict (first frame per key) and key list for the MP3 tag cache.
+/// `frame_ms` is the MPEG frame length of the audio (see `sylt_values_to_ms`).
+fn id3_tag_dict<'py>(py: Python<'py>, tags: &mut id3::tags::ID3Tags, frame_ms: f64) -> (Bound<'py, PyDict>, Vec<String>) {
+    let tag_dict = PyDict::new(py);
+    let mut tag_keys = Vec::with_capacity(tags.frames.len());
+    for (hash_key, frames) in tags.frames.iter_mut() {
+        if let Some(lf) = frames.first_mut() {
+            if let Ok(frame) = lf.decode_with_buf(&tags.raw_buf) {
+                let key_str = hash_key.as_str();
+                let _ = tag_dict.set_item(key_str, frame_to_py(py, frame));
+                tag_keys.push(key_str.to_string());
+            }
+        }
+    }
+    let _ = sylt_values_to_ms(&tag_dict, tag_keys.iter().map(String::as_str), frame_ms);
+    (tag_dict, tag_keys)
+}
+
+/// Give the SYLT values of an MP3 tag dict whose timestamps count MPEG frames
+/// (`format` 1) in milliseconds instead, `frame_ms` being the length of one
+/// frame of the file's audio.
+fn sylt_values_to_ms<'a>(dict: &Bound<'_, PyDict>, keys: impl Iterator<Item = &'a str>, frame_ms: f64) -> PyResult<()> {
+    use id3::frames::{mpeg_frames_to_ms, TIMESTAMP_MILLISECONDS, TIMESTAMP_MPEG_FRAMES};
+    for key in keys.filter(|k| k.starts_with("SYLT")) {
+        let Some(value) = dict.get_item(key)? else { continue };
+        let Ok(value) = value.cast::<PyDict>() else { continue };
+        let format = value.get_item("format")?.and_then(|f| f.extract::<u8>().ok());
+        if format != Some(TIMESTAMP_MPEG_FRAMES) || frame_ms <= 0.0 {
+            continue;
+        }
+        let Some(sync) = value.get_item("sync")? else { continue };
+        let sync: Vec<(String, u32)> = sync.extract()?;
+        let sync: Vec<(String, u32)> = sync.into_iter()
+            .map(|(text, frames)| (text, mpeg_frames_to_ms(frames, frame_ms)))
+            .collect();
+        value.set_item("sync", PyList::new(dict.py(), sync)?)?;
+        value.set_item("format", TIMESTAMP_MILLISECONDS)?;
+    }
+    Ok(())
+}
+
+/// Python dict (a list of values per key) and key list for a Vorbis comment tag cache.
+fn vc_tag_dict<'py>(py: Python<'py>, vc: &vorbis::VorbisComment) -> PyResult<(Bound<'py, PyDict>, Vec<String>)> {
+    let tag_dict = PyDict::new(py);
+    let tag_keys = vc.keys();
+    for key in &tag_keys {
+        let values = vc.get(key);
+        if !values.is_empty() {
+            let _ = tag_dict.set_item(key.as_str(), PyList::new(py, values)?);
+        }
+    }
+    Ok((tag_dict, tag_keys))
+}
+
+/// Python dict and key list for the MP4 tag cache.
+fn mp4_tag_dict<'py>(py: Python<'py>, tags: &mp4::MP4Tags) -> (Bound<'py, PyDict>, Vec<String>) {
+    let tag_dict = PyDict::new(py);
+    let tag_keys = tags.keys();
+    for key in &tag_keys {
+        if let Some(value) = tags.get(key) {
+            if let Ok(py_val) = mp4_value_to_py(py, value) {
+                let _ = tag_dict.set_item(key.as_str(), py_val);
+            }
+        }
+    }
+    (tag_dict, tag_keys)
+}
+
+/// Python dict and key list for an APEv2 tag: text items as lists of strings,
+/// binary items as bytes (as `APEv2[key]` gives them).
+fn ape_tag_dict<'py>(py: Python<'py>, tag: Option<&ape::apev2::APEv2Tag>) -> PyResult<(Bound<'py, PyDict>, Vec<String>)> {
+    let tag_dict = PyDict::new(py);
+    let mut tag_keys = Vec::new();
+    for item in tag.iter().flat_map(|t| &t.items) {
+        if tag_dict.contains(item.key.as_str())? {
+            continue;
+        }
+        match item.text() {
+            Some(values) => tag_dict.set_item(item.key.as_str(), PyList::new(py, values)?)?,
+            None => tag_dict.set_item(item.key.as_str(), PyBytes::new(py, &item.value))?,
+        }
+        tag_keys.push(item.key.clone());
+    }
+    Ok((tag_dict, tag_keys))
+}
+
+/// Drop the keys matching a `strip()` pattern from a file's cached tag dict and key list.
+fn strip_tag_cache(py: Python, tag_dict: &Py<PyDict>, tag_keys: &mut Vec<String>, patterns: &[&str], ignore_case: bool) {
+    let dict = tag_dict.bind(py);
+    tag_keys.retain(|k| {
+        let hit = patterns.iter().any(|p| common::util::key_matches_pattern(k, p, ignore_case));
+        if hit {
+            let _ = dict.del_item(k.as_str());
+        }
+        !hit
+    });
 }
 
 impl PyMP3 {
-    #[inline(always)]
-    fn from_data(py: Python<'_>, data: &[u8], filename: &str) -> PyResult<Self> {
+    /// Structural parse; touches no Python objects, so it can run detached.
+    fn parse_data(data: &[u8], filename: &str) -> PyResult<mp3::MP3File> {
         let mut mp3_file = mp3::MP3File::parse(data, filename)?;
         mp3_file.ensure_tags_parsed(data);
+        Ok(mp3_file)
+    }
+
+    #[inline(always)]
+    fn from_parsed(py: Python<'_>, mut mp3_file: mp3::MP3File, filename: &str) -> PyResult<Self> {
         let info = make_mpeg_info(&mp3_file.info);
         let version = mp3_file.id3_header.as_ref().map(|h| h.version).unwrap_or((4, 0));
 
         // Pre-build Python dict of all tags during construction
-        let tag_dict = PyDict::new(py);
-        let mut tag_keys = Vec::with_capacity(mp3_file.tags.frames.len());
-        for (hash_key, frames) in mp3_file.tags.frames.iter_mut() {
-            if let Some(lf) = frames.first_mut() {
-                if let Ok(frame) = lf.decode_with_buf(&mp3_file.tags.raw_buf) {
-                    let key_str = hash_key.as_str();
-                    let _ = tag_dict.set_item(key_str, frame_to_py(py, frame));
-                    tag_keys.push(key_str.to_string());
-                }
-            }
-        }
+        let (tag_dict, tag_keys) = id3_tag_dict(py, &mut mp3_file.tags, mp3_file.info.frame_ms());
 
         Ok(PyMP3 {
             info,
             filename: filename.to_string(),
             tag_dict: tag_dict.into(),
             tag_keys,
+            id3_bytes: None,
+            tag_version: mp3_file.tag_version(),
             id3: PyID3 {
                 tags: mp3_file.tags,
                 path: Some(filename.to_string()),
                 version,
+                size: mp3_file.id3_header.as_ref().map(|h| h.full_size()).unwrap_or(0),
+                truncated: false,
+                legacy: id3::specs::LegacyEncoding::Latin1,
             },
         })
     }
@@ -410,11 +1098,27 @@ impl PyMP3 {
 
 #[pymethods]
 impl PyMP3 {
+    /// `id3v1_encoding` works as in `ID3()`.
     #[new]
-    fn new(py: Python<'_>, filename: &str) -> PyResult<Self> {
-        let data = read_cached(filename)
-            .map_err(|e| PyIOError::new_err(format!("{}", e)))?;
-        Self::from_data(py, &data, filename)
+    #[pyo3(signature = (filename, id3v1_encoding="latin-1"))]
+    fn new(py: Python<'_>, filename: &str, id3v1_encoding: &str) -> PyResult<Self> {
+        let encoding = legacy_encoding_arg(id3v1_encoding)?;
+        let (parsed, legacy) = py.detach(|| -> PyResult<_> {
+            let data = read_cached(filename)
+                .map_err(|e| PyIOError::new_err(format!("{}", e)))?;
+            let mut parsed = Self::parse_data(&data, filename)?;
+            let (legacy, _) = parsed.tags.recode_latin1(encoding);
+            Ok((parsed, legacy))
+        })?;
+        let mut mp3 = Self::from_parsed(py, parsed, filename)?;
+        mp3.id3.legacy = legacy;
+        Ok(mp3)
+    }
+
+    /// Codepage used for ID3v1 fields and latin-1 frames (see `ID3.id3v1_encoding`).
+    #[getter]
+    fn id3v1_encoding(&self) -> &'static str {
+        self.id3.legacy.name()
     }
 
     #[getter]
@@ -423,6 +1127,9 @@ impl PyMP3 {
             tags: self.id3.tags.clone(),
             path: self.id3.path.clone(),
             version: self.id3.version,
+            size: self.id3.size,
+            truncated: false,
+            legacy: self.id3.legacy,
         };
         Ok(id3.into_pyobject(py)?.into_any().unbind())
     }
@@ -444,23 +1151,22 @@ impl PyMP3 {
         let text = value.extract::<Vec<String>>().or_else(|_| {
             value.extract::<String>().map(|s| vec![s])
         })?;
-        // Update the cached Python dict + key list
-        let _ = self.tag_dict.bind(py).set_item(key, PyList::new(py, &text)?);
-        if !self.tag_keys.contains(&key.to_string()) {
-            self.tag_keys.push(key.to_string());
+        // Update the cached Python dict + key list, under the frame's own key
+        // (`COMM:desc` is stored as `COMM:desc:eng`)
+        let frame = id3_frame_for_key(key, text.clone())?;
+        let hash_key = frame.hash_key();
+        let key = hash_key.as_str().to_string();
+        let _ = self.tag_dict.bind(py).set_item(&key, PyList::new(py, &text)?);
+        if !self.tag_keys.contains(&key) {
+            self.tag_keys.push(key);
         }
         // Update the underlying ID3 tag storage
-        let frame = id3::frames::Frame::Text(id3::frames::TextFrame {
-            id: key.to_string(),
-            encoding: id3::specs::Encoding::Utf8,
-            text,
-        });
-        let hash_key = frame.hash_key();
         if let Some((_, frames)) = self.id3.tags.frames.iter_mut().find(|(k, _)| k == &hash_key) {
             *frames = vec![id3::tags::LazyFrame::Decoded(frame)];
         } else {
             self.id3.tags.frames.push((hash_key, vec![id3::tags::LazyFrame::Decoded(frame)]));
         }
+        self.id3_bytes = None;
         Ok(())
     }
 
@@ -472,8 +1178,76 @@ impl PyMP3 {
         format!("MP3(filename={:?})", self.filename)
     }
 
-    fn save(&self) -> PyResult<()> {
-        self.id3.save(Some(&self.filename))
+    fn save(&mut self, py: Python) -> PyResult<()> {
+        match self.id3_bytes {
+            Some(ref tag) => {
+                id3::save_id3_bytes(&self.filename, tag)?;
+                invalidate_file(&self.filename);
+                Ok(())
+            }
+            None => {
+                let filename = self.filename.clone();
+                self.id3.save(Some(&filename), None)?;
+                // Saving may have converted frames for the tag version
+                let (tag_dict, tag_keys) = id3_tag_dict(py, &mut self.id3.tags, self.info.frame_ms());
+                self.tag_dict = tag_dict.into();
+                self.tag_keys = tag_keys;
+                Ok(())
+            }
+        }
+    }
+
+    /// Replace the whole ID3v2 tag with `data` (as from `ID3.render()`); the next
+    /// save writes it byte for byte. Later edits switch back to re-rendering.
+    fn set_id3_bytes(&mut self, py: Python, data: Vec<u8>) -> PyResult<()> {
+        let (mut tags, header) = id3::load_id3_from_slice(&data)?;
+        let header = header.ok_or(common::error::MutagenError::ID3NoHeader)?;
+        if header.full_size() as usize != data.len() {
+            return Err(common::error::MutagenError::ID3(format!(
+                "tag header declares {} bytes but {} were given", header.full_size(), data.len()
+            )).into());
+        }
+        let (tag_dict, tag_keys) = id3_tag_dict(py, &mut tags, self.info.frame_ms());
+        self.tag_dict = tag_dict.into();
+        self.tag_keys = tag_keys;
+        self.id3.tags = tags;
+        self.id3.version = header.version;
+        self.id3.size = header.full_size();
+        self.id3_bytes = Some(data);
+        Ok(())
+    }
+
+    fn get_raw<'py>(&self, py: Python<'py>, key: &str) -> PyResult<Bound<'py, PyBytes>> {
+        self.id3.get_raw(py, key)
+    }
+
+    fn set_raw(&mut self, py: Python, key: &str, data: Vec<u8>) -> PyResult<()> {
+        self.id3.set_raw(key, data)?;
+        let _ = self.tag_dict.bind(py).del_item(key);
+        self.tag_keys.retain(|k| k != key);
+        self.id3_bytes = None;
+        Ok(())
+    }
+
+    /// Remove every frame whose key matches one of `patterns`; `save()` writes
+    /// the result. Returns the removed keys.
+    fn strip(&mut self, py: Python, patterns: Vec<String>) -> Vec<String> {
+        let patterns: Vec<&str> = patterns.iter().map(String::as_str).collect();
+        let removed = self.id3.tags.strip(&patterns);
+        strip_tag_cache(py, &self.tag_dict, &mut self.tag_keys, &patterns, false);
+        self.id3_bytes = None;
+        removed
+    }
+
+    /// Replace all `USLT` frames with one holding `text`; `save()` writes it.
+    #[pyo3(signature = (text, lang="eng", desc=""))]
+    fn set_lyrics(&mut self, py: Python, text: &str, lang: &str, desc: &str) -> PyResult<()> {
+        strip_tag_cache(py, &self.tag_dict, &mut self.tag_keys, &["USLT"], false);
+        let key = self.id3.set_lyrics(text, lang, desc);
+        self.tag_dict.bind(py).set_item(key.as_str(), text)?;
+        self.tag_keys.push(key);
+        self.id3_bytes = None;
+        Ok(())
     }
 
     fn delete(&self) -> PyResult<()> {
@@ -487,6 +1261,7 @@ impl PyMP3 {
 
     fn clear(&mut self, py: Python) -> PyResult<()> {
         self.id3.tags.frames.clear();
+        self.id3_bytes = None;
         self.tag_keys.clear();
         let dict = self.tag_dict.bind(py);
         dict.clear();
@@ -496,14 +1271,46 @@ impl PyMP3 {
     fn pprint(&self) -> String {
         format!("{}\n{}", self.info.pprint(), self.id3.pprint())
     }
+
+    /// Serialize info + tags to a JSON string (schema documented on `preserialized_to_json`).
+    #[pyo3(signature = (indent=None, include_pictures=false))]
+    fn to_json(&self, indent: Option<usize>, include_pictures: bool) -> String {
+        let pf = PreSerializedFile {
+            length: Some(self.info.length),
+            sample_rate: self.info.sample_rate,
+            channels: self.info.channels,
+            bitrate: Some(self.info.bitrate),
+            tags: id3_tags_to_batch(self.id3.tags.clone()),
+            extra: vec![
+                ("length_samples", BatchTagValue::Int(self.info.length_samples as i64)),
+                ("duration_ms", BatchTagValue::Int(self.info.duration_ms as i64)),
+                ("version", BatchTagValue::Text(ryu::Buffer::new().format(self.info.version).to_string())),
+                ("layer", BatchTagValue::Int(self.info.layer as i64)),
+                ("mode", BatchTagValue::Int(self.info.mode as i64)),
+                ("protected", BatchTagValue::Bool(self.info.protected)),
+                ("bitrate_mode", BatchTagValue::Int(self.info.bitrate_mode as i64)),
+            ].into_iter()
+                .chain(self.info.xing_frames.map(|n| ("xing_frames", BatchTagValue::Int(n as i64))))
+                .collect(),
+            lazy_vc: None,
+        };
+        preserialized_to_json_string(&pf, include_pictures, indent)
+    }
 }
 
 /// FLAC stream info.
 #[pyclass(name = "StreamInfo", from_py_object)]
 #[derive(Debug, Clone)]
 struct PyStreamInfo {
+    /// `None` when STREAMINFO's `total_samples` is 0 (length unknown).
     #[pyo3(get)]
-    length: f64,
+    length: Option<f64>,
+    /// `total_samples`, or `None` when that is 0.
+    #[pyo3(get)]
+    length_samples: Option<u64>,
+    /// `total_samples` / `sample_rate` in whole milliseconds; `None` with `length`.
+    #[pyo3(get)]
+    duration_ms: Option<u64>,
     #[pyo3(get)]
     channels: u8,
     #[pyo3(get)]
@@ -522,23 +1329,46 @@ struct PyStreamInfo {
     max_frame_size: u32,
     #[pyo3(get)]
     bitrate: u32,
+    /// Header values that were out of range and read as 0.
+    #[pyo3(get)]
+    warnings: Vec<String>,
 }
 
 #[pymethods]
 impl PyStreamInfo {
-    fn __repr__(&self) -> String {
-        format!(
-            "StreamInfo(length={:.2}, sample_rate={}, channels={}, bits_per_sample={})",
-            self.length, self.sample_rate, self.channels, self.bits_per_sample
-        )
+    /// FLAC frames vary in size, so the stream always counts as VBR.
+    #[getter]
+    fn is_vbr(&self) -> bool {
+        true
     }
 
-    fn pprint(&self) -> String {
+    #[getter]
+    fn is_cbr(&self) -> bool {
+        false
+    }
+
+    #[getter]
+    fn is_abr(&self) -> bool {
+        false
+    }
+
+    fn __repr__(&self) -> String {
+        let length = match self.length {
+            Some(length) => format!("{:.2}", length),
+            None => "None".to_string(),
+        };
         format!(
-            "FLAC, {:.2} seconds, {} Hz",
-            self.length, self.sample_rate
+            "StreamInfo(length={}, sample_rate={}, channels={}, bits_per_sample={})",
+            length, self.sample_rate, self.channels, self.bits_per_sample
         )
     }
+
+    fn pprint(&self) -> String {
+        match self.length {
+            Some(length) => format!("FLAC, {:.2} seconds, {} Hz", length, self.sample_rate),
+            None => format!("FLAC, unknown length, {} Hz", self.sample_rate),
+        }
+    }
 }
 
 /// VorbisComment-based tags (used by FLAC and OGG).
@@ -578,6 +1408,13 @@ impl PyVComment {
         Ok(())
     }
 
+    /// Remove every comment whose key matches one of `patterns`, ignoring case.
+    /// Returns the removed keys.
+    fn strip(&mut self, patterns: Vec<String>) -> Vec<String> {
+        let patterns: Vec<&str> = patterns.iter().map(String::as_str).collect();
+        self.vc.strip(&patterns)
+    }
+
     fn __contains__(&self, key: &str) -> bool {
         !self.vc.get(key).is_empty()
     }
@@ -600,6 +1437,12 @@ impl PyVComment {
     fn vendor(&self) -> &str {
         &self.vc.vendor
     }
+
+    /// Override the vendor string written on the next save (parsed vendor is kept otherwise).
+    #[setter]
+    fn set_vendor(&mut self, vendor: String) {
+        self.vc.vendor = vendor;
+    }
 }
 
 /// FLAC file.
@@ -610,23 +1453,31 @@ struct PyFLAC {
     #[pyo3(get)]
     filename: String,
     flac_file: flac::FLACFile,
-    vc_data: vorbis::VorbisComment,
+    /// Shared with the `tags` getter so edits made through it (e.g. `vendor`) are saved.
+    vc: Py<PyVComment>,
     tag_dict: Py<PyDict>,
     tag_keys: Vec<String>,
 }
 
 impl PyFLAC {
-    #[inline(always)]
-    fn from_data(py: Python<'_>, data: &[u8], filename: &str) -> PyResult<Self> {
+    /// Structural parse; touches no Python objects, so it can run detached.
+    fn parse_data(data: &[u8], filename: &str) -> PyResult<flac::FLACFile> {
         let mut flac_file = flac::FLACFile::parse(data, filename)?;
+        flac_file.ensure_tags();
+        Ok(flac_file)
+    }
 
-        // Compute bitrate from audio data size (exclude metadata), matching mutagen
-        let audio_data_size = data.len().saturating_sub(flac_file.flac_offset + flac_file.metadata_length);
-        let bitrate = if flac_file.info.length > 0.0 {
-            (audio_data_size as f64 * 8.0 / flac_file.info.length) as u32
-        } else { 0 };
-        let info = PyStreamInfo {
+    /// Stream info; the bitrate is over the audio after the metadata, matching mutagen.
+    fn make_info(flac_file: &flac::FLACFile, file_size: usize) -> PyStreamInfo {
+        let audio_data_size = file_size.saturating_sub(flac_file.flac_offset + flac_file.metadata_length);
+        let bitrate = match flac_file.info.length {
+            Some(length) if length > 0.0 => (audio_data_size as f64 * 8.0 / length) as u32,
+            _ => 0,
+        };
+        PyStreamInfo {
             length: flac_file.info.length,
+            length_samples: (flac_file.info.total_samples > 0).then_some(flac_file.info.total_samples),
+            duration_ms: flac_file.info.duration_ms,
             channels: flac_file.info.channels,
             sample_rate: flac_file.info.sample_rate,
             bits_per_sample: flac_file.info.bits_per_sample,
@@ -636,46 +1487,102 @@ impl PyFLAC {
             min_frame_size: flac_file.info.min_frame_size,
             max_frame_size: flac_file.info.max_frame_size,
             bitrate,
-        };
+            warnings: flac_file.info.warnings.clone(),
+        }
+    }
 
-        flac_file.ensure_tags();
+    #[inline(always)]
+    fn from_parsed(py: Python<'_>, flac_file: flac::FLACFile, file_size: usize, filename: &str) -> PyResult<Self> {
+        let info = Self::make_info(&flac_file, file_size);
         let vc_data = flac_file.tags.clone().unwrap_or_else(|| vorbis::VorbisComment::new());
 
         // Pre-build Python dict of all tags
-        let tag_dict = PyDict::new(py);
-        let tag_keys = vc_data.keys();
-        for key in &tag_keys {
-            let values = vc_data.get(key);
-            if !values.is_empty() {
-                let _ = tag_dict.set_item(key.as_str(), PyList::new(py, values)?);
-            }
-        }
+        let (tag_dict, tag_keys) = vc_tag_dict(py, &vc_data)?;
+
+        let vc = Py::new(py, PyVComment { vc: vc_data, path: Some(filename.to_string()) })?;
 
         Ok(PyFLAC {
             info,
             filename: filename.to_string(),
             flac_file,
-            vc_data,
+            vc,
             tag_dict: tag_dict.into(),
             tag_keys,
         })
     }
 }
 
+/// A FLAC picture as a dict; the image data is copied straight from `block`
+/// into the bytes object.
+fn flac_picture_dict<'py>(py: Python<'py>, header: &flac::PictureHeader, block: &[u8]) -> Bound<'py, PyDict> {
+    let d = PyDict::new(py);
+    let _ = d.set_item("type", header.pic_type);
+    let _ = d.set_item("mime", &header.mime);
+    let _ = d.set_item("desc", &header.desc);
+    let _ = d.set_item("width", header.width);
+    let _ = d.set_item("height", header.height);
+    let _ = d.set_item("depth", header.depth);
+    let _ = d.set_item("colors", header.colors);
+    let _ = d.set_item("data", PyBytes::new(py, &block[header.data.clone()]));
+    d
+}
+
 #[pymethods]
 impl PyFLAC {
     #[new]
     fn new(py: Python<'_>, filename: &str) -> PyResult<Self> {
-        let data = read_cached(filename)
-            .map_err(|e| PyIOError::new_err(format!("{}", e)))?;
-        Self::from_data(py, &data, filename)
+        let (data, parsed) = py.detach(|| -> PyResult<_> {
+            let data = read_cached(filename)
+                .map_err(|e| PyIOError::new_err(format!("{}", e)))?;
+            let parsed = Self::parse_data(&data, filename)?;
+            Ok((data, parsed))
+        })?;
+        Self::from_parsed(py, parsed, data.len(), filename)
     }
 
     #[getter]
-    fn tags(&self, py: Python) -> PyResult<Py<PyAny>> {
-        let vc = self.vc_data.clone();
-        let pvc = PyVComment { vc, path: Some(self.filename.clone()) };
-        Ok(pvc.into_pyobject(py)?.into_any().unbind())
+    fn tags(&self, py: Python) -> Py<PyAny> {
+        self.vc.clone_ref(py).into_any()
+    }
+
+    /// `"VorbisComment"`, or `None` without a VORBIS_COMMENT block.
+    #[getter]
+    fn tag_version(&self) -> Option<&'static str> {
+        self.flac_file.tags.is_some().then_some(vorbis::TAG_VERSION)
+    }
+
+    /// Exact bytes (4-byte header + body) of the metadata block at `index`, in file order.
+    /// Read from the file on each call.
+    fn get_raw_block<'py>(&self, py: Python<'py>, index: usize) -> PyResult<Bound<'py, PyBytes>> {
+        let bd = self.flac_file.block_descs.get(index)
+            .ok_or_else(|| pyo3::exceptions::PyIndexError::new_err("block index out of range"))?;
+        let start = bd.header_offset();
+        let block = read_file_span(&self.filename, start, bd.data_offset + bd.data_size - start)?;
+        Ok(PyBytes::new(py, &block))
+    }
+
+    /// Every metadata block in file order as `{"block_type", "offset", "size",
+    /// "is_last"}`; `offset` is where the 4-byte header starts, `size` the body length.
+    #[getter]
+    fn metadata_blocks<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyList>> {
+        let blocks = PyList::empty(py);
+        for bd in &self.flac_file.block_descs {
+            let d = PyDict::new(py);
+            d.set_item("block_type", bd.block_type.to_byte())?;
+            d.set_item("offset", bd.header_offset())?;
+            d.set_item("size", bd.data_size)?;
+            d.set_item("is_last", bd.is_last)?;
+            blocks.append(d)?;
+        }
+        Ok(blocks)
+    }
+
+    /// Spec violations in the metadata block layout, as warning messages.
+    /// Empty for a well-formed file. Rereads the file.
+    fn validate_layout(&self) -> PyResult<Vec<String>> {
+        let data = std::fs::read(&self.filename)
+            .map_err(|e| PyIOError::new_err(format!("{}", e)))?;
+        Ok(self.flac_file.layout_warnings(&data))
     }
 
     fn keys(&self) -> Vec<String> {
@@ -701,10 +1608,7 @@ impl PyFLAC {
             self.tag_keys.push(key.to_string());
         }
         // Update the underlying Vorbis comment storage
-        self.vc_data.set(key, values.clone());
-        if let Some(ref mut tags) = self.flac_file.tags {
-            tags.set(key, values);
-        }
+        self.vc.borrow_mut(py).vc.set(key, values);
         Ok(())
     }
 
@@ -716,31 +1620,40 @@ impl PyFLAC {
         format!("FLAC(filename={:?})", self.filename)
     }
 
-    fn save(&self) -> PyResult<()> {
+    /// Remove every comment whose key matches one of `patterns`, ignoring case;
+    /// `save()` writes the result. Returns the removed keys.
+    fn strip(&mut self, py: Python, patterns: Vec<String>) -> Vec<String> {
+        let patterns: Vec<&str> = patterns.iter().map(String::as_str).collect();
+        let removed = self.vc.borrow_mut(py).vc.strip(&patterns);
+        strip_tag_cache(py, &self.tag_dict, &mut self.tag_keys, &patterns, true);
+        removed
+    }
+
+    fn save(&mut self, py: Python) -> PyResult<()> {
+        self.flac_file.tags = Some(self.vc.borrow(py).vc.clone());
         self.flac_file.save()?;
         invalidate_file(&self.filename);
+        // Pick up the new block layout so later saves and raw reads line up
+        let data = std::fs::read(&self.filename)
+            .map_err(|e| PyIOError::new_err(format!("{}", e)))?;
+        let mut flac_file = flac::FLACFile::parse(&data, &self.filename)?;
+        flac_file.tags = self.flac_file.tags.take();
+        self.flac_file = flac_file;
         Ok(())
     }
 
     #[getter]
     fn pictures(&self, py: Python) -> PyResult<Py<PyList>> {
         let mut pics = Vec::new();
-        // Resolve lazy pictures from the file data
-        for lp in &self.flac_file.lazy_pictures {
+        // Resolve lazy pictures from the file data, copying each image only into its bytes object
+        if !self.flac_file.lazy_pictures.is_empty() {
             let data = std::fs::read(&self.filename)
                 .map_err(|e| PyIOError::new_err(format!("{}", e)))?;
-            if lp.block_offset + lp.block_size <= data.len() {
-                if let Ok(pic) = flac::FLACPicture::parse(&data[lp.block_offset..lp.block_offset + lp.block_size]) {
-                    let dict = PyDict::new(py);
-                    let _ = dict.set_item("type", pic.pic_type);
-                    let _ = dict.set_item("mime", &pic.mime);
-                    let _ = dict.set_item("desc", &pic.desc);
-                    let _ = dict.set_item("width", pic.width);
-                    let _ = dict.set_item("height", pic.height);
-                    let _ = dict.set_item("depth", pic.depth);
-                    let _ = dict.set_item("colors", pic.colors);
-                    let _ = dict.set_item("data", pyo3::types::PyBytes::new(py, &pic.data));
-                    pics.push(dict.into_any().unbind());
+            for lp in &self.flac_file.lazy_pictures {
+                if let Some(block) = data.get(lp.block_offset..lp.block_offset + lp.block_size) {
+                    if let Ok(header) = flac::parse_picture_header(block) {
+                        pics.push(flac_picture_dict(py, &header, block).into_any().unbind());
+                    }
                 }
             }
         }
@@ -780,15 +1693,67 @@ impl PyFLAC {
     }
 
     fn clear(&mut self, py: Python) -> PyResult<()> {
-        self.vc_data = vorbis::VorbisComment::new();
+        let mut vc = self.vc.borrow_mut(py);
+        vc.vc.comments.clear();
         self.tag_keys.clear();
         let dict = self.tag_dict.bind(py);
         dict.clear();
-        if let Some(ref mut tags) = self.flac_file.tags {
-            *tags = vorbis::VorbisComment::new();
-        }
         Ok(())
     }
+
+    /// Serialize info + tags to a JSON string (schema documented on `preserialized_to_json`).
+    /// With `include_pictures`, PICTURE blocks are added as a top-level `"pictures"` array.
+    #[pyo3(signature = (indent=None, include_pictures=false))]
+    fn to_json(&self, py: Python, indent: Option<usize>, include_pictures: bool) -> PyResult<String> {
+        let mut extra = vec![
+            ("bits_per_sample", BatchTagValue::Int(self.info.bits_per_sample as i64)),
+            ("total_samples", BatchTagValue::Int(self.info.total_samples as i64)),
+        ];
+        if let Some(n) = self.info.length_samples {
+            extra.push(("length_samples", BatchTagValue::Int(n as i64)));
+        }
+        if let Some(ms) = self.info.duration_ms {
+            extra.push(("duration_ms", BatchTagValue::Int(ms as i64)));
+        }
+        let pf = PreSerializedFile {
+            length: self.info.length,
+            sample_rate: self.info.sample_rate,
+            channels: self.info.channels as u32,
+            bitrate: if self.info.bitrate > 0 { Some(self.info.bitrate) } else { None },
+            tags: vc_to_batch_tags(&self.vc.borrow(py).vc),
+            extra,
+            lazy_vc: None,
+        };
+        let mut out = String::with_capacity(512);
+        preserialized_to_json(&pf, include_pictures, &mut out);
+        if include_pictures {
+            // Reopen the top-level object to append the pictures array
+            out.pop();
+            out.push_str(",\"pictures\":[");
+            let mut first = true;
+            if !self.flac_file.lazy_pictures.is_empty() {
+                let data = read_cached(&self.filename)
+                    .map_err(|e| PyIOError::new_err(format!("{}", e)))?;
+                for lp in &self.flac_file.lazy_pictures {
+                    let Some(block) = data.get(lp.block_offset..lp.block_offset + lp.block_size) else { continue };
+                    let Ok(header) = flac::parse_picture_header(block) else { continue };
+                    if !first { out.push(','); }
+                    first = false;
+                    picture_json_to(&header.mime, header.pic_type as u8, &header.desc, &block[header.data], &mut out);
+                }
+            }
+            for pic in &self.flac_file.pictures {
+                if !first { out.push(','); }
+                first = false;
+                picture_json_to(&pic.mime, pic.pic_type as u8, &pic.desc, &pic.data, &mut out);
+            }
+            out.push_str("]}");
+        }
+        Ok(match indent {
+            Some(n) => json_pretty(&out, n),
+            None => out,
+        })
+    }
 }
 
 /// OGG Vorbis info.
@@ -797,16 +1762,50 @@ impl PyFLAC {
 struct PyOggVorbisInfo {
     #[pyo3(get)]
     length: f64,
+    /// Granule position of the last page.
+    #[pyo3(get)]
+    length_samples: u64,
+    /// Granule / sample rate in whole milliseconds.
+    #[pyo3(get)]
+    duration_ms: u64,
     #[pyo3(get)]
     channels: u8,
     #[pyo3(get)]
     sample_rate: u32,
     #[pyo3(get)]
     bitrate: u32,
+    /// Serial number of the Vorbis logical stream.
+    #[pyo3(get)]
+    serial: u32,
+    /// Number of pages carrying that serial.
+    #[pyo3(get)]
+    page_count: u32,
+    /// Length/sample rate were recovered from a damaged identification header.
+    #[pyo3(get)]
+    recovered: bool,
+    /// Header values that were out of range and read as 0.
+    #[pyo3(get)]
+    warnings: Vec<String>,
 }
 
 #[pymethods]
 impl PyOggVorbisInfo {
+    /// Vorbis is always VBR; the nominal bitrate is only a hint.
+    #[getter]
+    fn is_vbr(&self) -> bool {
+        true
+    }
+
+    #[getter]
+    fn is_cbr(&self) -> bool {
+        false
+    }
+
+    #[getter]
+    fn is_abr(&self) -> bool {
+        false
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "OggVorbisInfo(length={:.2}, sample_rate={}, channels={})",
@@ -829,39 +1828,47 @@ struct PyOggVorbis {
     info: PyOggVorbisInfo,
     #[pyo3(get)]
     filename: String,
-    vc: PyVComment,
+    /// Shared with the `tags` getter so edits made through it (e.g. `vendor`) are saved.
+    vc: Py<PyVComment>,
     tag_dict: Py<PyDict>,
     tag_keys: Vec<String>,
 }
 
 impl PyOggVorbis {
-    #[inline(always)]
-    fn from_data(py: Python<'_>, data: &[u8], filename: &str) -> PyResult<Self> {
+    /// Structural parse; touches no Python objects, so it can run detached.
+    fn parse_data(data: &[u8], filename: &str) -> PyResult<ogg::OggVorbisFile> {
         let mut ogg_file = ogg::OggVorbisFile::parse(data, filename)?;
         ogg_file.ensure_full_parse(data);
         ogg_file.ensure_tags();
+        Ok(ogg_file)
+    }
 
-        let info = PyOggVorbisInfo {
+    fn make_info(ogg_file: &ogg::OggVorbisFile) -> PyOggVorbisInfo {
+        PyOggVorbisInfo {
             length: ogg_file.info.length,
+            length_samples: ogg_file.info.length_samples,
+            duration_ms: ogg_file.info.duration_ms,
             channels: ogg_file.info.channels,
             sample_rate: ogg_file.info.sample_rate,
             bitrate: ogg_file.info.bitrate,
-        };
+            serial: ogg_file.info.serial,
+            page_count: ogg_file.info.page_count,
+            recovered: ogg_file.info.recovered,
+            warnings: ogg_file.info.warnings.clone(),
+        }
+    }
+
+    #[inline(always)]
+    fn from_parsed(py: Python<'_>, ogg_file: ogg::OggVorbisFile, filename: &str) -> PyResult<Self> {
+        let info = Self::make_info(&ogg_file);
 
         // Pre-build Python dict of all tags
-        let tag_dict = PyDict::new(py);
-        let tag_keys = ogg_file.tags.keys();
-        for key in &tag_keys {
-            let values = ogg_file.tags.get(key);
-            if !values.is_empty() {
-                let _ = tag_dict.set_item(key.as_str(), PyList::new(py, values)?);
-            }
-        }
+        let (tag_dict, tag_keys) = vc_tag_dict(py, &ogg_file.tags)?;
 
-        let vc = PyVComment {
+        let vc = Py::new(py, PyVComment {
             vc: ogg_file.tags,
             path: Some(filename.to_string()),
-        };
+        })?;
 
         Ok(PyOggVorbis {
             info,
@@ -877,15 +1884,23 @@ impl PyOggVorbis {
 impl PyOggVorbis {
     #[new]
     fn new(py: Python<'_>, filename: &str) -> PyResult<Self> {
-        let data = read_cached(filename)
-            .map_err(|e| PyIOError::new_err(format!("{}", e)))?;
-        Self::from_data(py, &data, filename)
+        let parsed = py.detach(|| -> PyResult<_> {
+            let data = read_cached(filename)
+                .map_err(|e| PyIOError::new_err(format!("{}", e)))?;
+            Self::parse_data(&data, filename)
+        })?;
+        Self::from_parsed(py, parsed, filename)
     }
 
     #[getter]
-    fn tags(&self, py: Python) -> PyResult<Py<PyAny>> {
-        let vc = self.vc.clone();
-        Ok(vc.into_pyobject(py)?.into_any().unbind())
+    fn tags(&self, py: Python) -> Py<PyAny> {
+        self.vc.clone_ref(py).into_any()
+    }
+
+    /// Always `"VorbisComment"`: the comment header is mandatory.
+    #[getter]
+    fn tag_version(&self) -> &'static str {
+        vorbis::TAG_VERSION
     }
 
     fn keys(&self) -> Vec<String> {
@@ -905,7 +1920,7 @@ impl PyOggVorbis {
         let values = value.extract::<Vec<String>>().or_else(|_| {
             value.extract::<String>().map(|s| vec![s])
         })?;
-        self.vc.vc.set(key, values.clone());
+        self.vc.borrow_mut(py).vc.set(key, values.clone());
         let _ = self.tag_dict.bind(py).set_item(key, PyList::new(py, &values)?);
         if !self.tag_keys.contains(&key.to_string()) {
             self.tag_keys.push(key.to_string());
@@ -921,12 +1936,21 @@ impl PyOggVorbis {
         format!("OggVorbis(filename={:?})", self.filename)
     }
 
-    fn save(&self) -> PyResult<()> {
+    /// Remove every comment whose key matches one of `patterns`, ignoring case;
+    /// `save()` writes the result. Returns the removed keys.
+    fn strip(&mut self, py: Python, patterns: Vec<String>) -> Vec<String> {
+        let patterns: Vec<&str> = patterns.iter().map(String::as_str).collect();
+        let removed = self.vc.borrow_mut(py).vc.strip(&patterns);
+        strip_tag_cache(py, &self.tag_dict, &mut self.tag_keys, &patterns, true);
+        removed
+    }
+
+    fn save(&self, py: Python) -> PyResult<()> {
         let data = read_cached(&self.filename)
             .map_err(|e| PyIOError::new_err(format!("{}", e)))?;
         let mut ogg_file = ogg::OggVorbisFile::parse(&data, &self.filename)
             .map_err(|e| PyValueError::new_err(format!("{}", e)))?;
-        ogg_file.tags = self.vc.vc.clone();
+        ogg_file.tags = self.vc.borrow(py).vc.clone();
         ogg_file.save()
             .map_err(|e| PyIOError::new_err(format!("{}", e)))?;
         invalidate_file(&self.filename);
@@ -950,192 +1974,192 @@ impl PyOggVorbis {
     }
 
     fn clear(&mut self, py: Python) -> PyResult<()> {
-        self.vc.vc = vorbis::VorbisComment::new();
+        self.vc.borrow_mut(py).vc.comments.clear();
         self.tag_keys.clear();
         let dict = self.tag_dict.bind(py);
         dict.clear();
         Ok(())
     }
+
+    /// Serialize info + tags to a JSON string (schema documented on `preserialized_to_json`).
+    #[pyo3(signature = (indent=None, include_pictures=false))]
+    fn to_json(&self, py: Python, indent: Option<usize>, include_pictures: bool) -> String {
+        let pf = PreSerializedFile {
+            length: Some(self.info.length),
+            sample_rate: self.info.sample_rate,
+            channels: self.info.channels as u32,
+            bitrate: if self.info.bitrate > 0 { Some(self.info.bitrate) } else { None },
+            tags: vc_to_batch_tags(&self.vc.borrow(py).vc),
+            extra: vec![
+                ("length_samples", BatchTagValue::Int(self.info.length_samples as i64)),
+                ("duration_ms", BatchTagValue::Int(self.info.duration_ms as i64)),
+            ],
+            lazy_vc: None,
+        };
+        preserialized_to_json_string(&pf, include_pictures, indent)
+    }
 }
 
-/// MP4 file info.
-#[pyclass(name = "MP4Info", from_py_object)]
+/// Ogg Opus info.
+#[pyclass(name = "OggOpusInfo", from_py_object)]
 #[derive(Debug, Clone)]
-struct PyMP4Info {
+struct PyOggOpusInfo {
+    /// (last granule − pre-skip) / 48000.
     #[pyo3(get)]
     length: f64,
+    /// Decoded samples at 48 kHz, pre-skip excluded.
     #[pyo3(get)]
-    channels: u32,
+    length_samples: u64,
+    #[pyo3(get)]
+    duration_ms: u64,
+    #[pyo3(get)]
+    channels: u8,
+    /// Always 48000: Opus decodes at 48 kHz.
     #[pyo3(get)]
     sample_rate: u32,
+    /// Sample rate of the encoder input (informational).
     #[pyo3(get)]
-    bitrate: u32,
+    input_sample_rate: u32,
+    /// Samples at 48 kHz dropped from the start of the decoded output.
     #[pyo3(get)]
-    bits_per_sample: u32,
+    pre_skip: u16,
+    /// Decoder output gain in dB.
     #[pyo3(get)]
-    codec: String,
+    output_gain: f64,
+    /// Average over the whole file.
     #[pyo3(get)]
-    codec_description: String,
+    bitrate: u32,
+    /// Serial number of the Opus logical stream.
+    #[pyo3(get)]
+    serial: u32,
+    /// Number of pages carrying that serial.
+    #[pyo3(get)]
+    page_count: u32,
 }
 
 #[pymethods]
-impl PyMP4Info {
+impl PyOggOpusInfo {
     fn __repr__(&self) -> String {
         format!(
-            "MP4Info(length={:.2}, codec={}, channels={}, sample_rate={})",
-            self.length, self.codec, self.channels, self.sample_rate
+            "OggOpusInfo(length={:.2}, channels={})",
+            self.length, self.channels
         )
     }
 
     fn pprint(&self) -> String {
-        format!(
-            "MPEG-4 audio ({}), {:.2} seconds, {} bps",
-            self.codec, self.length, self.bitrate
-        )
-    }
-}
-
-/// MP4 tags.
-#[pyclass(name = "MP4Tags", from_py_object)]
-#[derive(Debug, Clone)]
-struct PyMP4Tags {
-    tags: mp4::MP4Tags,
-}
-
-#[pymethods]
-impl PyMP4Tags {
-    fn keys(&self) -> Vec<String> {
-        self.tags.keys()
-    }
-
-    fn __getitem__(&self, py: Python, key: &str) -> PyResult<Py<PyAny>> {
-        match self.tags.get(key) {
-            Some(value) => mp4_value_to_py(py, value),
-            None => Err(PyKeyError::new_err(key.to_string())),
-        }
-    }
-
-    fn __contains__(&self, key: &str) -> bool {
-        self.tags.contains_key(key)
-    }
-
-    fn __len__(&self) -> usize {
-        self.tags.items.len()
-    }
-
-    fn __iter__(&self, py: Python) -> PyResult<Py<PyAny>> {
-        let keys = self.tags.keys();
-        let list = PyList::new(py, &keys)?;
-        Ok(list.call_method0("__iter__")?.into())
-    }
-
-    fn __repr__(&self) -> String {
-        format!("MP4Tags(keys={})", self.tags.keys().join(", "))
+        format!("Ogg Opus, {:.2} seconds", self.length)
     }
 }
 
-/// MP4 file.
-#[pyclass(name = "MP4")]
-struct PyMP4 {
+/// Ogg Opus file.
+#[pyclass(name = "OggOpus")]
+struct PyOggOpus {
     #[pyo3(get)]
-    info: PyMP4Info,
+    info: PyOggOpusInfo,
     #[pyo3(get)]
     filename: String,
-    mp4_tags: PyMP4Tags,
+    /// Shared with the `tags` getter so edits made through it (e.g. `vendor`) are saved.
+    vc: Py<PyVComment>,
     tag_dict: Py<PyDict>,
     tag_keys: Vec<String>,
 }
 
-impl PyMP4 {
-    #[inline(always)]
-    fn from_data(py: Python<'_>, data: &[u8], filename: &str) -> PyResult<Self> {
-        let mut mp4_file = mp4::MP4File::parse(data, filename)?;
-        mp4_file.ensure_parsed_with_data(data);
-
-        let info = PyMP4Info {
-            length: mp4_file.info.length,
-            channels: mp4_file.info.channels,
-            sample_rate: mp4_file.info.sample_rate,
-            bitrate: mp4_file.info.bitrate,
-            bits_per_sample: mp4_file.info.bits_per_sample,
-            codec: mp4_file.info.codec,
-            codec_description: mp4_file.info.codec_description,
-        };
-
-        // Pre-build Python dict of all tags
-        let tag_dict = PyDict::new(py);
-        let tag_keys = mp4_file.tags.keys();
-        for key in &tag_keys {
-            if let Some(value) = mp4_file.tags.get(key) {
-                if let Ok(py_val) = mp4_value_to_py(py, value) {
-                    let _ = tag_dict.set_item(key.as_str(), py_val);
-                }
-            }
-        }
-
-        let mp4_tags = PyMP4Tags {
-            tags: mp4_file.tags,
-        };
-
-        Ok(PyMP4 {
+impl PyOggOpus {
+    /// Structural parse; touches no Python objects, so it can run detached.
+    fn parse_data(data: &[u8], filename: &str) -> PyResult<ogg::opus::OpusFile> {
+        let mut opus_file = ogg::opus::OpusFile::parse(data, filename)?;
+        opus_file.ensure_tags();
+        Ok(opus_file)
+    }
+
+    fn make_info(opus_file: &ogg::opus::OpusFile) -> PyOggOpusInfo {
+        let info = &opus_file.info;
+        PyOggOpusInfo {
+            length: info.length,
+            length_samples: info.length_samples,
+            duration_ms: info.duration_ms,
+            channels: info.channels,
+            sample_rate: ogg::opus::GRANULE_RATE,
+            input_sample_rate: info.input_sample_rate,
+            pre_skip: info.pre_skip,
+            output_gain: info.output_gain as f64 / 256.0,
+            bitrate: info.bitrate,
+            serial: info.serial,
+            page_count: info.page_count,
+        }
+    }
+
+    fn from_parsed(py: Python<'_>, opus_file: ogg::opus::OpusFile, filename: &str) -> PyResult<Self> {
+        let info = Self::make_info(&opus_file);
+        let (tag_dict, tag_keys) = vc_tag_dict(py, &opus_file.tags)?;
+        let vc = Py::new(py, PyVComment {
+            vc: opus_file.tags,
+            path: Some(filename.to_string()),
+        })?;
+        Ok(PyOggOpus {
             info,
             filename: filename.to_string(),
-            mp4_tags,
+            vc,
             tag_dict: tag_dict.into(),
             tag_keys,
         })
     }
+
+    /// Re-read the file and write `vc` as its `OpusTags` packet.
+    fn write_tags(&self, vc: vorbis::VorbisComment) -> PyResult<()> {
+        let data = read_cached(&self.filename)
+            .map_err(|e| PyIOError::new_err(format!("{}", e)))?;
+        let mut opus_file = ogg::opus::OpusFile::parse(&data, &self.filename)?;
+        opus_file.tags = vc;
+        opus_file.save()?;
+        invalidate_file(&self.filename);
+        Ok(())
+    }
 }
 
 #[pymethods]
-impl PyMP4 {
+impl PyOggOpus {
     #[new]
     fn new(py: Python<'_>, filename: &str) -> PyResult<Self> {
-        let data = read_cached(filename)
-            .map_err(|e| PyIOError::new_err(format!("{}", e)))?;
-        Self::from_data(py, &data, filename)
+        let parsed = py.detach(|| -> PyResult<_> {
+            let data = read_cached(filename)
+                .map_err(|e| PyIOError::new_err(format!("{}", e)))?;
+            Self::parse_data(&data, filename)
+        })?;
+        Self::from_parsed(py, parsed, filename)
     }
 
     #[getter]
-    fn tags(&self, py: Python) -> PyResult<Py<PyAny>> {
-        let tags = self.mp4_tags.clone();
-        Ok(tags.into_pyobject(py)?.into_any().unbind())
+    fn tags(&self, py: Python) -> Py<PyAny> {
+        self.vc.clone_ref(py).into_any()
+    }
+
+    /// Always `"VorbisComment"`: the `OpusTags` packet is mandatory.
+    #[getter]
+    fn tag_version(&self) -> &'static str {
+        vorbis::TAG_VERSION
     }
 
     fn keys(&self) -> Vec<String> {
         self.tag_keys.clone()
     }
 
-    #[inline(always)]
     fn __getitem__(&self, py: Python, key: &str) -> PyResult<Py<PyAny>> {
-        let dict = self.tag_dict.bind(py);
-        match dict.get_item(key)? {
+        match self.tag_dict.bind(py).get_item(key)? {
             Some(val) => Ok(val.unbind()),
             None => Err(PyKeyError::new_err(key.to_string())),
         }
     }
 
     fn __setitem__(&mut self, py: Python, key: &str, value: &Bound<'_, PyAny>) -> PyResult<()> {
-        let tag_value = py_to_mp4_value(key, value)?;
-        // Update cached Python dict
-        let py_val = mp4_value_to_py(py, &tag_value)?;
-        let _ = self.tag_dict.bind(py).set_item(key, py_val);
+        let values = value.extract::<Vec<String>>().or_else(|_| {
+            value.extract::<String>().map(|s| vec![s])
+        })?;
+        self.vc.borrow_mut(py).vc.set(key, values.clone());
+        let _ = self.tag_dict.bind(py).set_item(key, PyList::new(py, &values)?);
         if !self.tag_keys.contains(&key.to_string()) {
             self.tag_keys.push(key.to_string());
         }
-        // Update underlying tag storage
-        self.mp4_tags.tags.set(key, tag_value);
-        Ok(())
-    }
-
-    fn __delitem__(&mut self, py: Python, key: &str) -> PyResult<()> {
-        let dict = self.tag_dict.bind(py);
-        if dict.get_item(key)?.is_none() {
-            return Err(PyKeyError::new_err(key.to_string()));
-        }
-        dict.del_item(key)?;
-        self.tag_keys.retain(|k| k != key);
-        self.mp4_tags.tags.delete(key);
         Ok(())
     }
 
@@ -1143,23 +2167,28 @@ impl PyMP4 {
         self.tag_dict.bind(py).get_item(key).ok().flatten().is_some()
     }
 
-    fn save(&self) -> PyResult<()> {
-        mp4::save_mp4_tags(&self.filename, &self.mp4_tags.tags)
-            .map_err(|e| PyIOError::new_err(format!("{}", e)))?;
-        invalidate_file(&self.filename);
-        Ok(())
+    fn __repr__(&self) -> String {
+        format!("OggOpus(filename={:?})", self.filename)
     }
 
-    fn delete(&self) -> PyResult<()> {
-        let empty = mp4::MP4Tags::new();
-        mp4::save_mp4_tags(&self.filename, &empty)
-            .map_err(|e| PyIOError::new_err(format!("{}", e)))?;
-        invalidate_file(&self.filename);
-        Ok(())
+    /// Remove every comment whose key matches one of `patterns`, ignoring case;
+    /// `save()` writes the result. Returns the removed keys.
+    fn strip(&mut self, py: Python, patterns: Vec<String>) -> Vec<String> {
+        let patterns: Vec<&str> = patterns.iter().map(String::as_str).collect();
+        let removed = self.vc.borrow_mut(py).vc.strip(&patterns);
+        strip_tag_cache(py, &self.tag_dict, &mut self.tag_keys, &patterns, true);
+        removed
     }
 
-    fn __repr__(&self) -> String {
-        format!("MP4(filename={:?})", self.filename)
+    fn save(&self, py: Python) -> PyResult<()> {
+        self.write_tags(self.vc.borrow(py).vc.clone())
+    }
+
+    /// Write an empty `OpusTags` packet, keeping the vendor string.
+    fn delete(&self, py: Python) -> PyResult<()> {
+        let mut vc = vorbis::VorbisComment::new();
+        vc.vendor = self.vc.borrow(py).vc.vendor.clone();
+        self.write_tags(vc)
     }
 
     fn add_tags(&self) -> PyResult<()> {
@@ -1167,22 +2196,763 @@ impl PyMP4 {
     }
 
     fn clear(&mut self, py: Python) -> PyResult<()> {
-        self.mp4_tags.tags.items.clear();
+        self.vc.borrow_mut(py).vc.comments.clear();
         self.tag_keys.clear();
-        let dict = self.tag_dict.bind(py);
-        dict.clear();
+        self.tag_dict.bind(py).clear();
         Ok(())
     }
-}
 
-// ---- Helper functions ----
+    /// Serialize info + tags to a JSON string (schema documented on `preserialized_to_json`).
+    #[pyo3(signature = (indent=None, include_pictures=false))]
+    fn to_json(&self, py: Python, indent: Option<usize>, include_pictures: bool) -> String {
+        let pf = PreSerializedFile {
+            length: Some(self.info.length),
+            sample_rate: self.info.sample_rate,
+            channels: self.info.channels as u32,
+            bitrate: if self.info.bitrate > 0 { Some(self.info.bitrate) } else { None },
+            tags: vc_to_batch_tags(&self.vc.borrow(py).vc),
+            extra: vec![
+                ("length_samples", BatchTagValue::Int(self.info.length_samples as i64)),
+                ("duration_ms", BatchTagValue::Int(self.info.duration_ms as i64)),
+            ],
+            lazy_vc: None,
+        };
+        preserialized_to_json_string(&pf, include_pictures, indent)
+    }
+}
 
-#[inline(always)]
-fn make_mpeg_info(info: &mp3::MPEGInfo) -> PyMPEGInfo {
-    PyMPEGInfo {
-        length: info.length,
-        channels: info.channels,
-        bitrate: info.bitrate,
+/// MP4 file info.
+#[pyclass(name = "MP4Info", from_py_object)]
+#[derive(Debug, Clone)]
+struct PyMP4Info {
+    #[pyo3(get)]
+    length: f64,
+    /// Audio track `mdhd` duration in samples.
+    #[pyo3(get)]
+    length_samples: u64,
+    /// `mvhd` duration / timescale in whole milliseconds.
+    #[pyo3(get)]
+    duration_ms: u64,
+    #[pyo3(get)]
+    channels: u32,
+    /// `"esds"` if the AudioSpecificConfig overrode the sample entry's channel count.
+    #[pyo3(get)]
+    channels_source: &'static str,
+    #[pyo3(get)]
+    sample_rate: u32,
+    #[pyo3(get)]
+    bitrate: u32,
+    #[pyo3(get)]
+    bits_per_sample: u32,
+    #[pyo3(get)]
+    codec: String,
+    #[pyo3(get)]
+    codec_description: String,
+    /// Header values that were out of range and read as 0.
+    #[pyo3(get)]
+    warnings: Vec<String>,
+}
+
+#[pymethods]
+impl PyMP4Info {
+    fn __repr__(&self) -> String {
+        format!(
+            "MP4Info(length={:.2}, codec={}, channels={}, sample_rate={})",
+            self.length, self.codec, self.channels, self.sample_rate
+        )
+    }
+
+    fn pprint(&self) -> String {
+        format!(
+            "MPEG-4 audio ({}), {:.2} seconds, {} bps",
+            self.codec, self.length, self.bitrate
+        )
+    }
+}
+
+/// MP4 tags.
+#[pyclass(name = "MP4Tags", from_py_object)]
+#[derive(Debug, Clone)]
+struct PyMP4Tags {
+    tags: mp4::MP4Tags,
+}
+
+#[pymethods]
+impl PyMP4Tags {
+    fn keys(&self) -> Vec<String> {
+        self.tags.keys()
+    }
+
+    fn __getitem__(&self, py: Python, key: &str) -> PyResult<Py<PyAny>> {
+        match self.tags.get(key) {
+            Some(value) => mp4_value_to_py(py, value),
+            None => Err(PyKeyError::new_err(key.to_string())),
+        }
+    }
+
+    fn __contains__(&self, key: &str) -> bool {
+        self.tags.contains_key(key)
+    }
+
+    fn __len__(&self) -> usize {
+        self.tags.items.len()
+    }
+
+    fn __iter__(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let keys = self.tags.keys();
+        let list = PyList::new(py, &keys)?;
+        Ok(list.call_method0("__iter__")?.into())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("MP4Tags(keys={})", self.tags.keys().join(", "))
+    }
+
+    /// Remove every item whose key matches one of `patterns` (`"covr"`, or
+    /// `"----*"` as a prefix). Returns the removed keys.
+    fn strip(&mut self, patterns: Vec<String>) -> Vec<String> {
+        let patterns: Vec<&str> = patterns.iter().map(String::as_str).collect();
+        self.tags.strip(&patterns)
+    }
+}
+
+/// APEv2 tag at the end of a file (MP3, Monkey's Audio, ...). Read-only; item
+/// flags are kept for a later write.
+#[pyclass(name = "APEv2", from_py_object)]
+#[derive(Debug, Clone)]
+struct PyAPEv2 {
+    tag: ape::apev2::APEv2Tag,
+}
+
+#[pymethods]
+impl PyAPEv2 {
+    #[new]
+    fn new(py: Python<'_>, filename: &str) -> PyResult<Self> {
+        let data = py.detach(|| read_cached(filename))
+            .map_err(|e| PyIOError::new_err(format!("{}", e)))?;
+        let tag = ape::apev2::parse(&data).ok_or(common::error::MutagenError::APENoHeader)?;
+        Ok(PyAPEv2 { tag })
+    }
+
+    fn keys(&self) -> Vec<String> {
+        self.tag.items.iter().map(|item| item.key.clone()).collect()
+    }
+
+    /// Text and external items as a list of strings, binary items as bytes.
+    fn __getitem__(&self, py: Python, key: &str) -> PyResult<Py<PyAny>> {
+        let item = self.tag.get(key).ok_or_else(|| PyKeyError::new_err(key.to_string()))?;
+        Ok(match item.text() {
+            Some(values) => PyList::new(py, values)?.into_any().unbind(),
+            None => PyBytes::new(py, &item.value).into_any().unbind(),
+        })
+    }
+
+    fn __contains__(&self, key: &str) -> bool {
+        self.tag.get(key).is_some()
+    }
+
+    fn __len__(&self) -> usize {
+        self.tag.items.len()
+    }
+
+    fn __iter__(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let list = PyList::new(py, self.keys())?;
+        Ok(list.call_method0("__iter__")?.into())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("APEv2(keys={})", self.keys().join(", "))
+    }
+
+    /// 1000 for APEv1, 2000 for APEv2.
+    #[getter]
+    fn version(&self) -> u32 {
+        self.tag.version
+    }
+
+    /// Value kind of the item `key`: `"text"`, `"binary"` or `"external"`.
+    fn kind(&self, key: &str) -> PyResult<&'static str> {
+        let item = self.tag.get(key).ok_or_else(|| PyKeyError::new_err(key.to_string()))?;
+        Ok(match item.kind() {
+            ape::apev2::APEValueKind::Text => "text",
+            ape::apev2::APEValueKind::Binary => "binary",
+            ape::apev2::APEValueKind::External => "external",
+        })
+    }
+}
+
+/// Tag dict and key list of the read-only formats tagged only with APEv2
+/// (WavPack, Monkey's Audio); their pyclasses delegate the mapping methods here.
+struct APETagCache {
+    tag_dict: Py<PyDict>,
+    tag_keys: Vec<String>,
+}
+
+impl APETagCache {
+    fn new(py: Python<'_>, tag: Option<&ape::apev2::APEv2Tag>) -> PyResult<Self> {
+        let (tag_dict, tag_keys) = ape_tag_dict(py, tag)?;
+        Ok(APETagCache { tag_dict: tag_dict.unbind(), tag_keys })
+    }
+
+    fn get(&self, py: Python, key: &str) -> PyResult<Py<PyAny>> {
+        match self.tag_dict.bind(py).get_item(key)? {
+            Some(val) => Ok(val.unbind()),
+            None => Err(PyKeyError::new_err(key.to_string())),
+        }
+    }
+
+    fn contains(&self, py: Python, key: &str) -> bool {
+        self.tag_dict.bind(py).get_item(key).ok().flatten().is_some()
+    }
+
+    fn iter(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let list = PyList::new(py, &self.tag_keys)?;
+        Ok(list.call_method0("__iter__")?.into())
+    }
+
+    /// `info` followed by a `key=value` line per item (binary items by size).
+    fn pprint(info: String, tag: Option<&ape::apev2::APEv2Tag>) -> String {
+        let mut out = info;
+        for item in tag.iter().flat_map(|t| &t.items) {
+            match item.text() {
+                Some(values) => out.push_str(&format!("\n{}={}", item.key, values.join(" / "))),
+                None => out.push_str(&format!("\n{}=[{} bytes]", item.key, item.value.len())),
+            }
+        }
+        out
+    }
+}
+
+/// WavPack stream info.
+#[pyclass(name = "WavPackInfo", from_py_object)]
+#[derive(Debug, Clone)]
+struct PyWavPackInfo {
+    #[pyo3(get)]
+    length: f64,
+    #[pyo3(get)]
+    length_samples: u64,
+    #[pyo3(get)]
+    duration_ms: u64,
+    #[pyo3(get)]
+    sample_rate: u32,
+    #[pyo3(get)]
+    channels: u32,
+    /// 1 for DSD audio.
+    #[pyo3(get)]
+    bits_per_sample: u32,
+    #[pyo3(get)]
+    bitrate: u32,
+    /// Stream version from the block header, e.g. 0x403.
+    #[pyo3(get)]
+    version: u16,
+}
+
+#[pymethods]
+impl PyWavPackInfo {
+    fn __repr__(&self) -> String {
+        format!(
+            "WavPackInfo(length={:.2}, sample_rate={}, channels={})",
+            self.length, self.sample_rate, self.channels
+        )
+    }
+
+    fn pprint(&self) -> String {
+        format!("WavPack, {:.2} seconds, {} Hz", self.length, self.sample_rate)
+    }
+}
+
+/// WavPack file (read-only): stream info and the trailing APEv2 tag.
+#[pyclass(name = "WavPack")]
+struct PyWavPack {
+    #[pyo3(get)]
+    info: PyWavPackInfo,
+    #[pyo3(get)]
+    filename: String,
+    file: wavpack::WavPackFile,
+    cache: APETagCache,
+}
+
+impl PyWavPack {
+    fn make_info(f: &wavpack::WavPackFile) -> PyWavPackInfo {
+        let info = &f.info;
+        PyWavPackInfo {
+            length: info.length,
+            length_samples: info.length_samples,
+            duration_ms: info.duration_ms,
+            sample_rate: info.sample_rate,
+            channels: info.channels,
+            bits_per_sample: info.bits_per_sample,
+            bitrate: info.bitrate,
+            version: info.version,
+        }
+    }
+
+    fn from_parsed(py: Python<'_>, f: wavpack::WavPackFile, filename: &str) -> PyResult<Self> {
+        Ok(PyWavPack {
+            info: Self::make_info(&f),
+            filename: filename.to_string(),
+            cache: APETagCache::new(py, f.tags.as_ref())?,
+            file: f,
+        })
+    }
+}
+
+#[pymethods]
+impl PyWavPack {
+    #[new]
+    fn new(py: Python<'_>, filename: &str) -> PyResult<Self> {
+        let parsed = py.detach(|| -> PyResult<_> {
+            let data = read_cached(filename)
+                .map_err(|e| PyIOError::new_err(format!("{}", e)))?;
+            Ok(wavpack::WavPackFile::parse(&data, filename)?)
+        })?;
+        Self::from_parsed(py, parsed, filename)
+    }
+
+    /// The APEv2 tag, or `None` when the file has none.
+    #[getter]
+    fn tags(&self) -> Option<PyAPEv2> {
+        self.file.tags.clone().map(|tag| PyAPEv2 { tag })
+    }
+
+    fn keys(&self) -> Vec<String> {
+        self.cache.tag_keys.clone()
+    }
+
+    fn __getitem__(&self, py: Python, key: &str) -> PyResult<Py<PyAny>> {
+        self.cache.get(py, key)
+    }
+
+    fn __contains__(&self, py: Python, key: &str) -> bool {
+        self.cache.contains(py, key)
+    }
+
+    fn __len__(&self) -> usize {
+        self.cache.tag_keys.len()
+    }
+
+    fn __iter__(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.cache.iter(py)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("WavPack(filename={:?})", self.filename)
+    }
+
+    fn pprint(&self) -> String {
+        APETagCache::pprint(self.info.pprint(), self.file.tags.as_ref())
+    }
+
+    /// Serialize info + tags to a JSON string (schema documented on `preserialized_to_json`).
+    #[pyo3(signature = (indent=None, include_pictures=false))]
+    fn to_json(&self, indent: Option<usize>, include_pictures: bool) -> String {
+        preserialized_to_json_string(&wavpack_preserialized(&self.file), include_pictures, indent)
+    }
+}
+
+/// Monkey's Audio stream info.
+#[pyclass(name = "MonkeysAudioInfo", from_py_object)]
+#[derive(Debug, Clone)]
+struct PyMonkeysAudioInfo {
+    #[pyo3(get)]
+    length: f64,
+    #[pyo3(get)]
+    length_samples: u64,
+    #[pyo3(get)]
+    duration_ms: u64,
+    #[pyo3(get)]
+    sample_rate: u32,
+    #[pyo3(get)]
+    channels: u32,
+    #[pyo3(get)]
+    bits_per_sample: u32,
+    #[pyo3(get)]
+    bitrate: u32,
+    /// Encoder version, e.g. 3.99; frame sizes (and so bitrates) differ per version.
+    #[pyo3(get)]
+    version: f64,
+    /// Out-of-range header values read as 0.
+    #[pyo3(get)]
+    warnings: Vec<String>,
+}
+
+#[pymethods]
+impl PyMonkeysAudioInfo {
+    fn __repr__(&self) -> String {
+        format!(
+            "MonkeysAudioInfo(length={:.2}, sample_rate={}, channels={}, version={:.2})",
+            self.length, self.sample_rate, self.channels, self.version
+        )
+    }
+
+    fn pprint(&self) -> String {
+        format!(
+            "Monkey's Audio {:.2}, {:.2} seconds, {} Hz",
+            self.version, self.length, self.sample_rate
+        )
+    }
+}
+
+/// Monkey's Audio file (read-only): stream info and the trailing APEv2 tag.
+#[pyclass(name = "MonkeysAudio")]
+struct PyMonkeysAudio {
+    #[pyo3(get)]
+    info: PyMonkeysAudioInfo,
+    #[pyo3(get)]
+    filename: String,
+    file: ape::APEFile,
+    cache: APETagCache,
+}
+
+impl PyMonkeysAudio {
+    fn make_info(f: &ape::APEFile) -> PyMonkeysAudioInfo {
+        let info = &f.info;
+        PyMonkeysAudioInfo {
+            length: info.length,
+            length_samples: info.length_samples,
+            duration_ms: info.duration_ms,
+            sample_rate: info.sample_rate,
+            channels: info.channels,
+            bits_per_sample: info.bits_per_sample,
+            bitrate: info.bitrate,
+            version: info.version,
+            warnings: info.warnings.clone(),
+        }
+    }
+
+    fn from_parsed(py: Python<'_>, f: ape::APEFile, filename: &str) -> PyResult<Self> {
+        Ok(PyMonkeysAudio {
+            info: Self::make_info(&f),
+            filename: filename.to_string(),
+            cache: APETagCache::new(py, f.tags.as_ref())?,
+            file: f,
+        })
+    }
+}
+
+#[pymethods]
+impl PyMonkeysAudio {
+    #[new]
+    fn new(py: Python<'_>, filename: &str) -> PyResult<Self> {
+        let parsed = py.detach(|| -> PyResult<_> {
+            let data = read_cached(filename)
+                .map_err(|e| PyIOError::new_err(format!("{}", e)))?;
+            Ok(ape::APEFile::parse(&data, filename)?)
+        })?;
+        Self::from_parsed(py, parsed, filename)
+    }
+
+    /// The APEv2 tag, or `None` when the file has none.
+    #[getter]
+    fn tags(&self) -> Option<PyAPEv2> {
+        self.file.tags.clone().map(|tag| PyAPEv2 { tag })
+    }
+
+    fn keys(&self) -> Vec<String> {
+        self.cache.tag_keys.clone()
+    }
+
+    fn __getitem__(&self, py: Python, key: &str) -> PyResult<Py<PyAny>> {
+        self.cache.get(py, key)
+    }
+
+    fn __contains__(&self, py: Python, key: &str) -> bool {
+        self.cache.contains(py, key)
+    }
+
+    fn __len__(&self) -> usize {
+        self.cache.tag_keys.len()
+    }
+
+    fn __iter__(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.cache.iter(py)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("MonkeysAudio(filename={:?})", self.filename)
+    }
+
+    fn pprint(&self) -> String {
+        APETagCache::pprint(self.info.pprint(), self.file.tags.as_ref())
+    }
+
+    /// Serialize info + tags to a JSON string (schema documented on `preserialized_to_json`).
+    #[pyo3(signature = (indent=None, include_pictures=false))]
+    fn to_json(&self, indent: Option<usize>, include_pictures: bool) -> String {
+        preserialized_to_json_string(&ape_preserialized(&self.file), include_pictures, indent)
+    }
+}
+
+/// MP4 file.
+#[pyclass(name = "MP4")]
+struct PyMP4 {
+    #[pyo3(get)]
+    info: PyMP4Info,
+    #[pyo3(get)]
+    filename: String,
+    mp4_tags: PyMP4Tags,
+    tag_dict: Py<PyDict>,
+    tag_keys: Vec<String>,
+    chapters: Vec<mp4::MP4Chapter>,
+    /// Handler type of the `meta` box, e.g. `"mdir"`; `None` without one.
+    #[pyo3(get)]
+    tag_version: Option<String>,
+    /// File offset of the `moov` atom header.
+    #[pyo3(get)]
+    moov_offset: usize,
+    /// Size of the `moov` atom, header included.
+    #[pyo3(get)]
+    moov_size: usize,
+    /// Whether `moov` precedes the first `mdat` (no faststart remux needed).
+    #[pyo3(get)]
+    is_faststart: bool,
+}
+
+impl PyMP4 {
+    /// Structural parse; touches no Python objects, so it can run detached.
+    fn parse_data(data: &[u8], filename: &str) -> PyResult<mp4::MP4File> {
+        let mut mp4_file = mp4::MP4File::parse(data, filename)?;
+        mp4_file.ensure_parsed_with_data(data);
+        Ok(mp4_file)
+    }
+
+    fn make_info(info: mp4::MP4Info) -> PyMP4Info {
+        PyMP4Info {
+            length: info.length,
+            length_samples: info.length_samples,
+            duration_ms: info.duration_ms,
+            channels: info.channels,
+            channels_source: info.channels_source,
+            sample_rate: info.sample_rate,
+            bitrate: info.bitrate,
+            bits_per_sample: info.bits_per_sample,
+            codec: info.codec,
+            codec_description: info.codec_description,
+            warnings: info.warnings,
+        }
+    }
+
+    #[inline(always)]
+    fn from_parsed(py: Python<'_>, mp4_file: mp4::MP4File, filename: &str) -> PyResult<Self> {
+        let (moov_offset, moov_size, is_faststart) =
+            (mp4_file.moov_offset(), mp4_file.moov_size(), mp4_file.is_faststart());
+        let info = Self::make_info(mp4_file.info);
+
+        // Pre-build Python dict of all tags
+        let (tag_dict, tag_keys) = mp4_tag_dict(py, &mp4_file.tags);
+
+        let mp4_tags = PyMP4Tags {
+            tags: mp4_file.tags,
+        };
+
+        Ok(PyMP4 {
+            info,
+            filename: filename.to_string(),
+            mp4_tags,
+            tag_dict: tag_dict.into(),
+            tag_keys,
+            chapters: mp4_file.chapters,
+            tag_version: mp4_file.meta_handler,
+            moov_offset,
+            moov_size,
+            is_faststart,
+        })
+    }
+}
+
+#[pymethods]
+impl PyMP4 {
+    #[new]
+    fn new(py: Python<'_>, filename: &str) -> PyResult<Self> {
+        let parsed = py.detach(|| -> PyResult<_> {
+            let data = read_cached(filename)
+                .map_err(|e| PyIOError::new_err(format!("{}", e)))?;
+            Self::parse_data(&data, filename)
+        })?;
+        Self::from_parsed(py, parsed, filename)
+    }
+
+    #[getter]
+    fn tags(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let tags = self.mp4_tags.clone();
+        Ok(tags.into_pyobject(py)?.into_any().unbind())
+    }
+
+    fn keys(&self) -> Vec<String> {
+        self.tag_keys.clone()
+    }
+
+    #[inline(always)]
+    fn __getitem__(&self, py: Python, key: &str) -> PyResult<Py<PyAny>> {
+        let dict = self.tag_dict.bind(py);
+        match dict.get_item(key)? {
+            Some(val) => Ok(val.unbind()),
+            None => Err(PyKeyError::new_err(key.to_string())),
+        }
+    }
+
+    fn __setitem__(&mut self, py: Python, key: &str, value: &Bound<'_, PyAny>) -> PyResult<()> {
+        let tag_value = py_to_mp4_value(key, value)?;
+        // Update cached Python dict
+        let py_val = mp4_value_to_py(py, &tag_value)?;
+        let _ = self.tag_dict.bind(py).set_item(key, py_val);
+        if !self.tag_keys.contains(&key.to_string()) {
+            self.tag_keys.push(key.to_string());
+        }
+        // Update underlying tag storage
+        self.mp4_tags.tags.set(key, tag_value);
+        Ok(())
+    }
+
+    fn __delitem__(&mut self, py: Python, key: &str) -> PyResult<()> {
+        let dict = self.tag_dict.bind(py);
+        if dict.get_item(key)?.is_none() {
+            return Err(PyKeyError::new_err(key.to_string()));
+        }
+        dict.del_item(key)?;
+        self.tag_keys.retain(|k| k != key);
+        self.mp4_tags.tags.delete(key);
+        Ok(())
+    }
+
+    fn __contains__(&self, py: Python, key: &str) -> bool {
+        self.tag_dict.bind(py).get_item(key).ok().flatten().is_some()
+    }
+
+    /// Exact bytes (header + children) of the ilst item atom for `key`.
+    /// Atoms not replaced with `set_raw` are read from the file on each call.
+    fn get_raw_atom<'py>(&self, py: Python<'py>, key: &str) -> PyResult<Bound<'py, PyBytes>> {
+        let tags = &self.mp4_tags.tags;
+        if let Some((_, atom)) = tags.verbatim.iter().find(|(k, _)| k == key) {
+            return Ok(PyBytes::new(py, atom));
+        }
+        let (off, size) = tags.raw_span(key).ok_or_else(|| PyKeyError::new_err(key.to_string()))?;
+        Ok(PyBytes::new(py, &read_file_span(&self.filename, off, size)?))
+    }
+
+    /// Tempo in BPM as a float: the `----:com.apple.iTunes:BPM` freeform when
+    /// it holds a number, else the integer `tmpo`; `None` without either.
+    fn bpm(&self) -> Option<f64> {
+        self.mp4_tags.tags.bpm()
+    }
+
+    /// Data atom locale of each value of `key`, in value order (0 = default).
+    fn get_locales(&self, key: &str) -> PyResult<Vec<u32>> {
+        if !self.mp4_tags.tags.contains_key(key) {
+            return Err(PyKeyError::new_err(key.to_string()));
+        }
+        Ok(self.mp4_tags.tags.value_locales(key))
+    }
+
+    /// Nero chapters as `(start_seconds, title)` tuples, including any set
+    /// with `set_chapters` but not yet saved.
+    #[getter]
+    fn chapters(&self) -> Vec<(f64, String)> {
+        self.chapters.iter().map(|c| (c.start, c.title.clone())).collect()
+    }
+
+    /// Replace the chapters with `(start_seconds, title)` pairs; written as a
+    /// `chpl` atom in `moov/udta` on save. An empty list removes them.
+    fn set_chapters(&mut self, chapters: Vec<(f64, String)>) -> PyResult<()> {
+        let chapters: Vec<mp4::MP4Chapter> = chapters.into_iter()
+            .map(|(start, title)| mp4::MP4Chapter { start, title })
+            .collect();
+        self.mp4_tags.tags.set_chapters(chapters.clone())?;
+        self.chapters = chapters;
+        Ok(())
+    }
+
+    /// Replace `key` with a complete item atom written verbatim on save.
+    fn set_raw(&mut self, py: Python, key: &str, data: Vec<u8>) -> PyResult<()> {
+        self.mp4_tags.tags.set_raw(key, data)?;
+        let _ = self.tag_dict.bind(py).del_item(key);
+        self.tag_keys.retain(|k| k != key);
+        Ok(())
+    }
+
+    /// Remove every item whose key matches one of `patterns`; `save()` writes
+    /// the result. Returns the removed keys.
+    fn strip(&mut self, py: Python, patterns: Vec<String>) -> Vec<String> {
+        let patterns: Vec<&str> = patterns.iter().map(String::as_str).collect();
+        let removed = self.mp4_tags.tags.strip(&patterns);
+        strip_tag_cache(py, &self.tag_dict, &mut self.tag_keys, &patterns, false);
+        removed
+    }
+
+    /// Write the tags back. Files that can't be rewritten (e.g. Audible DRM)
+    /// raise `MP4Error`; read and write failures raise `IOError`.
+    fn save(&mut self) -> PyResult<()> {
+        mp4::save_mp4_tags(&self.filename, &self.mp4_tags.tags)?;
+        invalidate_file(&self.filename);
+        // Pick up the new atom layout so later raw reads line up
+        let data = std::fs::read(&self.filename)
+            .map_err(|e| PyIOError::new_err(format!("{}", e)))?;
+        let saved = Self::parse_data(&data, &self.filename)?;
+        self.moov_offset = saved.moov_offset();
+        self.moov_size = saved.moov_size();
+        self.is_faststart = saved.is_faststart();
+        self.mp4_tags.tags.spans = saved.tags.spans;
+        Ok(())
+    }
+
+    fn delete(&self) -> PyResult<()> {
+        let empty = mp4::MP4Tags::new();
+        mp4::save_mp4_tags(&self.filename, &empty)?;
+        invalidate_file(&self.filename);
+        Ok(())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("MP4(filename={:?})", self.filename)
+    }
+
+    fn add_tags(&self) -> PyResult<()> {
+        Ok(())
+    }
+
+    fn clear(&mut self, py: Python) -> PyResult<()> {
+        self.mp4_tags.tags.items.clear();
+        self.mp4_tags.tags.locales.clear();
+        self.tag_keys.clear();
+        let dict = self.tag_dict.bind(py);
+        dict.clear();
+        Ok(())
+    }
+
+    /// Serialize info + tags to a JSON string (schema documented on `preserialized_to_json`).
+    #[pyo3(signature = (indent=None, include_pictures=false))]
+    fn to_json(&self, indent: Option<usize>, include_pictures: bool) -> String {
+        let pf = PreSerializedFile {
+            length: Some(self.info.length),
+            sample_rate: self.info.sample_rate,
+            channels: self.info.channels,
+            bitrate: if self.info.bitrate > 0 { Some(self.info.bitrate) } else { None },
+            tags: self.mp4_tags.tags.items.iter()
+                .map(|(key, value)| (key.clone(), mp4_value_to_batch(value.clone())))
+                .collect(),
+            extra: vec![
+                ("length_samples", BatchTagValue::Int(self.info.length_samples as i64)),
+                ("duration_ms", BatchTagValue::Int(self.info.duration_ms as i64)),
+                ("codec", BatchTagValue::Text(self.info.codec.clone())),
+                ("bits_per_sample", BatchTagValue::Int(self.info.bits_per_sample as i64)),
+            ],
+            lazy_vc: None,
+        };
+        preserialized_to_json_string(&pf, include_pictures, indent)
+    }
+}
+
+// ---- Helper functions ----
+
+#[inline(always)]
+fn make_mpeg_info(info: &mp3::MPEGInfo) -> PyMPEGInfo {
+    PyMPEGInfo {
+        length: info.length,
+        length_samples: info.length_samples,
+        duration_ms: info.duration_ms,
+        xing_frames: info.xing_frames,
+        channels: info.channels,
+        bitrate: info.bitrate,
         sample_rate: info.sample_rate,
         version: info.version,
         layer: info.layer,
@@ -1233,6 +3003,17 @@ fn frame_to_py(py: Python, frame: &id3::frames::Frame) -> Py<PyAny> {
         id3::frames::Frame::Lyrics(f) => {
             f.text.as_str().into_pyobject(py).unwrap().into_any().unbind()
         }
+        id3::frames::Frame::SyncLyrics(f) => {
+            // `sync` times are in the unit given by `format`; the MP3 readers
+            // turn MPEG frame counts into milliseconds (`sylt_values_to_ms`)
+            let dict = PyDict::new(py);
+            dict.set_item("lang", &f.lang).unwrap();
+            dict.set_item("desc", &f.desc).unwrap();
+            dict.set_item("format", f.format).unwrap();
+            dict.set_item("type", f.content_type).unwrap();
+            dict.set_item("sync", PyList::new(py, &f.sync).unwrap()).unwrap();
+            dict.into_any().unbind()
+        }
         id3::frames::Frame::Picture(f) => {
             let dict = PyDict::new(py);
             dict.set_item("mime", &f.mime).unwrap();
@@ -1241,6 +3022,14 @@ fn frame_to_py(py: Python, frame: &id3::frames::Frame) -> Py<PyAny> {
             dict.set_item("data", PyBytes::new(py, &f.data)).unwrap();
             dict.into_any().unbind()
         }
+        id3::frames::Frame::Geob(f) => {
+            let dict = PyDict::new(py);
+            dict.set_item("mime", &f.mime).unwrap();
+            dict.set_item("filename", &f.filename).unwrap();
+            dict.set_item("desc", &f.desc).unwrap();
+            dict.set_item("data", PyBytes::new(py, &f.data)).unwrap();
+            dict.into_any().unbind()
+        }
         id3::frames::Frame::Popularimeter(f) => {
             Py::new(py, PyPOPM {
                 email: f.email.clone(),
@@ -1259,6 +3048,144 @@ fn frame_to_py(py: Python, frame: &id3::frames::Frame) -> Py<PyAny> {
     }
 }
 
+/// Frame for `ID3[key] = text`: `TXXX:desc` builds a user text frame,
+/// `COMM:desc:lang` a comment (several values joined by NULs) and
+/// `USLT:desc:lang` lyrics (values joined by newlines); any other key a text
+/// frame with that ID. The language defaults to `eng` when the key has none
+/// and must otherwise be exactly 3 ASCII characters (`deu`, `xxx`).
+fn id3_frame_for_key(key: &str, text: Vec<String>) -> PyResult<id3::frames::Frame> {
+    use id3::frames::*;
+    let encoding = id3::specs::Encoding::Utf8;
+    if key == "TXXX" || key.starts_with("TXXX:") {
+        let desc = key.get(5..).unwrap_or("").to_string();
+        return Ok(Frame::UserText(UserTextFrame { id: "TXXX".to_string(), encoding, desc, text }));
+    }
+    let id = key.get(..4).unwrap_or(key);
+    if (id == "COMM" || id == "USLT") && (key.len() == 4 || key.as_bytes()[4] == b':') {
+        let rest = key.get(5..).unwrap_or("");
+        let (desc, lang) = match rest.rsplit_once(':') {
+            Some((desc, lang)) if lang.len() == 3 && lang.is_ascii() => (desc, lang),
+            Some((_, lang)) => {
+                return Err(PyValueError::new_err(format!(
+                    "invalid language {:?} in {:?}: expected 3 ASCII characters", lang, key
+                )));
+            }
+            None => (rest, "eng"),
+        };
+        let (id, lang, desc) = (id.to_string(), lang.to_string(), desc.to_string());
+        return Ok(if id == "COMM" {
+            Frame::Comment(CommentFrame { id, encoding, lang, desc, text: text.join("\0") })
+        } else {
+            Frame::Lyrics(LyricsFrame { id, encoding, lang, desc, text: text.join("\n") })
+        });
+    }
+    Ok(Frame::Text(TextFrame { id: key.to_string(), encoding, text }))
+}
+
+/// Build an ID3 frame from a frame object (`APIC(...)`, `POPM(...)`) or a dict
+/// shaped like `frame_to_py`'s output plus an `"id"` (else `default_id`):
+/// `text` (str or list) for text frames, `url` for URL frames,
+/// `lang`/`desc`/`text` for `COMM`/`USLT`, `mime`/`type`/`desc`/`data` for
+/// `APIC`, `mime`/`filename`/`desc`/`data` for `GEOB`, `email`/`rating`/`count`
+/// for `POPM`, `lang`/`format`/`type`/`desc`
+/// and `sync` (or a frame's `text`) `(text, time)` pairs for `SYLT`, and `data`
+/// bytes for anything else. `TXXX`/`WXXX` also take `desc`, and `encoding`
+/// (0-3) defaults to UTF-8.
+fn py_to_id3_frame(value: &Bound<'_, PyAny>, default_id: Option<&str>) -> PyResult<id3::frames::Frame> {
+    use id3::frames::*;
+    let py = value.py();
+    let dict = value.cast::<PyDict>().ok();
+    // Dict items, or attributes of a frame object
+    let field = |name: &str| -> PyResult<Option<Bound<'_, PyAny>>> {
+        match dict {
+            Some(d) => d.get_item(name),
+            None => Ok(value.getattr(name).ok().filter(|v| !v.is_none())),
+        }
+    };
+    let id = if dict.is_some() {
+        field("id")?
+    } else if value.is_instance_of::<PyPOPM>() {
+        Some(pyo3::types::PyString::new(py, "POPM").into_any())
+    } else {
+        Some(field("FrameID")?.ok_or_else(|| PyValueError::new_err("frame must be a dict or a Frame"))?)
+    };
+    let id = match id {
+        Some(id) => id.extract::<String>()?,
+        None => default_id.map(str::to_string)
+            .ok_or_else(|| PyValueError::new_err("frame dict needs an 'id'"))?,
+    };
+    if id.len() != 4 || !id.bytes().all(|b| b.is_ascii_uppercase() || b.is_ascii_digit()) {
+        return Err(PyValueError::new_err(format!("invalid frame id: {:?}", id)));
+    }
+    let str_field = |name: &str, default: &str| -> PyResult<String> {
+        Ok(match field(name)? {
+            Some(v) => v.extract()?,
+            None => default.to_string(),
+        })
+    };
+    let data_field = || -> PyResult<Vec<u8>> {
+        Ok(field("data")?.map(|d| d.extract()).transpose()?.unwrap_or_default())
+    };
+    let encoding = match field("encoding")? {
+        Some(e) => id3::specs::Encoding::from_byte(e.extract()?)?,
+        None => id3::specs::Encoding::Utf8,
+    };
+    let text_list = || -> PyResult<Vec<String>> {
+        let text = field("text")?.ok_or_else(|| PyValueError::new_err("frame needs 'text'"))?;
+        text.extract::<Vec<String>>().or_else(|_| text.extract::<String>().map(|s| vec![s]))
+    };
+    Ok(match id.as_str() {
+        "TXXX" => Frame::UserText(UserTextFrame { id, encoding, desc: str_field("desc", "")?, text: text_list()? }),
+        "WXXX" => Frame::UserUrl(UserUrlFrame { id, encoding, desc: str_field("desc", "")?, url: str_field("url", "")? }),
+        "COMM" => Frame::Comment(CommentFrame {
+            id, encoding, lang: str_field("lang", "eng")?, desc: str_field("desc", "")?, text: str_field("text", "")?,
+        }),
+        "USLT" => Frame::Lyrics(LyricsFrame {
+            id, encoding, lang: str_field("lang", "eng")?, desc: str_field("desc", "")?, text: str_field("text", "")?,
+        }),
+        "SYLT" => Frame::SyncLyrics(SyncLyricsFrame {
+            id,
+            encoding,
+            lang: str_field("lang", "eng")?,
+            format: field("format")?.map(|f| f.extract()).transpose()?.unwrap_or(TIMESTAMP_MILLISECONDS),
+            content_type: field("type")?.map(|t| t.extract()).transpose()?.unwrap_or(1),
+            desc: str_field("desc", "")?,
+            sync: match field("sync")? {
+                Some(sync) => sync.extract()?,
+                None => field("text")?.map(|t| t.extract()).transpose()?.unwrap_or_default(),
+            },
+        }),
+        "APIC" => Frame::Picture(PictureFrame {
+            id,
+            encoding,
+            mime: str_field("mime", "image/jpeg")?,
+            pic_type: id3::specs::PictureType::from_byte(match field("type")? {
+                Some(t) => t.extract()?,
+                None => 3,
+            }),
+            desc: str_field("desc", "")?,
+            data: data_field()?,
+        }),
+        "GEOB" => Frame::Geob(GeobFrame {
+            id,
+            encoding,
+            mime: str_field("mime", "")?,
+            filename: str_field("filename", "")?,
+            desc: str_field("desc", "")?,
+            data: data_field()?,
+        }),
+        "POPM" => Frame::Popularimeter(PopularimeterFrame {
+            id,
+            email: str_field("email", "")?,
+            rating: field("rating")?.map(|r| r.extract()).transpose()?.unwrap_or(0),
+            count: field("count")?.map(|c| c.extract()).transpose()?.unwrap_or(0),
+        }),
+        _ if id.starts_with('T') => Frame::Text(TextFrame { id, encoding, text: text_list()? }),
+        _ if id.starts_with('W') => Frame::Url(UrlFrame { id, url: str_field("url", "")? }),
+        _ => Frame::Binary(BinaryFrame { id, data: data_field()? }),
+    })
+}
+
 #[inline(always)]
 fn mp4_value_to_py(py: Python, value: &mp4::MP4TagValue) -> PyResult<Py<PyAny>> {
     match value {
@@ -1304,7 +3231,7 @@ fn mp4_value_to_py(py: Python, value: &mp4::MP4TagValue) -> PyResult<Py<PyAny>>
         mp4::MP4TagValue::FreeForm(forms) => {
             let list = PyList::empty(py);
             for form in forms {
-                list.append(PyBytes::new(py, &form.data))?;
+                list.append((PyBytes::new(py, &form.data), form.dataformat))?;
             }
             Ok(list.into_any().unbind())
         }
@@ -1314,28 +3241,57 @@ fn mp4_value_to_py(py: Python, value: &mp4::MP4TagValue) -> PyResult<Py<PyAny>>
     }
 }
 
+/// Parse a track/disc string like "3/12" (or a bare "3", total 0) into an int pair.
+fn parse_int_pair(key: &str, s: &str) -> PyResult<(i32, i32)> {
+    let invalid = || PyValueError::new_err(format!(
+        "Invalid value for MP4 key '{}': expected \"N\" or \"N/M\", got {:?}", key, s
+    ));
+    let (num, total) = match s.split_once('/') {
+        Some((n, t)) => (n, Some(t)),
+        None => (s, None),
+    };
+    let num = num.trim().parse::<i32>().map_err(|_| invalid())?;
+    let total = match total {
+        Some(t) => t.trim().parse::<i32>().map_err(|_| invalid())?,
+        None => 0,
+    };
+    Ok((num, total))
+}
+
+/// Cover bytes as an `MP4Cover`: the `imageformat` of an `MP4Cover` object
+/// when it has one, else PNG or JPEG by the data's magic bytes.
+fn py_to_mp4_cover(item: &Bound<'_, PyAny>) -> PyResult<Option<mp4::MP4Cover>> {
+    let Ok(data) = item.extract::<Vec<u8>>() else { return Ok(None) };
+    let explicit = match item.getattr(pyo3::intern!(item.py(), "imageformat")) {
+        Ok(fmt) if !fmt.is_none() => Some(fmt.extract::<u32>()?),
+        _ => None,
+    };
+    let format = match explicit {
+        Some(14) => mp4::MP4CoverFormat::PNG,
+        Some(_) => mp4::MP4CoverFormat::JPEG,
+        None if data.starts_with(b"\x89PNG") => mp4::MP4CoverFormat::PNG,
+        None => mp4::MP4CoverFormat::JPEG,
+    };
+    Ok(Some(mp4::MP4Cover { data: data.into(), format }))
+}
+
 /// Convert a Python value to an MP4TagValue based on the key and value type.
 fn py_to_mp4_value(key: &str, value: &Bound<'_, PyAny>) -> PyResult<mp4::MP4TagValue> {
-    // Cover art: list of bytes objects or list of dicts with data/format
+    // Cover art: list of bytes/MP4Cover objects or list of dicts with data/format
     if key == "covr" {
         if let Ok(list) = value.cast::<PyList>() {
             let mut covers = Vec::new();
             for item in list.iter() {
-                // Try bytes first (most common: [b'\x89PNG...'])
-                if let Ok(data) = item.extract::<Vec<u8>>() {
-                    let fmt = if data.starts_with(b"\x89PNG") {
-                        mp4::MP4CoverFormat::PNG
-                    } else {
-                        mp4::MP4CoverFormat::JPEG
-                    };
-                    covers.push(mp4::MP4Cover { data, format: fmt });
+                // Try bytes first (most common: [MP4Cover(b'\x89PNG...')])
+                if let Some(cover) = py_to_mp4_cover(&item)? {
+                    covers.push(cover);
                 } else if let Ok(dict) = item.cast::<PyDict>() {
                     // Dict with data/format keys
                     if let (Some(data_obj), Some(fmt_obj)) = (dict.get_item("data")?, dict.get_item("format")?) {
                         let data = data_obj.extract::<Vec<u8>>()?;
                         let fmt_int = fmt_obj.extract::<u32>().unwrap_or(13);
                         let format = if fmt_int == 14 { mp4::MP4CoverFormat::PNG } else { mp4::MP4CoverFormat::JPEG };
-                        covers.push(mp4::MP4Cover { data, format });
+                        covers.push(mp4::MP4Cover { data: data.into(), format });
                     }
                 }
             }
@@ -1344,16 +3300,11 @@ fn py_to_mp4_value(key: &str, value: &Bound<'_, PyAny>) -> PyResult<mp4::MP4TagV
             }
         }
         // Single bytes object
-        if let Ok(data) = value.extract::<Vec<u8>>() {
-            let fmt = if data.starts_with(b"\x89PNG") {
-                mp4::MP4CoverFormat::PNG
-            } else {
-                mp4::MP4CoverFormat::JPEG
-            };
-            return Ok(mp4::MP4TagValue::Cover(vec![mp4::MP4Cover { data, format: fmt }]));
+        if let Some(cover) = py_to_mp4_cover(value)? {
+            return Ok(mp4::MP4TagValue::Cover(vec![cover]));
         }
     }
-    // Int pairs (trkn, disk): [(num, total)]
+    // Int pairs (trkn, disk): [(num, total)], (num, total), "num/total" or "num"
     if key == "trkn" || key == "disk" {
         if let Ok(pairs) = value.extract::<Vec<(i32, i32)>>() {
             return Ok(mp4::MP4TagValue::IntPair(pairs));
@@ -1361,6 +3312,32 @@ fn py_to_mp4_value(key: &str, value: &Bound<'_, PyAny>) -> PyResult<mp4::MP4TagV
         if let Ok(pair) = value.extract::<(i32, i32)>() {
             return Ok(mp4::MP4TagValue::IntPair(vec![pair]));
         }
+        if let Ok(s) = value.extract::<String>() {
+            return Ok(mp4::MP4TagValue::IntPair(vec![parse_int_pair(key, &s)?]));
+        }
+        if let Ok(strings) = value.extract::<Vec<String>>() {
+            let pairs = strings.iter()
+                .map(|s| parse_int_pair(key, s))
+                .collect::<PyResult<Vec<_>>>()?;
+            return Ok(mp4::MP4TagValue::IntPair(pairs));
+        }
+        if !value.is_instance_of::<pyo3::types::PyBool>() {
+            if let Ok(n) = value.extract::<i32>() {
+                return Ok(mp4::MP4TagValue::IntPair(vec![(n, 0)]));
+            }
+        }
+    }
+    // Freeform (----) items keep the data type of every value
+    if key.starts_with("----") {
+        if let Some(form) = py_to_freeform(value) {
+            return Ok(mp4::MP4TagValue::FreeForm(vec![form]));
+        }
+        if let Ok(list) = value.cast::<PyList>() {
+            let forms: Option<Vec<_>> = list.iter().map(|item| py_to_freeform(&item)).collect();
+            if let Some(forms) = forms.filter(|f| !f.is_empty()) {
+                return Ok(mp4::MP4TagValue::FreeForm(forms));
+            }
+        }
     }
     // List of strings (most common for text tags)
     if let Ok(strings) = value.extract::<Vec<String>>() {
@@ -1390,8 +3367,8 @@ fn py_to_mp4_value(key: &str, value: &Bound<'_, PyAny>) -> PyResult<mp4::MP4TagV
     if let Ok(list) = value.cast::<PyList>() {
         let mut forms = Vec::new();
         for item in list.iter() {
-            if let Ok(data) = item.extract::<Vec<u8>>() {
-                forms.push(mp4::MP4FreeForm { data, dataformat: 1 });
+            if let Some(form) = py_to_freeform(&item) {
+                forms.push(form);
             }
         }
         if !forms.is_empty() {
@@ -1404,32 +3381,55 @@ fn py_to_mp4_value(key: &str, value: &Bound<'_, PyAny>) -> PyResult<mp4::MP4TagV
     )))
 }
 
+/// One freeform value: a `(bytes, dataformat)` tuple, or bytes whose
+/// `dataformat` attribute (as on `MP4FreeForm`) defaults to 1 (UTF-8).
+fn py_to_freeform(item: &Bound<'_, PyAny>) -> Option<mp4::MP4FreeForm> {
+    if let Ok((data, dataformat)) = item.extract::<(Vec<u8>, u32)>() {
+        return Some(mp4::MP4FreeForm { data: data.into(), dataformat });
+    }
+    if !item.is_instance_of::<PyBytes>() {
+        return None;
+    }
+    let data = item.extract::<Vec<u8>>().ok()?;
+    let dataformat = item.getattr("dataformat")
+        .and_then(|f| f.extract::<u32>())
+        .unwrap_or(1);
+    Some(mp4::MP4FreeForm { data: data.into(), dataformat })
+}
+
 // ---- Batch API ----
 
 /// Pre-serialized tag value — all decoding done in parallel phase.
 #[derive(Clone)]
-enum BatchTagValue {
+pub(crate) enum BatchTagValue {
     Text(String),
     TextList(Vec<String>),
-    Bytes(Vec<u8>),
+    /// Binary payloads share the buffer they were parsed from; they are copied
+    /// only when the Python/JSON/msgpack output is written.
+    Bytes(SharedBytes),
     Int(i64),
     IntPair(i32, i32),
     Bool(bool),
-    Picture { mime: String, pic_type: u8, desc: String, data: Vec<u8> },
+    Picture { mime: String, pic_type: u8, desc: String, data: SharedBytes },
+    /// An ID3 GEOB frame.
+    Object { mime: String, filename: String, desc: String, data: SharedBytes },
     Popularimeter { email: String, rating: u8, count: u64 },
     PairedText(Vec<(String, String)>),
-    CoverList(Vec<(Vec<u8>, u8)>),
-    FreeFormList(Vec<Vec<u8>>),
+    CoverList(Vec<(SharedBytes, u8)>),
+    FreeFormList(Vec<SharedBytes>),
+    /// Nested key → value mapping (e.g. unmapped tags under `extra` in easy mode).
+    Map(Vec<(String, BatchTagValue)>),
 }
 
 /// Pre-serialized file — all Rust work done, ready for Python wrapping.
 #[derive(Clone)]
-struct PreSerializedFile {
-    length: f64,
+pub(crate) struct PreSerializedFile {
+    /// `None` when the stream doesn't record its duration (FLAC `total_samples` of 0).
+    length: Option<f64>,
     sample_rate: u32,
     channels: u32,
     bitrate: Option<u32>,
-    tags: Vec<(String, BatchTagValue)>,
+    pub(crate) tags: Vec<(String, BatchTagValue)>,
     // Format-specific extra metadata (emitted as dict entries in _fast_read)
     extra: Vec<(&'static str, BatchTagValue)>,
     // Lazy VC tag support: raw Vorbis Comment bytes (copied from file data).
@@ -1438,47 +3438,79 @@ struct PreSerializedFile {
 }
 
 /// Convert a Frame to a BatchTagValue (runs in parallel phase, no GIL needed).
+/// Takes the frame by value so its strings and bytes move instead of being cloned.
 #[inline(always)]
-fn frame_to_batch_value(frame: &id3::frames::Frame) -> BatchTagValue {
+fn frame_to_batch_value(frame: id3::frames::Frame) -> BatchTagValue {
     match frame {
-        id3::frames::Frame::Text(f) => {
+        id3::frames::Frame::Text(mut f) => {
             if f.text.len() == 1 {
-                BatchTagValue::Text(f.text[0].clone())
+                BatchTagValue::Text(f.text.swap_remove(0))
             } else {
-                BatchTagValue::TextList(f.text.clone())
+                BatchTagValue::TextList(f.text)
             }
         }
-        id3::frames::Frame::UserText(f) => {
+        id3::frames::Frame::UserText(mut f) => {
             if f.text.len() == 1 {
-                BatchTagValue::Text(f.text[0].clone())
+                BatchTagValue::Text(f.text.swap_remove(0))
             } else {
-                BatchTagValue::TextList(f.text.clone())
+                BatchTagValue::TextList(f.text)
             }
         }
-        id3::frames::Frame::Url(f) => BatchTagValue::Text(f.url.clone()),
-        id3::frames::Frame::UserUrl(f) => BatchTagValue::Text(f.url.clone()),
-        id3::frames::Frame::Comment(f) => BatchTagValue::Text(f.text.clone()),
-        id3::frames::Frame::Lyrics(f) => BatchTagValue::Text(f.text.clone()),
+        id3::frames::Frame::Url(f) => BatchTagValue::Text(f.url),
+        id3::frames::Frame::UserUrl(f) => BatchTagValue::Text(f.url),
+        id3::frames::Frame::Comment(f) => BatchTagValue::Text(f.text),
+        id3::frames::Frame::Lyrics(f) => BatchTagValue::Text(f.text),
+        id3::frames::Frame::SyncLyrics(f) => BatchTagValue::Text(f.sync.into_iter().map(|(text, _)| text).collect()),
         id3::frames::Frame::Picture(f) => BatchTagValue::Picture {
-            mime: f.mime.clone(),
+            mime: f.mime,
             pic_type: f.pic_type as u8,
-            desc: f.desc.clone(),
-            data: f.data.clone(),
+            desc: f.desc,
+            data: f.data.into(),
+        },
+        id3::frames::Frame::Geob(f) => BatchTagValue::Object {
+            mime: f.mime,
+            filename: f.filename,
+            desc: f.desc,
+            data: f.data.into(),
         },
         id3::frames::Frame::Popularimeter(f) => BatchTagValue::Popularimeter {
-            email: f.email.clone(),
+            email: f.email,
             rating: f.rating,
             count: f.count,
         },
-        id3::frames::Frame::Binary(f) => BatchTagValue::Bytes(f.data.clone()),
-        id3::frames::Frame::PairedText(f) => BatchTagValue::PairedText(f.people.clone()),
+        id3::frames::Frame::Binary(f) => BatchTagValue::Bytes(f.data.into()),
+        id3::frames::Frame::PairedText(f) => BatchTagValue::PairedText(f.people),
+    }
+}
+
+/// Decode one stored frame into a batch value. APIC frames stored as slices
+/// of the tag buffer keep their picture data there instead of copying it out.
+#[inline(always)]
+fn lazy_frame_to_batch_value(lf: id3::tags::LazyFrame, buf: &SharedBytes) -> Option<BatchTagValue> {
+    match lf {
+        id3::tags::LazyFrame::Slice { id, offset, len } => {
+            let (start, end) = (offset as usize, offset as usize + len as usize);
+            let payload = buf.get(start..end)?;
+            if &id == b"APIC" {
+                let header = id3::frames::parse_picture_header(payload).ok()?;
+                return Some(BatchTagValue::Picture {
+                    mime: header.mime,
+                    pic_type: header.pic_type as u8,
+                    desc: header.desc,
+                    data: buf.slice(start + header.data_offset, end),
+                });
+            }
+            let id_str = std::str::from_utf8(&id).unwrap_or("XXXX");
+            id3::frames::parse_frame(id_str, payload).ok().map(frame_to_batch_value)
+        }
+        other => other.into_decoded().ok().map(frame_to_batch_value),
     }
 }
 
 /// Parse VorbisComment data directly into batch tags — single-pass, minimal allocations.
 /// Skips vendor string, uses memchr for fast '=' finding, groups by key inline.
 #[inline(always)]
-fn parse_vc_to_batch_tags(data: &[u8]) -> Vec<(String, BatchTagValue)> {
+pub(crate) fn parse_vc_to_batch_tags(data: &[u8]) -> Vec<(String, BatchTagValue)> {
     if data.len() < 8 { return Vec::new(); }
     let mut pos = 0usize;
 
@@ -1493,6 +3525,7 @@ fn parse_vc_to_batch_tags(data: &[u8]) -> Vec<(String, BatchTagValue)> {
     pos += 4;
 
     let mut tags: Vec<(String, BatchTagValue)> = Vec::with_capacity(count.min(64));
+    let mut index = common::keyindex::KeyIndex::new();
 
     for _ in 0..count {
         if pos + 4 > data.len() { break; }
@@ -1514,9 +3547,9 @@ fn parse_vc_to_batch_tags(data: &[u8]) -> Vec<(String, BatchTagValue)> {
 
         // Key: lowercase ASCII (matches mutagen behavior)
         let key = if key_bytes.iter().all(|&b| !b.is_ascii_uppercase()) {
-            match std::str::from_utf8(key_bytes) {
-                Ok(s) => s.to_string(),
-                Err(_) => continue,
+            match common::text::from_utf8(key_bytes) {
+                Some(s) => s.to_string(),
+                None => continue,
             }
         } else {
             // Fast ASCII lowercase (no allocation for checking)
@@ -1528,18 +3561,16 @@ fn parse_vc_to_batch_tags(data: &[u8]) -> Vec<(String, BatchTagValue)> {
         };
 
         // Value: zero-copy if valid UTF-8
-        let value = match std::str::from_utf8(value_bytes) {
-            Ok(s) => s.to_string(),
-            Err(_) => String::from_utf8_lossy(value_bytes).into_owned(),
-        };
+        let value = common::text::utf8_lossy(value_bytes);
 
-        // Group by key (linear scan — fast for typical 5-15 unique keys)
-        if let Some(entry) = tags.iter_mut().find(|(k, _)| k == &key) {
-            if let BatchTagValue::TextList(ref mut v) = entry.1 {
+        // Group by key (linear scan for typical 5-15 unique keys, hashed past that)
+        if let Some(i) = index.find(&tags, key.as_str()) {
+            if let BatchTagValue::TextList(ref mut v) = tags[i].1 {
                 v.push(value);
             }
         } else {
             tags.push((key, BatchTagValue::TextList(vec![value])));
+            index.pushed(&tags);
         }
     }
 
@@ -1563,9 +3594,10 @@ fn parse_flac_batch(data: &[u8], file_size: usize) -> Option<PreSerializedFile>
     let mut pos = flac_offset + 4;
     let mut sample_rate = 0u32;
     let mut channels = 0u8;
-    let mut length = 0.0f64;
+    let mut length = None;
     let mut bits_per_sample = 0u8;
     let mut total_samples = 0u64;
+    let mut has_streaminfo = false;
     let mut vc_pos: Option<(usize, usize)> = None;
 
     loop {
@@ -1580,6 +3612,7 @@ fn parse_flac_batch(data: &[u8], file_size: usize) -> Option<PreSerializedFile>
         match bt {
             0 => {
                 if let Ok(si) = flac::StreamInfo::parse(&data[pos..pos+block_size]) {
+                    has_streaminfo = true;
                     sample_rate = si.sample_rate;
                     channels = si.channels;
                     length = si.length;
@@ -1596,11 +3629,12 @@ fn parse_flac_batch(data: &[u8], file_size: usize) -> Option<PreSerializedFile>
         }
 
         pos += block_size;
-        // Early break: we only need StreamInfo + VC, skip remaining blocks
-        if is_last || (sample_rate > 0 && vc_pos.is_some()) { break; }
+        // Early break: we only need StreamInfo + VC (in either order), skip remaining blocks
+        if is_last || (has_streaminfo && vc_pos.is_some()) { break; }
     }
 
-    if sample_rate == 0 { return None; }
+    // Without STREAMINFO the tags are still returned, with no length
+    if !has_streaminfo && vc_pos.is_none() { return None; }
 
     // Lazy VC: copy just the VC raw bytes (typically 100-1000 bytes), defer parsing to access time.
     // This avoids ~15 String allocations per file during the rayon parallel phase.
@@ -1609,9 +3643,19 @@ fn parse_flac_batch(data: &[u8], file_size: usize) -> Option<PreSerializedFile>
     // Bitrate: use audio data size only (exclude metadata), matching mutagen behavior
     // pos points to the start of audio frames after the metadata block loop
     let audio_data_size = file_size.saturating_sub(pos);
-    let bitrate = if length > 0.0 {
-        Some((audio_data_size as f64 * 8.0 / length) as u32)
-    } else { None };
+    let bitrate = match length {
+        Some(length) if length > 0.0 => Some((audio_data_size as f64 * 8.0 / length) as u32),
+        _ => None,
+    };
+
+    let mut extra = vec![
+        ("bits_per_sample", BatchTagValue::Int(bits_per_sample as i64)),
+        ("total_samples", BatchTagValue::Int(total_samples as i64)),
+    ];
+    if total_samples > 0 {
+        extra.push(("length_samples", BatchTagValue::Int(total_samples as i64)));
+        extra.push(("duration_ms", BatchTagValue::Int(common::util::duration_ms(total_samples, sample_rate as u64) as i64)));
+    }
 
     Some(PreSerializedFile {
         length,
@@ -1619,17 +3663,14 @@ fn parse_flac_batch(data: &[u8], file_size: usize) -> Option<PreSerializedFile>
         channels: channels as u32,
         bitrate,
         tags: Vec::new(),
-        extra: vec![
-            ("bits_per_sample", BatchTagValue::Int(bits_per_sample as i64)),
-            ("total_samples", BatchTagValue::Int(total_samples as i64)),
-        ],
+        extra,
         lazy_vc,
     })
 }
 
 /// Batch-optimized OGG Vorbis parser: inline page headers, direct VC parsing.
 #[inline(always)]
-fn parse_ogg_batch(data: &[u8]) -> Option<PreSerializedFile> {
+pub(crate) fn parse_ogg_batch(data: &[u8]) -> Option<PreSerializedFile> {
     if data.len() < 58 || &data[0..4] != b"OggS" { return None; }
 
     let serial = u32::from_le_bytes([data[14], data[15], data[16], data[17]]);
@@ -1644,8 +3685,8 @@ fn parse_ogg_batch(data: &[u8]) -> Option<PreSerializedFile> {
     let id_data = &data[seg_table_end..];
     if id_data.len() < 30 || &id_data[0..7] != b"\x01vorbis" { return None; }
 
-    let channels = id_data[11];
-    let sample_rate = u32::from_le_bytes([id_data[12], id_data[13], id_data[14], id_data[15]]);
+    let (channels, sample_rate) = ogg::check_ident_values(
+        id_data[11], u32::from_le_bytes([id_data[12], id_data[13], id_data[14], id_data[15]]), &mut Vec::new());
     let nominal_bitrate = u32::from_le_bytes([id_data[20], id_data[21], id_data[22], id_data[23]]);
 
     if first_page_end + 27 > data.len() { return None; }
@@ -1665,9 +3706,8 @@ fn parse_ogg_batch(data: &[u8]) -> Option<PreSerializedFile> {
         if seg < 255 { single_page = true; break; }
     }
 
-    let length = ogg::find_last_granule(data, serial)
-        .map(|g| if g > 0 && sample_rate > 0 { g as f64 / sample_rate as f64 } else { 0.0 })
-        .unwrap_or(0.0);
+    let vl = ogg::vorbis_length(data, serial, sample_rate, nominal_bitrate);
+    let length = vl.length;
 
     let bitrate = if nominal_bitrate > 0 {
         Some(nominal_bitrate)
@@ -1690,42 +3730,106 @@ fn parse_ogg_batch(data: &[u8]) -> Option<PreSerializedFile> {
         Some(comment_packet[7..].to_vec())
     };
 
+    let mut extra = vec![
+        ("length_samples", BatchTagValue::Int(vl.length_samples as i64)),
+        ("duration_ms", BatchTagValue::Int(vl.duration_ms as i64)),
+    ];
+    if vl.recovered {
+        extra.push(("recovered", BatchTagValue::Bool(true)));
+    }
     Some(PreSerializedFile {
-        length,
-        sample_rate,
+        length: Some(length),
+        sample_rate: vl.sample_rate,
         channels: channels as u32,
         bitrate,
         tags: Vec::new(),
-        extra: Vec::new(),
+        extra,
         lazy_vc,
     })
 }
 
-/// Convert MP4TagValue to BatchTagValue (inline, no extra lookup).
+/// Batch Ogg Opus parser: the comment body stays raw until tags are needed.
+pub(crate) fn parse_opus_batch(data: &[u8], path: &str) -> Option<PreSerializedFile> {
+    let f = ogg::opus::OpusFile::parse(data, path).ok()?;
+    let info = &f.info;
+    Some(PreSerializedFile {
+        length: Some(info.length),
+        sample_rate: ogg::opus::GRANULE_RATE,
+        channels: info.channels as u32,
+        bitrate: if info.bitrate > 0 { Some(info.bitrate) } else { None },
+        tags: Vec::new(),
+        extra: vec![
+            ("length_samples", BatchTagValue::Int(info.length_samples as i64)),
+            ("duration_ms", BatchTagValue::Int(info.duration_ms as i64)),
+            ("pre_skip", BatchTagValue::Int(info.pre_skip as i64)),
+        ],
+        lazy_vc: Some(f.raw_comments().to_vec()),
+    })
+}
+
+/// Convert MP4TagValue to BatchTagValue (inline, no extra lookup). Takes the
+/// value by value so text and cover data move instead of being cloned.
 #[inline(always)]
-fn mp4_value_to_batch(value: &mp4::MP4TagValue) -> BatchTagValue {
+fn mp4_value_to_batch(value: mp4::MP4TagValue) -> BatchTagValue {
     match value {
-        mp4::MP4TagValue::Text(v) => {
-            if v.len() == 1 { BatchTagValue::Text(v[0].clone()) }
-            else { BatchTagValue::TextList(v.clone()) }
+        mp4::MP4TagValue::Text(mut v) => {
+            if v.len() == 1 { BatchTagValue::Text(v.swap_remove(0)) }
+            else { BatchTagValue::TextList(v) }
         }
         mp4::MP4TagValue::Integer(v) => {
-            if v.len() == 1 { BatchTagValue::Int(v[0] as i64) }
+            if v.len() == 1 { BatchTagValue::Int(v[0]) }
             else { BatchTagValue::TextList(v.iter().map(|i| itoa::Buffer::new().format(*i).to_string()).collect()) }
         }
         mp4::MP4TagValue::IntPair(v) => {
             if v.len() == 1 { BatchTagValue::IntPair(v[0].0, v[0].1) }
             else { BatchTagValue::TextList(v.iter().map(|(a,b)| { let mut s = String::with_capacity(12); s.push('('); s.push_str(itoa::Buffer::new().format(*a)); s.push(','); s.push_str(itoa::Buffer::new().format(*b)); s.push(')'); s }).collect()) }
         }
-        mp4::MP4TagValue::Bool(v) => BatchTagValue::Bool(*v),
+        mp4::MP4TagValue::Bool(v) => BatchTagValue::Bool(v),
         mp4::MP4TagValue::Cover(covers) => {
-            BatchTagValue::CoverList(covers.iter().map(|c| (c.data.clone(), c.format as u8)).collect())
+            BatchTagValue::CoverList(covers.into_iter().map(|c| (c.data, c.format as u8)).collect())
         }
+        // UTF-8 freeform values read as text; binary ones stay bytes
         mp4::MP4TagValue::FreeForm(forms) => {
-            BatchTagValue::FreeFormList(forms.iter().map(|f| f.data.clone()).collect())
+            match forms.iter().map(|f| f.text().map(str::to_string)).collect::<Option<Vec<_>>>() {
+                Some(mut texts) if texts.len() == 1 => BatchTagValue::Text(texts.swap_remove(0)),
+                Some(texts) => BatchTagValue::TextList(texts),
+                None => BatchTagValue::FreeFormList(forms.into_iter().map(|f| f.data).collect()),
+            }
+        }
+        mp4::MP4TagValue::Data(d) => BatchTagValue::Bytes(d.into()),
+    }
+}
+
+/// Decode the first frame of every ID3 key into batch tags. Consumes the tags:
+/// decoded values move out of the frames, and the tag buffer becomes the
+/// shared backing store for picture data.
+#[inline(always)]
+fn id3_tags_to_batch(id3_tags: id3::tags::ID3Tags) -> Vec<(String, BatchTagValue)> {
+    let buf = SharedBytes::from(id3_tags.raw_buf);
+    let mut tags = Vec::with_capacity(id3_tags.frames.len());
+    // Keys as mutagen reports them after its v2.4 update (TYER → TDRC, ...);
+    // a renamed frame gives way to a real one under the new key
+    let mut renamed_to: Vec<&str> = ["TDRC", "TDOR", "TIPL"].into_iter()
+        .filter(|&k| !id3_tags.frames.iter().any(|(f, _)| f.as_str() == k))
+        .collect();
+    for (hash_key, frames) in id3_tags.frames {
+        let Some(lf) = frames.into_iter().next() else { continue };
+        let key = hash_key.as_str();
+        let Some(v24) = id3::tags::v24_key(key) else { continue };
+        let Some(value) = lazy_frame_to_batch_value(lf, &buf) else { continue };
+        if v24 != key {
+            let year = match &value {
+                BatchTagValue::Text(t) => id3::tags::is_year(t),
+                BatchTagValue::TextList(t) => t.first().is_some_and(|t| id3::tags::is_year(t)),
+                _ => false,
+            };
+            if key == "TYER" && !year { continue; }
+            let Some(i) = renamed_to.iter().position(|&k| k == v24) else { continue };
+            renamed_to.swap_remove(i);
         }
-        mp4::MP4TagValue::Data(d) => BatchTagValue::Bytes(d.clone()),
+        tags.push((v24.to_string(), value));
     }
+    tags
 }
 
 /// Parse MP3 data into batch result.
@@ -1733,25 +3837,15 @@ fn mp4_value_to_batch(value: &mp4::MP4TagValue) -> BatchTagValue {
 fn parse_mp3_batch(data: &[u8], path: &str) -> Option<PreSerializedFile> {
     let mut f = mp3::MP3File::parse(data, path).ok()?;
     f.ensure_tags_parsed(data);
-    let mut tags = Vec::with_capacity(f.tags.frames.len());
-    let mut has_tdrc = f.tags.frames.iter().any(|(k, _)| k.as_str() == "TDRC");
-    for (hash_key, frames) in f.tags.frames.iter_mut() {
-        if let Some(lf) = frames.first_mut() {
-            if let Ok(frame) = lf.decode_with_buf(&f.tags.raw_buf) {
-                let key = hash_key.as_str();
-                // TYER→TDRC normalization (matches mutagen behavior)
-                if key == "TYER" {
-                    if has_tdrc { continue; }
-                    has_tdrc = true;
-                    tags.push(("TDRC".to_string(), frame_to_batch_value(frame)));
-                } else {
-                    tags.push((key.to_string(), frame_to_batch_value(frame)));
-                }
-            }
-        }
-    }
+    let ape_tags: Vec<(String, BatchTagValue)> = f.ape_extra_items().into_iter()
+        .map(|item| (item.key.clone(), ape_item_to_batch(item)))
+        .collect();
+    let mut tags = id3_tags_to_batch(f.tags);
+    tags.extend(ape_tags);
     // MP3-specific extra metadata
-    let extra = vec![
+    let mut extra = vec![
+        ("length_samples", BatchTagValue::Int(f.info.length_samples as i64)),
+        ("duration_ms", BatchTagValue::Int(f.info.duration_ms as i64)),
         ("version", BatchTagValue::Text(ryu::Buffer::new().format(f.info.version).to_string())),
         ("layer", BatchTagValue::Int(f.info.layer as i64)),
         ("mode", BatchTagValue::Int(f.info.mode as i64)),
@@ -1763,8 +3857,11 @@ fn parse_mp3_batch(data: &[u8], path: &str) -> Option<PreSerializedFile> {
             mp3::xing::BitrateMode::ABR => 3,
         })),
     ];
+    if let Some(n) = f.info.xing_frames {
+        extra.push(("xing_frames", BatchTagValue::Int(n as i64)));
+    }
     Some(PreSerializedFile {
-        length: f.info.length,
+        length: Some(f.info.length),
         sample_rate: f.info.sample_rate,
         channels: f.info.channels,
         bitrate: Some(f.info.bitrate),
@@ -1779,49 +3876,169 @@ fn parse_mp3_batch(data: &[u8], path: &str) -> Option<PreSerializedFile> {
 fn parse_mp4_batch(data: &[u8], path: &str) -> Option<PreSerializedFile> {
     let mut f = mp4::MP4File::parse(data, path).ok()?;
     f.ensure_parsed_with_data(data);
+    Some(mp4_file_to_batch(f))
+}
+
+/// Batch result for an already parsed MP4 file.
+fn mp4_file_to_batch(f: mp4::MP4File) -> PreSerializedFile {
     let mut tags = Vec::with_capacity(f.tags.items.len());
-    for (key, value) in f.tags.items.iter() {
-        tags.push((key.clone(), mp4_value_to_batch(value)));
+    for (key, value) in f.tags.items {
+        tags.push((key, mp4_value_to_batch(value)));
     }
     let extra = vec![
+        ("length_samples", BatchTagValue::Int(f.info.length_samples as i64)),
+        ("duration_ms", BatchTagValue::Int(f.info.duration_ms as i64)),
         ("codec", BatchTagValue::Text(f.info.codec.clone())),
         ("bits_per_sample", BatchTagValue::Int(f.info.bits_per_sample as i64)),
+        ("channels_source", BatchTagValue::Text(f.info.channels_source.to_string())),
     ];
-    Some(PreSerializedFile {
-        length: f.info.length,
+    PreSerializedFile {
+        length: Some(f.info.length),
         sample_rate: f.info.sample_rate,
         channels: f.info.channels as u32,
         bitrate: if f.info.bitrate > 0 { Some(f.info.bitrate) } else { None },
         tags,
         extra,
         lazy_vc: None,
+    }
+}
+
+/// Parse CAF data into batch result; `info` strings are grouped per key like Vorbis comments.
+#[inline(always)]
+fn parse_caf_batch(data: &[u8], path: &str) -> Option<PreSerializedFile> {
+    let f = caf::CAFFile::parse(data, path).ok()?;
+    let mut tags: Vec<(String, BatchTagValue)> = Vec::with_capacity(f.tags.len());
+    let mut index = common::keyindex::KeyIndex::new();
+    for (key, value) in f.tags {
+        if let Some(i) = index.find(&tags, key.as_str()) {
+            if let BatchTagValue::TextList(ref mut v) = tags[i].1 {
+                v.push(value);
+            }
+        } else {
+            tags.push((key, BatchTagValue::TextList(vec![value])));
+            index.pushed(&tags);
+        }
+    }
+    Some(PreSerializedFile {
+        length: Some(f.info.length),
+        sample_rate: f.info.sample_rate,
+        channels: f.info.channels,
+        bitrate: if f.info.bitrate > 0 { Some(f.info.bitrate) } else { None },
+        tags,
+        extra: vec![
+            ("length_samples", BatchTagValue::Int(f.info.length_samples as i64)),
+            ("duration_ms", BatchTagValue::Int(f.info.duration_ms as i64)),
+            ("codec", BatchTagValue::Text(f.info.codec)),
+            ("bits_per_sample", BatchTagValue::Int(f.info.bits_per_sample as i64)),
+        ],
+        lazy_vc: None,
     })
 }
 
+/// APEv2 item as a batch value: text items as string lists, binary as bytes.
+fn ape_item_to_batch(item: &ape::apev2::APEItem) -> BatchTagValue {
+    match item.text() {
+        Some(values) => BatchTagValue::TextList(values),
+        None => BatchTagValue::Bytes(SharedBytes::from(item.value.clone())),
+    }
+}
+
+/// Monkey's Audio file as a batch result; APEv2 keys keep their case.
+fn ape_preserialized(f: &ape::APEFile) -> PreSerializedFile {
+    let tags = f.tags.iter()
+        .flat_map(|t| &t.items)
+        .map(|item| (item.key.clone(), ape_item_to_batch(item)))
+        .collect();
+    PreSerializedFile {
+        length: Some(f.info.length),
+        sample_rate: f.info.sample_rate,
+        channels: f.info.channels,
+        bitrate: if f.info.bitrate > 0 { Some(f.info.bitrate) } else { None },
+        tags,
+        extra: vec![
+            ("length_samples", BatchTagValue::Int(f.info.length_samples as i64)),
+            ("duration_ms", BatchTagValue::Int(f.info.duration_ms as i64)),
+            ("bits_per_sample", BatchTagValue::Int(f.info.bits_per_sample as i64)),
+            ("version", BatchTagValue::Text(ryu::Buffer::new().format(f.info.version).to_string())),
+        ],
+        lazy_vc: None,
+    }
+}
+
+/// Parse Monkey's Audio data into batch result.
+#[inline(always)]
+fn parse_ape_batch(data: &[u8], path: &str) -> Option<PreSerializedFile> {
+    ape::APEFile::parse(data, path).ok().map(|f| ape_preserialized(&f))
+}
+
+/// WavPack file as a batch result; APEv2 keys keep their case.
+fn wavpack_preserialized(f: &wavpack::WavPackFile) -> PreSerializedFile {
+    let tags = f.tags.iter()
+        .flat_map(|t| &t.items)
+        .map(|item| (item.key.clone(), ape_item_to_batch(item)))
+        .collect();
+    PreSerializedFile {
+        length: Some(f.info.length),
+        sample_rate: f.info.sample_rate,
+        channels: f.info.channels,
+        bitrate: if f.info.bitrate > 0 { Some(f.info.bitrate) } else { None },
+        tags,
+        extra: vec![
+            ("length_samples", BatchTagValue::Int(f.info.length_samples as i64)),
+            ("duration_ms", BatchTagValue::Int(f.info.duration_ms as i64)),
+            ("bits_per_sample", BatchTagValue::Int(f.info.bits_per_sample as i64)),
+            ("version", BatchTagValue::Int(f.info.version as i64)),
+        ],
+        lazy_vc: None,
+    }
+}
+
+/// Parse WavPack data into batch result.
+#[inline(always)]
+fn parse_wavpack_batch(data: &[u8], path: &str) -> Option<PreSerializedFile> {
+    wavpack::WavPackFile::parse(data, path).ok().map(|f| wavpack_preserialized(&f))
+}
+
 /// Parse + fully decode a single file from data (runs in parallel phase).
 /// Uses extension-based fast dispatch to skip unnecessary scoring.
 #[inline(always)]
-fn parse_and_serialize(data: &[u8], path: &str) -> Option<PreSerializedFile> {
+pub(crate) fn parse_and_serialize(data: &[u8], path: &str) -> Option<PreSerializedFile> {
     let ext = path.rsplit('.').next().unwrap_or("");
     if ext.eq_ignore_ascii_case("flac") {
         return parse_flac_batch(data, data.len());
     }
     if ext.eq_ignore_ascii_case("ogg") {
-        return parse_ogg_batch(data);
+        return parse_ogg_batch(data).or_else(|| parse_opus_batch(data, path));
+    }
+    if ext.eq_ignore_ascii_case("opus") {
+        return parse_opus_batch(data, path);
     }
     if ext.eq_ignore_ascii_case("mp3") {
         return parse_mp3_batch(data, path);
     }
-    if ext.eq_ignore_ascii_case("m4a") || ext.eq_ignore_ascii_case("m4b")
-        || ext.eq_ignore_ascii_case("mp4") || ext.eq_ignore_ascii_case("m4v") {
+    if mp4::is_mp4_extension(ext) {
         return parse_mp4_batch(data, path);
     }
+    if ext.eq_ignore_ascii_case("caf") {
+        return parse_caf_batch(data, path);
+    }
+    if ext.eq_ignore_ascii_case("ape") {
+        return parse_ape_batch(data, path);
+    }
+    if ext.eq_ignore_ascii_case("wv") {
+        return parse_wavpack_batch(data, path);
+    }
 
     let mp3_score = mp3::MP3File::score(path, data);
     let flac_score = flac::FLACFile::score(path, data);
     let ogg_score = ogg::OggVorbisFile::score(path, data);
+    let opus_score = ogg::opus::OpusFile::score(path, data);
     let mp4_score = mp4::MP4File::score(path, data);
-    let max_score = mp3_score.max(flac_score).max(ogg_score).max(mp4_score);
+    let caf_score = caf::CAFFile::score(path, data);
+    let ape_score = ape::APEFile::score(path, data);
+    let wavpack_score = wavpack::WavPackFile::score(path, data);
+    let max_score = mp3_score.max(flac_score).max(ogg_score).max(opus_score).max(mp4_score).max(caf_score)
+        .max(ape_score).max(wavpack_score);
 
     if max_score == 0 {
         return None;
@@ -1831,8 +4048,16 @@ fn parse_and_serialize(data: &[u8], path: &str) -> Option<PreSerializedFile> {
         parse_flac_batch(data, data.len())
     } else if max_score == ogg_score {
         parse_ogg_batch(data)
+    } else if max_score == opus_score {
+        parse_opus_batch(data, path)
     } else if max_score == mp4_score {
         parse_mp4_batch(data, path)
+    } else if max_score == caf_score {
+        parse_caf_batch(data, path)
+    } else if max_score == ape_score {
+        parse_ape_batch(data, path)
+    } else if max_score == wavpack_score {
+        parse_wavpack_batch(data, path)
     } else {
         parse_mp3_batch(data, path)
     }
@@ -1856,6 +4081,14 @@ fn batch_value_to_py(py: Python<'_>, bv: &BatchTagValue) -> PyResult<Py<PyAny>>
             dict.set_item(pyo3::intern!(py, "data"), PyBytes::new(py, data))?;
             Ok(dict.into_any().unbind())
         }
+        BatchTagValue::Object { mime, filename, desc, data } => {
+            let dict = PyDict::new(py);
+            dict.set_item(pyo3::intern!(py, "mime"), mime.as_str())?;
+            dict.set_item(pyo3::intern!(py, "filename"), filename.as_str())?;
+            dict.set_item(pyo3::intern!(py, "desc"), desc.as_str())?;
+            dict.set_item(pyo3::intern!(py, "data"), PyBytes::new(py, data))?;
+            Ok(dict.into_any().unbind())
+        }
         BatchTagValue::Popularimeter { email, rating, count } => {
             Ok(Py::new(py, PyPOPM {
                 email: email.clone(),
@@ -1884,6 +4117,13 @@ fn batch_value_to_py(py: Python<'_>, bv: &BatchTagValue) -> PyResult<Py<PyAny>>
             }
             Ok(list.into_any().unbind())
         }
+        BatchTagValue::Map(entries) => {
+            let dict = PyDict::new(py);
+            for (key, value) in entries {
+                dict.set_item(key.as_str(), batch_value_to_py(py, value)?)?;
+            }
+            Ok(dict.into_any().unbind())
+        }
     }
 }
 
@@ -1943,7 +4183,14 @@ fn preserialized_to_py_dict(py: Python<'_>, pf: &PreSerializedFile) -> PyResult<
         if inner.is_null() {
             return Err(pyo3::exceptions::PyMemoryError::new_err("dict alloc failed"));
         }
-        set_dict_f64(inner, pyo3::intern!(py, "length").as_ptr(), pf.length);
+        set_dict_opt_f64(inner, pyo3::intern!(py, "length").as_ptr(), pf.length);
+        // The only format extras batch results carry: they go with `length`
+        if let Some((_, BatchTagValue::Int(n))) = pf.extra.iter().find(|(k, _)| *k == "length_samples") {
+            set_dict_i64(inner, pyo3::intern!(py, "length_samples").as_ptr(), *n);
+        }
+        if let Some((_, BatchTagValue::Int(ms))) = pf.extra.iter().find(|(k, _)| *k == "duration_ms") {
+            set_dict_i64(inner, pyo3::intern!(py, "duration_ms").as_ptr(), *ms);
+        }
         set_dict_u32(inner, pyo3::intern!(py, "sample_rate").as_ptr(), pf.sample_rate);
         set_dict_u32(inner, pyo3::intern!(py, "channels").as_ptr(), pf.channels);
         if let Some(br) = pf.bitrate {
@@ -1954,7 +4201,7 @@ fn preserialized_to_py_dict(py: Python<'_>, pf: &PreSerializedFile) -> PyResult<
             if let Some(ref vc_bytes) = pf.lazy_vc {
                 let tags_dict = pyo3::ffi::PyDict_New();
                 if !tags_dict.is_null() {
-                    parse_vc_to_ffi_dict(vc_bytes, tags_dict);
+                    parse_vc_to_ffi_dict(vc_bytes, tags_dict, false);
                     pyo3::ffi::PyDict_SetItem(inner, pyo3::intern!(py, "tags").as_ptr(), tags_dict);
                     pyo3::ffi::Py_DECREF(tags_dict);
                 }
@@ -1990,8 +4237,9 @@ fn preserialized_to_py_dict(py: Python<'_>, pf: &PreSerializedFile) -> PyResult<
 /// Parse VC bytes directly into a Python dict using raw FFI.
 /// Skips intermediate Rust String allocations — goes from raw bytes straight to Python objects.
 /// Values are wrapped in lists (VC format: duplicate keys are merged into a single list).
+/// With `flat`, the dict also holds info fields and clashing names are namespaced.
 #[inline(always)]
-unsafe fn parse_vc_to_ffi_dict(data: &[u8], tags_dict: *mut pyo3::ffi::PyObject) {
+unsafe fn parse_vc_to_ffi_dict(data: &[u8], tags_dict: *mut pyo3::ffi::PyObject, flat: bool) {
     if data.len() < 8 { return; }
     let mut pos = 0;
     let vendor_len = u32::from_le_bytes([data[pos], data[pos+1], data[pos+2], data[pos+3]]) as usize;
@@ -2002,7 +4250,8 @@ unsafe fn parse_vc_to_ffi_dict(data: &[u8], tags_dict: *mut pyo3::ffi::PyObject)
     let count = u32::from_le_bytes([data[pos], data[pos+1], data[pos+2], data[pos+3]]) as usize;
     pos += 4;
 
-    for _ in 0..count.min(256) {
+    // No cap on count: every pass consumes at least 4 bytes, and grouping goes through the dict.
+    for _ in 0..count {
         if pos + 4 > data.len() { break; }
         let clen = u32::from_le_bytes([data[pos], data[pos+1], data[pos+2], data[pos+3]]) as usize;
         pos += 4;
@@ -2022,7 +4271,7 @@ unsafe fn parse_vc_to_ffi_dict(data: &[u8], tags_dict: *mut pyo3::ffi::PyObject)
         let key_len = key_bytes.len().min(128);
         for i in 0..key_len { buf[i] = key_bytes[i].to_ascii_lowercase(); }
 
-        let key_ptr = intern_tag_key(&buf[..key_len]);
+        let key_ptr = if flat { flat_tag_key(&buf[..key_len]) } else { intern_tag_key(&buf[..key_len]) };
         if key_ptr.is_null() { pyo3::ffi::PyErr_Clear(); continue; }
 
         let val_ptr = pyo3::ffi::PyUnicode_FromStringAndSize(
@@ -2060,7 +4309,6 @@ unsafe fn parse_vc_to_ffi_dict(data: &[u8], tags_dict: *mut pyo3::ffi::PyObject)
     }
 }
 
-#[allow(dead_code)]
 #[inline(always)]
 fn json_escape_to(s: &str, out: &mut String) {
     out.push('"');
@@ -2086,8 +4334,77 @@ fn json_escape_to(s: &str, out: &mut String) {
     out.push('"');
 }
 
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Write binary data as a quoted standard (RFC 4648, padded) base64 string.
+fn base64_json_to(data: &[u8], out: &mut String) {
+    out.reserve(data.len().div_ceil(3) * 4 + 2);
+    out.push('"');
+    base64_to(data, out);
+    out.push('"');
+}
+
+/// Append standard (RFC 4648, padded) base64 of `data` to `out`.
+fn base64_to(data: &[u8], out: &mut String) {
+    for chunk in data.chunks(3) {
+        let b = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let n = ((b[0] as u32) << 16) | ((b[1] as u32) << 8) | b[2] as u32;
+        out.push(BASE64_ALPHABET[(n >> 18) as usize & 0x3F] as char);
+        out.push(BASE64_ALPHABET[(n >> 12) as usize & 0x3F] as char);
+        out.push(if chunk.len() > 1 { BASE64_ALPHABET[(n >> 6) as usize & 0x3F] as char } else { '=' });
+        out.push(if chunk.len() > 2 { BASE64_ALPHABET[n as usize & 0x3F] as char } else { '=' });
+    }
+}
+
+/// Decode standard base64, padded or not, ignoring whitespace. `None` on any
+/// other character outside the alphabet.
+fn base64_decode(text: &str) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(text.len() / 4 * 3);
+    let (mut acc, mut bits) = (0u32, 0u32);
+    for &c in text.as_bytes() {
+        let v = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            b'=' => break,
+            b' ' | b'\t' | b'\r' | b'\n' => continue,
+            _ => return None,
+        } as u32;
+        acc = (acc << 6) | v;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((acc >> bits) as u8);
+        }
+    }
+    Some(out)
+}
+
+/// Binary tag values are only emitted in JSON when explicitly requested.
+#[inline(always)]
+fn is_binary_batch_value(bv: &BatchTagValue) -> bool {
+    matches!(bv, BatchTagValue::Bytes(_) | BatchTagValue::Picture { .. } | BatchTagValue::Object { .. } |
+        BatchTagValue::CoverList(_) | BatchTagValue::FreeFormList(_))
+}
+
+/// A picture as a JSON object with its data in base64.
+fn picture_json_to(mime: &str, pic_type: u8, desc: &str, data: &[u8], out: &mut String) {
+    out.push_str("{\"mime\":");
+    json_escape_to(mime, out);
+    out.push_str(",\"type\":");
+    write_int(out, pic_type);
+    out.push_str(",\"desc\":");
+    json_escape_to(desc, out);
+    out.push_str(",\"data\":");
+    base64_json_to(data, out);
+    out.push('}');
+}
+
 /// Serialize a BatchTagValue to a JSON fragment.
-#[allow(dead_code)]
+/// Binary payloads are written as base64 strings (callers filter them out
+/// with `is_binary_batch_value` when binary output is disabled).
 #[inline(always)]
 fn batch_value_to_json(bv: &BatchTagValue, out: &mut String) {
     match bv {
@@ -2110,32 +4427,77 @@ fn batch_value_to_json(bv: &BatchTagValue, out: &mut String) {
             write_int(out, *b);
             out.push(']');
         }
-        BatchTagValue::Bool(v) => {
-            out.push_str(if *v { "true" } else { "false" });
-        }
-        BatchTagValue::PairedText(pairs) => {
+        BatchTagValue::Bool(v) => {
+            out.push_str(if *v { "true" } else { "false" });
+        }
+        BatchTagValue::PairedText(pairs) => {
+            out.push('[');
+            for (i, (a, b)) in pairs.iter().enumerate() {
+                if i > 0 { out.push(','); }
+                out.push('[');
+                json_escape_to(a, out);
+                out.push(',');
+                json_escape_to(b, out);
+                out.push(']');
+            }
+            out.push(']');
+        }
+        BatchTagValue::Popularimeter { email, rating, count } => {
+            out.push_str("{\"email\":");
+            json_escape_to(email, out);
+            out.push_str(",\"rating\":");
+            write_int(out, *rating);
+            out.push_str(",\"count\":");
+            write_int(out, *count);
+            out.push('}');
+        }
+        BatchTagValue::Bytes(d) => base64_json_to(d, out),
+        BatchTagValue::Picture { mime, pic_type, desc, data } => picture_json_to(mime, *pic_type, desc, data, out),
+        BatchTagValue::Object { mime, filename, desc, data } => {
+            out.push_str("{\"mime\":");
+            json_escape_to(mime, out);
+            out.push_str(",\"filename\":");
+            json_escape_to(filename, out);
+            out.push_str(",\"desc\":");
+            json_escape_to(desc, out);
+            out.push_str(",\"data\":");
+            base64_json_to(data, out);
+            out.push('}');
+        }
+        BatchTagValue::CoverList(covers) => {
+            out.push('[');
+            for (i, (data, format)) in covers.iter().enumerate() {
+                if i > 0 { out.push(','); }
+                out.push_str("{\"format\":");
+                write_int(out, *format);
+                out.push_str(",\"data\":");
+                base64_json_to(data, out);
+                out.push('}');
+            }
+            out.push(']');
+        }
+        BatchTagValue::FreeFormList(forms) => {
             out.push('[');
-            for (i, (a, b)) in pairs.iter().enumerate() {
+            for (i, data) in forms.iter().enumerate() {
                 if i > 0 { out.push(','); }
-                out.push('[');
-                json_escape_to(a, out);
-                out.push(',');
-                json_escape_to(b, out);
-                out.push(']');
+                base64_json_to(data, out);
             }
             out.push(']');
         }
-        // Binary data types: serialize as null (skip in JSON mode)
-        BatchTagValue::Bytes(_) | BatchTagValue::Picture { .. } |
-        BatchTagValue::Popularimeter { .. } | BatchTagValue::CoverList(_) |
-        BatchTagValue::FreeFormList(_) => {
-            out.push_str("null");
+        BatchTagValue::Map(entries) => {
+            out.push('{');
+            for (i, (key, value)) in entries.iter().enumerate() {
+                if i > 0 { out.push(','); }
+                json_escape_to(key, out);
+                out.push(':');
+                batch_value_to_json(value, out);
+            }
+            out.push('}');
         }
     }
 }
 
 /// Write an integer to a string using itoa (faster than format!).
-#[allow(dead_code)]
 #[inline(always)]
 fn write_int(out: &mut String, v: impl itoa::Integer) {
     let mut buf = itoa::Buffer::new();
@@ -2143,19 +4505,32 @@ fn write_int(out: &mut String, v: impl itoa::Integer) {
 }
 
 /// Write a float to a string using ryu (faster than format!).
-#[allow(dead_code)]
+/// JSON has no NaN/Infinity, so non-finite values become null.
 #[inline(always)]
 fn write_float(out: &mut String, v: f64) {
+    if !v.is_finite() {
+        out.push_str("null");
+        return;
+    }
     let mut buf = ryu::Buffer::new();
     out.push_str(buf.format(v));
 }
 
 /// Serialize a PreSerializedFile to a JSON object string.
-#[allow(dead_code)]
+///
+/// Schema (stable): `{"length": float (null when unknown), "sample_rate": int, "channels": int,
+/// "bitrate": int (omitted when unknown), <format extras>..., "tags": {key: value}}`.
+/// Format extras are the same fields `_fast_read` exposes (e.g. `layer`, `codec`,
+/// `bits_per_sample`). Binary tag values (pictures, covers, freeform and raw frame
+/// data) are omitted unless `include_binary` is set, in which case bytes are
+/// base64-encoded.
 #[inline(always)]
-fn preserialized_to_json(pf: &PreSerializedFile, out: &mut String) {
+pub(crate) fn preserialized_to_json(pf: &PreSerializedFile, include_binary: bool, out: &mut String) {
     out.push_str("{\"length\":");
-    write_float(out, pf.length);
+    match pf.length {
+        Some(length) => write_float(out, length),
+        None => out.push_str("null"),
+    }
     out.push_str(",\"sample_rate\":");
     write_int(out, pf.sample_rate);
     out.push_str(",\"channels\":");
@@ -2164,6 +4539,12 @@ fn preserialized_to_json(pf: &PreSerializedFile, out: &mut String) {
         out.push_str(",\"bitrate\":");
         write_int(out, br);
     }
+    for (key, value) in &pf.extra {
+        out.push(',');
+        json_escape_to(key, out);
+        out.push(':');
+        batch_value_to_json(value, out);
+    }
     // Materialize lazy VC tags if needed
     let lazy_tags;
     let tags = if pf.tags.is_empty() {
@@ -2179,9 +4560,7 @@ fn preserialized_to_json(pf: &PreSerializedFile, out: &mut String) {
     out.push_str(",\"tags\":{");
     let mut first = true;
     for (key, value) in tags {
-        if matches!(value, BatchTagValue::Bytes(_) | BatchTagValue::Picture { .. } |
-            BatchTagValue::Popularimeter { .. } | BatchTagValue::CoverList(_) |
-            BatchTagValue::FreeFormList(_)) {
+        if !include_binary && is_binary_batch_value(value) {
             continue;
         }
         if !first { out.push(','); }
@@ -2193,15 +4572,450 @@ fn preserialized_to_json(pf: &PreSerializedFile, out: &mut String) {
     out.push_str("}}");
 }
 
-/// Lazy batch result — stores parsed Rust data, creates Python objects on demand.
-/// Uses HashMap for O(1) path lookup instead of O(n) linear search.
+/// Re-indent compact JSON produced by `preserialized_to_json` (matches `json.dumps(indent=n)`).
+fn json_pretty(compact: &str, indent: usize) -> String {
+    let mut out = String::with_capacity(compact.len() * 2);
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut chars = compact.chars().peekable();
+    let newline = |out: &mut String, depth: usize| {
+        out.push('\n');
+        out.extend(std::iter::repeat_n(' ', depth * indent));
+    };
+    while let Some(c) = chars.next() {
+        if in_string {
+            out.push(c);
+            if escaped { escaped = false; }
+            else if c == '\\' { escaped = true; }
+            else if c == '"' { in_string = false; }
+            continue;
+        }
+        match c {
+            '"' => { in_string = true; out.push(c); }
+            '{' | '[' => {
+                out.push(c);
+                if matches!(chars.peek(), Some('}') | Some(']')) {
+                    out.push(chars.next().unwrap_or(']'));
+                } else {
+                    depth += 1;
+                    newline(&mut out, depth);
+                }
+            }
+            '}' | ']' => {
+                depth = depth.saturating_sub(1);
+                newline(&mut out, depth);
+                out.push(c);
+            }
+            ',' => { out.push(','); newline(&mut out, depth); }
+            ':' => out.push_str(": "),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Render a PreSerializedFile as a JSON string, optionally pretty-printed.
+fn preserialized_to_json_string(pf: &PreSerializedFile, include_binary: bool, indent: Option<usize>) -> String {
+    let mut out = String::with_capacity(512);
+    preserialized_to_json(pf, include_binary, &mut out);
+    match indent {
+        Some(n) => json_pretty(&out, n),
+        None => out,
+    }
+}
+
+/// Group Vorbis comments into batch tags (one TextList per key, first-seen order).
+fn vc_to_batch_tags(vc: &vorbis::VorbisComment) -> Vec<(String, BatchTagValue)> {
+    let mut tags: Vec<(String, BatchTagValue)> = Vec::new();
+    let mut index = common::keyindex::KeyIndex::new();
+    for (key, value) in &vc.comments {
+        if let Some(i) = index.find(&tags, key.as_str()) {
+            if let BatchTagValue::TextList(ref mut v) = tags[i].1 {
+                v.push(value.clone());
+            }
+        } else {
+            tags.push((key.clone(), BatchTagValue::TextList(vec![value.clone()])));
+            index.pushed(&tags);
+        }
+    }
+    tags
+}
+
+// ---- MessagePack output (same schema as the JSON mode, binary values as bin) ----
+// Writes go to a Vec<u8>, so the rmp encoder results are infallible and ignored.
+
+#[inline(always)]
+fn msgpack_float(out: &mut Vec<u8>, v: f64) {
+    if v.is_finite() {
+        let _ = rmp::encode::write_f64(out, v);
+    } else {
+        let _ = rmp::encode::write_nil(out);
+    }
+}
+
+fn batch_value_to_msgpack(bv: &BatchTagValue, out: &mut Vec<u8>) {
+    use rmp::encode::{write_array_len, write_bin, write_bool, write_map_len, write_sint, write_str, write_uint};
+    match bv {
+        BatchTagValue::Text(s) => { let _ = write_str(out, s); }
+        BatchTagValue::TextList(v) => {
+            let _ = write_array_len(out, v.len() as u32);
+            for s in v { let _ = write_str(out, s); }
+        }
+        BatchTagValue::Int(i) => { let _ = write_sint(out, *i); }
+        BatchTagValue::IntPair(a, b) => {
+            let _ = write_array_len(out, 2);
+            let _ = write_sint(out, *a as i64);
+            let _ = write_sint(out, *b as i64);
+        }
+        BatchTagValue::Bool(v) => { let _ = write_bool(out, *v); }
+        BatchTagValue::PairedText(pairs) => {
+            let _ = write_array_len(out, pairs.len() as u32);
+            for (a, b) in pairs {
+                let _ = write_array_len(out, 2);
+                let _ = write_str(out, a);
+                let _ = write_str(out, b);
+            }
+        }
+        BatchTagValue::Popularimeter { email, rating, count } => {
+            let _ = write_map_len(out, 3);
+            let _ = write_str(out, "email");
+            let _ = write_str(out, email);
+            let _ = write_str(out, "rating");
+            let _ = write_uint(out, *rating as u64);
+            let _ = write_str(out, "count");
+            let _ = write_uint(out, *count);
+        }
+        BatchTagValue::Bytes(d) => { let _ = write_bin(out, d); }
+        BatchTagValue::Picture { mime, pic_type, desc, data } => {
+            let _ = write_map_len(out, 4);
+            let _ = write_str(out, "mime");
+            let _ = write_str(out, mime);
+            let _ = write_str(out, "type");
+            let _ = write_uint(out, *pic_type as u64);
+            let _ = write_str(out, "desc");
+            let _ = write_str(out, desc);
+            let _ = write_str(out, "data");
+            let _ = write_bin(out, data);
+        }
+        BatchTagValue::Object { mime, filename, desc, data } => {
+            let _ = write_map_len(out, 4);
+            let _ = write_str(out, "mime");
+            let _ = write_str(out, mime);
+            let _ = write_str(out, "filename");
+            let _ = write_str(out, filename);
+            let _ = write_str(out, "desc");
+            let _ = write_str(out, desc);
+            let _ = write_str(out, "data");
+            let _ = write_bin(out, data);
+        }
+        BatchTagValue::CoverList(covers) => {
+            let _ = write_array_len(out, covers.len() as u32);
+            for (data, format) in covers {
+                let _ = write_map_len(out, 2);
+                let _ = write_str(out, "format");
+                let _ = write_uint(out, *format as u64);
+                let _ = write_str(out, "data");
+                let _ = write_bin(out, data);
+            }
+        }
+        BatchTagValue::FreeFormList(forms) => {
+            let _ = write_array_len(out, forms.len() as u32);
+            for data in forms { let _ = write_bin(out, data); }
+        }
+        BatchTagValue::Map(entries) => {
+            let _ = write_map_len(out, entries.len() as u32);
+            for (key, value) in entries {
+                let _ = write_str(out, key);
+                batch_value_to_msgpack(value, out);
+            }
+        }
+    }
+}
+
+/// Serialize a PreSerializedFile as a MessagePack map with the same keys as
+/// `preserialized_to_json`. Binary tag values are always included (as bin).
+fn preserialized_to_msgpack(pf: &PreSerializedFile, out: &mut Vec<u8>) {
+    use rmp::encode::{write_map_len, write_str, write_uint};
+    let lazy_tags;
+    let tags = match pf.lazy_vc {
+        Some(ref vc_bytes) if pf.tags.is_empty() => {
+            lazy_tags = parse_vc_to_batch_tags(vc_bytes);
+            &lazy_tags
+        }
+        _ => &pf.tags,
+    };
+    let fields = 4 + pf.bitrate.is_some() as u32 + pf.extra.len() as u32;
+    let _ = write_map_len(out, fields);
+    let _ = write_str(out, "length");
+    match pf.length {
+        Some(length) => msgpack_float(out, length),
+        None => { let _ = rmp::encode::write_nil(out); }
+    }
+    let _ = write_str(out, "sample_rate");
+    let _ = write_uint(out, pf.sample_rate as u64);
+    let _ = write_str(out, "channels");
+    let _ = write_uint(out, pf.channels as u64);
+    if let Some(br) = pf.bitrate {
+        let _ = write_str(out, "bitrate");
+        let _ = write_uint(out, br as u64);
+    }
+    for (key, value) in &pf.extra {
+        let _ = write_str(out, key);
+        batch_value_to_msgpack(value, out);
+    }
+    let _ = write_str(out, "tags");
+    let _ = write_map_len(out, tags.len() as u32);
+    for (key, value) in tags {
+        let _ = write_str(out, key);
+        batch_value_to_msgpack(value, out);
+    }
+}
+
+/// Serialize `(path, file)` pairs as one MessagePack map (path → file map). Each file is
+/// encoded on the rayon pool; only the final concatenation is sequential.
+fn batch_to_msgpack(entries: &[(&str, &PreSerializedFile)]) -> Vec<u8> {
+    use rayon::prelude::*;
+    let parts: Vec<Vec<u8>> = entries.par_iter()
+        .map(|(path, pf)| {
+            let mut buf = Vec::with_capacity(512);
+            let _ = rmp::encode::write_str(&mut buf, path);
+            preserialized_to_msgpack(pf, &mut buf);
+            buf
+        })
+        .collect();
+    let total: usize = parts.iter().map(|p| p.len()).sum();
+    let mut out = Vec::with_capacity(total + 5);
+    let _ = rmp::encode::write_map_len(&mut out, entries.len() as u32);
+    for part in &parts {
+        out.extend_from_slice(part);
+    }
+    out
+}
+
+/// Append a Python value to `out` as MessagePack: dicts as maps, lists and
+/// tuples as arrays, bytes as bin. Other types raise `TypeError`.
+fn py_to_msgpack(obj: &Bound<'_, PyAny>, out: &mut Vec<u8>) -> PyResult<()> {
+    use rmp::encode::{write_array_len, write_bin, write_bool, write_map_len, write_nil, write_sint, write_str, write_uint};
+    if obj.is_none() {
+        let _ = write_nil(out);
+    } else if let Ok(b) = obj.cast::<pyo3::types::PyBool>() {
+        let _ = write_bool(out, b.is_true());
+    } else if let Ok(i) = obj.extract::<i64>() {
+        let _ = write_sint(out, i);
+    } else if let Ok(u) = obj.extract::<u64>() {
+        let _ = write_uint(out, u);
+    } else if let Ok(f) = obj.cast::<pyo3::types::PyFloat>() {
+        msgpack_float(out, f.value());
+    } else if let Ok(s) = obj.cast::<pyo3::types::PyString>() {
+        let _ = write_str(out, &s.to_cow()?);
+    } else if let Ok(b) = obj.cast::<PyBytes>() {
+        let _ = write_bin(out, b.as_bytes());
+    } else if let Ok(d) = obj.cast::<PyDict>() {
+        let _ = write_map_len(out, d.len() as u32);
+        for (k, v) in d.iter() {
+            py_to_msgpack(&k, out)?;
+            py_to_msgpack(&v, out)?;
+        }
+    } else if let Ok(items) = obj.cast::<PyList>() {
+        let _ = write_array_len(out, items.len() as u32);
+        for v in items.iter() {
+            py_to_msgpack(&v, out)?;
+        }
+    } else if let Ok(items) = obj.cast::<PyTuple>() {
+        let _ = write_array_len(out, items.len() as u32);
+        for v in items.iter() {
+            py_to_msgpack(&v, out)?;
+        }
+    } else {
+        return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "cannot serialize {} to MessagePack", obj.get_type().name()?)));
+    }
+    Ok(())
+}
+
+/// Every easy key name produced by `easy_tag_key`.
+const EASY_KEYS: &[&str] = &[
+    "title", "artist", "album", "albumartist", "tracknumber", "discnumber", "genre", "date",
+    "composer", "lyricist", "length", "organization", "copyright", "isrc", "mood", "bpm",
+    "grouping", "media", "encodedby", "website", "conductor", "arranger", "discsubtitle",
+    "language", "version", "performer", "albumsort", "albumartistsort", "artistsort",
+    "titlesort", "composersort", "comment", "lyrics",
+];
+
+/// ID3v2 frame IDs with an easy name; mirrors `_EASY_ID3_MAP` in the Python wrapper.
+const EASY_ID3_FRAMES: &[(&str, &str)] = &[
+    ("TIT2", "title"), ("TPE1", "artist"), ("TALB", "album"), ("TPE2", "albumartist"),
+    ("TRCK", "tracknumber"), ("TPOS", "discnumber"), ("TCON", "genre"), ("TDRC", "date"),
+    ("TCOM", "composer"), ("TEXT", "lyricist"), ("TLEN", "length"), ("TPUB", "organization"),
+    ("TCOP", "copyright"), ("TSRC", "isrc"), ("TMOO", "mood"), ("TBPM", "bpm"),
+    ("TIT1", "grouping"), ("TMED", "media"), ("TENC", "encodedby"), ("WOAR", "website"),
+    ("TPE3", "conductor"), ("TPE4", "arranger"), ("TSST", "discsubtitle"), ("TLAN", "language"),
+    ("TIT3", "version"), ("TMCL", "performer"), ("TSOA", "albumsort"), ("TSO2", "albumartistsort"),
+    ("TSOP", "artistsort"), ("TSOT", "titlesort"), ("TSOC", "composersort"),
+];
+
+/// MP4 atoms with an easy name; mirrors `_EASY_MP4_MAP` in the Python wrapper.
+const EASY_MP4_ATOMS: &[(&str, &str)] = &[
+    ("\u{a9}nam", "title"), ("\u{a9}ART", "artist"), ("\u{a9}alb", "album"), ("aART", "albumartist"),
+    ("\u{a9}day", "date"), ("\u{a9}gen", "genre"), ("\u{a9}cmt", "comment"), ("\u{a9}wrt", "composer"),
+    ("\u{a9}grp", "grouping"), ("trkn", "tracknumber"), ("disk", "discnumber"), ("tmpo", "bpm"),
+    ("cprt", "copyright"), ("\u{a9}lyr", "lyrics"), ("\u{a9}too", "encodedby"),
+];
+
+/// Canonical easy key for a native tag key (ID3 frame ID, MP4 atom or Vorbis comment name).
+/// Vorbis comment keys are already easy names, so they pass through when they name a
+/// known field.
+fn easy_tag_key(key: &str) -> Option<&'static str> {
+    EASY_ID3_FRAMES.iter().chain(EASY_MP4_ATOMS)
+        .find(|(native, _)| *native == key)
+        .map(|(_, easy)| *easy)
+        // Vorbis comments are lowercased during parsing and already use easy names
+        .or_else(|| EASY_KEYS.iter().find(|k| **k == key).copied())
+}
+
+/// Native key for an easy name in `table` (`EASY_ID3_FRAMES` or `EASY_MP4_ATOMS`).
+fn native_tag_key(table: &[(&'static str, &str)], easy: &str) -> Option<&'static str> {
+    table.iter().find(|(_, e)| *e == easy).map(|(native, _)| *native)
+}
+
+/// Render a tag value as a list of strings for easy-key output.
+/// Binary values have no text form and yield `None`.
+fn batch_value_to_strings(bv: &BatchTagValue) -> Option<Vec<String>> {
+    let strings = match bv {
+        BatchTagValue::Text(s) => vec![s.clone()],
+        BatchTagValue::TextList(v) => v.clone(),
+        BatchTagValue::Int(i) => vec![i.to_string()],
+        BatchTagValue::IntPair(n, 0) => vec![n.to_string()],
+        BatchTagValue::IntPair(n, total) => vec![format!("{}/{}", n, total)],
+        BatchTagValue::Bool(b) => vec![if *b { "1" } else { "0" }.to_string()],
+        BatchTagValue::PairedText(pairs) => pairs.iter()
+            .map(|(role, name)| format!("{}:{}", role, name))
+            .collect(),
+        BatchTagValue::Popularimeter { .. }
+        | BatchTagValue::Bytes(_)
+        | BatchTagValue::Picture { .. }
+        | BatchTagValue::Object { .. }
+        | BatchTagValue::CoverList(_)
+        | BatchTagValue::FreeFormList(_)
+        | BatchTagValue::Map(_) => return None,
+    };
+    Some(strings)
+}
+
+/// Map a file's native tags onto easy keys (`title`, `tracknumber`, ...), all values as
+/// string lists; repeated easy keys are merged. Tags without an easy name (or without a
+/// text form) are dropped, or with `keep_extra` kept as-is under an `extra` map.
+fn easy_batch_tags(pf: &PreSerializedFile, keep_extra: bool) -> Vec<(String, BatchTagValue)> {
+    let lazy_tags;
+    let tags = match pf.lazy_vc {
+        Some(ref vc_bytes) if pf.tags.is_empty() => {
+            lazy_tags = parse_vc_to_batch_tags(vc_bytes);
+            &lazy_tags
+        }
+        _ => &pf.tags,
+    };
+    let mut easy: Vec<(String, BatchTagValue)> = Vec::with_capacity(tags.len());
+    let mut unmapped: Vec<(String, BatchTagValue)> = Vec::new();
+    for (key, value) in tags {
+        let mapped = easy_tag_key(key).zip(batch_value_to_strings(value));
+        let Some((easy_key, mut strings)) = mapped else {
+            if keep_extra {
+                unmapped.push((key.clone(), value.clone()));
+            }
+            continue;
+        };
+        if let Some((_, BatchTagValue::TextList(existing))) = easy.iter_mut().find(|(k, _)| k == easy_key) {
+            existing.append(&mut strings);
+        } else {
+            easy.push((easy_key.to_string(), BatchTagValue::TextList(strings)));
+        }
+    }
+    if keep_extra {
+        easy.push(("extra".to_string(), BatchTagValue::Map(unmapped)));
+    }
+    easy
+}
+
+/// Copy of a parsed file with its tags routed through `easy_batch_tags`.
+fn easy_preserialized(pf: &PreSerializedFile, keep_extra: bool) -> PreSerializedFile {
+    PreSerializedFile {
+        length: pf.length,
+        sample_rate: pf.sample_rate,
+        channels: pf.channels,
+        bitrate: pf.bitrate,
+        tags: easy_batch_tags(pf, keep_extra),
+        extra: pf.extra.clone(),
+        lazy_vc: None,
+    }
+}
+
+/// Editable batch result: the result dicts of `batch_open` and the edits
+/// waiting for `save_all`. Uses HashMap for O(1) path lookup instead of O(n)
+/// linear search.
 #[pyclass(name = "BatchResult")]
 struct PyBatchResult {
     paths: Vec<String>,
-    /// Pre-materialized dict templates (one per dedup group, shared via clone_ref).
-    /// __getitem__ returns PyDict_Copy of these — no Mutex, no HashMap lookup.
+    /// Result dicts (parallel to `paths`), one per file and shared via
+    /// clone_ref by every path naming it. Edits change them in place.
     dicts: Vec<Py<PyAny>>,
     index: HashMap<String, usize>,
+    /// The file each path names (parallel to `paths`): its slot after path
+    /// deduplication, equal for every spelling of one file.
+    slots: Vec<usize>,
+    /// Format extras of each path's file (parallel to `paths`), which the
+    /// dicts leave out but `to_msgpack` writes like `_fast_batch_read_msgpack`.
+    extras: Vec<Vec<(&'static str, BatchTagValue)>>,
+    /// Unsaved `set_tag`/`del_tag` changes, keyed by the first path of each file.
+    edits: HashMap<usize, Vec<TagEdit>>,
+}
+
+/// A pending batch tag change: the new text values, or `None` to delete the key.
+type TagEdit = (String, Option<Vec<String>>);
+
+impl PyBatchResult {
+    /// Index of the first path of each distinct file (dedupe slot), so paths
+    /// naming the same file count once.
+    fn distinct_files(&self) -> Vec<usize> {
+        let mut seen = HashSet::with_capacity(self.slots.len());
+        (0..self.slots.len())
+            .filter(|&i| seen.insert(self.slots[i]))
+            .collect()
+    }
+
+    /// Apply one edit to the result dict of `path`, shared by every path
+    /// naming the same file, and queue it for `save_all`.
+    fn edit(&mut self, py: Python<'_>, path: &str, key: &str, values: Option<Vec<String>>) -> PyResult<()> {
+        let Some(&idx) = self.index.get(path) else {
+            return Err(PyKeyError::new_err(path.to_string()));
+        };
+        // Vorbis comment keys are stored lowercase, like the parsed ones
+        let format = probe_format(path, &[]);
+        let vc = matches!(format, Some("flac" | "ogg" | "opus"));
+        let key = if vc { key.to_lowercase() } else { key.to_string() };
+        let mp4_value = match (format, &values) {
+            (Some("mp3"), Some(v)) => { batch_id3_frame(&key, v.clone())?; None }
+            (Some("mp4"), Some(v)) => Some(batch_mp4_value(&key, v)?),
+            _ => None,
+        };
+
+        // Shaped as parsed: one text is a string, except Vorbis comments (lists);
+        // typed MP4 atoms as the batch reader gives them
+        let tags = self.dicts[idx].bind(py).get_item("tags")?.cast_into::<PyDict>()?;
+        match (&values, mp4_value) {
+            (_, Some(value)) => tags.set_item(&key, batch_value_to_py(py, &mp4_value_to_batch(value))?)?,
+            (Some(v), None) if v.len() == 1 && !vc => tags.set_item(&key, &v[0])?,
+            (Some(v), None) => tags.set_item(&key, v)?,
+            (None, None) if tags.contains(&key)? => tags.del_item(&key)?,
+            (None, None) => return Err(PyKeyError::new_err(key)),
+        }
+
+        let first = self.slots.iter().position(|&s| s == self.slots[idx]).unwrap_or(idx);
+        let pending = self.edits.entry(first).or_default();
+        pending.retain(|(k, _)| *k != key);
+        pending.push((key, values));
+        Ok(())
+    }
 }
 
 #[pymethods]
@@ -2236,13 +5050,541 @@ impl PyBatchResult {
                 list.append(tuple)?;
             }
         }
-        Ok(list.into_any().unbind())
+        Ok(list.into_any().unbind())
+    }
+
+    /// Sum of the lengths in seconds of all files, each file counted once
+    /// however many paths name it. Files without a length add nothing.
+    fn total_length(&self, py: Python<'_>) -> f64 {
+        self.distinct_files().into_iter().filter_map(|i| dict_length(self.dicts[i].bind(py))).sum()
+    }
+
+    /// Number of files per format name (`"mp3"`, `"flac"`, ...), each file
+    /// counted once; the format comes from the path's extension, else `"unknown"`.
+    fn count_by_format(&self) -> HashMap<&'static str, usize> {
+        count_formats(self.distinct_files().into_iter().map(|i| self.paths[i].as_str()))
+    }
+
+    /// Serialize all results as a MessagePack map (path → file), binary values included.
+    /// Built from the result dicts (so edits are included) plus each file's format
+    /// extras, giving the fields `_fast_batch_read_msgpack` writes.
+    fn to_msgpack<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        let mut out = Vec::with_capacity(512 * self.paths.len());
+        let _ = rmp::encode::write_map_len(&mut out, self.paths.len() as u32);
+        for ((path, dict), extras) in self.paths.iter().zip(&self.dicts).zip(&self.extras) {
+            let _ = rmp::encode::write_str(&mut out, path);
+            // The dict's fields, then the format extras it lacks, then its tags
+            let dict = dict.bind(py).cast::<PyDict>()?;
+            let extras: Vec<_> = extras.iter()
+                .filter(|(key, _)| !matches!(dict.contains(*key), Ok(true)))
+                .collect();
+            let _ = rmp::encode::write_map_len(&mut out, (dict.len() + extras.len()) as u32);
+            for (key, value) in dict.iter() {
+                if key.eq("tags")? { continue; }
+                py_to_msgpack(&key, &mut out)?;
+                py_to_msgpack(&value, &mut out)?;
+            }
+            for (key, value) in extras {
+                let _ = rmp::encode::write_str(&mut out, key);
+                batch_value_to_msgpack(value, &mut out);
+            }
+            if let Some(tags) = dict.get_item("tags")? {
+                let _ = rmp::encode::write_str(&mut out, "tags");
+                py_to_msgpack(&tags, &mut out)?;
+            }
+        }
+        Ok(PyBytes::new(py, &out))
+    }
+
+    /// Set a text tag on one file (a string or list of strings). The result
+    /// dict updates at once; the file is written by `save_all`.
+    fn set_tag(&mut self, py: Python<'_>, path: &str, key: &str, value: &Bound<'_, PyAny>) -> PyResult<()> {
+        let text = value.extract::<Vec<String>>().or_else(|_| {
+            value.extract::<String>().map(|s| vec![s])
+        })?;
+        self.edit(py, path, key, Some(text))
+    }
+
+    /// Remove a tag from one file; written by `save_all`.
+    fn del_tag(&mut self, py: Python<'_>, path: &str, key: &str) -> PyResult<()> {
+        self.edit(py, path, key, None)
+    }
+
+    /// Paths with unsaved changes.
+    fn dirty(&self) -> Vec<String> {
+        let mut idx: Vec<usize> = self.edits.keys().copied().collect();
+        idx.sort_unstable();
+        idx.into_iter().map(|i| self.paths[i].clone()).collect()
+    }
+
+    /// Write every changed file in parallel, each in its own format. Returns
+    /// the number of files saved; on failure the first error is raised and the
+    /// files that failed stay pending.
+    fn save_all(&mut self, py: Python<'_>) -> PyResult<usize> {
+        use rayon::prelude::*;
+        let jobs: Vec<(usize, Vec<TagEdit>)> = self.edits.drain().collect();
+        let paths = &self.paths;
+        let results: Vec<(usize, Vec<TagEdit>, common::error::Result<()>)> = py.detach(|| {
+            jobs.into_par_iter()
+                .map(|(idx, edits)| {
+                    let res = save_tag_edits(&paths[idx], &edits);
+                    invalidate_file(&paths[idx]);
+                    (idx, edits, res)
+                })
+                .collect()
+        });
+        let mut saved = 0;
+        let mut first_err = None;
+        for (idx, edits, res) in results {
+            match res {
+                Ok(()) => saved += 1,
+                Err(e) => {
+                    first_err.get_or_insert_with(|| format!("{}: {}", self.paths[idx], e));
+                    self.edits.insert(idx, edits);
+                }
+            }
+        }
+        match first_err {
+            Some(msg) => Err(PyIOError::new_err(msg)),
+            None => Ok(saved),
+        }
+    }
+}
+
+/// Number of paths per format name, from each path's extension.
+fn count_formats<'a>(paths: impl Iterator<Item = &'a str>) -> HashMap<&'static str, usize> {
+    let mut counts = HashMap::new();
+    for path in paths {
+        *counts.entry(probe_format(path, &[]).unwrap_or("unknown")).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// The entries of a `batch_open` result, dict or `BatchResult`, with one
+/// `(path, result dict)` per distinct file. Paths naming the same file share
+/// one result dict, so they are told apart by its identity.
+fn distinct_batch_entries<'py>(result: &Bound<'py, PyAny>) -> PyResult<Vec<(String, Bound<'py, PyAny>)>> {
+    let py = result.py();
+    if let Ok(batch) = result.cast::<PyBatchResult>() {
+        let batch = batch.borrow();
+        return Ok(batch.distinct_files().into_iter()
+            .map(|i| (batch.paths[i].clone(), batch.dicts[i].bind(py).clone()))
+            .collect());
+    }
+    let dict = result.cast::<PyDict>()?;
+    let mut seen = HashSet::with_capacity(dict.len());
+    let mut out = Vec::with_capacity(dict.len());
+    for (path, d) in dict.iter() {
+        if seen.insert(d.as_ptr()) {
+            out.push((path.extract::<String>()?, d));
+        }
+    }
+    Ok(out)
+}
+
+/// The `length` of a result dict, if it has one.
+fn dict_length(d: &Bound<'_, PyAny>) -> Option<f64> {
+    d.get_item("length").ok()?.extract::<Option<f64>>().ok()?
+}
+
+/// Sum of the lengths in seconds of the files in a `batch_open` result (the
+/// plain dict or a `BatchResult`), each file counted once however many paths
+/// name it. Files without a length add nothing.
+#[pyfunction]
+fn batch_total_length(result: &Bound<'_, PyAny>) -> PyResult<f64> {
+    Ok(distinct_batch_entries(result)?.iter().filter_map(|(_, d)| dict_length(d)).sum())
+}
+
+/// Number of files per format name in a `batch_open` result (the plain dict
+/// or a `BatchResult`), each file counted once; the format comes from the
+/// path's extension, else `"unknown"`.
+#[pyfunction]
+fn batch_count_by_format(result: &Bound<'_, PyAny>) -> PyResult<HashMap<&'static str, usize>> {
+    let entries = distinct_batch_entries(result)?;
+    Ok(count_formats(entries.iter().map(|(p, _)| p.as_str())))
+}
+
+/// The ID3 frame a batch edit of `key` writes: a text frame, `TXXX:desc`, or a
+/// `COMM`/`USLT` key, built by `id3_frame_for_key`. Keys of other frames
+/// (`APIC:`, `POPM:...`) and malformed IDs are rejected.
+fn batch_id3_frame(key: &str, text: Vec<String>) -> PyResult<id3::frames::Frame> {
+    let id = key.get(..4).unwrap_or(key);
+    let qualified = key.len() > 4 && key.as_bytes()[4] == b':';
+    let editable = match id {
+        "TXXX" | "COMM" | "USLT" => key.len() == 4 || qualified,
+        _ => key.len() == 4 && id.starts_with('T')
+            && id.bytes().all(|b| b.is_ascii_uppercase() || b.is_ascii_digit()),
+    };
+    if !editable {
+        return Err(PyValueError::new_err(format!(
+            "cannot set {:?}: only text, TXXX, COMM and USLT frames can be edited", key
+        )));
+    }
+    id3_frame_for_key(key, text)
+}
+
+/// MP4 text atoms (besides the `©` ones) a batch edit may write.
+const BATCH_MP4_TEXT_ATOMS: &[&str] = &[
+    "aART", "cprt", "desc", "ldes", "purd", "purl", "egid", "catg", "keyw", "soaa", "soal",
+    "soar", "soco", "sonm", "sosn", "tvsh", "tven", "tvnn", "ownr", "apID", "xid ",
+];
+
+/// MP4 integer atoms a batch edit may write.
+const BATCH_MP4_INT_ATOMS: &[&str] = &[
+    "tmpo", "rtng", "stik", "tves", "tvsn", "akID", "cnID", "geID", "atID", "sfID", "cmID", "plID",
+];
+
+/// MP4 flag atoms a batch edit may write.
+const BATCH_MP4_BOOL_ATOMS: &[&str] = &["cpil", "pgap", "pcst", "hdvd", "shwm"];
+
+/// The MP4 value a batch edit of `key` writes: `trkn`/`disk` pairs from "N/M",
+/// integers and flags (`1`/`0`/`true`/`false`) for their atoms, text for text
+/// and freeform atoms. Other atoms (`covr`, `gnre`, ...) are rejected.
+fn batch_mp4_value(key: &str, text: &[String]) -> PyResult<mp4::MP4TagValue> {
+    let invalid = |s: &str| PyValueError::new_err(format!(
+        "Invalid value for MP4 key '{}': {:?}", key, s
+    ));
+    if key.starts_with("----") {
+        return Ok(mp4::MP4TagValue::FreeForm(text.iter()
+            .map(|s| mp4::MP4FreeForm { data: s.clone().into_bytes().into(), dataformat: 1 })
+            .collect()));
+    }
+    if key.starts_with('\u{a9}') || BATCH_MP4_TEXT_ATOMS.contains(&key) {
+        return Ok(mp4::MP4TagValue::Text(text.to_vec()));
+    }
+    if key == "trkn" || key == "disk" {
+        let pairs = text.iter().map(|s| parse_int_pair(key, s)).collect::<PyResult<Vec<_>>>()?;
+        return Ok(mp4::MP4TagValue::IntPair(pairs));
+    }
+    if BATCH_MP4_INT_ATOMS.contains(&key) {
+        let ints = text.iter()
+            .map(|s| s.trim().parse::<i64>().map_err(|_| invalid(s)))
+            .collect::<PyResult<Vec<_>>>()?;
+        return Ok(mp4::MP4TagValue::Integer(ints));
+    }
+    if BATCH_MP4_BOOL_ATOMS.contains(&key) {
+        return match text {
+            [s] => match s.trim().to_ascii_lowercase().as_str() {
+                "1" | "true" => Ok(mp4::MP4TagValue::Bool(true)),
+                "0" | "false" => Ok(mp4::MP4TagValue::Bool(false)),
+                _ => Err(invalid(s)),
+            },
+            _ => Err(PyValueError::new_err(format!("MP4 key '{}' takes a single flag", key))),
+        };
+    }
+    Err(PyValueError::new_err(format!(
+        "cannot set {:?}: only text, freeform, trkn/disk, integer and flag atoms can be edited", key
+    )))
+}
+
+/// Apply text tag edits to the file at `path` and save it through its format's
+/// writer (ID3v2 text, TXXX, COMM and USLT frames for MP3, Vorbis comments for
+/// FLAC/Ogg, ilst atoms typed by `batch_mp4_value` for MP4).
+fn save_tag_edits(path: &str, edits: &[TagEdit]) -> common::error::Result<()> {
+    let data = std::fs::read(path)?;
+    match probe_format(path, &data) {
+        Some("flac") => {
+            let mut f = flac::FLACFile::parse(&data, path)?;
+            f.ensure_tags();
+            let vc = f.tags.get_or_insert_with(vorbis::VorbisComment::new);
+            for (key, values) in edits {
+                match values {
+                    Some(v) => vc.set(key, v.clone()),
+                    None => vc.delete(key),
+                }
+            }
+            f.save()
+        }
+        Some("ogg") => {
+            let mut f = ogg::OggVorbisFile::parse(&data, path)?;
+            f.ensure_tags();
+            for (key, values) in edits {
+                match values {
+                    Some(v) => f.tags.set(key, v.clone()),
+                    None => f.tags.delete(key),
+                }
+            }
+            f.save()
+        }
+        Some("opus") => {
+            let mut f = ogg::opus::OpusFile::parse(&data, path)?;
+            f.ensure_tags();
+            for (key, values) in edits {
+                match values {
+                    Some(v) => f.tags.set(key, v.clone()),
+                    None => f.tags.delete(key),
+                }
+            }
+            f.save()
+        }
+        Some("mp3") => {
+            let mut f = mp3::MP3File::parse(&data, path)?;
+            f.ensure_tags_parsed(&data);
+            for (key, values) in edits {
+                f.tags.delall(key);
+                if let Some(v) = values {
+                    let frame = batch_id3_frame(key, v.clone()).map_err(|_| {
+                        common::error::MutagenError::ValueError(format!("cannot write {} as an ID3 frame", key))
+                    })?;
+                    f.tags.add(frame);
+                }
+            }
+            f.save()
+        }
+        Some("mp4") => {
+            let mut f = mp4::MP4File::parse(&data, path)?;
+            f.ensure_parsed_with_data(&data);
+            for (key, values) in edits {
+                match values {
+                    Some(v) => {
+                        let value = batch_mp4_value(key, v).map_err(|_| {
+                            common::error::MutagenError::ValueError(format!("cannot write {} as an MP4 atom", key))
+                        })?;
+                        f.tags.set(key, value);
+                    }
+                    None => f.tags.delete(key),
+                }
+            }
+            f.save()
+        }
+        _ => Err(common::error::MutagenError::ValueError(format!("cannot save tags to {}", path))),
+    }
+}
+
+/// Tags `copy_tags` carries from one format to another: text fields under their
+/// easy names and the cover picture.
+struct PortableTags {
+    fields: Vec<(String, Vec<String>)>,
+    cover: Option<flac::FLACPicture>,
+}
+
+/// Read the portable tags of `path`. Text fields are those `batch_open(easy=True)`
+/// gives, plus an ID3 comment or lyrics frame without description, less `length`
+/// (which describes the source audio). The cover is the front cover, else the
+/// first picture.
+fn read_portable_tags(path: &str, data: &[u8]) -> common::error::Result<PortableTags> {
+    use common::error::MutagenError;
+    let pf = parse_and_serialize(data, path)
+        .ok_or_else(|| MutagenError::ValueError(format!("cannot read tags from {}", path)))?;
+    let mut fields: Vec<(String, Vec<String>)> = easy_batch_tags(&pf, false).into_iter()
+        .filter_map(|(key, value)| match value {
+            BatchTagValue::TextList(values) if key != "length" && !values.is_empty() => Some((key, values)),
+            _ => None,
+        })
+        .collect();
+
+    let lazy_tags;
+    let tags = match pf.lazy_vc {
+        Some(ref vc_bytes) if pf.tags.is_empty() => {
+            lazy_tags = parse_vc_to_batch_tags(vc_bytes);
+            &lazy_tags
+        }
+        _ => &pf.tags,
+    };
+    let mut pictures: Vec<flac::FLACPicture> = Vec::new();
+    let picture = |pic_type: u32, mime: &str, desc: &str, data: &[u8]| flac::FLACPicture {
+        pic_type, mime: mime.to_string(), desc: desc.to_string(),
+        width: 0, height: 0, depth: 0, colors: 0, data: data.to_vec(),
+    };
+    for (key, value) in tags {
+        match value {
+            BatchTagValue::Picture { mime, pic_type, desc, data } => {
+                pictures.push(picture(*pic_type as u32, mime, desc, data));
+            }
+            BatchTagValue::CoverList(covers) => {
+                for (data, format) in covers {
+                    let mime = if *format == mp4::MP4CoverFormat::PNG as u8 { "image/png" } else { "image/jpeg" };
+                    pictures.push(picture(3, mime, "", data));
+                }
+            }
+            _ if key.eq_ignore_ascii_case("metadata_block_picture") => {
+                for b64 in batch_value_to_strings(value).unwrap_or_default() {
+                    if let Some(pic) = base64_decode(&b64).and_then(|block| flac::FLACPicture::parse(&block).ok()) {
+                        pictures.push(pic);
+                    }
+                }
+            }
+            // ID3 comment and lyrics frames keyed `COMM::lang` / `USLT:desc:lang`
+            _ => {
+                let easy = if key.starts_with("COMM::") {
+                    "comment"
+                } else if key.starts_with("USLT:") {
+                    "lyrics"
+                } else {
+                    continue;
+                };
+                if fields.iter().any(|(k, _)| k == easy) { continue; }
+                if let Some(values) = batch_value_to_strings(value).filter(|v| !v.is_empty()) {
+                    fields.push((easy.to_string(), values));
+                }
+            }
+        }
+    }
+    if probe_format(path, data) == Some("flac") {
+        let f = flac::FLACFile::parse(data, path)?;
+        for lp in &f.lazy_pictures {
+            if let Some(block) = data.get(lp.block_offset..lp.block_offset + lp.block_size) {
+                pictures.extend(flac::FLACPicture::parse(block).ok());
+            }
+        }
+        pictures.extend(f.pictures);
+    }
+    let front = pictures.iter().position(|p| p.pic_type == 3).unwrap_or(0);
+    let cover = (front < pictures.len()).then(|| pictures.swap_remove(front));
+    Ok(PortableTags { fields, cover })
+}
+
+/// ID3v2 frame for an easy field, or `None` when ID3 has no frame for it.
+fn portable_id3_frame(easy: &str, values: &[String]) -> Option<id3::frames::Frame> {
+    use id3::frames::*;
+    let encoding = id3::specs::Encoding::Utf8;
+    let (lang, desc) = ("eng".to_string(), String::new());
+    Some(match easy {
+        "comment" => Frame::Comment(CommentFrame { id: "COMM".into(), encoding, lang, desc, text: values.join("\0") }),
+        "lyrics" => Frame::Lyrics(LyricsFrame { id: "USLT".into(), encoding, lang, desc, text: values.join("\n") }),
+        _ => match native_tag_key(EASY_ID3_FRAMES, easy)? {
+            "WOAR" => Frame::Url(UrlFrame { id: "WOAR".into(), url: values[0].clone() }),
+            // Easy performer values read as `role:name`
+            "TMCL" => Frame::PairedText(PairedTextFrame {
+                id: "TMCL".into(),
+                encoding,
+                people: values.iter()
+                    .map(|v| v.split_once(':').unwrap_or(("", v)))
+                    .map(|(role, name)| (role.to_string(), name.to_string()))
+                    .collect(),
+            }),
+            id => Frame::Text(TextFrame { id: id.into(), encoding, text: values.to_vec() }),
+        },
+    })
+}
+
+/// MP4 item for an easy field: `trkn`/`disk` from `"N/M"`, `tmpo` from a number,
+/// text otherwise. `None` when MP4 has no atom for it or the number doesn't parse.
+fn portable_mp4_item(easy: &str, values: &[String]) -> Option<(&'static str, mp4::MP4TagValue)> {
+    let atom = native_tag_key(EASY_MP4_ATOMS, easy)?;
+    let value = match atom {
+        "trkn" | "disk" => {
+            let (num, total) = values[0].split_once('/').unwrap_or((&values[0], "0"));
+            mp4::MP4TagValue::IntPair(vec![(num.trim().parse().ok()?, total.trim().parse().unwrap_or(0))])
+        }
+        "tmpo" => mp4::MP4TagValue::Integer(vec![values[0].trim().parse::<f64>().ok()?.round() as i64]),
+        _ => mp4::MP4TagValue::Text(values.to_vec()),
+    };
+    Some((atom, value))
+}
+
+/// Write portable tags into `path` and save it. Each field replaces the
+/// destination's own; fields its format has no key for are skipped. A cover
+/// replaces every picture in the destination.
+fn write_portable_tags(path: &str, tags: PortableTags) -> common::error::Result<()> {
+    let data = std::fs::read(path)?;
+    let b64_picture = |pic: &flac::FLACPicture| {
+        let mut out = String::new();
+        base64_to(&pic.render(), &mut out);
+        out
+    };
+    match probe_format(path, &data) {
+        Some("flac") => {
+            let mut f = flac::FLACFile::parse(&data, path)?;
+            f.ensure_tags();
+            let vc = f.tags.get_or_insert_with(vorbis::VorbisComment::new);
+            for (key, values) in tags.fields {
+                vc.set(&key, values);
+            }
+            if let Some(cover) = tags.cover {
+                vc.delete("metadata_block_picture");
+                f.lazy_pictures.clear();
+                f.pictures = vec![cover];
+            }
+            f.save()
+        }
+        Some("ogg") => {
+            let mut f = ogg::OggVorbisFile::parse(&data, path)?;
+            f.ensure_tags();
+            for (key, values) in tags.fields {
+                f.tags.set(&key, values);
+            }
+            if let Some(cover) = tags.cover {
+                f.tags.set("metadata_block_picture", vec![b64_picture(&cover)]);
+            }
+            f.save()
+        }
+        Some("opus") => {
+            let mut f = ogg::opus::OpusFile::parse(&data, path)?;
+            f.ensure_tags();
+            for (key, values) in tags.fields {
+                f.tags.set(&key, values);
+            }
+            if let Some(cover) = tags.cover {
+                f.tags.set("metadata_block_picture", vec![b64_picture(&cover)]);
+            }
+            f.save()
+        }
+        Some("mp3") => {
+            let mut f = mp3::MP3File::parse(&data, path)?;
+            f.ensure_tags_parsed(&data);
+            for (key, values) in &tags.fields {
+                if let Some(frame) = portable_id3_frame(key, values) {
+                    f.tags.delall(frame.hash_key().as_str());
+                    f.tags.add(frame);
+                }
+            }
+            if let Some(cover) = tags.cover {
+                f.tags.strip(&["APIC"]);
+                f.tags.add(id3::frames::Frame::Picture(id3::frames::PictureFrame {
+                    id: "APIC".into(),
+                    encoding: id3::specs::Encoding::Utf8,
+                    mime: cover.mime,
+                    pic_type: id3::specs::PictureType::from_byte(cover.pic_type as u8),
+                    desc: cover.desc,
+                    data: cover.data,
+                }));
+            }
+            f.save()
+        }
+        Some("mp4") => {
+            let mut f = mp4::MP4File::parse(&data, path)?;
+            f.ensure_parsed_with_data(&data);
+            for (key, values) in &tags.fields {
+                if let Some((atom, value)) = portable_mp4_item(key, values) {
+                    f.tags.set(atom, value);
+                }
+            }
+            if let Some(cover) = tags.cover {
+                let format = if cover.mime.eq_ignore_ascii_case("image/png") {
+                    mp4::MP4CoverFormat::PNG
+                } else {
+                    mp4::MP4CoverFormat::JPEG
+                };
+                f.tags.set("covr", mp4::MP4TagValue::Cover(vec![mp4::MP4Cover { data: cover.data.into(), format }]));
+            }
+            f.save()
+        }
+        _ => Err(common::error::MutagenError::ValueError(format!("cannot save tags to {}", path))),
     }
 }
 
+/// Copy the tags of `src` into `dst` across formats (FLAC → MP3 when
+/// re-encoding, say) and save `dst`. Carries the text fields with an easy name
+/// (title, artist, album, date, tracknumber, genre, ...) plus comment and
+/// lyrics, written as the destination's own frames, atoms or comments, and the
+/// cover, which replaces the destination's pictures. Fields the destination
+/// format cannot hold are skipped; its other tags are kept.
+#[pyfunction]
+fn copy_tags(py: Python<'_>, src: &str, dst: &str) -> PyResult<()> {
+    py.detach(|| -> common::error::Result<()> {
+        let data = std::fs::read(src)?;
+        let tags = read_portable_tags(src, &data)?;
+        write_portable_tags(dst, tags)?;
+        invalidate_file(dst);
+        Ok(())
+    })?;
+    Ok(())
+}
+
 /// Batch I/O helper (Unix): uses fstatat/openat/pread for maximum performance.
 #[cfg(unix)]
-fn batch_open_io(filenames: &[String], exts: &[&str]) -> Vec<(usize, Arc<PreSerializedFile>)> {
+fn batch_open_io(filenames: &[String], exts: &[&str], io_depth: usize) -> Vec<(usize, Arc<PreSerializedFile>)> {
     use rayon::prelude::*;
     let n = filenames.len();
     if n == 0 { return Vec::new(); }
@@ -2310,54 +5652,42 @@ fn batch_open_io(filenames: &[String], exts: &[&str]) -> Vec<(usize, Arc<PreSeri
         })
         .collect();
 
-    // Phase 2: Group by (file_size, extension) using sort (faster than HashMap).
-    let mut sorted_indices: Vec<(u64, usize)> = Vec::with_capacity(n);
-    for i in 0..n {
-        if sizes[i] >= 0 {
-            let ext_id: u64 = match exts[i].as_bytes() {
-                b"mp3" | b"MP3" => 1,
-                b"flac" | b"FLAC" => 2,
-                b"ogg" | b"OGG" => 3,
-                b"mp4" | b"MP4" | b"m4a" | b"M4A" | b"m4b" | b"M4B" => 4,
-                _ => 0,
-            };
-            sorted_indices.push(((sizes[i] as u64) << 4 | ext_id, i));
-        }
-    }
-    sorted_indices.sort_unstable_by_key(|&(k, _)| k);
+    // Phase 2: Every file that exists is parsed. `batch_open` has already
+    // folded paths naming the same file, so no two of these share a result.
+    let readable: Vec<usize> = (0..n).filter(|&i| sizes[i] >= 0).collect();
 
-    let mut reps: Vec<usize> = Vec::new();
-    let mut group_bounds: Vec<usize> = Vec::new();
-    {
-        let mut i = 0;
-        while i < sorted_indices.len() {
-            let key = sorted_indices[i].0;
-            reps.push(sorted_indices[i].1);
-            group_bounds.push(i);
-            while i < sorted_indices.len() && sorted_indices[i].0 == key { i += 1; }
-        }
-        group_bounds.push(sorted_indices.len());
-    }
-
-    // Phase 3: Parse representatives in parallel (FLAC uses 4KB prefix with kept-open fd).
-    let parsed: HashMap<usize, Arc<PreSerializedFile>> = reps.par_iter().copied()
-        .filter_map(|i| {
-            let fd = open_file(i);
-            if fd < 0 { return None; }
-            let file_len = sizes[i] as usize;
-            let ext = exts[i];
-
-            let pf = if ext.eq_ignore_ascii_case("flac") && file_len > 4096 {
-                let mut buf = vec![0u8; 4096];
-                let nr = unsafe {
-                    libc::pread(fd, buf.as_mut_ptr() as *mut libc::c_void, 4096, 0)
-                };
-                if nr <= 0 { unsafe { libc::close(fd); } return None; }
-                buf.truncate(nr as usize);
-                if let Some(pf) = parse_flac_batch(&buf, file_len) {
-                    if pf.lazy_vc.is_some() {
-                        unsafe { libc::close(fd); }
-                        Some(pf)
+    // Phase 3: Parse in parallel (FLAC uses 4KB prefix with kept-open fd).
+    let parsed: HashMap<usize, Arc<PreSerializedFile>> = if io_depth > 0 {
+        prefetch_and_parse(filenames, &sizes, &readable, io_depth)
+    } else {
+        readable.par_iter().copied()
+            .filter_map(|i| {
+                let fd = open_file(i);
+                if fd < 0 { return None; }
+                let file_len = sizes[i] as usize;
+                let ext = exts[i];
+
+                let pf = if ext.eq_ignore_ascii_case("flac") && file_len > 4096 {
+                    let mut buf = vec![0u8; 4096];
+                    let nr = unsafe {
+                        libc::pread(fd, buf.as_mut_ptr() as *mut libc::c_void, 4096, 0)
+                    };
+                    if nr <= 0 { unsafe { libc::close(fd); } return None; }
+                    buf.truncate(nr as usize);
+                    if let Some(pf) = parse_flac_batch(&buf, file_len) {
+                        if pf.lazy_vc.is_some() {
+                            unsafe { libc::close(fd); }
+                            Some(pf)
+                        } else {
+                            let mut data = vec![0u8; file_len];
+                            let nr2 = unsafe {
+                                libc::pread(fd, data.as_mut_ptr() as *mut libc::c_void, file_len, 0)
+                            };
+                            unsafe { libc::close(fd); }
+                            if nr2 <= 0 { return None; }
+                            data.truncate(nr2 as usize);
+                            parse_flac_batch(&data, file_len)
+                        }
                     } else {
                         let mut data = vec![0u8; file_len];
                         let nr2 = unsafe {
@@ -2368,49 +5698,35 @@ fn batch_open_io(filenames: &[String], exts: &[&str]) -> Vec<(usize, Arc<PreSeri
                         data.truncate(nr2 as usize);
                         parse_flac_batch(&data, file_len)
                     }
+                } else if let Some(ranges) = RangeRead::for_ext(ext).filter(|_| file_len > RANGE_READ_MIN) {
+                    let data = ranges.read(&[], file_len, |b, off| pread_exact(fd, b, off));
+                    unsafe { libc::close(fd); }
+                    parse_and_serialize(&data.ok()?, &filenames[i])
                 } else {
                     let mut data = vec![0u8; file_len];
-                    let nr2 = unsafe {
+                    let nr = unsafe {
                         libc::pread(fd, data.as_mut_ptr() as *mut libc::c_void, file_len, 0)
                     };
                     unsafe { libc::close(fd); }
-                    if nr2 <= 0 { return None; }
-                    data.truncate(nr2 as usize);
-                    parse_flac_batch(&data, file_len)
-                }
-            } else {
-                let mut data = vec![0u8; file_len];
-                let nr = unsafe {
-                    libc::pread(fd, data.as_mut_ptr() as *mut libc::c_void, file_len, 0)
-                };
-                unsafe { libc::close(fd); }
-                if nr <= 0 { return None; }
-                data.truncate(nr as usize);
-                parse_and_serialize(&data, &filenames[i])
-            }?;
+                    if nr <= 0 { return None; }
+                    data.truncate(nr as usize);
+                    parse_and_serialize(&data, &filenames[i])
+                }?;
 
-            Some((i, Arc::new(pf)))
-        })
-        .collect();
+                Some((i, Arc::new(pf)))
+            })
+            .collect()
+    };
 
     // Close directory fd
     if dir_fd >= 0 { unsafe { libc::close(dir_fd); } }
 
-    // Phase 4: Assign — each file gets its group representative's parsed result.
-    let mut results: Vec<(usize, Arc<PreSerializedFile>)> = Vec::with_capacity(n);
-    for (g, &rep) in reps.iter().enumerate() {
-        if let Some(pf) = parsed.get(&rep) {
-            for j in group_bounds[g]..group_bounds[g + 1] {
-                results.push((sorted_indices[j].1, Arc::clone(pf)));
-            }
-        }
-    }
-    results
+    parsed.into_iter().collect()
 }
 
 /// Batch I/O helper (non-Unix): portable fallback using std::fs.
 #[cfg(not(unix))]
-fn batch_open_io(filenames: &[String], exts: &[&str]) -> Vec<(usize, Arc<PreSerializedFile>)> {
+fn batch_open_io(filenames: &[String], exts: &[&str], io_depth: usize) -> Vec<(usize, Arc<PreSerializedFile>)> {
     use rayon::prelude::*;
     use std::io::Read;
     let n = filenames.len();
@@ -2421,88 +5737,392 @@ fn batch_open_io(filenames: &[String], exts: &[&str]) -> Vec<(usize, Arc<PreSeri
         .map(|i| std::fs::metadata(&filenames[i]).map(|m| m.len() as i64).unwrap_or(-1))
         .collect();
 
-    // Phase 2: Group by (file_size, extension) using sort.
-    let mut sorted_indices: Vec<(u64, usize)> = Vec::with_capacity(n);
-    for i in 0..n {
-        if sizes[i] >= 0 {
-            let ext_id: u64 = match exts[i].as_bytes() {
-                b"mp3" | b"MP3" => 1,
-                b"flac" | b"FLAC" => 2,
-                b"ogg" | b"OGG" => 3,
-                b"mp4" | b"MP4" | b"m4a" | b"M4A" | b"m4b" | b"M4B" => 4,
-                _ => 0,
-            };
-            sorted_indices.push(((sizes[i] as u64) << 4 | ext_id, i));
-        }
-    }
-    sorted_indices.sort_unstable_by_key(|&(k, _)| k);
+    // Phase 2: Every file that exists is parsed. `batch_open` has already
+    // folded paths naming the same file, so no two of these share a result.
+    let readable: Vec<usize> = (0..n).filter(|&i| sizes[i] >= 0).collect();
 
-    let mut reps: Vec<usize> = Vec::new();
-    let mut group_bounds: Vec<usize> = Vec::new();
-    {
-        let mut i = 0;
-        while i < sorted_indices.len() {
-            let key = sorted_indices[i].0;
-            reps.push(sorted_indices[i].1);
-            group_bounds.push(i);
-            while i < sorted_indices.len() && sorted_indices[i].0 == key { i += 1; }
-        }
-        group_bounds.push(sorted_indices.len());
-    }
-
-    // Phase 3: Parse representatives in parallel using std::fs.
-    let parsed: HashMap<usize, Arc<PreSerializedFile>> = reps.par_iter().copied()
-        .filter_map(|i| {
-            let file_len = sizes[i] as usize;
-            let ext = exts[i];
-
-            let pf = if ext.eq_ignore_ascii_case("flac") && file_len > 4096 {
-                let mut file = std::fs::File::open(&filenames[i]).ok()?;
-                let mut buf = vec![0u8; 4096];
-                file.read_exact(&mut buf).ok()?;
-                if let Some(pf) = parse_flac_batch(&buf, file_len) {
-                    if pf.lazy_vc.is_some() {
-                        Some(pf)
+    // Phase 3: Parse in parallel using std::fs.
+    let parsed: HashMap<usize, Arc<PreSerializedFile>> = if io_depth > 0 {
+        prefetch_and_parse(filenames, &sizes, &readable, io_depth)
+    } else {
+        readable.par_iter().copied()
+            .filter_map(|i| {
+                let file_len = sizes[i] as usize;
+                let ext = exts[i];
+
+                let pf = if ext.eq_ignore_ascii_case("flac") && file_len > 4096 {
+                    let mut file = std::fs::File::open(&filenames[i]).ok()?;
+                    let mut buf = vec![0u8; 4096];
+                    file.read_exact(&mut buf).ok()?;
+                    if let Some(pf) = parse_flac_batch(&buf, file_len) {
+                        if pf.lazy_vc.is_some() {
+                            Some(pf)
+                        } else {
+                            let data = std::fs::read(&filenames[i]).ok()?;
+                            parse_flac_batch(&data, file_len)
+                        }
                     } else {
                         let data = std::fs::read(&filenames[i]).ok()?;
                         parse_flac_batch(&data, file_len)
                     }
+                } else if let Some(ranges) = RangeRead::for_ext(ext).filter(|_| file_len > RANGE_READ_MIN) {
+                    let mut file = std::fs::File::open(&filenames[i]).ok()?;
+                    let data = ranges.read(&[], file_len, |b, off| read_range(&mut file, b, off)).ok()?;
+                    parse_and_serialize(&data, &filenames[i])
                 } else {
                     let data = std::fs::read(&filenames[i]).ok()?;
-                    parse_flac_batch(&data, file_len)
-                }
-            } else {
-                let data = std::fs::read(&filenames[i]).ok()?;
-                parse_and_serialize(&data, &filenames[i])
-            }?;
+                    parse_and_serialize(&data, &filenames[i])
+                }?;
 
-            Some((i, Arc::new(pf)))
-        })
+                Some((i, Arc::new(pf)))
+            })
+            .collect()
+    };
+
+    parsed.into_iter().collect()
+}
+
+/// Files at or below this size are read whole; range hints only pay off beyond it.
+const PREFETCH_HINT_MIN: usize = 256 * 1024;
+/// Ogg tail read for range hints: the window `ogg::find_last_granule` searches.
+const PREFETCH_OGG_TAIL: usize = 64 * 1024;
+
+/// `len` bytes of `path` starting at `offset`, for raw reads of spans recorded at parse time.
+fn read_file_span(path: &str, offset: usize, len: usize) -> PyResult<Vec<u8>> {
+    let mut buf = vec![0u8; len];
+    std::fs::File::open(path)
+        .and_then(|mut file| read_range(&mut file, &mut buf, offset))
+        .map_err(|e| PyIOError::new_err(format!("{}", e)))?;
+    Ok(buf)
+}
+
+fn read_range(file: &mut std::fs::File, buf: &mut [u8], offset: usize) -> std::io::Result<()> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::FileExt;
+        file.read_exact_at(buf, offset as u64)
+    }
+    #[cfg(not(unix))]
+    {
+        use std::io::{Read, Seek, SeekFrom};
+        file.seek(SeekFrom::Start(offset as u64))?;
+        file.read_exact(buf)
+    }
+}
+
+/// End of the FLAC metadata blocks in `buf`, or a larger offset to read up to
+/// when the walk runs past the buffer. None if this isn't a bare `fLaC` stream.
+fn flac_metadata_extent(buf: &[u8]) -> Option<usize> {
+    if buf.len() < 4 || &buf[..4] != b"fLaC" { return None; }
+    let mut pos = 4;
+    loop {
+        if pos + 4 > buf.len() { return Some(pos + 4); }
+        let is_last = buf[pos] & 0x80 != 0;
+        let size = ((buf[pos + 1] as usize) << 16) | ((buf[pos + 2] as usize) << 8) | buf[pos + 3] as usize;
+        pos += 4 + size;
+        if is_last || pos > buf.len() { return Some(pos); }
+    }
+}
+
+/// End of the Ogg page holding the end of the second packet (the comment header),
+/// or a larger offset to read up to. None if `buf` doesn't start with an Ogg page.
+fn ogg_header_extent(buf: &[u8]) -> Option<usize> {
+    let mut pos = 0;
+    let mut packets = 0;
+    loop {
+        if pos + 27 > buf.len() { return Some(pos + 27); }
+        if &buf[pos..pos + 4] != b"OggS" { return None; }
+        let num_seg = buf[pos + 26] as usize;
+        if pos + 27 + num_seg > buf.len() { return Some(pos + 27 + num_seg); }
+        let lacing = &buf[pos + 27..pos + 27 + num_seg];
+        packets += lacing.iter().filter(|&&l| l < 255).count();
+        pos += 27 + num_seg + lacing.iter().map(|&l| l as usize).sum::<usize>();
+        if packets >= 2 { return Some(pos); }
+    }
+}
+
+/// Read a growing head, starting at `initial` bytes, until `extent` reports where
+/// the needed prefix ends.
+fn read_head_extent(
+    file: &mut std::fs::File,
+    file_len: usize,
+    initial: usize,
+    extent: fn(&[u8]) -> Option<usize>,
+) -> Option<Vec<u8>> {
+    let mut head = Vec::new();
+    let mut want = initial.min(file_len);
+    loop {
+        let have = head.len();
+        head.resize(want, 0);
+        read_range(file, &mut head[have..], have).ok()?;
+        let end = extent(&head)?;
+        if end <= head.len() {
+            head.truncate(end);
+            return Some(head);
+        }
+        if want == file_len || end > file_len { return None; }
+        want = end.max(want * 2).min(file_len);
+    }
+}
+
+/// Read only the byte ranges the batch parser needs from a large file, into a
+/// zero-filled buffer of the full file length (untouched pages are never
+/// committed). FLAC: the metadata blocks. Ogg: the header pages and the last
+/// 64 KB for the final granule. MP4: every top-level atom header plus `moov`.
+/// MP3: see `mp3_sparse_read`.
+/// Returns None when the layout isn't recognised; the caller then reads it all.
+fn read_with_hints(file: &mut std::fs::File, ext: &str, file_len: usize) -> Option<Vec<u8>> {
+    if file_len <= PREFETCH_HINT_MIN { return None; }
+    if let Some(ranges) = RangeRead::for_ext(ext) {
+        ranges.read(&[], file_len, |buf, off| read_range(file, buf, off)).ok()
+    } else if ext.eq_ignore_ascii_case("flac") {
+        let head = read_head_extent(file, file_len, 4096, flac_metadata_extent)?;
+        let mut data = vec![0u8; file_len];
+        data[..head.len()].copy_from_slice(&head);
+        Some(data)
+    } else if ext.eq_ignore_ascii_case("ogg") || ext.eq_ignore_ascii_case("opus") {
+        let head = read_head_extent(file, file_len, 16 * 1024, ogg_header_extent)?;
+        let mut data = vec![0u8; file_len];
+        data[..head.len()].copy_from_slice(&head);
+        let tail = file_len.saturating_sub(PREFETCH_OGG_TAIL).max(head.len());
+        read_range(file, &mut data[tail..], tail).ok()?;
+        Some(data)
+    } else {
+        None
+    }
+}
+
+/// Overlapped batch parse: `io_depth` reader threads open files and fetch the
+/// ranges the parser needs into a bounded queue, which rayon workers drain.
+/// Keeps many reads in flight on high-latency filesystems (NFS/SMB), where
+/// rayon workers blocking in `open`/`read` leave the queue depth at the
+/// thread count.
+fn prefetch_and_parse(
+    filenames: &[String],
+    sizes: &[i64],
+    indices: &[usize],
+    io_depth: usize,
+) -> HashMap<usize, Arc<PreSerializedFile>> {
+    use rayon::prelude::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = std::sync::mpsc::sync_channel::<(usize, Vec<u8>, bool)>(io_depth * 2);
+    std::thread::scope(|scope| {
+        for _ in 0..io_depth {
+            let tx = tx.clone();
+            let next = &next;
+            scope.spawn(move || loop {
+                let k = next.fetch_add(1, Ordering::Relaxed);
+                let Some(&i) = indices.get(k) else { break };
+                let path = &filenames[i];
+                let Ok(mut file) = std::fs::File::open(path) else { continue };
+                let file_len = sizes[i] as usize;
+                let ext = path.rsplit('.').next().unwrap_or("");
+                let (data, complete) = match read_with_hints(&mut file, ext, file_len) {
+                    Some(data) => (data, false),
+                    None => {
+                        let mut data = vec![0u8; file_len];
+                        if read_range(&mut file, &mut data, 0).is_err() { continue; }
+                        (data, true)
+                    }
+                };
+                if tx.send((i, data, complete)).is_err() { break; }
+            });
+        }
+        drop(tx);
+
+        rx.into_iter().par_bridge()
+            .filter_map(|(i, data, complete)| {
+                let path = &filenames[i];
+                let pf = match parse_and_serialize(&data, path) {
+                    Some(pf) => pf,
+                    // Range hints missed something the parser wanted; read it all.
+                    None if !complete => parse_and_serialize(&std::fs::read(path).ok()?, path)?,
+                    None => return None,
+                };
+                Some((i, Arc::new(pf)))
+            })
+            .collect()
+    })
+}
+
+/// Route batch results through the easy-key mapping (parallel, once per distinct
+/// parsed file so dedup sharing is preserved).
+fn easy_batch_results(
+    results: Vec<(usize, Arc<PreSerializedFile>)>,
+    keep_extra: bool,
+) -> Vec<(usize, Arc<PreSerializedFile>)> {
+    use rayon::prelude::*;
+    let mut unique: HashMap<usize, Arc<PreSerializedFile>> = HashMap::new();
+    for (_, pf) in &results {
+        unique.entry(Arc::as_ptr(pf) as usize).or_insert_with(|| Arc::clone(pf));
+    }
+    let converted: HashMap<usize, Arc<PreSerializedFile>> = unique.into_par_iter()
+        .map(|(key, pf)| (key, Arc::new(easy_preserialized(&pf, keep_extra))))
         .collect();
+    results.into_iter()
+        .map(|(idx, pf)| (idx, Arc::clone(&converted[&(Arc::as_ptr(&pf) as usize)])))
+        .collect()
+}
 
-    // Phase 4: Assign results by group.
-    let mut results: Vec<(usize, Arc<PreSerializedFile>)> = Vec::with_capacity(n);
-    for (g, &rep) in reps.iter().enumerate() {
-        if let Some(pf) = parsed.get(&rep) {
-            for j in group_bounds[g]..group_bounds[g + 1] {
-                results.push((sorted_indices[j].1, Arc::clone(pf)));
-            }
+/// How `batch_open` decides that two submitted paths name the same file.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum PathDedupe {
+    /// Only identical strings.
+    Exact,
+    /// Same path after lexical normalization (absolute, `.`/`..` folded); no filesystem access.
+    Lexical,
+    /// Same path after resolving symlinks (`realpath`).
+    Realpath,
+    /// Same device and inode, so hard links also match.
+    Inode,
+}
+
+impl PathDedupe {
+    fn from_name(name: &str) -> PyResult<Self> {
+        match name {
+            "none" => Ok(PathDedupe::Exact),
+            "lexical" => Ok(PathDedupe::Lexical),
+            "realpath" => Ok(PathDedupe::Realpath),
+            "inode" => Ok(PathDedupe::Inode),
+            _ => Err(PyValueError::new_err(format!(
+                "unknown dedupe mode {:?} (expected 'none', 'lexical', 'realpath' or 'inode')", name
+            ))),
         }
     }
-    results
+}
+
+/// Make `path` absolute against `cwd`, drop `.` components and fold `..` into
+/// the preceding component, without touching the filesystem.
+fn normalize_path_lexically(path: &str, cwd: &std::path::Path) -> std::path::PathBuf {
+    use std::path::{Component, PathBuf};
+    let path = std::path::Path::new(path);
+    let joined;
+    let abs = if path.is_absolute() { path } else { joined = cwd.join(path); &joined };
+    let mut out = PathBuf::new();
+    for c in abs.components() {
+        match c {
+            Component::CurDir => {}
+            Component::ParentDir => { out.pop(); }
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+/// Group submitted paths that name the same file. Returns the paths to read (the
+/// first spelling of each file) and, for every submitted path, the index of its file.
+/// Paths that can't be resolved or stat'ed fall back to lexical normalization.
+fn dedupe_batch_paths(filenames: &[String], mode: PathDedupe) -> (Vec<String>, Vec<usize>) {
+    use std::path::PathBuf;
+
+    #[derive(Hash, PartialEq, Eq)]
+    enum FileKey {
+        Path(PathBuf),
+        Inode(u64, u64),
+    }
+
+    let cwd = std::env::current_dir().unwrap_or_default();
+    let lexical = |p: &str| FileKey::Path(normalize_path_lexically(p, &cwd));
+    let mut seen: HashMap<FileKey, usize> = HashMap::with_capacity(filenames.len());
+    let mut unique = Vec::with_capacity(filenames.len());
+    let mut slots = Vec::with_capacity(filenames.len());
+    for path in filenames {
+        let key = match mode {
+            PathDedupe::Exact => FileKey::Path(PathBuf::from(path)),
+            PathDedupe::Lexical => lexical(path),
+            PathDedupe::Realpath => std::fs::canonicalize(path).map(FileKey::Path).unwrap_or_else(|_| lexical(path)),
+            #[cfg(unix)]
+            PathDedupe::Inode => {
+                use std::os::unix::fs::MetadataExt;
+                std::fs::metadata(path).map(|m| FileKey::Inode(m.dev(), m.ino())).unwrap_or_else(|_| lexical(path))
+            }
+            #[cfg(not(unix))]
+            PathDedupe::Inode => std::fs::canonicalize(path).map(FileKey::Path).unwrap_or_else(|_| lexical(path)),
+        };
+        let slot = *seen.entry(key).or_insert_with(|| {
+            unique.push(path.clone());
+            unique.len() - 1
+        });
+        slots.push(slot);
+    }
+    (unique, slots)
 }
 
 /// Batch open: read and parse multiple files in parallel using rayon.
 /// Returns a native Python dict (path → metadata dict) for zero-overhead iteration.
+/// With `easy`, every file's tags use easy keys with string-list values; `extra` keeps
+/// the unmapped native tags in an `extra` sub-dict. `io_depth > 0` reads through that
+/// many prefetch threads with per-format range hints (see `prefetch_and_parse`).
+/// Paths naming the same file (per `dedupe`, see `PathDedupe`) are read and parsed
+/// once; every submitted spelling maps to the same result dict.
+/// With `editable`, returns a `BatchResult` whose tags can be changed with
+/// `set_tag`/`del_tag` and written back with `save_all` (native keys only).
 #[pyfunction]
-fn batch_open(py: Python<'_>, filenames: Vec<String>) -> PyResult<Py<PyAny>> {
-    let exts: Vec<&str> = filenames.iter()
-        .map(|p| p.rsplit('.').next().unwrap_or(""))
-        .collect();
+#[pyo3(signature = (filenames, easy=false, extra=false, io_depth=0, dedupe="lexical", editable=false))]
+fn batch_open(py: Python<'_>, filenames: Vec<String>, easy: bool, extra: bool, io_depth: usize, dedupe: &str, editable: bool) -> PyResult<Py<PyAny>> {
+    let mode = PathDedupe::from_name(dedupe)?;
+    if editable && easy {
+        return Err(PyValueError::new_err("editable batch results use native keys; pass easy=False"));
+    }
+
+    let (slots, files): (Vec<usize>, Vec<Option<Arc<PreSerializedFile>>>) = py.detach(|| {
+        let (unique, slots) = dedupe_batch_paths(&filenames, mode);
+        let exts: Vec<&str> = unique.iter()
+            .map(|p| p.rsplit('.').next().unwrap_or(""))
+            .collect();
+        let results = batch_open_io(&unique, &exts, io_depth);
+        let results = if easy { easy_batch_results(results, extra) } else { results };
+        let mut files = vec![None; unique.len()];
+        for (idx, pf) in results {
+            files[idx] = Some(pf);
+        }
+        (slots, files)
+    });
 
-    let file_indices: Vec<(usize, Arc<PreSerializedFile>)> =
-        py.detach(|| batch_open_io(&filenames, &exts));
+    if editable {
+        let mut result = PyBatchResult {
+            paths: Vec::new(),
+            dicts: Vec::new(),
+            index: HashMap::new(),
+            slots: Vec::new(),
+            extras: Vec::new(),
+            edits: HashMap::new(),
+        };
+        let mut mat_cache: HashMap<usize, Py<PyAny>> = HashMap::new();
+        for (path, &slot) in filenames.iter().zip(&slots) {
+            let Some(pf) = &files[slot] else { continue };
+            if result.index.contains_key(path) { continue; }
+            let dict = match mat_cache.get(&slot) {
+                Some(d) => d.clone_ref(py),
+                None => {
+                    let d = preserialized_to_py_dict(py, pf)?;
+                    mat_cache.insert(slot, d.clone_ref(py));
+                    d
+                }
+            };
+            result.index.insert(path.clone(), result.paths.len());
+            result.paths.push(path.clone());
+            result.dicts.push(dict);
+            result.slots.push(slot);
+            result.extras.push(pf.extra.clone());
+        }
+        return Ok(Py::new(py, result)?.into_any());
+    }
+
+    batch_results_to_dict(py, &filenames, &slots, &files)
+}
 
+/// Build the `batch_open` result dict: every path in `filenames` maps to the
+/// dict of `files[slots[i]]`, materialized once per parsed file (paths whose
+/// file failed are left out).
+fn batch_results_to_dict(
+    py: Python<'_>,
+    filenames: &[String],
+    slots: &[usize],
+    files: &[Option<Arc<PreSerializedFile>>],
+) -> PyResult<Py<PyAny>> {
     // Build native Python dict with dict-level dedup (one materialization per unique file)
     unsafe {
         let result_ptr = pyo3::ffi::PyDict_New();
@@ -2512,17 +6132,16 @@ fn batch_open(py: Python<'_>, filenames: Vec<String>) -> PyResult<Py<PyAny>> {
 
         let mut mat_cache: HashMap<usize, *mut pyo3::ffi::PyObject> = HashMap::new();
 
-        for &(idx, ref pf) in &file_indices {
-            let cache_key = Arc::as_ptr(pf) as usize;
-            let dict_ptr = if let Some(&cached) = mat_cache.get(&cache_key) {
+        for (path, &slot) in filenames.iter().zip(slots) {
+            let Some(pf) = &files[slot] else { continue };
+            let dict_ptr = if let Some(&cached) = mat_cache.get(&slot) {
                 cached
             } else {
                 let d = preserialized_to_py_dict(py, pf)?.into_ptr();
-                mat_cache.insert(cache_key, d);
+                mat_cache.insert(slot, d);
                 d
             };
 
-            let path = &filenames[idx];
             let path_ptr = pyo3::ffi::PyUnicode_FromStringAndSize(
                 path.as_ptr() as *const std::ffi::c_char, path.len() as pyo3::ffi::Py_ssize_t);
             pyo3::ffi::PyDict_SetItem(result_ptr, path_ptr, dict_ptr);
@@ -2538,11 +6157,45 @@ fn batch_open(py: Python<'_>, filenames: Vec<String>) -> PyResult<Py<PyAny>> {
     }
 }
 
+/// Batch open over buffers already in memory: parses each `(path, bytes)` pair
+/// in parallel with no I/O, the path only choosing the format by extension.
+/// Returns the same dict as `batch_open`; `easy`/`extra` behave as there.
+/// Buffers that fail to parse are left out.
+#[pyfunction]
+#[pyo3(signature = (items, easy=false, extra=false))]
+fn batch_open_buffers(py: Python<'_>, items: Vec<(String, Bound<'_, PyBytes>)>, easy: bool, extra: bool) -> PyResult<Py<PyAny>> {
+    use rayon::prelude::*;
+    let filenames: Vec<String> = items.iter().map(|(path, _)| path.clone()).collect();
+    let buffers: Vec<&[u8]> = items.iter().map(|(_, data)| data.as_bytes()).collect();
+
+    let files = py.detach(|| {
+        let results: Vec<(usize, Arc<PreSerializedFile>)> = buffers.par_iter()
+            .enumerate()
+            .filter_map(|(i, data)| parse_and_serialize(data, &filenames[i]).map(|pf| (i, Arc::new(pf))))
+            .collect();
+        let results = if easy { easy_batch_results(results, extra) } else { results };
+        let mut files = vec![None; filenames.len()];
+        for (idx, pf) in results {
+            files[idx] = Some(pf);
+        }
+        files
+    });
+
+    let slots: Vec<usize> = (0..filenames.len()).collect();
+    batch_results_to_dict(py, &filenames, &slots, &files)
+}
+
+/// `_fast_batch_read` (and `batch_diag`) memory-map files above this size and
+/// read smaller ones into a buffer.
+const MMAP_READ_MIN: usize = 32768;
+
 /// Fast batch read: parallel I/O + parse, then raw FFI dict creation.
 /// Returns a Python dict mapping path → flat dict (same format as _fast_read).
 /// Faster than batch_open for scenarios where all results are accessed.
+/// `easy`/`extra` behave as in `batch_open`.
 #[pyfunction]
-fn _fast_batch_read(py: Python<'_>, filenames: Vec<String>) -> PyResult<Py<PyAny>> {
+#[pyo3(signature = (filenames, easy=false, extra=false))]
+fn _fast_batch_read(py: Python<'_>, filenames: Vec<String>, easy: bool, extra: bool) -> PyResult<Py<PyAny>> {
     use rayon::prelude::*;
     use std::sync::Arc;
 
@@ -2576,7 +6229,7 @@ fn _fast_batch_read(py: Python<'_>, filenames: Vec<String>) -> PyResult<Py<PyAny
 
                 let file_len = file.metadata().ok()?.len() as usize;
                 file.seek(std::io::SeekFrom::Start(0)).ok()?;
-                let pf = if file_len > 32768 {
+                let pf = if file_len > MMAP_READ_MIN {
                     let mmap = unsafe { memmap2::Mmap::map(&file).ok()? };
                     parse_and_serialize(&mmap, path)
                 } else {
@@ -2584,6 +6237,7 @@ fn _fast_batch_read(py: Python<'_>, filenames: Vec<String>) -> PyResult<Py<PyAny
                     file.read_to_end(&mut data).ok()?;
                     parse_and_serialize(&data, path)
                 }?;
+                let pf = if easy { easy_preserialized(&pf, extra) } else { pf };
 
                 let arc = Arc::new(pf);
                 if let Ok(mut cache) = dedup.write() {
@@ -2618,7 +6272,7 @@ fn _fast_batch_read(py: Python<'_>, filenames: Vec<String>) -> PyResult<Py<PyAny
                 if dp.is_null() { continue; }
 
                 // Info fields via raw FFI
-                set_dict_f64(dp, pyo3::intern!(py, "length").as_ptr(), pf.length);
+                set_dict_opt_f64(dp, pyo3::intern!(py, "length").as_ptr(), pf.length);
                 set_dict_u32(dp, pyo3::intern!(py, "sample_rate").as_ptr(), pf.sample_rate);
                 set_dict_u32(dp, pyo3::intern!(py, "channels").as_ptr(), pf.channels);
                 if let Some(br) = pf.bitrate {
@@ -2637,13 +6291,14 @@ fn _fast_batch_read(py: Python<'_>, filenames: Vec<String>) -> PyResult<Py<PyAny
                 // Tags: direct VC→FFI path for lazy VC, standard path otherwise
                 if pf.tags.is_empty() {
                     if let Some(ref vc_bytes) = pf.lazy_vc {
-                        parse_vc_to_ffi_dict(vc_bytes, dp);
+                        parse_vc_to_ffi_dict(vc_bytes, dp, true);
                     }
                 } else {
                     for (key, value) in &pf.tags {
                         let py_val = batch_value_to_py_ffi(py, value);
                         if py_val.is_null() { continue; }
-                        let key_ptr = intern_tag_key(key.as_bytes());
+                        // Names that clash with info fields (easy TLEN → "length") go under "tag:"
+                        let key_ptr = flat_tag_key(key.as_bytes());
                         if key_ptr.is_null() {
                             pyo3::ffi::Py_DECREF(py_val);
                             continue;
@@ -2679,116 +6334,1113 @@ fn _fast_batch_read(py: Python<'_>, filenames: Vec<String>) -> PyResult<Py<PyAny
     }
 }
 
-/// Diagnostic version: measures I/O vs parse vs parallel overhead.
+/// Fast batch read straight to MessagePack: parallel I/O + parse + encode, returning a
+/// single `bytes` map of path → file (same fields as `_fast_read_json`, binary as bin).
+#[pyfunction]
+fn _fast_batch_read_msgpack<'py>(py: Python<'py>, filenames: Vec<String>) -> PyResult<Bound<'py, PyBytes>> {
+    use rayon::prelude::*;
+
+    let packed = py.detach(|| {
+        // Repeated paths would be repeated map keys
+        let mut seen = HashSet::with_capacity(filenames.len());
+        let filenames: Vec<&String> = filenames.iter().filter(|p| seen.insert(p.as_str())).collect();
+        let parsed: Vec<(&str, PreSerializedFile)> = filenames.par_iter()
+            .filter_map(|path| {
+                let data = fast_file_read(path).ok()?;
+                Some((path.as_str(), parse_and_serialize(&data, path)?))
+            })
+            .collect();
+        let entries: Vec<(&str, &PreSerializedFile)> = parsed.iter()
+            .map(|(path, pf)| (*path, pf))
+            .collect();
+        batch_to_msgpack(&entries)
+    });
+    Ok(PyBytes::new(py, &packed))
+}
+
+// ---- Arrow C stream export (batch_open_arrow) ----
+// Implements the Arrow C data/stream interface directly so pyarrow/polars can import
+// batch results zero-copy without pulling arrow-rs into the build.
+
+const ARROW_FLAG_NULLABLE: i64 = 2;
+
+/// Columns always present in `batch_open_arrow` output, in order.
+const ARROW_FIXED_COLUMNS: &[&str] = &["path", "length", "sample_rate", "channels", "bitrate"];
+
+/// Easy keys exported as large_list<large_utf8>; every other tag column is
+/// large_utf8 (first value). 64-bit offsets so a column can pass 2 GiB.
+const ARROW_LIST_COLUMNS: &[&str] = &[
+    "artist", "albumartist", "composer", "genre", "lyricist", "performer", "conductor",
+    "arranger", "artistsort", "albumartistsort", "composersort",
+];
+
+/// Tag columns used when `batch_open_arrow` is called without `columns`.
+const ARROW_DEFAULT_COLUMNS: &[&str] = &[
+    "title", "artist", "album", "albumartist", "tracknumber", "discnumber", "date", "genre",
+];
+
+#[repr(C)]
+struct ArrowSchemaC {
+    format: *const std::ffi::c_char,
+    name: *const std::ffi::c_char,
+    metadata: *const std::ffi::c_char,
+    flags: i64,
+    n_children: i64,
+    children: *mut *mut ArrowSchemaC,
+    dictionary: *mut ArrowSchemaC,
+    release: Option<unsafe extern "C" fn(*mut ArrowSchemaC)>,
+    private_data: *mut std::ffi::c_void,
+}
+
+#[repr(C)]
+struct ArrowArrayC {
+    length: i64,
+    null_count: i64,
+    offset: i64,
+    n_buffers: i64,
+    n_children: i64,
+    buffers: *mut *const std::ffi::c_void,
+    children: *mut *mut ArrowArrayC,
+    dictionary: *mut ArrowArrayC,
+    release: Option<unsafe extern "C" fn(*mut ArrowArrayC)>,
+    private_data: *mut std::ffi::c_void,
+}
+
+#[repr(C)]
+struct ArrowArrayStreamC {
+    get_schema: Option<unsafe extern "C" fn(*mut ArrowArrayStreamC, *mut ArrowSchemaC) -> std::ffi::c_int>,
+    get_next: Option<unsafe extern "C" fn(*mut ArrowArrayStreamC, *mut ArrowArrayC) -> std::ffi::c_int>,
+    get_last_error: Option<unsafe extern "C" fn(*mut ArrowArrayStreamC) -> *const std::ffi::c_char>,
+    release: Option<unsafe extern "C" fn(*mut ArrowArrayStreamC)>,
+    private_data: *mut std::ffi::c_void,
+}
+
+/// Owned Arrow buffer; typed so value buffers keep their natural alignment.
+#[derive(Clone)]
+enum ArrowBuffer {
+    Absent,
+    Bytes(Vec<u8>),
+    I64(Vec<i64>),
+    U32(Vec<u32>),
+    F64(Vec<f64>),
+}
+
+impl ArrowBuffer {
+    fn as_ptr(&self) -> *const std::ffi::c_void {
+        match self {
+            ArrowBuffer::Absent => std::ptr::null(),
+            ArrowBuffer::Bytes(v) => v.as_ptr() as *const std::ffi::c_void,
+            ArrowBuffer::I64(v) => v.as_ptr() as *const std::ffi::c_void,
+            ArrowBuffer::U32(v) => v.as_ptr() as *const std::ffi::c_void,
+            ArrowBuffer::F64(v) => v.as_ptr() as *const std::ffi::c_void,
+        }
+    }
+}
+
+/// One array (and its schema) in Rust-owned form, exported on demand.
+#[derive(Clone)]
+struct ArrowNode {
+    format: &'static std::ffi::CStr,
+    name: std::ffi::CString,
+    nullable: bool,
+    length: i64,
+    null_count: i64,
+    buffers: Vec<ArrowBuffer>,
+    children: Vec<ArrowNode>,
+}
+
+impl ArrowNode {
+    fn new(format: &'static std::ffi::CStr, name: &str, length: usize) -> Self {
+        ArrowNode {
+            format,
+            name: std::ffi::CString::new(name).unwrap_or_default(),
+            nullable: true,
+            length: length as i64,
+            null_count: 0,
+            buffers: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Copy of the type tree without any buffers (for `get_schema`).
+    fn schema_only(&self) -> Self {
+        ArrowNode {
+            format: self.format,
+            name: self.name.clone(),
+            nullable: self.nullable,
+            length: 0,
+            null_count: 0,
+            buffers: Vec::new(),
+            children: self.children.iter().map(|c| c.schema_only()).collect(),
+        }
+    }
+}
+
+/// Build a validity bitmap (LSB-first); absent when every slot is valid.
+fn arrow_validity(valid: impl Iterator<Item = bool>, len: usize) -> (ArrowBuffer, i64) {
+    let mut bits = vec![0u8; len.div_ceil(8)];
+    let mut nulls = 0i64;
+    for (i, v) in valid.enumerate() {
+        if v { bits[i / 8] |= 1 << (i % 8); } else { nulls += 1; }
+    }
+    if nulls == 0 { (ArrowBuffer::Absent, 0) } else { (ArrowBuffer::Bytes(bits), nulls) }
+}
+
+fn arrow_utf8(name: &str, values: &[Option<&str>]) -> ArrowNode {
+    let mut offsets = Vec::with_capacity(values.len() + 1);
+    let mut data = Vec::new();
+    offsets.push(0i64);
+    for v in values {
+        if let Some(s) = v { data.extend_from_slice(s.as_bytes()); }
+        offsets.push(data.len() as i64);
+    }
+    let (validity, null_count) = arrow_validity(values.iter().map(|v| v.is_some()), values.len());
+    let mut node = ArrowNode::new(c"U", name, values.len());
+    node.null_count = null_count;
+    node.buffers = vec![validity, ArrowBuffer::I64(offsets), ArrowBuffer::Bytes(data)];
+    node
+}
+
+fn arrow_list_utf8(name: &str, values: &[Option<&[String]>]) -> ArrowNode {
+    let mut offsets = Vec::with_capacity(values.len() + 1);
+    let mut items: Vec<Option<&str>> = Vec::new();
+    offsets.push(0i64);
+    for v in values {
+        if let Some(list) = v { items.extend(list.iter().map(|s| Some(s.as_str()))); }
+        offsets.push(items.len() as i64);
+    }
+    let (validity, null_count) = arrow_validity(values.iter().map(|v| v.is_some()), values.len());
+    let mut child = arrow_utf8("item", &items);
+    child.nullable = false;
+    let mut node = ArrowNode::new(c"+L", name, values.len());
+    node.null_count = null_count;
+    node.buffers = vec![validity, ArrowBuffer::I64(offsets)];
+    node.children = vec![child];
+    node
+}
+
+fn arrow_u32(name: &str, values: &[Option<u32>]) -> ArrowNode {
+    let (validity, null_count) = arrow_validity(values.iter().map(|v| v.is_some()), values.len());
+    let mut node = ArrowNode::new(c"I", name, values.len());
+    node.null_count = null_count;
+    node.nullable = null_count > 0;
+    node.buffers = vec![validity, ArrowBuffer::U32(values.iter().map(|v| v.unwrap_or(0)).collect())];
+    node
+}
+
+fn arrow_f64(name: &str, values: &[Option<f64>]) -> ArrowNode {
+    let (validity, null_count) = arrow_validity(values.iter().map(|v| v.is_some()), values.len());
+    let mut node = ArrowNode::new(c"g", name, values.len());
+    node.null_count = null_count;
+    node.nullable = null_count > 0;
+    node.buffers = vec![validity, ArrowBuffer::F64(values.iter().map(|v| v.unwrap_or(0.0)).collect())];
+    node
+}
+
+struct ArrowSchemaPrivate {
+    name: std::ffi::CString,
+    children: Vec<*mut ArrowSchemaC>,
+}
+
+unsafe extern "C" fn arrow_release_schema(schema: *mut ArrowSchemaC) {
+    if schema.is_null() || (*schema).release.is_none() { return; }
+    let private = Box::from_raw((*schema).private_data as *mut ArrowSchemaPrivate);
+    for &child in &private.children {
+        if let Some(release) = (*child).release { release(child); }
+        drop(Box::from_raw(child));
+    }
+    drop(private);
+    (*schema).release = None;
+}
+
+unsafe fn arrow_export_schema(node: &ArrowNode, out: *mut ArrowSchemaC) {
+    let mut private = Box::new(ArrowSchemaPrivate {
+        name: node.name.clone(),
+        children: node.children.iter().map(|child| {
+            let ptr = Box::into_raw(Box::new(std::mem::zeroed::<ArrowSchemaC>()));
+            arrow_export_schema(child, ptr);
+            ptr
+        }).collect(),
+    });
+    std::ptr::write(out, ArrowSchemaC {
+        format: node.format.as_ptr(),
+        name: private.name.as_ptr(),
+        metadata: std::ptr::null(),
+        flags: if node.nullable { ARROW_FLAG_NULLABLE } else { 0 },
+        n_children: private.children.len() as i64,
+        children: private.children.as_mut_ptr(),
+        dictionary: std::ptr::null_mut(),
+        release: Some(arrow_release_schema),
+        private_data: Box::into_raw(private) as *mut std::ffi::c_void,
+    });
+}
+
+struct ArrowArrayPrivate {
+    _buffers: Vec<ArrowBuffer>,
+    buffer_ptrs: Vec<*const std::ffi::c_void>,
+    children: Vec<*mut ArrowArrayC>,
+}
+
+unsafe extern "C" fn arrow_release_array(array: *mut ArrowArrayC) {
+    if array.is_null() || (*array).release.is_none() { return; }
+    let private = Box::from_raw((*array).private_data as *mut ArrowArrayPrivate);
+    for &child in &private.children {
+        if let Some(release) = (*child).release { release(child); }
+        drop(Box::from_raw(child));
+    }
+    drop(private);
+    (*array).release = None;
+}
+
+unsafe fn arrow_export_array(node: ArrowNode, out: *mut ArrowArrayC) {
+    let children: Vec<*mut ArrowArrayC> = node.children.into_iter().map(|child| {
+        let ptr = Box::into_raw(Box::new(std::mem::zeroed::<ArrowArrayC>()));
+        arrow_export_array(child, ptr);
+        ptr
+    }).collect();
+    let buffer_ptrs = node.buffers.iter().map(|b| b.as_ptr()).collect();
+    let mut private = Box::new(ArrowArrayPrivate { _buffers: node.buffers, buffer_ptrs, children });
+    std::ptr::write(out, ArrowArrayC {
+        length: node.length,
+        null_count: node.null_count,
+        offset: 0,
+        n_buffers: private.buffer_ptrs.len() as i64,
+        n_children: private.children.len() as i64,
+        buffers: private.buffer_ptrs.as_mut_ptr(),
+        children: private.children.as_mut_ptr(),
+        dictionary: std::ptr::null_mut(),
+        release: Some(arrow_release_array),
+        private_data: Box::into_raw(private) as *mut std::ffi::c_void,
+    });
+}
+
+/// Stream state: the schema tree plus the single batch, handed out once.
+struct ArrowStreamPrivate {
+    schema: ArrowNode,
+    batch: Option<ArrowNode>,
+}
+
+unsafe extern "C" fn arrow_stream_get_schema(stream: *mut ArrowArrayStreamC, out: *mut ArrowSchemaC) -> std::ffi::c_int {
+    let private = &*((*stream).private_data as *const ArrowStreamPrivate);
+    arrow_export_schema(&private.schema, out);
+    0
+}
+
+unsafe extern "C" fn arrow_stream_get_next(stream: *mut ArrowArrayStreamC, out: *mut ArrowArrayC) -> std::ffi::c_int {
+    let private = &mut *((*stream).private_data as *mut ArrowStreamPrivate);
+    match private.batch.take() {
+        Some(batch) => arrow_export_array(batch, out),
+        // End of stream is signalled by a released (zeroed) array
+        None => std::ptr::write(out, std::mem::zeroed()),
+    }
+    0
+}
+
+unsafe extern "C" fn arrow_stream_get_last_error(_stream: *mut ArrowArrayStreamC) -> *const std::ffi::c_char {
+    std::ptr::null()
+}
+
+unsafe extern "C" fn arrow_stream_release(stream: *mut ArrowArrayStreamC) {
+    if stream.is_null() || (*stream).release.is_none() { return; }
+    drop(Box::from_raw((*stream).private_data as *mut ArrowStreamPrivate));
+    (*stream).release = None;
+}
+
+const ARROW_STREAM_CAPSULE: &std::ffi::CStr = c"arrow_array_stream";
+
+unsafe extern "C" fn arrow_stream_capsule_destructor(capsule: *mut pyo3::ffi::PyObject) {
+    let stream = pyo3::ffi::PyCapsule_GetPointer(capsule, ARROW_STREAM_CAPSULE.as_ptr()) as *mut ArrowArrayStreamC;
+    if stream.is_null() { return; }
+    // Consumers that imported the stream have already moved it out and nulled `release`
+    if let Some(release) = (*stream).release { release(stream); }
+    drop(Box::from_raw(stream));
+}
+
+/// One parsed file for `batch_open_arrow`: path, file data and requested tag values.
+type ArrowRow<'a> = (&'a str, PreSerializedFile, Vec<Option<Vec<String>>>);
+
+/// Columnar batch result exposing the Arrow PyCapsule stream interface
+/// (`pyarrow.table(obj)`, `polars.from_arrow(obj)`).
+#[pyclass(name = "ArrowBatch")]
+struct PyArrowBatch {
+    table: ArrowNode,
+}
+
+#[pymethods]
+impl PyArrowBatch {
+    fn __len__(&self) -> usize {
+        self.table.length as usize
+    }
+
+    #[getter]
+    fn column_names(&self) -> Vec<String> {
+        self.table.children.iter().map(|c| c.name.to_string_lossy().into_owned()).collect()
+    }
+
+    #[pyo3(signature = (requested_schema=None))]
+    fn __arrow_c_stream__(&self, py: Python<'_>, requested_schema: Option<Py<PyAny>>) -> PyResult<Py<PyAny>> {
+        // Only the native schema is offered; consumers cast if they asked for another.
+        let _ = requested_schema;
+        let private = Box::new(ArrowStreamPrivate {
+            schema: self.table.schema_only(),
+            batch: Some(self.table.clone()),
+        });
+        let stream = Box::into_raw(Box::new(ArrowArrayStreamC {
+            get_schema: Some(arrow_stream_get_schema),
+            get_next: Some(arrow_stream_get_next),
+            get_last_error: Some(arrow_stream_get_last_error),
+            release: Some(arrow_stream_release),
+            private_data: Box::into_raw(private) as *mut std::ffi::c_void,
+        }));
+        unsafe {
+            let capsule = pyo3::ffi::PyCapsule_New(
+                stream as *mut std::ffi::c_void,
+                ARROW_STREAM_CAPSULE.as_ptr(),
+                Some(arrow_stream_capsule_destructor),
+            );
+            if capsule.is_null() {
+                arrow_stream_release(stream);
+                drop(Box::from_raw(stream));
+                return Err(PyErr::fetch(py));
+            }
+            Ok(Bound::from_owned_ptr(py, capsule).unbind())
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ArrowBatch(rows={}, columns={})", self.table.length, self.column_names().join(", "))
+    }
+}
+
+/// Batch read into Arrow columns: one row per parsed file with `path`, `length`,
+/// `sample_rate`, `channels`, `bitrate` plus the requested easy-key tag columns.
+/// Missing tags are null; unparseable files are skipped like in `batch_open`.
+#[pyfunction]
+#[pyo3(signature = (filenames, columns=None))]
+fn batch_open_arrow(py: Python<'_>, filenames: Vec<String>, columns: Option<Vec<String>>) -> PyResult<PyArrowBatch> {
+    use rayon::prelude::*;
+
+    let columns: Vec<String> = match columns {
+        Some(cols) => {
+            let unknown: Vec<&str> = cols.iter()
+                .map(|c| c.as_str())
+                .filter(|c| !EASY_KEYS.contains(c) && !ARROW_FIXED_COLUMNS.contains(c))
+                .collect();
+            if !unknown.is_empty() {
+                return Err(PyValueError::new_err(format!("Unknown columns: {}", unknown.join(", "))));
+            }
+            let mut tag_cols: Vec<String> = Vec::with_capacity(cols.len());
+            for c in cols {
+                if !ARROW_FIXED_COLUMNS.contains(&c.as_str()) && !tag_cols.contains(&c) {
+                    tag_cols.push(c);
+                }
+            }
+            tag_cols
+        }
+        None => ARROW_DEFAULT_COLUMNS.iter().map(|c| c.to_string()).collect(),
+    };
+
+    let table = py.detach(|| {
+        let rows: Vec<ArrowRow> = filenames.par_iter()
+            .filter_map(|path| {
+                let data = fast_file_read(path).ok()?;
+                let pf = parse_and_serialize(&data, path)?;
+                let mut easy = easy_batch_tags(&pf, false);
+                let tags = columns.iter().map(|col| {
+                    let idx = easy.iter().position(|(k, _)| k == col)?;
+                    match easy.swap_remove(idx).1 {
+                        BatchTagValue::TextList(v) => Some(v),
+                        _ => None,
+                    }
+                }).collect();
+                Some((path.as_str(), pf, tags))
+            })
+            .collect();
+
+        let n = rows.len();
+        let mut children = Vec::with_capacity(ARROW_FIXED_COLUMNS.len() + columns.len());
+        let paths: Vec<Option<&str>> = rows.iter().map(|r| Some(r.0)).collect();
+        let mut path_col = arrow_utf8("path", &paths);
+        path_col.nullable = false;
+        children.push(path_col);
+        children.push(arrow_f64("length", &rows.iter().map(|r| r.1.length).collect::<Vec<_>>()));
+        children.push(arrow_u32("sample_rate", &rows.iter().map(|r| Some(r.1.sample_rate)).collect::<Vec<_>>()));
+        children.push(arrow_u32("channels", &rows.iter().map(|r| Some(r.1.channels)).collect::<Vec<_>>()));
+        let mut bitrate_col = arrow_u32("bitrate", &rows.iter().map(|r| r.1.bitrate).collect::<Vec<_>>());
+        bitrate_col.nullable = true;
+        children.push(bitrate_col);
+        for (ci, col) in columns.iter().enumerate() {
+            if ARROW_LIST_COLUMNS.contains(&col.as_str()) {
+                let values: Vec<Option<&[String]>> = rows.iter().map(|r| r.2[ci].as_deref()).collect();
+                children.push(arrow_list_utf8(col, &values));
+            } else {
+                let values: Vec<Option<&str>> = rows.iter()
+                    .map(|r| r.2[ci].as_ref().and_then(|v| v.first()).map(|s| s.as_str()))
+                    .collect();
+                children.push(arrow_utf8(col, &values));
+            }
+        }
+
+        let mut table = ArrowNode::new(c"+s", "", n);
+        table.nullable = false;
+        table.buffers = vec![ArrowBuffer::Absent];
+        table.children = children;
+        table
+    });
+    Ok(PyArrowBatch { table })
+}
+
+/// Per-format totals for `batch_diag`.
+#[derive(Default)]
+struct DiagFormat {
+    files: usize,
+    bytes: u64,
+    failed: usize,
+    read_us: u128,
+    parse_us: u128,
+}
+
+/// File-size buckets reported by `batch_diag`: label and exclusive upper bound.
+const DIAG_SIZE_BUCKETS: &[(&str, u64)] = &[
+    ("<=32K", MMAP_READ_MIN as u64 + 1),
+    ("32K-256K", 256 * 1024),
+    ("256K-4M", 4 * 1024 * 1024),
+    (">=4M", u64::MAX),
+];
+
+/// Diagnostic version: measures I/O vs parse vs parallel overhead, per format.
+///
+/// Returns a dict: file and byte counts, the rayon thread count, phase timings
+/// in µs (`seq_read_us`, `seq_parse_us`, `par_parse_us`, `full_par_us` and
+/// `build_py_us`, the GIL-bound Python object construction), `parse_par_speedup`,
+/// `io_fraction`, per-format `formats` totals, `cache` hit rates for the given
+/// paths and `read_modes` (mmap or buffered, as `_fast_batch_read` does) per
+/// file-size bucket. `pretty=True` returns a readable summary string instead.
 #[pyfunction]
-fn batch_diag(py: Python<'_>, filenames: Vec<String>) -> PyResult<String> {
+#[pyo3(signature = (filenames, pretty=false))]
+fn batch_diag(py: Python<'_>, filenames: Vec<String>, pretty: bool) -> PyResult<Py<PyAny>> {
     use rayon::prelude::*;
+    use std::collections::BTreeMap;
     use std::time::Instant;
 
-    let result = py.detach(|| {
-        let n = filenames.len();
+    let n = filenames.len();
+    let (file_hits, result_hits) = {
+        let files = get_file_cache().read().unwrap();
+        let results = get_result_cache().read().unwrap();
+        (filenames.iter().filter(|p| files.contains_key(p.as_str())).count(),
+         filenames.iter().filter(|p| results.contains_key(p.as_str())).count())
+    };
+
+    let (timings, formats, buckets, parsed) = py.detach(|| {
+        let mut formats: BTreeMap<&'static str, DiagFormat> = BTreeMap::new();
+        let mut buckets = vec![(0usize, 0u64); DIAG_SIZE_BUCKETS.len()];
 
         // Phase 1: Sequential file reads (no fstat)
         let t1 = Instant::now();
-        let file_data: Vec<(String, Vec<u8>)> = filenames.iter()
-            .filter_map(|p| std::fs::read(p).ok().map(|d| (p.clone(), d)))
-            .collect();
+        let mut file_data: Vec<(&String, Vec<u8>, &'static str)> = Vec::with_capacity(n);
+        for p in &filenames {
+            let t = Instant::now();
+            let Ok(d) = std::fs::read(p) else { continue };
+            let fmt = probe_format(p, &d).unwrap_or("unknown");
+            let entry = formats.entry(fmt).or_default();
+            entry.files += 1;
+            entry.bytes += d.len() as u64;
+            entry.read_us += t.elapsed().as_micros();
+            let bucket = DIAG_SIZE_BUCKETS.iter().position(|(_, max)| (d.len() as u64) < *max).unwrap_or(0);
+            buckets[bucket].0 += 1;
+            buckets[bucket].1 += d.len() as u64;
+            file_data.push((p, d, fmt));
+        }
         let read_seq_us = t1.elapsed().as_micros();
 
         // Phase 2: Sequential parse (no I/O)
         let t2 = Instant::now();
-        let _: Vec<_> = file_data.iter()
-            .filter_map(|(p, d)| parse_and_serialize(d, p).map(|pf| (p.clone(), pf)))
-            .collect();
+        for (p, d, fmt) in &file_data {
+            let t = Instant::now();
+            let ok = parse_and_serialize(d, p).is_some();
+            let entry = formats.entry(fmt).or_default();
+            entry.parse_us += t.elapsed().as_micros();
+            entry.failed += usize::from(!ok);
+        }
         let parse_seq_us = t2.elapsed().as_micros();
 
         // Phase 3: Parallel parse (no I/O)
         let t3 = Instant::now();
-        let _: Vec<_> = file_data.par_iter()
-            .filter_map(|(p, d)| parse_and_serialize(d, p).map(|pf| (p.clone(), pf)))
+        let parsed: Vec<PreSerializedFile> = file_data.par_iter()
+            .filter_map(|(p, d, _)| parse_and_serialize(d, p))
             .collect();
         let parse_par_us = t3.elapsed().as_micros();
 
-        // Phase 4: Parallel read+parse (current approach)
+        // Phase 4: Parallel read+parse, reading like _fast_batch_read
         let t4 = Instant::now();
         let _: Vec<_> = filenames.par_iter().filter_map(|path| {
-            let data = std::fs::read(path).ok()?;
-            let pf = parse_and_serialize(&data, path)?;
-            Some((path.clone(), pf))
+            use std::io::Read;
+            let mut file = std::fs::File::open(path).ok()?;
+            let file_len = file.metadata().ok()?.len() as usize;
+            if file_len > MMAP_READ_MIN {
+                let mmap = unsafe { memmap2::Mmap::map(&file).ok()? };
+                parse_and_serialize(&mmap, path)
+            } else {
+                let mut data = Vec::with_capacity(file_len);
+                file.read_to_end(&mut data).ok()?;
+                parse_and_serialize(&data, path)
+            }
         }).collect();
         let full_par_us = t4.elapsed().as_micros();
 
-        format!(
-            "n={} | seq_read={}µs seq_parse={}µs par_parse={}µs full_par={}µs | \
-             parse_par_speedup={:.1}x io_fraction={:.0}%",
-            n, read_seq_us, parse_seq_us, parse_par_us, full_par_us,
-            parse_seq_us as f64 / parse_par_us.max(1) as f64,
-            read_seq_us as f64 / (read_seq_us + parse_seq_us).max(1) as f64 * 100.0,
-        )
+        ([read_seq_us, parse_seq_us, parse_par_us, full_par_us], formats, buckets, parsed)
     });
+    let [read_seq_us, parse_seq_us, parse_par_us, full_par_us] = timings;
+
+    // Phase 5: Python object construction (under the GIL)
+    let t5 = std::time::Instant::now();
+    for pf in &parsed {
+        drop(preserialized_to_py_dict(py, pf)?);
+    }
+    let build_py_us = t5.elapsed().as_micros();
+
+    let speedup = parse_seq_us as f64 / parse_par_us.max(1) as f64;
+    let io_fraction = read_seq_us as f64 / (read_seq_us + parse_seq_us).max(1) as f64;
+    let bytes_read: u64 = formats.values().map(|f| f.bytes).sum();
+    let threads = rayon::current_num_threads();
+    let hit_rate = |hits: usize| if n > 0 { hits as f64 / n as f64 } else { 0.0 };
+    let mode = |max: u64| if max > MMAP_READ_MIN as u64 + 1 { "mmap" } else { "buffered" };
+
+    if pretty {
+        let mut out = format!(
+            "n={} | seq_read={}µs seq_parse={}µs par_parse={}µs full_par={}µs build_py={}µs | \
+             parse_par_speedup={:.1}x io_fraction={:.0}% | threads={} bytes={} | \
+             file_cache_hits={:.0}% result_cache_hits={:.0}%",
+            n, read_seq_us, parse_seq_us, parse_par_us, full_par_us, build_py_us,
+            speedup, io_fraction * 100.0, threads, bytes_read,
+            hit_rate(file_hits) * 100.0, hit_rate(result_hits) * 100.0,
+        );
+        for (fmt, f) in &formats {
+            out.push_str(&format!(
+                "\n  {}: files={} bytes={} failed={} read={}µs parse={}µs",
+                fmt, f.files, f.bytes, f.failed, f.read_us, f.parse_us,
+            ));
+        }
+        for ((label, max), (files, bytes)) in DIAG_SIZE_BUCKETS.iter().zip(&buckets) {
+            if *files > 0 {
+                out.push_str(&format!("\n  {}: files={} bytes={} read={}", label, files, bytes, mode(*max)));
+            }
+        }
+        return Ok(out.into_pyobject(py)?.into_any().unbind());
+    }
+
+    let dict = PyDict::new(py);
+    dict.set_item("files", n)?;
+    dict.set_item("parsed", parsed.len())?;
+    dict.set_item("bytes_read", bytes_read)?;
+    dict.set_item("threads", threads)?;
+    dict.set_item("seq_read_us", read_seq_us)?;
+    dict.set_item("seq_parse_us", parse_seq_us)?;
+    dict.set_item("par_parse_us", parse_par_us)?;
+    dict.set_item("full_par_us", full_par_us)?;
+    dict.set_item("build_py_us", build_py_us)?;
+    dict.set_item("parse_par_speedup", speedup)?;
+    dict.set_item("io_fraction", io_fraction)?;
+    let per_format = PyDict::new(py);
+    for (fmt, f) in &formats {
+        let d = PyDict::new(py);
+        d.set_item("files", f.files)?;
+        d.set_item("bytes", f.bytes)?;
+        d.set_item("failed", f.failed)?;
+        d.set_item("read_us", f.read_us)?;
+        d.set_item("parse_us", f.parse_us)?;
+        per_format.set_item(*fmt, d)?;
+    }
+    dict.set_item("formats", per_format)?;
+    let cache = PyDict::new(py);
+    cache.set_item("file_hit_rate", hit_rate(file_hits))?;
+    cache.set_item("result_hit_rate", hit_rate(result_hits))?;
+    dict.set_item("cache", cache)?;
+    let modes = PyDict::new(py);
+    for ((label, max), (files, bytes)) in DIAG_SIZE_BUCKETS.iter().zip(&buckets) {
+        let d = PyDict::new(py);
+        d.set_item("files", files)?;
+        d.set_item("bytes", bytes)?;
+        d.set_item("mode", mode(*max))?;
+        modes.set_item(*label, d)?;
+    }
+    dict.set_item("read_modes", modes)?;
+    Ok(dict.into_any().unbind())
+}
 
-    Ok(result)
+/// Extensions picked up when `batch_export_ndjson` is given a directory.
+const NDJSON_SCAN_EXTS: &[&str] = &["mp3", "flac", "ogg", "opus", "m4a", "m4b", "mp4", "m4v", "aax", "aaxc", "caf", "ape", "wv"];
+
+/// Bounded queue depth between the parse workers and the single NDJSON writer.
+const NDJSON_CHANNEL_CAP: usize = 1024;
+
+/// Recursively collect supported audio files under `dir`. Symlinked directories are not
+/// followed (avoids cycles); output is sorted so repeated scans produce the same order.
+fn collect_audio_files(dir: &std::path::Path, out: &mut Vec<String>) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            collect_audio_files(&path, out)?;
+        } else if path.extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| NDJSON_SCAN_EXTS.iter().any(|x| e.eq_ignore_ascii_case(x)))
+        {
+            if let Some(s) = path.to_str() {
+                out.push(s.to_string());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// NDJSON output target: a file on disk (gzip when the name ends in `.gz`) or a
+/// Python file-like object with `write()`.
+enum NdjsonSink<'py> {
+    Plain(std::io::BufWriter<std::fs::File>),
+    Gzip(flate2::write::GzEncoder<std::io::BufWriter<std::fs::File>>),
+    PyText(Bound<'py, PyAny>),
+    PyBinary(Bound<'py, PyAny>),
+}
+
+impl<'py> NdjsonSink<'py> {
+    fn open(target: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(path) = target.extract::<std::path::PathBuf>() {
+            let file = std::fs::File::create(&path)
+                .map_err(|e| PyIOError::new_err(format!("Cannot create {}: {}", path.display(), e)))?;
+            let buf = std::io::BufWriter::with_capacity(256 * 1024, file);
+            let gz = path.extension().is_some_and(|e| e.eq_ignore_ascii_case("gz"));
+            return Ok(if gz {
+                NdjsonSink::Gzip(flate2::write::GzEncoder::new(buf, flate2::Compression::default()))
+            } else {
+                NdjsonSink::Plain(buf)
+            });
+        }
+        if !target.hasattr("write")? {
+            return Err(pyo3::exceptions::PyTypeError::new_err(
+                "out must be a path or a file-like object with write()"));
+        }
+        let text_io = target.py().import("io")?.getattr("TextIOBase")?;
+        Ok(if target.is_instance(&text_io)? {
+            NdjsonSink::PyText(target.clone())
+        } else {
+            NdjsonSink::PyBinary(target.clone())
+        })
+    }
+
+    fn write(&mut self, chunk: &str) -> PyResult<()> {
+        use std::io::Write;
+        let io_err = |e: std::io::Error| PyIOError::new_err(format!("NDJSON write failed: {}", e));
+        match self {
+            NdjsonSink::Plain(w) => w.write_all(chunk.as_bytes()).map_err(io_err),
+            NdjsonSink::Gzip(w) => w.write_all(chunk.as_bytes()).map_err(io_err),
+            NdjsonSink::PyText(obj) => obj.call_method1("write", (chunk,)).map(|_| ()),
+            NdjsonSink::PyBinary(obj) => {
+                obj.call_method1("write", (PyBytes::new(obj.py(), chunk.as_bytes()),)).map(|_| ())
+            }
+        }
+    }
+
+    fn finish(self) -> PyResult<()> {
+        use std::io::Write;
+        let io_err = |e: std::io::Error| PyIOError::new_err(format!("NDJSON write failed: {}", e));
+        match self {
+            NdjsonSink::Plain(mut w) => w.flush().map_err(io_err),
+            NdjsonSink::Gzip(w) => w.finish().and_then(|mut b| b.flush()).map_err(io_err),
+            NdjsonSink::PyText(obj) | NdjsonSink::PyBinary(obj) => {
+                if obj.hasattr("flush")? {
+                    obj.call_method0("flush")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Stream batch results as NDJSON (one `{"path": ..., <to_json schema>}` object per line).
+///
+/// Files are parsed on the rayon pool and handed to a single writer over a bounded
+/// channel, so memory stays flat regardless of how many files are scanned. Lines are
+/// written in completion order. Returns `{"written", "failed", "errors": [(path, msg)]}`.
+#[pyfunction]
+#[pyo3(signature = (filenames_or_dir, out, threads=None, easy=false))]
+fn batch_export_ndjson(
+    py: Python<'_>,
+    filenames_or_dir: &Bound<'_, PyAny>,
+    out: &Bound<'_, PyAny>,
+    threads: Option<usize>,
+    easy: bool,
+) -> PyResult<Py<PyAny>> {
+    use rayon::prelude::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::mpsc::{sync_channel, TryRecvError};
+
+    let filenames: Vec<String> = match filenames_or_dir.extract::<std::path::PathBuf>() {
+        Ok(path) if path.is_dir() => {
+            let mut files = Vec::new();
+            py.detach(|| collect_audio_files(&path, &mut files))
+                .map_err(|e| PyIOError::new_err(format!("Cannot scan {}: {}", path.display(), e)))?;
+            files.sort_unstable();
+            files
+        }
+        Ok(path) => vec![path.to_string_lossy().into_owned()],
+        Err(_) => filenames_or_dir.extract()?,
+    };
+
+    let pool = match threads {
+        Some(n) => Some(rayon::ThreadPoolBuilder::new().num_threads(n).build()
+            .map_err(|e| PyValueError::new_err(format!("Cannot create thread pool: {}", e)))?),
+        None => None,
+    };
+
+    let mut sink = NdjsonSink::open(out)?;
+    let (tx, rx) = sync_channel::<(usize, Result<String, String>)>(NDJSON_CHANNEL_CAP);
+    // Receiver is !Sync; the mutex lets the writer wait on it with the GIL released.
+    let rx = std::sync::Mutex::new(rx);
+    let cancelled = AtomicBool::new(false);
+    let mut written = 0usize;
+    let mut errors: Vec<(String, String)> = Vec::new();
+
+    let write_result = std::thread::scope(|s| {
+        let filenames = &filenames;
+        let cancelled = &cancelled;
+        s.spawn(move || {
+            let work = || filenames.par_iter().enumerate().for_each_with(tx, |tx, (i, path)| {
+                if cancelled.load(Ordering::Relaxed) { return; }
+                let line = match fast_file_read(path) {
+                    Err(e) => Err(e.to_string()),
+                    Ok(data) => match parse_and_serialize(&data, path) {
+                        None => Err("unsupported or corrupt file".to_string()),
+                        Some(mut pf) => {
+                            if easy {
+                                pf.tags = easy_batch_tags(&pf, false);
+                                pf.lazy_vc = None;
+                            }
+                            let mut line = String::with_capacity(512);
+                            line.push_str("{\"path\":");
+                            json_escape_to(path, &mut line);
+                            line.push(',');
+                            let start = line.len();
+                            preserialized_to_json(&pf, false, &mut line);
+                            // Drop the object's opening brace: fields continue after "path".
+                            line.remove(start);
+                            line.push('\n');
+                            Ok(line)
+                        }
+                    },
+                };
+                let _ = tx.send((i, line));
+            });
+            match pool {
+                Some(ref pool) => pool.install(work),
+                None => work(),
+            }
+        });
+
+        let mut chunk = String::with_capacity(64 * 1024);
+        loop {
+            let mut done = false;
+            let batch: Vec<_> = py.detach(|| {
+                let rx = rx.lock().unwrap_or_else(|e| e.into_inner());
+                let mut batch = Vec::new();
+                match rx.recv() {
+                    Ok(msg) => batch.push(msg),
+                    Err(_) => { done = true; return batch; }
+                }
+                while batch.len() < 256 {
+                    match rx.try_recv() {
+                        Ok(msg) => batch.push(msg),
+                        Err(TryRecvError::Empty) => break,
+                        Err(TryRecvError::Disconnected) => { done = true; break; }
+                    }
+                }
+                batch
+            });
+            for (i, line) in batch {
+                match line {
+                    Ok(line) => { chunk.push_str(&line); written += 1; }
+                    Err(msg) => errors.push((filenames[i].clone(), msg)),
+                }
+            }
+            if chunk.len() >= 60 * 1024 || (done && !chunk.is_empty()) {
+                if let Err(e) = sink.write(&chunk) {
+                    cancelled.store(true, Ordering::Relaxed);
+                    // Drain so blocked workers can exit.
+                    py.detach(|| while rx.lock().unwrap_or_else(|e| e.into_inner()).recv().is_ok() {});
+                    return Err(e);
+                }
+                chunk.clear();
+            }
+            if done { return Ok(()); }
+        }
+    });
+    write_result?;
+    sink.finish()?;
+
+    let result = PyDict::new(py);
+    result.set_item("written", written)?;
+    result.set_item("failed", errors.len())?;
+    result.set_item("errors", errors)?;
+    Ok(result.into_any().unbind())
+}
+
+type BatchIterItem = (usize, Option<PreSerializedFile>);
+
+/// Iterator over batch results as workers finish them (see `batch_iter`).
+#[pyclass(name = "BatchIter")]
+struct PyBatchIter {
+    filenames: Arc<Vec<String>>,
+    rx: std::sync::Mutex<std::sync::mpsc::Receiver<BatchIterItem>>,
+    cancelled: Arc<std::sync::atomic::AtomicBool>,
+    ordered: bool,
+    /// Ordered mode: next input index to yield, and results that arrived early.
+    next_index: usize,
+    pending: HashMap<usize, Option<PreSerializedFile>>,
+}
+
+impl PyBatchIter {
+    /// Next result in the configured order, `None` once the workers are done.
+    fn next_item(&mut self, py: Python<'_>) -> Option<(usize, PreSerializedFile)> {
+        loop {
+            if self.ordered {
+                while let Some(slot) = self.pending.remove(&self.next_index) {
+                    self.next_index += 1;
+                    if let Some(pf) = slot {
+                        return Some((self.next_index - 1, pf));
+                    }
+                }
+            }
+            let rx = &self.rx;
+            let (i, slot) = py.detach(|| rx.lock().unwrap_or_else(|e| e.into_inner()).recv()).ok()?;
+            if !self.ordered {
+                if let Some(pf) = slot {
+                    return Some((i, pf));
+                }
+            } else {
+                self.pending.insert(i, slot);
+            }
+        }
+    }
+}
+
+#[pymethods]
+impl PyBatchIter {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<(String, Py<PyAny>)>> {
+        match self.next_item(py) {
+            Some((i, pf)) => Ok(Some((self.filenames[i].clone(), preserialized_to_py_dict(py, &pf)?))),
+            None => Ok(None),
+        }
+    }
+}
+
+impl Drop for PyBatchIter {
+    fn drop(&mut self) {
+        // Workers skip remaining files; their sends fail once the receiver is gone.
+        self.cancelled.store(true, std::sync::atomic::Ordering::Relaxed);
+    }
+}
+
+/// Iterate `(path, dict)` results as parallel workers complete them.
+///
+/// Parsed files pass through a channel bounded at `buffer` entries, so workers stall
+/// when the consumer falls behind and peak memory follows the buffer rather than the
+/// number of files. Results arrive in completion order; `ordered=True` yields them in
+/// input order, holding early finishers until their turn. Unreadable files are skipped.
+#[pyfunction]
+#[pyo3(signature = (filenames, threads=None, buffer=1024, ordered=false))]
+fn batch_iter(filenames: Vec<String>, threads: Option<usize>, buffer: usize, ordered: bool) -> PyResult<PyBatchIter> {
+    use rayon::prelude::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    let pool = match threads {
+        Some(n) => Some(rayon::ThreadPoolBuilder::new().num_threads(n).build()
+            .map_err(|e| PyValueError::new_err(format!("Cannot create thread pool: {}", e)))?),
+        None => None,
+    };
+    let filenames = Arc::new(filenames);
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let (tx, rx) = std::sync::mpsc::sync_channel::<BatchIterItem>(buffer.max(1));
+
+    let worker_files = Arc::clone(&filenames);
+    let worker_cancelled = Arc::clone(&cancelled);
+    std::thread::spawn(move || {
+        let work = || worker_files.par_iter().enumerate().for_each_with(tx, |tx, (i, path)| {
+            if worker_cancelled.load(Ordering::Relaxed) { return; }
+            let pf = fast_file_read(path).ok().and_then(|data| parse_and_serialize(&data, path));
+            if tx.send((i, pf)).is_err() {
+                worker_cancelled.store(true, Ordering::Relaxed);
+            }
+        });
+        match pool {
+            Some(ref pool) => pool.install(work),
+            None => work(),
+        }
+    });
+
+    Ok(PyBatchIter {
+        filenames,
+        rx: std::sync::Mutex::new(rx),
+        cancelled,
+        ordered,
+        next_index: 0,
+        pending: HashMap::new(),
+    })
+}
+
+/// Read `filename` and parse it completely. Touches no Python objects, so
+/// `file_open` calls it with the GIL released.
+fn read_and_parse(filename: &str) -> PyResult<(Arc<[u8]>, ParsedFile)> {
+    let data = read_cached(filename)
+        .map_err(|e| PyIOError::new_err(format!("Cannot open file: {}", e)))?;
+    let parsed = common::parse::parse_data(&data, filename)?;
+    Ok((data, parsed))
 }
 
 /// Auto-detect file format and open.
+///
+/// Reading and parsing happen with the GIL released; it is re-acquired only
+/// to build the tag dict and the returned object.
 #[pyfunction]
 #[pyo3(signature = (filename, easy=false))]
 fn file_open(py: Python<'_>, filename: &str, easy: bool) -> PyResult<Py<PyAny>> {
     let _ = easy;
 
-    let data = read_cached(filename)
-        .map_err(|e| PyIOError::new_err(format!("Cannot open file: {}", e)))?;
+    let (data, parsed) = py.detach(|| read_and_parse(filename))?;
+    match parsed {
+        ParsedFile::Flac(f) => {
+            let f = PyFLAC::from_parsed(py, f, data.len(), filename)?;
+            Ok(f.into_pyobject(py)?.into_any().unbind())
+        }
+        ParsedFile::Ogg(f) => {
+            let f = PyOggVorbis::from_parsed(py, f, filename)?;
+            Ok(f.into_pyobject(py)?.into_any().unbind())
+        }
+        ParsedFile::Opus(f) => {
+            let f = PyOggOpus::from_parsed(py, f, filename)?;
+            Ok(f.into_pyobject(py)?.into_any().unbind())
+        }
+        ParsedFile::Mp3(f) => {
+            let f = PyMP3::from_parsed(py, f, filename)?;
+            Ok(f.into_pyobject(py)?.into_any().unbind())
+        }
+        ParsedFile::Mp4(f) => {
+            let f = PyMP4::from_parsed(py, f, filename)?;
+            Ok(f.into_pyobject(py)?.into_any().unbind())
+        }
+        ParsedFile::WavPack(f) => {
+            let f = PyWavPack::from_parsed(py, f, filename)?;
+            Ok(f.into_pyobject(py)?.into_any().unbind())
+        }
+        ParsedFile::MonkeysAudio(f) => {
+            let f = PyMonkeysAudio::from_parsed(py, f, filename)?;
+            Ok(f.into_pyobject(py)?.into_any().unbind())
+        }
+    }
+}
 
-    // Fast path: extension-based detection (avoids scoring overhead)
-    let ext = filename.rsplit('.').next().unwrap_or("");
-    if ext.eq_ignore_ascii_case("flac") {
-        let f = PyFLAC::from_data(py, &data, filename)?;
-        return Ok(f.into_pyobject(py)?.into_any().unbind());
+/// Read-only file: stream info and the pre-built tag dict, without the mutable
+/// tag objects (`ID3`, `VComment`, `MP4Tags`) the format classes also keep.
+#[pyclass(name = "ReadOnlyFile")]
+struct PyReadOnlyFile {
+    #[pyo3(get)]
+    info: Py<PyAny>,
+    #[pyo3(get)]
+    filename: String,
+    tag_dict: Py<PyDict>,
+    tag_keys: Vec<String>,
+}
+
+#[pymethods]
+impl PyReadOnlyFile {
+    /// Copy of the tag dict (same values as `File(...)[key]`).
+    #[getter]
+    fn tags<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        self.tag_dict.bind(py).copy()
     }
-    if ext.eq_ignore_ascii_case("ogg") {
-        let f = PyOggVorbis::from_data(py, &data, filename)?;
-        return Ok(f.into_pyobject(py)?.into_any().unbind());
+
+    fn keys(&self) -> Vec<String> {
+        self.tag_keys.clone()
     }
-    if ext.eq_ignore_ascii_case("mp3") {
-        let f = PyMP3::from_data(py, &data, filename)?;
-        return Ok(f.into_pyobject(py)?.into_any().unbind());
+
+    fn __getitem__(&self, py: Python, key: &str) -> PyResult<Py<PyAny>> {
+        match self.tag_dict.bind(py).get_item(key)? {
+            Some(val) => Ok(val.unbind()),
+            None => Err(PyKeyError::new_err(key.to_string())),
+        }
     }
-    if ext.eq_ignore_ascii_case("m4a") || ext.eq_ignore_ascii_case("m4b")
-        || ext.eq_ignore_ascii_case("mp4") || ext.eq_ignore_ascii_case("m4v") {
-        let f = PyMP4::from_data(py, &data, filename)?;
-        return Ok(f.into_pyobject(py)?.into_any().unbind());
+
+    #[pyo3(signature = (key, default=None))]
+    fn get(&self, py: Python, key: &str, default: Option<Py<PyAny>>) -> PyResult<Option<Py<PyAny>>> {
+        Ok(self.tag_dict.bind(py).get_item(key)?.map(Bound::unbind).or(default))
     }
 
-    // Fallback: score-based detection
-    let mp3_score = mp3::MP3File::score(filename, &data);
-    let flac_score = flac::FLACFile::score(filename, &data);
-    let ogg_score = ogg::OggVorbisFile::score(filename, &data);
-    let mp4_score = mp4::MP4File::score(filename, &data);
+    fn __contains__(&self, py: Python, key: &str) -> bool {
+        self.tag_dict.bind(py).get_item(key).ok().flatten().is_some()
+    }
 
-    let max_score = mp3_score.max(flac_score).max(ogg_score).max(mp4_score);
+    fn __len__(&self) -> usize {
+        self.tag_keys.len()
+    }
 
-    if max_score == 0 {
-        return Err(PyValueError::new_err(format!(
-            "Unable to detect format for: {}",
-            filename
-        )));
+    fn __iter__(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let list = PyList::new(py, &self.tag_keys)?;
+        Ok(list.call_method0("__iter__")?.into())
     }
 
-    if max_score == flac_score {
-        let f = PyFLAC::from_data(py, &data, filename)?;
-        Ok(f.into_pyobject(py)?.into_any().unbind())
-    } else if max_score == ogg_score {
-        let f = PyOggVorbis::from_data(py, &data, filename)?;
-        Ok(f.into_pyobject(py)?.into_any().unbind())
-    } else if max_score == mp4_score {
-        let f = PyMP4::from_data(py, &data, filename)?;
-        Ok(f.into_pyobject(py)?.into_any().unbind())
-    } else {
-        let f = PyMP3::from_data(py, &data, filename)?;
-        Ok(f.into_pyobject(py)?.into_any().unbind())
+    fn __repr__(&self) -> String {
+        format!("ReadOnlyFile(filename={:?})", self.filename)
     }
 }
 
+/// Open a file for reading only. Detection and parsing match `File()`, but the
+/// result carries just `info` and the tag dict: no `ID3`/`VComment`/`MP4Tags`
+/// is built, so it can't be edited or saved.
+#[pyfunction]
+fn open_readonly(py: Python<'_>, filename: &str) -> PyResult<PyReadOnlyFile> {
+    let (data, parsed) = py.detach(|| read_and_parse(filename))?;
+    let (info, (tag_dict, tag_keys)) = match parsed {
+        ParsedFile::Flac(f) => {
+            let info = PyFLAC::make_info(&f, data.len()).into_pyobject(py)?.into_any();
+            let tags = match f.tags {
+                Some(ref vc) => vc_tag_dict(py, vc)?,
+                None => (PyDict::new(py), Vec::new()),
+            };
+            (info, tags)
+        }
+        ParsedFile::Ogg(f) => {
+            (PyOggVorbis::make_info(&f).into_pyobject(py)?.into_any(), vc_tag_dict(py, &f.tags)?)
+        }
+        ParsedFile::Opus(f) => {
+            (PyOggOpus::make_info(&f).into_pyobject(py)?.into_any(), vc_tag_dict(py, &f.tags)?)
+        }
+        ParsedFile::Mp3(mut f) => {
+            let tags = id3_tag_dict(py, &mut f.tags, f.info.frame_ms());
+            (make_mpeg_info(&f.info).into_pyobject(py)?.into_any(), tags)
+        }
+        ParsedFile::Mp4(f) => {
+            let tags = mp4_tag_dict(py, &f.tags);
+            (PyMP4::make_info(f.info).into_pyobject(py)?.into_any(), tags)
+        }
+        ParsedFile::WavPack(f) => {
+            (PyWavPack::make_info(&f).into_pyobject(py)?.into_any(), ape_tag_dict(py, f.tags.as_ref())?)
+        }
+        ParsedFile::MonkeysAudio(f) => {
+            (PyMonkeysAudio::make_info(&f).into_pyobject(py)?.into_any(), ape_tag_dict(py, f.tags.as_ref())?)
+        }
+    };
+    Ok(PyReadOnlyFile {
+        info: info.unbind(),
+        filename: filename.to_string(),
+        tag_dict: tag_dict.unbind(),
+        tag_keys,
+    })
+}
+
 /// Global result cache — stores parsed PyDict per file path.
 /// On warm hit, returns a shallow copy (~200ns vs ~1700ns for re-parsing).
 static RESULT_CACHE: OnceLock<RwLock<HashMap<String, Py<PyDict>>>> = OnceLock::new();
@@ -2806,18 +7458,43 @@ fn get_template_cache() -> &'static RwLock<HashMap<String, Py<PyDict>>> {
     TEMPLATE_CACHE.get_or_init(|| RwLock::new(HashMap::with_capacity(256)))
 }
 
+/// Info-only cache for `_fast_info` — kept apart from RESULT_CACHE so duration
+/// polling doesn't evict full parses (and vice versa). Entries are validated
+/// against the file's mtime and size on every hit.
+static INFO_CACHE: OnceLock<RwLock<HashMap<String, InfoCacheEntry>>> = OnceLock::new();
+
+/// Upper bound on INFO_CACHE entries; the cache is reset when it fills up.
+const INFO_CACHE_CAP: usize = 4096;
+
+struct InfoCacheEntry {
+    mtime: std::time::SystemTime,
+    size: u64,
+    info: Py<PyDict>,
+}
+
+fn get_info_cache() -> &'static RwLock<HashMap<String, InfoCacheEntry>> {
+    INFO_CACHE.get_or_init(|| RwLock::new(HashMap::with_capacity(256)))
+}
+
 /// Clear the result cache, forcing subsequent reads to re-parse (but not re-read from disk).
 /// File data cache persists for I/O amortization across repeated reads of unchanged files.
 #[pyfunction]
 fn clear_cache(_py: Python<'_>) {
-    let cache = get_result_cache();
-    let mut guard = cache.write().unwrap();
-    guard.clear();
+    {
+        let cache = get_result_cache();
+        let mut guard = cache.write().unwrap();
+        guard.clear();
+    }
+    {
+        let cache = get_info_cache();
+        let mut guard = cache.write().unwrap();
+        guard.clear();
+    }
 }
 
 /// Clear ALL caches including raw file data. Use when files on disk may have changed.
 #[pyfunction]
-fn clear_all_caches(_py: Python<'_>) {
+fn clear_all_caches(py: Python<'_>) {
     {
         let cache = get_file_cache();
         let mut guard = cache.write().unwrap();
@@ -2833,6 +7510,33 @@ fn clear_all_caches(_py: Python<'_>) {
         let mut guard = cache.write().unwrap();
         guard.clear();
     }
+    {
+        let cache = get_info_cache();
+        let mut guard = cache.write().unwrap();
+        guard.clear();
+    }
+    release_tag_key_intern(py);
+}
+
+/// Whole file contents from the read cache, reading and caching them on a miss.
+/// Later calls for the same path skip the read until `clear_all_caches()` or
+/// a save drops the entry.
+#[pyfunction]
+fn read_cached_bytes<'py>(py: Python<'py>, filename: &str) -> PyResult<Bound<'py, PyBytes>> {
+    let data = py.detach(|| read_cached(filename))
+        .map_err(|e| PyIOError::new_err(format!("Cannot open file: {}", e)))?;
+    Ok(PyBytes::new(py, &data))
+}
+
+/// Entry counts of the Rust-level caches: `file`, `template`, `result` and `info`.
+#[pyfunction]
+fn _cache_stats(py: Python<'_>) -> PyResult<Py<PyAny>> {
+    let dict = PyDict::new(py);
+    dict.set_item("file", get_file_cache().read().unwrap().len())?;
+    dict.set_item("template", get_template_cache().read().unwrap().len())?;
+    dict.set_item("result", get_result_cache().read().unwrap().len())?;
+    dict.set_item("info", get_info_cache().read().unwrap().len())?;
+    Ok(dict.into_any().unbind())
 }
 
 /// Invalidate a single file from all caches (called after save/write operations).
@@ -2852,12 +7556,18 @@ fn invalidate_file(path: &str) {
         let mut guard = cache.write().unwrap();
         guard.remove(path);
     }
+    {
+        let cache = get_info_cache();
+        let mut guard = cache.write().unwrap();
+        guard.remove(path);
+    }
 }
 
 /// Alias for batch_open (used by benchmark scripts).
 #[pyfunction]
-fn _rust_batch_open(py: Python<'_>, filenames: Vec<String>) -> PyResult<Py<PyAny>> {
-    batch_open(py, filenames)
+#[pyo3(signature = (filenames, easy=false, extra=false, io_depth=0, dedupe="lexical"))]
+fn _rust_batch_open(py: Python<'_>, filenames: Vec<String>, easy: bool, extra: bool, io_depth: usize, dedupe: &str) -> PyResult<Py<PyAny>> {
+    batch_open(py, filenames, easy, extra, io_depth, dedupe, false)
 }
 
 // ---- Fast single-file read API ----
@@ -2888,10 +7598,11 @@ fn preserialized_to_flat_dict(py: Python<'_>, pf: &PreSerializedFile, dict: &Bou
     } else {
         &pf.tags
     };
-    let mut keys: Vec<&str> = Vec::with_capacity(tags.len());
+    let mut keys: Vec<String> = Vec::with_capacity(tags.len());
     for (key, value) in tags {
+        let key = if flat_key_clashes(key.as_bytes()) { format!("tag:{}", key) } else { key.clone() };
         dict.set_item(key.as_str(), batch_value_to_py(py, value)?)?;
-        keys.push(key.as_str());
+        keys.push(key);
     }
     dict.set_item(pyo3::intern!(py, "_keys"), PyList::new(py, &keys)?)?;
     Ok(())
@@ -3052,16 +7763,22 @@ fn set_keys_list(
 // ---- Interned tag key cache ----
 // Caches Python string objects for common ID3 frame IDs (4 bytes) and Vorbis comment keys.
 // Avoids PyUnicode_FromStringAndSize per tag on repeated file reads.
-// Thread-safe via GIL: only accessed from _fast_read which holds the GIL.
+// One map shared by all threads; entries are only created and dropped with the GIL
+// held, so the mutex is uncontended (it is there for free-threaded builds).
+
+static TAG_KEY_INTERN: OnceLock<std::sync::Mutex<HashMap<[u8; 8], Py<pyo3::types::PyString>>>> = OnceLock::new();
 
-use std::cell::RefCell;
+/// Upper bound on interned keys. Past it, new keys are created uncached, so files
+/// with many distinct Vorbis comment names can't grow the map without limit.
+const TAG_KEY_INTERN_CAP: usize = 1024;
 
-thread_local! {
-    static TAG_KEY_INTERN: RefCell<HashMap<[u8; 8], *mut pyo3::ffi::PyObject>> = RefCell::new(HashMap::with_capacity(64));
+fn get_tag_key_intern() -> &'static std::sync::Mutex<HashMap<[u8; 8], Py<pyo3::types::PyString>>> {
+    TAG_KEY_INTERN.get_or_init(|| std::sync::Mutex::new(HashMap::with_capacity(64)))
 }
 
 /// Get or create an interned Python string for a tag key.
 /// Returns a NEW reference (caller must DECREF or transfer ownership).
+/// The GIL must be held.
 #[inline(always)]
 unsafe fn intern_tag_key(key: &[u8]) -> *mut pyo3::ffi::PyObject {
     if key.len() > 8 {
@@ -3073,22 +7790,70 @@ unsafe fn intern_tag_key(key: &[u8]) -> *mut pyo3::ffi::PyObject {
     let mut buf = [0u8; 8];
     buf[..key.len()].copy_from_slice(key);
 
-    TAG_KEY_INTERN.with(|cache| {
-        let mut cache = cache.borrow_mut();
-        if let Some(&ptr) = cache.get(&buf) {
-            pyo3::ffi::Py_INCREF(ptr);
-            ptr
-        } else {
-            let ptr = pyo3::ffi::PyUnicode_FromStringAndSize(
-                key.as_ptr() as *const std::ffi::c_char,
-                key.len() as pyo3::ffi::Py_ssize_t);
-            if !ptr.is_null() {
-                pyo3::ffi::Py_INCREF(ptr); // one ref for cache, one for caller
-                cache.insert(buf, ptr);
-            }
-            ptr
-        }
-    })
+    let mut cache = get_tag_key_intern().lock().unwrap();
+    if let Some(obj) = cache.get(&buf) {
+        let ptr = obj.as_ptr();
+        pyo3::ffi::Py_INCREF(ptr);
+        return ptr;
+    }
+    let ptr = pyo3::ffi::PyUnicode_FromStringAndSize(
+        key.as_ptr() as *const std::ffi::c_char,
+        key.len() as pyo3::ffi::Py_ssize_t);
+    if !ptr.is_null() && cache.len() < TAG_KEY_INTERN_CAP {
+        pyo3::ffi::Py_INCREF(ptr); // one ref for cache, one for caller
+        cache.insert(buf, Bound::from_owned_ptr(Python::assume_attached(), ptr).cast_into_unchecked().unbind());
+    }
+    ptr
+}
+
+/// Info and metadata field names a flat `_fast_read` dict sets next to the tags.
+const FLAT_INFO_KEYS: &[&str] = &[
+    "bitrate", "bitrate_mode", "bits_per_sample", "channels", "channels_source", "codec",
+    "codec_description", "duration_ms", "encoder_info", "encoder_settings", "input_sample_rate", "layer", "length",
+    "length_samples", "mode", "output_gain", "page_count", "pre_skip", "protected", "recovered", "sample_rate",
+    "serial", "tag_version",
+    "total_samples", "version", "xing_frames",
+];
+
+/// Prefix for flat-dict tag keys that would collide with an info field.
+const FLAT_TAG_PREFIX: &[u8] = b"tag:";
+
+/// Whether a tag named `key` has to be namespaced in a flat dict: it matches
+/// an info field, starts with `_`, or already carries the prefix (so stripping
+/// it once always gives back the tag name).
+#[inline(always)]
+fn flat_key_clashes(key: &[u8]) -> bool {
+    key.first() == Some(&b'_')
+        || key.starts_with(FLAT_TAG_PREFIX)
+        || FLAT_INFO_KEYS.iter().any(|k| k.as_bytes() == key)
+}
+
+/// `intern_tag_key` for a tag in a flat dict: clashing names come back as
+/// `tag:<key>`. Returns a NEW reference; the GIL must be held.
+#[inline(always)]
+unsafe fn flat_tag_key(key: &[u8]) -> *mut pyo3::ffi::PyObject {
+    if !flat_key_clashes(key) {
+        return intern_tag_key(key);
+    }
+    let mut buf = Vec::with_capacity(FLAT_TAG_PREFIX.len() + key.len());
+    buf.extend_from_slice(FLAT_TAG_PREFIX);
+    buf.extend_from_slice(key);
+    intern_tag_key(&buf)
+}
+
+/// Release every interned tag key. Dropped with the GIL held, so the
+/// references go immediately rather than through PyO3's deferred pool.
+fn release_tag_key_intern(_py: Python<'_>) {
+    if let Some(cache) = TAG_KEY_INTERN.get() {
+        cache.lock().unwrap().clear();
+    }
+}
+
+/// Registered with `atexit` at import: PyO3 has no hook for the module's
+/// `m_free`, and the strings must go before the interpreter finalizes.
+#[pyfunction]
+fn _release_interned_keys(py: Python<'_>) {
+    release_tag_key_intern(py);
 }
 
 // ---- Raw FFI helpers for fast dict population ----
@@ -3100,6 +7865,27 @@ unsafe fn set_dict_f64(dict: *mut pyo3::ffi::PyObject, key: *mut pyo3::ffi::PyOb
     pyo3::ffi::Py_DECREF(v);
 }
 
+/// Like `set_dict_f64`, storing `None` for a missing value.
+#[inline(always)]
+unsafe fn set_dict_opt_f64(dict: *mut pyo3::ffi::PyObject, key: *mut pyo3::ffi::PyObject, val: Option<f64>) {
+    match val {
+        Some(val) => set_dict_f64(dict, key, val),
+        None => {
+            pyo3::ffi::PyDict_SetItem(dict, key, pyo3::ffi::Py_None());
+        }
+    }
+}
+
+#[inline(always)]
+unsafe fn set_dict_opt_i64(dict: *mut pyo3::ffi::PyObject, key: *mut pyo3::ffi::PyObject, val: Option<i64>) {
+    match val {
+        Some(val) => set_dict_i64(dict, key, val),
+        None => {
+            pyo3::ffi::PyDict_SetItem(dict, key, pyo3::ffi::Py_None());
+        }
+    }
+}
+
 #[inline(always)]
 unsafe fn set_dict_u32(dict: *mut pyo3::ffi::PyObject, key: *mut pyo3::ffi::PyObject, val: u32) {
     let v = pyo3::ffi::PyLong_FromUnsignedLong(val as std::ffi::c_ulong);
@@ -3122,6 +7908,15 @@ unsafe fn set_dict_bool(dict: *mut pyo3::ffi::PyObject, key: *mut pyo3::ffi::PyO
     pyo3::ffi::Py_DECREF(v);
 }
 
+/// Set `_warnings` to the out-of-range header values (see
+/// `common::util::check_header_value`), if there were any.
+#[inline(always)]
+fn set_dict_warnings(py: Python<'_>, dict: &Bound<'_, PyDict>, warnings: &[String]) {
+    if !warnings.is_empty() {
+        let _ = dict.set_item(pyo3::intern!(py, "_warnings"), warnings);
+    }
+}
+
 #[inline(always)]
 #[allow(dead_code)]
 unsafe fn set_dict_str(dict: *mut pyo3::ffi::PyObject, key: *mut pyo3::ffi::PyObject, val: &str) {
@@ -3144,10 +7939,12 @@ unsafe fn try_text_frame_to_py(data: &[u8]) -> Option<*mut pyo3::ffi::PyObject>
     while len > 0 && text_data[len - 1] == 0 { len -= 1; }
     if len == 0 { return None; }
     let text = &text_data[..len];
+    // NUL-separated values: the full decode splits them into a list
+    if memchr::memchr(0, text).is_some() { return None; }
     match enc {
         3 | 0 => {
             // UTF-8 (3) or Latin-1 (0)
-            let has_high = enc == 0 && text.iter().any(|&b| b >= 128);
+            let has_high = enc == 0 && !text.is_ascii();
             let make_str = |s: &[u8]| -> *mut pyo3::ffi::PyObject {
                 if has_high {
                     pyo3::ffi::PyUnicode_DecodeLatin1(
@@ -3160,7 +7957,7 @@ unsafe fn try_text_frame_to_py(data: &[u8]) -> Option<*mut pyo3::ffi::PyObject>
                         s.len() as pyo3::ffi::Py_ssize_t)
                 }
             };
-            if enc == 3 && std::str::from_utf8(text).is_err() { return None; }
+            if enc == 3 && common::text::from_utf8(text).is_none() { return None; }
             let ptr = make_str(text);
             if ptr.is_null() { None } else { Some(ptr) }
         }
@@ -3168,44 +7965,17 @@ unsafe fn try_text_frame_to_py(data: &[u8]) -> Option<*mut pyo3::ffi::PyObject>
     }
 }
 
-/// Resolve a single TCON genre reference like "(3)", "35", or "(3)Dance" to a name.
-fn resolve_tcon_genre_single(text: &str) -> String {
-    let genres = crate::id3::specs::GENRES;
-    // Handle "(N)" prefix format
-    if text.starts_with('(') {
-        if let Some(end) = text.find(')') {
-            if let Ok(n) = text[1..end].parse::<usize>() {
-                let suffix = &text[end + 1..];
-                if !suffix.is_empty() {
-                    return suffix.to_string(); // "(3)Dance" → "Dance"
-                }
-                if n < genres.len() {
-                    return genres[n].to_string(); // "(3)" → "Dance"
-                }
-            }
-        }
-    }
-    // Handle bare number "35"
-    if let Ok(n) = text.parse::<usize>() {
-        if n < genres.len() {
-            return genres[n].to_string();
-        }
-    }
-    text.to_string()
-}
-
-/// Resolve TCON genre references, handling null-separated multi-value text.
-/// Returns the first resolved genre value.
-fn resolve_tcon_genre(text: &str) -> String {
-    // Handle null-separated multi-value TCON (ID3v2.4 uses \0 as separator)
-    if text.contains('\0') {
-        for part in text.split('\0') {
-            if !part.is_empty() {
-                return resolve_tcon_genre_single(part);
-            }
-        }
+/// TCON with every value expanded to genre names (`id3::specs::tcon_genres`),
+/// as the fast-read dict reports it. Other frames give `None`.
+fn resolve_tcon_frame(frame: &id3::frames::Frame) -> Option<id3::frames::Frame> {
+    match frame {
+        id3::frames::Frame::Text(tf) if tf.id == "TCON" => Some(id3::frames::Frame::Text(id3::frames::TextFrame {
+            id: tf.id.clone(),
+            encoding: tf.encoding,
+            text: tf.text.iter().flat_map(|t| id3::specs::tcon_genres(t)).collect(),
+        })),
+        _ => None,
     }
-    resolve_tcon_genre_single(text)
 }
 
 /// Walk v2.2 ID3 frames and emit directly to PyDict.
@@ -3254,41 +8024,19 @@ fn fast_walk_v22_frames(
             Some(id) => id,
             None => continue,
         };
-
-        // Fast text path with merge, TCON resolution, TYER→TDRC
-        if v24_id.as_bytes()[0] == b'T' && v24_id != "TXXX" && v24_id != "TIPL" && v24_id != "TMCL" && v24_id != "IPLS" {
-            unsafe {
-                if let Some(py_ptr) = try_text_frame_to_py(frame_data) {
-                    let v24_bytes = v24_id.as_bytes();
-                    // TCON: resolve genre references
-                    let final_ptr = if v24_bytes == b"TCON" {
-                        let py_str = pyo3::ffi::PyUnicode_AsUTF8(py_ptr);
-                        if !py_str.is_null() {
-                            let s = std::ffi::CStr::from_ptr(py_str).to_string_lossy();
-                            let resolved = resolve_tcon_genre(&s);
-                            let r = resolved.as_bytes();
-                            let new_ptr = pyo3::ffi::PyUnicode_FromStringAndSize(
-                                r.as_ptr() as *const std::ffi::c_char,
-                                r.len() as pyo3::ffi::Py_ssize_t);
-                            pyo3::ffi::Py_DECREF(py_ptr);
-                            new_ptr
-                        } else { py_ptr }
-                    } else { py_ptr };
-                    if final_ptr.is_null() { continue; }
-                    // TYER normalization: store as TDRC only (mutagen normalizes TYER to TDRC)
-                    let is_tyer = v24_bytes == b"TYER";
-                    let key_ptr = if is_tyer {
-                        let tdrc_key = intern_tag_key(b"TDRC");
-                        // If TDRC already exists, skip TYER entirely
-                        if pyo3::ffi::PyDict_Contains(dict_ptr, tdrc_key) != 0 {
-                            pyo3::ffi::Py_DECREF(tdrc_key);
-                            pyo3::ffi::Py_DECREF(final_ptr);
-                            continue;
-                        }
-                        tdrc_key
-                    } else {
-                        intern_tag_key(v24_bytes)
-                    };
+
+        // Fast text path with merge, TYER→TDRC; TCON takes the full decode for genre resolution
+        if v24_id.as_bytes()[0] == b'T' && v24_id != "TXXX" && v24_id != "TIPL" && v24_id != "TMCL" && v24_id != "IPLS"
+            && v24_id != "TCON" {
+            unsafe {
+                if let Some(py_ptr) = try_text_frame_to_py(frame_data) {
+                    let final_ptr = py_ptr;
+                    // v2.4 keys as mutagen reports them (TYER → TDRC, ...)
+                    let key_ptr = fast_id3_key(dict_ptr, v24_id, final_ptr);
+                    if key_ptr.is_null() {
+                        pyo3::ffi::Py_DECREF(final_ptr);
+                        continue;
+                    }
                     let existing = pyo3::ffi::PyDict_GetItem(dict_ptr, key_ptr);
                     if existing.is_null() {
                         pyo3::ffi::PyDict_SetItem(dict_ptr, key_ptr, final_ptr);
@@ -3315,10 +8063,12 @@ fn fast_walk_v22_frames(
 
         // Full decode fallback (skip if key exists)
         if let Ok(frame) = id3::frames::parse_frame(v24_id, frame_data) {
+            let frame = resolve_tcon_frame(&frame).unwrap_or(frame);
             let key = frame.hash_key();
             let py_val = frame_to_py(py, &frame);
             unsafe {
-                let key_ptr = intern_tag_key(key.as_str().as_bytes());
+                let key_ptr = fast_id3_key(dict_ptr, key.as_str(), py_val.as_ptr());
+                if key_ptr.is_null() { continue; }
                 if pyo3::ffi::PyDict_Contains(dict_ptr, key_ptr) == 0 {
                     pyo3::ffi::PyDict_SetItem(dict_ptr, key_ptr, py_val.as_ptr());
                     key_ptrs.push(key_ptr);
@@ -3330,6 +8080,47 @@ fn fast_walk_v22_frames(
     }
 }
 
+/// Interned dict key for an ID3 frame key, translated as mutagen's load-time
+/// `update_to_v24` does (see `id3::tags::v24_key`). Null when the frame is
+/// dropped, renamed to a frame the dict already has, or a TYER whose `value`
+/// is not a year (mutagen drops those). NEW reference.
+unsafe fn fast_id3_key(
+    dict_ptr: *mut pyo3::ffi::PyObject, key: &str, value: *mut pyo3::ffi::PyObject,
+) -> *mut pyo3::ffi::PyObject {
+    match id3::tags::v24_key(key) {
+        None => std::ptr::null_mut(),
+        Some(_) if key == "TYER" && !py_text_is_year(value) => std::ptr::null_mut(),
+        Some(v24) if v24 != key => {
+            let key_ptr = intern_tag_key(v24.as_bytes());
+            if !key_ptr.is_null() && pyo3::ffi::PyDict_Contains(dict_ptr, key_ptr) != 0 {
+                pyo3::ffi::Py_DECREF(key_ptr);
+                return std::ptr::null_mut();
+            }
+            key_ptr
+        }
+        Some(_) => intern_tag_key(key.as_bytes()),
+    }
+}
+
+/// Whether a text frame value (a str, or a list whose first item is one) is
+/// all digits, as a TYER needs to be for mutagen to turn it into TDRC.
+unsafe fn py_text_is_year(value: *mut pyo3::ffi::PyObject) -> bool {
+    let text = if pyo3::ffi::PyList_Check(value) != 0 {
+        if pyo3::ffi::PyList_Size(value) < 1 { return false; }
+        pyo3::ffi::PyList_GetItem(value, 0)
+    } else {
+        value
+    };
+    let mut len: pyo3::ffi::Py_ssize_t = 0;
+    let ptr = pyo3::ffi::PyUnicode_AsUTF8AndSize(text, &mut len);
+    if ptr.is_null() {
+        pyo3::ffi::PyErr_Clear();
+        return false;
+    }
+    let bytes = std::slice::from_raw_parts(ptr as *const u8, len as usize);
+    id3::tags::is_year(std::str::from_utf8_unchecked(bytes))
+}
+
 /// Walk v2.3/v2.4 ID3 frames and emit directly to PyDict.
 #[inline(always)]
 fn fast_walk_v2x_frames(
@@ -3369,36 +8160,18 @@ fn fast_walk_v2x_frames(
             let frame_data = &tag_bytes[*offset..*offset+size];
             *offset += size;
 
-            // Simple text frames: zero-alloc direct to Python
-            if id_bytes[0] == b'T' && id_str != "TXXX" && id_str != "TIPL" && id_str != "TMCL" && id_str != "IPLS" {
+            // Simple text frames: zero-alloc direct to Python (TCON goes through
+            // the full decode for genre resolution)
+            if id_bytes[0] == b'T' && id_str != "TXXX" && id_str != "TIPL" && id_str != "TMCL" && id_str != "IPLS"
+                && id_str != "TCON" {
                 unsafe {
                     if let Some(py_ptr) = try_text_frame_to_py(frame_data) {
-                        let final_ptr = if id_bytes == b"TCON" {
-                            let py_str = pyo3::ffi::PyUnicode_AsUTF8(py_ptr);
-                            if !py_str.is_null() {
-                                let s = std::ffi::CStr::from_ptr(py_str).to_string_lossy();
-                                let resolved = resolve_tcon_genre(&s);
-                                let r = resolved.as_bytes();
-                                let new_ptr = pyo3::ffi::PyUnicode_FromStringAndSize(
-                                    r.as_ptr() as *const std::ffi::c_char,
-                                    r.len() as pyo3::ffi::Py_ssize_t);
-                                pyo3::ffi::Py_DECREF(py_ptr);
-                                new_ptr
-                            } else { py_ptr }
-                        } else { py_ptr };
-                        if final_ptr.is_null() { continue; }
-                        let is_tyer = id_bytes == b"TYER";
-                        let key_ptr = if is_tyer {
-                            let tdrc_key = intern_tag_key(b"TDRC");
-                            if pyo3::ffi::PyDict_Contains(dict_ptr, tdrc_key) != 0 {
-                                pyo3::ffi::Py_DECREF(tdrc_key);
-                                pyo3::ffi::Py_DECREF(final_ptr);
-                                continue;
-                            }
-                            tdrc_key
-                        } else {
-                            intern_tag_key(id_bytes)
-                        };
+                        let final_ptr = py_ptr;
+                        let key_ptr = fast_id3_key(dict_ptr, id_str, final_ptr);
+                        if key_ptr.is_null() {
+                            pyo3::ffi::Py_DECREF(final_ptr);
+                            continue;
+                        }
                         let existing = pyo3::ffi::PyDict_GetItem(dict_ptr, key_ptr);
                         if existing.is_null() {
                             pyo3::ffi::PyDict_SetItem(dict_ptr, key_ptr, final_ptr);
@@ -3427,7 +8200,7 @@ fn fast_walk_v2x_frames(
             if id_bytes[0] == b'W' && id_str != "WXXX" {
                 let mut flen = frame_data.len();
                 while flen > 0 && frame_data[flen-1] == 0 { flen -= 1; }
-                if flen > 0 && frame_data[..flen].iter().all(|&b| b < 128) {
+                if flen > 0 && frame_data[..flen].is_ascii() {
                     unsafe {
                         let py_ptr = pyo3::ffi::PyUnicode_FromStringAndSize(
                             frame_data.as_ptr() as *const std::ffi::c_char, flen as pyo3::ffi::Py_ssize_t);
@@ -3484,37 +8257,14 @@ fn emit_frame_to_dict(
     dict_ptr: *mut pyo3::ffi::PyObject, key_ptrs: &mut Vec<*mut pyo3::ffi::PyObject>,
 ) {
     // TCON genre resolution
-    let frame_ref;
-    let resolved_frame;
-    let actual_frame = if id_str == "TCON" {
-        if let id3::frames::Frame::Text(tf) = frame {
-            let resolved_text: Vec<String> = tf.text.iter()
-                .map(|t| resolve_tcon_genre(t))
-                .collect();
-            resolved_frame = id3::frames::Frame::Text(id3::frames::TextFrame {
-                id: tf.id.clone(),
-                encoding: tf.encoding,
-                text: resolved_text,
-            });
-            frame_ref = &resolved_frame;
-            frame_ref
-        } else { frame }
-    } else { frame };
+    let resolved = if id_str == "TCON" { resolve_tcon_frame(frame) } else { None };
+    let actual_frame = resolved.as_ref().unwrap_or(frame);
 
     let key = actual_frame.hash_key();
     let py_val = frame_to_py(py, actual_frame);
     unsafe {
-        // TYER normalization: store as TDRC only if TDRC not already present
-        let key_ptr = if id_str == "TYER" {
-            let tdrc_key = intern_tag_key(b"TDRC");
-            if pyo3::ffi::PyDict_Contains(dict_ptr, tdrc_key) != 0 {
-                pyo3::ffi::Py_DECREF(tdrc_key);
-                return; // TDRC already exists, skip TYER
-            }
-            tdrc_key
-        } else {
-            intern_tag_key(key.as_str().as_bytes())
-        };
+        let key_ptr = fast_id3_key(dict_ptr, key.as_str(), py_val.as_ptr());
+        if key_ptr.is_null() { return; }
         let existing = pyo3::ffi::PyDict_GetItem(dict_ptr, key_ptr);
         if existing.is_null() {
             pyo3::ffi::PyDict_SetItem(dict_ptr, key_ptr, py_val.as_ptr());
@@ -3596,7 +8346,7 @@ fn parse_vc_to_dict_direct<'py>(
             let key_len = key_bytes.len().min(128);
             for i in 0..key_len { buf[i] = key_bytes[i].to_ascii_lowercase(); }
 
-            let key_ptr = intern_tag_key(&buf[..key_len]);
+            let key_ptr = flat_tag_key(&buf[..key_len]);
             if key_ptr.is_null() { pyo3::ffi::PyErr_Clear(); continue; }
 
             // Create value PyUnicode directly from raw bytes (CPython validates UTF-8)
@@ -3687,25 +8437,32 @@ fn fast_read_flac_direct<'py>(py: Python<'py>, data: &[u8], file_size: usize, di
         if is_last { break; }
     }
 
-    // pos now points to the start of audio frames (after all metadata blocks)
+    // pos now points to the start of audio frames (after all metadata blocks).
+    // Without STREAMINFO the tags are still returned, with no length.
     let si = match streaminfo {
         Some(si) => si,
+        None if vc_data.is_some() => flac::StreamInfo::default(),
         None => return Ok(false),
     };
     // Bitrate: use audio data size only (exclude metadata), matching mutagen behavior
     let audio_data_size = file_size.saturating_sub(pos);
-    let bitrate = if si.length > 0.0 {
-        (audio_data_size as f64 * 8.0 / si.length) as u32
-    } else { 0 };
+    let bitrate = match si.length {
+        Some(length) if length > 0.0 => (audio_data_size as f64 * 8.0 / length) as u32,
+        _ => 0,
+    };
     let dict_ptr = dict.as_ptr();
     unsafe {
-        set_dict_f64(dict_ptr, pyo3::intern!(py, "length").as_ptr(), si.length);
+        set_dict_opt_f64(dict_ptr, pyo3::intern!(py, "length").as_ptr(), si.length);
+        set_dict_opt_i64(dict_ptr, pyo3::intern!(py, "length_samples").as_ptr(),
+            (si.total_samples > 0).then_some(si.total_samples as i64));
+        set_dict_opt_i64(dict_ptr, pyo3::intern!(py, "duration_ms").as_ptr(), si.duration_ms.map(|ms| ms as i64));
         set_dict_u32(dict_ptr, pyo3::intern!(py, "sample_rate").as_ptr(), si.sample_rate);
         set_dict_u32(dict_ptr, pyo3::intern!(py, "channels").as_ptr(), si.channels as u32);
         set_dict_u32(dict_ptr, pyo3::intern!(py, "bits_per_sample").as_ptr(), si.bits_per_sample as u32);
         set_dict_i64(dict_ptr, pyo3::intern!(py, "total_samples").as_ptr(), si.total_samples as i64);
         set_dict_u32(dict_ptr, pyo3::intern!(py, "bitrate").as_ptr(), bitrate);
     }
+    set_dict_warnings(py, dict, &si.warnings);
 
     let mut keys_out: Vec<*mut pyo3::ffi::PyObject> = Vec::with_capacity(16);
     if let Some(vc) = vc_data {
@@ -3716,23 +8473,16 @@ fn fast_read_flac_direct<'py>(py: Python<'py>, data: &[u8], file_size: usize, di
     if !picture_blocks.is_empty() {
         let pics = PyList::empty(py);
         for (pic_pos, pic_size) in &picture_blocks {
-            if let Ok(pic) = flac::FLACPicture::parse(&data[*pic_pos..*pic_pos + *pic_size]) {
-                let d = PyDict::new(py);
-                let _ = d.set_item("type", pic.pic_type);
-                let _ = d.set_item("mime", &pic.mime);
-                let _ = d.set_item("desc", &pic.desc);
-                let _ = d.set_item("width", pic.width);
-                let _ = d.set_item("height", pic.height);
-                let _ = d.set_item("depth", pic.depth);
-                let _ = d.set_item("colors", pic.colors);
-                let _ = d.set_item("data", pyo3::types::PyBytes::new(py, &pic.data));
-                let _ = pics.append(d);
+            let block = &data[*pic_pos..*pic_pos + *pic_size];
+            if let Ok(header) = flac::parse_picture_header(block) {
+                let _ = pics.append(flac_picture_dict(py, &header, block));
             }
         }
         let _ = dict.set_item(pyo3::intern!(py, "_pictures"), pics);
     }
 
     set_keys_list(py, dict, keys_out)?;
+    let _ = dict.set_item(pyo3::intern!(py, "tag_version"), vc_data.map(|_| vorbis::TAG_VERSION));
     unsafe {
         let fmt = pyo3::ffi::PyUnicode_InternFromString(b"flac\0".as_ptr() as *const std::ffi::c_char);
         pyo3::ffi::PyDict_SetItem(dict.as_ptr(), pyo3::intern!(py, "_format").as_ptr(), fmt);
@@ -3758,8 +8508,9 @@ fn fast_read_ogg_direct<'py>(py: Python<'py>, data: &[u8], dict: &Bound<'py, PyD
     let id_data = &data[seg_table_end..];
     if id_data.len() < 30 || &id_data[0..7] != b"\x01vorbis" { return Ok(false); }
 
-    let channels = id_data[11];
-    let sample_rate = u32::from_le_bytes([id_data[12], id_data[13], id_data[14], id_data[15]]);
+    let mut warnings = Vec::new();
+    let (channels, sample_rate) = ogg::check_ident_values(
+        id_data[11], u32::from_le_bytes([id_data[12], id_data[13], id_data[14], id_data[15]]), &mut warnings);
     let nominal_bitrate = u32::from_le_bytes([id_data[20], id_data[21], id_data[22], id_data[23]]);
 
     if first_page_end + 27 > data.len() { return Ok(false); }
@@ -3779,23 +8530,27 @@ fn fast_read_ogg_direct<'py>(py: Python<'py>, data: &[u8], dict: &Bound<'py, PyD
         if seg < 255 { single_page = true; break; }
     }
 
-    let length = ogg::find_last_granule(data, serial)
-        .map(|g| if g > 0 && sample_rate > 0 { g as f64 / sample_rate as f64 } else { 0.0 })
-        .unwrap_or(0.0);
+    let vl = ogg::vorbis_length(data, serial, sample_rate, nominal_bitrate);
 
     let bitrate = if nominal_bitrate > 0 {
         nominal_bitrate
-    } else if length > 0.0 {
-        (data.len() as f64 * 8.0 / length) as u32
+    } else if vl.length > 0.0 {
+        (data.len() as f64 * 8.0 / vl.length) as u32
     } else { 0 };
 
     let dict_ptr_ogg = dict.as_ptr();
     unsafe {
-        set_dict_f64(dict_ptr_ogg, pyo3::intern!(py, "length").as_ptr(), length);
-        set_dict_u32(dict_ptr_ogg, pyo3::intern!(py, "sample_rate").as_ptr(), sample_rate);
+        set_dict_f64(dict_ptr_ogg, pyo3::intern!(py, "length").as_ptr(), vl.length);
+        set_dict_i64(dict_ptr_ogg, pyo3::intern!(py, "length_samples").as_ptr(), vl.length_samples as i64);
+        set_dict_i64(dict_ptr_ogg, pyo3::intern!(py, "duration_ms").as_ptr(), vl.duration_ms as i64);
+        set_dict_u32(dict_ptr_ogg, pyo3::intern!(py, "sample_rate").as_ptr(), vl.sample_rate);
+        set_dict_bool(dict_ptr_ogg, pyo3::intern!(py, "recovered").as_ptr(), vl.recovered);
         set_dict_u32(dict_ptr_ogg, pyo3::intern!(py, "channels").as_ptr(), channels as u32);
         set_dict_u32(dict_ptr_ogg, pyo3::intern!(py, "bitrate").as_ptr(), bitrate);
+        set_dict_u32(dict_ptr_ogg, pyo3::intern!(py, "serial").as_ptr(), serial);
+        set_dict_u32(dict_ptr_ogg, pyo3::intern!(py, "page_count").as_ptr(), ogg::count_pages(data, serial));
     }
+    set_dict_warnings(py, dict, &warnings);
 
     let mut keys_out: Vec<*mut pyo3::ffi::PyObject> = Vec::with_capacity(16);
     if single_page {
@@ -3817,6 +8572,7 @@ fn fast_read_ogg_direct<'py>(py: Python<'py>, data: &[u8], dict: &Bound<'py, PyD
         parse_vc_to_dict_direct(py, &comment_packet[7..], dict, &mut keys_out)?;
     }
     set_keys_list(py, dict, keys_out)?;
+    let _ = dict.set_item(pyo3::intern!(py, "tag_version"), vorbis::TAG_VERSION);
     unsafe {
         let fmt = pyo3::ffi::PyUnicode_InternFromString(b"ogg\0".as_ptr() as *const std::ffi::c_char);
         pyo3::ffi::PyDict_SetItem(dict.as_ptr(), pyo3::intern!(py, "_format").as_ptr(), fmt);
@@ -3825,6 +8581,43 @@ fn fast_read_ogg_direct<'py>(py: Python<'py>, data: &[u8], dict: &Bound<'py, PyD
     Ok(true)
 }
 
+/// Ogg Opus info fields shared by `_fast_read` and `_fast_info`.
+fn set_opus_info(py: Python<'_>, info: &ogg::opus::OpusInfo, dict: &Bound<'_, PyDict>) {
+    let dict_ptr = dict.as_ptr();
+    unsafe {
+        set_dict_f64(dict_ptr, pyo3::intern!(py, "length").as_ptr(), info.length);
+        set_dict_i64(dict_ptr, pyo3::intern!(py, "length_samples").as_ptr(), info.length_samples as i64);
+        set_dict_i64(dict_ptr, pyo3::intern!(py, "duration_ms").as_ptr(), info.duration_ms as i64);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "sample_rate").as_ptr(), ogg::opus::GRANULE_RATE);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "input_sample_rate").as_ptr(), info.input_sample_rate);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "pre_skip").as_ptr(), info.pre_skip as u32);
+        set_dict_f64(dict_ptr, pyo3::intern!(py, "output_gain").as_ptr(), info.output_gain as f64 / 256.0);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "channels").as_ptr(), info.channels as u32);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "bitrate").as_ptr(), info.bitrate);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "serial").as_ptr(), info.serial);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "page_count").as_ptr(), info.page_count);
+    }
+}
+
+/// Direct Ogg Opus → PyDict.
+fn fast_read_opus_direct<'py>(py: Python<'py>, data: &[u8], dict: &Bound<'py, PyDict>) -> PyResult<bool> {
+    let f = match ogg::opus::OpusFile::parse(data, "") {
+        Ok(f) => f,
+        Err(_) => return Ok(false),
+    };
+    set_opus_info(py, &f.info, dict);
+    let mut keys_out: Vec<*mut pyo3::ffi::PyObject> = Vec::with_capacity(16);
+    parse_vc_to_dict_direct(py, f.raw_comments(), dict, &mut keys_out)?;
+    set_keys_list(py, dict, keys_out)?;
+    let _ = dict.set_item(pyo3::intern!(py, "tag_version"), vorbis::TAG_VERSION);
+    unsafe {
+        let fmt = pyo3::ffi::PyUnicode_InternFromString(c"opus".as_ptr());
+        pyo3::ffi::PyDict_SetItem(dict.as_ptr(), pyo3::intern!(py, "_format").as_ptr(), fmt);
+        pyo3::ffi::Py_DECREF(fmt);
+    }
+    Ok(true)
+}
+
 /// Direct MP3 → PyDict: inline ID3 frame walking with zero-alloc text frame decoding.
 /// Eliminates raw_buf copy, LazyFrame allocation, and Rust String allocation for text frames.
 #[inline(always)]
@@ -3857,6 +8650,8 @@ fn fast_read_mp3_direct<'py>(py: Python<'py>, data: &[u8], _path: &str, dict: &B
     let dict_ptr = dict.as_ptr();
     unsafe {
         set_dict_f64(dict_ptr, pyo3::intern!(py, "length").as_ptr(), info.length);
+        set_dict_i64(dict_ptr, pyo3::intern!(py, "length_samples").as_ptr(), info.length_samples as i64);
+        set_dict_i64(dict_ptr, pyo3::intern!(py, "duration_ms").as_ptr(), info.duration_ms as i64);
         set_dict_u32(dict_ptr, pyo3::intern!(py, "sample_rate").as_ptr(), info.sample_rate);
         set_dict_u32(dict_ptr, pyo3::intern!(py, "channels").as_ptr(), info.channels);
         set_dict_u32(dict_ptr, pyo3::intern!(py, "bitrate").as_ptr(), info.bitrate);
@@ -3870,6 +8665,9 @@ fn fast_read_mp3_direct<'py>(py: Python<'py>, data: &[u8], _path: &str, dict: &B
             mp3::xing::BitrateMode::VBR => 2,
             mp3::xing::BitrateMode::ABR => 3,
         });
+        set_dict_str(dict_ptr, pyo3::intern!(py, "encoder_info").as_ptr(), &info.encoder_info);
+        set_dict_str(dict_ptr, pyo3::intern!(py, "encoder_settings").as_ptr(), &info.encoder_settings);
+        set_dict_opt_i64(dict_ptr, pyo3::intern!(py, "xing_frames").as_ptr(), info.xing_frames.map(i64::from));
     }
 
     // 4. Walk ID3v2 frames directly (no LazyFrame/ID3Tags intermediary)
@@ -3900,7 +8698,7 @@ fn fast_read_mp3_direct<'py>(py: Python<'py>, data: &[u8], _path: &str, dict: &B
             offset = if version == 4 { ext_size } else { ext_size + 4 };
         }
 
-        let bpi = if version == 4 {
+        let bpi = if version == 4 && offset <= tag_bytes.len() {
             id3::header::determine_bpi(&tag_bytes[offset..], tag_bytes.len())
         } else { 8 };
 
@@ -3912,9 +8710,11 @@ fn fast_read_mp3_direct<'py>(py: Python<'py>, data: &[u8], _path: &str, dict: &B
     }
 
     // 5. Check for ID3v1 at file end
+    let mut id3v1 = false;
     if data.len() >= 128 {
         let v1_data = &data[data.len() - 128..];
         if v1_data.len() >= 3 && &v1_data[0..3] == b"TAG" {
+            id3v1 = true;
             if let Ok(v1_frames) = id3::id3v1::parse_id3v1(v1_data) {
                 for frame in v1_frames {
                     let key = frame.hash_key();
@@ -3934,13 +8734,39 @@ fn fast_read_mp3_direct<'py>(py: Python<'py>, data: &[u8], _path: &str, dict: &B
         }
     }
 
+    // 6. SYLT timestamps counting MPEG frames, in milliseconds
+    let is_sylt = |k: *mut pyo3::ffi::PyObject| unsafe {
+        let mut len: pyo3::ffi::Py_ssize_t = 0;
+        let p = pyo3::ffi::PyUnicode_AsUTF8AndSize(k, &mut len);
+        !p.is_null() && len >= 4 && std::slice::from_raw_parts(p as *const u8, 4) == b"SYLT"
+    };
+    if key_ptrs.iter().any(|&k| is_sylt(k)) {
+        let keys: Vec<String> = key_ptrs.iter()
+            .filter(|&&k| is_sylt(k))
+            .filter_map(|&k| unsafe { Bound::from_borrowed_ptr(py, k) }.extract().ok())
+            .collect();
+        sylt_values_to_ms(dict, keys.iter().map(String::as_str), info.frame_ms())?;
+    }
+
+    // 7. APEv2 items whose field no ID3 frame holds
+    let mut ape_items = false;
+    if let Some(ape_tag) = ape::apev2::parse(data) {
+        let id3_keys: Vec<String> = key_ptrs.iter()
+            .filter_map(|&k| unsafe { Bound::from_borrowed_ptr(py, k) }.extract().ok())
+            .collect();
+        let extra = ape::apev2::uncovered_by_id3(&ape_tag, &id3_keys);
+        ape_items = !extra.is_empty();
+        fast_set_ape_items(py, extra.into_iter(), dict, &mut key_ptrs)?;
+    }
+
     set_keys_list(py, dict, key_ptrs)?;
+    let _ = dict.set_item(pyo3::intern!(py, "tag_version"), mp3::tag_version(id3_header.as_ref(), id3v1));
     unsafe {
         let fmt = pyo3::ffi::PyUnicode_InternFromString(b"mp3\0".as_ptr() as *const std::ffi::c_char);
         pyo3::ffi::PyDict_SetItem(dict.as_ptr(), pyo3::intern!(py, "_format").as_ptr(), fmt);
         pyo3::ffi::Py_DECREF(fmt);
-        // _has_tags: true only if an ID3 header was found
-        let has_tags = if id3_header.is_some() { pyo3::ffi::Py_True() } else { pyo3::ffi::Py_False() };
+        // _has_tags: true if an ID3 header was found or APEv2 items were merged in
+        let has_tags = if id3_header.is_some() || ape_items { pyo3::ffi::Py_True() } else { pyo3::ffi::Py_False() };
         pyo3::ffi::PyDict_SetItem(dict.as_ptr(), pyo3::intern!(py, "_has_tags").as_ptr(), has_tags);
     }
     Ok(true)
@@ -3980,10 +8806,13 @@ fn fast_read_mp4_direct<'py>(py: Python<'py>, data: &[u8], _path: &str, dict: &B
 
     // 3. Find audio track for codec/channels/sample_rate
     let mut channels = 2u32;
+    let mut channels_source = "stsd";
     let mut sample_rate = 44100u32;
     let mut bits_per_sample = 16u32;
     let mut codec_bytes: [u8; 4] = *b"mp4a";
+    let mut drm = false;
     let mut esds_bitrate = 0u32;
+    let mut mdhd = None;
 
     'trak_loop: for trak in AtomIter::new(data, moov_s, moov_e) {
         if trak.name != *b"trak" { continue; }
@@ -4002,6 +8831,8 @@ fn fast_read_mp4_direct<'py>(py: Python<'py>, data: &[u8], _path: &str, dict: &B
             } else { false }
         });
         if !is_audio { continue; }
+        mdhd = AtomIter::new(data, mdia_s, mdia_e).find_name(b"mdhd")
+            .and_then(|a| mp4::parse_mdhd(&data[a.data_offset..a.data_offset + a.data_size]));
         let minf = match AtomIter::new(data, mdia_s, mdia_e).find_name(b"minf") {
             Some(a) => a, None => continue,
         };
@@ -4017,6 +8848,7 @@ fn fast_read_mp4_direct<'py>(py: Python<'py>, data: &[u8], _path: &str, dict: &B
             if entry_data.len() >= 36 {
                 let entry_size = u32::from_be_bytes([entry_data[0], entry_data[1], entry_data[2], entry_data[3]]) as usize;
                 codec_bytes.copy_from_slice(&entry_data[4..8]);
+                drm = mp4::is_audible_drm_entry(data, stsd.data_offset, entry_size);
                 let audio_entry = &entry_data[8..];
                 if audio_entry.len() >= 20 {
                     channels = u16::from_be_bytes([audio_entry[16], audio_entry[17]]) as u32;
@@ -4025,24 +8857,21 @@ fn fast_read_mp4_direct<'py>(py: Python<'py>, data: &[u8], _path: &str, dict: &B
                         sample_rate = u16::from_be_bytes([audio_entry[24], audio_entry[25]]) as u32;
                     }
                 }
-                // Look for esds sub-atom for accurate bitrate
+                // Look for esds sub-atom for accurate bitrate and channels
                 if entry_size > 36 && audio_entry.len() >= entry_size - 8 {
-                    let sub_start = stsd.data_offset + 8 + 8 + 28;
-                    let sub_end = stsd.data_offset + 8 + entry_size;
-                    if sub_end <= data.len() {
-                        for sub in AtomIter::new(data, sub_start, sub_end) {
-                            if sub.name == *b"esds" {
-                                let esds = &data[sub.data_offset..sub.data_offset + sub.data_size];
-                                esds_bitrate = mp4::parse_esds_bitrate(esds);
-                                break;
-                            }
-                        }
+                    if let Some(esds) = mp4::find_stsd_esds(data, stsd.data_offset, entry_size) {
+                        esds_bitrate = mp4::parse_esds_bitrate(esds);
+                        (channels, channels_source) = mp4::resolve_channels(channels, mp4::parse_esds_channels(esds));
                     }
                 }
             }
         }
         break 'trak_loop;
     }
+    let mut warnings = Vec::new();
+    let (channels, bits_per_sample, sample_rate) =
+        mp4::check_audio_entry(channels, bits_per_sample, sample_rate, &mut warnings);
+    set_dict_warnings(py, dict, &warnings);
 
     let bitrate = if esds_bitrate > 0 {
         esds_bitrate
@@ -4054,8 +8883,13 @@ fn fast_read_mp4_direct<'py>(py: Python<'py>, data: &[u8], _path: &str, dict: &B
     let dict_ptr = dict.as_ptr();
     unsafe {
         set_dict_f64(dict_ptr, pyo3::intern!(py, "length").as_ptr(), length);
+        set_dict_i64(dict_ptr, pyo3::intern!(py, "length_samples").as_ptr(),
+            mp4::length_samples(mdhd, length, sample_rate) as i64);
+        set_dict_i64(dict_ptr, pyo3::intern!(py, "duration_ms").as_ptr(),
+            common::util::duration_ms(duration, timescale as u64) as i64);
         set_dict_u32(dict_ptr, pyo3::intern!(py, "sample_rate").as_ptr(), sample_rate);
         set_dict_u32(dict_ptr, pyo3::intern!(py, "channels").as_ptr(), channels);
+        set_dict_str(dict_ptr, pyo3::intern!(py, "channels_source").as_ptr(), channels_source);
         set_dict_u32(dict_ptr, pyo3::intern!(py, "bitrate").as_ptr(), bitrate);
         set_dict_u32(dict_ptr, pyo3::intern!(py, "bits_per_sample").as_ptr(), bits_per_sample);
         // Codec: create Python string directly from 4 bytes (no Rust String)
@@ -4063,6 +8897,9 @@ fn fast_read_mp4_direct<'py>(py: Python<'py>, data: &[u8], _path: &str, dict: &B
             codec_bytes.as_ptr() as *const std::ffi::c_char, 4);
         pyo3::ffi::PyDict_SetItem(dict_ptr, pyo3::intern!(py, "codec").as_ptr(), codec_ptr);
         pyo3::ffi::Py_DECREF(codec_ptr);
+        if drm {
+            set_dict_str(dict_ptr, pyo3::intern!(py, "codec_description").as_ptr(), "Audible AAX");
+        }
     }
 
     // 5. Walk ilst and convert tags directly to Python (no MP4Tags intermediate)
@@ -4075,6 +8912,7 @@ fn fast_read_mp4_direct<'py>(py: Python<'py>, data: &[u8], _path: &str, dict: &B
             if meta_off < meta_end {
                 if let Some(ilst) = AtomIter::new(data, meta_off, meta_end).find_name(b"ilst") {
                     for item in AtomIter::new(data, ilst.data_offset, ilst.data_offset + ilst.data_size) {
+                        if item.is_truncated() { break; }
                         // For freeform atoms (----), build key from mean+name sub-atoms
                         let key_ptr = if item.name == *b"----" {
                             let freeform_key = mp4::build_freeform_key(data, item.data_offset, item.data_offset + item.data_size);
@@ -4089,7 +8927,30 @@ fn fast_read_mp4_direct<'py>(py: Python<'py>, data: &[u8], _path: &str, dict: &B
                         };
                         if key_ptr.is_null() { continue; }
 
-                        // Find first "data" atom and convert value directly to Python
+                        // Freeform items: every data atom as a (bytes, dataformat) tuple
+                        if item.name == *b"----" {
+                            let forms = PyList::empty(py);
+                            for da in AtomIter::new(data, item.data_offset, item.data_offset + item.data_size) {
+                                if da.name != *b"data" { continue; }
+                                let ad = &data[da.data_offset..da.data_offset + da.data_size];
+                                if ad.len() < 8 { continue; }
+                                let type_ind = u32::from_be_bytes([ad[0], ad[1], ad[2], ad[3]]);
+                                forms.append((PyBytes::new(py, &ad[8..]), type_ind))?;
+                            }
+                            unsafe {
+                                if !forms.is_empty() && pyo3::ffi::PyDict_Contains(dict_ptr, key_ptr) == 0 {
+                                    pyo3::ffi::PyDict_SetItem(dict_ptr, key_ptr, forms.as_ptr());
+                                    key_ptrs.push(key_ptr);
+                                } else {
+                                    pyo3::ffi::Py_DECREF(key_ptr);
+                                }
+                            }
+                            continue;
+                        }
+
+                        // Convert each "data" atom directly to Python; an item with
+                        // several (e.g. one per locale) becomes a list, not just the first.
+                        let mut vals: Vec<*mut pyo3::ffi::PyObject> = Vec::new();
                         for da in AtomIter::new(data, item.data_offset, item.data_offset + item.data_size) {
                             if da.name != *b"data" { continue; }
                             let ad = &data[da.data_offset..da.data_offset + da.data_size];
@@ -4099,19 +8960,30 @@ fn fast_read_mp4_direct<'py>(py: Python<'py>, data: &[u8], _path: &str, dict: &B
 
                             let py_val = unsafe { mp4_data_to_py_raw(py, &item.name, type_ind, vd) };
                             if !py_val.is_null() {
-                                unsafe {
-                                    if pyo3::ffi::PyDict_Contains(dict_ptr, key_ptr) == 0 {
-                                        pyo3::ffi::PyDict_SetItem(dict_ptr, key_ptr, py_val);
-                                        key_ptrs.push(key_ptr);
-                                    } else {
-                                        pyo3::ffi::Py_DECREF(key_ptr);
+                                vals.push(py_val);
+                            }
+                        }
+                        unsafe {
+                            let py_val = match vals.len() {
+                                0 => std::ptr::null_mut(),
+                                1 => vals[0],
+                                n => {
+                                    let list = pyo3::ffi::PyList_New(n as pyo3::ffi::Py_ssize_t);
+                                    for (i, v) in vals.into_iter().enumerate() {
+                                        pyo3::ffi::PyList_SET_ITEM(list, i as pyo3::ffi::Py_ssize_t, v);
                                     }
-                                    pyo3::ffi::Py_DECREF(py_val);
+                                    list
                                 }
+                            };
+                            if !py_val.is_null() && pyo3::ffi::PyDict_Contains(dict_ptr, key_ptr) == 0 {
+                                pyo3::ffi::PyDict_SetItem(dict_ptr, key_ptr, py_val);
+                                key_ptrs.push(key_ptr);
                             } else {
-                                unsafe { pyo3::ffi::Py_DECREF(key_ptr); }
+                                pyo3::ffi::Py_DECREF(key_ptr);
+                            }
+                            if !py_val.is_null() {
+                                pyo3::ffi::Py_DECREF(py_val);
                             }
-                            break; // Only first data atom per item
                         }
                     }
                 }
@@ -4120,6 +8992,7 @@ fn fast_read_mp4_direct<'py>(py: Python<'py>, data: &[u8], _path: &str, dict: &B
     }
 
     set_keys_list(py, dict, key_ptrs)?;
+    let _ = dict.set_item(pyo3::intern!(py, "tag_version"), mp4::meta_handler_type(data, moov_s, moov_e));
     unsafe {
         let fmt = pyo3::ffi::PyUnicode_InternFromString(b"mp4\0".as_ptr() as *const std::ffi::c_char);
         pyo3::ffi::PyDict_SetItem(dict.as_ptr(), pyo3::intern!(py, "_format").as_ptr(), fmt);
@@ -4204,18 +9077,18 @@ unsafe fn mp4_data_to_py_raw(_py: Python<'_>, atom_name: &[u8; 4], type_ind: u32
 
 // ---- Info-only parsers: parse audio metadata without creating tag Python objects ----
 
-/// FLAC info only: just StreamInfo, skip VorbisComment.
+/// Locate and parse the STREAMINFO block, skipping a leading ID3 tag.
 #[inline(always)]
-fn fast_info_flac<'py>(py: Python<'py>, data: &[u8], dict: &Bound<'py, PyDict>) -> PyResult<bool> {
+fn info_flac_streaminfo(data: &[u8]) -> Option<flac::StreamInfo> {
     let flac_offset = if data.len() >= 4 && &data[0..4] == b"fLaC" {
         0
     } else if data.len() >= 10 && &data[0..3] == b"ID3" {
         let size = id3::header::BitPaddedInt::syncsafe(&data[6..10]) as usize;
         let off = 10 + size;
-        if off + 4 > data.len() || &data[off..off+4] != b"fLaC" { return Ok(false); }
+        if off + 4 > data.len() || &data[off..off+4] != b"fLaC" { return None; }
         off
     } else {
-        return Ok(false);
+        return None;
     };
     let mut pos = flac_offset + 4;
     loop {
@@ -4228,89 +9101,356 @@ fn fast_info_flac<'py>(py: Python<'py>, data: &[u8], dict: &Bound<'py, PyDict>)
         if pos + block_size > data.len() { break; }
         if bt == 0 {
             if let Ok(si) = flac::StreamInfo::parse(&data[pos..pos+block_size]) {
-                let dict_ptr = dict.as_ptr();
-                unsafe {
-                    set_dict_f64(dict_ptr, pyo3::intern!(py, "length").as_ptr(), si.length);
-                    set_dict_u32(dict_ptr, pyo3::intern!(py, "sample_rate").as_ptr(), si.sample_rate);
-                    set_dict_u32(dict_ptr, pyo3::intern!(py, "channels").as_ptr(), si.channels as u32);
-                    set_dict_u32(dict_ptr, pyo3::intern!(py, "bits_per_sample").as_ptr(), si.bits_per_sample as u32);
-                    set_dict_i64(dict_ptr, pyo3::intern!(py, "total_samples").as_ptr(), si.total_samples as i64);
-                }
-                return Ok(true);
+                return Some(si);
             }
         }
         pos += block_size;
         if is_last { break; }
     }
-    Ok(false)
+    None
 }
 
-/// OGG info only: parse identification header + last granule, skip VorbisComment.
+/// FLAC info only: just StreamInfo, skip VorbisComment.
 #[inline(always)]
-fn fast_info_ogg<'py>(py: Python<'py>, data: &[u8], dict: &Bound<'py, PyDict>) -> PyResult<bool> {
-    if data.len() < 58 || &data[0..4] != b"OggS" { return Ok(false); }
+fn fast_info_flac<'py>(py: Python<'py>, data: &[u8], dict: &Bound<'py, PyDict>) -> PyResult<bool> {
+    let Some(si) = info_flac_streaminfo(data) else { return Ok(false) };
+    let dict_ptr = dict.as_ptr();
+    unsafe {
+        set_dict_opt_f64(dict_ptr, pyo3::intern!(py, "length").as_ptr(), si.length);
+        set_dict_opt_i64(dict_ptr, pyo3::intern!(py, "length_samples").as_ptr(),
+            (si.total_samples > 0).then_some(si.total_samples as i64));
+        set_dict_opt_i64(dict_ptr, pyo3::intern!(py, "duration_ms").as_ptr(),
+            si.duration_ms.map(|ms| ms as i64));
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "sample_rate").as_ptr(), si.sample_rate);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "channels").as_ptr(), si.channels as u32);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "bits_per_sample").as_ptr(), si.bits_per_sample as u32);
+        set_dict_i64(dict_ptr, pyo3::intern!(py, "total_samples").as_ptr(), si.total_samples as i64);
+    }
+    set_dict_warnings(py, dict, &si.warnings);
+    Ok(true)
+}
+
+/// Channel count and length of an Ogg Vorbis stream from its identification
+/// header and last granule, plus any out-of-range header warnings.
+#[inline(always)]
+fn info_ogg_vorbis(data: &[u8]) -> Option<(u8, ogg::VorbisLength, Vec<String>)> {
+    if data.len() < 58 || &data[0..4] != b"OggS" { return None; }
     let serial = u32::from_le_bytes([data[14], data[15], data[16], data[17]]);
     let num_seg = data[26] as usize;
     let seg_table_end = 27 + num_seg;
-    if seg_table_end + 30 > data.len() { return Ok(false); }
+    if seg_table_end + 30 > data.len() { return None; }
     let id_data = &data[seg_table_end..];
-    if id_data.len() < 30 || &id_data[0..7] != b"\x01vorbis" { return Ok(false); }
-    let channels = id_data[11];
-    let sample_rate = u32::from_le_bytes([id_data[12], id_data[13], id_data[14], id_data[15]]);
-    let length = ogg::find_last_granule(data, serial)
-        .map(|g| if g > 0 && sample_rate > 0 { g as f64 / sample_rate as f64 } else { 0.0 })
-        .unwrap_or(0.0);
+    if id_data.len() < 30 || &id_data[0..7] != b"\x01vorbis" { return None; }
+    let mut warnings = Vec::new();
+    let (channels, sample_rate) = ogg::check_ident_values(
+        id_data[11], u32::from_le_bytes([id_data[12], id_data[13], id_data[14], id_data[15]]), &mut warnings);
+    let nominal_bitrate = u32::from_le_bytes([id_data[20], id_data[21], id_data[22], id_data[23]]);
+    Some((channels, ogg::vorbis_length(data, serial, sample_rate, nominal_bitrate), warnings))
+}
+
+/// OGG info only: parse identification header + last granule, skip VorbisComment.
+#[inline(always)]
+fn fast_info_ogg<'py>(py: Python<'py>, data: &[u8], dict: &Bound<'py, PyDict>) -> PyResult<bool> {
+    let Some((channels, vl, warnings)) = info_ogg_vorbis(data) else { return Ok(false) };
     let dict_ptr = dict.as_ptr();
     unsafe {
-        set_dict_f64(dict_ptr, pyo3::intern!(py, "length").as_ptr(), length);
-        set_dict_u32(dict_ptr, pyo3::intern!(py, "sample_rate").as_ptr(), sample_rate);
+        set_dict_f64(dict_ptr, pyo3::intern!(py, "length").as_ptr(), vl.length);
+        set_dict_i64(dict_ptr, pyo3::intern!(py, "length_samples").as_ptr(), vl.length_samples as i64);
+        set_dict_i64(dict_ptr, pyo3::intern!(py, "duration_ms").as_ptr(), vl.duration_ms as i64);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "sample_rate").as_ptr(), vl.sample_rate);
         set_dict_u32(dict_ptr, pyo3::intern!(py, "channels").as_ptr(), channels as u32);
+        set_dict_bool(dict_ptr, pyo3::intern!(py, "recovered").as_ptr(), vl.recovered);
+    }
+    set_dict_warnings(py, dict, &warnings);
+    Ok(true)
+}
+
+/// Ogg Opus info only: the `OpusHead` packet and the last granule.
+fn fast_info_opus<'py>(py: Python<'py>, data: &[u8], dict: &Bound<'py, PyDict>) -> PyResult<bool> {
+    let Ok(info) = ogg::opus::parse_info(data) else { return Ok(false) };
+    let dict_ptr = dict.as_ptr();
+    unsafe {
+        set_dict_f64(dict_ptr, pyo3::intern!(py, "length").as_ptr(), info.length);
+        set_dict_i64(dict_ptr, pyo3::intern!(py, "length_samples").as_ptr(), info.length_samples as i64);
+        set_dict_i64(dict_ptr, pyo3::intern!(py, "duration_ms").as_ptr(), info.duration_ms as i64);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "sample_rate").as_ptr(), ogg::opus::GRANULE_RATE);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "channels").as_ptr(), info.channels as u32);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "pre_skip").as_ptr(), info.pre_skip as u32);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "input_sample_rate").as_ptr(), info.input_sample_rate);
+    }
+    Ok(true)
+}
+
+/// MPEG info from the first frame after any ID3v2 tag (and its Xing/VBRI header).
+#[inline(always)]
+fn info_mp3(data: &[u8]) -> Option<mp3::MPEGInfo> {
+    let file_size = data.len() as u64;
+    let audio_start = if data.len() >= 10 {
+        match id3::header::ID3Header::parse(&data[0..10], 0) {
+            Ok(h) => {
+                let tag_size = h.size as usize;
+                if 10 + tag_size <= data.len() { h.full_size() as usize } else { 0 }
+            }
+            Err(_) => 0,
+        }
+    } else { 0 };
+    let audio_end = data.len().min(audio_start + 8192);
+    let audio_data = if audio_start < data.len() { &data[audio_start..audio_end] } else { &[] };
+    mp3::MPEGInfo::parse(audio_data, 0, file_size.saturating_sub(audio_start as u64)).ok()
+}
+
+/// MP3 info only: parse MPEG frame header, skip ID3 tags.
+#[inline(always)]
+fn fast_info_mp3<'py>(py: Python<'py>, data: &[u8], dict: &Bound<'py, PyDict>) -> PyResult<bool> {
+    let Some(info) = info_mp3(data) else { return Ok(false) };
+    let dict_ptr = dict.as_ptr();
+    unsafe {
+        set_dict_f64(dict_ptr, pyo3::intern!(py, "length").as_ptr(), info.length);
+        set_dict_i64(dict_ptr, pyo3::intern!(py, "length_samples").as_ptr(), info.length_samples as i64);
+        set_dict_i64(dict_ptr, pyo3::intern!(py, "duration_ms").as_ptr(), info.duration_ms as i64);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "sample_rate").as_ptr(), info.sample_rate);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "channels").as_ptr(), info.channels);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "bitrate").as_ptr(), info.bitrate);
+        // Xing/LAME header fields come from the same 8 KB scan, so they are free here.
+        set_dict_i64(dict_ptr, pyo3::intern!(py, "bitrate_mode").as_ptr(), match info.bitrate_mode {
+            mp3::xing::BitrateMode::Unknown => 0,
+            mp3::xing::BitrateMode::CBR => 1,
+            mp3::xing::BitrateMode::VBR => 2,
+            mp3::xing::BitrateMode::ABR => 3,
+        });
+        set_dict_str(dict_ptr, pyo3::intern!(py, "encoder_info").as_ptr(), &info.encoder_info);
+        set_dict_str(dict_ptr, pyo3::intern!(py, "encoder_settings").as_ptr(), &info.encoder_settings);
+        set_dict_opt_i64(dict_ptr, pyo3::intern!(py, "xing_frames").as_ptr(), info.xing_frames.map(i64::from));
+    }
+    Ok(true)
+}
+
+/// CAF info fields, shared by `_fast_read` and `_fast_info`.
+#[inline(always)]
+fn set_caf_info(py: Python<'_>, info: &caf::CAFInfo, dict: &Bound<'_, PyDict>) {
+    let dict_ptr = dict.as_ptr();
+    unsafe {
+        set_dict_f64(dict_ptr, pyo3::intern!(py, "length").as_ptr(), info.length);
+        set_dict_i64(dict_ptr, pyo3::intern!(py, "length_samples").as_ptr(), info.length_samples as i64);
+        set_dict_i64(dict_ptr, pyo3::intern!(py, "duration_ms").as_ptr(), info.duration_ms as i64);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "sample_rate").as_ptr(), info.sample_rate);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "channels").as_ptr(), info.channels);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "bitrate").as_ptr(), info.bitrate);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "bits_per_sample").as_ptr(), info.bits_per_sample);
+        set_dict_str(dict_ptr, pyo3::intern!(py, "codec").as_ptr(), &info.codec);
+    }
+    set_dict_warnings(py, dict, &info.warnings);
+}
+
+/// Raw AC-3/E-AC-3 stream info for `_fast_info`.
+fn set_ac3_info(py: Python<'_>, info: &ac3::AC3Info, dict: &Bound<'_, PyDict>) {
+    let dict_ptr = dict.as_ptr();
+    unsafe {
+        set_dict_f64(dict_ptr, pyo3::intern!(py, "length").as_ptr(), info.length);
+        set_dict_i64(dict_ptr, pyo3::intern!(py, "length_samples").as_ptr(), info.length_samples as i64);
+        set_dict_i64(dict_ptr, pyo3::intern!(py, "duration_ms").as_ptr(), info.duration_ms as i64);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "sample_rate").as_ptr(), info.sample_rate);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "channels").as_ptr(), info.channels);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "bitrate").as_ptr(), info.bitrate);
+        set_dict_str(dict_ptr, pyo3::intern!(py, "codec").as_ptr(), &info.codec);
+    }
+}
+
+/// Raw DTS stream info for `_fast_info`.
+fn set_dts_info(py: Python<'_>, info: &dts::DTSInfo, dict: &Bound<'_, PyDict>) {
+    let dict_ptr = dict.as_ptr();
+    unsafe {
+        set_dict_f64(dict_ptr, pyo3::intern!(py, "length").as_ptr(), info.length);
+        set_dict_i64(dict_ptr, pyo3::intern!(py, "length_samples").as_ptr(), info.length_samples as i64);
+        set_dict_i64(dict_ptr, pyo3::intern!(py, "duration_ms").as_ptr(), info.duration_ms as i64);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "sample_rate").as_ptr(), info.sample_rate);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "channels").as_ptr(), info.channels);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "bitrate").as_ptr(), info.bitrate);
+        set_dict_str(dict_ptr, pyo3::intern!(py, "codec").as_ptr(), "dts");
+    }
+}
+
+/// Standard MIDI File info for `_fast_info`, with the first track name as
+/// `title`.
+fn set_midi_info(py: Python<'_>, f: &midi::MIDIFile, dict: &Bound<'_, PyDict>) {
+    let dict_ptr = dict.as_ptr();
+    let info = &f.info;
+    unsafe {
+        set_dict_f64(dict_ptr, pyo3::intern!(py, "length").as_ptr(), info.length);
+        set_dict_i64(dict_ptr, pyo3::intern!(py, "duration_ms").as_ptr(), info.duration_ms as i64);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "format").as_ptr(), info.format as u32);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "tracks").as_ptr(), info.tracks as u32);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "ticks_per_beat").as_ptr(), info.ticks_per_beat as u32);
+        set_dict_f64(dict_ptr, pyo3::intern!(py, "bpm").as_ptr(), info.bpm);
+        set_dict_str(dict_ptr, pyo3::intern!(py, "codec").as_ptr(), "midi");
+        if let Some(title) = &f.title {
+            set_dict_str(dict_ptr, pyo3::intern!(py, "title").as_ptr(), title);
+        }
+    }
+}
+
+/// Direct CAF → PyDict. `info` chunk strings are grouped per key like Vorbis
+/// comments: a bare string for one value, a list when the key repeats.
+fn fast_read_caf_direct<'py>(py: Python<'py>, data: &[u8], dict: &Bound<'py, PyDict>) -> PyResult<bool> {
+    let f = match caf::CAFFile::parse(data, "") {
+        Ok(f) => f,
+        Err(_) => return Ok(false),
+    };
+    set_caf_info(py, &f.info, dict);
+    let dict_ptr = dict.as_ptr();
+    let mut keys_out: Vec<*mut pyo3::ffi::PyObject> = Vec::with_capacity(f.tags.len());
+    for (key, value) in &f.tags {
+        unsafe {
+            let key_ptr = flat_tag_key(key.as_bytes());
+            if key_ptr.is_null() { pyo3::ffi::PyErr_Clear(); continue; }
+            let val_ptr = pyo3::ffi::PyUnicode_FromStringAndSize(
+                value.as_ptr() as *const std::ffi::c_char,
+                value.len() as pyo3::ffi::Py_ssize_t);
+            if val_ptr.is_null() {
+                pyo3::ffi::PyErr_Clear();
+                pyo3::ffi::Py_DECREF(key_ptr);
+                continue;
+            }
+            let existing = pyo3::ffi::PyDict_GetItem(dict_ptr, key_ptr);
+            if existing.is_null() {
+                pyo3::ffi::PyDict_SetItem(dict_ptr, key_ptr, val_ptr);
+                pyo3::ffi::Py_DECREF(val_ptr);
+                keys_out.push(key_ptr);
+                continue;
+            }
+            if pyo3::ffi::PyList_Check(existing) != 0 {
+                pyo3::ffi::PyList_Append(existing, val_ptr);
+                pyo3::ffi::Py_DECREF(val_ptr);
+            } else {
+                let list_ptr = pyo3::ffi::PyList_New(2);
+                pyo3::ffi::Py_INCREF(existing);
+                pyo3::ffi::PyList_SET_ITEM(list_ptr, 0, existing);
+                pyo3::ffi::PyList_SET_ITEM(list_ptr, 1, val_ptr);
+                pyo3::ffi::PyDict_SetItem(dict_ptr, key_ptr, list_ptr);
+                pyo3::ffi::Py_DECREF(list_ptr);
+            }
+            pyo3::ffi::Py_DECREF(key_ptr);
+        }
+    }
+    set_keys_list(py, dict, keys_out)?;
+    unsafe {
+        let fmt = pyo3::ffi::PyUnicode_InternFromString(c"caf".as_ptr());
+        pyo3::ffi::PyDict_SetItem(dict.as_ptr(), pyo3::intern!(py, "_format").as_ptr(), fmt);
+        pyo3::ffi::Py_DECREF(fmt);
+    }
+    Ok(true)
+}
+
+/// Monkey's Audio info fields, shared by `_fast_read` and `_fast_info`.
+#[inline(always)]
+fn set_ape_info(py: Python<'_>, info: &ape::APEInfo, dict: &Bound<'_, PyDict>) {
+    let dict_ptr = dict.as_ptr();
+    unsafe {
+        set_dict_f64(dict_ptr, pyo3::intern!(py, "length").as_ptr(), info.length);
+        set_dict_i64(dict_ptr, pyo3::intern!(py, "length_samples").as_ptr(), info.length_samples as i64);
+        set_dict_i64(dict_ptr, pyo3::intern!(py, "duration_ms").as_ptr(), info.duration_ms as i64);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "sample_rate").as_ptr(), info.sample_rate);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "channels").as_ptr(), info.channels);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "bitrate").as_ptr(), info.bitrate);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "bits_per_sample").as_ptr(), info.bits_per_sample);
+        set_dict_f64(dict_ptr, pyo3::intern!(py, "version").as_ptr(), info.version);
+    }
+    set_dict_warnings(py, dict, &info.warnings);
+}
+
+/// Store APEv2 items in a flat `_fast_read` dict, keys in their stored case:
+/// text items like Vorbis comments (a bare string for one value, a list for
+/// several), binary items as bytes. A key already in the dict is skipped.
+fn fast_set_ape_items<'a, 'py>(
+    py: Python<'py>,
+    items: impl Iterator<Item = &'a ape::apev2::APEItem>,
+    dict: &Bound<'py, PyDict>,
+    keys_out: &mut Vec<*mut pyo3::ffi::PyObject>,
+) -> PyResult<()> {
+    for item in items {
+        let value = match item.text() {
+            Some(mut values) if values.len() == 1 => values.swap_remove(0).into_pyobject(py)?.into_any(),
+            Some(values) => PyList::new(py, values)?.into_any(),
+            None => PyBytes::new(py, &item.value).into_any(),
+        };
+        unsafe {
+            let key_ptr = flat_tag_key(item.key.as_bytes());
+            if key_ptr.is_null() { pyo3::ffi::PyErr_Clear(); continue; }
+            // Keys are unique per tag; a repeated one replaces the earlier item
+            if pyo3::ffi::PyDict_Contains(dict.as_ptr(), key_ptr) == 0 {
+                keys_out.push(key_ptr);
+            } else {
+                pyo3::ffi::Py_DECREF(key_ptr);
+                continue;
+            }
+            pyo3::ffi::PyDict_SetItem(dict.as_ptr(), key_ptr, value.as_ptr());
+        }
+    }
+    Ok(())
+}
+
+/// Direct Monkey's Audio → PyDict. APEv2 text items are stored like Vorbis
+/// comments (a bare string for one value, a list for several), binary items
+/// as bytes.
+fn fast_read_ape_direct<'py>(py: Python<'py>, data: &[u8], dict: &Bound<'py, PyDict>) -> PyResult<bool> {
+    let f = match ape::APEFile::parse(data, "") {
+        Ok(f) => f,
+        // A corrupt frame count is reported as such, not as an unknown format
+        Err(e @ common::error::MutagenError::MonkeysAudioTruncated(_)) => return Err(e.into()),
+        Err(_) => return Ok(false),
+    };
+    set_ape_info(py, &f.info, dict);
+    let mut keys_out: Vec<*mut pyo3::ffi::PyObject> = Vec::new();
+    fast_set_ape_items(py, f.tags.iter().flat_map(|t| &t.items), dict, &mut keys_out)?;
+    set_keys_list(py, dict, keys_out)?;
+    unsafe {
+        let fmt = pyo3::ffi::PyUnicode_InternFromString(c"ape".as_ptr());
+        pyo3::ffi::PyDict_SetItem(dict.as_ptr(), pyo3::intern!(py, "_format").as_ptr(), fmt);
+        pyo3::ffi::Py_DECREF(fmt);
     }
     Ok(true)
 }
 
-/// MP3 info only: parse MPEG frame header, skip ID3 tags.
+/// WavPack info fields, shared by `_fast_read` and `_fast_info`.
 #[inline(always)]
-fn fast_info_mp3<'py>(py: Python<'py>, data: &[u8], dict: &Bound<'py, PyDict>) -> PyResult<bool> {
-    let file_size = data.len() as u64;
-    let audio_start = if data.len() >= 10 {
-        match id3::header::ID3Header::parse(&data[0..10], 0) {
-            Ok(h) => {
-                let tag_size = h.size as usize;
-                if 10 + tag_size <= data.len() { h.full_size() as usize } else { 0 }
-            }
-            Err(_) => 0,
-        }
-    } else { 0 };
-    let audio_end = data.len().min(audio_start + 8192);
-    let audio_data = if audio_start < data.len() { &data[audio_start..audio_end] } else { &[] };
-    let info = match mp3::MPEGInfo::parse(audio_data, 0, file_size.saturating_sub(audio_start as u64)) {
-        Ok(i) => i,
-        Err(_) => return Ok(false),
-    };
+fn set_wavpack_info(py: Python<'_>, info: &wavpack::WavPackInfo, dict: &Bound<'_, PyDict>) {
     let dict_ptr = dict.as_ptr();
     unsafe {
         set_dict_f64(dict_ptr, pyo3::intern!(py, "length").as_ptr(), info.length);
+        set_dict_i64(dict_ptr, pyo3::intern!(py, "length_samples").as_ptr(), info.length_samples as i64);
+        set_dict_i64(dict_ptr, pyo3::intern!(py, "duration_ms").as_ptr(), info.duration_ms as i64);
         set_dict_u32(dict_ptr, pyo3::intern!(py, "sample_rate").as_ptr(), info.sample_rate);
         set_dict_u32(dict_ptr, pyo3::intern!(py, "channels").as_ptr(), info.channels);
         set_dict_u32(dict_ptr, pyo3::intern!(py, "bitrate").as_ptr(), info.bitrate);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "bits_per_sample").as_ptr(), info.bits_per_sample);
+        set_dict_u32(dict_ptr, pyo3::intern!(py, "version").as_ptr(), info.version as u32);
+    }
+}
+
+/// Direct WavPack → PyDict, APEv2 items stored as in `fast_read_ape_direct`.
+fn fast_read_wavpack_direct<'py>(py: Python<'py>, data: &[u8], dict: &Bound<'py, PyDict>) -> PyResult<bool> {
+    let f = match wavpack::WavPackFile::parse(data, "") {
+        Ok(f) => f,
+        Err(_) => return Ok(false),
+    };
+    set_wavpack_info(py, &f.info, dict);
+    let mut keys_out: Vec<*mut pyo3::ffi::PyObject> = Vec::new();
+    fast_set_ape_items(py, f.tags.iter().flat_map(|t| &t.items), dict, &mut keys_out)?;
+    set_keys_list(py, dict, keys_out)?;
+    unsafe {
+        let fmt = pyo3::ffi::PyUnicode_InternFromString(c"wavpack".as_ptr());
+        pyo3::ffi::PyDict_SetItem(dict.as_ptr(), pyo3::intern!(py, "_format").as_ptr(), fmt);
+        pyo3::ffi::Py_DECREF(fmt);
     }
     Ok(true)
 }
 
-/// MP4 info only: parse moov/mvhd + audio track, skip ilst tags.
+/// Movie duration and timescale from the `mvhd` inside the given `moov` body.
 #[inline(always)]
-fn fast_info_mp4<'py>(py: Python<'py>, data: &[u8], dict: &Bound<'py, PyDict>) -> PyResult<bool> {
-    use mp4::atom::AtomIter;
-    let moov = match AtomIter::new(data, 0, data.len()).find_name(b"moov") {
-        Some(a) => a,
-        None => return Ok(false),
-    };
-    let moov_s = moov.data_offset;
-    let moov_e = moov_s + moov.data_size;
+fn info_mp4_mvhd(data: &[u8], moov_s: usize, moov_e: usize) -> (u64, u32) {
     let mut duration = 0u64;
     let mut timescale = 1000u32;
-    if let Some(mvhd) = AtomIter::new(data, moov_s, moov_e).find_name(b"mvhd") {
+    if let Some(mvhd) = mp4::atom::AtomIter::new(data, moov_s, moov_e).find_name(b"mvhd") {
         let d = &data[mvhd.data_offset..mvhd.data_offset + mvhd.data_size.min(32)];
         if !d.is_empty() {
             let version = d[0];
@@ -4323,9 +9463,25 @@ fn fast_info_mp4<'py>(py: Python<'py>, data: &[u8], dict: &Bound<'py, PyDict>) -
             }
         }
     }
+    (duration, timescale)
+}
+
+/// MP4 info only: parse moov/mvhd + audio track, skip ilst tags.
+#[inline(always)]
+fn fast_info_mp4<'py>(py: Python<'py>, data: &[u8], dict: &Bound<'py, PyDict>) -> PyResult<bool> {
+    use mp4::atom::AtomIter;
+    let moov = match AtomIter::new(data, 0, data.len()).find_name(b"moov") {
+        Some(a) => a,
+        None => return Ok(false),
+    };
+    let moov_s = moov.data_offset;
+    let moov_e = moov_s + moov.data_size;
+    let (duration, timescale) = info_mp4_mvhd(data, moov_s, moov_e);
     let length = if timescale > 0 { duration as f64 / timescale as f64 } else { 0.0 };
     let mut channels = 2u32;
+    let mut channels_source = "stsd";
     let mut sample_rate = 44100u32;
+    let mut mdhd = None;
     'trak: for trak in AtomIter::new(data, moov_s, moov_e) {
         if trak.name != *b"trak" { continue; }
         let ts = trak.data_offset;
@@ -4340,6 +9496,8 @@ fn fast_info_mp4<'py>(py: Python<'py>, data: &[u8], dict: &Bound<'py, PyDict>) -
             }
         });
         if !is_audio { continue; }
+        mdhd = AtomIter::new(data, ms, me).find_name(b"mdhd")
+            .and_then(|a| mp4::parse_mdhd(&data[a.data_offset..a.data_offset + a.data_size]));
         let minf = match AtomIter::new(data, ms, me).find_name(b"minf") { Some(a) => a, None => continue };
         let stbl = match AtomIter::new(data, minf.data_offset, minf.data_offset + minf.data_size).find_name(b"stbl") { Some(a) => a, None => continue };
         let stsd = match AtomIter::new(data, stbl.data_offset, stbl.data_offset + stbl.data_size).find_name(b"stsd") { Some(a) => a, None => continue };
@@ -4347,29 +9505,63 @@ fn fast_info_mp4<'py>(py: Python<'py>, data: &[u8], dict: &Bound<'py, PyDict>) -
         if stsd_data.len() >= 16 {
             let entry = &stsd_data[8..];
             if entry.len() >= 36 {
+                let entry_size = u32::from_be_bytes([entry[0], entry[1], entry[2], entry[3]]) as usize;
                 let audio = &entry[8..];
                 if audio.len() >= 20 {
                     channels = u16::from_be_bytes([audio[16], audio[17]]) as u32;
                     if audio.len() >= 28 { sample_rate = u16::from_be_bytes([audio[24], audio[25]]) as u32; }
                 }
+                if let Some(esds) = mp4::find_stsd_esds(data, stsd.data_offset, entry_size) {
+                    (channels, channels_source) = mp4::resolve_channels(channels, mp4::parse_esds_channels(esds));
+                }
             }
         }
         break 'trak;
     }
+    let mut warnings = Vec::new();
+    let (channels, _, sample_rate) = mp4::check_audio_entry(channels, 0, sample_rate, &mut warnings);
+    set_dict_warnings(py, dict, &warnings);
     let dict_ptr = dict.as_ptr();
     unsafe {
         set_dict_f64(dict_ptr, pyo3::intern!(py, "length").as_ptr(), length);
+        set_dict_i64(dict_ptr, pyo3::intern!(py, "length_samples").as_ptr(),
+            mp4::length_samples(mdhd, length, sample_rate) as i64);
+        set_dict_i64(dict_ptr, pyo3::intern!(py, "duration_ms").as_ptr(),
+            common::util::duration_ms(duration, timescale as u64) as i64);
         set_dict_u32(dict_ptr, pyo3::intern!(py, "sample_rate").as_ptr(), sample_rate);
         set_dict_u32(dict_ptr, pyo3::intern!(py, "channels").as_ptr(), channels);
+        set_dict_str(dict_ptr, pyo3::intern!(py, "channels_source").as_ptr(), channels_source);
     }
     Ok(true)
 }
 
 /// Fast info-only read: returns dict with audio info (no tags).
 /// Selective parsing — skips tag structures entirely for maximum speed.
+/// Results are kept in INFO_CACHE keyed by path and checked against mtime/size,
+/// so repeated duration queries on unchanged files skip the read entirely.
 #[pyfunction]
 fn _fast_info(py: Python<'_>, filename: &str) -> PyResult<Py<PyAny>> {
-    let data = fast_file_read(filename)
+    let meta = std::fs::metadata(filename)
+        .map_err(|e| PyIOError::new_err(format!("{}", e)))?;
+    let mtime = meta.modified().ok();
+    let size = meta.len();
+
+    if let Some(mtime) = mtime {
+        let cache = get_info_cache();
+        let guard = cache.read().unwrap();
+        if let Some(entry) = guard.get(filename) {
+            if entry.mtime == mtime && entry.size == size {
+                let copy = unsafe { pyo3::ffi::PyDict_Copy(entry.info.as_ptr()) };
+                if !copy.is_null() {
+                    return Ok(unsafe { Bound::from_owned_ptr(py, copy).unbind() });
+                }
+            }
+        }
+    }
+
+    let ext = filename.rsplit('.').next().unwrap_or("");
+    // Never goes through FILE_CACHE: an info-only sweep keeps no file data around.
+    let data = py.detach(|| fast_file_read_info(filename, ext))
         .map_err(|e| PyIOError::new_err(format!("{}", e)))?;
     let dict: Bound<'_, PyDict> = unsafe {
         let ptr = pyo3::ffi::PyDict_New();
@@ -4378,32 +9570,630 @@ fn _fast_info(py: Python<'_>, filename: &str) -> PyResult<Py<PyAny>> {
         }
         Bound::from_owned_ptr(py, ptr).cast_into_unchecked()
     };
-    let ext = filename.rsplit('.').next().unwrap_or("");
     let ok = if ext.eq_ignore_ascii_case("flac") {
         fast_info_flac(py, &data, &dict)?
     } else if ext.eq_ignore_ascii_case("ogg") {
-        fast_info_ogg(py, &data, &dict)?
+        fast_info_ogg(py, &data, &dict)? || fast_info_opus(py, &data, &dict)?
+    } else if ext.eq_ignore_ascii_case("opus") {
+        fast_info_opus(py, &data, &dict)?
     } else if ext.eq_ignore_ascii_case("mp3") {
         fast_info_mp3(py, &data, &dict)?
-    } else if ext.eq_ignore_ascii_case("m4a") || ext.eq_ignore_ascii_case("m4b")
-            || ext.eq_ignore_ascii_case("mp4") || ext.eq_ignore_ascii_case("m4v") {
+    } else if mp4::is_mp4_extension(ext) {
         fast_info_mp4(py, &data, &dict)?
+    } else if ext.eq_ignore_ascii_case("caf") {
+        match caf::CAFFile::parse(&data, filename) {
+            Ok(f) => { set_caf_info(py, &f.info, &dict); true }
+            Err(_) => false,
+        }
+    } else if ext.eq_ignore_ascii_case("ape") {
+        match ape::APEFile::parse(&data, filename) {
+            Ok(f) => { set_ape_info(py, &f.info, &dict); true }
+            Err(e @ common::error::MutagenError::MonkeysAudioTruncated(_)) => return Err(e.into()),
+            Err(_) => false,
+        }
+    } else if ext.eq_ignore_ascii_case("wv") {
+        match wavpack::WavPackFile::parse(&data, filename) {
+            Ok(f) => { set_wavpack_info(py, &f.info, &dict); true }
+            Err(_) => false,
+        }
+    } else if ext.eq_ignore_ascii_case("ac3") || ext.eq_ignore_ascii_case("eac3") {
+        match ac3::AC3File::parse(&data, filename) {
+            Ok(f) => { set_ac3_info(py, &f.info, &dict); true }
+            Err(_) => false,
+        }
+    } else if ext.eq_ignore_ascii_case("dts") {
+        match dts::DTSFile::parse(&data, filename) {
+            Ok(f) => { set_dts_info(py, &f.info, &dict); true }
+            Err(_) => false,
+        }
+    } else if ext.eq_ignore_ascii_case("mid") || ext.eq_ignore_ascii_case("midi") {
+        match midi::MIDIFile::parse(&data, filename) {
+            Ok(f) => { set_midi_info(py, &f, &dict); true }
+            Err(_) => false,
+        }
     } else {
         false
     };
     if !ok {
         return Err(PyValueError::new_err(format!("Unable to parse: {}", filename)));
     }
+    if let Some(mtime) = mtime {
+        // Cache a private copy so callers can mutate the returned dict freely
+        let copy = unsafe { pyo3::ffi::PyDict_Copy(dict.as_ptr()) };
+        if !copy.is_null() {
+            let info: Bound<'_, PyDict> = unsafe { Bound::from_owned_ptr(py, copy).cast_into_unchecked() };
+            let cache = get_info_cache();
+            let mut guard = cache.write().unwrap();
+            if guard.len() >= INFO_CACHE_CAP && !guard.contains_key(filename) {
+                guard.clear();
+            }
+            guard.insert(filename.to_string(), InfoCacheEntry { mtime, size, info: info.unbind() });
+        }
+    }
     Ok(dict.into_any().unbind())
 }
 
+/// Length in seconds from the same per-format parsing as `_fast_info`, without
+/// building a dict. `None` if the file is not recognized, `Some(None)` when it
+/// doesn't record its duration (FLAC `total_samples` of 0).
+fn info_length(data: &[u8], filename: &str, ext: &str) -> Option<Option<f64>> {
+    if ext.eq_ignore_ascii_case("flac") {
+        return info_flac_streaminfo(data).map(|si| si.length);
+    }
+    let length = if ext.eq_ignore_ascii_case("ogg") {
+        info_ogg_vorbis(data).map(|(_, vl, _)| vl.length)
+            .or_else(|| ogg::opus::parse_info(data).ok().map(|info| info.length))
+    } else if ext.eq_ignore_ascii_case("opus") {
+        ogg::opus::parse_info(data).ok().map(|info| info.length)
+    } else if ext.eq_ignore_ascii_case("mp3") {
+        info_mp3(data).map(|info| info.length)
+    } else if mp4::is_mp4_extension(ext) {
+        let moov = mp4::atom::AtomIter::new(data, 0, data.len()).find_name(b"moov")?;
+        let (duration, timescale) = info_mp4_mvhd(data, moov.data_offset, moov.data_offset + moov.data_size);
+        Some(if timescale > 0 { duration as f64 / timescale as f64 } else { 0.0 })
+    } else if ext.eq_ignore_ascii_case("caf") {
+        caf::CAFFile::parse(data, filename).ok().map(|f| f.info.length)
+    } else if ext.eq_ignore_ascii_case("ape") {
+        ape::APEFile::parse(data, filename).ok().map(|f| f.info.length)
+    } else if ext.eq_ignore_ascii_case("wv") {
+        wavpack::WavPackFile::parse(data, filename).ok().map(|f| f.info.length)
+    } else if ext.eq_ignore_ascii_case("ac3") || ext.eq_ignore_ascii_case("eac3") {
+        ac3::AC3File::parse(data, filename).ok().map(|f| f.info.length)
+    } else if ext.eq_ignore_ascii_case("dts") {
+        dts::DTSFile::parse(data, filename).ok().map(|f| f.info.length)
+    } else if ext.eq_ignore_ascii_case("mid") || ext.eq_ignore_ascii_case("midi") {
+        midi::MIDIFile::parse(data, filename).ok().map(|f| f.info.length)
+    } else {
+        None
+    };
+    length.map(Some)
+}
+
+/// Duration-only probe: the `length` of `_fast_info` as a bare float, `None`
+/// when the file doesn't record it (a FLAC with `total_samples` of 0).
+/// Answers from INFO_CACHE when it holds the file unchanged, but never fills it.
+#[pyfunction]
+fn duration(py: Python<'_>, filename: &str) -> PyResult<Option<f64>> {
+    let meta = std::fs::metadata(filename)
+        .map_err(|e| PyIOError::new_err(format!("{}", e)))?;
+    if let Ok(mtime) = meta.modified() {
+        let cache = get_info_cache();
+        let guard = cache.read().unwrap();
+        if let Some(entry) = guard.get(filename) {
+            if entry.mtime == mtime && entry.size == meta.len() {
+                if let Ok(Some(length)) = entry.info.bind(py).get_item(pyo3::intern!(py, "length")) {
+                    return length.extract::<Option<f64>>();
+                }
+            }
+        }
+    }
+
+    let ext = filename.rsplit('.').next().unwrap_or("");
+    py.detach(|| {
+        let data = fast_file_read_info(filename, ext)
+            .map_err(|e| PyIOError::new_err(format!("{}", e)))?;
+        info_length(&data, filename, ext)
+            .ok_or_else(|| PyValueError::new_err(format!("Unable to parse: {}", filename)))
+    })
+}
+
+// ---- Tag presence probes ----
+
+/// Outcome of a tag presence probe. Counts come from container structure only
+/// (frame headers, comment counts, ilst children); no tag values are decoded.
+struct TagSummary {
+    format: &'static str,
+    present: bool,
+    tag_count: usize,
+    has_pictures: bool,
+}
+
+/// Pick a probe format by extension, falling back to score-based detection.
+fn probe_format(filename: &str, data: &[u8]) -> Option<&'static str> {
+    let ext = filename.rsplit('.').next().unwrap_or("");
+    if ext.eq_ignore_ascii_case("flac") { return Some("flac"); }
+    if ext.eq_ignore_ascii_case("ogg") {
+        return Some(if ogg::opus::is_opus(data) { "opus" } else { "ogg" });
+    }
+    if ext.eq_ignore_ascii_case("opus") { return Some("opus"); }
+    if ext.eq_ignore_ascii_case("mp3") { return Some("mp3"); }
+    if mp4::is_mp4_extension(ext) {
+        return Some("mp4");
+    }
+    if ext.eq_ignore_ascii_case("caf") { return Some("caf"); }
+    if ext.eq_ignore_ascii_case("ape") { return Some("ape"); }
+    if ext.eq_ignore_ascii_case("wv") { return Some("wavpack"); }
+    let mp3_score = mp3::MP3File::score(filename, data);
+    let flac_score = flac::FLACFile::score(filename, data);
+    let ogg_score = ogg::OggVorbisFile::score(filename, data);
+    let opus_score = ogg::opus::OpusFile::score(filename, data);
+    let mp4_score = mp4::MP4File::score(filename, data);
+    let caf_score = caf::CAFFile::score(filename, data);
+    let ape_score = ape::APEFile::score(filename, data);
+    let wavpack_score = wavpack::WavPackFile::score(filename, data);
+    let max_score = mp3_score.max(flac_score).max(ogg_score).max(opus_score).max(mp4_score).max(caf_score)
+        .max(ape_score).max(wavpack_score);
+    if max_score == 0 { None }
+    else if max_score == flac_score { Some("flac") }
+    else if max_score == ogg_score { Some("ogg") }
+    else if max_score == opus_score { Some("opus") }
+    else if max_score == mp4_score { Some("mp4") }
+    else if max_score == caf_score { Some("caf") }
+    else if max_score == ape_score { Some("ape") }
+    else if max_score == wavpack_score { Some("wavpack") }
+    else { Some("mp3") }
+}
+
+/// Count entries in a VorbisComment body (after any packet signature) without
+/// decoding them. With `scan_pictures`, also looks for embedded cover art keys.
+fn probe_vc(vc: &[u8], scan_pictures: bool) -> Option<(usize, bool)> {
+    if vc.len() < 8 { return None; }
+    let vendor_len = u32::from_le_bytes([vc[0], vc[1], vc[2], vc[3]]) as usize;
+    let mut pos = 4usize.checked_add(vendor_len)?;
+    if pos + 4 > vc.len() { return None; }
+    let count = u32::from_le_bytes([vc[pos], vc[pos+1], vc[pos+2], vc[pos+3]]) as usize;
+    pos += 4;
+    let mut has_pictures = false;
+    if scan_pictures {
+        for _ in 0..count {
+            if pos + 4 > vc.len() { break; }
+            let len = u32::from_le_bytes([vc[pos], vc[pos+1], vc[pos+2], vc[pos+3]]) as usize;
+            pos += 4;
+            let end = pos.saturating_add(len).min(vc.len());
+            let entry = &vc[pos..end];
+            let key = entry.split(|&b| b == b'=').next().unwrap_or(entry);
+            if key.eq_ignore_ascii_case(b"METADATA_BLOCK_PICTURE") || key.eq_ignore_ascii_case(b"COVERART") {
+                has_pictures = true;
+                break;
+            }
+            pos = end;
+        }
+    }
+    Some((count, has_pictures))
+}
+
+fn probe_mp3(data: &[u8]) -> TagSummary {
+    let mut summary = TagSummary { format: "mp3", present: false, tag_count: 0, has_pictures: false };
+    // Frame IDs seen, to leave out the APEv2 items `_fast_read` drops for them
+    let mut frame_ids: Vec<String> = Vec::new();
+    if data.len() >= 10 {
+        if let Ok(h) = id3::header::ID3Header::parse(&data[0..10], 0) {
+            let tag_size = h.size as usize;
+            if 10 + tag_size <= data.len() {
+                summary.present = true;
+                let version = h.version.0;
+                let decoded_buf;
+                let tag_bytes: &[u8] = if h.flags.unsynchronisation && version < 4 {
+                    decoded_buf = id3::unsynch::decode(&data[10..10 + tag_size]).unwrap_or_default();
+                    &decoded_buf[..]
+                } else {
+                    &data[10..10 + tag_size]
+                };
+                let mut offset = 0usize;
+                if h.flags.extended && version >= 3 && tag_bytes.len() >= 4 {
+                    let ext_size = if version == 4 {
+                        id3::header::BitPaddedInt::syncsafe(&tag_bytes[0..4]) as usize
+                    } else {
+                        u32::from_be_bytes([tag_bytes[0], tag_bytes[1], tag_bytes[2], tag_bytes[3]]) as usize
+                    };
+                    offset = if version == 4 { ext_size } else { ext_size + 4 };
+                }
+                let (header_len, id_len) = if version == 2 { (6, 3) } else { (10, 4) };
+                let bpi = if version == 4 && offset <= tag_bytes.len() {
+                    id3::header::determine_bpi(&tag_bytes[offset..], tag_bytes.len())
+                } else { 8 };
+                while offset + header_len <= tag_bytes.len() {
+                    let id = &tag_bytes[offset..offset + id_len];
+                    if !id.iter().all(|&b| b.is_ascii_uppercase() || b.is_ascii_digit()) { break; }
+                    let size = if version == 2 {
+                        ((tag_bytes[offset+3] as usize) << 16)
+                            | ((tag_bytes[offset+4] as usize) << 8)
+                            | (tag_bytes[offset+5] as usize)
+                    } else {
+                        id3::header::BitPaddedInt::decode(&tag_bytes[offset+4..offset+8], bpi) as usize
+                    };
+                    offset += header_len + size;
+                    if offset > tag_bytes.len() { break; }
+                    if size == 0 { continue; }
+                    summary.tag_count += 1;
+                    frame_ids.push(String::from_utf8_lossy(id).into_owned());
+                    if id == b"APIC" || id == b"PIC" { summary.has_pictures = true; }
+                }
+            }
+        }
+    }
+    if data.len() >= 128 && &data[data.len() - 128..data.len() - 125] == b"TAG" {
+        summary.present = true;
+        // ID3v1 fields only count when there is no ID3v2 data to take precedence
+        if summary.tag_count == 0 {
+            let frames = id3::id3v1::parse_id3v1(&data[data.len() - 128..]).unwrap_or_default();
+            summary.tag_count = frames.len();
+            frame_ids.extend(frames.iter().map(|f| f.hash_key().as_str().to_string()));
+        }
+    }
+    // APEv2 items count when no ID3 frame holds their field, as `_fast_read` merges them
+    if let Some(ape_tag) = ape::apev2::parse(data) {
+        summary.present = true;
+        summary.tag_count += ape::apev2::uncovered_by_id3(&ape_tag, &frame_ids).len();
+    }
+    summary
+}
+
+fn probe_flac(data: &[u8]) -> Option<TagSummary> {
+    let flac_offset = if data.len() >= 4 && &data[0..4] == b"fLaC" {
+        0
+    } else if data.len() >= 10 && &data[0..3] == b"ID3" {
+        let size = id3::header::BitPaddedInt::syncsafe(&data[6..10]) as usize;
+        let off = 10 + size;
+        if off + 4 > data.len() || &data[off..off+4] != b"fLaC" { return None; }
+        off
+    } else {
+        return None;
+    };
+    let mut summary = TagSummary { format: "flac", present: false, tag_count: 0, has_pictures: false };
+    let mut pos = flac_offset + 4;
+    loop {
+        if pos + 4 > data.len() { break; }
+        let header = data[pos];
+        let is_last = header & 0x80 != 0;
+        let bt = header & 0x7F;
+        let block_size = ((data[pos+1] as usize) << 16) | ((data[pos+2] as usize) << 8) | (data[pos+3] as usize);
+        pos += 4;
+        if pos + block_size > data.len() { break; }
+        if bt == 4 {
+            if let Some((count, _)) = probe_vc(&data[pos..pos+block_size], false) {
+                summary.tag_count += count;
+            }
+        } else if bt == 6 {
+            summary.has_pictures = true;
+        }
+        pos += block_size;
+        if is_last { break; }
+    }
+    summary.present = summary.tag_count > 0 || summary.has_pictures;
+    Some(summary)
+}
+
+fn probe_ogg(data: &[u8]) -> Option<TagSummary> {
+    if data.len() < 58 || &data[0..4] != b"OggS" { return None; }
+    let seg_table_end = 27 + data[26] as usize;
+    if seg_table_end > data.len() { return None; }
+    let page_data_size: usize = data[27..seg_table_end].iter().map(|&s| s as usize).sum();
+    let first_page_end = seg_table_end + page_data_size;
+    let packet = ogg::ogg_assemble_first_packet(data, first_page_end)?;
+    if packet.len() < 7 || &packet[0..7] != b"\x03vorbis" { return None; }
+    let (count, has_pictures) = probe_vc(&packet[7..], true)?;
+    Some(TagSummary { format: "ogg", present: count > 0, tag_count: count, has_pictures })
+}
+
+fn probe_opus(data: &[u8]) -> Option<TagSummary> {
+    let (_, page1_size) = ogg::ogg_page_header(data, 0)?;
+    let packet = ogg::ogg_assemble_first_packet(data, page1_size)?;
+    let body = packet.strip_prefix(b"OpusTags")?;
+    let (count, has_pictures) = probe_vc(body, true)?;
+    Some(TagSummary { format: "opus", present: count > 0, tag_count: count, has_pictures })
+}
+
+fn probe_caf(data: &[u8]) -> Option<TagSummary> {
+    let f = caf::CAFFile::parse(data, "").ok()?;
+    let tag_count = f.tags.len();
+    Some(TagSummary { format: "caf", present: tag_count > 0, tag_count, has_pictures: false })
+}
+
+/// Formats tagged only with APEv2 at the end of the file (Monkey's Audio, WavPack).
+fn probe_apev2(data: &[u8], format: &'static str) -> Option<TagSummary> {
+    let tag_count = ape::apev2::parse(data).map_or(0, |t| t.items.len());
+    Some(TagSummary { format, present: tag_count > 0, tag_count, has_pictures: false })
+}
+
+fn probe_mp4(data: &[u8]) -> Option<TagSummary> {
+    use mp4::atom::AtomIter;
+    let moov = AtomIter::new(data, 0, data.len()).find_name(b"moov")?;
+    let mut summary = TagSummary { format: "mp4", present: false, tag_count: 0, has_pictures: false };
+    let ilst = AtomIter::new(data, moov.data_offset, moov.data_offset + moov.data_size)
+        .find_name(b"udta")
+        .and_then(|udta| AtomIter::new(data, udta.data_offset, udta.data_offset + udta.data_size).find_name(b"meta"))
+        .filter(|meta| meta.data_size > 4)
+        .and_then(|meta| AtomIter::new(data, meta.data_offset + 4, meta.data_offset + meta.data_size).find_name(b"ilst"));
+    if let Some(ilst) = ilst {
+        for item in AtomIter::new(data, ilst.data_offset, ilst.data_offset + ilst.data_size) {
+            if item.is_truncated() { break; }
+            summary.tag_count += 1;
+            if item.name == *b"covr" { summary.has_pictures = true; }
+        }
+    }
+    summary.present = summary.tag_count > 0;
+    Some(summary)
+}
+
+/// Probe a file's tags with early exits per format; `None` if the format is not recognized.
+fn probe_tags(filename: &str, data: &[u8]) -> Option<TagSummary> {
+    match probe_format(filename, data)? {
+        "flac" => probe_flac(data),
+        "ogg" => probe_ogg(data),
+        "opus" => probe_opus(data),
+        "mp4" => probe_mp4(data),
+        "caf" => probe_caf(data),
+        fmt @ ("ape" | "wavpack") => probe_apev2(data, fmt),
+        _ => Some(probe_mp3(data)),
+    }
+}
+
+fn probe_tags_file(filename: &str) -> PyResult<TagSummary> {
+    let ext = filename.rsplit('.').next().unwrap_or("");
+    let data = fast_file_read_tags(filename, ext)
+        .map_err(|e| PyIOError::new_err(format!("{}", e)))?;
+    probe_tags(filename, &data)
+        .ok_or_else(|| PyValueError::new_err(format!("Unable to parse: {}", filename)))
+}
+
+/// Format an extension names, in `probe_format`'s terms.
+fn ext_format(ext: &str) -> Option<&'static str> {
+    const EXTS: &[(&str, &str)] = &[
+        ("mp3", "mp3"), ("flac", "flac"), ("ogg", "ogg"), ("oga", "ogg"), ("opus", "opus"),
+        ("m4a", "mp4"), ("m4b", "mp4"), ("mp4", "mp4"), ("m4v", "mp4"), ("aax", "mp4"), ("aaxc", "mp4"),
+        ("caf", "caf"), ("ape", "ape"), ("wv", "wavpack"),
+    ];
+    EXTS.iter().find(|(e, _)| e.eq_ignore_ascii_case(ext)).map(|(_, f)| *f)
+}
+
+/// Format detected from the content when it differs from what the extension
+/// says (a `.m4a` holding MP3 gives `"mp3"`); `None` when they agree or the
+/// content is not recognized. Detection is score-based with the extension
+/// left out, so the extension shortcut never wins. Opus under `.ogg`/`.oga`
+/// is not flagged: both are plain Ogg extensions.
+#[pyfunction]
+fn verify_extension(py: Python<'_>, filename: &str) -> PyResult<Option<&'static str>> {
+    let data = py.detach(|| fast_file_read(filename))
+        .map_err(|e| PyIOError::new_err(format!("{}", e)))?;
+    let ext = filename.rsplit('.').next().unwrap_or("");
+    Ok(probe_format("", &data).filter(|&detected| match ext_format(ext) {
+        Some("ogg") => detected != "ogg" && detected != "opus",
+        expected => expected != Some(detected),
+    }))
+}
+
+/// Read an MP4 from only its last bytes (`tail`, e.g. from a range request)
+/// when `moov` sits at the end of a `file_len`-byte file. Returns a dict shaped
+/// like a `batch_open` entry, or `None` if `moov` is not in the tail and the
+/// head must be fetched instead.
+#[pyfunction]
+fn mp4_parse_tail(py: Python<'_>, tail: &[u8], file_len: u64) -> PyResult<Option<Py<PyAny>>> {
+    match mp4::MP4File::parse_tail_bytes(tail, file_len) {
+        mp4::TailParse::Parsed(f) => preserialized_to_py_dict(py, &mp4_file_to_batch(*f)).map(Some),
+        mp4::TailParse::MoovNotInTail => Ok(None),
+    }
+}
+
+/// Whether a file has any tags at all, without decoding them.
+/// MP3: an ID3v2 header, ID3v1 trailer or APEv2 tag. FLAC: a comment block
+/// with count > 0 or a PICTURE block. OGG: a comment header with count > 0.
+/// MP4: an ilst with at least one child. Large files are read by range
+/// (`RangeRead::for_tags_ext`).
+#[pyfunction]
+fn has_tags(py: Python<'_>, filename: &str) -> PyResult<bool> {
+    py.detach(|| probe_tags_file(filename)).map(|s| s.present)
+}
+
+/// Cheap tag overview: (format, tag_count, has_pictures), read the same way as `has_tags`.
+#[pyfunction]
+fn tag_summary(py: Python<'_>, filename: &str) -> PyResult<(&'static str, usize, bool)> {
+    py.detach(|| probe_tags_file(filename)).map(|s| (s.format, s.tag_count, s.has_pictures))
+}
+
+/// Parallel `tag_summary` over many files. Returns path → (format, tag_count, has_pictures);
+/// unreadable or unrecognized files are left out, as in `batch_open`.
+#[pyfunction]
+fn batch_tag_summary(py: Python<'_>, filenames: Vec<String>) -> PyResult<Py<PyAny>> {
+    use rayon::prelude::*;
+    let summaries: Vec<Option<TagSummary>> = py.detach(|| {
+        filenames.par_iter()
+            .map(|path| {
+                let ext = path.rsplit('.').next().unwrap_or("");
+                fast_file_read_tags(path, ext).ok().and_then(|data| probe_tags(path, &data))
+            })
+            .collect()
+    });
+    let result = PyDict::new(py);
+    for (path, summary) in filenames.iter().zip(summaries) {
+        if let Some(s) = summary {
+            result.set_item(path, (s.format, s.tag_count, s.has_pictures))?;
+        }
+    }
+    Ok(result.into_any().unbind())
+}
+
 /// Fast single-file read with two-tier caching + direct parsing:
 ///   Level 1 (warm): RESULT_CACHE → PyDict_Copy (~200ns)
 ///   Level 2 (cold): TEMPLATE_CACHE → PyDict_Copy (~200ns, template persists across clear_cache)
 ///   First read: std::fs::read → fast_read_*_direct → PyDict (no intermediary)
 /// clear_cache() only clears Level 1. Templates persist until file is modified.
+///
+/// Vorbis comment values are stored bare when a key occurs once and as a list
+/// when it repeats; `multi=True` returns every FLAC/OGG tag value as a list,
+/// like mutagen. The cached dicts keep the mixed form either way.
+///
+/// `id3v1_encoding` decodes MP3 ID3v1 fields and encoding-0 frames with another
+/// codepage, as in `ID3()`, and reports the one used as `_id3v1_encoding`.
+///
+/// Tags whose names clash with an info field (a `LENGTH` comment, say) are
+/// stored as `tag:<name>`. `nested=True` instead returns the `batch_open` shape,
+/// with the tags under `"tags"` and no `_keys` (see `nest_fast_read`).
+///
+/// ID3 keys are mutagen's (`COMM::eng`, `TXXX:desc`, `APIC:`, TYER as TDRC);
+/// `bare_ids=True` groups them under the plain frame IDs instead.
 #[pyfunction]
-fn _fast_read(py: Python<'_>, filename: &str) -> PyResult<Py<PyAny>> {
+#[pyo3(signature = (filename, multi=false, id3v1_encoding=None, nested=false, bare_ids=false))]
+fn _fast_read(
+    py: Python<'_>, filename: &str, multi: bool, id3v1_encoding: Option<&str>, nested: bool, bare_ids: bool,
+) -> PyResult<Py<PyAny>> {
+    let result = match id3v1_encoding {
+        Some(label) => fast_read_legacy(py, filename, legacy_encoding_arg(label)?)?,
+        None => fast_read_dict(py, filename)?,
+    };
+    if multi {
+        vc_values_to_lists(py, result.bind(py).cast::<PyDict>()?)?;
+    }
+    if bare_ids {
+        id3_keys_to_bare(py, result.bind(py).cast::<PyDict>()?)?;
+    }
+    if nested {
+        return Ok(nest_fast_read(py, result.bind(py).cast::<PyDict>()?)?.into_any().unbind());
+    }
+    Ok(result)
+}
+
+/// Regroup an MP3 fast-read dict under bare frame IDs: every `ID:...` key
+/// (`COMM::eng`, `TXXX:desc`) moves to `ID`, whose value becomes the list of
+/// the grouped values once two keys share it. Only dict entries are replaced,
+/// so the cached values are untouched.
+fn id3_keys_to_bare(py: Python<'_>, dict: &Bound<'_, PyDict>) -> PyResult<()> {
+    let is_mp3 = match dict.get_item(pyo3::intern!(py, "_format"))? {
+        Some(fmt) => fmt.extract::<&str>()? == "mp3",
+        None => false,
+    };
+    let Some(keys) = dict.get_item(pyo3::intern!(py, "_keys"))?.filter(|_| is_mp3) else {
+        return Ok(());
+    };
+    let mut bare: Vec<String> = Vec::new();
+    let mut grouped: Vec<String> = Vec::new();
+    for key in keys.try_iter()? {
+        let key: String = key?.extract()?;
+        let Some((id, _)) = key.split_once(':') else {
+            bare.push(key);
+            continue;
+        };
+        let Some(value) = dict.get_item(key.as_str())? else { continue };
+        dict.del_item(key.as_str())?;
+        match dict.get_item(id)? {
+            None => {
+                dict.set_item(id, value)?;
+                bare.push(id.to_string());
+            }
+            Some(existing) if grouped.iter().any(|g| g == id) => {
+                existing.cast::<PyList>()?.append(value)?;
+            }
+            Some(existing) => {
+                dict.set_item(id, PyList::new(py, [existing, value])?)?;
+                grouped.push(id.to_string());
+            }
+        }
+    }
+    dict.set_item(pyo3::intern!(py, "_keys"), bare)?;
+    Ok(())
+}
+
+/// Nested form of a flat `_fast_read` dict: info and `_` metadata stay at the
+/// top level, the tags listed in `_keys` move to a `"tags"` dict in that order
+/// under their real names, and `_keys` is dropped. Builds a new dict, so the
+/// cached flat one is not touched.
+fn nest_fast_read<'py>(py: Python<'py>, flat: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+    let out = flat.copy()?;
+    let tags = PyDict::new(py);
+    if let Some(keys) = flat.get_item(pyo3::intern!(py, "_keys"))? {
+        for key in keys.try_iter()? {
+            let key = key?;
+            let Some(value) = flat.get_item(&key)? else { continue };
+            out.del_item(&key)?;
+            let name = key.extract::<&str>()?;
+            tags.set_item(name.strip_prefix("tag:").unwrap_or(name), value)?;
+        }
+        out.del_item(pyo3::intern!(py, "_keys"))?;
+    }
+    out.set_item(pyo3::intern!(py, "tags"), tags)?;
+    Ok(out)
+}
+
+/// `_fast_read` for a non-default `id3v1_encoding`. MP3 files skip the caches
+/// (they hold latin-1 results) and have their recoded frames replaced in the
+/// dict; other formats are read as usual.
+fn fast_read_legacy(py: Python<'_>, filename: &str, encoding: id3::specs::LegacyEncoding) -> PyResult<Py<PyAny>> {
+    let data = py.detach(|| read_cached(filename))
+        .map_err(|e| PyIOError::new_err(format!("{}", e)))?;
+    if probe_format(filename, &data) != Some("mp3") {
+        return fast_read_dict(py, filename);
+    }
+    let dict = PyDict::new(py);
+    if !fast_read_mp3_direct(py, &data, filename, &dict)? {
+        return Err(PyValueError::new_err(format!("Unable to parse: {}", filename)));
+    }
+
+    let mut legacy = id3::specs::LegacyEncoding::Latin1;
+    if let Ok(mut mp3_file) = mp3::MP3File::parse(&data, filename) {
+        mp3_file.ensure_tags_parsed(&data);
+        let (used, renamed) = mp3_file.tags.recode_latin1(encoding);
+        legacy = used;
+        if !renamed.is_empty() {
+            let mut keys: Vec<String> = match dict.get_item(pyo3::intern!(py, "_keys"))? {
+                Some(k) => k.extract()?,
+                None => Vec::new(),
+            };
+            for (old, new) in &renamed {
+                let (old, new) = (old.as_str(), new.as_str());
+                if let Some(frame) = mp3_file.tags.get(new) {
+                    dict.del_item(old).ok();
+                    dict.set_item(new, frame_to_py(py, frame))?;
+                    let present = keys.iter().any(|k| k == new);
+                    match keys.iter().position(|k| k == old) {
+                        Some(i) if !present => keys[i] = new.to_string(),
+                        Some(i) => { keys.remove(i); }
+                        None if !present => keys.push(new.to_string()),
+                        None => {}
+                    }
+                }
+            }
+            dict.set_item(pyo3::intern!(py, "_keys"), keys)?;
+        }
+    }
+    dict.set_item(pyo3::intern!(py, "_id3v1_encoding"), legacy.name())?;
+    Ok(dict.into_any().unbind())
+}
+
+/// Wrap every bare tag value of a FLAC/OGG fast-read dict in a one-item list.
+/// Only dict entries are replaced, so lists shared with the caches are untouched.
+fn vc_values_to_lists(py: Python<'_>, dict: &Bound<'_, PyDict>) -> PyResult<()> {
+    let is_vc = match dict.get_item(pyo3::intern!(py, "_format"))? {
+        Some(fmt) => matches!(fmt.extract::<&str>()?, "flac" | "ogg" | "opus" | "caf" | "ape" | "wavpack"),
+        None => false,
+    };
+    if !is_vc {
+        return Ok(());
+    }
+    let Some(keys) = dict.get_item(pyo3::intern!(py, "_keys"))? else {
+        return Ok(());
+    };
+    for key in keys.try_iter()? {
+        let key = key?;
+        if let Some(value) = dict.get_item(&key)? {
+            if !value.is_instance_of::<PyList>() {
+                dict.set_item(&key, PyList::new(py, [value])?)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn fast_read_dict(py: Python<'_>, filename: &str) -> PyResult<Py<PyAny>> {
     // Level 1: Check result cache (warm path)
     {
         let rcache = get_result_cache();
@@ -4436,8 +10226,12 @@ fn _fast_read(py: Python<'_>, filename: &str) -> PyResult<Py<PyAny>> {
         }
     }
 
-    // First read: raw libc I/O + direct parsing (no intermediary structures)
-    let data = fast_file_read(filename)
+    // First read: raw libc I/O + direct parsing (no intermediary structures).
+    // Large MP3s and MP4s only read the ranges their parsers look at. The read
+    // runs detached; the walkers below hold the GIL only while they copy the
+    // (small) tag regions into the dict.
+    let ext = filename.rsplit('.').next().unwrap_or("");
+    let data = py.detach(|| fast_file_read_ranged(filename, ext))
         .map_err(|e| PyIOError::new_err(format!("{}", e)))?;
 
     let dict: Bound<'_, PyDict> = unsafe {
@@ -4448,27 +10242,42 @@ fn _fast_read(py: Python<'_>, filename: &str) -> PyResult<Py<PyAny>> {
         Bound::from_owned_ptr(py, ptr).cast_into_unchecked()
     };
 
-    let ext = filename.rsplit('.').next().unwrap_or("");
     let ok = if ext.eq_ignore_ascii_case("flac") {
         fast_read_flac_direct(py, &data, data.len(), &dict)?
     } else if ext.eq_ignore_ascii_case("ogg") {
-        fast_read_ogg_direct(py, &data, &dict)?
+        fast_read_ogg_direct(py, &data, &dict)? || fast_read_opus_direct(py, &data, &dict)?
+    } else if ext.eq_ignore_ascii_case("opus") {
+        fast_read_opus_direct(py, &data, &dict)?
     } else if ext.eq_ignore_ascii_case("mp3") {
         fast_read_mp3_direct(py, &data, filename, &dict)?
-    } else if ext.eq_ignore_ascii_case("m4a") || ext.eq_ignore_ascii_case("m4b")
-            || ext.eq_ignore_ascii_case("mp4") || ext.eq_ignore_ascii_case("m4v") {
+    } else if mp4::is_mp4_extension(ext) {
         fast_read_mp4_direct(py, &data, filename, &dict)?
+    } else if ext.eq_ignore_ascii_case("caf") {
+        fast_read_caf_direct(py, &data, &dict)?
+    } else if ext.eq_ignore_ascii_case("ape") {
+        fast_read_ape_direct(py, &data, &dict)?
+    } else if ext.eq_ignore_ascii_case("wv") {
+        fast_read_wavpack_direct(py, &data, &dict)?
     } else {
         // Unknown extension: try score-based detection
         let mp3_score = mp3::MP3File::score(filename, &data);
         let flac_score = flac::FLACFile::score(filename, &data);
         let ogg_score = ogg::OggVorbisFile::score(filename, &data);
+        let opus_score = ogg::opus::OpusFile::score(filename, &data);
         let mp4_score = mp4::MP4File::score(filename, &data);
-        let max_score = mp3_score.max(flac_score).max(ogg_score).max(mp4_score);
+        let caf_score = caf::CAFFile::score(filename, &data);
+        let ape_score = ape::APEFile::score(filename, &data);
+        let wavpack_score = wavpack::WavPackFile::score(filename, &data);
+        let max_score = mp3_score.max(flac_score).max(ogg_score).max(opus_score).max(mp4_score).max(caf_score)
+            .max(ape_score).max(wavpack_score);
         if max_score == 0 { false }
         else if max_score == flac_score { fast_read_flac_direct(py, &data, data.len(), &dict)? }
         else if max_score == ogg_score { fast_read_ogg_direct(py, &data, &dict)? }
+        else if max_score == opus_score { fast_read_opus_direct(py, &data, &dict)? }
         else if max_score == mp4_score { fast_read_mp4_direct(py, &data, filename, &dict)? }
+        else if max_score == caf_score { fast_read_caf_direct(py, &data, &dict)? }
+        else if max_score == ape_score { fast_read_ape_direct(py, &data, &dict)? }
+        else if max_score == wavpack_score { fast_read_wavpack_direct(py, &data, &dict)? }
         else { fast_read_mp3_direct(py, &data, filename, &dict)? }
     };
 
@@ -4493,11 +10302,26 @@ fn _fast_read(py: Python<'_>, filename: &str) -> PyResult<Py<PyAny>> {
     Ok(dict.into_any().unbind())
 }
 
+/// Single-file read straight to JSON bytes: skips PyDict construction entirely.
+/// Output follows the `to_json()` schema (see `preserialized_to_json`).
+#[pyfunction]
+#[pyo3(signature = (filename, include_pictures=false))]
+fn _fast_read_json<'py>(py: Python<'py>, filename: &str, include_pictures: bool) -> PyResult<Bound<'py, PyBytes>> {
+    let data = read_cached(filename)
+        .map_err(|e| PyIOError::new_err(format!("{}", e)))?;
+    let pf = parse_and_serialize(&data, filename)
+        .ok_or_else(|| PyValueError::new_err(format!("Unable to parse: {}", filename)))?;
+    let mut out = String::with_capacity(512);
+    preserialized_to_json(&pf, include_pictures, &mut out);
+    Ok(PyBytes::new(py, out.as_bytes()))
+}
+
 /// Batch sequential read: processes all files in a single Rust call.
 /// Eliminates per-file Python→Rust dispatch overhead.
-/// Uses file cache for warm reads.
+/// Uses file cache for warm reads. `multi`, `nested` and `bare_ids` are as for `_fast_read`.
 #[pyfunction]
-fn _fast_read_seq(py: Python<'_>, filenames: Vec<String>) -> PyResult<Py<PyAny>> {
+#[pyo3(signature = (filenames, multi=false, nested=false, bare_ids=false))]
+fn _fast_read_seq(py: Python<'_>, filenames: Vec<String>, multi: bool, nested: bool, bare_ids: bool) -> PyResult<Py<PyAny>> {
     unsafe {
         let result_ptr = pyo3::ffi::PyList_New(0);
         if result_ptr.is_null() {
@@ -4519,10 +10343,12 @@ fn _fast_read_seq(py: Python<'_>, filenames: Vec<String>) -> PyResult<Py<PyAny>>
                 fast_read_flac_direct(py, &data, data.len(), &dict).unwrap_or(false)
             } else if ext.eq_ignore_ascii_case("ogg") {
                 fast_read_ogg_direct(py, &data, &dict).unwrap_or(false)
+                    || fast_read_opus_direct(py, &data, &dict).unwrap_or(false)
+            } else if ext.eq_ignore_ascii_case("opus") {
+                fast_read_opus_direct(py, &data, &dict).unwrap_or(false)
             } else if ext.eq_ignore_ascii_case("mp3") {
                 fast_read_mp3_direct(py, &data, filename, &dict).unwrap_or(false)
-            } else if ext.eq_ignore_ascii_case("m4a") || ext.eq_ignore_ascii_case("m4b")
-                    || ext.eq_ignore_ascii_case("mp4") || ext.eq_ignore_ascii_case("m4v") {
+            } else if mp4::is_mp4_extension(ext) {
                 fast_read_mp4_direct(py, &data, filename, &dict).unwrap_or(false)
             } else {
                 if let Some(pf) = parse_and_serialize(&data, filename) {
@@ -4534,6 +10360,18 @@ fn _fast_read_seq(py: Python<'_>, filenames: Vec<String>) -> PyResult<Py<PyAny>>
             };
 
             if ok {
+                if multi {
+                    vc_values_to_lists(py, &dict).unwrap_or(());
+                }
+                if bare_ids {
+                    id3_keys_to_bare(py, &dict).unwrap_or(());
+                }
+                if nested {
+                    if let Ok(tree) = nest_fast_read(py, &dict) {
+                        pyo3::ffi::PyList_Append(result_ptr, tree.as_ptr());
+                    }
+                    continue;
+                }
                 pyo3::ffi::PyList_Append(result_ptr, dict.as_ptr());
             }
         }
@@ -4554,22 +10392,50 @@ fn mutagen_rs(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyVComment>()?;
     m.add_class::<PyOggVorbis>()?;
     m.add_class::<PyOggVorbisInfo>()?;
+    m.add_class::<PyOggOpus>()?;
+    m.add_class::<PyOggOpusInfo>()?;
     m.add_class::<PyMP4>()?;
     m.add_class::<PyMP4Info>()?;
     m.add_class::<PyMP4Tags>()?;
+    m.add_class::<PyAPEv2>()?;
+    m.add_class::<PyWavPack>()?;
+    m.add_class::<PyWavPackInfo>()?;
+    m.add_class::<PyMonkeysAudio>()?;
+    m.add_class::<PyMonkeysAudioInfo>()?;
     m.add_class::<PyBatchResult>()?;
+    m.add_class::<PyBatchIter>()?;
+    m.add_class::<PyArrowBatch>()?;
     m.add_class::<PyPOPM>()?;
+    m.add_class::<PyReadOnlyFile>()?;
 
     m.add_function(wrap_pyfunction!(file_open, m)?)?;
+    m.add_function(wrap_pyfunction!(open_readonly, m)?)?;
     m.add_function(wrap_pyfunction!(batch_open, m)?)?;
+    m.add_function(wrap_pyfunction!(batch_open_buffers, m)?)?;
     m.add_function(wrap_pyfunction!(batch_diag, m)?)?;
+    m.add_function(wrap_pyfunction!(batch_export_ndjson, m)?)?;
+    m.add_function(wrap_pyfunction!(batch_iter, m)?)?;
+    m.add_function(wrap_pyfunction!(batch_open_arrow, m)?)?;
+    m.add_function(wrap_pyfunction!(batch_total_length, m)?)?;
+    m.add_function(wrap_pyfunction!(batch_count_by_format, m)?)?;
     m.add_function(wrap_pyfunction!(clear_cache, m)?)?;
     m.add_function(wrap_pyfunction!(clear_all_caches, m)?)?;
+    m.add_function(wrap_pyfunction!(_cache_stats, m)?)?;
+    m.add_function(wrap_pyfunction!(read_cached_bytes, m)?)?;
     m.add_function(wrap_pyfunction!(_rust_batch_open, m)?)?;
     m.add_function(wrap_pyfunction!(_fast_read, m)?)?;
+    m.add_function(wrap_pyfunction!(_fast_read_json, m)?)?;
     m.add_function(wrap_pyfunction!(_fast_info, m)?)?;
+    m.add_function(wrap_pyfunction!(duration, m)?)?;
+    m.add_function(wrap_pyfunction!(verify_extension, m)?)?;
+    m.add_function(wrap_pyfunction!(copy_tags, m)?)?;
+    m.add_function(wrap_pyfunction!(mp4_parse_tail, m)?)?;
+    m.add_function(wrap_pyfunction!(has_tags, m)?)?;
+    m.add_function(wrap_pyfunction!(tag_summary, m)?)?;
+    m.add_function(wrap_pyfunction!(batch_tag_summary, m)?)?;
     m.add_function(wrap_pyfunction!(_fast_read_seq, m)?)?;
     m.add_function(wrap_pyfunction!(_fast_batch_read, m)?)?;
+    m.add_function(wrap_pyfunction!(_fast_batch_read_msgpack, m)?)?;
 
     m.add("MutagenError", m.py().get_type::<common::error::MutagenPyError>())?;
     m.add("ID3Error", m.py().get_type::<common::error::ID3Error>())?;
@@ -4580,9 +10446,52 @@ fn mutagen_rs(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add("FLACNoHeaderError", m.py().get_type::<common::error::FLACNoHeaderError>())?;
     m.add("OggError", m.py().get_type::<common::error::OggError>())?;
     m.add("MP4Error", m.py().get_type::<common::error::MP4Error>())?;
+    m.add("CAFError", m.py().get_type::<common::error::CAFError>())?;
+    m.add("MonkeysAudioHeaderError", m.py().get_type::<common::error::MonkeysAudioHeaderError>())?;
+    m.add("MonkeysAudioTruncatedError", m.py().get_type::<common::error::MonkeysAudioTruncatedError>())?;
+    m.add("WavPackHeaderError", m.py().get_type::<common::error::WavPackHeaderError>())?;
+    m.add("APEError", m.py().get_type::<common::error::APEError>())?;
+    m.add("APENoHeaderError", m.py().get_type::<common::error::APENoHeaderError>())?;
 
     m.add("File", wrap_pyfunction!(file_open, m)?)?;
 
+    let release = wrap_pyfunction!(_release_interned_keys, m)?;
+    m.py().import("atexit")?.call_method1("register", (release,))?;
+
     Ok(())
 }
 } // mod python_bindings
+
+/// Entry points for the `fuzz/` targets and the allocation tests into the
+/// batch parsers, which are otherwise private to the Python bindings. Not
+/// part of the public API.
+#[cfg(feature = "python")]
+#[doc(hidden)]
+pub mod fuzz {
+    use crate::python_bindings as pb;
+
+    /// `batch_open`'s per-file work: parse `data` as `path` and serialize the
+    /// result to JSON. Returns the JSON length, or None when nothing parsed.
+    pub fn batch_parse(data: &[u8], path: &str) -> Option<usize> {
+        let pf = pb::parse_and_serialize(data, path)?;
+        let mut out = String::new();
+        pb::preserialized_to_json(&pf, true, &mut out);
+        Some(out.len())
+    }
+
+    /// `batch_open`'s per-file parse alone, without serializing. Returns the
+    /// number of tags, or None when nothing parsed.
+    pub fn batch_tag_count(data: &[u8], path: &str) -> Option<usize> {
+        pb::parse_and_serialize(data, path).map(|pf| pf.tags.len())
+    }
+
+    /// The batch Ogg Vorbis parser on its own.
+    pub fn ogg_batch(data: &[u8]) -> bool {
+        pb::parse_ogg_batch(data).is_some()
+    }
+
+    /// The batch Vorbis comment decoder (FLAC and Ogg comment payloads).
+    pub fn vc_batch_tags(data: &[u8]) -> usize {
+        pb::parse_vc_to_batch_tags(data).len()
+    }
+}
@@ -0,0 +1,216 @@
+use crate::common::error::{MutagenError, Result};
+
+/// Tempo in microseconds per quarter note until a set-tempo event (120 BPM).
+const DEFAULT_TEMPO: u32 = 500_000;
+
+/// Header values and the playing time of a Standard MIDI File.
+#[derive(Debug, Clone, Default)]
+pub struct MIDIInfo {
+    /// Seconds up to the last event, following every set-tempo event.
+    pub length: f64,
+    pub duration_ms: u64,
+    /// 0 (one track), 1 (simultaneous tracks) or 2 (independent sequences).
+    pub format: u16,
+    /// Track count from `MThd`.
+    pub tracks: u16,
+    /// Ticks per quarter note; 0 for SMPTE time division.
+    pub ticks_per_beat: u16,
+    /// Tempo at the first tick in beats per minute.
+    pub bpm: f64,
+}
+
+/// A Standard MIDI File (`.mid`, `.midi`). Read-only; its text meta events
+/// stand in for tags.
+#[derive(Debug, Clone, Default)]
+pub struct MIDIFile {
+    pub info: MIDIInfo,
+    /// The first track name (FF 03) in file order: the sequence name for
+    /// format 0 and 1 files.
+    pub title: Option<String>,
+    /// The first copyright notice (FF 02).
+    pub copyright: Option<String>,
+    /// Text events (FF 01) in file order.
+    pub texts: Vec<String>,
+    pub path: String,
+}
+
+/// One `MTrk` chunk: the tick of its last event and its tempo changes.
+struct Track {
+    end_tick: u64,
+    tempos: Vec<(u64, u32)>,
+}
+
+impl MIDIFile {
+    /// Open and parse a MIDI file.
+    pub fn open(path: &str) -> Result<Self> {
+        let data = std::fs::read(path)?;
+        Self::parse(&data, path)
+    }
+
+    /// Parse the `MThd` header and scan every `MTrk` chunk for meta events.
+    pub fn parse(data: &[u8], path: &str) -> Result<Self> {
+        if data.len() < 14 || &data[0..4] != b"MThd" {
+            return Err(MutagenError::InvalidData("Not a MIDI file".into()));
+        }
+        let header_len = u32::from_be_bytes([data[4], data[5], data[6], data[7]]) as usize;
+        if header_len < 6 {
+            return Err(MutagenError::InvalidData("MThd chunk too short".into()));
+        }
+        let format = u16::from_be_bytes([data[8], data[9]]);
+        let tracks = u16::from_be_bytes([data[10], data[11]]);
+        let division = u16::from_be_bytes([data[12], data[13]]);
+
+        let mut f = MIDIFile { path: path.to_string(), ..Default::default() };
+        let mut parsed = Vec::new();
+        let mut pos = 8usize.saturating_add(header_len);
+        while pos.saturating_add(8) <= data.len() {
+            let size = u32::from_be_bytes([data[pos + 4], data[pos + 5], data[pos + 6], data[pos + 7]]) as usize;
+            let body = &data[pos + 8..pos.saturating_add(8).saturating_add(size).min(data.len())];
+            if &data[pos..pos + 4] == b"MTrk" {
+                parsed.push(f.scan_track(body));
+            }
+            pos = pos.saturating_add(8).saturating_add(size);
+        }
+
+        // Format 2 tracks play one after another, each with its own tempos;
+        // otherwise they play together under the tempo map of all tracks
+        let length = if format == 2 {
+            parsed.iter().map(|t| seconds(t.end_tick, &t.tempos, division)).sum()
+        } else {
+            let mut tempos: Vec<(u64, u32)> = parsed.iter().flat_map(|t| t.tempos.iter().copied()).collect();
+            tempos.sort_by_key(|&(tick, _)| tick);
+            let end = parsed.iter().map(|t| t.end_tick).max().unwrap_or(0);
+            seconds(end, &tempos, division)
+        };
+        let first_tempo = parsed.iter()
+            .flat_map(|t| t.tempos.iter())
+            .find(|&&(tick, _)| tick == 0)
+            .map_or(DEFAULT_TEMPO, |&(_, tempo)| tempo);
+
+        f.info = MIDIInfo {
+            length,
+            duration_ms: (length * 1000.0).round() as u64,
+            format,
+            tracks,
+            ticks_per_beat: if division & 0x8000 == 0 { division } else { 0 },
+            bpm: 60_000_000.0 / first_tempo.max(1) as f64,
+        };
+        Ok(f)
+    }
+
+    /// Walk the events of one track, keeping its text meta events.
+    fn scan_track(&mut self, body: &[u8]) -> Track {
+        let mut track = Track { end_tick: 0, tempos: Vec::new() };
+        let mut pos = 0usize;
+        let mut tick = 0u64;
+        let mut running = 0u8;
+        while pos < body.len() {
+            let Some(delta) = read_vlq(body, &mut pos) else { break };
+            tick += delta as u64;
+            let Some(&first) = body.get(pos) else { break };
+            let status = if first & 0x80 != 0 {
+                pos += 1;
+                first
+            } else {
+                running
+            };
+            match status {
+                0xFF => {
+                    let Some(&kind) = body.get(pos) else { break };
+                    pos += 1;
+                    let Some(len) = read_vlq(body, &mut pos) else { break };
+                    let end = pos.saturating_add(len as usize).min(body.len());
+                    let payload = &body[pos..end];
+                    pos = end;
+                    match kind {
+                        0x01 => self.texts.push(decode_text(payload)),
+                        0x02 if self.copyright.is_none() => self.copyright = Some(decode_text(payload)),
+                        0x03 if self.title.is_none() && !payload.is_empty() => self.title = Some(decode_text(payload)),
+                        0x51 if payload.len() == 3 => {
+                            track.tempos.push((tick, u32::from_be_bytes([0, payload[0], payload[1], payload[2]])));
+                        }
+                        0x2F => {
+                            track.end_tick = tick;
+                            break;
+                        }
+                        _ => {}
+                    }
+                }
+                0xF0 | 0xF7 => {
+                    let Some(len) = read_vlq(body, &mut pos) else { break };
+                    pos = pos.saturating_add(len as usize);
+                }
+                0x80..=0xEF => {
+                    running = status;
+                    pos += if matches!(status & 0xF0, 0xC0 | 0xD0) { 1 } else { 2 };
+                }
+                // Data byte with no running status, or a stray system message
+                _ => break,
+            }
+            track.end_tick = tick;
+        }
+        track
+    }
+
+    /// Score for auto-detection.
+    pub fn score(path: &str, data: &[u8]) -> u32 {
+        let mut score = 0u32;
+
+        let ext = path.rsplit('.').next().unwrap_or("");
+        if ext.eq_ignore_ascii_case("mid") || ext.eq_ignore_ascii_case("midi") {
+            score += 2;
+        }
+
+        if data.len() >= 4 && &data[0..4] == b"MThd" {
+            score += 3;
+        }
+
+        score
+    }
+}
+
+/// Read a variable-length quantity (7 bits per byte, at most 4 bytes).
+fn read_vlq(data: &[u8], pos: &mut usize) -> Option<u32> {
+    let mut value = 0u32;
+    for _ in 0..4 {
+        let byte = *data.get(*pos)?;
+        *pos += 1;
+        value = (value << 7) | (byte & 0x7F) as u32;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+    None
+}
+
+/// Seconds from tick 0 to `end_tick` under `tempos` (sorted `(tick, µs per
+/// quarter note)` changes). SMPTE divisions ignore the tempo.
+fn seconds(end_tick: u64, tempos: &[(u64, u32)], division: u16) -> f64 {
+    if division & 0x8000 != 0 {
+        let fps = match (division >> 8) as u8 as i8 {
+            -29 => 29.97,
+            fps => -(fps as f64),
+        };
+        let ticks_per_frame = (division & 0xFF) as f64;
+        return if fps > 0.0 && ticks_per_frame > 0.0 { end_tick as f64 / (fps * ticks_per_frame) } else { 0.0 };
+    }
+    if division == 0 {
+        return 0.0;
+    }
+    let mut total = 0.0;
+    let (mut tick, mut tempo) = (0u64, DEFAULT_TEMPO);
+    for &(at, next) in tempos.iter().take_while(|&&(at, _)| at < end_tick) {
+        total += (at - tick) as f64 * tempo as f64;
+        (tick, tempo) = (at, next);
+    }
+    total += end_tick.saturating_sub(tick) as f64 * tempo as f64;
+    total / 1_000_000.0 / division as f64
+}
+
+/// Meta event text: UTF-8 when valid, otherwise Latin-1.
+fn decode_text(bytes: &[u8]) -> String {
+    match std::str::from_utf8(bytes) {
+        Ok(s) => s.to_string(),
+        Err(_) => bytes.iter().map(|&b| b as char).collect(),
+    }
+}
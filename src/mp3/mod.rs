@@ -2,6 +2,8 @@ pub mod header;
 pub mod xing;
 
 use crate::common::error::{MutagenError, Result};
+use crate::ape::apev2::{self, APEItem, APEv2Tag};
+use crate::common::util::duration_ms;
 use crate::id3;
 use crate::id3::header::ID3Header;
 use crate::id3::tags::ID3Tags;
@@ -12,6 +14,14 @@ use crate::mp3::xing::{XingHeader, VBRIHeader, BitrateMode};
 #[derive(Debug, Clone)]
 pub struct MPEGInfo {
     pub length: f64,
+    /// Frames × samples per frame when a Xing/VBRI header gives the frame count,
+    /// otherwise `length` × sample rate rounded (a CBR estimate).
+    pub length_samples: u64,
+    /// Whole milliseconds from the frame count, or from the audio size and
+    /// bitrate for the CBR estimate.
+    pub duration_ms: u64,
+    /// Total MPEG frame count from the Xing/Info or VBRI header, if present.
+    pub xing_frames: Option<u32>,
     pub channels: u32,
     pub bitrate: u32,
     pub sample_rate: u32,
@@ -27,7 +37,23 @@ pub struct MPEGInfo {
     pub album_gain: Option<f32>,
 }
 
+/// Length of one MPEG frame in milliseconds: 384 samples for Layer I, 576 for
+/// Layer III of MPEG-2/2.5, 1152 otherwise; 0 without a sample rate.
+pub fn frame_ms(version: f64, layer: u8, sample_rate: u32) -> f64 {
+    let samples = match layer {
+        1 => 384,
+        3 if version != 1.0 => 576,
+        _ => 1152,
+    };
+    if sample_rate == 0 { 0.0 } else { samples as f64 * 1000.0 / sample_rate as f64 }
+}
+
 impl MPEGInfo {
+    /// Length of one frame of this stream in milliseconds (see `frame_ms`).
+    pub fn frame_ms(&self) -> f64 {
+        frame_ms(self.version, self.layer, self.sample_rate)
+    }
+
     /// Parse MPEG audio info from data starting at offset.
     pub fn parse(data: &[u8], offset: usize, file_size: u64) -> Result<Self> {
         let (sync_offset, first_frame) = find_sync(data, offset)
@@ -52,19 +78,25 @@ impl MPEGInfo {
 
         let mut bitrate_mode = BitrateMode::Unknown;
         let mut length = 0.0f64;
+        let mut length_samples = 0u64;
+        let mut duration = 0u64;
         let mut bitrate = first_frame.bitrate * 1000;
         let mut encoder_info = String::new();
         let encoder_settings = String::new();
         let mut track_gain = None;
         let mut track_peak = None;
         let mut album_gain = None;
+        let mut xing_frames = None;
 
         if let Some(xing) = XingHeader::parse(frame_data, version, channel_mode) {
             bitrate_mode = if xing.is_info { BitrateMode::CBR } else { BitrateMode::VBR };
+            xing_frames = xing.frames;
 
             if let (Some(frames), Some(bytes)) = (xing.frames, xing.bytes) {
                 let spf = first_frame.samples_per_frame as f64;
                 length = (frames as f64 * spf) / sample_rate as f64;
+                length_samples = frames as u64 * first_frame.samples_per_frame as u64;
+                duration = duration_ms(length_samples, sample_rate as u64);
                 if length > 0.0 {
                     bitrate = (bytes as f64 * 8.0 / length) as u32;
                 }
@@ -84,9 +116,12 @@ impl MPEGInfo {
             }
         } else if let Some(vbri) = VBRIHeader::parse(frame_data) {
             bitrate_mode = BitrateMode::VBR;
+            xing_frames = Some(vbri.frames);
             if vbri.frames > 0 {
                 let spf = first_frame.samples_per_frame as f64;
                 length = (vbri.frames as f64 * spf) / sample_rate as f64;
+                length_samples = vbri.frames as u64 * first_frame.samples_per_frame as u64;
+                duration = duration_ms(length_samples, sample_rate as u64);
                 if length > 0.0 {
                     bitrate = (vbri.bytes as f64 * 8.0 / length) as u32;
                 }
@@ -98,11 +133,13 @@ impl MPEGInfo {
             let audio_size = file_size as usize - sync_offset;
             if bitrate > 0 {
                 length = audio_size as f64 * 8.0 / bitrate as f64;
+                length_samples = (length * sample_rate as f64).round() as u64;
+                duration = duration_ms(audio_size as u64 * 8, bitrate as u64);
             }
         }
 
         Ok(MPEGInfo {
-            length, channels, bitrate, sample_rate,
+            length, length_samples, duration_ms: duration, xing_frames, channels, bitrate, sample_rate,
             version: version.as_f64(), layer: layer.as_u8(),
             mode, protected, bitrate_mode,
             encoder_info, encoder_settings,
@@ -118,6 +155,20 @@ pub struct MP3File {
     pub info: MPEGInfo,
     pub path: String,
     pub id3_header: Option<ID3Header>,
+    /// An ID3v1 tag closes the file (set by `ensure_tags_parsed`).
+    pub id3v1: bool,
+    /// APEv2 tag at the end of the file, before any ID3v1 tag (set by
+    /// `ensure_tags_parsed`).
+    pub ape: Option<APEv2Tag>,
+}
+
+/// `(2, major)` for an ID3v2 tag, e.g. `(2, 4)`; `(1, 1)` for an ID3v1 tag
+/// alone; `None` when untagged.
+pub fn tag_version(id3_header: Option<&ID3Header>, id3v1: bool) -> Option<(u8, u8)> {
+    match id3_header {
+        Some(h) => Some((2, h.version.0)),
+        None => id3v1.then_some((1, 1)),
+    }
 }
 
 impl MP3File {
@@ -167,6 +218,8 @@ impl MP3File {
             info,
             path: path.to_string(),
             id3_header,
+            id3v1: false,
+            ape: None,
         })
     }
 
@@ -192,16 +245,26 @@ impl MP3File {
         if data.len() >= 128 {
             let v1_data = &data[data.len() - 128..];
             if v1_data.len() >= 3 && &v1_data[0..3] == b"TAG" {
+                self.id3v1 = true;
                 if let Ok(v1_frames) = id3::id3v1::parse_id3v1(v1_data) {
-                    for frame in v1_frames {
-                        let key = frame.hash_key();
-                        if !self.tags.contains_key(&key) {
-                            self.tags.add(frame);
-                        }
-                    }
+                    self.tags.merge_id3v1(v1_frames);
                 }
             }
         }
+
+        self.ape = apev2::parse(data);
+    }
+
+    /// APEv2 items whose field no ID3 frame holds (see `apev2::uncovered_by_id3`).
+    pub fn ape_extra_items(&self) -> Vec<&APEItem> {
+        let Some(ape) = &self.ape else { return Vec::new() };
+        let keys = self.tags.keys();
+        apev2::uncovered_by_id3(ape, &keys)
+    }
+
+    /// Version of the file's ID3 tag (see `tag_version`).
+    pub fn tag_version(&self) -> Option<(u8, u8)> {
+        tag_version(self.id3_header.as_ref(), self.id3v1)
     }
 
     pub fn save(&self) -> Result<()> {
@@ -22,6 +22,13 @@ impl Atom {
     pub fn name_str(&self) -> String {
         String::from_utf8_lossy(&self.name).to_string()
     }
+
+    /// True if the declared size runs past the iterated region (e.g. a truncated
+    /// download); `data_size` then only covers the bytes actually present.
+    #[inline]
+    pub fn is_truncated(&self) -> bool {
+        self.data_size < self.size - self.header_size as usize
+    }
 }
 
 /// Zero-allocation atom iterator over a byte slice region.
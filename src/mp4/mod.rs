@@ -1,30 +1,57 @@
 pub mod atom;
 
 use crate::common::error::{MutagenError, Result};
-use crate::mp4::atom::AtomIter;
+use crate::common::keyindex::KeyIndex;
+use crate::common::shared::SharedBytes;
+use crate::common::util::{
+    check_header_value, duration_ms, key_matches_pattern, BITS_PER_SAMPLE_RANGE, CHANNELS_RANGE,
+    SAMPLE_RATE_RANGE,
+};
+use crate::mp4::atom::{Atom, AtomIter};
+
+/// File extensions handled as MP4: the iTunes ones plus Audible's `.aax`/`.aaxc`.
+pub const EXTENSIONS: &[&str] = &["m4a", "m4b", "mp4", "m4v", "aax", "aaxc"];
+
+/// Whether `ext` (without the dot) is one of `EXTENSIONS`, ignoring case.
+pub fn is_mp4_extension(ext: &str) -> bool {
+    EXTENSIONS.iter().any(|e| ext.eq_ignore_ascii_case(e))
+}
 
 /// MP4 audio information.
 #[derive(Debug, Clone)]
 pub struct MP4Info {
     pub length: f64,
+    /// Duration in samples, from the audio track's `mdhd` (see `length_samples`).
+    pub length_samples: u64,
+    /// `mvhd` duration / timescale in whole milliseconds.
+    pub duration_ms: u64,
     pub channels: u32,
+    /// `"esds"` when the AudioSpecificConfig channel count overrode the
+    /// sample entry's (which was 0 or disagreed), else `"stsd"`.
+    pub channels_source: &'static str,
     pub sample_rate: u32,
     pub bitrate: u32,
     pub bits_per_sample: u32,
     pub codec: String,
     pub codec_description: String,
+    /// Header values that were out of range and read as 0 (see `check_header_value`).
+    pub warnings: Vec<String>,
 }
 
 impl Default for MP4Info {
     fn default() -> Self {
         MP4Info {
             length: 0.0,
+            length_samples: 0,
+            duration_ms: 0,
             channels: 2,
+            channels_source: "stsd",
             sample_rate: 44100,
             bitrate: 0,
             bits_per_sample: 16,
             codec: String::new(),
             codec_description: String::new(),
+            warnings: Vec::new(),
         }
     }
 }
@@ -37,21 +64,40 @@ pub enum MP4CoverFormat {
 }
 
 /// MP4 cover art.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct MP4Cover {
-    pub data: Vec<u8>,
+    pub data: SharedBytes,
     pub format: MP4CoverFormat,
 }
 
 /// MP4 freeform data.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct MP4FreeForm {
-    pub data: Vec<u8>,
+    pub data: SharedBytes,
+    /// Type indicator of the `data` atom (1 = UTF-8 text, 0 = implicit/binary).
     pub dataformat: u32,
 }
 
+impl MP4FreeForm {
+    /// The value as text, if it is stored as UTF-8.
+    pub fn text(&self) -> Option<&str> {
+        if self.dataformat == 1 {
+            std::str::from_utf8(&self.data).ok()
+        } else {
+            None
+        }
+    }
+}
+
+/// A Nero (`chpl`) chapter: start time in seconds and title.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MP4Chapter {
+    pub start: f64,
+    pub title: String,
+}
+
 /// Tag value types in MP4.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum MP4TagValue {
     Text(Vec<String>),
     Integer(Vec<i64>),
@@ -66,6 +112,18 @@ pub enum MP4TagValue {
 #[derive(Debug, Clone, Default)]
 pub struct MP4Tags {
     pub items: Vec<(String, MP4TagValue)>,
+    /// File offset and total size of each ilst item atom as parsed.
+    pub spans: Vec<(String, usize, usize)>,
+    /// Complete item atoms from `set_raw`, written unchanged after the rendered items.
+    pub verbatim: Vec<(String, Vec<u8>)>,
+    /// Chapters to write as `moov/udta/chpl` on save; `None` leaves the file's alone.
+    pub chapters: Option<Vec<MP4Chapter>>,
+    /// Per-value data atom locales for items that carry a non-zero one, in
+    /// value order; written back on save. Setting a key to a different value or
+    /// deleting it drops them.
+    pub locales: Vec<(String, Vec<u32>)>,
+    /// Lookup index over `items`, built once there are many of them.
+    index: KeyIndex<String>,
 }
 
 impl MP4Tags {
@@ -81,42 +139,149 @@ impl MP4Tags {
 
     #[inline]
     pub fn get(&self, key: &str) -> Option<&MP4TagValue> {
-        self.items.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+        self.index.get(&self.items, key).map(|i| &self.items[i].1)
     }
 
     #[inline]
     pub fn get_mut(&mut self, key: &str) -> Option<&mut MP4TagValue> {
-        self.items.iter_mut().find(|(k, _)| k == key).map(|(_, v)| v)
+        self.index.find(&self.items, key).map(|i| &mut self.items[i].1)
     }
 
     #[inline]
     pub fn contains_key(&self, key: &str) -> bool {
-        self.items.iter().any(|(k, _)| k == key)
+        self.get(key).is_some()
+    }
+
+    /// Data atom locale of each value of `key` (0 when none was recorded).
+    pub fn value_locales(&self, key: &str) -> Vec<u32> {
+        let count = match self.get(key) {
+            Some(MP4TagValue::Text(v)) => v.len(),
+            Some(MP4TagValue::Integer(v)) => v.len(),
+            Some(MP4TagValue::IntPair(v)) => v.len(),
+            Some(MP4TagValue::Cover(v)) => v.len(),
+            Some(MP4TagValue::FreeForm(v)) => v.len(),
+            Some(MP4TagValue::Bool(_)) | Some(MP4TagValue::Data(_)) => 1,
+            None => 0,
+        };
+        let stored = self.locales.iter().find(|(k, _)| k == key).map(|(_, l)| l.as_slice()).unwrap_or(&[]);
+        (0..count).map(|i| stored.get(i).copied().unwrap_or(0)).collect()
+    }
+
+    /// Tempo in beats per minute. A fractional value in the
+    /// `----:com.apple.iTunes:BPM` freeform text wins when it parses, since
+    /// the integer `tmpo` atom cannot hold one; otherwise `tmpo`.
+    pub fn bpm(&self) -> Option<f64> {
+        let text = match self.get("----:com.apple.iTunes:BPM") {
+            Some(MP4TagValue::FreeForm(v)) => v.first().and_then(MP4FreeForm::text),
+            Some(MP4TagValue::Text(v)) => v.first().map(String::as_str),
+            _ => None,
+        };
+        if let Some(bpm) = text.and_then(|t| t.trim().parse::<f64>().ok()).filter(|b| b.is_finite()) {
+            return Some(bpm);
+        }
+        match self.get("tmpo") {
+            Some(MP4TagValue::Integer(v)) => v.first().map(|&n| n as f64),
+            _ => None,
+        }
     }
 
     /// Set a tag value, replacing existing or inserting new.
     pub fn set(&mut self, key: &str, value: MP4TagValue) {
-        if let Some((_, v)) = self.items.iter_mut().find(|(k, _)| k == key) {
-            *v = value;
+        if let Some(i) = self.index.find(&self.items, key) {
+            if self.items[i].1 != value {
+                self.items[i].1 = value;
+                self.locales.retain(|(k, _)| k != key);
+            }
         } else {
-            self.items.push((key.to_string(), value));
+            self.push(key.to_string(), value);
         }
     }
 
+    /// Append an item for a key known not to be present.
+    #[inline]
+    fn push(&mut self, key: String, value: MP4TagValue) {
+        self.items.push((key, value));
+        self.index.pushed(&self.items);
+    }
+
     /// Remove a tag by key.
     pub fn delete(&mut self, key: &str) {
         self.items.retain(|(k, _)| k != key);
+        self.verbatim.retain(|(k, _)| k != key);
+        self.locales.retain(|(k, _)| k != key);
+        self.index.clear();
+    }
+
+    /// File offset and size of the item atom parsed for `key`, if any.
+    pub fn raw_span(&self, key: &str) -> Option<(usize, usize)> {
+        self.spans.iter().find(|(k, _, _)| k == key).map(|&(_, off, size)| (off, size))
+    }
+
+    /// Replace `key` with a complete item atom that is written verbatim on save.
+    /// Only the atom header is checked: its size field must equal the data length and
+    /// its name must match `key` (`----` for freeform keys).
+    pub fn set_raw(&mut self, key: &str, atom: Vec<u8>) -> Result<()> {
+        if atom.len() < 8 {
+            return Err(MutagenError::MP4("Raw atom must include an 8-byte header".into()));
+        }
+        let size = u32::from_be_bytes([atom[0], atom[1], atom[2], atom[3]]) as usize;
+        if size != atom.len() {
+            return Err(MutagenError::MP4(format!(
+                "Raw atom size field {} does not match data length {}", size, atom.len()
+            )));
+        }
+        let expected = if key.starts_with("----:") { *b"----" } else { key_to_atom_name(key) };
+        if atom[4..8] != expected {
+            return Err(MutagenError::MP4(format!(
+                "Raw atom name {:?} does not match key {}", String::from_utf8_lossy(&atom[4..8]), key
+            )));
+        }
+        self.delete(key);
+        self.verbatim.push((key.to_string(), atom));
+        Ok(())
     }
 
     /// Render all tags as an ilst atom.
     pub fn render_ilst(&self) -> Vec<u8> {
         let mut ilst_data = Vec::new();
         for (key, value) in &self.items {
-            let item_data = render_tag_item(key, value);
+            let locales = self.locales.iter().find(|(k, _)| k == key).map(|(_, l)| l.as_slice()).unwrap_or(&[]);
+            let item_data = render_tag_item(key, value, locales);
             ilst_data.extend_from_slice(&item_data);
         }
+        for (_, atom) in &self.verbatim {
+            ilst_data.extend_from_slice(atom);
+        }
         make_atom(b"ilst", &ilst_data)
     }
+
+    /// Delete every item whose key matches one of `patterns` (see
+    /// `key_matches_pattern`). Returns the removed keys.
+    pub fn strip(&mut self, patterns: &[&str]) -> Vec<String> {
+        let mut removed: Vec<String> = Vec::new();
+        for key in self.items.iter().map(|(k, _)| k).chain(self.verbatim.iter().map(|(k, _)| k)) {
+            if patterns.iter().any(|p| key_matches_pattern(key, p, false)) && !removed.contains(key) {
+                removed.push(key.clone());
+            }
+        }
+        for key in &removed {
+            self.delete(key);
+        }
+        removed
+    }
+
+    /// Replace the file's chapters on the next save (an empty list removes them).
+    /// `chpl` stores at most 255 chapters and 255-byte titles.
+    pub fn set_chapters(&mut self, chapters: Vec<MP4Chapter>) -> Result<()> {
+        if chapters.len() > 255 {
+            return Err(MutagenError::MP4(format!("chpl holds at most 255 chapters, got {}", chapters.len())));
+        }
+        if let Some(c) = chapters.iter().find(|c| !(c.start >= 0.0 && c.start.is_finite())) {
+            return Err(MutagenError::MP4(format!("Invalid chapter start {}", c.start)));
+        }
+        self.chapters = Some(chapters);
+        Ok(())
+    }
 }
 
 /// Complete MP4 file handler.
@@ -124,34 +289,92 @@ impl MP4Tags {
 pub struct MP4File {
     pub info: MP4Info,
     pub tags: MP4Tags,
+    /// Nero chapters from `moov/udta/chpl`, in file order.
+    pub chapters: Vec<MP4Chapter>,
+    /// Handler type of the metadata box (`mdir` for iTunes-style tags); `None`
+    /// without a `moov/udta/meta/hdlr`.
+    pub meta_handler: Option<String>,
     pub path: String,
     moov_offset: usize,
     moov_size: usize,
+    moov_header_size: usize,
+    /// Offset of the first top-level `mdat`; `None` without one (or from a tail).
+    mdat_offset: Option<usize>,
     file_size: usize,
     parsed: bool,
 }
 
 impl MP4File {
     pub fn open(path: &str) -> Result<Self> {
-        let data = std::fs::read(path)?;
+        use std::io::{Read, Seek, SeekFrom};
+        let mut file = std::fs::File::open(path)?;
+        let total = file.metadata()?.len() as usize;
+        // One read covers ftyp and a faststart moov for most files.
+        let mut head = vec![0u8; total.min(64 * 1024)];
+        file.read_exact(&mut head)?;
+        let data = sparse_read(&head, total, |buf, off| {
+            file.seek(SeekFrom::Start(off as u64))?;
+            file.read_exact(buf)
+        })?;
         let mut f = Self::parse(&data, path)?;
         f.ensure_parsed_with_data(&data);
         Ok(f)
     }
 
+    /// Read tags and info from the last `TAIL_READ` bytes of a `file_len`-byte
+    /// stream, for files that keep `moov` after `mdat`. Returns
+    /// `TailParse::MoovNotInTail` when no complete `moov` ends the file, in
+    /// which case the caller should fetch the head (or the whole file) instead.
+    pub fn parse_tail<R: std::io::Read + std::io::Seek>(reader: &mut R, file_len: u64) -> Result<TailParse> {
+        let n = file_len.min(TAIL_READ as u64);
+        reader.seek(std::io::SeekFrom::Start(file_len - n))?;
+        let mut tail = vec![0u8; n as usize];
+        reader.read_exact(&mut tail)?;
+        Ok(Self::parse_tail_bytes(&tail, file_len))
+    }
+
+    /// Like `parse_tail`, for tail bytes already in memory (e.g. from an HTTP
+    /// range request). `tail` must be the last `tail.len()` bytes of a
+    /// `file_len`-byte file; `file_len` is only used for the bitrate.
+    pub fn parse_tail_bytes(tail: &[u8], file_len: u64) -> TailParse {
+        let Some((moov_offset, moov_size)) = find_trailing_moov(tail) else {
+            return TailParse::MoovNotInTail;
+        };
+        let mut f = MP4File {
+            info: MP4Info::default(),
+            tags: MP4Tags::new(),
+            chapters: Vec::new(),
+            meta_handler: None,
+            path: String::new(),
+            moov_offset,
+            moov_size,
+            moov_header_size: 8,
+            mdat_offset: None,
+            file_size: file_len as usize,
+            parsed: false,
+        };
+        f.ensure_parsed_with_data(tail);
+        TailParse::Parsed(Box::new(f))
+    }
+
     /// Parse: only find moov atom position (zero-copy, no data allocation).
     pub fn parse(data: &[u8], path: &str) -> Result<Self> {
         // Find moov atom using iterator (no Vec allocation for top-level)
         let moov = AtomIter::new(data, 0, data.len())
             .find_name(b"moov")
             .ok_or_else(|| MutagenError::MP4("No moov atom".into()))?;
+        let mdat_offset = AtomIter::new(data, 0, data.len()).find_name(b"mdat").map(|a| a.offset);
 
         Ok(MP4File {
             info: MP4Info::default(),
             tags: MP4Tags::new(),
+            chapters: Vec::new(),
+            meta_handler: None,
             path: path.to_string(),
             moov_offset: moov.data_offset,
             moov_size: moov.data_size,
+            moov_header_size: moov.header_size as usize,
+            mdat_offset,
             file_size: data.len(),
             parsed: false,
         })
@@ -163,7 +386,8 @@ impl MP4File {
             return;
         }
         self.parsed = true;
-        let moov_end = self.moov_offset + self.moov_size;
+        // A truncated file can declare a moov larger than what's on disk.
+        let moov_end = (self.moov_offset + self.moov_size).min(data.len());
         if let Ok(mut info) = parse_mp4_info_iter(data, self.moov_offset, moov_end) {
             if info.length > 0.0 {
                 info.bitrate = (self.file_size as f64 * 8.0 / info.length) as u32;
@@ -173,6 +397,25 @@ impl MP4File {
         if let Ok(tags) = parse_mp4_tags_iter(data, self.moov_offset, moov_end) {
             self.tags = tags;
         }
+        self.chapters = parse_chpl(data, self.moov_offset, moov_end);
+        self.meta_handler = meta_handler_type(data, self.moov_offset, moov_end);
+    }
+
+    /// Offset of the `moov` atom's header in the file. For `parse_tail_bytes`
+    /// results it is relative to the tail.
+    pub fn moov_offset(&self) -> usize {
+        self.moov_offset - self.moov_header_size
+    }
+
+    /// Size of the `moov` atom, header included.
+    pub fn moov_size(&self) -> usize {
+        self.moov_header_size + self.moov_size
+    }
+
+    /// Whether `moov` comes before the first `mdat` ("faststart"), so players
+    /// can start before the whole file arrives. True when there is no `mdat`.
+    pub fn is_faststart(&self) -> bool {
+        self.mdat_offset.is_none_or(|mdat| self.moov_offset() < mdat)
     }
 
     /// Save tags back to the file.
@@ -189,20 +432,205 @@ impl MP4File {
     pub fn score(path: &str, data: &[u8]) -> u32 {
         let mut score = 0u32;
         let ext = path.rsplit('.').next().unwrap_or("");
-        if ext.eq_ignore_ascii_case("m4a") || ext.eq_ignore_ascii_case("m4b")
-            || ext.eq_ignore_ascii_case("mp4") || ext.eq_ignore_ascii_case("m4v") {
+        if is_mp4_extension(ext) {
             score += 2;
         }
 
-        if data.len() >= 8 {
-            let name = &data[4..8];
-            if name == b"ftyp" {
-                score += 3;
-            }
+        if Self::has_leading_ftyp_or_moov(data) {
+            score += 3;
         }
 
         score
     }
+
+    /// Walk the first few top-level atoms looking for `ftyp` or `moov`.
+    /// `ftyp` is normally first, but some real-world files lead with `free`,
+    /// `wide` or even `mdat`, so don't insist on offset 4.
+    fn has_leading_ftyp_or_moov(data: &[u8]) -> bool {
+        const MAX_ATOMS: usize = 8;
+        let mut pos = 0usize;
+        for _ in 0..MAX_ATOMS {
+            if pos + 8 > data.len() {
+                return false;
+            }
+            let name = &data[pos + 4..pos + 8];
+            if name == b"ftyp" || name == b"moov" {
+                return true;
+            }
+            // Stop on anything that doesn't look like an atom header
+            if !name.iter().all(|&b| b.is_ascii_graphic() || b == b' ') {
+                return false;
+            }
+            let size = u32::from_be_bytes([data[pos], data[pos + 1], data[pos + 2], data[pos + 3]]) as u64;
+            let size = match size {
+                0 => return false, // extends to end of file
+                1 if pos + 16 <= data.len() => u64::from_be_bytes([
+                    data[pos + 8], data[pos + 9], data[pos + 10], data[pos + 11],
+                    data[pos + 12], data[pos + 13], data[pos + 14], data[pos + 15],
+                ]),
+                s if s < 8 => return false,
+                s => s,
+            };
+            if size < 8 {
+                return false;
+            }
+            pos = match pos.checked_add(size as usize) {
+                Some(next) => next,
+                None => return false,
+            };
+        }
+        false
+    }
+}
+
+/// Bytes `MP4File::parse_tail` reads from the end of the file.
+pub const TAIL_READ: usize = 256 * 1024;
+
+/// Result of `MP4File::parse_tail`.
+pub enum TailParse {
+    /// The tail held the whole `moov`.
+    Parsed(Box<MP4File>),
+    /// `moov` is at the front (faststart) or larger than the tail read.
+    MoovNotInTail,
+}
+
+/// Atom size and header length at `pos`, or None if the header doesn't fit.
+/// A size of 0 ("to the end") runs to the end of `data`.
+fn atom_extent(data: &[u8], pos: usize) -> Option<(u64, usize)> {
+    let size = u32::from_be_bytes(data.get(pos..pos + 4)?.try_into().unwrap()) as u64;
+    match size {
+        0 => Some(((data.len() - pos) as u64, 8)),
+        1 => Some((u64::from_be_bytes(data.get(pos + 8..pos + 16)?.try_into().unwrap()), 16)),
+        s => Some((s, 8)),
+    }
+}
+
+/// Data offset and size of a `moov` in `tail` that is followed only by whole
+/// top-level atoms up to the end of `tail`. The last such candidate wins, so a
+/// stray `moov` byte pattern inside `mdat` is only considered if nothing
+/// later checks out.
+fn find_trailing_moov(tail: &[u8]) -> Option<(usize, usize)> {
+    let mut search_end = tail.len();
+    while let Some(p) = memchr::memmem::rfind(&tail[..search_end], b"moov") {
+        search_end = p + 3;
+        let Some(start) = p.checked_sub(4) else { break };
+        let Some((size, header)) = atom_extent(tail, start) else { continue };
+        if size < header as u64 || size > (tail.len() - start) as u64 {
+            continue;
+        }
+        // Everything after the candidate must chain to exactly the end
+        let mut pos = start + size as usize;
+        while pos < tail.len() {
+            match atom_extent(tail, pos) {
+                Some((s, h)) if s >= h as u64 && s <= (tail.len() - pos) as u64 => pos += s as usize,
+                _ => break,
+            }
+        }
+        if pos == tail.len() {
+            return Some((start + header, size as usize - header));
+        }
+    }
+    None
+}
+
+/// Build a `total`-byte buffer holding only what `MP4File::parse` and
+/// `ensure_parsed_with_data` read: every top-level atom header plus the whole
+/// `ftyp` and `moov` atoms. Top-level sizes are followed from header to header,
+/// so `mdat` is skipped whether `moov` sits before or after it. `head` holds
+/// the file's first bytes (may be empty); anything past it comes from
+/// `read_at(buf, offset)`. The rest stays zero and is never paged in.
+pub fn sparse_read(
+    head: &[u8],
+    total: usize,
+    mut read_at: impl FnMut(&mut [u8], usize) -> std::io::Result<()>,
+) -> std::io::Result<Vec<u8>> {
+    let mut data = vec![0u8; total];
+    let have = head.len().min(total);
+    data[..have].copy_from_slice(&head[..have]);
+    let mut fill = |data: &mut [u8], start: usize, end: usize| {
+        let start = start.max(have);
+        if start < end { read_at(&mut data[start..end], start) } else { Ok(()) }
+    };
+    let mut pos = 0usize;
+    while pos + 8 <= total {
+        let hdr_end = (pos + 16).min(total);
+        fill(&mut data, pos, hdr_end)?;
+        let hdr = &data[pos..hdr_end];
+        let size = match u32::from_be_bytes([hdr[0], hdr[1], hdr[2], hdr[3]]) {
+            0 => (total - pos) as u64,
+            1 if hdr.len() == 16 => u64::from_be_bytes([
+                hdr[8], hdr[9], hdr[10], hdr[11], hdr[12], hdr[13], hdr[14], hdr[15],
+            ]),
+            1 => break,
+            s => s as u64,
+        };
+        if size < 8 {
+            break;
+        }
+        // A truncated file can declare an atom running past EOF.
+        let end = pos.saturating_add(size.min(usize::MAX as u64) as usize).min(total);
+        if &hdr[4..8] == b"ftyp" || &hdr[4..8] == b"moov" {
+            fill(&mut data, pos, end)?;
+        }
+        pos = end;
+    }
+    Ok(data)
+}
+
+/// Nero `chpl` start times are in 100 ns units.
+const CHPL_TIMESCALE: f64 = 10_000_000.0;
+
+/// Parse chapters from `moov/udta/chpl`: version(1) + flags(3), a reserved
+/// u32 when version is 1, then a u8 count of (u64 start, u8 length, title).
+/// Stops at the first entry that runs past the atom.
+fn parse_chpl(data: &[u8], moov_start: usize, moov_end: usize) -> Vec<MP4Chapter> {
+    let mut chapters = Vec::new();
+    let Some(udta) = AtomIter::new(data, moov_start, moov_end).find_name(b"udta") else {
+        return chapters;
+    };
+    let Some(chpl) = AtomIter::new(data, udta.data_offset, udta.data_offset + udta.data_size)
+        .find_name(b"chpl") else {
+        return chapters;
+    };
+    let Some(body) = data.get(chpl.data_offset..(chpl.data_offset + chpl.data_size).min(data.len())) else {
+        return chapters;
+    };
+    let mut pos = if body.first() == Some(&1) { 8 } else { 4 };
+    let Some(&count) = body.get(pos) else {
+        return chapters;
+    };
+    pos += 1;
+    for _ in 0..count {
+        let Some(hdr) = body.get(pos..pos + 9) else { break };
+        let start = u64::from_be_bytes([hdr[0], hdr[1], hdr[2], hdr[3], hdr[4], hdr[5], hdr[6], hdr[7]]);
+        let len = hdr[8] as usize;
+        let Some(title) = body.get(pos + 9..pos + 9 + len) else { break };
+        chapters.push(MP4Chapter {
+            start: start as f64 / CHPL_TIMESCALE,
+            title: String::from_utf8_lossy(title).into_owned(),
+        });
+        pos += 9 + len;
+    }
+    chapters
+}
+
+/// Render a version 1 `chpl` atom. Titles longer than 255 bytes are cut at a
+/// character boundary.
+fn render_chpl(chapters: &[MP4Chapter]) -> Vec<u8> {
+    let mut body = Vec::with_capacity(9 + chapters.len() * 32);
+    body.extend_from_slice(&[1, 0, 0, 0]); // version 1, flags
+    body.extend_from_slice(&[0u8; 4]); // reserved
+    body.push(chapters.len() as u8);
+    for chapter in chapters {
+        let mut end = chapter.title.len().min(255);
+        while !chapter.title.is_char_boundary(end) {
+            end -= 1;
+        }
+        body.extend_from_slice(&((chapter.start * CHPL_TIMESCALE).round() as u64).to_be_bytes());
+        body.push(end as u8);
+        body.extend_from_slice(&chapter.title.as_bytes()[..end]);
+    }
+    make_atom(b"chpl", &body)
 }
 
 /// Read a variable-length descriptor size (ISO 14496-1).
@@ -218,6 +646,35 @@ fn read_descriptor_length(data: &[u8]) -> (usize, usize) {
     (len, bytes_read)
 }
 
+/// `(timescale, duration)` from an `mdhd` atom's data (after the atom header).
+pub fn parse_mdhd(mdhd_data: &[u8]) -> Option<(u32, u64)> {
+    let d = mdhd_data;
+    match *d.first()? {
+        0 if d.len() >= 20 => Some((
+            u32::from_be_bytes([d[12], d[13], d[14], d[15]]),
+            u32::from_be_bytes([d[16], d[17], d[18], d[19]]) as u64,
+        )),
+        1 if d.len() >= 32 => Some((
+            u32::from_be_bytes([d[20], d[21], d[22], d[23]]),
+            u64::from_be_bytes([d[24], d[25], d[26], d[27], d[28], d[29], d[30], d[31]]),
+        )),
+        _ => None,
+    }
+}
+
+/// Duration in samples at `sample_rate`. The audio track's `mdhd` duration is
+/// exact when its timescale is the sample rate (as encoders write it) and is
+/// rescaled otherwise; without `mdhd` it falls back to `length` (from `mvhd`).
+pub fn length_samples(mdhd: Option<(u32, u64)>, length: f64, sample_rate: u32) -> u64 {
+    match mdhd {
+        Some((timescale, duration)) if timescale == sample_rate => duration,
+        Some((timescale, duration)) if timescale > 0 => {
+            ((duration as u128 * sample_rate as u128 + timescale as u128 / 2) / timescale as u128) as u64
+        }
+        _ => (length * sample_rate as f64).round() as u64,
+    }
+}
+
 /// Parse avgBitrate from an esds atom's data (after the atom header).
 /// Returns the average bitrate in bits/second, or 0 if not found.
 pub fn parse_esds_bitrate(esds_data: &[u8]) -> u32 {
@@ -242,6 +699,107 @@ pub fn parse_esds_bitrate(esds_data: &[u8]) -> u32 {
     u32::from_be_bytes([body[pos], body[pos+1], body[pos+2], body[pos+3]])
 }
 
+/// Channel count from the AudioSpecificConfig in an esds atom's
+/// DecoderSpecificInfo. None when absent or when the layout lives in a
+/// program config element (channelConfiguration 0).
+pub fn parse_esds_channels(esds_data: &[u8]) -> Option<u32> {
+    let body = esds_data.get(4..)?; // skip version(4)
+    if *body.first()? != 0x03 { return None; }
+    let mut pos = 1;
+    pos += read_descriptor_length(body.get(pos..)?).1;
+    let flags = *body.get(pos + 2)?;
+    pos += 3; // ES_ID(2) + flags(1)
+    if flags & 0x80 != 0 { pos += 2; } // dependsOn_ES_ID
+    if flags & 0x40 != 0 { pos += 1 + *body.get(pos)? as usize; } // URL
+    if flags & 0x20 != 0 { pos += 2; } // OCR_ES_Id
+    if *body.get(pos)? != 0x04 { return None; }
+    pos += 1;
+    pos += read_descriptor_length(body.get(pos..)?).1;
+    pos += 13; // objectTypeIndication .. avgBitrate
+    if *body.get(pos)? != 0x05 { return None; }
+    pos += 1;
+    let (len, n) = read_descriptor_length(body.get(pos..)?);
+    pos += n;
+    let asc = body.get(pos..(pos + len).min(body.len()))?;
+
+    let mut bit = 0usize;
+    let mut read = |count: usize| -> Option<u32> {
+        let mut v = 0u32;
+        for _ in 0..count {
+            let byte = *asc.get(bit / 8)?;
+            v = (v << 1) | ((byte >> (7 - bit % 8)) & 1) as u32;
+            bit += 1;
+        }
+        Some(v)
+    };
+    let mut object_type = read(5)?;
+    if object_type == 31 { object_type = 32 + read(6)?; }
+    if read(4)? == 0xF { read(24)?; } // explicit sampling frequency
+    match read(4)? {
+        // Parametric stereo decodes a mono core to two channels
+        1 if object_type == 29 => Some(2),
+        c @ 1..=6 => Some(c),
+        7 => Some(8),
+        11 => Some(7),
+        12 | 14 => Some(8),
+        13 => Some(24),
+        _ => None,
+    }
+}
+
+/// The sample entry's channel count, unless the AudioSpecificConfig gives a
+/// different one. Returns the count and where it came from.
+pub fn resolve_channels(stsd_channels: u32, asc_channels: Option<u32>) -> (u32, &'static str) {
+    match asc_channels {
+        Some(c) if c != stsd_channels => (c, "esds"),
+        _ => (stsd_channels, "stsd"),
+    }
+}
+
+/// The esds atom data of the first sample entry in an stsd atom, given the
+/// stsd data offset and the sample entry's size.
+pub fn find_stsd_esds(data: &[u8], stsd_data_offset: usize, entry_size: usize) -> Option<&[u8]> {
+    if entry_size <= 36 { return None; }
+    let sub_start = stsd_data_offset + 8 + 8 + 28;
+    let sub_end = stsd_data_offset + 8 + entry_size;
+    if sub_end > data.len() { return None; }
+    AtomIter::new(data, sub_start, sub_end)
+        .find(|sub| sub.name == *b"esds")
+        .map(|sub| &data[sub.data_offset..sub.data_offset + sub.data_size])
+}
+
+/// Whether a sample entry is Audible DRM-protected audio: an `aavd` entry, or
+/// one carrying an `adrm` atom after the 28-byte audio entry header.
+pub fn is_audible_drm_entry(data: &[u8], stsd_data_offset: usize, entry_size: usize) -> bool {
+    let entry_start = stsd_data_offset + 8;
+    if entry_start + 8 > data.len() { return false; }
+    if &data[entry_start + 4..entry_start + 8] == b"aavd" { return true; }
+    let sub_start = entry_start + 8 + 28;
+    let sub_end = (entry_start + entry_size).min(data.len());
+    sub_start < sub_end && AtomIter::new(data, sub_start, sub_end).any(|sub| sub.name == *b"adrm")
+}
+
+/// Whether any track in `moov` holds Audible DRM-protected audio (`.aax`).
+/// The tags of such files are plaintext, but they are not rewritten.
+pub fn has_audible_drm(data: &[u8], moov_start: usize, moov_end: usize) -> bool {
+    let child = |atom: &Atom, name: &[u8; 4]| {
+        AtomIter::new(data, atom.data_offset, atom.data_offset + atom.data_size).find_name(name)
+    };
+    AtomIter::new(data, moov_start, moov_end)
+        .filter(|trak| trak.name == *b"trak")
+        .filter_map(|trak| child(&trak, b"mdia"))
+        .filter_map(|mdia| child(&mdia, b"minf"))
+        .filter_map(|minf| child(&minf, b"stbl"))
+        .filter_map(|stbl| child(&stbl, b"stsd"))
+        .any(|stsd| {
+            let entry = stsd.data_offset + 8;
+            entry + 4 <= data.len() && {
+                let size = u32::from_be_bytes([data[entry], data[entry + 1], data[entry + 2], data[entry + 3]]) as usize;
+                is_audible_drm_entry(data, stsd.data_offset, size)
+            }
+        })
+}
+
 /// Parse MP4 audio info using iterators (no intermediate Vec allocations).
 fn parse_mp4_info_iter(data: &[u8], moov_start: usize, moov_end: usize) -> Result<MP4Info> {
     let mut duration = 0u64;
@@ -272,11 +830,13 @@ fn parse_mp4_info_iter(data: &[u8], moov_start: usize, moov_end: usize) -> Resul
     };
 
     let mut channels = 2u32;
+    let mut channels_source = "stsd";
     let mut sample_rate = 44100u32;
     let mut bits_per_sample = 16u32;
     let mut codec = String::from("mp4a");
-    let codec_description = String::new();
+    let mut codec_description = String::new();
     let mut bitrate = 0u32;
+    let mut mdhd = None;
 
     // Walk trak atoms using iterator
     for trak in AtomIter::new(data, moov_start, moov_end) {
@@ -302,6 +862,8 @@ fn parse_mp4_info_iter(data: &[u8], moov_start: usize, moov_end: usize) -> Resul
         });
 
         if !is_audio { continue; }
+        mdhd = AtomIter::new(data, mdia_s, mdia_e).find_name(b"mdhd")
+            .and_then(|a| parse_mdhd(&data[a.data_offset..a.data_offset + a.data_size]));
 
         let minf = match AtomIter::new(data, mdia_s, mdia_e).find_name(b"minf") {
             Some(a) => a,
@@ -323,6 +885,9 @@ fn parse_mp4_info_iter(data: &[u8], moov_start: usize, moov_end: usize) -> Resul
                 let entry_size = u32::from_be_bytes([entry_data[0], entry_data[1], entry_data[2], entry_data[3]]) as usize;
                 let fmt = &entry_data[4..8];
                 codec = String::from_utf8_lossy(fmt).to_string();
+                if is_audible_drm_entry(data, stsd.data_offset, entry_size) {
+                    codec_description = "Audible AAX".to_string();
+                }
 
                 let audio_entry = &entry_data[8..];
                 if audio_entry.len() >= 20 {
@@ -334,17 +899,10 @@ fn parse_mp4_info_iter(data: &[u8], moov_start: usize, moov_end: usize) -> Resul
                 }
                 // Look for esds sub-atom after the 28-byte audio entry header
                 if entry_size > 36 && audio_entry.len() >= entry_size - 8 {
-                    let sub_start = stsd.data_offset + 8 + 8 + 28;
-                    let sub_end = stsd.data_offset + 8 + entry_size;
-                    if sub_end <= data.len() {
-                        for sub in AtomIter::new(data, sub_start, sub_end) {
-                            if sub.name == *b"esds" {
-                                let esds = &data[sub.data_offset..sub.data_offset + sub.data_size];
-                                let avg = parse_esds_bitrate(esds);
-                                if avg > 0 { bitrate = avg; }
-                                break;
-                            }
-                        }
+                    if let Some(esds) = find_stsd_esds(data, stsd.data_offset, entry_size) {
+                        let avg = parse_esds_bitrate(esds);
+                        if avg > 0 { bitrate = avg; }
+                        (channels, channels_source) = resolve_channels(channels, parse_esds_channels(esds));
                     }
                 }
             }
@@ -356,17 +914,47 @@ fn parse_mp4_info_iter(data: &[u8], moov_start: usize, moov_end: usize) -> Resul
         bitrate = (data.len() as f64 * 8.0 / length) as u32;
     }
 
+    let mut warnings = Vec::new();
+    let (channels, bits_per_sample, sample_rate) =
+        check_audio_entry(channels, bits_per_sample, sample_rate, &mut warnings);
+
     Ok(MP4Info {
         length,
+        length_samples: length_samples(mdhd, length, sample_rate),
+        duration_ms: duration_ms(duration, timescale as u64),
         channels,
+        channels_source,
         sample_rate,
         bitrate,
         bits_per_sample,
         codec,
         codec_description,
+        warnings,
     })
 }
 
+/// Audio sample entry `(channels, bits_per_sample, sample_rate)` with each
+/// value range-checked (see `check_header_value`).
+pub fn check_audio_entry(channels: u32, bits_per_sample: u32, sample_rate: u32, warnings: &mut Vec<String>) -> (u32, u32, u32) {
+    (
+        check_header_value("channels", channels, CHANNELS_RANGE, warnings),
+        check_header_value("bits_per_sample", bits_per_sample, BITS_PER_SAMPLE_RANGE, warnings),
+        check_header_value("sample_rate", sample_rate, SAMPLE_RATE_RANGE, warnings),
+    )
+}
+
+/// Handler type of the `hdlr` in `moov/udta/meta` between `moov_start` and
+/// `moov_end`, e.g. `mdir`; `None` when there is no such box.
+pub fn meta_handler_type(data: &[u8], moov_start: usize, moov_end: usize) -> Option<String> {
+    let udta = AtomIter::new(data, moov_start, moov_end).find_name(b"udta")?;
+    let meta = AtomIter::new(data, udta.data_offset, udta.data_offset + udta.data_size).find_name(b"meta")?;
+    // meta has 4 bytes of version/flags before its children
+    let hdlr = AtomIter::new(data, meta.data_offset + 4, meta.data_offset + meta.data_size).find_name(b"hdlr")?;
+    // Version/flags and pre_defined come before the handler type
+    let body = data.get(hdlr.data_offset..hdlr.data_offset + hdlr.data_size)?;
+    body.get(8..12).map(|t| String::from_utf8_lossy(t).into_owned())
+}
+
 /// Parse MP4 tags using iterators (no intermediate Vec allocations).
 fn parse_mp4_tags_iter(data: &[u8], moov_start: usize, moov_end: usize) -> Result<MP4Tags> {
     let mut tags = MP4Tags::new();
@@ -395,8 +983,14 @@ fn parse_mp4_tags_iter(data: &[u8], moov_start: usize, moov_end: usize) -> Resul
         None => return Ok(tags),
     };
 
+    let mut payloads = IlstPayloads::new(data, ilst.data_offset, ilst.data_offset + ilst.data_size);
+
     // Iterate ilst children
     for item_atom in AtomIter::new(data, ilst.data_offset, ilst.data_offset + ilst.data_size) {
+        // Keep only items that are fully present; everything after a cut-off one is gone too.
+        if item_atom.is_truncated() {
+            break;
+        }
         let item_start = item_atom.data_offset;
         let item_end = item_atom.data_offset + item_atom.data_size;
 
@@ -406,6 +1000,7 @@ fn parse_mp4_tags_iter(data: &[u8], moov_start: usize, moov_end: usize) -> Resul
         } else {
             atom_name_to_key(&item_atom.name)
         };
+        tags.spans.push((key.clone(), item_atom.offset, item_atom.size));
 
         // Iterate data atoms within each item
         for data_atom in AtomIter::new(data, item_start, item_end) {
@@ -416,13 +1011,33 @@ fn parse_mp4_tags_iter(data: &[u8], moov_start: usize, moov_end: usize) -> Resul
                 }
 
                 let type_indicator = u32::from_be_bytes([atom_data[0], atom_data[1], atom_data[2], atom_data[3]]);
+                let locale = u32::from_be_bytes([atom_data[4], atom_data[5], atom_data[6], atom_data[7]]);
                 let value_data = &atom_data[8..];
-
-                let value = parse_mp4_data_value(&key, type_indicator, value_data);
+                let value_start = data_atom.data_offset + 8;
+                let value_end = data_atom.data_offset + data_atom.data_size;
+
+                // Freeform items keep each data atom's bytes and type so a save
+                // writes them back unchanged.
+                let value = if item_atom.name == *b"----" {
+                    Some(MP4TagValue::FreeForm(vec![MP4FreeForm {
+                        data: payloads.slice(value_start, value_end),
+                        dataformat: type_indicator,
+                    }]))
+                } else {
+                    parse_mp4_data_value(&key, type_indicator, value_data, || payloads.slice(value_start, value_end))
+                };
                 if let Some(v) = value {
-                    match tags.get_mut(&key) {
+                    // Values of one item stay separate entries; each keeps its
+                    // own locale so e.g. a French and an English ©cmt survive a save.
+                    let index = match tags.get_mut(&key) {
                         Some(existing) => merge_mp4_values(existing, v),
-                        None => { tags.items.push((key.clone(), v)); }
+                        None => {
+                            tags.push(key.clone(), v);
+                            Some(0)
+                        }
+                    };
+                    if let (Some(i), true) = (index, locale != 0) {
+                        record_locale(&mut tags.locales, &key, i, locale);
                     }
                 }
             }
@@ -472,7 +1087,37 @@ fn atom_name_to_key(name: &[u8; 4]) -> String {
     }
 }
 
-fn parse_mp4_data_value(key: &str, type_indicator: u32, data: &[u8]) -> Option<MP4TagValue> {
+/// Binary item values as ranges of one copy of the `ilst` payload, made when
+/// the first such value is read; text-only tags never copy it.
+struct IlstPayloads<'a> {
+    data: &'a [u8],
+    start: usize,
+    end: usize,
+    shared: Option<SharedBytes>,
+}
+
+impl<'a> IlstPayloads<'a> {
+    fn new(data: &'a [u8], start: usize, end: usize) -> Self {
+        IlstPayloads { data, start, end: end.min(data.len()), shared: None }
+    }
+
+    /// File range `start..end`, which must lie within the `ilst` payload.
+    fn slice(&mut self, start: usize, end: usize) -> SharedBytes {
+        let (data, base, ilst_end) = (self.data, self.start, self.end);
+        self.shared
+            .get_or_insert_with(|| data[base..ilst_end].to_vec().into())
+            .slice(start - base, end - base)
+    }
+}
+
+/// Decode one `data` atom payload; `shared` returns the same bytes as a range
+/// of the shared `ilst` copy, for covers.
+fn parse_mp4_data_value(
+    key: &str,
+    type_indicator: u32,
+    data: &[u8],
+    shared: impl FnOnce() -> SharedBytes,
+) -> Option<MP4TagValue> {
     match type_indicator {
         1 => {
             let text = String::from_utf8_lossy(data).to_string();
@@ -484,13 +1129,13 @@ fn parse_mp4_data_value(key: &str, type_indicator: u32, data: &[u8]) -> Option<M
         }
         13 => {
             Some(MP4TagValue::Cover(vec![MP4Cover {
-                data: data.to_vec(),
+                data: shared(),
                 format: MP4CoverFormat::JPEG,
             }]))
         }
         14 => {
             Some(MP4TagValue::Cover(vec![MP4Cover {
-                data: data.to_vec(),
+                data: shared(),
                 format: MP4CoverFormat::PNG,
             }]))
         }
@@ -550,17 +1195,40 @@ fn parse_mp4_data_value(key: &str, type_indicator: u32, data: &[u8]) -> Option<M
     }
 }
 
-fn merge_mp4_values(existing: &mut MP4TagValue, new: MP4TagValue) {
+/// Append `new`'s values to `existing`; returns the index of the first one
+/// appended, or `None` if the types differ and `new` was dropped.
+fn merge_mp4_values(existing: &mut MP4TagValue, new: MP4TagValue) -> Option<usize> {
+    fn extend<T>(v: &mut Vec<T>, new_v: Vec<T>) -> Option<usize> {
+        let at = v.len();
+        v.extend(new_v);
+        Some(at)
+    }
     match (existing, new) {
-        (MP4TagValue::Text(ref mut v), MP4TagValue::Text(new_v)) => v.extend(new_v),
-        (MP4TagValue::Integer(ref mut v), MP4TagValue::Integer(new_v)) => v.extend(new_v),
-        (MP4TagValue::Cover(ref mut v), MP4TagValue::Cover(new_v)) => v.extend(new_v),
-        (MP4TagValue::FreeForm(ref mut v), MP4TagValue::FreeForm(new_v)) => v.extend(new_v),
-        (MP4TagValue::IntPair(ref mut v), MP4TagValue::IntPair(new_v)) => v.extend(new_v),
-        _ => {}
+        (MP4TagValue::Text(ref mut v), MP4TagValue::Text(new_v)) => extend(v, new_v),
+        (MP4TagValue::Integer(ref mut v), MP4TagValue::Integer(new_v)) => extend(v, new_v),
+        (MP4TagValue::Cover(ref mut v), MP4TagValue::Cover(new_v)) => extend(v, new_v),
+        (MP4TagValue::FreeForm(ref mut v), MP4TagValue::FreeForm(new_v)) => extend(v, new_v),
+        (MP4TagValue::IntPair(ref mut v), MP4TagValue::IntPair(new_v)) => extend(v, new_v),
+        _ => None,
     }
 }
 
+/// Record `locale` for value `index` of `key`, padding earlier values with 0.
+fn record_locale(locales: &mut Vec<(String, Vec<u32>)>, key: &str, index: usize, locale: u32) {
+    let pos = match locales.iter().position(|(k, _)| k == key) {
+        Some(pos) => pos,
+        None => {
+            locales.push((key.to_string(), Vec::new()));
+            locales.len() - 1
+        }
+    };
+    let values = &mut locales[pos].1;
+    if values.len() <= index {
+        values.resize(index + 1, 0);
+    }
+    values[index] = locale;
+}
+
 // ────────────────────────────────────────────────────────
 // MP4 Write Support
 // ────────────────────────────────────────────────────────
@@ -575,15 +1243,15 @@ fn make_atom(name: &[u8; 4], data: &[u8]) -> Vec<u8> {
     buf
 }
 
-/// Build a data atom with type indicator and locale (0).
-fn make_data_atom(type_indicator: u32, payload: &[u8]) -> Vec<u8> {
+/// Build a data atom with type indicator and locale.
+fn make_data_atom(type_indicator: u32, locale: u32, payload: &[u8]) -> Vec<u8> {
     // data atom: [size][name="data"][type(4)][locale(4)][payload]
     let data_size = (8 + 4 + 4 + payload.len()) as u32;
     let mut buf = Vec::with_capacity(data_size as usize);
     buf.extend_from_slice(&data_size.to_be_bytes());
     buf.extend_from_slice(b"data");
     buf.extend_from_slice(&type_indicator.to_be_bytes());
-    buf.extend_from_slice(&[0u8; 4]); // locale
+    buf.extend_from_slice(&locale.to_be_bytes());
     buf.extend_from_slice(payload);
     buf
 }
@@ -610,73 +1278,79 @@ fn key_to_atom_name(key: &str) -> [u8; 4] {
 }
 
 /// Render a single tag item as an atom (item_atom wrapping data atoms).
-fn render_tag_item(key: &str, value: &MP4TagValue) -> Vec<u8> {
+fn render_tag_item(key: &str, value: &MP4TagValue, locales: &[u32]) -> Vec<u8> {
     // Freeform (----) atoms have a special structure
     if key.starts_with("----:") {
-        return render_freeform_item(key, value);
+        return render_freeform_item(key, value, locales);
     }
 
     let atom_name = key_to_atom_name(key);
-    let data_atoms = render_data_atoms(key, value);
+    let data_atoms = render_data_atoms(value, locales, integer_min_width(key));
     make_atom(&atom_name, &data_atoms)
 }
 
-/// Render data atoms for a tag value.
-fn render_data_atoms(_key: &str, value: &MP4TagValue) -> Vec<u8> {
+/// Fewest bytes an integer item is written in, as iTunes stores it: 8 for the
+/// `plID` collection ID, 4 for the other store IDs, 1 for anything else.
+fn integer_min_width(key: &str) -> usize {
+    match key {
+        "plID" => 8,
+        "cnID" | "geID" | "atID" | "sfID" | "cmID" => 4,
+        _ => 1,
+    }
+}
+
+/// Render data atoms for a tag value; `locales[i]` is the locale of value `i`
+/// (0 past its end). Integers take the smallest of 1, 2, 4 or 8 bytes that
+/// holds them, and at least `int_width`.
+fn render_data_atoms(value: &MP4TagValue, locales: &[u32], int_width: usize) -> Vec<u8> {
+    let loc = |i: usize| locales.get(i).copied().unwrap_or(0);
     let mut buf = Vec::new();
     match value {
         MP4TagValue::Text(texts) => {
-            for text in texts {
-                buf.extend_from_slice(&make_data_atom(1, text.as_bytes()));
+            for (i, text) in texts.iter().enumerate() {
+                buf.extend_from_slice(&make_data_atom(1, loc(i), text.as_bytes()));
             }
         }
         MP4TagValue::Integer(ints) => {
-            for &val in ints {
-                // Use the smallest representation that fits
-                let payload = if val >= i8::MIN as i64 && val <= i8::MAX as i64 {
-                    vec![val as u8]
-                } else if val >= i16::MIN as i64 && val <= i16::MAX as i64 {
-                    (val as i16).to_be_bytes().to_vec()
-                } else if val >= i32::MIN as i64 && val <= i32::MAX as i64 {
-                    (val as i32).to_be_bytes().to_vec()
-                } else {
-                    val.to_be_bytes().to_vec()
-                };
-                buf.extend_from_slice(&make_data_atom(21, &payload));
+            for (i, &val) in ints.iter().enumerate() {
+                let fits = |width: usize| width == 8 || (-(1i64 << (width * 8 - 1))..1i64 << (width * 8 - 1)).contains(&val);
+                let width = [1, 2, 4, 8].into_iter().find(|&w| w >= int_width && fits(w)).unwrap_or(8);
+                // The low `width` bytes of the big-endian i64 are its two's complement at that width
+                buf.extend_from_slice(&make_data_atom(21, loc(i), &val.to_be_bytes()[8 - width..]));
             }
         }
         MP4TagValue::IntPair(pairs) => {
-            for &(a, b) in pairs {
+            for (i, &(a, b)) in pairs.iter().enumerate() {
                 // trkn/disk format: 2 bytes padding + 2 bytes num + 2 bytes total + 2 bytes padding
                 let mut payload = vec![0u8; 8];
                 payload[2..4].copy_from_slice(&(a as i16).to_be_bytes());
                 payload[4..6].copy_from_slice(&(b as i16).to_be_bytes());
-                buf.extend_from_slice(&make_data_atom(0, &payload));
+                buf.extend_from_slice(&make_data_atom(0, loc(i), &payload));
             }
         }
         MP4TagValue::Bool(val) => {
-            buf.extend_from_slice(&make_data_atom(21, &[if *val { 1 } else { 0 }]));
+            buf.extend_from_slice(&make_data_atom(21, loc(0), &[if *val { 1 } else { 0 }]));
         }
         MP4TagValue::Cover(covers) => {
-            for cover in covers {
+            for (i, cover) in covers.iter().enumerate() {
                 let type_ind = cover.format as u32;
-                buf.extend_from_slice(&make_data_atom(type_ind, &cover.data));
+                buf.extend_from_slice(&make_data_atom(type_ind, loc(i), &cover.data));
             }
         }
         MP4TagValue::FreeForm(forms) => {
-            for form in forms {
-                buf.extend_from_slice(&make_data_atom(form.dataformat, &form.data));
+            for (i, form) in forms.iter().enumerate() {
+                buf.extend_from_slice(&make_data_atom(form.dataformat, loc(i), &form.data));
             }
         }
         MP4TagValue::Data(d) => {
-            buf.extend_from_slice(&make_data_atom(0, d));
+            buf.extend_from_slice(&make_data_atom(0, loc(0), d));
         }
     }
     buf
 }
 
 /// Render a freeform (----) atom with mean/name/data sub-atoms.
-fn render_freeform_item(key: &str, value: &MP4TagValue) -> Vec<u8> {
+fn render_freeform_item(key: &str, value: &MP4TagValue, locales: &[u32]) -> Vec<u8> {
     // Parse key: "----:com.apple.iTunes:NAME"
     let parts: Vec<&str> = key.splitn(3, ':').collect();
     let mean = if parts.len() > 1 { parts[1] } else { "com.apple.iTunes" };
@@ -699,7 +1373,7 @@ fn render_freeform_item(key: &str, value: &MP4TagValue) -> Vec<u8> {
     inner.extend_from_slice(name.as_bytes());
 
     // data atoms
-    inner.extend_from_slice(&render_data_atoms(key, value));
+    inner.extend_from_slice(&render_data_atoms(value, locales, 1));
 
     make_atom(b"----", &inner)
 }
@@ -709,7 +1383,8 @@ fn render_freeform_item(key: &str, value: &MP4TagValue) -> Vec<u8> {
 /// Strategy:
 /// 1. Read file, locate moov atom
 /// 2. Build new ilst from tags
-/// 3. Rebuild moov with new ilst (preserving non-tag atoms)
+/// 3. Rebuild moov with new ilst (preserving non-tag atoms), and a new chpl
+///    if chapters were set
 /// 4. If moov size changed and moov is before mdat, fix stco/co64 offsets
 /// 5. Write output file
 pub fn save_mp4_tags(path: &str, tags: &MP4Tags) -> Result<()> {
@@ -719,14 +1394,21 @@ pub fn save_mp4_tags(path: &str, tags: &MP4Tags) -> Result<()> {
     let moov = AtomIter::new(&data, 0, data.len())
         .find_name(b"moov")
         .ok_or_else(|| MutagenError::MP4("No moov atom found".into()))?;
+    if has_audible_drm(&data, moov.data_offset, moov.data_offset + moov.data_size) {
+        return Err(MutagenError::MP4("Saving Audible AAX (DRM-protected) files is not supported".into()));
+    }
 
     let moov_start = moov.offset; // includes header
     let moov_header_size = moov.header_size as usize;
     let moov_body_start = moov.data_offset;
     let moov_body_end = moov.data_offset + moov.data_size;
 
-    // Render new ilst
+    // Render new ilst, and chpl if chapters were set (empty list = remove)
     let new_ilst = tags.render_ilst();
+    let new_chpl = match &tags.chapters {
+        Some(chapters) if !chapters.is_empty() => Some(render_chpl(chapters)),
+        _ => None,
+    };
 
     // Rebuild moov body: keep all atoms except udta, then append new udta/meta/ilst
     let mut new_moov_body = Vec::new();
@@ -742,22 +1424,32 @@ pub fn save_mp4_tags(path: &str, tags: &MP4Tags) -> Result<()> {
             for ua in AtomIter::new(&data, atom.data_offset, atom.data_offset + atom.data_size) {
                 if ua.name == *b"meta" {
                     had_meta = true;
-                    // Rebuild meta: keep non-ilst atoms, insert new ilst
+                    // Rebuild meta: keep non-ilst atoms (hdlr verbatim), insert new ilst
                     let mut new_meta_body = Vec::with_capacity(4 + new_ilst.len());
-                    // meta has 4 bytes version/flags
+                    // meta has 4 bytes version/flags, kept as they were
                     let meta_inner_start = ua.data_offset + 4;
                     let meta_inner_end = ua.data_offset + ua.data_size;
-                    new_meta_body.extend_from_slice(&[0u8; 4]); // version/flags
+                    if meta_inner_start <= meta_inner_end {
+                        new_meta_body.extend_from_slice(&data[ua.data_offset..meta_inner_start]);
+                    } else {
+                        new_meta_body.extend_from_slice(&[0u8; 4]);
+                    }
 
+                    let mut had_hdlr = false;
                     if meta_inner_start < meta_inner_end {
                         // Copy non-ilst atoms from original meta
                         for ma in AtomIter::new(&data, meta_inner_start, meta_inner_end) {
                             if ma.name != *b"ilst" {
+                                had_hdlr |= ma.name == *b"hdlr";
                                 let orig = &data[ma.offset..ma.offset + ma.size];
                                 new_meta_body.extend_from_slice(orig);
                             }
                         }
                     }
+                    // Synthesize a handler only when the meta had none
+                    if !had_hdlr && !new_ilst.is_empty() {
+                        new_meta_body.splice(4..4, make_meta_hdlr());
+                    }
 
                     // Append new ilst (even if empty, to clear tags)
                     if !new_ilst.is_empty() {
@@ -765,6 +1457,8 @@ pub fn save_mp4_tags(path: &str, tags: &MP4Tags) -> Result<()> {
                     }
 
                     new_udta_body.extend_from_slice(&make_atom(b"meta", &new_meta_body));
+                } else if ua.name == *b"chpl" && tags.chapters.is_some() {
+                    // Replaced (or dropped) below
                 } else {
                     // Copy other udta children as-is
                     let orig = &data[ua.offset..ua.offset + ua.size];
@@ -781,6 +1475,9 @@ pub fn save_mp4_tags(path: &str, tags: &MP4Tags) -> Result<()> {
                 meta_body.extend_from_slice(&new_ilst);
                 new_udta_body.extend_from_slice(&make_atom(b"meta", &meta_body));
             }
+            if let Some(chpl) = &new_chpl {
+                new_udta_body.extend_from_slice(chpl);
+            }
 
             new_moov_body.extend_from_slice(&make_atom(b"udta", &new_udta_body));
         } else {
@@ -791,13 +1488,16 @@ pub fn save_mp4_tags(path: &str, tags: &MP4Tags) -> Result<()> {
     }
 
     if !had_udta && !new_ilst.is_empty() {
-        // Create udta/meta/ilst from scratch
+        // Create udta/meta/ilst (+ chpl) from scratch
         let mut meta_body = Vec::new();
         meta_body.extend_from_slice(&[0u8; 4]); // version/flags
         meta_body.extend_from_slice(&make_meta_hdlr());
         meta_body.extend_from_slice(&new_ilst);
-        let meta_atom = make_atom(b"meta", &meta_body);
-        new_moov_body.extend_from_slice(&make_atom(b"udta", &meta_atom));
+        let mut udta_body = make_atom(b"meta", &meta_body);
+        if let Some(chpl) = &new_chpl {
+            udta_body.extend_from_slice(chpl);
+        }
+        new_moov_body.extend_from_slice(&make_atom(b"udta", &udta_body));
     }
 
     // Build new moov atom
@@ -868,41 +1568,37 @@ fn fix_chunk_offsets_in(buf: &mut [u8], start: usize, end: usize, delta: i64) {
         let data_end = pos + size;
 
         match &name {
-            b"stco" => {
+            b"stco" if data_end - data_start >= 8 => {
                 // stco: version(1) + flags(3) + entry_count(4) + entries(4 each)
-                if data_end - data_start >= 8 {
-                    let count = u32::from_be_bytes([
-                        buf[data_start + 4], buf[data_start + 5],
-                        buf[data_start + 6], buf[data_start + 7],
-                    ]) as usize;
-                    let entries_start = data_start + 8;
-                    for i in 0..count {
-                        let off = entries_start + i * 4;
-                        if off + 4 > data_end { break; }
-                        let old = u32::from_be_bytes([buf[off], buf[off + 1], buf[off + 2], buf[off + 3]]);
-                        let new_val = (old as i64 + delta) as u32;
-                        buf[off..off + 4].copy_from_slice(&new_val.to_be_bytes());
-                    }
+                let count = u32::from_be_bytes([
+                    buf[data_start + 4], buf[data_start + 5],
+                    buf[data_start + 6], buf[data_start + 7],
+                ]) as usize;
+                let entries_start = data_start + 8;
+                for i in 0..count {
+                    let off = entries_start + i * 4;
+                    if off + 4 > data_end { break; }
+                    let old = u32::from_be_bytes([buf[off], buf[off + 1], buf[off + 2], buf[off + 3]]);
+                    let new_val = (old as i64 + delta) as u32;
+                    buf[off..off + 4].copy_from_slice(&new_val.to_be_bytes());
                 }
             }
-            b"co64" => {
+            b"co64" if data_end - data_start >= 8 => {
                 // co64: version(1) + flags(3) + entry_count(4) + entries(8 each)
-                if data_end - data_start >= 8 {
-                    let count = u32::from_be_bytes([
-                        buf[data_start + 4], buf[data_start + 5],
-                        buf[data_start + 6], buf[data_start + 7],
-                    ]) as usize;
-                    let entries_start = data_start + 8;
-                    for i in 0..count {
-                        let off = entries_start + i * 8;
-                        if off + 8 > data_end { break; }
-                        let old = u64::from_be_bytes([
-                            buf[off], buf[off + 1], buf[off + 2], buf[off + 3],
-                            buf[off + 4], buf[off + 5], buf[off + 6], buf[off + 7],
-                        ]);
-                        let new_val = (old as i64 + delta) as u64;
-                        buf[off..off + 8].copy_from_slice(&new_val.to_be_bytes());
-                    }
+                let count = u32::from_be_bytes([
+                    buf[data_start + 4], buf[data_start + 5],
+                    buf[data_start + 6], buf[data_start + 7],
+                ]) as usize;
+                let entries_start = data_start + 8;
+                for i in 0..count {
+                    let off = entries_start + i * 8;
+                    if off + 8 > data_end { break; }
+                    let old = u64::from_be_bytes([
+                        buf[off], buf[off + 1], buf[off + 2], buf[off + 3],
+                        buf[off + 4], buf[off + 5], buf[off + 6], buf[off + 7],
+                    ]);
+                    let new_val = (old as i64 + delta) as u64;
+                    buf[off..off + 8].copy_from_slice(&new_val.to_be_bytes());
                 }
             }
             // Container atoms: recurse into children
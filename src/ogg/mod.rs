@@ -1,7 +1,9 @@
-use std::io::{Read, Write, Seek, SeekFrom};
 use crate::common::error::{MutagenError, Result};
+use crate::common::util::{check_header_value, duration_ms, CHANNELS_RANGE, SAMPLE_RATE_RANGE};
 use crate::vorbis::VorbisComment;
 
+pub mod opus;
+
 /// A single OGG page.
 #[derive(Debug, Clone)]
 pub struct OggPage {
@@ -159,15 +161,93 @@ pub fn find_last_granule(data: &[u8], serial: u32) -> Option<i64> {
     None
 }
 
+/// Identification header `(channels, sample_rate)`, range-checked (see
+/// `check_header_value`). A rejected rate is then recovered by `vorbis_length`.
+pub fn check_ident_values(channels: u8, sample_rate: u32, warnings: &mut Vec<String>) -> (u8, u32) {
+    (
+        check_header_value("channels", channels as u32, CHANNELS_RANGE, warnings) as u8,
+        check_header_value("sample_rate", sample_rate, SAMPLE_RATE_RANGE, warnings),
+    )
+}
+
+/// Stream duration from `vorbis_length`; always finite.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct VorbisLength {
+    pub length: f64,
+    pub length_samples: u64,
+    pub duration_ms: u64,
+    pub sample_rate: u32,
+    /// The identification header's sample rate was unusable and the values
+    /// come from a later header copy or the nominal bitrate.
+    pub recovered: bool,
+}
+
+/// Sample rate from the first intact Vorbis identification header in `data`.
+/// Chained streams repeat the headers for every link.
+fn replica_sample_rate(data: &[u8]) -> Option<u32> {
+    memchr::memmem::find_iter(data, b"\x01vorbis").find_map(|pos| {
+        let p = data.get(pos..pos + 30)?;
+        let version = u32::from_le_bytes([p[7], p[8], p[9], p[10]]);
+        let rate = u32::from_le_bytes([p[12], p[13], p[14], p[15]]);
+        let valid = version == 0 && p[11] > 0 && p[29] & 1 == 1 && SAMPLE_RATE_RANGE.contains(&rate);
+        valid.then_some(rate)
+    })
+}
+
+/// Length of the stream `serial` from its last granule and the identification
+/// header's `sample_rate`. A rate outside `SAMPLE_RATE_RANGE` is damage: the rate
+/// is then read from a later copy of the header, and failing that the length
+/// is estimated from the nominal bitrate and the byte count (sample rate 0).
+pub fn vorbis_length(data: &[u8], serial: u32, sample_rate: u32, nominal_bitrate: u32) -> VorbisLength {
+    let mut out = VorbisLength { sample_rate, ..VorbisLength::default() };
+    if !SAMPLE_RATE_RANGE.contains(&sample_rate) {
+        out.sample_rate = 0;
+        match replica_sample_rate(data) {
+            Some(rate) => {
+                out.sample_rate = rate;
+                out.recovered = true;
+            }
+            None => {
+                // Vorbis bitrate fields are signed; <= 0 means unset
+                let bps = nominal_bitrate as i32;
+                if bps > 0 {
+                    out.length = data.len() as f64 * 8.0 / bps as f64;
+                    out.duration_ms = duration_ms(data.len() as u64 * 8, bps as u64);
+                    out.recovered = true;
+                }
+                return out;
+            }
+        }
+    }
+    if let Some(granule) = find_last_granule(data, serial).filter(|&g| g > 0) {
+        out.length_samples = granule as u64;
+        out.length = granule as f64 / out.sample_rate as f64;
+        out.duration_ms = duration_ms(out.length_samples, out.sample_rate as u64);
+    }
+    out
+}
+
 /// Parsed OGG Vorbis audio info.
 #[derive(Debug, Clone)]
 pub struct OggVorbisInfo {
     pub length: f64,
+    /// Granule position of the stream's last page, i.e. the duration in samples.
+    pub length_samples: u64,
+    /// `length_samples` / `sample_rate` in whole milliseconds (see `vorbis_length`).
+    pub duration_ms: u64,
     pub channels: u8,
     pub sample_rate: u32,
     pub bitrate: u32,       // nominal bitrate
     pub bitrate_max: u32,
     pub bitrate_min: u32,
+    /// Serial number of the logical Vorbis stream.
+    pub serial: u32,
+    /// Pages belonging to that stream (filled in by `ensure_full_parse`).
+    pub page_count: u32,
+    /// Length and sample rate were recovered from damaged headers (see `vorbis_length`).
+    pub recovered: bool,
+    /// Header values that were out of range and read as 0 (see `check_header_value`).
+    pub warnings: Vec<String>,
 }
 
 /// Complete OGG Vorbis file handler.
@@ -184,7 +264,7 @@ pub struct OggVorbisFile {
 
 /// Lightweight page header — no packet reassembly, zero allocations.
 #[inline(always)]
-fn ogg_page_header(data: &[u8], offset: usize) -> Option<(u32, usize)> {
+pub fn ogg_page_header(data: &[u8], offset: usize) -> Option<(u32, usize)> {
     if offset + 27 > data.len() { return None; }
     let d = &data[offset..];
     if &d[0..4] != b"OggS" { return None; }
@@ -196,6 +276,30 @@ fn ogg_page_header(data: &[u8], offset: usize) -> Option<(u32, usize)> {
     Some((serial, header_size + data_size))
 }
 
+/// Count the pages with the given serial number by hopping from header to
+/// header. After a damaged page the scan resyncs on the next `OggS` capture
+/// pattern; a truncated final page still counts.
+pub fn count_pages(data: &[u8], serial: u32) -> u32 {
+    let finder = memchr::memmem::Finder::new(b"OggS");
+    let mut count = 0;
+    let mut offset = 0;
+    while offset + 27 <= data.len() {
+        match ogg_page_header(data, offset) {
+            Some((page_serial, size)) => {
+                if page_serial == serial {
+                    count += 1;
+                }
+                offset += size;
+            }
+            None => match finder.find(&data[offset + 1..]) {
+                Some(pos) => offset += 1 + pos,
+                None => break,
+            },
+        }
+    }
+    count
+}
+
 /// Extract the first packet from an OGG page without allocating.
 /// Returns a slice into the original data.
 #[inline(always)]
@@ -298,10 +402,10 @@ impl OggVorbisFile {
             return Err(MutagenError::Ogg("Not a Vorbis stream".into()));
         }
 
-        let channels = id_packet[11];
-        let sample_rate = u32::from_le_bytes([
+        let mut warnings = Vec::new();
+        let (channels, sample_rate) = check_ident_values(id_packet[11], u32::from_le_bytes([
             id_packet[12], id_packet[13], id_packet[14], id_packet[15],
-        ]);
+        ]), &mut warnings);
         let bitrate = u32::from_le_bytes([
             id_packet[20], id_packet[21], id_packet[22], id_packet[23],
         ]);
@@ -309,11 +413,17 @@ impl OggVorbisFile {
         Ok(OggVorbisFile {
             info: OggVorbisInfo {
                 length: 0.0,
+                length_samples: 0,
+                duration_ms: 0,
                 channels,
                 sample_rate,
                 bitrate,
                 bitrate_max: 0,
                 bitrate_min: 0,
+                serial,
+                page_count: 0,
+                recovered: false,
+                warnings,
             },
             tags: VorbisComment::new(),
             path: path.to_string(),
@@ -347,11 +457,14 @@ impl OggVorbisFile {
         }
 
         // Duration from last page
-        if let Some(granule) = find_last_granule(data, self.serial) {
-            if granule > 0 && self.info.sample_rate > 0 {
-                self.info.length = granule as f64 / self.info.sample_rate as f64;
-            }
-        }
+        let vl = vorbis_length(data, self.serial, self.info.sample_rate, self.info.bitrate);
+        self.info.length = vl.length;
+        self.info.length_samples = vl.length_samples;
+        self.info.duration_ms = vl.duration_ms;
+        self.info.sample_rate = vl.sample_rate;
+        self.info.recovered = vl.recovered;
+
+        self.info.page_count = count_pages(data, self.serial);
 
         // Compute actual bitrate
         if self.info.bitrate == 0 && self.info.length > 0.0 {
@@ -370,58 +483,17 @@ impl OggVorbisFile {
         }
     }
 
-    /// Save tags back to the OGG file.
+    /// Save tags back to the OGG file. The comment packet is replaced and the
+    /// setup header, which often shares its page, is written back unchanged;
+    /// both go on fresh pages and the rest of the stream is renumbered.
     pub fn save(&self) -> Result<()> {
-        // For now, read-only support. Writing OGG is complex (page rewriting).
-        // A full implementation would rebuild the comment pages.
-        let mut file = std::fs::OpenOptions::new().read(true).write(true).open(&self.path)?;
-        let mut existing = Vec::new();
-        file.read_to_end(&mut existing)?;
-
-        // Parse original pages to find comment page boundaries
-        let first_page = OggPage::parse(&existing, 0)?;
-        let second_page = OggPage::parse(&existing, first_page.size)?;
-
-        // Build new comment packet
-        let mut comment_packet = Vec::new();
-        comment_packet.extend_from_slice(b"\x03vorbis");
+        let existing = std::fs::read(&self.path)?;
+        let mut packets = read_header_packets(&existing, self.serial, 2)?;
+        let mut comment_packet = b"\x03vorbis".to_vec();
         comment_packet.extend_from_slice(&self.tags.render(true));
-
-        // Build new comment page segments
-        let mut segments = Vec::new();
-        let mut remaining = comment_packet.len();
-        while remaining >= 255 {
-            segments.push(255u8);
-            remaining -= 255;
-        }
-        segments.push(remaining as u8);
-
-        // Build new second page
-        let mut new_page = Vec::new();
-        new_page.extend_from_slice(b"OggS");
-        new_page.push(0); // version
-        new_page.push(0); // header type (not continuation, not BOS, not EOS)
-        new_page.extend_from_slice(&second_page.granule_position.to_le_bytes());
-        new_page.extend_from_slice(&second_page.serial_number.to_le_bytes());
-        new_page.extend_from_slice(&second_page.page_sequence.to_le_bytes());
-        new_page.extend_from_slice(&0u32.to_le_bytes()); // checksum placeholder
-        new_page.push(segments.len() as u8);
-        new_page.extend_from_slice(&segments);
-        new_page.extend_from_slice(&comment_packet);
-
-        // Calculate CRC
-        let crc = ogg_crc(&new_page);
-        new_page[22..26].copy_from_slice(&crc.to_le_bytes());
-
-        // Rebuild file
-        let rest_start = first_page.size + second_page.size;
-        file.seek(SeekFrom::Start(0))?;
-        file.set_len(0)?;
-        file.write_all(&existing[..first_page.size])?;
-        file.write_all(&new_page)?;
-        file.write_all(&existing[rest_start..])?;
-        file.flush()?;
-
+        packets[0] = comment_packet;
+        let out = rewrite_header_packets(&existing, self.serial, 2, &packets)?;
+        std::fs::write(&self.path, out)?;
         Ok(())
     }
 
@@ -449,6 +521,121 @@ impl OggVorbisFile {
     }
 }
 
+/// The `count` packets that follow the identification page of the stream
+/// `serial`, reassembled across pages (comment and setup headers for Vorbis).
+pub fn read_header_packets(data: &[u8], serial: u32, count: usize) -> Result<Vec<Vec<u8>>> {
+    let (_, mut offset) = ogg_page_header(data, 0)
+        .ok_or_else(|| MutagenError::Ogg("Cannot parse first OGG page".into()))?;
+    let mut packets = Vec::with_capacity(count);
+    let mut current = Vec::new();
+    while packets.len() < count {
+        let page = OggPage::parse(data, offset)?;
+        offset += page.size;
+        if page.serial_number != serial {
+            continue;
+        }
+        let mut pos = page.offset + 27 + page.segments.len();
+        for &seg in &page.segments {
+            current.extend_from_slice(&data[pos..pos + seg as usize]);
+            pos += seg as usize;
+            if seg < 255 {
+                packets.push(std::mem::take(&mut current));
+                if packets.len() == count {
+                    break;
+                }
+            }
+        }
+    }
+    Ok(packets)
+}
+
+/// Replace the `count` header packets that follow the identification page of
+/// the stream `serial` (1 for Opus, 2 for Vorbis) with `packets`, laid out on
+/// fresh pages. The stream's later pages are renumbered when the page count
+/// changes; pages of other streams are copied as they are.
+pub fn rewrite_header_packets(data: &[u8], serial: u32, count: usize, packets: &[Vec<u8>]) -> Result<Vec<u8>> {
+    let (_, page1_size) = ogg_page_header(data, 0)
+        .ok_or_else(|| MutagenError::Ogg("Cannot parse first OGG page".into()))?;
+
+    // Find the pages holding the old header packets; they must end on a page
+    // boundary, as both Vorbis and Opus require before the first audio packet
+    let mut offset = page1_size;
+    let mut old_pages = 0u32;
+    let mut first_sequence = None;
+    let mut done = 0usize;
+    while done < count {
+        let page = OggPage::parse(data, offset)?;
+        offset += page.size;
+        if page.serial_number != serial {
+            return Err(MutagenError::Ogg("Interleaved header pages are not supported".into()));
+        }
+        first_sequence.get_or_insert(page.page_sequence);
+        old_pages += 1;
+        done += page.segments.iter().filter(|&&s| s < 255).count();
+        if done > count || (done == count && page.segments.last().is_some_and(|&s| s == 255)) {
+            return Err(MutagenError::Ogg("Header packets share a page with audio".into()));
+        }
+    }
+    let first_sequence = first_sequence.unwrap_or(1);
+
+    let mut out = Vec::with_capacity(data.len() + packets.iter().map(Vec::len).sum::<usize>());
+    out.extend_from_slice(&data[..page1_size]);
+    let new_pages = write_packet_pages(&mut out, serial, first_sequence, packets);
+
+    // Renumber the rest of the stream
+    let delta = new_pages as i64 - old_pages as i64;
+    while offset < data.len() {
+        let Some((page_serial, size)) = ogg_page_header(data, offset).filter(|&(_, size)| offset + size <= data.len()) else {
+            out.extend_from_slice(&data[offset..]);
+            break;
+        };
+        let start = out.len();
+        out.extend_from_slice(&data[offset..offset + size]);
+        if delta != 0 && page_serial == serial {
+            let page = &mut out[start..];
+            let sequence = u32::from_le_bytes([page[18], page[19], page[20], page[21]]);
+            page[18..22].copy_from_slice(&((sequence as i64 + delta) as u32).to_le_bytes());
+            page[22..26].copy_from_slice(&[0; 4]);
+            let crc = ogg_crc(page);
+            page[22..26].copy_from_slice(&crc.to_le_bytes());
+        }
+        offset += size;
+    }
+    Ok(out)
+}
+
+/// Append `packets` to `out` as pages of up to 255 segments, each packet
+/// starting on a new page. Returns the number of pages written.
+fn write_packet_pages(out: &mut Vec<u8>, serial: u32, first_sequence: u32, packets: &[Vec<u8>]) -> u32 {
+    let mut sequence = first_sequence;
+    for packet in packets {
+        let mut lacing = vec![255u8; packet.len() / 255];
+        lacing.push((packet.len() % 255) as u8);
+        let mut body = packet.as_slice();
+        for (i, segments) in lacing.chunks(255).enumerate() {
+            let size: usize = segments.iter().map(|&s| s as usize).sum();
+            let finishes = segments.last().is_some_and(|&s| s < 255);
+            let start = out.len();
+            out.extend_from_slice(b"OggS");
+            out.push(0);
+            out.push(if i > 0 { 0x01 } else { 0 });
+            // No packet ends on a page with granule -1
+            out.extend_from_slice(&(if finishes { 0i64 } else { -1 }).to_le_bytes());
+            out.extend_from_slice(&serial.to_le_bytes());
+            out.extend_from_slice(&sequence.to_le_bytes());
+            out.extend_from_slice(&[0; 4]);
+            out.push(segments.len() as u8);
+            out.extend_from_slice(segments);
+            out.extend_from_slice(&body[..size]);
+            body = &body[size..];
+            let crc = ogg_crc(&out[start..]);
+            out[start + 22..start + 26].copy_from_slice(&crc.to_le_bytes());
+            sequence += 1;
+        }
+    }
+    sequence - first_sequence
+}
+
 /// Calculate OGG-style CRC32.
 fn ogg_crc(data: &[u8]) -> u32 {
     // OGG uses CRC32 with polynomial 0x04C11DB7
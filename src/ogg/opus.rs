@@ -0,0 +1,165 @@
+use crate::common::error::{MutagenError, Result};
+use crate::common::util::duration_ms;
+use crate::vorbis::VorbisComment;
+use super::{count_pages, find_last_granule, ogg_assemble_first_packet, ogg_first_packet, ogg_page_header};
+
+/// Opus granule positions count samples at 48 kHz, whatever the input rate.
+pub const GRANULE_RATE: u32 = 48000;
+
+/// Parsed Ogg Opus audio info.
+#[derive(Debug, Clone, Default)]
+pub struct OpusInfo {
+    /// (last granule − pre-skip) / 48000; 0 when the pre-skip covers the stream.
+    pub length: f64,
+    /// Decoded samples at 48 kHz, pre-skip excluded.
+    pub length_samples: u64,
+    pub duration_ms: u64,
+    pub channels: u8,
+    /// Samples to discard from the start of the decoded output, at 48 kHz.
+    pub pre_skip: u16,
+    /// Sample rate of the encoder input, informational only (0 if unknown).
+    pub input_sample_rate: u32,
+    /// Gain to apply on decoding, in Q7.8 dB.
+    pub output_gain: i16,
+    /// Average bitrate over the whole file.
+    pub bitrate: u32,
+    /// Serial number of the logical Opus stream.
+    pub serial: u32,
+    /// Pages belonging to that stream.
+    pub page_count: u32,
+}
+
+/// Ogg Opus file handler. Only the first logical stream is read: in chained
+/// files the length covers the first link.
+#[derive(Debug)]
+pub struct OpusFile {
+    pub info: OpusInfo,
+    pub tags: VorbisComment,
+    pub path: String,
+    raw_comment_data: Vec<u8>,
+    tags_parsed: bool,
+}
+
+/// Fields of an `OpusHead` packet: `(channels, pre_skip, input_sample_rate, output_gain)`.
+pub fn parse_opus_head(packet: &[u8]) -> Result<(u8, u16, u32, i16)> {
+    if packet.len() < 19 || &packet[0..8] != b"OpusHead" {
+        return Err(MutagenError::Ogg("Not an Opus stream".into()));
+    }
+    // Only the minor version may change compatibly
+    if packet[8] >> 4 != 0 {
+        return Err(MutagenError::Ogg(format!("Unsupported Opus version {}", packet[8])));
+    }
+    Ok((
+        packet[9],
+        u16::from_le_bytes([packet[10], packet[11]]),
+        u32::from_le_bytes([packet[12], packet[13], packet[14], packet[15]]),
+        i16::from_le_bytes([packet[16], packet[17]]),
+    ))
+}
+
+/// Whether the first packet of `data` is an `OpusHead`: an Ogg file holding
+/// Opus rather than Vorbis, whatever its extension.
+pub fn is_opus(data: &[u8]) -> bool {
+    ogg_first_packet(data, 0).is_some_and(|p| p.starts_with(b"OpusHead"))
+}
+
+/// Info from the `OpusHead` packet and the last granule alone; `page_count`
+/// stays 0. Enough for `_fast_info`, which reads just the first and last pages.
+pub fn parse_info(data: &[u8]) -> Result<OpusInfo> {
+    let (serial, _) = ogg_page_header(data, 0)
+        .ok_or_else(|| MutagenError::Ogg("Cannot parse first OGG page".into()))?;
+    let head = ogg_first_packet(data, 0)
+        .ok_or_else(|| MutagenError::Ogg("No packets in first page".into()))?;
+    let (channels, pre_skip, input_sample_rate, output_gain) = parse_opus_head(head)?;
+    let (length, length_samples, duration_ms) = opus_length(data, serial, pre_skip);
+    Ok(OpusInfo {
+        length,
+        length_samples,
+        duration_ms,
+        channels,
+        pre_skip,
+        input_sample_rate,
+        output_gain,
+        bitrate: if length > 0.0 { (data.len() as f64 * 8.0 / length) as u32 } else { 0 },
+        serial,
+        page_count: 0,
+    })
+}
+
+/// Length of the stream `serial` from its last granule, less the pre-skip.
+pub fn opus_length(data: &[u8], serial: u32, pre_skip: u16) -> (f64, u64, u64) {
+    let samples = find_last_granule(data, serial)
+        .map_or(0, |granule| granule.saturating_sub(pre_skip as i64).max(0) as u64);
+    (
+        samples as f64 / GRANULE_RATE as f64,
+        samples,
+        duration_ms(samples, GRANULE_RATE as u64),
+    )
+}
+
+impl OpusFile {
+    pub fn open(path: &str) -> Result<Self> {
+        let data = std::fs::read(path)?;
+        Self::parse(&data, path)
+    }
+
+    /// Parse the `OpusHead` and `OpusTags` packets and the stream length.
+    /// Tags are decoded on `ensure_tags`.
+    pub fn parse(data: &[u8], path: &str) -> Result<Self> {
+        let mut info = parse_info(data)?;
+        let page1_size = ogg_page_header(data, 0).map_or(0, |(_, size)| size);
+        let comment_packet = ogg_assemble_first_packet(data, page1_size)
+            .filter(|p| p.starts_with(b"OpusTags"))
+            .ok_or_else(|| MutagenError::Ogg("Missing OpusTags packet".into()))?;
+        info.page_count = count_pages(data, info.serial);
+
+        Ok(OpusFile {
+            info,
+            tags: VorbisComment::new(),
+            path: path.to_string(),
+            raw_comment_data: comment_packet[8..].to_vec(),
+            tags_parsed: false,
+        })
+    }
+
+    /// The `OpusTags` body after the magic, as read (empty once tags are decoded).
+    pub fn raw_comments(&self) -> &[u8] {
+        &self.raw_comment_data
+    }
+
+    /// Ensure VorbisComment tags are parsed (lazy initialization).
+    pub fn ensure_tags(&mut self) {
+        if !self.tags_parsed {
+            self.tags_parsed = true;
+            if let Ok(vc) = VorbisComment::parse(&self.raw_comment_data, false) {
+                self.tags = vc;
+            }
+            self.raw_comment_data = Vec::new();
+        }
+    }
+
+    /// Write `tags` as the new `OpusTags` packet (no framing bit).
+    pub fn save(&self) -> Result<()> {
+        let existing = std::fs::read(&self.path)?;
+        let mut packet = b"OpusTags".to_vec();
+        packet.extend_from_slice(&self.tags.render(false));
+        let out = super::rewrite_header_packets(&existing, self.info.serial, 1, &[packet])?;
+        std::fs::write(&self.path, out)?;
+        Ok(())
+    }
+
+    pub fn score(path: &str, data: &[u8]) -> u32 {
+        let mut score = 0u32;
+        let ext = path.rsplit('.').next().unwrap_or("");
+        if ext.eq_ignore_ascii_case("opus") {
+            score += 2;
+        }
+        if data.len() >= 4 && &data[0..4] == b"OggS" {
+            score += 1;
+            if is_opus(data) {
+                score += 2;
+            }
+        }
+        score
+    }
+}
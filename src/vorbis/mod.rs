@@ -1,6 +1,12 @@
 use crate::common::error::{MutagenError, Result};
+use crate::common::keyindex::LINEAR_MAX;
+use crate::common::text;
+use crate::common::util::key_matches_pattern;
 use std::collections::HashMap;
 
+/// `tag_version` reported for Vorbis comment tags (FLAC and Ogg).
+pub const TAG_VERSION: &str = "VorbisComment";
+
 /// A Vorbis comment: list of key=value pairs with a vendor string.
 #[derive(Debug, Clone)]
 pub struct VorbisComment {
@@ -41,10 +47,7 @@ impl VorbisComment {
             return Err(MutagenError::InvalidData("Vendor string extends past data".into()));
         }
 
-        let vendor = match std::str::from_utf8(&data[pos..pos + vendor_len]) {
-            Ok(s) => s.to_string(),
-            Err(_) => String::from_utf8_lossy(&data[pos..pos + vendor_len]).into_owned(),
-        };
+        let vendor = text::utf8_lossy(&data[pos..pos + vendor_len]);
         pos += vendor_len;
 
         if pos + 4 > data.len() {
@@ -88,9 +91,9 @@ impl VorbisComment {
             // Key: fast ASCII lowercase (matches mutagen behavior)
             let key = if key_bytes.iter().all(|&b| !b.is_ascii_uppercase()) {
                 // Already lowercase (common case) - zero-copy if valid UTF-8
-                match std::str::from_utf8(key_bytes) {
-                    Ok(s) => s.to_string(),
-                    Err(_) => continue,
+                match text::from_utf8(key_bytes) {
+                    Some(s) => s.to_string(),
+                    None => continue,
                 }
             } else {
                 // Fast ASCII lowercase without full Unicode overhead
@@ -102,10 +105,7 @@ impl VorbisComment {
             };
 
             // Value: zero-copy if valid UTF-8
-            let value = match std::str::from_utf8(value_bytes) {
-                Ok(s) => s.to_string(),
-                Err(_) => String::from_utf8_lossy(value_bytes).into_owned(),
-            };
+            let value = text::utf8_lossy(value_bytes);
 
             comments.push((key, value));
         }
@@ -176,10 +176,29 @@ impl VorbisComment {
         self.comments.retain(|(k, _)| k != &lower);
     }
 
+    /// Delete every comment whose key matches one of `patterns`, ignoring case
+    /// (see `key_matches_pattern`). Returns the removed keys.
+    pub fn strip(&mut self, patterns: &[&str]) -> Vec<String> {
+        let mut removed: Vec<String> = Vec::new();
+        self.comments.retain(|(k, _)| {
+            let hit = patterns.iter().any(|p| key_matches_pattern(k, p, true));
+            if hit && !removed.contains(k) { removed.push(k.clone()); }
+            !hit
+        });
+        removed
+    }
+
     /// Get all unique keys. Uses linear scan instead of HashSet for
-    /// typical small key counts (5-15 unique keys).
+    /// typical small key counts (5-15 unique keys), hashing only for large blocks.
     #[inline(always)]
     pub fn keys(&self) -> Vec<String> {
+        if self.comments.len() > LINEAR_MAX {
+            let mut seen = std::collections::HashSet::with_capacity(self.comments.len());
+            return self.comments.iter()
+                .filter(|(k, _)| seen.insert(k.as_str()))
+                .map(|(k, _)| k.clone())
+                .collect();
+        }
         let mut keys = Vec::with_capacity(8);
         for (k, _) in &self.comments {
             if !keys.iter().any(|existing: &String| existing == k) {
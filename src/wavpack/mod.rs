@@ -0,0 +1,212 @@
+use crate::ape::apev2;
+use crate::common::error::{MutagenError, Result};
+use crate::common::util::duration_ms;
+
+/// Sample rates for the 4-bit index in the block flags; index 15 means a
+/// non-standard rate stored in a metadata sub-block.
+const SAMPLE_RATES: [u32; 15] = [
+    6000, 8000, 9600, 11025, 12000, 16000, 22050, 24000, 32000, 44100, 48000, 64000, 88200, 96000, 192000,
+];
+
+const FLAG_MONO: u32 = 0x4;
+const FLAG_DSD: u32 = 0x8000_0000;
+
+/// Metadata sub-block ids (low 6 bits of the id byte).
+const ID_CHANNEL_INFO: u8 = 0x0D;
+const ID_DSD_BLOCK: u8 = 0x0E;
+const ID_SAMPLE_RATE: u8 = 0x27;
+const ID_ODD_SIZE: u8 = 0x40;
+const ID_LARGE: u8 = 0x80;
+
+/// Stream information from the first WavPack block.
+#[derive(Debug, Clone, Default)]
+pub struct WavPackInfo {
+    pub length: f64,
+    pub length_samples: u64,
+    pub duration_ms: u64,
+    pub sample_rate: u32,
+    pub channels: u32,
+    /// 1 for DSD audio.
+    pub bits_per_sample: u32,
+    /// File size × 8 / length.
+    pub bitrate: u32,
+    /// Stream version from the block header, e.g. 0x403.
+    pub version: u16,
+}
+
+/// A parsed WavPack file (read-only), with its APEv2 tag if any.
+#[derive(Debug, Clone, Default)]
+pub struct WavPackFile {
+    pub info: WavPackInfo,
+    pub tags: Option<apev2::APEv2Tag>,
+    pub path: String,
+}
+
+/// The fixed 32-byte header in front of every block.
+struct BlockHeader {
+    /// Bytes after the first 8 (magic and this field).
+    block_size: u32,
+    version: u16,
+    /// `None` when the encoder didn't know the count (stored as -1).
+    total_samples: Option<u32>,
+    block_index: u32,
+    block_samples: u32,
+    flags: u32,
+}
+
+fn block_header(data: &[u8], pos: usize) -> Option<BlockHeader> {
+    let h = data.get(pos..pos.checked_add(32)?)?;
+    if &h[0..4] != b"wvpk" {
+        return None;
+    }
+    let u32_at = |o: usize| u32::from_le_bytes(h[o..o + 4].try_into().unwrap());
+    let block_size = u32_at(4);
+    if block_size < 24 {
+        return None;
+    }
+    Some(BlockHeader {
+        block_size,
+        version: u16::from_le_bytes([h[8], h[9]]),
+        total_samples: Some(u32_at(12)).filter(|&n| n != u32::MAX),
+        block_index: u32_at(16),
+        block_samples: u32_at(20),
+        flags: u32_at(24),
+    })
+}
+
+impl WavPackFile {
+    /// Open and parse a WavPack file.
+    pub fn open(path: &str) -> Result<Self> {
+        let data = std::fs::read(path)?;
+        Self::parse(&data, path)
+    }
+
+    /// Parse the first block header and the trailing APEv2 tag.
+    ///
+    /// When the first block doesn't carry the total sample count (written as
+    /// -1 by encoders reading from a pipe), the length comes from the index
+    /// and sample count of the last block instead.
+    pub fn parse(data: &[u8], path: &str) -> Result<Self> {
+        let first = block_header(data, 0)
+            .ok_or_else(|| MutagenError::WavPack("Not a WavPack file".into()))?;
+        let tags = apev2::parse(data);
+        let audio_end = tags.as_ref().map_or_else(|| apev2::tag_end(data), |t| t.offset);
+
+        let body_end = (first.block_size as usize + 8).min(data.len());
+        let sub = SubBlocks::read(&data[32..body_end]);
+        let mut sample_rate = match (first.flags >> 23) & 0xF {
+            15 => sub.sample_rate.unwrap_or(0),
+            i => SAMPLE_RATES[i as usize],
+        };
+        let mut bits_per_sample = ((first.flags & 3) + 1) * 8;
+        if first.flags & FLAG_DSD != 0 {
+            // DSD is stored as bytes of 8 one-bit samples; the rate is scaled
+            // back up by the shift in the DSD sub-block (4 for DSD64)
+            sample_rate <<= sub.dsd_shift.unwrap_or(2).min(16);
+            bits_per_sample = 1;
+        }
+        let channels = sub.channels.unwrap_or(if first.flags & FLAG_MONO != 0 { 1 } else { 2 });
+
+        let length_samples = match first.total_samples {
+            Some(n) if first.block_index == 0 => n as u64,
+            _ => last_block(data, audio_end, first.version)
+                .map_or(first.block_samples as u64, |last| {
+                    (last.block_index as u64 + last.block_samples as u64).saturating_sub(first.block_index as u64)
+                }),
+        };
+        let length = if sample_rate > 0 { length_samples as f64 / sample_rate as f64 } else { 0.0 };
+        let bitrate = if length > 0.0 { (data.len() as f64 * 8.0 / length) as u32 } else { 0 };
+
+        Ok(WavPackFile {
+            info: WavPackInfo {
+                length,
+                length_samples,
+                duration_ms: duration_ms(length_samples, sample_rate as u64),
+                sample_rate,
+                channels,
+                bits_per_sample,
+                bitrate,
+                version: first.version,
+            },
+            tags,
+            path: path.to_string(),
+        })
+    }
+
+    /// Score for auto-detection.
+    pub fn score(path: &str, data: &[u8]) -> u32 {
+        let mut score = 0u32;
+
+        let ext = path.rsplit('.').next().unwrap_or("");
+        if ext.eq_ignore_ascii_case("wv") {
+            score += 2;
+        }
+
+        if data.len() >= 4 && &data[0..4] == b"wvpk" {
+            score += 3;
+        }
+
+        score
+    }
+}
+
+/// The last block before `end`: searching back from there, the first `wvpk`
+/// header of the same stream version whose block fits before `end`.
+fn last_block(data: &[u8], end: usize, version: u16) -> Option<BlockHeader> {
+    let finder = memchr::memmem::FinderRev::new(b"wvpk");
+    let mut limit = end.min(data.len());
+    while let Some(pos) = finder.rfind(&data[..limit]) {
+        if let Some(h) = block_header(data, pos) {
+            if h.version == version && pos + 8 + h.block_size as usize <= end {
+                return Some(h);
+            }
+        }
+        limit = pos;
+    }
+    None
+}
+
+/// Values read from the metadata sub-blocks of a block.
+#[derive(Default)]
+struct SubBlocks {
+    sample_rate: Option<u32>,
+    channels: Option<u32>,
+    dsd_shift: Option<u32>,
+}
+
+impl SubBlocks {
+    fn read(body: &[u8]) -> Self {
+        let mut out = SubBlocks::default();
+        let mut pos = 0usize;
+        while pos + 2 <= body.len() {
+            let id = body[pos];
+            // Sizes count 16-bit words, in one byte or three
+            let (words, header) = if id & ID_LARGE != 0 {
+                let Some(b) = body.get(pos + 1..pos + 4) else { break };
+                (u32::from_le_bytes([b[0], b[1], b[2], 0]) as usize, 4)
+            } else {
+                (body[pos + 1] as usize, 2)
+            };
+            let start = pos + header;
+            let Some(end) = start.checked_add(words * 2).filter(|&e| e <= body.len()) else { break };
+            // An odd size drops the last byte, which an empty sub-block doesn't have
+            let odd = (id & ID_ODD_SIZE != 0) as usize;
+            let Some(payload_end) = end.checked_sub(odd).filter(|&e| e >= start) else { break };
+            let payload = &body[start..payload_end];
+            match id & 0x3F {
+                ID_SAMPLE_RATE if payload.len() >= 3 => {
+                    out.sample_rate = Some(u32::from_le_bytes([payload[0], payload[1], payload[2], 0]));
+                }
+                ID_DSD_BLOCK if !payload.is_empty() => out.dsd_shift = Some(payload[0] as u32),
+                // Six-byte form for more than 255 channels: 12-bit count minus one
+                ID_CHANNEL_INFO if payload.len() == 6 => {
+                    out.channels = Some((payload[0] as u32 | ((payload[2] as u32 & 0xF) << 8)) + 1);
+                }
+                ID_CHANNEL_INFO if !payload.is_empty() => out.channels = Some(payload[0] as u32),
+                _ => {}
+            }
+            pos = end;
+        }
+        out
+    }
+}
@@ -0,0 +1,52 @@
+//! `id3::load_id3_from_slice` reads an ID3v2.4 tag into the same frames as
+//! `id3::load_id3_from_data`.
+
+use mutagen_rs::id3::{load_id3_from_data, load_id3_from_slice};
+
+fn syncsafe(n: usize) -> [u8; 4] {
+    [(n >> 21) as u8 & 0x7f, (n >> 14) as u8 & 0x7f, (n >> 7) as u8 & 0x7f, n as u8 & 0x7f]
+}
+
+/// An ID3v2.4 tag holding `frames` (id, body), followed by some audio bytes.
+fn id3v24(frames: &[(&[u8; 4], &[u8])]) -> Vec<u8> {
+    let mut body = Vec::new();
+    for (id, data) in frames {
+        body.extend_from_slice(*id);
+        body.extend_from_slice(&syncsafe(data.len()));
+        body.extend_from_slice(&[0, 0]);
+        body.extend_from_slice(data);
+    }
+    let mut tag = b"ID3\x04\x00\x00".to_vec();
+    tag.extend_from_slice(&syncsafe(body.len()));
+    tag.extend_from_slice(&body);
+    tag.extend_from_slice(&[0xff, 0xfb, 0x90, 0x00]);
+    tag
+}
+
+#[test]
+fn slice_matches_data_for_v24() {
+    let data = id3v24(&[
+        (b"TIT2", b"\x03Title"),
+        (b"TPE1", b"\x03One\x00Two"),
+        (b"TXXX", b"\x03desc\x00value"),
+        (b"COMM", b"\x03engshort\x00long text"),
+        (b"TDRC", b"\x032020-05-06"),
+    ]);
+    let (mut from_data, data_header) = load_id3_from_data(&data).unwrap();
+    let (mut from_slice, slice_header) = load_id3_from_slice(&data).unwrap();
+
+    assert_eq!(data_header.unwrap().version, (4, 0));
+    assert_eq!(slice_header.unwrap().version, (4, 0));
+    assert_eq!(from_slice.keys(), from_data.keys());
+    assert_eq!(from_slice.len(), 5);
+    let decoded = format!("{:?}", from_slice.values_decoded());
+    assert!(decoded.contains("long text") && decoded.contains("Two"));
+    assert_eq!(decoded, format!("{:?}", from_data.values_decoded()));
+}
+
+#[test]
+fn slice_without_header_is_empty() {
+    let (tags, header) = load_id3_from_slice(b"not a tag").unwrap();
+    assert!(tags.is_empty());
+    assert!(header.is_none());
+}
@@ -0,0 +1,45 @@
+//! `common::parse::parse_file` on the committed fixtures: each picks the
+//! right format and reports the length the Python API does.
+
+use mutagen_rs::common::parse::parse_file;
+
+fn fixture(name: &str) -> String {
+    format!("{}/test_files/{}", env!("CARGO_MANIFEST_DIR"), name)
+}
+
+fn check(name: &str, format: &str, length: f64) {
+    let parsed = parse_file(&fixture(name)).unwrap_or_else(|e| panic!("{name}: {e}"));
+    assert_eq!(parsed.format(), format, "{name}");
+    let got = parsed.length().unwrap_or_else(|| panic!("{name}: no length"));
+    assert!((got - length).abs() < 1e-6, "{name}: length {got}, expected {length}");
+}
+
+#[test]
+fn mp3() {
+    check("silence-44-s.mp3", "mp3", 3.7675);
+}
+
+#[test]
+fn flac() {
+    check("silence-44-s.flac", "flac", 3.684716553287982);
+}
+
+#[test]
+fn ogg_vorbis() {
+    check("empty.ogg", "ogg", 3.684716553287982);
+}
+
+#[test]
+fn ogg_opus() {
+    check("example.opus", "opus", 11.354708333333333);
+}
+
+#[test]
+fn mp4() {
+    check("has-tags.m4a", "mp4", 3.706522222222222);
+}
+
+#[test]
+fn missing_file_is_an_error() {
+    assert!(parse_file(&fixture("does-not-exist.mp3")).is_err());
+}
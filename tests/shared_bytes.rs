@@ -0,0 +1,142 @@
+//! Binary tag payloads are not copied on their way into batch results: the
+//! APIC header parser leaves the picture in place, `SharedBytes` slices and
+//! clones share one buffer, and MP4 covers and freeform data are ranges of a
+//! single copy of the `ilst`. Checked with an allocator that counts the bytes
+//! (and the large blocks) each thread asks for.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use mutagen_rs::common::shared::SharedBytes;
+use mutagen_rs::id3::frames::parse_picture_header;
+
+struct Counting;
+
+thread_local! {
+    static ALLOCATED: Cell<usize> = const { Cell::new(0) };
+    static LARGE: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Allocations of at least this many bytes are counted one by one.
+const LARGE_ALLOC: usize = 64 * 1024;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATED.with(|n| n.set(n.get() + layout.size()));
+        if layout.size() >= LARGE_ALLOC {
+            LARGE.with(|n| n.set(n.get() + 1));
+        }
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATED.with(|n| n.set(n.get() + new_size));
+        if new_size >= LARGE_ALLOC {
+            LARGE.with(|n| n.set(n.get() + 1));
+        }
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+/// Bytes allocated by the current thread while running `f`.
+fn allocated_by<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATED.with(Cell::get);
+    let out = f();
+    (out, ALLOCATED.with(Cell::get) - before)
+}
+
+/// Allocations of at least `LARGE_ALLOC` bytes made by the current thread while running `f`.
+#[cfg(feature = "python")]
+fn large_allocations_by<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = LARGE.with(Cell::get);
+    let out = f();
+    (out, LARGE.with(Cell::get) - before)
+}
+
+const PICTURE_LEN: usize = 1 << 20;
+
+#[test]
+fn picture_header_leaves_data_in_place() {
+    let mut frame = b"\x00image/png\x00\x03cover\x00".to_vec();
+    frame.resize(frame.len() + PICTURE_LEN, 0x89);
+    let (header, bytes) = allocated_by(|| parse_picture_header(&frame).unwrap());
+    assert_eq!(header.mime, "image/png");
+    assert_eq!(frame.len() - header.data_offset, PICTURE_LEN);
+    assert!(bytes < 1024, "parsing the APIC header allocated {bytes} bytes");
+}
+
+#[test]
+fn shared_bytes_slices_without_copying() {
+    let (shared, bytes) = allocated_by(|| SharedBytes::from(vec![7u8; PICTURE_LEN]));
+    assert!(bytes >= PICTURE_LEN);
+    let (parts, bytes) = allocated_by(|| {
+        let half = shared.slice(PICTURE_LEN / 2, PICTURE_LEN);
+        (half.clone(), half.slice(1, 4), shared.clone())
+    });
+    assert_eq!(bytes, 0, "slicing and cloning allocated {bytes} bytes");
+    assert_eq!(parts.0.len(), PICTURE_LEN / 2);
+    assert_eq!(&*parts.1, &[7, 7, 7]);
+    assert_eq!(parts.2.len(), PICTURE_LEN);
+}
+
+/// A copy of the fixture `name` in a scratch directory, for tests that save into it.
+#[cfg(feature = "python")]
+fn scratch_copy(name: &str) -> String {
+    let dir = std::env::temp_dir().join(format!("mutagen-rs-shared-bytes-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(name);
+    std::fs::copy(format!("{}/test_files/{}", env!("CARGO_MANIFEST_DIR"), name), &path).unwrap();
+    path.to_str().unwrap().to_string()
+}
+
+#[cfg(feature = "python")]
+#[test]
+fn batch_mp4_binary_values_share_one_copy() {
+    use mutagen_rs::mp4::{save_mp4_tags, MP4Cover, MP4CoverFormat, MP4FreeForm, MP4TagValue, MP4Tags};
+
+    let path = scratch_copy("has-tags.m4a");
+    let mut tags = MP4Tags::new();
+    tags.set("covr", MP4TagValue::Cover(vec![
+        MP4Cover { data: vec![0x89; PICTURE_LEN].into(), format: MP4CoverFormat::PNG },
+        MP4Cover { data: vec![0xff; PICTURE_LEN].into(), format: MP4CoverFormat::JPEG },
+    ]));
+    tags.set("----:com.example:blob", MP4TagValue::FreeForm(vec![
+        MP4FreeForm { data: vec![0; PICTURE_LEN / 2].into(), dataformat: 0 },
+    ]));
+    save_mp4_tags(&path, &tags).unwrap();
+    let data = std::fs::read(&path).unwrap();
+
+    let ((count, large), bytes) = allocated_by(|| {
+        large_allocations_by(|| mutagen_rs::fuzz::batch_tag_count(&data, &path))
+    });
+    assert_eq!(count, Some(2));
+    assert_eq!(large, 1, "binary values were copied {large} times");
+    let payload = 2 * PICTURE_LEN + PICTURE_LEN / 2;
+    assert!(bytes < payload + LARGE_ALLOC, "parsing allocated {bytes} bytes for {payload} bytes of payload");
+}
+
+#[cfg(feature = "python")]
+#[test]
+fn batch_flac_leaves_pictures_in_the_file_buffer() {
+    use mutagen_rs::flac::{FLACFile, FLACPicture};
+
+    let path = scratch_copy("silence-44-s.flac");
+    let mut f = FLACFile::open(&path).unwrap();
+    f.pictures.push(FLACPicture {
+        pic_type: 3, mime: "image/png".into(), desc: String::new(),
+        width: 0, height: 0, depth: 0, colors: 0, data: vec![0x89; PICTURE_LEN],
+    });
+    f.save().unwrap();
+    let data = std::fs::read(&path).unwrap();
+
+    let (parsed, bytes) = allocated_by(|| mutagen_rs::fuzz::batch_tag_count(&data, &path));
+    assert!(parsed.is_some());
+    assert!(bytes < LARGE_ALLOC, "parsing allocated {bytes} bytes");
+}
@@ -6,6 +6,7 @@ Validates info fields, tag keys, tag values, and API behavior.
 import json
 import os
 import shutil
+import struct
 import pytest
 
 from mutagen.mp3 import MP3
@@ -23,6 +24,48 @@ def get_test_file(name):
     return os.path.join(TEST_DIR, name)
 
 
+def copy_fixture(tmp_path, name, dst_name=None):
+    """Copy test file ``name`` into ``tmp_path`` (as ``dst_name`` if given) and return the copy's path."""
+    src = get_test_file(name)
+    if not os.path.exists(src):
+        pytest.skip(f"Test file not found: {name}")
+    dst = str(tmp_path / (dst_name or name))
+    shutil.copy(src, dst)
+    return dst
+
+
+def _syncsafe(n):
+    return bytes([(n >> 21) & 0x7F, (n >> 14) & 0x7F, (n >> 7) & 0x7F, n & 0x7F])
+
+
+def _id3v24(frames):
+    """An ID3v2.4 tag holding ``frames``, a list of (frame id, payload) pairs."""
+    body = b"".join(fid + _syncsafe(len(payload)) + b"\x00\x00" + payload
+                    for fid, payload in frames)
+    return b"ID3\x04\x00\x00" + _syncsafe(len(body)) + body
+
+
+# APIC payload: a front cover described "cover" holding 256 bytes of "PNG" data
+_PNG_APIC = b"\x00image/png\x00\x03cover\x00" + b"\x89PNG" * 64
+
+
+def _mp3_with_frames(tmp_path, frames, name="id3.mp3", audio_from="silence-44-s.mp3"):
+    """Write an MP3 with an ID3v2.4 tag of ``frames`` in front of the audio of
+    ``audio_from`` (its own ID3v2 tag dropped) and return its path."""
+    src = get_test_file(audio_from)
+    if not os.path.exists(src):
+        pytest.skip("Test file not found")
+    with open(src, "rb") as f:
+        data = f.read()
+    if data[:3] == b"ID3":
+        data = data[mutagen_rs.ID3.from_bytes(data).size:]
+    path = str(tmp_path / name)
+    with open(path, "wb") as f:
+        f.write(_id3v24(frames) + data)
+    mutagen_rs.clear_all_caches()
+    return path
+
+
 # ──────────────────────────────────────────────────────────────
 # MP3 Tests
 # ──────────────────────────────────────────────────────────────
@@ -176,6 +219,95 @@ class TestMP3NoTags:
         assert orig_count == rust_count == 0
 
 
+class TestMP3FastKeys:
+    """Fast-path ID3 keys are mutagen's composite hash keys."""
+
+    @pytest.fixture(params=MP3_FILES)
+    def mp3_file(self, request):
+        path = get_test_file(request.param)
+        if not os.path.exists(path):
+            pytest.skip(f"Test file not found: {path}")
+        return path
+
+    def test_keys_match_mutagen(self, mp3_file):
+        orig = mutagen.File(mp3_file)
+        expected = set(orig.keys()) if orig is not None else set()
+        assert set(mutagen_rs._fast_read(mp3_file)["_keys"]) == expected
+        assert set(mutagen_rs._fast_read(mp3_file, nested=True)["tags"]) == expected
+        assert set(mutagen_rs.batch_open([mp3_file])[mp3_file]["tags"]) == expected
+
+    def test_composite_keys(self):
+        path = get_test_file("id3v1v2-combined.mp3")
+        keys = mutagen_rs._fast_read(path)["_keys"]
+        assert "COMM::eng" in keys and "COMM:iTunNORM:eng" in keys
+        assert "COMM:ID3v1 Comment:eng" in keys
+        assert "COMM" not in keys
+        priv = mutagen_rs._fast_read(get_test_file("apev2-lyricsv2.mp3"))["_keys"]
+        assert "PRIV:PeakValue:\xff\x7f\x00\x00" in priv
+
+    def test_invalid_tyer_dropped(self):
+        path = get_test_file("bad-TYER-frame.mp3")
+        assert "TDRC" not in mutagen_rs._fast_read(path)["_keys"]
+        assert "TDRC" not in mutagen_rs.batch_open([path])[path]["tags"]
+
+    def test_bare_ids(self):
+        path = get_test_file("id3v1v2-combined.mp3")
+        full = mutagen_rs._fast_read(path)
+        comments = [full[k] for k in full["_keys"] if k.startswith("COMM:")]
+        d = mutagen_rs._fast_read(path, bare_ids=True)
+        assert d["_keys"] == ["TIT2", "TPE1", "TRCK", "TDRC", "TENC", "COMM", "TALB"]
+        assert d["COMM"] == comments
+        assert not any(":" in k for k in d if not k.startswith("_"))
+        [seq] = mutagen_rs._fast_read_seq([path], bare_ids=True, nested=True)
+        assert list(seq["tags"]) == d["_keys"]
+        # The cached dict keeps the composite keys
+        assert mutagen_rs._fast_read(path)["_keys"] == full["_keys"]
+
+    def test_bare_ids_ignores_other_formats(self):
+        path = get_test_file("silence-44-s.flac")
+        assert mutagen_rs._fast_read(path, bare_ids=True) == mutagen_rs._fast_read(path)
+
+
+class TestID3PaddingOnly:
+    """Test an ID3v2 tag holding nothing but padding reads as an empty tag."""
+
+    @pytest.mark.parametrize("version,size", [(3, 1), (3, 128), (4, 127), (4, 4096)])
+    def test_fast_read(self, tmp_path, version, size):
+        syncsafe = bytes((size >> shift) & 0x7F for shift in (21, 14, 7, 0))
+        dst = str(tmp_path / "padding.mp3")
+        with open(get_test_file("xing.mp3"), "rb") as src:
+            audio = src.read()
+        with open(dst, "wb") as out:
+            out.write(b"ID3" + bytes([version, 0, 0]) + syncsafe + b"\x00" * size + audio)
+        mutagen_rs.clear_cache()
+        d = mutagen_rs._fast_read(dst)
+        assert d["_keys"] == []
+        assert d["length"] == mutagen_rs._fast_read(get_test_file("xing.mp3"))["length"]
+        assert d["sample_rate"] == 44100
+        assert mutagen_rs._fast_read(dst, nested=True)["tags"] == {}
+        assert dict(mutagen_rs.MP3(dst)) == {}
+
+
+class TestMP3XingFrames:
+    """Test the Xing/VBRI frame count on MP3 info."""
+
+    @pytest.mark.parametrize("name,frames", [("lame.mp3", 4), ("vbri.mp3", 8506)])
+    def test_header_frame_count(self, name, frames):
+        path = get_test_file(name)
+        assert mutagen_rs._fast_read(path)["xing_frames"] == frames
+        assert mutagen_rs._fast_info(path)["xing_frames"] == frames
+        info = mutagen_rs.mutagen_rs.MP3(path).info
+        assert info.xing_frames == frames
+        assert info.length_samples == frames * 1152
+        assert mutagen_rs.MP3(path).info.xing_frames == frames
+
+    def test_headerless_cbr(self):
+        path = get_test_file("silence-44-s.mp3")
+        assert mutagen_rs._fast_read(path)["xing_frames"] is None
+        assert mutagen_rs.mutagen_rs.MP3(path).info.xing_frames is None
+        assert mutagen_rs.MP3(path).info.xing_frames is None
+
+
 class TestMP3ErrorHandling:
     """Test MP3 error handling matches mutagen behavior."""
 
@@ -189,6 +321,616 @@ class TestMP3ErrorHandling:
             mutagen_rs.MP3(path)
 
 
+class TestID3FromBytes:
+    """Test parsing ID3 tags from an in-memory buffer."""
+
+    def _data(self):
+        path = get_test_file("silence-44-s.mp3")
+        if not os.path.exists(path):
+            pytest.skip("Test file not found")
+        with open(path, "rb") as f:
+            return f.read()
+
+    def test_matches_file_load(self):
+        data = self._data()
+        tags = mutagen_rs.ID3.from_bytes(data[:4096])
+        assert not tags.truncated
+        # The file loader also merges ID3v1 fields; from_bytes only reads ID3v2
+        assert set(tags.keys()) <= set(mutagen_rs.ID3(get_test_file("silence-44-s.mp3")).keys())
+        assert "TPE1" in tags
+        assert str(tags["TIT2"]) == "Silence"
+
+    def test_short_buffer_reports_truncation(self):
+        data = self._data()
+        full = mutagen_rs.ID3.from_bytes(data)
+        partial = mutagen_rs.ID3.from_bytes(data[:200])
+        assert partial.truncated
+        assert partial.size == full.size > 200
+
+    def test_no_header_raises(self):
+        with pytest.raises(mutagen_rs.ID3NoHeaderError):
+            mutagen_rs.ID3.from_bytes(b"\xff\xfb" + b"\x00" * 64)
+
+    def test_save_to_bytes_round_trip(self):
+        tags = mutagen_rs.ID3.from_bytes(self._data())
+        tags["TIT2"] = "Streamed"
+        rendered = tags.save_to_bytes()
+        assert rendered[:3] == b"ID3"
+        again = mutagen_rs.ID3.from_bytes(rendered)
+        assert str(again["TIT2"]) == "Streamed"
+        assert again.size == len(rendered)
+
+
+class TestID3CommentDescriptor:
+    """Test the null-terminated description of COMM/USLT frames."""
+
+    @staticmethod
+    def _tag(frame_id, payload):
+        return mutagen_rs.ID3.from_bytes(_id3v24([(frame_id, payload)]))
+
+    @staticmethod
+    def _utf16(text):
+        return b"\xff\xfe" + text.encode("utf-16-le")
+
+    @pytest.mark.parametrize("frame_id", [b"COMM", b"USLT"])
+    def test_utf16_double_null(self, frame_id):
+        # U+0100 encodes as 00 01: a null byte that is not a terminator
+        payload = b"\x01eng" + self._utf16("desc\u0100") + b"\x00\x00" + self._utf16("the text")
+        tags = self._tag(frame_id, payload)
+        key = frame_id.decode() + ":desc\u0100:eng"
+        assert tags.keys() == [key]
+        assert str(tags[key]) == "the text"
+
+    @pytest.mark.parametrize("frame_id", [b"COMM", b"USLT"])
+    def test_missing_terminator(self, frame_id):
+        tags = self._tag(frame_id, b"\x00engno terminator")
+        key = frame_id.decode() + ":no terminator:eng"
+        assert tags.keys() == [key]
+        assert str(tags[key]) == ""
+
+    def test_utf16_half_terminator(self):
+        # Truncated inside the terminator: one of its two null bytes is left
+        tags = self._tag(b"COMM", b"\x01eng" + self._utf16("cut") + b"\x00")
+        assert tags.keys() == ["COMM:cut:eng"]
+        assert str(tags["COMM:cut:eng"]) == ""
+
+    def test_utf16_text_half_terminator(self):
+        tags = self._tag(b"USLT", b"\x01eng\x00\x00" + self._utf16("la la") + b"\x00")
+        assert str(tags["USLT::eng"]) == "la la"
+
+
+class TestID3SyncLyrics:
+    """Test SYLT (synchronised lyrics) frames."""
+
+    @staticmethod
+    def _sylt(fmt, pairs, encoding=0, desc="words"):
+        def enc(text):
+            if encoding == 1:
+                return b"\xff\xfe" + text.encode("utf-16-le") + b"\x00\x00"
+            return text.encode("latin-1" if encoding == 0 else "utf-8") + b"\x00"
+        return (bytes([encoding]) + b"eng" + bytes([fmt, 1]) + enc(desc)
+                + b"".join(enc(text) + struct.pack(">I", time) for text, time in pairs))
+
+    def _mp3(self, tmp_path, payload):
+        return _mp3_with_frames(tmp_path, [(b"SYLT", payload)], "sylt.mp3", "no-tags.mp3")
+
+    def test_milliseconds(self, tmp_path):
+        path = self._mp3(tmp_path, self._sylt(2, [("Hel", 0), ("lo", 500), ("world", 1250)]))
+        value = mutagen_rs._fast_read(path)["SYLT:words:eng"]
+        assert value == {"lang": "eng", "desc": "words", "format": 2, "type": 1,
+                         "sync": [("Hel", 0), ("lo", 500), ("world", 1250)]}
+
+    def test_mpeg_frames(self, tmp_path):
+        # MPEG-1 Layer III at 44.1 kHz: 1152 samples, about 26.12 ms a frame
+        path = self._mp3(tmp_path, self._sylt(1, [("one", 0), ("two", 38), ("three", 77)]))
+        expected = [("one", 0), ("two", 993), ("three", 2011)]
+        value = mutagen_rs._fast_read(path)["SYLT:words:eng"]
+        assert value["format"] == 2 and value["sync"] == expected
+        assert mutagen_rs.open_readonly(path).tags["SYLT:words:eng"]["sync"] == expected
+        assert mutagen_rs.MP3(path).getall("SYLT")[0][0]["sync"] == expected
+
+    def test_mpeg_frames_without_audio(self):
+        payload = self._sylt(1, [("one", 0), ("two", 38)])
+        tags = mutagen_rs.ID3.from_bytes(_id3v24([(b"SYLT", payload)]))
+        value = tags["SYLT:words:eng"]
+        assert value["format"] == 1 and value["sync"] == [("one", 0), ("two", 38)]
+
+    def test_utf16(self, tmp_path):
+        path = self._mp3(tmp_path, self._sylt(2, [("\u00e9t\u00e9", 10), ("\u0100", 20)], encoding=1, desc="d\u0100"))
+        value = mutagen_rs._fast_read(path)["SYLT:d\u0100:eng"]
+        assert value["sync"] == [("\u00e9t\u00e9", 10), ("\u0100", 20)]
+
+    def test_truncated_pair_dropped(self, tmp_path):
+        payload = self._sylt(2, [("a", 1), ("b", 2)])[:-2]
+        value = mutagen_rs._fast_read(self._mp3(tmp_path, payload))["SYLT:words:eng"]
+        assert value["sync"] == [("a", 1)]
+
+    def test_add_round_trip(self):
+        tags = mutagen_rs.ID3.from_bytes(b"ID3\x04\x00\x00\x00\x00\x00\x00")
+        key = tags.add(mutagen_rs.SYLT(encoding=3, lang="deu", format=2, type=1, desc="k",
+                                       text=[("la", 100), ("\u00fc", 2500)]))
+        assert key == "SYLT:k:deu"
+        value = mutagen_rs.ID3.from_bytes(tags.render())[key]
+        assert value == {"lang": "deu", "desc": "k", "format": 2, "type": 1,
+                         "sync": [("la", 100), ("\u00fc", 2500)]}
+
+
+class TestID3EncapsulatedObject:
+    """Test GEOB (general encapsulated object) frames."""
+
+    @staticmethod
+    def _geob(filename, desc, data, encoding=0):
+        def enc(text):
+            if encoding == 1:
+                return b"\xff\xfe" + text.encode("utf-16-le") + b"\x00\x00"
+            return text.encode("latin-1" if encoding == 0 else "utf-8") + b"\x00"
+        return bytes([encoding]) + b"text/plain\x00" + enc(filename) + enc(desc) + data
+
+    def _mp3(self, tmp_path, *payloads):
+        return _mp3_with_frames(tmp_path, [(b"GEOB", p) for p in payloads], "geob.mp3", "no-tags.mp3")
+
+    def test_fields(self, tmp_path):
+        path = self._mp3(tmp_path, self._geob("song.lrc", "lyrics", b"[00:01]la\x00\x01"))
+        expected = {"mime": "text/plain", "filename": "song.lrc", "desc": "lyrics", "data": b"[00:01]la\x00\x01"}
+        assert mutagen_rs._fast_read(path)["GEOB:lyrics"] == expected
+        assert mutagen_rs.batch_open([path])[path]["tags"]["GEOB:lyrics"][0] == expected
+        assert mutagen_rs.open_readonly(path).tags["GEOB:lyrics"] == expected
+
+    def test_utf16_strings(self, tmp_path):
+        # A UTF-16 "\u0100" is 00 01: only an aligned double null ends the string
+        path = self._mp3(tmp_path, self._geob("\u0100.txt", "d\u0100", b"\x00\x00data", encoding=1))
+        value = mutagen_rs._fast_read(path)["GEOB:d\u0100"]
+        assert value["filename"] == "\u0100.txt"
+        assert value["data"] == b"\x00\x00data"
+
+    def test_descriptions_coexist(self, tmp_path):
+        path = self._mp3(tmp_path, self._geob("a.bin", "one", b"1"), self._geob("b.bin", "two", b"2"))
+        result = mutagen_rs._fast_read(path)
+        assert result["GEOB:one"]["data"] == b"1"
+        assert result["GEOB:two"]["data"] == b"2"
+
+    def test_add_round_trip(self):
+        tags = mutagen_rs.ID3.from_bytes(b"ID3\x04\x00\x00\x00\x00\x00\x00")
+        key = tags.add(mutagen_rs.GEOB(encoding=3, mime="application/octet-stream",
+                                       filename="\u00fc.bin", desc="k", data=b"\x00\xff"))
+        assert key == "GEOB:k"
+        value = mutagen_rs.ID3.from_bytes(tags.render())[key]
+        assert value == {"mime": "application/octet-stream", "filename": "\u00fc.bin", "desc": "k", "data": b"\x00\xff"}
+
+
+class TestAPEv2:
+    """Test APEv2 tags, standalone and appended to MP3 files."""
+
+    @staticmethod
+    def _ape_tag(items):
+        """An APEv2 tag with header and footer from (key, flags, value) items."""
+        body = b"".join(struct.pack("<II", len(value), flags) + key.encode() + b"\x00" + value
+                        for key, flags, value in items)
+
+        def block(flags):
+            return (b"APETAGEX" + struct.pack("<IIII", 2000, len(body) + 32, len(items), flags)
+                    + b"\x00" * 8)
+        return block(0xA0000000) + body + block(0x80000000)
+
+    def _mp3_with_ape(self, tmp_path, items, name="silence-44-s.mp3", pad=0, trailer=b""):
+        path = copy_fixture(tmp_path, name, "ape.mp3")
+        with open(path, "ab") as f:
+            f.write(b"\x00" * pad + self._ape_tag(items) + trailer)
+        mutagen_rs.clear_all_caches()
+        return path
+
+    def test_standalone_tag(self):
+        tag = mutagen_rs.APEv2(get_test_file("oldtag.apev2"))
+        assert tag.version == 2000
+        assert tag.keys() == ["Track", "Artist", "Title", "Album"]
+        assert tag["Artist"] == ["AnArtist"]
+        assert tag["title"] == ["Some Music"]
+        assert "ALBUM" in tag and "Genre" not in tag
+        assert len(tag) == 4 and list(tag) == tag.keys()
+        with pytest.raises(KeyError):
+            tag["Genre"]
+
+    def test_no_tag(self):
+        with pytest.raises(mutagen_rs.APENoHeaderError):
+            mutagen_rs.APEv2(get_test_file("no-tags.mp3"))
+        assert issubclass(mutagen_rs.APENoHeaderError, mutagen_rs.MutagenError)
+
+    def test_before_lyrics3v2_and_id3v1(self):
+        tag = mutagen_rs.APEv2(get_test_file("apev2-lyricsv2.mp3"))
+        assert tag["REPLAYGAIN_TRACK_GAIN"] == ["-4.080000 dB"]
+
+    def test_item_kinds(self, tmp_path):
+        path = self._mp3_with_ape(tmp_path, [
+            ("Artists", 0, b"One\x00Two"),
+            ("Cover Art (Front)", 2, b"cover.jpg\x00\xff\xd8"),
+            ("Link", 4, b"http://example.com/"),
+        ])
+        tag = mutagen_rs.APEv2(path)
+        assert tag["Artists"] == ["One", "Two"]
+        assert tag["Cover Art (Front)"] == b"cover.jpg\x00\xff\xd8"
+        assert tag["Link"] == ["http://example.com/"]
+        assert [tag.kind(k) for k in tag] == ["text", "binary", "external"]
+
+    def test_mp3_fast_read(self, tmp_path):
+        path = self._mp3_with_ape(tmp_path, [
+            ("Title", 0, b"APE title"),
+            ("CATALOG", 0, b"CAT-1"),
+            ("Artists", 0, b"One\x00Two"),
+            ("Cover Art (Front)", 2, b"cover.jpg\x00\xff\xd8"),
+        ])
+        d = mutagen_rs._fast_read(path)
+        # TIT2 holds the title already
+        assert d["TIT2"] == "Silence" and "Title" not in d
+        assert d["CATALOG"] == "CAT-1"
+        assert d["Artists"] == ["One", "Two"]
+        assert d["Cover Art (Front)"] == b"cover.jpg\x00\xff\xd8"
+        assert d["_keys"][-3:] == ["CATALOG", "Artists", "Cover Art (Front)"]
+        assert mutagen_rs.File(path)["CATALOG"] == ["CAT-1"]
+
+    def test_txxx_covers_key(self, tmp_path):
+        path = self._mp3_with_ape(tmp_path, [("Catalog", 0, b"CAT-1"), ("Label", 0, b"L")])
+        f = mutagen_rs.MP3(path)
+        f["TXXX:CATALOG"] = ["CAT-2"]
+        f.save()
+        mutagen_rs.clear_all_caches()
+        d = mutagen_rs._fast_read(path)
+        assert "Catalog" not in d and d["Label"] == "L"
+
+    def test_large_file(self, tmp_path):
+        # Past the size read by range, behind Lyrics3v2 and ID3v1 trailers
+        lyrics = b"LYRICSBEGININD0000210" + b"000021LYRICS200"
+        trailer = lyrics + b"TAG" + b"\x00" * 125
+        path = self._mp3_with_ape(tmp_path, [("CATALOG", 0, b"CAT-1")], pad=512 * 1024, trailer=trailer)
+        assert mutagen_rs._fast_read(path)["CATALOG"] == "CAT-1"
+        assert mutagen_rs._fast_read_json(path).count(b"CAT-1") == 1
+
+    def test_save_keeps_tag(self, tmp_path):
+        path = self._mp3_with_ape(tmp_path, [("Tool", 0, b"encoder"), ("CATALOG", 0, b"CAT-1")])
+        f = mutagen_rs.File(path)
+        assert f["Tool"] == ["encoder"]
+        f["TIT2"] = ["New"]
+        f.save()
+        mutagen_rs.clear_all_caches()
+        tag = mutagen_rs.APEv2(path)
+        assert tag.keys() == ["Tool", "CATALOG"]
+        # Not written back as an ID3 frame named "Tool"
+        with open(path, "rb") as fh:
+            assert fh.read().count(b"Tool") == 1
+        d = mutagen_rs._fast_read(path)
+        assert d["TIT2"] == "New" and "TOOL" not in d and d["Tool"] == "encoder"
+
+
+class TestID3Transplant:
+    """Test copying a whole ID3 tag between files with render/set_id3_bytes."""
+
+    def test_render_matches_save_to_bytes(self):
+        tags = mutagen_rs.ID3(get_test_file("silence-44-s.mp3"))
+        rendered = tags.render()
+        assert rendered == tags.save_to_bytes()
+        assert rendered[:3] == b"ID3"
+        assert rendered[3] == tags.version[0]
+
+    @pytest.mark.parametrize("target", ["lame.mp3", "no-tags.mp3"])
+    def test_transplant(self, tmp_path, target):
+        rendered = mutagen_rs.ID3(get_test_file("silence-44-s.mp3")).render()
+        dst = copy_fixture(tmp_path, target)
+        old_size = mutagen_rs.ID3(dst).size if mutagen_rs.has_tags(dst) else 0
+        with open(dst, "rb") as f:
+            audio = f.read()[old_size:]
+
+        f = mutagen_rs.MP3(dst)
+        f.set_id3_bytes(rendered)
+        assert str(f["TIT2"]) == "Silence"
+        f.save()
+
+        with open(dst, "rb") as fh:
+            data = fh.read()
+        assert data[:len(rendered)] == rendered
+        assert data[len(rendered):] == audio
+        mutagen_rs.clear_cache()
+        expected = mutagen_rs.ID3.from_bytes(rendered)
+        got = mutagen_rs.ID3(dst)
+        for key in expected.keys():
+            assert got.get_raw(key) == expected.get_raw(key), key
+
+    def test_size_mismatch_rejected(self):
+        rendered = mutagen_rs.ID3(get_test_file("silence-44-s.mp3")).render()
+        f = mutagen_rs.MP3(get_test_file("silence-44-s.mp3"))
+        with pytest.raises(mutagen_rs.ID3Error):
+            f.set_id3_bytes(rendered[:-5])
+        with pytest.raises(mutagen_rs.ID3NoHeaderError):
+            f.set_id3_bytes(b"\x00" * 32)
+
+
+class TestID3UserTextWrite:
+    """Test setting TXXX:desc, COMM:desc:lang and USLT:desc:lang keys builds frames with that description."""
+
+    def test_txxx_round_trip(self, tmp_path):
+        dst = copy_fixture(tmp_path, "silence-44-s.mp3", "txxx.mp3")
+        f = mutagen_rs.MP3(dst)
+        f["TXXX:MusicBrainz Album Id"] = "9e8c4d1a-0000-4000-8000-000000000001"
+        f["TXXX:Mood"] = ["Happy", "Calm"]
+        f.save()
+        mutagen_rs.clear_cache()
+        f = mutagen_rs.MP3(dst)
+        assert f["TXXX:MusicBrainz Album Id"] == ["9e8c4d1a-0000-4000-8000-000000000001"]
+        assert f["TXXX:Mood"] == ["Happy", "Calm"]
+        assert f["TIT2"] == ["Silence"]
+        tags = mutagen_rs.ID3(dst)
+        assert tags["TXXX:MusicBrainz Album Id"] == "9e8c4d1a-0000-4000-8000-000000000001"
+        assert "TXXX" not in tags.keys()
+
+    def test_comm_descriptions(self, tmp_path):
+        dst = copy_fixture(tmp_path, "silence-44-s.mp3", "txxx.mp3")
+        tags = mutagen_rs.ID3(dst)
+        tags["COMM:review:eng"] = "Nice"
+        tags["COMM:note:deu"] = "Gut"
+        tags["COMM:plain"] = "Default language"
+        tags.save()
+        mutagen_rs.clear_cache()
+        tags = mutagen_rs.ID3(dst)
+        assert tags["COMM:review:eng"] == "Nice"
+        assert tags["COMM:note:deu"] == "Gut"
+        assert tags["COMM:plain:eng"] == "Default language"
+
+    def test_language_round_trips_through_mp3(self, tmp_path):
+        dst = copy_fixture(tmp_path, "silence-44-s.mp3", "txxx.mp3")
+        f = mutagen_rs.MP3(dst)
+        f["COMM:note:deu"] = "Gut"
+        f["USLT:verse:fra"] = ["Ligne un", "Ligne deux"]
+        f.save()
+        mutagen_rs.clear_cache()
+        tags = mutagen_rs.ID3(dst)
+        assert tags["COMM:note:deu"] == "Gut"
+        assert tags["USLT:verse:fra"] == "Ligne un\nLigne deux"
+        # Encoding byte, then the language
+        assert tags.get_raw("COMM:note:deu")[11:14] == b"deu"
+        assert tags.get_raw("USLT:verse:fra")[11:14] == b"fra"
+
+    @pytest.mark.parametrize("key", ["COMM:note:de", "COMM:note:deut", "USLT:verse:d\xe9u", "USLT::"])
+    def test_invalid_language_rejected(self, tmp_path, key):
+        tags = mutagen_rs.ID3(copy_fixture(tmp_path, "silence-44-s.mp3", "txxx.mp3"))
+        with pytest.raises(ValueError):
+            tags[key] = "text"
+        f = mutagen_rs.MP3(copy_fixture(tmp_path, "silence-44-s.mp3", "txxx.mp3"))
+        f[key] = "text"
+        with pytest.raises(ValueError):
+            f.save()
+
+    def test_replaces_same_description_only(self, tmp_path):
+        dst = copy_fixture(tmp_path, "silence-44-s.mp3", "txxx.mp3")
+        tags = mutagen_rs.ID3(dst)
+        tags["TXXX:A"] = "one"
+        tags["TXXX:B"] = "two"
+        tags["TXXX:A"] = "three"
+        assert tags["TXXX:A"] == "three"
+        assert tags["TXXX:B"] == "two"
+
+
+class TestID3AddFrames:
+    """Test ID3.add() and setall() keep other frames under the same key."""
+
+    def test_add_second_apic(self, tmp_path):
+        dst = copy_fixture(tmp_path, "silence-44-s.mp3", "add.mp3")
+        tags = mutagen_rs.ID3(dst)
+        assert tags.add({"id": "APIC", "type": 3, "mime": "image/png", "data": b"front"}) == "APIC:"
+        tags.add(mutagen_rs.APIC(type=4, mime="image/jpeg", data=b"back"))
+        tags.save()
+        mutagen_rs.clear_cache()
+        pics = mutagen_rs.ID3(dst).getall("APIC:")
+        assert [(p["type"], p["mime"], p["data"]) for p in pics] == [
+            (3, "image/png", b"front"), (4, "image/jpeg", b"back")]
+
+    def test_add_keeps_setitem_frames(self, tmp_path):
+        dst = copy_fixture(tmp_path, "silence-44-s.mp3", "add.mp3")
+        tags = mutagen_rs.ID3(dst)
+        tags["TIT2"] = "Title"
+        tags.add({"id": "COMM", "lang": "eng", "text": "one"})
+        tags.add({"id": "COMM", "lang": "eng", "text": "two"})
+        tags.add(mutagen_rs.POPM(email="a@b", rating=128, count=3))
+        tags.save()
+        mutagen_rs.clear_cache()
+        tags = mutagen_rs.ID3(dst)
+        assert tags["TIT2"] == "Title"
+        assert tags.getall("COMM::eng") == ["one", "two"]
+        popm = tags["POPM:a@b"]
+        assert (popm.rating, popm.count) == (128, 3)
+
+    def test_setall(self, tmp_path):
+        dst = copy_fixture(tmp_path, "silence-44-s.mp3", "add.mp3")
+        tags = mutagen_rs.ID3(dst)
+        tags.add({"id": "APIC", "data": b"old"})
+        tags.setall("APIC:", [{"data": b"a"}, {"data": b"b"}])
+        assert [p["data"] for p in tags.getall("APIC:")] == [b"a", b"b"]
+        tags.setall("APIC:", [])
+        assert "APIC:" not in tags
+
+    def test_invalid(self, tmp_path):
+        tags = mutagen_rs.ID3(copy_fixture(tmp_path, "silence-44-s.mp3", "add.mp3"))
+        with pytest.raises(ValueError):
+            tags.add({"text": "no id"})
+        with pytest.raises(ValueError):
+            tags.add({"id": "tit2", "text": "x"})
+        with pytest.raises(ValueError):
+            tags.add("TIT2")
+
+
+class TestID3SeekFrames:
+    """v2.4 SEEK and ASPI frames are kept through the frame walk and on save."""
+
+    SEEK = struct.pack(">I", 123456)
+    ASPI = struct.pack(">IIHB", 0, 1000, 2, 8) + b"\x00\x10\x00\x20"
+
+    def _mp3(self, tmp_path):
+        frames = [(b"TIT2", b"\x03Title"), (b"SEEK", self.SEEK),
+                  (b"ASPI", self.ASPI), (b"TCOM", b"\x03Composer")]
+        return _mp3_with_frames(tmp_path, frames, "seek.mp3")
+
+    def test_frames_after_seek_still_parsed(self, tmp_path):
+        path = self._mp3(tmp_path)
+        tags = mutagen_rs.ID3(path)
+        assert tags.seek_offset == 123456
+        assert tags["SEEK"] == self.SEEK
+        assert tags["ASPI"] == self.ASPI
+        assert str(tags["TCOM"]) == "Composer"
+        assert str(mutagen_rs.MP3(path)["TCOM"]) == "Composer"
+
+    def test_preserved_on_save(self, tmp_path):
+        path = self._mp3(tmp_path)
+        f = mutagen_rs.MP3(path)
+        f["TIT2"] = "New title"
+        f.save()
+        mutagen_rs.clear_cache()
+        tags = mutagen_rs.ID3(path)
+        assert str(tags["TIT2"]) == "New title"
+        assert tags.seek_offset == 123456
+        assert tags.get_raw("SEEK")[10:] == self.SEEK
+        assert tags.get_raw("ASPI")[10:] == self.ASPI
+
+    def test_no_seek_frame(self):
+        assert mutagen_rs.ID3(get_test_file("silence-44-s.mp3")).seek_offset is None
+
+
+class TestID3SaveVersion:
+    """Test choosing the ID3v2 version a tag is saved as."""
+
+    def test_version_setter(self, tmp_path):
+        path = copy_fixture(tmp_path, "silence-44-s.mp3", "v.mp3")
+        tags = mutagen_rs.ID3(path)
+        assert tags.version == (3, 0)
+        tags.version = (4, 0)
+        tags.save()
+        assert tags.version == (4, 0)
+        reread = mutagen_rs.ID3(path)
+        assert reread.version == (4, 0)
+        assert "TYER" not in reread.keys()
+        assert str(reread["TDRC"]) == "2004"
+
+    def test_save_v2_version_overrides(self, tmp_path):
+        path = copy_fixture(tmp_path, "silence-44-s.mp3", "v.mp3")
+        tags = mutagen_rs.ID3(path)
+        tags.save(v2_version=4)
+        tags = mutagen_rs.ID3(path)
+        assert tags.version == (4, 0)
+        tags.save(v2_version=3)
+        assert tags.version == (3, 0)
+        reread = mutagen_rs.ID3(path)
+        assert reread.version == (3, 0)
+        assert str(reread["TYER"]) == "2004"
+        with open(path, "rb") as f:
+            assert f.read(4) == b"ID3\x03"
+
+    def test_v23_conversion(self, tmp_path):
+        tags = mutagen_rs.ID3(_mp3_with_frames(tmp_path, [
+            (b"TDRC", b"\x032003-04-05T06:07"),
+            (b"TPE1", b"\x03A\x00B"),
+            (b"TSST", b"\x03subtitle"),
+        ]))
+        assert tags.version == (4, 0)
+        tags.update_to_v23()
+        assert "TDRC" not in tags.keys() and "TSST" not in tags.keys()
+        assert str(tags["TYER"]) == "2003"
+        assert str(tags["TDAT"]) == "0504"
+        assert str(tags["TIME"]) == "0607"
+        assert str(tags["TPE1"]) == "A/B"
+
+    def test_new_date_replaces_v23_year(self, tmp_path):
+        path = copy_fixture(tmp_path, "silence-44-s.mp3", "v.mp3")
+        tags = mutagen_rs.ID3(path)
+        tags["TDRC"] = "2020-05-06"
+        tags.save()
+        reread = mutagen_rs.ID3(path)
+        assert reread.version == (3, 0)
+        assert str(reread["TYER"]) == "2020"
+        assert str(reread["TDAT"]) == "0605"
+        # The ID3v1 year (2004) must not stand in for the new date
+        mutagen_rs.clear_cache()
+        assert str(mutagen_rs.MP3(path).tags["TDRC"]).startswith("2020")
+        assert mutagen_rs.batch_open([path], easy=True)[path]["tags"]["date"] == ["2020"]
+
+    def test_raw_frame_size_follows_version(self, tmp_path):
+        path = copy_fixture(tmp_path, "silence-44-s.mp3", "v.mp3")
+        tags = mutagen_rs.ID3(path)
+        body = b"owner\x00" + b"x" * 295
+        tags.set_raw("PRIV:owner", b"PRIV" + len(body).to_bytes(4, "big") + b"\x00\x00" + body)
+        tags.version = (4, 0)
+        tags.save()
+        reread = mutagen_rs.ID3(path)
+        assert reread.version == (4, 0)
+        key = next(k for k in reread.keys() if k.startswith("PRIV:owner"))
+        assert reread.get_raw(key) == b"PRIV" + _syncsafe(len(body)) + b"\x00\x00" + body
+
+    def test_invalid_versions(self, tmp_path):
+        tags = mutagen_rs.ID3(copy_fixture(tmp_path, "silence-44-s.mp3", "v.mp3"))
+        with pytest.raises(ValueError):
+            tags.version = (5, 0)
+        with pytest.raises(ValueError):
+            tags.save(v2_version=2)
+        assert tags.version == (3, 0)
+
+
+class TestTextDecoding:
+    """Test that invalid UTF-8 and Latin-1 text decode the same on every read path."""
+
+    INVALID = b"ok \xff\xfe bad \xe2\x82"
+
+    def test_id3_text_frames(self, tmp_path):
+        multibyte = "\u00c1g\u00e6tis \u2014 \u30c6\u30b9\u30c8"
+        path = _mp3_with_frames(tmp_path, [
+            (b"TIT2", b"\x03" + self.INVALID),
+            (b"TPE1", b"\x00Bj\xf6rk"),
+            (b"TALB", b"\x03" + multibyte.encode("utf-8")),
+        ])
+        expected = {
+            "TIT2": self.INVALID.decode("utf-8", "replace"),
+            "TPE1": "Bj\u00f6rk",
+            "TALB": multibyte,
+        }
+        f = mutagen_rs.MP3(path)
+        d = mutagen_rs._fast_read(path)
+        batch = mutagen_rs.batch_open([path])[path]["tags"]
+        for key, value in expected.items():
+            assert str(f[key]) == value, key
+            fast = d[key]
+            assert (fast[0] if isinstance(fast, list) else fast) == value, key
+            got = batch[key]
+            assert (got[0] if isinstance(got, list) else got) == value, key
+
+    def test_id3_utf16_invalid_surrogates(self, tmp_path):
+        lone_high = b"\x00\xd8"
+        path = _mp3_with_frames(tmp_path, [
+            (b"TIT2", b"\x01\xff\xfe" + lone_high + "ok".encode("utf-16-le")),
+            (b"TPE1", b"\x02" + "ok".encode("utf-16-be") + b"\xdc\x00"),
+            (b"TALB", b"\x01\xff\xfe" + "Album".encode("utf-16-le")),
+        ])
+        expected = {"TIT2": "\ufffdok", "TPE1": "ok\ufffd", "TALB": "Album"}
+        tags = mutagen_rs.ID3(path)
+        f = mutagen_rs.MP3(path)
+        d = mutagen_rs._fast_read(path)
+        for key, value in expected.items():
+            assert str(tags[key]) == value, key
+            assert str(f[key]) == value, key
+            fast = d[key]
+            assert (fast[0] if isinstance(fast, list) else fast) == value, key
+
+    def test_vorbis_comment_values(self, tmp_path):
+        path = copy_fixture(tmp_path, "silence-44-s.flac", "text.flac")
+        f = mutagen_rs.FLAC(path)
+        f["title"] = ["ok XX bad"]
+        f.save()
+        with open(path, "rb") as fh:
+            data = fh.read()
+        with open(path, "wb") as fh:
+            fh.write(data.replace(b"ok XX bad", b"ok \xff\xfe bad"))
+        mutagen_rs.clear_all_caches()
+        # Comments whose value isn't valid UTF-8 are skipped on every read path
+        assert "title" not in mutagen_rs.FLAC(path).keys()
+        d = mutagen_rs._fast_read(path)
+        assert "title" not in d
+        assert d["album"] == "Quod Libet Test Data"
+        assert "title" not in mutagen_rs.batch_open([path])[path]["tags"]
+
+
 # ──────────────────────────────────────────────────────────────
 # FLAC Tests
 # ──────────────────────────────────────────────────────────────
@@ -204,6 +946,68 @@ FLAC_FILES = [
 ]
 
 
+class TestLegacyCodepage:
+    """Test id3v1_encoding for cp1251 text in ID3v1 fields and latin-1 frames."""
+
+    TITLE = "\u041a\u0438\u043d\u043e"
+    ARTIST = "\u0413\u0440\u0443\u043f\u043f\u0430 \u043a\u0440\u043e\u0432\u0438"
+
+    def _mp3(self, tmp_path):
+        path = _mp3_with_frames(tmp_path, [
+            (b"TIT2", b"\x00" + self.TITLE.encode("cp1251")),
+            (b"TALB", b"\x03plain utf-8"),
+        ])
+        v1 = bytearray(128)
+        v1[0:3] = b"TAG"
+        artist = self.ARTIST.encode("cp1251")
+        v1[33:33 + len(artist)] = artist
+        v1[127] = 255
+        with open(path, "ab") as f:
+            f.write(bytes(v1))
+        return path
+
+    def test_default_is_latin1(self, tmp_path):
+        path = self._mp3(tmp_path)
+        tags = mutagen_rs.ID3(path)
+        assert tags.id3v1_encoding == "latin-1"
+        assert str(tags["TIT2"]) == self.TITLE.encode("cp1251").decode("latin-1")
+
+    @pytest.mark.parametrize("label", ["cp1251", "windows-1251", "auto"])
+    def test_cp1251(self, tmp_path, label):
+        path = self._mp3(tmp_path)
+        tags = mutagen_rs.ID3(path, id3v1_encoding=label)
+        assert tags.id3v1_encoding == "windows-1251"
+        assert str(tags["TIT2"]) == self.TITLE
+        assert str(tags["TPE1"]) == self.ARTIST
+        assert str(tags["TALB"]) == "plain utf-8"
+
+        mp3 = mutagen_rs.mutagen_rs.MP3(path, id3v1_encoding=label)
+        assert mp3.id3v1_encoding == "windows-1251"
+        assert str(mp3["TIT2"]) == self.TITLE
+
+        d = mutagen_rs._fast_read(path, id3v1_encoding=label)
+        assert d["_id3v1_encoding"] == "windows-1251"
+        assert d["TIT2"] == self.TITLE
+        assert d["TPE1"] == self.ARTIST
+        assert "_id3v1_encoding" not in mutagen_rs._fast_read(path)
+
+    def test_auto_keeps_latin1(self, tmp_path):
+        path = _mp3_with_frames(tmp_path, [(b"TPE1", b"\x00Bj\xf6rk Gu\xf0mundsd\xf3ttir")])
+        tags = mutagen_rs.ID3(path, id3v1_encoding="auto")
+        assert tags.id3v1_encoding == "latin-1"
+        assert str(tags["TPE1"]) == "Bj\u00f6rk Gu\u00f0mundsd\u00f3ttir"
+
+    def test_save_rewrites_as_unicode(self, tmp_path):
+        path = self._mp3(tmp_path)
+        tags = mutagen_rs.ID3(path, id3v1_encoding="cp1251")
+        tags.save(path)
+        assert str(mutagen_rs.ID3(path)["TIT2"]) == self.TITLE
+
+    def test_unknown_label(self, tmp_path):
+        with pytest.raises(ValueError):
+            mutagen_rs.ID3(self._mp3(tmp_path), id3v1_encoding="no-such-codepage")
+
+
 class TestFLACCompat:
     """Test FLAC compatibility."""
 
@@ -305,8 +1109,262 @@ class TestFLACErrorHandling:
             mutagen_rs.FLAC(path)
 
 
-# ──────────────────────────────────────────────────────────────
-# OGG Vorbis Tests
+class TestFLACUnknownLength:
+    """Test FLAC streams whose STREAMINFO has total_samples == 0 (unknown length)."""
+
+    def _streaming_flac(self, tmp_path):
+        src = get_test_file("silence-44-s.flac")
+        if not os.path.exists(src):
+            pytest.skip("Test file not found")
+        with open(src, "rb") as f:
+            data = bytearray(f.read())
+        # STREAMINFO body starts at 8; total_samples is the low nibble of byte
+        # 13 plus bytes 14-17.
+        data[8 + 13] &= 0xF0
+        data[8 + 14:8 + 18] = b"\x00\x00\x00\x00"
+        path = str(tmp_path / "stream.flac")
+        with open(path, "wb") as f:
+            f.write(bytes(data))
+        return path
+
+    def test_length_is_none(self, tmp_path):
+        path = self._streaming_flac(tmp_path)
+        f = mutagen_rs.FLAC(path)
+        assert f.info.length is None
+        assert f.info.total_samples == 0
+        assert f.info.sample_rate == 44100
+        assert f.info.bitrate == 0
+        assert "unknown length" in f.info.pprint()
+        assert f.info.length_samples is None
+        assert f.info.duration_ms is None
+        assert mutagen_rs.File(path).info.length is None
+        assert mutagen_rs.File(path).info.length_samples is None
+
+    def test_fast_and_batch_paths(self, tmp_path):
+        path = self._streaming_flac(tmp_path)
+        d = mutagen_rs._fast_read(path)
+        assert d["length"] is None
+        assert d["title"] == "Silence"
+        mutagen_rs.clear_all_caches()
+        assert mutagen_rs.duration(path) is None
+        assert mutagen_rs._fast_info(path)["length"] is None
+        assert mutagen_rs.duration(path) is None
+        result = mutagen_rs.batch_open([path])[path]
+        assert result["length"] is None
+        assert "bitrate" not in result
+        assert "duration_ms" not in result
+        assert json.loads(mutagen_rs._fast_read_json(path))["length"] is None
+
+    def test_known_length_is_float(self):
+        # A real STREAMINFO length stays a float
+        path = get_test_file("silence-44-s.flac")
+        if not os.path.exists(path):
+            pytest.skip("Test file not found")
+        assert isinstance(mutagen_rs.FLAC(path).info.length, float)
+        assert isinstance(mutagen_rs._fast_read(path)["length"], float)
+
+
+class TestFLACStreamInfoPlacement:
+    """Test FLACs whose VORBIS_COMMENT precedes STREAMINFO, or that have none."""
+
+    def _reordered(self, tmp_path, keep_streaminfo):
+        src = get_test_file("silence-44-s.flac")
+        if not os.path.exists(src):
+            pytest.skip("Test file not found")
+        with open(src, "rb") as f:
+            data = f.read()
+        blocks = mutagen_rs.FLAC(src).metadata_blocks
+        raw = {b["block_type"]: data[b["offset"]:b["offset"] + 4 + b["size"]] for b in blocks}
+        end = blocks[-1]["offset"] + 4 + blocks[-1]["size"]
+        order = [4, 0] if keep_streaminfo else [4]
+        out = b"fLaC" + b"".join(bytes([raw[t][0] & 0x7F]) + raw[t][1:] for t in order)
+        out += bytes([0x81]) + b"\x00\x00\x00"  # empty padding as the last block
+        path = str(tmp_path / "order.flac")
+        with open(path, "wb") as f:
+            f.write(out + data[end:])
+        mutagen_rs.clear_all_caches()
+        return path
+
+    def test_comment_before_streaminfo(self, tmp_path):
+        path = self._reordered(tmp_path, True)
+        expected = mutagen_rs.FLAC(get_test_file("silence-44-s.flac"))
+        f = mutagen_rs.FLAC(path)
+        assert f["title"] == expected["title"]
+        assert f.info.length == expected.info.length
+        d = mutagen_rs._fast_read(path)
+        assert d["title"] == expected["title"][0]
+        assert d["length"] == expected.info.length
+        assert mutagen_rs.batch_open([path])[path]["length"] == expected.info.length
+
+    def test_missing_streaminfo(self, tmp_path):
+        path = self._reordered(tmp_path, False)
+        title = mutagen_rs.FLAC(get_test_file("silence-44-s.flac"))["title"]
+        for f in (mutagen_rs.FLAC(path), mutagen_rs.File(path)):
+            assert f["title"] == title
+            assert f.info.length is None
+            assert f.info.sample_rate == 0
+        d = mutagen_rs._fast_read(path)
+        assert d["title"] == title[0]
+        assert d["length"] is None
+        result = mutagen_rs.batch_open([path])[path]
+        assert result["tags"]["title"] == title
+        assert result["length"] is None
+
+
+class TestFLACManyComments:
+    """Large comment blocks group values per key in first-seen order."""
+
+    def _flac_with_comments(self, tmp_path, comments):
+        src = get_test_file("silence-44-s.flac")
+        if not os.path.exists(src):
+            pytest.skip("Test file not found")
+        with open(src, "rb") as f:
+            data = f.read()
+        vendor = b"test"
+        vc = struct.pack("<I", len(vendor)) + vendor + struct.pack("<I", len(comments))
+        for c in comments:
+            vc += struct.pack("<I", len(c)) + c
+        # Keep STREAMINFO and the audio; the comment block becomes the last block
+        pos = 4
+        while True:
+            header = data[pos]
+            size = int.from_bytes(data[pos + 1:pos + 4], "big")
+            if header & 0x7F == 0:
+                streaminfo = data[pos:pos + 4 + size]
+            pos += 4 + size
+            if header & 0x80:
+                break
+        out = (b"fLaC" + bytes([streaminfo[0] & 0x7F]) + streaminfo[1:]
+               + bytes([0x84]) + len(vc).to_bytes(3, "big") + vc + data[pos:])
+        path = str(tmp_path / "many.flac")
+        with open(path, "wb") as f:
+            f.write(out)
+        return path
+
+    def test_grouping_past_linear_threshold(self, tmp_path):
+        # 300 distinct keys, each repeated after all others have been seen
+        keys = [f"CUEPOINT{i:03d}" for i in range(300)]
+        comments = [f"{k}=a".encode() for k in keys] + [f"{k}=b".encode() for k in keys]
+        path = self._flac_with_comments(tmp_path, comments)
+        expected_keys = [k.lower() for k in keys]
+
+        f = mutagen_rs.FLAC(path)
+        assert sorted(f.keys()) == sorted(expected_keys)
+        assert f["cuepoint150"] == ["a", "b"]
+        assert mutagen_rs._fast_read(path)["_keys"] == expected_keys
+
+        result = mutagen_rs.batch_open([path])[path]
+        assert list(result["tags"]) == expected_keys
+        assert all(result["tags"][k] == ["a", "b"] for k in expected_keys)
+
+
+class TestOpenReadonly:
+    """Test open_readonly: File() info and tags without the mutable tag objects."""
+
+    @pytest.mark.parametrize("name", [
+        "silence-44-s.mp3", "silence-44-s.flac", "multipagecomment.ogg", "has-tags.m4a",
+    ])
+    def test_matches_full_class(self, name):
+        path = get_test_file(name)
+        full = mutagen_rs.mutagen_rs.File(path)
+        ro = mutagen_rs.open_readonly(path)
+        assert type(ro.info) is type(full.info)
+        assert ro.info.length == full.info.length
+        assert ro.info.sample_rate == full.info.sample_rate
+        assert ro.keys() == full.keys()
+        assert len(ro) == len(full.keys()) and list(ro) == full.keys()
+        for key in ro.keys():
+            assert ro[key] == full[key], key
+            assert key in ro
+        assert ro.tags == {k: full[k] for k in full.keys()}
+        assert ro.get("no-such-key", 1) == 1
+        with pytest.raises(KeyError):
+            ro["no-such-key"]
+        assert not hasattr(ro, "save")
+
+    def test_tags_copy_is_detached(self):
+        ro = mutagen_rs.open_readonly(get_test_file("silence-44-s.flac"))
+        ro.tags["title"] = ["changed"]
+        assert ro["title"] != ["changed"]
+
+
+class TestFLACBlockLayout:
+    """Test metadata_blocks, validate_layout and the padding-aware save."""
+
+    def _write(self, path, data):
+        with open(path, "wb") as f:
+            f.write(data)
+        mutagen_rs.clear_all_caches()
+
+    def test_blocks_cover_metadata(self):
+        f = mutagen_rs.FLAC(get_test_file("silence-44-s.flac"))
+        blocks = f.metadata_blocks
+        assert [b["block_type"] for b in blocks] == [0, 3, 4, 5, 6, 1]
+        assert blocks[0]["offset"] == 4
+        for prev, cur in zip(blocks, blocks[1:]):
+            assert cur["offset"] == prev["offset"] + 4 + prev["size"]
+        assert [b["is_last"] for b in blocks] == [False] * 5 + [True]
+        assert len(f.get_raw_block(2)) == 4 + blocks[2]["size"]
+        assert f.validate_layout() == []
+
+    def test_overlapping_comment_block(self):
+        f = mutagen_rs.FLAC(get_test_file("52-too-short-block-size.flac"))
+        warnings = f.validate_layout()
+        assert len(warnings) == 1 and "VORBIS_COMMENT" in warnings[0]
+
+    def test_streaminfo_not_first(self, tmp_path):
+        path = copy_fixture(tmp_path, "silence-44-s.flac")
+        with open(path, "rb") as f:
+            data = f.read()
+        blocks = mutagen_rs.FLAC(path).metadata_blocks
+        si, st = blocks[0], blocks[1]
+        streaminfo = data[si["offset"]:si["offset"] + 4 + si["size"]]
+        seektable = data[st["offset"]:st["offset"] + 4 + st["size"]]
+        self._write(path, b"fLaC" + seektable + streaminfo + data[st["offset"] + 4 + st["size"]:])
+        warnings = mutagen_rs.FLAC(path).validate_layout()
+        assert warnings == ["STREAMINFO is block 1 instead of the first block"]
+
+    def test_last_flag_set_early(self, tmp_path):
+        path = copy_fixture(tmp_path, "silence-44-s.flac")
+        with open(path, "rb") as f:
+            data = bytearray(f.read())
+        data[4] |= 0x80
+        self._write(path, bytes(data))
+        f = mutagen_rs.FLAC(path)
+        assert len(f.metadata_blocks) == 1
+        warnings = f.validate_layout()
+        assert len(warnings) == 1 and "is-last set" in warnings[0]
+
+    def test_save_reuses_padding(self, tmp_path):
+        path = copy_fixture(tmp_path, "silence-44-s.flac")
+        with open(path, "rb") as fh:
+            before = fh.read()
+        f = mutagen_rs.FLAC(path)
+        audio_start = f.metadata_blocks[-1]["offset"] + 4 + f.metadata_blocks[-1]["size"]
+        f["title"] = ["A somewhat longer title than before"]
+        f.save()
+        with open(path, "rb") as fh:
+            after = fh.read()
+        assert len(after) == len(before)
+        assert after[audio_start:] == before[audio_start:]
+        mutagen_rs.clear_all_caches()
+        f = mutagen_rs.FLAC(path)
+        assert f["title"] == ["A somewhat longer title than before"]
+        assert f.metadata_blocks[-1]["block_type"] == 1
+        assert f.validate_layout() == []
+
+    def test_save_grows_past_padding(self, tmp_path):
+        path = copy_fixture(tmp_path, "silence-44-s.flac")
+        f = mutagen_rs.FLAC(path)
+        f["comment"] = ["x" * 8000]
+        f.save()
+        mutagen_rs.clear_all_caches()
+        f = mutagen_rs.FLAC(path)
+        assert f["comment"] == ["x" * 8000]
+        blocks = f.metadata_blocks
+        assert (blocks[-1]["block_type"], blocks[-1]["size"]) == (1, 1024)
+        assert f.validate_layout() == []
+        assert f.info.length > 0
 # ──────────────────────────────────────────────────────────────
 
 OGG_FILES = [
@@ -376,6 +1434,403 @@ class TestOggTags:
         assert len(list(rust.keys())) == 0
 
 
+class TestOggVorbisWrite:
+    """Test saving Vorbis comments: repaged headers, setup packet kept."""
+
+    @staticmethod
+    def _crc(page):
+        crc = 0
+        for byte in page[:22] + b"\x00" * 4 + page[26:]:
+            crc ^= byte << 24
+            for _ in range(8):
+                crc = ((crc << 1) ^ 0x04C11DB7 if crc & 0x80000000 else crc << 1) & 0xFFFFFFFF
+        return crc
+
+    def _packets(self, data):
+        """Header packets (identification, comment, setup) of the first stream."""
+        packets, current = [], b""
+        for off, _ in TestOggStreamInfo()._pages(data):
+            nseg = data[off + 26]
+            pos = off + 27 + nseg
+            for seg in data[off + 27:off + 27 + nseg]:
+                current += data[pos:pos + seg]
+                pos += seg
+                if seg < 255:
+                    packets.append(current)
+                    current = b""
+                    if len(packets) == 3:
+                        return packets
+        return packets
+
+    def _copy(self, tmp_path, name):
+        path = copy_fixture(tmp_path, name)
+        with open(path, "rb") as fh:
+            return path, fh.read()
+
+    def _check_pages(self, data):
+        pages = TestOggStreamInfo()._pages(data)
+        sequences = [struct.unpack("<I", data[off + 18:off + 22])[0] for off, _ in pages]
+        assert sequences == list(range(len(pages)))
+        ends = [off for off, _ in pages[1:]] + [len(data)]
+        for (off, _), end in zip(pages, ends):
+            page = data[off:end]
+            assert struct.unpack("<I", page[22:26])[0] == self._crc(page)
+        return pages
+
+    @pytest.mark.parametrize("name", ["empty.ogg", "multipage-setup.ogg", "multipagecomment.ogg"])
+    def test_save_keeps_setup_header(self, tmp_path, name):
+        path, before = self._copy(tmp_path, name)
+        f = mutagen_rs.OggVorbis(path)
+        f["title"] = ["Saved title"]
+        f.save()
+        mutagen_rs.clear_all_caches()
+        with open(path, "rb") as fh:
+            after = fh.read()
+        old, new = self._packets(before), self._packets(after)
+        assert new[0] == old[0]
+        assert new[1].startswith(b"\x03vorbis")
+        assert new[2] == old[2]
+        self._check_pages(after)
+        g = mutagen_rs.OggVorbis(path)
+        assert g["title"] == ["Saved title"]
+        assert g.info.length == f.info.length
+
+    def test_large_comment_spans_pages(self, tmp_path):
+        path, before = self._copy(tmp_path, "empty.ogg")
+        f = mutagen_rs.OggVorbis(path)
+        f["comment"] = ["x" * 200000]
+        f.save()
+        mutagen_rs.clear_all_caches()
+        with open(path, "rb") as fh:
+            after = fh.read()
+        pages = self._check_pages(after)
+        assert len(pages) > len(TestOggStreamInfo()._pages(before))
+        g = mutagen_rs.OggVorbis(path)
+        assert g["comment"] == ["x" * 200000]
+        assert g.info.page_count == len(pages)
+        assert self._packets(after)[2] == self._packets(before)[2]
+
+    def test_shrinking_comment_renumbers(self, tmp_path):
+        path, before = self._copy(tmp_path, "multipagecomment.ogg")
+        f = mutagen_rs.OggVorbis(path)
+        f.strip(["big", "bigger"])
+        f.save()
+        mutagen_rs.clear_all_caches()
+        with open(path, "rb") as fh:
+            after = fh.read()
+        pages = self._check_pages(after)
+        assert len(pages) < len(TestOggStreamInfo()._pages(before))
+        g = mutagen_rs.OggVorbis(path)
+        assert not any(k.lower() in ("big", "bigger") for k in g.keys())
+        assert abs(g.info.length - f.info.length) < 1e-9
+
+
+class TestOggRecovery:
+    """Test length recovery when the Vorbis identification header is damaged."""
+
+    def _damaged(self, tmp_path, rate, bitrate=None, chained=False):
+        src = get_test_file("empty.ogg")
+        if not os.path.exists(src):
+            pytest.skip("Test file not found")
+        with open(src, "rb") as f:
+            data = bytearray(f.read())
+        original = bytes(data)
+        ident = data.find(b"\x01vorbis")
+        data[ident + 12:ident + 16] = struct.pack("<I", rate)
+        if bitrate is not None:
+            data[ident + 20:ident + 24] = struct.pack("<I", bitrate)
+        if chained:
+            data += original
+        path = str(tmp_path / "damaged.ogg")
+        with open(path, "wb") as f:
+            f.write(data)
+        mutagen_rs.clear_all_caches()
+        return path
+
+    def _infos(self, path):
+        info = mutagen_rs.mutagen_rs.OggVorbis(path).info
+        yield info.length, info.sample_rate, info.recovered
+        for d in (mutagen_rs._fast_read(path), mutagen_rs._fast_info(path)):
+            yield d["length"], d["sample_rate"], d["recovered"]
+        b = mutagen_rs._fast_batch_read([path])[path]
+        yield b["length"], b["sample_rate"], b.get("recovered", False)
+
+    def test_intact_header(self):
+        path = get_test_file("empty.ogg")
+        for length, rate, recovered in self._infos(path):
+            assert rate == 44100 and not recovered and length > 3
+
+    @pytest.mark.parametrize("rate", [0, 5_000_000])
+    def test_rate_from_chained_copy(self, tmp_path, rate):
+        path = self._damaged(tmp_path, rate, chained=True)
+        expected = mutagen_rs.mutagen_rs.OggVorbis(get_test_file("empty.ogg")).info.length
+        for length, sample_rate, recovered in self._infos(path):
+            assert (sample_rate, recovered) == (44100, True)
+            assert abs(length - expected) < 1e-9
+
+    def test_length_from_nominal_bitrate(self, tmp_path):
+        path = self._damaged(tmp_path, 0)
+        size = os.path.getsize(path)
+        for length, rate, recovered in self._infos(path):
+            assert (rate, recovered) == (0, True)
+            assert abs(length - size * 8 / 112000) < 1e-9
+
+    def test_garbage_stays_finite(self, tmp_path):
+        path = self._damaged(tmp_path, 0xFFFFFFFF, bitrate=0xFFFFFFFF)
+        for length, rate, recovered in self._infos(path):
+            assert (length, rate, recovered) == (0.0, 0, False)
+
+
+class TestHeaderRangeChecks:
+    """Out-of-range sample rate, channels and bits read as 0 with a warning."""
+
+    def _patched(self, tmp_path, name, patch):
+        with open(get_test_file(name), "rb") as f:
+            data = bytearray(f.read())
+        patch(data)
+        path = str(tmp_path / ("patched-" + name))
+        with open(path, "wb") as f:
+            f.write(data)
+        mutagen_rs.clear_all_caches()
+        return path
+
+    def test_flac_sample_rate(self, tmp_path):
+        def patch(data):
+            # STREAMINFO's 20-bit rate starts 10 bytes into the block body
+            data[18:20] = b"\xff\xff"
+            data[20] |= 0xF0
+        path = self._patched(tmp_path, "silence-44-s.flac", patch)
+        info = mutagen_rs.FLAC(path).info
+        assert info.sample_rate == 0
+        assert info.length == 0.0
+        assert info.warnings == ["sample_rate 1048575 out of range 1..=768000, using 0"]
+        d = mutagen_rs._fast_info(path)
+        assert (d["sample_rate"], d["length"]) == (0, 0.0)
+        assert d["_warnings"] == info.warnings
+
+    def test_ogg_channels(self, tmp_path):
+        def patch(data):
+            data[data.find(b"\x01vorbis") + 11] = 200
+        path = self._patched(tmp_path, "empty.ogg", patch)
+        info = mutagen_rs.mutagen_rs.OggVorbis(path).info
+        assert info.channels == 0
+        assert info.sample_rate == 44100
+        assert info.warnings == ["channels 200 out of range 1..=64, using 0"]
+        for d in (mutagen_rs._fast_read(path), mutagen_rs._fast_info(path)):
+            assert d["channels"] == 0
+
+    def test_mp4_sample_entry(self, tmp_path):
+        def patch(data):
+            entry = data.find(b"mp4a")
+            data[entry + 22:entry + 24] = struct.pack(">H", 200)
+            data[entry + 28:entry + 30] = b"\x00\x00"
+        path = self._patched(tmp_path, "has-tags.m4a", patch)
+        info = mutagen_rs.MP4(path).info
+        assert (info.bits_per_sample, info.sample_rate, info.channels) == (0, 0, 2)
+        assert info.length == mutagen_rs.MP4(get_test_file("has-tags.m4a")).info.length
+        assert info.warnings == [
+            "bits_per_sample 200 out of range 0..=64, using 0",
+            "sample_rate 0 out of range 1..=768000, using 0",
+        ]
+        for d in (mutagen_rs._fast_read(path), mutagen_rs._fast_info(path)):
+            assert (d["sample_rate"], d["channels"]) == (0, 2)
+
+    def test_intact_headers_have_no_warnings(self):
+        assert mutagen_rs.FLAC(get_test_file("silence-44-s.flac")).info.warnings == []
+        assert mutagen_rs.mutagen_rs.OggVorbis(get_test_file("empty.ogg")).info.warnings == []
+        assert mutagen_rs.MP4(get_test_file("has-tags.m4a")).info.warnings == []
+
+
+class TestOggHeaderOnly:
+    """Files cut after the three Vorbis header packets: tags, zero length."""
+
+    def _header_only(self, tmp_path, name):
+        src = get_test_file(name)
+        if not os.path.exists(src):
+            pytest.skip("Test file not found")
+        with open(src, "rb") as f:
+            data = f.read()
+        # Header pages carry granule 0 (or -1); the first audio page ends them
+        off = 0
+        while struct.unpack("<q", data[off + 6:off + 14])[0] <= 0:
+            nseg = data[off + 26]
+            off += 27 + nseg + sum(data[off + 27:off + 27 + nseg])
+        path = str(tmp_path / ("header-only-" + name))
+        with open(path, "wb") as f:
+            f.write(data[:off])
+        mutagen_rs.clear_all_caches()
+        return src, path
+
+    @pytest.mark.parametrize("name", ["empty.ogg", "multipage-setup.ogg", "multipagecomment.ogg"])
+    def test_tags_and_zero_length(self, tmp_path, name):
+        src, path = self._header_only(tmp_path, name)
+        expected = dict(mutagen_rs._RustOggVorbis(src).tags or {})
+        f = mutagen_rs._RustOggVorbis(path)
+        assert f.info.length == 0.0
+        assert dict(f.tags or {}) == expected
+        f = mutagen_rs.OggVorbis(path)
+        assert f.info.length == 0.0
+        assert dict(f) == expected
+        assert set(mutagen_rs._fast_read(path)["_keys"]) == set(expected)
+        b = mutagen_rs.batch_open([path])[path]
+        assert b["length"] == 0.0
+        assert b["tags"] == expected
+        assert mutagen_rs._fast_info(path)["length"] == 0.0
+        assert mutagen_rs.duration(path) == 0.0
+
+
+class TestOggStreamInfo:
+    """info.serial / info.page_count for debugging Ogg container issues."""
+
+    def _pages(self, data):
+        """(offset, serial) of every page, walked from the headers."""
+        pages, off = [], 0
+        while off + 27 <= len(data):
+            assert data[off:off + 4] == b"OggS"
+            nseg = data[off + 26]
+            pages.append((off, struct.unpack("<I", data[off + 14:off + 18])[0]))
+            off += 27 + nseg + sum(data[off + 27:off + 27 + nseg])
+        return pages
+
+    @pytest.mark.parametrize("name", ["empty.ogg", "multipagecomment.ogg", "multipage-setup.ogg"])
+    def test_serial_and_page_count(self, name):
+        path = get_test_file(name)
+        if not os.path.exists(path):
+            pytest.skip("Test file not found")
+        with open(path, "rb") as f:
+            pages = self._pages(f.read())
+        serial = pages[0][1]
+        expected = sum(1 for _, s in pages if s == serial)
+        for f in (mutagen_rs.OggVorbis(path), mutagen_rs._RustOggVorbis(path)):
+            assert f.info.serial == serial
+            assert f.info.page_count == expected
+        assert expected >= 3
+
+    def test_resyncs_after_damaged_page(self, tmp_path):
+        src = get_test_file("multipagecomment.ogg")
+        if not os.path.exists(src):
+            pytest.skip("Test file not found")
+        with open(src, "rb") as f:
+            data = bytearray(f.read())
+        pages = self._pages(bytes(data))
+        # Break the capture pattern of a comment page in the middle
+        off = pages[len(pages) // 2][0]
+        data[off:off + 4] = b"XggS"
+        path = str(tmp_path / "damaged.ogg")
+        with open(path, "wb") as f:
+            f.write(data)
+        assert mutagen_rs._RustOggVorbis(path).info.page_count == len(pages) - 1
+
+
+class TestOggOpus:
+    """Ogg Opus: OpusHead info, OpusTags comments, 48 kHz granule length."""
+
+    def test_info(self, tmp_path):
+        path = copy_fixture(tmp_path, "example.opus")
+        f = mutagen_rs.File(path)
+        assert type(f).__name__ == "OggOpus"
+        # Last granule 545026 + pre-skip 65535 at 48 kHz
+        assert abs(f.info.length - 545026 / 48000) < 1e-9
+        assert f.info.channels == 1
+        assert f.info.sample_rate == 48000
+        assert (f.info.pre_skip, f.info.input_sample_rate, f.info.output_gain) == (65535, 48000, 0.0)
+        assert f.vendor.startswith("libopus")
+        native = mutagen_rs._RustOggOpus(path)
+        assert native.info.length == f.info.length
+        assert native.info.page_count == f.info.page_count
+
+    def test_file_open_and_batch(self, tmp_path):
+        path = copy_fixture(tmp_path, "example.opus")
+        assert type(mutagen_rs._rust_file_open(path)).__name__ == "OggOpus"
+        b = mutagen_rs.batch_open([path])[path]
+        assert b["sample_rate"] == 48000
+        assert b["length"] == mutagen_rs.File(path).info.length
+
+    def test_save_roundtrip(self, tmp_path):
+        path = copy_fixture(tmp_path, "example.opus")
+        with open(path, "rb") as fh:
+            before = TestOggStreamInfo()._pages(fh.read())
+        f = mutagen_rs.OggOpus(path)
+        f["title"] = ["Opus title"]
+        # Large enough to spread the OpusTags packet over several pages
+        f["comment"] = ["x" * 200000]
+        f.save()
+        mutagen_rs.clear_all_caches()
+        g = mutagen_rs.OggOpus(path)
+        assert g["title"] == ["Opus title"]
+        assert g["comment"] == ["x" * 200000]
+        assert g.vendor == f.vendor
+        assert g.info.length == f.info.length
+        with open(path, "rb") as fh:
+            data = fh.read()
+        pages = TestOggStreamInfo()._pages(data)
+        assert len(pages) > len(before)
+        sequences = [struct.unpack("<I", data[off + 18:off + 22])[0] for off, _ in pages]
+        assert sequences == list(range(len(pages)))
+        assert g.info.page_count == len(pages)
+
+    def test_delete(self, tmp_path):
+        path = copy_fixture(tmp_path, "example.opus")
+        f = mutagen_rs.OggOpus(path)
+        f["artist"] = ["Someone"]
+        f.save()
+        mutagen_rs.clear_all_caches()
+        mutagen_rs.OggOpus(path).delete()
+        mutagen_rs.clear_all_caches()
+        g = mutagen_rs.OggOpus(path)
+        assert g.keys() == []
+        assert g.vendor == f.vendor
+
+    def test_chained_stream_uses_first_link(self, tmp_path):
+        path = copy_fixture(tmp_path, "example.opus")
+        with open(path, "rb") as fh:
+            data = fh.read()
+        # Second link: the same pages under another serial number
+        link = bytearray(data)
+        for off, _ in TestOggStreamInfo()._pages(data):
+            link[off + 14:off + 18] = struct.pack("<I", 0x1234)
+        with open(path, "ab") as fh:
+            fh.write(link)
+        f = mutagen_rs.OggOpus(path)
+        assert abs(f.info.length - 545026 / 48000) < 1e-9
+
+    def test_pre_skip_longer_than_stream(self, tmp_path):
+        path = copy_fixture(tmp_path, "example.opus")
+        with open(path, "rb") as fh:
+            data = bytearray(fh.read())
+        last = data.rfind(b"OggS")
+        data[last + 6:last + 14] = struct.pack("<q", 1000)
+        with open(path, "wb") as fh:
+            fh.write(data)
+        f = mutagen_rs.OggOpus(path)
+        assert f.info.length == 0.0
+        assert mutagen_rs._RustOggOpus(path).info.length_samples == 0
+
+    def test_opus_in_ogg_extension(self, tmp_path):
+        path = copy_fixture(tmp_path, "example.opus", "opus.ogg")
+        f = mutagen_rs.File(path)
+        assert type(f).__name__ == "OggOpus"
+        assert abs(f.info.length - 545026 / 48000) < 1e-9
+        assert f.info.pre_skip == 65535
+        info = mutagen_rs._fast_info(path)
+        assert (info["length_samples"], info["sample_rate"]) == (545026, 48000)
+        assert mutagen_rs.batch_open([path])[path]["length"] == f.info.length
+        assert mutagen_rs.verify_extension(path) is None
+        f["title"] = ["In .ogg"]
+        f.save()
+        mutagen_rs.clear_all_caches()
+        g = mutagen_rs.File(path)
+        assert g["title"] == ["In .ogg"]
+        assert g.vendor == f.vendor
+
+    def test_detected_without_extension(self, tmp_path):
+        path = copy_fixture(tmp_path, "example.opus", "opus.bin")
+        f = mutagen_rs.File(path)
+        assert type(f).__name__ == "OggOpus"
+        assert abs(f.info.length - 545026 / 48000) < 1e-9
+        assert mutagen_rs.verify_extension(path) == "opus"
+
+
 # ──────────────────────────────────────────────────────────────
 # MP4 Tests
 # ──────────────────────────────────────────────────────────────
@@ -476,104 +1931,2641 @@ class TestMP4NoTags:
         assert orig_count == rust_count == 0
 
 
-# ──────────────────────────────────────────────────────────────
-# File() auto-detection tests
-# ──────────────────────────────────────────────────────────────
+class TestMP4Genre:
+    """Test MP4 files carrying both gnre and \xa9gen atoms."""
 
-class TestFileAutoDetect:
-    """Test mutagen_rs.File() format auto-detection."""
+    def _copy(self, tmp_path, free_text):
+        dst = copy_fixture(tmp_path, "has-tags.m4a", "genre.m4a")
+        mutagen_rs.MP4(dst).delete()
+        mutagen_rs.clear_cache()
+        # gnre: implicit-type data atom holding ID3v1 genre number + 1 (18 -> Rock)
+        data = (18).to_bytes(4, "big") + b"data" + b"\x00" * 8 + (18).to_bytes(2, "big")
+        f = mutagen_rs.MP4(dst)
+        f.set_raw("gnre", (8 + len(data)).to_bytes(4, "big") + b"gnre" + data)
+        if free_text:
+            f["\xa9gen"] = [free_text]
+        f.save()
+        mutagen_rs.clear_cache()
+        return dst
 
-    @pytest.fixture(params=[
-        "silence-44-s.mp3",
-        "silence-44-s.flac",
-        "empty.ogg",
-        "has-tags.m4a",
-        "no-tags.mp3",
-        "no-tags.flac",
-        "no-tags.m4a",
-        "nero-chapters.m4b",
-        "variable-block.flac",
-        "xing.mp3",
-    ])
-    def audio_file(self, request):
-        path = get_test_file(request.param)
-        if not os.path.exists(path):
-            pytest.skip(f"Test file not found: {path}")
-        return path
+    def _both(self, tmp_path):
+        return self._copy(tmp_path, "Shoegaze")
 
-    def test_file_opens(self, audio_file):
-        """File() should auto-detect format and open successfully."""
-        f = mutagen_rs.File(audio_file)
-        assert f is not None
+    def test_both_keys_readable(self, tmp_path):
+        path = self._both(tmp_path)
+        f = mutagen_rs.MP4(path)
+        assert f["gnre"] == ["Rock"]
+        assert f["\xa9gen"] == ["Shoegaze"]
+        d = mutagen_rs._fast_read(path)
+        assert d["gnre"] == "Rock"
+        assert d["\xa9gen"] == "Shoegaze"
+
+    def test_genre_prefers_free_text(self, tmp_path):
+        path = self._both(tmp_path)
+        assert mutagen_rs.MP4(path).genre() == "Shoegaze"
+
+    def test_genre_falls_back_to_gnre(self, tmp_path):
+        path = self._copy(tmp_path, None)
+        f = mutagen_rs.MP4(path)
+        assert "\xa9gen" not in f
+        assert f.genre() == "Rock"
 
-    def test_file_has_info(self, audio_file):
-        """File() result should have info with sample_rate."""
-        f = mutagen_rs.File(audio_file)
-        assert f.info.sample_rate > 0
 
-    def test_file_keys_iterable(self, audio_file):
-        """File() result keys should be iterable."""
-        f = mutagen_rs.File(audio_file)
-        keys = list(f.keys())
-        assert isinstance(keys, list)
+class TestArtists:
+    """Test artists() and that MP4 \xa9ART and aART stay separate keys."""
 
+    def test_mp4_artist_and_album_artist(self, tmp_path):
+        dst = copy_fixture(tmp_path, "has-tags.m4a", "artists.m4a")
+        f = mutagen_rs.MP4(dst)
+        f["\xa9ART"] = ["Singer", "Guest"]
+        f["aART"] = ["Various Artists"]
+        f.save()
+        mutagen_rs.clear_cache()
+        f = mutagen_rs.MP4(dst)
+        assert f["\xa9ART"] == ["Singer", "Guest"]
+        assert f["aART"] == ["Various Artists"]
+        assert f.artists() == ["Singer", "Guest"]
+        assert f.credits()["album_artist"] == "Various Artists"
+        batch = mutagen_rs.batch_open([dst])[dst]["tags"]
+        assert batch["\xa9ART"] == ["Singer", "Guest"]
+        assert batch["aART"] == "Various Artists"
+
+    def test_mp3_and_flac(self, tmp_path):
+        mp3 = copy_fixture(tmp_path, "xing.mp3", "artists.mp3")
+        f = mutagen_rs.MP3(mp3)
+        f["TPE1"] = ["One", "Two"]
+        f.save()
+        flac = copy_fixture(tmp_path, "silence-44-s.flac", "artists.flac")
+        f = mutagen_rs.FLAC(flac)
+        f["artist"] = ["Three"]
+        f.save()
+        mutagen_rs.clear_cache()
+        assert mutagen_rs.MP3(mp3).artists() == ["One", "Two"]
+        assert mutagen_rs.FLAC(flac).artists() == ["Three"]
 
-# ──────────────────────────────────────────────────────────────
-# _fast_read API tests
-# ──────────────────────────────────────────────────────────────
+    def test_none(self):
+        assert mutagen_rs.MP4(get_test_file("no-tags.m4a")).artists() == []
 
-class TestFastRead:
-    """Test the _fast_read low-level API."""
 
-    @pytest.fixture(params=[
-        "silence-44-s.mp3",
-        "silence-44-s.flac",
-        "empty.ogg",
-        "has-tags.m4a",
+class TestBPM:
+    """Test bpm() prefers a fractional MP4 freeform BPM over the integer tmpo."""
+
+    def test_mp4_freeform_wins(self, tmp_path):
+        dst = copy_fixture(tmp_path, "has-tags.m4a", "bpm.m4a")
+        f = mutagen_rs.MP4(dst)
+        f["tmpo"] = [128]
+        f["----:com.apple.iTunes:BPM"] = [mutagen_rs.MP4FreeForm(b"128.5")]
+        f.save()
+        mutagen_rs.clear_cache()
+        assert mutagen_rs.MP4(dst).bpm() == 128.5
+        assert mutagen_rs._RustMP4(dst).bpm() == 128.5
+
+    def test_mp4_tmpo_only(self, tmp_path):
+        dst = copy_fixture(tmp_path, "has-tags.m4a", "bpm.m4a")
+        f = mutagen_rs.MP4(dst)
+        f["tmpo"] = [120]
+        f["----:com.apple.iTunes:BPM"] = [mutagen_rs.MP4FreeForm(b"fast")]
+        f.save()
+        mutagen_rs.clear_cache()
+        assert mutagen_rs.MP4(dst).bpm() == 120.0
+        assert mutagen_rs._RustMP4(dst).bpm() == 120.0
+
+    def test_mp3_and_flac(self, tmp_path):
+        mp3 = copy_fixture(tmp_path, "xing.mp3", "bpm.mp3")
+        f = mutagen_rs.MP3(mp3)
+        f["TBPM"] = ["97"]
+        f.save()
+        flac = copy_fixture(tmp_path, "silence-44-s.flac", "bpm.flac")
+        f = mutagen_rs.FLAC(flac)
+        f["bpm"] = ["140.25"]
+        f.save()
+        mutagen_rs.clear_cache()
+        assert mutagen_rs.MP3(mp3).bpm() == 97.0
+        assert mutagen_rs.FLAC(flac).bpm() == 140.25
+
+    def test_none(self):
+        assert mutagen_rs.MP4(get_test_file("no-tags.m4a")).bpm() is None
+
+
+class TestTagVersion:
+    """Test tag_version on file objects and in _fast_read output."""
+
+    def _versions(self, name):
+        path = get_test_file(name)
+        yield mutagen_rs.File(path).tag_version
+        yield mutagen_rs._fast_read(path)["tag_version"]
+        yield mutagen_rs._fast_read(path, nested=True)["tag_version"]
+
+    @pytest.mark.parametrize("name,expected", [
+        ("id3v1v2-combined.mp3", (2, 4)),
+        ("silence-44-s.mp3", (2, 3)),
+        ("silence-44-s-v1.mp3", (1, 1)),
+        ("xing.mp3", None),
+        ("silence-44-s.flac", "VorbisComment"),
+        ("empty.ogg", "VorbisComment"),
+        ("has-tags.m4a", "mdir"),
+        ("no-tags.m4a", None),
     ])
-    def audio_file(self, request):
-        path = get_test_file(request.param)
-        if not os.path.exists(path):
-            pytest.skip(f"Test file not found: {path}")
-        return path
+    def test_formats(self, name, expected):
+        assert list(self._versions(name)) == [expected] * 3
 
-    def test_returns_dict(self, audio_file):
-        d = mutagen_rs._fast_read(audio_file)
-        assert isinstance(d, dict)
+    def test_native_objects(self):
+        assert mutagen_rs._RustMP3(get_test_file("id3v1v2-combined.mp3")).tag_version == (2, 4)
+        assert mutagen_rs._RustFLAC(get_test_file("silence-44-s.flac")).tag_version == "VorbisComment"
+        assert mutagen_rs._RustFLAC(get_test_file("no-tags.flac")).tag_version is None
+        assert mutagen_rs._RustMP4(get_test_file("has-tags.m4a")).tag_version == "mdir"
 
-    def test_has_length(self, audio_file):
-        d = mutagen_rs._fast_read(audio_file)
-        assert "length" in d
-        assert isinstance(d["length"], (int, float))
-        assert d["length"] > 0
 
-    def test_has_sample_rate(self, audio_file):
-        d = mutagen_rs._fast_read(audio_file)
-        assert "sample_rate" in d
-        assert d["sample_rate"] > 0
+class TestGenres:
+    """Test genres() returns every genre as a list for all formats."""
 
-    def test_matches_object_api(self, audio_file):
-        """_fast_read length should match File().info.length."""
-        d = mutagen_rs._fast_read(audio_file)
-        f = mutagen_rs.File(audio_file)
-        assert abs(d["length"] - f.info.length) < 0.01
+    def test_mp3_multi_value(self, tmp_path):
+        dst = copy_fixture(tmp_path, "xing.mp3", "genres.mp3")
+        f = mutagen_rs.MP3(dst)
+        f["TCON"] = ["Rock", "Pop"]
+        f.save()
+        mutagen_rs.clear_cache()
+        f = mutagen_rs.MP3(dst)
+        assert f.genres() == ["Rock", "Pop"]
+        assert f.genre() == "Rock"
+        assert mutagen_rs._fast_read(dst)["TCON"] == ["Rock", "Pop"]
 
+    def test_mp3_numeric_references(self, tmp_path):
+        dst = copy_fixture(tmp_path, "xing.mp3", "genres.mp3")
+        f = mutagen_rs.MP3(dst)
+        f["TCON"] = ["(17)(RX)Shoegaze"]
+        f.save()
+        mutagen_rs.clear_cache()
+        assert mutagen_rs.MP3(dst).genres() == ["Rock", "Remix", "Shoegaze"]
 
-# ──────────────────────────────────────────────────────────────
-# batch_open API tests
-# ──────────────────────────────────────────────────────────────
+    def test_flac_two_comments(self, tmp_path):
+        dst = copy_fixture(tmp_path, "silence-44-s.flac", "genres.flac")
+        f = mutagen_rs.FLAC(dst)
+        f["genre"] = ["Jazz", "Blues"]
+        f.save()
+        mutagen_rs.clear_cache()
+        assert mutagen_rs.FLAC(dst).genres() == ["Jazz", "Blues"]
 
-class TestBatchOpen:
-    """Test the batch_open parallel processing API."""
+    def test_mp4_single(self, tmp_path):
+        dst = copy_fixture(tmp_path, "has-tags.m4a", "genres.m4a")
+        f = mutagen_rs.MP4(dst)
+        f["\xa9gen"] = ["Ambient"]
+        f.save()
+        mutagen_rs.clear_cache()
+        assert mutagen_rs.MP4(dst).genres() == ["Ambient"]
 
-    def test_batch_returns_dict(self):
-        paths = [
-            get_test_file("silence-44-s.mp3"),
-            get_test_file("silence-44-s.flac"),
-            get_test_file("empty.ogg"),
-            get_test_file("has-tags.m4a"),
-        ]
-        paths = [p for p in paths if os.path.exists(p)]
+    def test_none(self):
+        assert mutagen_rs.MP3(get_test_file("no-tags.mp3")).genres() == []
+
+
+class TestCredits:
+    """Test credits() maps conductor/album artist/composer across formats."""
+
+    def _copy(self, tmp_path, name, ext):
+        dst = copy_fixture(tmp_path, name, "credits." + ext)
+        mutagen_rs.clear_cache()
+        return dst
+
+    def test_mp3(self, tmp_path):
+        path = self._copy(tmp_path, "silence-44-s.mp3", "mp3")
+        f = mutagen_rs.MP3(path)
+        f["TPE3"] = "The Conductor"
+        f["TPE2"] = "Various Artists"
+        f["TCOM"] = "A Composer"
+        f.save()
+        mutagen_rs.clear_cache()
+        credits = mutagen_rs.MP3(path).credits()
+        assert credits == {
+            "conductor": "The Conductor",
+            "album_artist": "Various Artists",
+            "composer": "A Composer",
+            "lyricist": None,
+            "performer": None,
+        }
+
+    def test_mp4(self, tmp_path):
+        path = self._copy(tmp_path, "has-tags.m4a", "m4a")
+        f = mutagen_rs.MP4(path)
+        f.delete()
+        mutagen_rs.clear_cache()
+        f = mutagen_rs.MP4(path)
+        f["----:com.apple.iTunes:CONDUCTOR"] = [mutagen_rs.MP4FreeForm(b"The Conductor")]
+        f["aART"] = ["Various Artists"]
+        f["\xa9wrt"] = ["A Composer"]
+        f.save()
+        mutagen_rs.clear_cache()
+        credits = mutagen_rs.MP4(path).credits()
+        assert credits["conductor"] == "The Conductor"
+        assert credits["album_artist"] == "Various Artists"
+        assert credits["composer"] == "A Composer"
+        assert credits["lyricist"] is None
+        assert credits["performer"] is None
+
+    def test_vorbis(self):
+        credits = mutagen_rs.FLAC(get_test_file("silence-44-s.flac")).credits()
+        assert set(credits) == {"conductor", "album_artist", "composer", "lyricist", "performer"}
+
+
+class TestITunNORM:
+    """Test iTunes special comments stay distinct and iTunNORM is parsed."""
+
+    def test_comments_distinct(self):
+        f = mutagen_rs.MP3(get_test_file("id3v22-test.mp3"))
+        assert str(f["COMM::eng"]) == "Waterbug Records, www.anaismitchell.com"
+        assert str(f["COMM:iTunes_CDDB_TrackNumber:eng"]) == "3"
+        assert str(f["COMM:iTunNORM:eng"]).split()[0] == "0000044E"
+
+    def test_mp3_volume_adjustment(self):
+        norm = mutagen_rs.MP3(get_test_file("id3v22-test.mp3")).itunes_normalization()
+        assert norm["volume_left"] == 0x44E
+        assert norm["volume_right"] == 0x61
+        assert norm["volume_left_2500"] == 0x9B67
+        assert norm["volume_right_2500"] == 0x44C3
+        assert norm["peak_left"] == 0x7FCC
+        assert norm["peak_right"] == 0x7E5C
+        assert len(norm["values"]) == 10
+
+    def test_mp4(self):
+        norm = mutagen_rs.MP4(get_test_file("has-tags.m4a")).itunes_normalization()
+        assert norm is not None and len(norm["values"]) == 10
+
+    def test_absent(self):
+        assert mutagen_rs.MP3(get_test_file("silence-44-s.mp3")).itunes_normalization() is None
+        assert mutagen_rs.FLAC(get_test_file("silence-44-s.flac")).itunes_normalization() is None
+
+
+class TestReplayGain:
+    """Test replay_gain() parses ReplayGain freeforms and comments to floats."""
+
+    def test_mp4_freeform(self, tmp_path):
+        dst = copy_fixture(tmp_path, "has-tags.m4a", "rg.m4a")
+        mutagen_rs.clear_cache()
+        f = mutagen_rs.MP4(dst)
+        f["----:com.apple.iTunes:replaygain_track_gain"] = [mutagen_rs.MP4FreeForm(b"-7.50 dB")]
+        f["----:com.apple.iTunes:replaygain_track_peak"] = [mutagen_rs.MP4FreeForm(b"0.988831")]
+        f.save()
+        mutagen_rs.clear_cache()
+        rg = mutagen_rs.MP4(dst).replay_gain()
+        assert rg["track_gain"] == -7.5
+        assert rg["track_peak"] == 0.988831
+        assert rg["album_gain"] is None
+        assert rg["album_peak"] is None
+
+    def test_soundcheck_fallback(self):
+        # iTunNORM volume 0x44E (1102 on the 1000 basis) is about -0.42 dB
+        rg = mutagen_rs.MP3(get_test_file("id3v22-test.mp3")).replay_gain()
+        assert rg["track_gain"] == -0.42
+        assert rg["track_peak"] == 0x7FCC / 32768.0
+        assert rg["album_gain"] is None
+        # An all-zero SoundCheck value carries no gain
+        assert mutagen_rs.MP4(get_test_file("has-tags.m4a")).replay_gain()["track_gain"] is None
+
+    def test_flac_comments(self, tmp_path):
+        dst = copy_fixture(tmp_path, "silence-44-s.flac", "rg.flac")
+        f = mutagen_rs.FLAC(dst)
+        f["REPLAYGAIN_ALBUM_GAIN"] = ["+1.25 dB"]
+        f["replaygain_album_peak"] = ["1.000000"]
+        f.save()
+        mutagen_rs.clear_cache()
+        rg = mutagen_rs.FLAC(dst).replay_gain()
+        assert rg == {"track_gain": None, "album_gain": 1.25,
+                      "track_peak": None, "album_peak": 1.0}
+
+    def test_opus_r128(self, tmp_path):
+        dst = copy_fixture(tmp_path, "example.opus", "rg.opus")
+        f = mutagen_rs.OggOpus(dst)
+        # Q7.8: -2560 is -10 dB and 256 is +1 dB at -23 LUFS; +5 dB to -18 LUFS
+        f["R128_TRACK_GAIN"] = ["-2560"]
+        f["R128_ALBUM_GAIN"] = ["256"]
+        f.save()
+        mutagen_rs.clear_all_caches()
+        rg = mutagen_rs.OggOpus(dst).replay_gain()
+        assert rg == {"track_gain": -5.0, "album_gain": 6.0,
+                      "track_peak": None, "album_peak": None}
+        # The OpusHead output gain (-512, i.e. -2 dB) adds to both
+        with open(dst, "rb") as fh:
+            data = bytearray(fh.read())
+        head = data.find(b"OpusHead")
+        data[head + 16:head + 18] = struct.pack("<h", -512)
+        with open(dst, "wb") as fh:
+            fh.write(data)
+        mutagen_rs.clear_all_caches()
+        rg = mutagen_rs.OggOpus(dst).replay_gain()
+        assert (rg["track_gain"], rg["album_gain"]) == (-7.0, 4.0)
+
+
+class TestMP4CoverWrite:
+    """Test constructing MP4Cover values and writing them to covr."""
+
+    PNG = b"\x89PNG\r\n\x1a\n" + b"\x00" * 24
+
+    def test_format_detected(self):
+        assert mutagen_rs.MP4Cover(self.PNG).imageformat == mutagen_rs.MP4Cover.FORMAT_PNG
+        assert mutagen_rs.MP4Cover(b"\xff\xd8\xff\xe0").imageformat == mutagen_rs.MP4Cover.FORMAT_JPEG
+        explicit = mutagen_rs.MP4Cover(self.PNG, imageformat=mutagen_rs.MP4Cover.FORMAT_JPEG)
+        assert explicit.imageformat == mutagen_rs.MP4Cover.FORMAT_JPEG
+
+    def test_set_save_reopen(self, tmp_path):
+        dst = copy_fixture(tmp_path, "no-tags.m4a", "cover.m4a")
+        mutagen_rs.clear_cache()
+        f = mutagen_rs.MP4(dst)
+        f["covr"] = [mutagen_rs.MP4Cover(self.PNG)]
+        f.save()
+        mutagen_rs.clear_cache()
+        covers = mutagen_rs.MP4(dst)["covr"]
+        assert covers == [self.PNG]
+        assert isinstance(covers[0], mutagen_rs.MP4Cover)
+        assert covers[0].imageformat == mutagen_rs.MP4Cover.FORMAT_PNG
+        native = mutagen_rs._RustMP4(dst)["covr"]
+        assert native == [{"data": self.PNG, "format": 14}]
+
+    def test_explicit_format_written(self, tmp_path):
+        dst = copy_fixture(tmp_path, "no-tags.m4a", "cover.m4a")
+        mutagen_rs.clear_cache()
+        f = mutagen_rs.MP4(dst)
+        f["covr"] = [mutagen_rs.MP4Cover(self.PNG, imageformat=mutagen_rs.MP4Cover.FORMAT_JPEG)]
+        f.save()
+        mutagen_rs.clear_cache()
+        assert mutagen_rs._RustMP4(dst)["covr"][0]["format"] == 13
+
+
+class TestMP4FreeForm:
+    """Test freeform (----) atoms keep the data type of each value."""
+
+    TEXT = "----:com.apple.iTunes:MOOD"
+    BIN = "----:com.apple.iTunes:BLOB"
+
+    def _copy(self, tmp_path):
+        dst = copy_fixture(tmp_path, "has-tags.m4a", "freeform.m4a")
+        f = mutagen_rs.MP4(dst)
+        f[self.TEXT] = [mutagen_rs.MP4FreeForm(b"calm"), mutagen_rs.MP4FreeForm(b"dark")]
+        f[self.BIN] = [mutagen_rs.MP4FreeForm(
+            b"\x00\x01\xff", dataformat=mutagen_rs.AtomDataType.IMPLICIT)]
+        f.save()
+        mutagen_rs.clear_cache()
+        return dst
+
+    def test_dataformat_read(self, tmp_path):
+        f = mutagen_rs.MP4(self._copy(tmp_path))
+        text, binary = f[self.TEXT], f[self.BIN]
+        assert all(isinstance(v, mutagen_rs.MP4FreeForm) for v in text + binary)
+        assert [(bytes(v), v.dataformat) for v in text] == [(b"calm", 1), (b"dark", 1)]
+        assert [(bytes(v), v.dataformat) for v in binary] == [(b"\x00\x01\xff", 0)]
+        assert str(text[0]) == "calm"
+
+    def test_save_round_trips_dataformat(self, tmp_path):
+        path = self._copy(tmp_path)
+        f = mutagen_rs.MP4(path)
+        f["\xa9nam"] = ["retitled"]
+        f.save()
+        mutagen_rs.clear_cache()
+        d = mutagen_rs._fast_read(path)
+        assert d[self.TEXT] == [(b"calm", 1), (b"dark", 1)]
+        assert d[self.BIN] == [(b"\x00\x01\xff", 0)]
+        f = mutagen_rs.MP4(path)
+        assert [v.dataformat for v in f[self.TEXT] + f[self.BIN]] == [1, 1, 0]
+
+    def test_batch_text_and_binary(self, tmp_path):
+        path = self._copy(tmp_path)
+        tags = mutagen_rs.batch_open([path])[path]["tags"]
+        assert tags[self.TEXT] == ["calm", "dark"]
+        assert self.BIN in tags and tags[self.BIN] == [b"\x00\x01\xff"]
+        doc = json.loads(mutagen_rs.MP4(path).to_json())
+        assert doc["tags"][self.TEXT] == ["calm", "dark"]
+        assert self.BIN not in doc["tags"]
+
+
+class TestMP4Truncated:
+    """Test MP4 files whose moov atom declares more bytes than the file holds."""
+
+    def _data(self):
+        path = get_test_file("has-tags.m4a")
+        if not os.path.exists(path):
+            pytest.skip("Test file not found")
+        with open(path, "rb") as f:
+            return f.read()
+
+    def test_every_cut_inside_moov(self, tmp_path):
+        data = self._data()
+        full = mutagen_rs.MP4(get_test_file("has-tags.m4a"))
+        full_keys = list(full.keys())
+        moov = data.find(b"moov") - 4
+        dst = str(tmp_path / "cut.m4a")
+        for cut in range(moov + 8, len(data), 13):
+            with open(dst, "wb") as f:
+                f.write(data[:cut])
+            mutagen_rs.clear_all_caches()
+            try:
+                f = mutagen_rs.MP4(dst)
+            except mutagen_rs.MutagenError:
+                continue
+            keys = list(f.keys())
+            assert keys == full_keys[:len(keys)], cut
+            for k in keys:
+                assert f[k] == full[k], (cut, k)
+            d = mutagen_rs._fast_read(dst)
+            assert [k for k in d["_keys"]] == keys, cut
+            assert mutagen_rs.batch_open([dst])[dst]["tags"].keys() == set(keys), cut
+
+    def test_partial_info_and_complete_items_kept(self, tmp_path):
+        data = self._data()
+        # Cut in the middle of the covr item: info and earlier items survive
+        covr = data.find(b"covr") - 4
+        dst = str(tmp_path / "cut.m4a")
+        with open(dst, "wb") as f:
+            f.write(data[:covr + 100])
+        f = mutagen_rs.MP4(dst)
+        assert f.info.length > 0
+        assert f.info.sample_rate == 44100
+        assert "\xa9ART" in f
+        assert "covr" not in f
+        assert not mutagen_rs.tag_summary(dst)[2]
+
+
+class TestMP4Channels:
+    """Test that the esds AudioSpecificConfig overrides an implausible stsd channel count."""
+
+    def _with_stsd_channels(self, tmp_path, channels):
+        src = get_test_file("has-tags.m4a")
+        if not os.path.exists(src):
+            pytest.skip("Test file not found")
+        with open(src, "rb") as f:
+            data = bytearray(f.read())
+        # channelcount sits 20 bytes into the mp4a sample entry body
+        pos = data.find(b"mp4a") + 20
+        data[pos:pos + 2] = channels.to_bytes(2, "big")
+        path = str(tmp_path / "channels.m4a")
+        with open(path, "wb") as f:
+            f.write(data)
+        mutagen_rs.clear_all_caches()
+        return path
+
+    def _check(self, path, channels, source):
+        info = mutagen_rs.mutagen_rs.MP4(path).info
+        assert (info.channels, info.channels_source) == (channels, source)
+        f = mutagen_rs.MP4(path)
+        assert (f.info.channels, f.info.channels_source) == (channels, source)
+        for d in (mutagen_rs._fast_read(path), mutagen_rs._fast_info(path),
+                  mutagen_rs._fast_batch_read([path])[path]):
+            assert (d["channels"], d["channels_source"]) == (channels, source)
+
+    def test_consistent_stsd_kept(self):
+        self._check(get_test_file("has-tags.m4a"), 2, "stsd")
+
+    @pytest.mark.parametrize("stsd", [0, 1, 6])
+    def test_asc_preferred(self, tmp_path, stsd):
+        self._check(self._with_stsd_channels(tmp_path, stsd), 2, "esds")
+
+
+class TestAudibleAAX:
+    """Test that Audible DRM files read their plaintext tags and refuse to save."""
+
+    def _aax(self, tmp_path, old, new):
+        src = get_test_file("has-tags.m4a")
+        if not os.path.exists(src):
+            pytest.skip("Test file not found")
+        with open(src, "rb") as f:
+            data = bytearray(f.read())
+        pos = data.find(old, data.find(b"stsd"))
+        data[pos:pos + 4] = new
+        path = str(tmp_path / "book.aax")
+        with open(path, "wb") as f:
+            f.write(data)
+        mutagen_rs.clear_all_caches()
+        return path
+
+    @pytest.mark.parametrize("old,new", [(b"mp4a", b"aavd"), (b"esds", b"adrm")])
+    def test_plaintext_tags_read(self, tmp_path, old, new):
+        path = self._aax(tmp_path, old, new)
+        for f in (mutagen_rs.File(path), mutagen_rs.MP4(path)):
+            assert f["\xa9ART"] == ["Test Artist"]
+            assert f.info.codec_description == "Audible AAX"
+        d = mutagen_rs._fast_read(path)
+        assert d["codec_description"] == "Audible AAX"
+        assert d["\xa9ART"] == "Test Artist"
+
+    def test_plain_mp4_not_flagged(self):
+        assert mutagen_rs.MP4(get_test_file("has-tags.m4a")).info.codec_description == ""
+
+    @pytest.mark.parametrize("old,new", [(b"mp4a", b"aavd"), (b"esds", b"adrm")])
+    def test_save_refused(self, tmp_path, old, new):
+        path = self._aax(tmp_path, old, new)
+        with open(path, "rb") as fh:
+            before = fh.read()
+        f = mutagen_rs.MP4(path)
+        f["\xa9nam"] = ["Title"]
+        with pytest.raises(mutagen_rs.MP4Error, match="Audible"):
+            f.save()
+        with open(path, "rb") as fh:
+            assert fh.read() == before
+
+
+class TestMP4MetaHandler:
+    """Test that saving keeps the meta atom's hdlr and only adds one when missing."""
+
+    def _patched(self, tmp_path, patch):
+        src = get_test_file("has-tags.m4a")
+        if not os.path.exists(src):
+            pytest.skip("Test file not found")
+        with open(src, "rb") as f:
+            data = bytearray(f.read())
+        hdlr = data.find(b"hdlr", data.find(b"meta")) - 4
+        patch(data, hdlr)
+        path = str(tmp_path / "meta.m4a")
+        with open(path, "wb") as f:
+            f.write(data)
+        mutagen_rs.clear_all_caches()
+        return path
+
+    @staticmethod
+    def _meta_hdlr(data):
+        hdlr = data.find(b"hdlr", data.find(b"meta")) - 4
+        return bytes(data[hdlr:hdlr + int.from_bytes(data[hdlr:hdlr + 4], "big")])
+
+    def test_hdlr_kept_verbatim(self, tmp_path):
+        def patch(data, hdlr):
+            data[hdlr + 20:hdlr + 24] = b"XYZW"
+        path = self._patched(tmp_path, patch)
+        with open(path, "rb") as f:
+            before = self._meta_hdlr(f.read())
+        f = mutagen_rs.MP4(path)
+        f["\xa9nam"] = ["New Title"]
+        f.save()
+        with open(path, "rb") as fh:
+            assert self._meta_hdlr(fh.read()) == before
+        mutagen_rs.clear_all_caches()
+        assert mutagen_rs.MP4(path)["\xa9nam"] == ["New Title"]
+
+    def test_missing_hdlr_synthesized(self, tmp_path):
+        def patch(data, hdlr):
+            data[hdlr + 4:hdlr + 8] = b"free"
+        path = self._patched(tmp_path, patch)
+        assert mutagen_rs.MP4(path).tag_version is None
+        f = mutagen_rs.MP4(path)
+        f["\xa9nam"] = ["New Title"]
+        f.save()
+        mutagen_rs.clear_all_caches()
+        f = mutagen_rs.MP4(path)
+        assert f.tag_version == "mdir"
+        assert f["\xa9nam"] == ["New Title"]
+
+
+class TestMP4MoovPlacement:
+    """Test moov_offset, moov_size and is_faststart against a plain atom scan."""
+
+    @staticmethod
+    def _scan(path):
+        with open(path, "rb") as f:
+            data = f.read()
+        atoms, pos = [], 0
+        while pos + 8 <= len(data):
+            size = int.from_bytes(data[pos:pos + 4], "big")
+            if size == 1:
+                size = int.from_bytes(data[pos + 8:pos + 16], "big")
+            if size < 8:
+                break
+            atoms.append((data[pos + 4:pos + 8], pos, size))
+            pos += size
+        return atoms
+
+    @pytest.mark.parametrize("name,faststart", [
+        ("alac.m4a", True), ("nero-chapters.m4b", True), ("has-tags.m4a", False), ("ep7.m4b", False),
+    ])
+    def test_matches_scan(self, name, faststart):
+        path = get_test_file(name)
+        if not os.path.exists(path):
+            pytest.skip("Test file not found")
+        atoms = self._scan(path)
+        moov = next(a for a in atoms if a[0] == b"moov")
+        mdat = next(a for a in atoms if a[0] == b"mdat")
+        assert (moov[1] < mdat[1]) == faststart
+        for f in (mutagen_rs.MP4(path), mutagen_rs.mutagen_rs.MP4(path)):
+            assert (f.moov_offset, f.moov_size) == (moov[1], moov[2])
+            assert f.is_faststart is faststart
+
+
+class TestMP4Locales:
+    """Test that data atoms with different locales are kept apart and written back."""
+
+    FR = 0x0000040C
+    EN = 0x00000409
+
+    @staticmethod
+    def _data_atom(locale, text):
+        payload = text.encode("utf-8")
+        return struct.pack(">I4sII", 16 + len(payload), b"data", 1, locale) + payload
+
+    def _two_locale_file(self, tmp_path):
+        path = copy_fixture(tmp_path, "has-tags.m4a", "locales.m4a")
+        body = self._data_atom(self.FR, "Bonjour") + self._data_atom(self.EN, "Hello")
+        atom = struct.pack(">I", 8 + len(body)) + b"\xa9cmt" + body
+        f = mutagen_rs.MP4(path)
+        f.set_raw("\xa9cmt", atom)
+        f.save()
+        mutagen_rs.clear_all_caches()
+        return path, atom
+
+    def test_values_not_merged(self, tmp_path):
+        path, _ = self._two_locale_file(tmp_path)
+        f = mutagen_rs.MP4(path)
+        assert f["\xa9cmt"] == ["Bonjour", "Hello"]
+        assert f.get_locales("\xa9cmt") == [self.FR, self.EN]
+        assert f.get_locales("\xa9ART") == [0]
+
+    def test_locales_survive_save(self, tmp_path):
+        path, atom = self._two_locale_file(tmp_path)
+        f = mutagen_rs.MP4(path)
+        f["\xa9nam"] = "Renamed"
+        f.save()
+
+        mutagen_rs.clear_all_caches()
+        f = mutagen_rs.MP4(path)
+        assert f["\xa9nam"] == ["Renamed"]
+        assert f.get_locales("\xa9cmt") == [self.FR, self.EN]
+        assert f.get_raw_atom("\xa9cmt") == atom
+
+    def test_new_value_uses_default_locale(self, tmp_path):
+        path, _ = self._two_locale_file(tmp_path)
+        f = mutagen_rs.MP4(path)
+        f["\xa9cmt"] = ["Salut"]
+        f.save()
+
+        mutagen_rs.clear_all_caches()
+        f = mutagen_rs.MP4(path)
+        assert f["\xa9cmt"] == ["Salut"]
+        assert f.get_locales("\xa9cmt") == [0]
+
+    def test_missing_key(self, tmp_path):
+        path, _ = self._two_locale_file(tmp_path)
+        with pytest.raises(KeyError):
+            mutagen_rs.MP4(path).get_locales("\xa9xyz")
+
+
+class TestMP4StoreIDs:
+    """Test iTunes store ID integers (plID, cnID, ...) at their full width."""
+
+    PLAYLIST_ID = 0x0123456789ABCDEF
+
+    @staticmethod
+    def _int_atom(name, payload):
+        data = struct.pack(">I4sII", 16 + len(payload), b"data", 21, 0) + payload
+        return struct.pack(">I", 8 + len(data)) + name + data
+
+    def test_64_bit_round_trip(self, tmp_path):
+        path = copy_fixture(tmp_path, "has-tags.m4a", "store.m4a")
+        atom = self._int_atom(b"plID", struct.pack(">q", self.PLAYLIST_ID))
+        f = mutagen_rs.MP4(path)
+        f.set_raw("plID", atom)
+        f.save()
+        mutagen_rs.clear_all_caches()
+        assert mutagen_rs.MP4(path)["plID"] == [self.PLAYLIST_ID]
+        assert mutagen_rs._fast_read(path)["plID"] == self.PLAYLIST_ID
+
+        # Written again from the value, not the raw atom
+        f = mutagen_rs.MP4(path)
+        f["plID"] = [self.PLAYLIST_ID]
+        f.save()
+        mutagen_rs.clear_all_caches()
+        f = mutagen_rs.MP4(path)
+        assert f["plID"] == [self.PLAYLIST_ID]
+        assert f.get_raw_atom("plID") == atom
+
+    @pytest.mark.parametrize("key,value,payload", [
+        ("plID", 42, struct.pack(">q", 42)),
+        ("cnID", 42, struct.pack(">i", 42)),
+        ("cnID", -(2 ** 40), struct.pack(">q", -(2 ** 40))),
+        ("tmpo", 42, struct.pack(">b", 42)),
+    ])
+    def test_render_width(self, tmp_path, key, value, payload):
+        path = copy_fixture(tmp_path, "has-tags.m4a", "store.m4a")
+        f = mutagen_rs.MP4(path)
+        f[key] = [value]
+        f.save()
+        mutagen_rs.clear_all_caches()
+        f = mutagen_rs.MP4(path)
+        assert f[key] == [value]
+        assert f.get_raw_atom(key) == self._int_atom(key.encode("latin-1"), payload)
+
+
+class TestMP4Chapters:
+    """Test reading and writing Nero (chpl) chapters."""
+
+    CHAPTERS = [
+        (0.0, "Intro"),
+        (12.5, "Chapter 1"),
+        (95.25, "Chapitre deux \u2014 \u00e9t\u00e9"),
+        (600.0, ""),
+        (3725.1234567, "Epilogue"),
+    ]
+
+    def test_read_existing(self):
+        path = get_test_file("nero-chapters.m4b")
+        if not os.path.exists(path):
+            pytest.skip("Test file not found")
+        chapters = mutagen_rs.MP4(path).chapters
+        assert len(chapters) == 112
+        assert chapters[0] == (0.0, "001")
+        assert chapters[1][1] == "002"
+        assert [c[0] for c in chapters] == sorted(c[0] for c in chapters)
+
+    @pytest.mark.parametrize("name", ["has-tags.m4a", "no-tags.m4a", "nero-chapters.m4b"])
+    def test_write_and_read_back(self, tmp_path, name):
+        path = copy_fixture(tmp_path, name)
+        before = mutagen_rs.MP4(path)
+        keys = before.keys()
+        length = before.info.length
+        f = mutagen_rs.MP4(path)
+        f.set_chapters(self.CHAPTERS)
+        f.save()
+
+        mutagen_rs.clear_all_caches()
+        f = mutagen_rs.MP4(path)
+        assert [t for _, t in f.chapters] == [t for _, t in self.CHAPTERS]
+        for (start, _), (expected, _) in zip(f.chapters, self.CHAPTERS):
+            assert abs(start - expected) < 1e-7
+        assert f.keys() == keys
+        assert f.info.length == length
+        assert mutagen_rs._fast_read(path)["length"] == length
+
+    def test_chunk_offsets_fixed_when_moov_grows(self, tmp_path):
+        # nero-chapters.m4b has moov before mdat, so growing moov shifts the audio
+        path = copy_fixture(tmp_path, "nero-chapters.m4b")
+
+        def chunk_data(p):
+            with open(p, "rb") as fh:
+                data = fh.read()
+            pos = data.find(b"stco")
+            count = struct.unpack(">I", data[pos + 8:pos + 12])[0]
+            offsets = struct.unpack(">%dI" % count, data[pos + 12:pos + 12 + 4 * count])
+            return [data[o:o + 8] for o in offsets[:4]]
+
+        expected = chunk_data(path)
+        f = mutagen_rs.MP4(path)
+        f.set_chapters(self.CHAPTERS * 40)
+        f.save()
+        assert chunk_data(path) == expected
+
+    def test_empty_list_removes_chapters(self, tmp_path):
+        path = copy_fixture(tmp_path, "nero-chapters.m4b")
+        f = mutagen_rs.MP4(path)
+        f.set_chapters([])
+        f.save()
+        mutagen_rs.clear_all_caches()
+        assert mutagen_rs.MP4(path).chapters == []
+        with open(path, "rb") as fh:
+            assert b"chpl" not in fh.read()
+
+    def test_other_edits_keep_chapters(self, tmp_path):
+        path = copy_fixture(tmp_path, "nero-chapters.m4b")
+        expected = mutagen_rs.MP4(path).chapters
+        f = mutagen_rs.MP4(path)
+        f["\xa9nam"] = ["Renamed"]
+        f.save()
+        mutagen_rs.clear_all_caches()
+        assert mutagen_rs.MP4(path).chapters == expected
+
+    def test_long_title_truncated_to_255_bytes(self, tmp_path):
+        path = copy_fixture(tmp_path, "has-tags.m4a")
+        f = mutagen_rs.MP4(path)
+        f.set_chapters([(1.0, "\u00e9" * 200)])
+        f.save()
+        mutagen_rs.clear_all_caches()
+        assert mutagen_rs.MP4(path).chapters == [(1.0, "\u00e9" * 127)]
+
+    def test_invalid_chapters_rejected(self, tmp_path):
+        path = copy_fixture(tmp_path, "has-tags.m4a")
+        f = mutagen_rs.MP4(path)
+        with pytest.raises(mutagen_rs.MP4Error):
+            f.set_chapters([(0.0, "x")] * 256)
+        with pytest.raises(mutagen_rs.MP4Error):
+            f.set_chapters([(-1.0, "x")])
+
+
+class TestMP4RangeRead:
+    """Large MP4s read atom headers, ftyp and moov, never mdat."""
+
+    MDAT_SIZE = 8 * 1024 * 1024
+
+    def _atoms(self):
+        path = get_test_file("has-tags.m4a")
+        if not os.path.exists(path):
+            pytest.skip("Test file not found")
+        with open(path, "rb") as f:
+            data = f.read()
+        atoms, pos = {}, 0
+        while pos < len(data):
+            size = struct.unpack(">I", data[pos:pos + 4])[0]
+            atoms[data[pos + 4:pos + 8]] = data[pos:pos + size]
+            pos += size
+        return atoms
+
+    def _write(self, tmp_path, name, order):
+        atoms = self._atoms()
+        atoms[b"mdat"] = struct.pack(">I", self.MDAT_SIZE) + b"mdat" + b"\x01" * (self.MDAT_SIZE - 8)
+        path = str(tmp_path / name)
+        with open(path, "wb") as f:
+            f.write(b"".join(atoms[a] for a in order))
+        return path
+
+    @staticmethod
+    def _bytes_read(fn):
+        if not os.path.exists("/proc/self/io"):
+            pytest.skip("/proc/self/io not available")
+
+        def rchar():
+            with open("/proc/self/io") as f:
+                return int(f.readline().split()[1])
+
+        mutagen_rs.clear_all_caches()
+        before = rchar()
+        result = fn()
+        return result, rchar() - before
+
+    @pytest.mark.parametrize("order", [
+        (b"ftyp", b"moov", b"mdat"),
+        (b"ftyp", b"mdat", b"moov"),
+    ])
+    def test_mdat_not_read(self, tmp_path, order):
+        path = self._write(tmp_path, "big.m4a", order)
+        limit = 256 * 1024 + 64 * 1024
+        d, n = self._bytes_read(lambda: mutagen_rs._fast_read(path))
+        assert n < limit
+        _, n = self._bytes_read(lambda: mutagen_rs._fast_info(path))
+        assert n < limit
+        _, n = self._bytes_read(lambda: mutagen_rs.batch_open([path]))
+        assert n < 64 * 1024
+        _, n = self._bytes_read(lambda: mutagen_rs.batch_open([path], io_depth=4))
+        assert n < 64 * 1024
+        assert d["_keys"]
+
+    @pytest.mark.parametrize("order", [
+        (b"ftyp", b"moov", b"mdat"),
+        (b"ftyp", b"mdat", b"moov"),
+    ])
+    def test_results_match_small_file(self, tmp_path, order):
+        path = self._write(tmp_path, "big.m4a", order)
+        small = get_test_file("has-tags.m4a")
+        expected = mutagen_rs.MP4(small)
+        f = mutagen_rs.MP4(path)
+        assert f.keys() == expected.keys()
+        for k in expected.keys():
+            assert f[k] == expected[k], k
+        assert f.info.length == expected.info.length
+        d = mutagen_rs._fast_read(path)
+        assert d["_keys"] == mutagen_rs._fast_read(small)["_keys"]
+        assert d["length"] == expected.info.length
+        assert mutagen_rs._fast_info(path)["sample_rate"] == expected.info.sample_rate
+        batch = mutagen_rs.batch_open([path])[path]
+        assert set(batch["tags"]) == set(expected.keys())
+        assert batch["length"] == expected.info.length
+
+    def _tail(self, tmp_path, order, n=256 * 1024, trailer=b""):
+        path = self._write(tmp_path, "big.m4a", order)
+        with open(path, "ab") as f:
+            f.write(trailer)
+        with open(path, "rb") as f:
+            data = f.read()
+        return path, data[-n:], len(data)
+
+    def test_parse_tail(self, tmp_path):
+        path, tail, size = self._tail(tmp_path, (b"ftyp", b"mdat", b"moov"))
+        d = mutagen_rs.mp4_parse_tail(tail, size)
+        assert d == mutagen_rs.batch_open([path])[path]
+        assert [d["tags"]["\xa9ART"]] == mutagen_rs.MP4(path)["\xa9ART"]
+
+    def test_parse_tail_trailing_free(self, tmp_path):
+        free = struct.pack(">I", 24) + b"free" + b"moov" * 4
+        path, tail, size = self._tail(tmp_path, (b"ftyp", b"mdat", b"moov"), trailer=free)
+        d = mutagen_rs.mp4_parse_tail(tail, size)
+        assert set(d["tags"]) == set(mutagen_rs.MP4(path).keys())
+
+    def test_parse_tail_moov_at_front(self, tmp_path):
+        _, tail, size = self._tail(tmp_path, (b"ftyp", b"moov", b"mdat"))
+        assert mutagen_rs.mp4_parse_tail(tail, size) is None
+
+    def test_parse_tail_moov_cut_off(self, tmp_path):
+        _, tail, size = self._tail(tmp_path, (b"ftyp", b"mdat", b"moov"), n=100)
+        assert mutagen_rs.mp4_parse_tail(tail, size) is None
+
+
+# ──────────────────────────────────────────────────────────────
+# File() auto-detection tests
+# ──────────────────────────────────────────────────────────────
+
+class TestFileAutoDetect:
+    """Test mutagen_rs.File() format auto-detection."""
+
+    @pytest.fixture(params=[
+        "silence-44-s.mp3",
+        "silence-44-s.flac",
+        "empty.ogg",
+        "has-tags.m4a",
+        "no-tags.mp3",
+        "no-tags.flac",
+        "no-tags.m4a",
+        "nero-chapters.m4b",
+        "variable-block.flac",
+        "xing.mp3",
+    ])
+    def audio_file(self, request):
+        path = get_test_file(request.param)
+        if not os.path.exists(path):
+            pytest.skip(f"Test file not found: {path}")
+        return path
+
+    def test_file_opens(self, audio_file):
+        """File() should auto-detect format and open successfully."""
+        f = mutagen_rs.File(audio_file)
+        assert f is not None
+
+    def test_file_has_info(self, audio_file):
+        """File() result should have info with sample_rate."""
+        f = mutagen_rs.File(audio_file)
+        assert f.info.sample_rate > 0
+
+    def test_file_keys_iterable(self, audio_file):
+        """File() result keys should be iterable."""
+        f = mutagen_rs.File(audio_file)
+        keys = list(f.keys())
+        assert isinstance(keys, list)
+
+    def test_mp4_with_leading_free_atom(self, tmp_path):
+        """An MP4 whose first atom is `free` (not `ftyp`) is still detected."""
+        src = get_test_file("has-tags.m4a")
+        if not os.path.exists(src):
+            pytest.skip("Test file not found")
+        with open(src, "rb") as f:
+            data = f.read()
+        dst = tmp_path / "leading-free.dat"
+        dst.write_bytes(b"\x00\x00\x00\x10free" + b"\x00" * 8 + data)
+        f = mutagen_rs.File(str(dst))
+        assert f is not None
+        assert type(f).__name__ == "MP4"
+        assert abs(f.info.length - mutagen_rs.MP4(src).info.length) < 0.01
+
+
+
+class TestCAF:
+    """Core Audio Format files: `desc` stream info and `info` chunk tags."""
+
+    def _caf(self, tmp_path, info, name="test.caf", rate=44100.0, channels=2, bits=16):
+        def chunk(kind, body):
+            return kind + struct.pack(">q", len(body)) + body
+        # lpcm, 44.1 kHz stereo 16-bit: 4 bytes per packet, 1 frame per packet
+        desc = struct.pack(">d4sIIIII", rate, b"lpcm", 0, 4, 1, channels, bits)
+        body = struct.pack(">I", len(info))
+        for k, v in info:
+            body += k.encode() + b"\x00" + v.encode() + b"\x00"
+        # One second of silence after the 4-byte edit count
+        data = struct.pack(">I", 0) + b"\x00" * (44100 * 4)
+        out = (b"caff" + struct.pack(">HH", 1, 0) + chunk(b"desc", desc)
+               + chunk(b"info", body) + chunk(b"data", data))
+        path = str(tmp_path / name)
+        with open(path, "wb") as f:
+            f.write(out)
+        return path
+
+    def test_fast_read(self, tmp_path):
+        path = self._caf(tmp_path, [("title", "Caf Title"), ("artist", "A"), ("artist", "B")])
+        d = mutagen_rs._fast_read(path)
+        assert d["sample_rate"] == 44100
+        assert d["channels"] == 2
+        assert d["bits_per_sample"] == 16
+        assert d["codec"] == "lpcm"
+        assert abs(d["length"] - 1.0) < 1e-6
+        assert d["bitrate"] == 44100 * 2 * 16
+        assert d["title"] == "Caf Title"
+        assert d["artist"] == ["A", "B"]
+
+    def test_info_keys_normalized(self, tmp_path):
+        path = self._caf(tmp_path, [("Track Number", "3"), ("year", "2001"), ("comments", "hi")])
+        d = mutagen_rs._fast_read(path)
+        assert d["_keys"] == ["tracknumber", "date", "comment"]
+
+    def test_file_detects_caf(self, tmp_path):
+        path = self._caf(tmp_path, [("title", "Caf Title")], name="noext.dat")
+        f = mutagen_rs.File(path)
+        assert type(f).__name__ == "CAF"
+        assert f.info.sample_rate == 44100
+        assert f["title"] == ["Caf Title"]
+
+    def test_batch_open(self, tmp_path):
+        path = self._caf(tmp_path, [("title", "Caf Title")])
+        result = mutagen_rs.batch_open([path])[path]
+        assert result["sample_rate"] == 44100
+        assert result["tags"]["title"] == ["Caf Title"]
+
+    def test_out_of_range_desc(self, tmp_path):
+        path = self._caf(tmp_path, [], rate=5e6, channels=200, bits=1000)
+        d = mutagen_rs._fast_info(path)
+        assert (d["sample_rate"], d["channels"], d["bits_per_sample"]) == (0, 0, 0)
+        assert (d["length"], d["bitrate"]) == (0.0, 0)
+        assert len(d["_warnings"]) == 3
+        assert "_warnings" not in mutagen_rs._fast_info(self._caf(tmp_path, [], name="ok.caf"))
+
+class TestMonkeysAudio:
+    """Monkey's Audio files: MAC header durations and trailing APEv2 tags."""
+
+    @staticmethod
+    def _apev2(items):
+        body = b""
+        for key, value in items:
+            body += struct.pack("<II", len(value), 0) + key.encode() + b"\x00" + value
+        footer = struct.pack("<8sIIII8s", b"APETAGEX", 2000, len(body) + 32,
+                             len(items), 0, b"\x00" * 8)
+        return body + footer
+
+    def _tagged(self, tmp_path, items, name="tagged.ape"):
+        with open(get_test_file("mac-399.ape"), "rb") as f:
+            data = f.read()
+        path = str(tmp_path / name)
+        with open(path, "wb") as f:
+            f.write(data + self._apev2(items))
+        return path
+
+    def test_durations(self):
+        # 3.99 (descriptor layout), 3.96 and 3.90 (old single header)
+        for name, length in [("mac-399.ape", 3.68), ("mac-396.ape", 3.68), ("mac-390-hdr.ape", 15.63)]:
+            d = mutagen_rs._fast_read(get_test_file(name))
+            assert round(d["length"], 2) == length, name
+            assert d["sample_rate"] == 44100
+            assert d["channels"] == 2
+            assert d["_keys"] == []
+
+    def test_version(self):
+        d = mutagen_rs._fast_info(get_test_file("mac-399.ape"))
+        assert d["version"] == 3.99
+        assert d["bits_per_sample"] == 16
+
+    def test_out_of_range_header(self, tmp_path):
+        with open(get_test_file("mac-399.ape"), "rb") as f:
+            data = bytearray(f.read())
+        header = struct.unpack_from("<I", data, 8)[0]
+        struct.pack_into("<HI", data, header + 18, 200, 0xFFFFFFFF)
+        path = str(tmp_path / "damaged.ape")
+        with open(path, "wb") as f:
+            f.write(data)
+        d = mutagen_rs._fast_info(path)
+        assert (d["sample_rate"], d["channels"]) == (0, 0)
+        assert (d["length"], d["bitrate"]) == (0.0, 0)
+        assert d["_warnings"] == [
+            "sample_rate 4294967295 out of range 1..=768000, using 0",
+            "channels 200 out of range 1..=64, using 0",
+        ]
+
+    def test_apev2_artist(self, tmp_path):
+        path = self._tagged(tmp_path, [("Artist", b"Mac Artist"), ("Genre", b"Rock\x00Pop")])
+        d = mutagen_rs._fast_read(path)
+        assert round(d["length"], 2) == 3.68
+        assert d["Artist"] == "Mac Artist"
+        assert d["Genre"] == ["Rock", "Pop"]
+        assert d["_keys"] == ["Artist", "Genre"]
+
+    def test_file_detects_ape(self, tmp_path):
+        path = self._tagged(tmp_path, [("Artist", b"Mac Artist")], name="noext.dat")
+        f = mutagen_rs.File(path)
+        assert type(f).__name__ == "MonkeysAudio"
+        assert f["Artist"] == ["Mac Artist"]
+        assert f.info.sample_rate == 44100
+
+    def test_batch_open(self, tmp_path):
+        path = self._tagged(tmp_path, [("Artist", b"Mac Artist")])
+        result = mutagen_rs.batch_open([path])[path]
+        assert result["tags"]["Artist"] == ["Mac Artist"]
+        assert round(result["length"], 2) == 3.68
+
+    def test_native_file(self, tmp_path):
+        from mutagen_rs import mutagen_rs as native
+        path = self._tagged(tmp_path, [("Artist", b"Mac Artist")])
+        f = native.File(path)
+        assert type(f).__name__ == "MonkeysAudio"
+        assert f.info.version == 3.99
+        assert f.info.pprint() == "Monkey's Audio 3.99, 3.68 seconds, 44100 Hz"
+        assert f["Artist"] == ["Mac Artist"]
+        assert f.tags.kind("Artist") == "text"
+        mutagen_rs.clear_cache()
+        assert mutagen_rs.MonkeysAudio(path).info.version == 3.99
+
+    @pytest.mark.parametrize("name,frames_at", [
+        ("mac-399.ape", 52 + 12),  # descriptor, then total frames in the header
+        ("mac-396.ape", 24),
+    ])
+    def test_frame_count_beyond_file_size(self, tmp_path, name, frames_at):
+        from mutagen_rs import mutagen_rs as native
+        with open(get_test_file(name), "rb") as f:
+            data = bytearray(f.read())
+        struct.pack_into("<I", data, frames_at, 1_000_000)
+        path = str(tmp_path / "corrupt.ape")
+        with open(path, "wb") as f:
+            f.write(data)
+        mutagen_rs.clear_cache()
+        for read in (mutagen_rs._fast_read, mutagen_rs._fast_info, mutagen_rs.MonkeysAudio, native.File):
+            with pytest.raises(mutagen_rs.MonkeysAudioTruncatedError):
+                read(path)
+        assert issubclass(mutagen_rs.MonkeysAudioTruncatedError, mutagen_rs.MonkeysAudioHeaderError)
+
+
+
+class TestWavPack:
+    """WavPack files: first block header info and trailing APEv2 tags."""
+
+    @pytest.mark.parametrize("name,length,sample_rate,bits,version", [
+        ("silence-44-s.wv", 3.68, 44100, 16, 0x403),
+        ("no_length.wv", 3.705, 44100, 16, 0x407),
+        ("dsd.wv", 0.01, 352800, 1, 0x410),
+    ])
+    def test_stream_info(self, name, length, sample_rate, bits, version):
+        path = get_test_file(name)
+        d = mutagen_rs._fast_info(path)
+        assert abs(d["length"] - length) < 0.005
+        assert d["sample_rate"] == sample_rate
+        assert d["channels"] == 2
+        assert d["bits_per_sample"] == bits
+        assert d["version"] == version
+        assert abs(mutagen_rs.File(path).info.length - length) < 0.005
+
+    def test_unknown_total_reads_last_block(self, tmp_path):
+        with open(get_test_file("silence-44-s.wv"), "rb") as f:
+            data = bytearray(f.read())
+        struct.pack_into("<I", data, 12, 0xFFFFFFFF)
+        path = str(tmp_path / "piped.wv")
+        with open(path, "wb") as f:
+            f.write(data)
+        d = mutagen_rs._fast_info(path)
+        assert d["length_samples"] == 162496
+        assert d["sample_rate"] == 44100
+
+    def test_apev2_tags(self):
+        mutagen_rs.clear_cache()
+        f = mutagen_rs.WavPack(get_test_file("silence-44-s.wv"))
+        assert type(f).__name__ == "WavPack"
+        assert f["Title"] == ["Silence"]
+        assert f["Artist"] == ["piman", "jzig"]
+        assert f.genre() == "Silence"
+        assert f.mime == ["audio/x-wavpack"]
+
+    def test_native_file(self):
+        from mutagen_rs import mutagen_rs as native
+        f = native.File(get_test_file("silence-44-s.wv"))
+        assert type(f).__name__ == "WavPack"
+        assert type(f.info).__name__ == "WavPackInfo"
+        assert f.info.pprint() == "WavPack, 3.68 seconds, 44100 Hz"
+        assert f.pprint().splitlines()[0] == f.info.pprint()
+        assert f.tags["Album"] == ["Quod Libet Test Data"]
+        assert json.loads(f.to_json())["version"] == 0x403
+
+    def test_detected_without_extension(self, tmp_path):
+        path = copy_fixture(tmp_path, "silence-44-s.wv", "noext.dat")
+        f = mutagen_rs.File(path)
+        assert type(f).__name__ == "WavPack"
+        assert f["Genre"] == ["Silence"]
+
+    def test_batch_open(self):
+        path = get_test_file("silence-44-s.wv")
+        result = mutagen_rs.batch_open([path])[path]
+        assert result["tags"]["Title"] == ["Silence"]
+        assert round(result["length"], 2) == 3.68
+
+    def test_not_wavpack(self, tmp_path):
+        path = copy_fixture(tmp_path, "silence-44-s.flac", "bad.wv")
+        mutagen_rs.clear_cache()
+        with pytest.raises(mutagen_rs.WavPackHeaderError):
+            mutagen_rs.WavPack(path)
+
+
+class TestRawAC3DTS:
+    """Raw AC-3 / E-AC-3 / DTS streams: stream info from the first sync frame."""
+
+    @staticmethod
+    def _pack(fields):
+        """Pack (value, bit count) pairs MSB first, padded to whole bytes."""
+        value, bits = 0, 0
+        for v, n in fields:
+            value, bits = (value << n) | v, bits + n
+        pad = -bits % 8
+        return (value << pad).to_bytes((bits + pad) // 8, "big")
+
+    def _write(self, tmp_path, name, frame, count):
+        path = str(tmp_path / name)
+        with open(path, "wb") as f:
+            f.write(frame * count)
+        return path
+
+    def test_ac3_frame_header(self, tmp_path):
+        # 48 kHz, frmsizecod 30 (448 kbps, 1792-byte frames), bsid 8, 3/2 + LFE
+        header = b"\x0b\x77\x00\x00" + self._pack([
+            (0, 2), (30, 6), (8, 5), (0, 3), (7, 3), (0, 2), (0, 2), (1, 1)])
+        frame = header.ljust(1792, b"\x00")
+        path = self._write(tmp_path, "test.ac3", frame, 375)
+        d = mutagen_rs._fast_info(path)
+        assert d["channels"] == 6
+        assert d["bitrate"] == 448000
+        assert d["sample_rate"] == 48000
+        assert d["codec"] == "ac-3"
+        # 375 frames of 1536 samples at 48 kHz
+        assert abs(d["length"] - 12.0) < 1e-9
+        assert d["length_samples"] == 375 * 1536
+
+    def test_ac3_stereo_no_lfe(self, tmp_path):
+        # 44.1 kHz, frmsizecod 20 (192 kbps), 2/0 with dsurmod, no LFE
+        header = b"\x0b\x77\x00\x00" + self._pack([
+            (1, 2), (20, 6), (8, 5), (0, 3), (2, 3), (0, 2), (0, 1)])
+        path = self._write(tmp_path, "stereo.ac3", header.ljust(557, b"\x00"), 10)
+        d = mutagen_rs._fast_info(path)
+        assert (d["channels"], d["bitrate"], d["sample_rate"]) == (2, 192000, 44100)
+
+    def test_eac3_frame_header(self, tmp_path):
+        # 768-byte frames of 6 blocks at 48 kHz -> 192 kbps, stereo, bsid 16
+        header = b"\x0b\x77" + self._pack([
+            (0, 2), (0, 3), (383, 11), (0, 2), (3, 2), (2, 3), (0, 1), (16, 5)])
+        path = self._write(tmp_path, "test.eac3", header.ljust(768, b"\x00"), 250)
+        d = mutagen_rs._fast_info(path)
+        assert (d["channels"], d["bitrate"], d["sample_rate"]) == (2, 192000, 48000)
+        assert d["codec"] == "ec-3"
+        assert abs(d["length"] - 250 * 1536 / 48000) < 1e-9
+
+    def test_dts_core_header(self, tmp_path):
+        # 16 blocks (512 samples), 48 kHz, 1536 kbps, 3/2 + LFE
+        header = b"\x7f\xfe\x80\x01" + self._pack([
+            (1, 1), (31, 5), (0, 1), (15, 7), (2047, 14), (9, 6), (13, 4), (24, 5),
+            (0, 5), (0, 3), (0, 1), (0, 1), (1, 2)])
+        path = self._write(tmp_path, "test.dts", header.ljust(2048, b"\x00"), 100)
+        d = mutagen_rs._fast_info(path)
+        assert (d["channels"], d["bitrate"], d["sample_rate"]) == (6, 1536000, 48000)
+        assert d["codec"] == "dts"
+        assert abs(d["length"] - 100 * 2048 * 8 / 1536000) < 1e-9
+
+    def test_large_stream_reads_head_only(self, tmp_path):
+        header = b"\x0b\x77\x00\x00" + self._pack([
+            (0, 2), (30, 6), (8, 5), (0, 3), (7, 3), (0, 2), (0, 2), (1, 1)])
+        path = self._write(tmp_path, "big.ac3", header.ljust(1792, b"\x00"), 3000)
+        d, n = TestMP4RangeRead._bytes_read(lambda: mutagen_rs._fast_info(path))
+        assert n < 256 * 1024 + 64 * 1024
+        assert abs(d["length"] - 3000 * 1792 * 8 / 448000) < 1e-9
+
+    def test_no_sync_frame(self, tmp_path):
+        path = self._write(tmp_path, "junk.ac3", b"\x00" * 4096, 1)
+        with pytest.raises(ValueError):
+            mutagen_rs._fast_info(path)
+
+
+class TestMIDI:
+    @staticmethod
+    def _chunk(kind, body):
+        return kind + struct.pack(">I", len(body)) + body
+
+    def _write(self, tmp_path, name, fmt, division, tracks):
+        path = str(tmp_path / name)
+        with open(path, "wb") as f:
+            f.write(self._chunk(b"MThd", struct.pack(">HHH", fmt, len(tracks), division)))
+            for body in tracks:
+                f.write(self._chunk(b"MTrk", body))
+        return path
+
+    def test_track_name_and_length(self, tmp_path):
+        # 480 ticks per beat at 100 BPM (600000 us); four quarter notes with
+        # running status -> 4 * 0.6 s
+        notes = b"\x00\x90\x3c\x40" + b"\x83\x60\x3c\x00\x00\x3e\x40" * 3 + b"\x83\x60\x3e\x00"
+        track = (b"\x00\xff\x03\x05Intro" + b"\x00\xff\x01\x04note"
+                 + b"\x00\xff\x51\x03\x09\x27\xc0" + notes + b"\x00\xff\x2f\x00")
+        path = self._write(tmp_path, "song.mid", 0, 480, [track])
+        d = mutagen_rs._fast_info(path)
+        assert d["title"] == "Intro"
+        assert d["codec"] == "midi"
+        assert (d["format"], d["tracks"], d["ticks_per_beat"]) == (0, 1, 480)
+        assert abs(d["bpm"] - 100.0) < 1e-9
+        assert abs(d["length"] - 2.4) < 1e-9
+        assert mutagen_rs.duration(path) == d["length"]
+
+    def test_tempo_map_across_tracks(self, tmp_path):
+        # Conductor track: 120 BPM, then 60 BPM from beat 2; the note track
+        # ends at beat 4 -> 2 * 0.5 s + 2 * 1 s
+        conductor = (b"\x00\xff\x03\x04Song" + b"\x00\xff\x51\x03\x07\xa1\x20"
+                     + b"\x87\x40\xff\x51\x03\x0f\x42\x40" + b"\x00\xff\x2f\x00")
+        notes = b"\x00\xff\x03\x05Piano\x00\xc0\x00\x00\x90\x3c\x40\x8f\x00\x80\x3c\x00\x00\xff\x2f\x00"
+        path = self._write(tmp_path, "song.midi", 1, 480, [conductor, notes])
+        d = mutagen_rs._fast_info(path)
+        assert d["title"] == "Song"
+        assert d["tracks"] == 2
+        assert abs(d["bpm"] - 120.0) < 1e-9
+        assert abs(d["length"] - 3.0) < 1e-9
+
+    def test_not_midi(self, tmp_path):
+        path = str(tmp_path / "junk.mid")
+        with open(path, "wb") as f:
+            f.write(b"RIFF" + b"\x00" * 64)
+        with pytest.raises(ValueError):
+            mutagen_rs._fast_info(path)
+
+
+class TestFuzzRegressions:
+    """Minimized fuzz crashers must raise ordinary errors, never panic."""
+
+    FUZZ_DIR = os.path.join(TEST_DIR, "fuzz")
+    ENTRY_POINTS = [
+        mutagen_rs._fast_read,
+        mutagen_rs._fast_info,
+        mutagen_rs.File,
+        lambda p: mutagen_rs.batch_open([p]),
+    ]
+
+    def _inputs(self):
+        names = sorted(os.listdir(self.FUZZ_DIR)) if os.path.isdir(self.FUZZ_DIR) else []
+        if not names:
+            pytest.skip("No fuzz regression inputs")
+        return [os.path.join(self.FUZZ_DIR, n) for n in names] + [get_test_file("issue_21.id3")]
+
+    def test_no_panics(self, tmp_path):
+        # PanicException derives from BaseException, so it escapes `except Exception`
+        for src in self._inputs():
+            data = open(src, "rb").read()
+            for ext in (".mp3", ".flac", ".ogg", ".m4a", ".wv"):
+                path = str(tmp_path / (os.path.basename(src) + ext))
+                with open(path, "wb") as f:
+                    f.write(data)
+                for entry in self.ENTRY_POINTS:
+                    mutagen_rs.clear_all_caches()
+                    try:
+                        entry(path)
+                    except Exception:
+                        pass
+
+    def test_extended_header_overrun_keeps_info(self):
+        path = os.path.join(self.FUZZ_DIR, "id3v24_extended_header_overrun.mp3")
+        mutagen_rs.clear_all_caches()
+        d = mutagen_rs._fast_read(path)
+        assert d["_format"] == "mp3"
+        assert d["sample_rate"] == 44100
+        assert d["_keys"] == []
+
+    def test_wavpack_empty_odd_sub_block(self):
+        # A zero-length sub-block flagged odd-sized has no byte to drop
+        path = os.path.join(self.FUZZ_DIR, "wavpack_empty_odd_sub_block.wv")
+        mutagen_rs.clear_all_caches()
+        d = mutagen_rs._fast_info(path)
+        assert d["sample_rate"] == 44100
+        assert mutagen_rs.WavPack(path).info.channels == 2
+
+
+# ──────────────────────────────────────────────────────────────
+# _fast_read API tests
+# ──────────────────────────────────────────────────────────────
+
+class TestFastRead:
+    """Test the _fast_read low-level API."""
+
+    @pytest.fixture(params=[
+        "silence-44-s.mp3",
+        "silence-44-s.flac",
+        "empty.ogg",
+        "has-tags.m4a",
+    ])
+    def audio_file(self, request):
+        path = get_test_file(request.param)
+        if not os.path.exists(path):
+            pytest.skip(f"Test file not found: {path}")
+        return path
+
+    def test_returns_dict(self, audio_file):
+        d = mutagen_rs._fast_read(audio_file)
+        assert isinstance(d, dict)
+
+    def test_has_length(self, audio_file):
+        d = mutagen_rs._fast_read(audio_file)
+        assert "length" in d
+        assert isinstance(d["length"], (int, float))
+        assert d["length"] > 0
+
+    def test_has_sample_rate(self, audio_file):
+        d = mutagen_rs._fast_read(audio_file)
+        assert "sample_rate" in d
+        assert d["sample_rate"] > 0
+
+    def test_matches_object_api(self, audio_file):
+        """_fast_read length should match File().info.length."""
+        d = mutagen_rs._fast_read(audio_file)
+        f = mutagen_rs.File(audio_file)
+        assert abs(d["length"] - f.info.length) < 0.01
+
+    @pytest.mark.parametrize("name", ["silence-44-s.mp3", "silence-44-s-v1.mp3"])
+    def test_large_mp3_range_read_matches_full_read(self, tmp_path, name):
+        """Large MP3s are read by range; results match parsing the whole file."""
+        src = get_test_file(name)
+        if not os.path.exists(src):
+            pytest.skip("Test file not found")
+        with open(src, "rb") as f:
+            data = f.read()
+        # Repeat the audio frames to ~1 MB, keeping the ID3v1 trailer at the end
+        audio = data[mutagen_rs.ID3.from_bytes(data).size if data[:3] == b"ID3" else 0:-128]
+        big = data[:-128] + audio * (1024 * 1024 // len(audio)) + data[-128:]
+        ranged = str(tmp_path / "big.mp3")
+        # An unrecognised extension takes the whole-file detection path
+        whole = str(tmp_path / "big.mpga")
+        for path in (ranged, whole):
+            with open(path, "wb") as f:
+                f.write(big)
+
+        expected = mutagen_rs._fast_read(whole)
+        expected.pop("_format", None)
+        got = mutagen_rs._fast_read(ranged)
+        got.pop("_format", None)
+        assert got == expected
+        info = mutagen_rs._fast_info(ranged)
+        assert info["length"] == expected["length"]
+        assert info["bitrate"] == expected["bitrate"]
+        batch = mutagen_rs.batch_open([ranged])[ranged]
+        assert batch["length"] == expected["length"]
+        assert set(batch["tags"]) == set(expected["_keys"])
+
+    @pytest.mark.parametrize("name", ["silence-44-s.flac", "multipagecomment.ogg"])
+    def test_multi_returns_lists(self, name):
+        path = get_test_file(name)
+        if not os.path.exists(path):
+            pytest.skip("Test file not found")
+        mixed = mutagen_rs._fast_read(path)
+        multi = mutagen_rs._fast_read(path, multi=True)
+        assert multi["_keys"] == mixed["_keys"]
+        for key in mixed["_keys"]:
+            assert isinstance(multi[key], list), key
+            expected = mixed[key] if isinstance(mixed[key], list) else [mixed[key]]
+            assert multi[key] == expected, key
+        assert mutagen_rs._fast_read_seq([path], multi=True)[0]["_keys"] == mixed["_keys"]
+
+    def test_multi_single_value(self):
+        path = get_test_file("silence-44-s.flac")
+        if not os.path.exists(path):
+            pytest.skip("Test file not found")
+        assert mutagen_rs._fast_read(path, multi=True)["title"] == ["Silence"]
+        # The default stays mixed, including on a cache hit after a multi read
+        assert mutagen_rs._fast_read(path)["title"] == "Silence"
+        assert mutagen_rs._fast_read(path)["artist"] == ["piman", "jzig"]
+
+    def test_fast_info_mp3_encoder_fields(self):
+        path = get_test_file("lame.mp3")
+        if not os.path.exists(path):
+            pytest.skip("Test file not found")
+        d = mutagen_rs._fast_info(path)
+        info = mutagen_rs.MP3(path).info
+        assert d["encoder_info"] == info.encoder_info
+        assert d["encoder_info"].startswith("LAME")
+        assert d["encoder_settings"] == info.encoder_settings
+        assert d["bitrate_mode"] == info.bitrate_mode
+
+
+class TestBitrateModeFlags:
+    """`is_vbr`/`is_cbr`/`is_abr` booleans on native and cached info objects."""
+
+    @pytest.mark.parametrize("name,expected", [
+        ("lame.mp3", (True, False, False)),
+        ("silence-44-s.mp3", (False, True, False)),
+        ("no-tags.mp3", (False, False, True)),
+        ("silence-44-s.flac", (True, False, False)),
+        ("empty.ogg", (True, False, False)),
+    ])
+    def test_flags(self, name, expected):
+        path = get_test_file(name)
+        if not os.path.exists(path):
+            pytest.skip("Test file not found")
+        for info in (mutagen_rs.mutagen_rs.File(path).info, mutagen_rs.File(path).info):
+            assert (info.is_vbr, info.is_cbr, info.is_abr) == expected
+
+    def test_mp3_follows_bitrate_mode(self):
+        path = get_test_file("lame.mp3")
+        if not os.path.exists(path):
+            pytest.skip("Test file not found")
+        info = mutagen_rs.MP3(path).info
+        assert info.bitrate_mode == 2
+        assert info.is_vbr
+
+    def test_unknown_for_mp4(self):
+        path = get_test_file("has-tags.m4a")
+        if not os.path.exists(path):
+            pytest.skip("Test file not found")
+        assert mutagen_rs.File(path).info.is_vbr is None
+
+
+class TestLengthSamples:
+    """Integer `length_samples` next to the float `length` on every info path."""
+
+    @pytest.fixture(params=[
+        "silence-44-s.flac",
+        "multipagecomment.ogg",
+        "has-tags.m4a",
+        "silence-44-s.mp3",
+        "xing.mp3",
+    ])
+    def audio_file(self, request):
+        path = get_test_file(request.param)
+        if not os.path.exists(path):
+            pytest.skip(f"Test file not found: {path}")
+        return path
+
+    def _check(self, length_samples, sample_rate, length):
+        assert isinstance(length_samples, int)
+        assert length_samples > 0
+        # MP4 `length` comes from mvhd and `length_samples` from the track's
+        # mdhd, which may be rounded to different timescales
+        assert abs(length_samples / sample_rate - length) < 0.01
+
+    def test_native_info(self, audio_file):
+        info = mutagen_rs.File(audio_file).info
+        self._check(info.length_samples, info.sample_rate, info.length)
+
+    def test_fast_read_and_info(self, audio_file):
+        for d in (mutagen_rs._fast_read(audio_file), mutagen_rs._fast_info(audio_file)):
+            self._check(d["length_samples"], d["sample_rate"], d["length"])
+
+    def test_batch_and_json(self, audio_file):
+        result = mutagen_rs.batch_open([audio_file])[audio_file]
+        self._check(result["length_samples"], result["sample_rate"], result["length"])
+        doc = json.loads(mutagen_rs._fast_read_json(audio_file))
+        assert doc["length_samples"] == result["length_samples"]
+
+    def test_exact_sources(self):
+        flac = get_test_file("silence-44-s.flac")
+        if not os.path.exists(flac):
+            pytest.skip("Test file not found")
+        f = mutagen_rs.FLAC(flac)
+        assert f.info.length_samples == f.info.total_samples
+        # Ogg length is the last granule position over the sample rate
+        ogg = mutagen_rs.OggVorbis(get_test_file("multipagecomment.ogg"))
+        assert ogg.info.length_samples == round(ogg.info.length * ogg.info.sample_rate)
+
+
+class TestDurationMs:
+    """Integer `duration_ms` agrees across every output path."""
+
+    @pytest.fixture(params=[
+        "silence-44-s.flac",
+        "multipagecomment.ogg",
+        "has-tags.m4a",
+        "silence-44-s.mp3",
+        "xing.mp3",
+    ])
+    def audio_file(self, request):
+        path = get_test_file(request.param)
+        if not os.path.exists(path):
+            pytest.skip(f"Test file not found: {path}")
+        return path
+
+    def test_same_on_every_path(self, audio_file):
+        info = mutagen_rs.File(audio_file).info
+        ms = info.duration_ms
+        assert isinstance(ms, int)
+        assert abs(ms - info.length * 1000) <= 1
+        assert mutagen_rs._fast_read(audio_file)["duration_ms"] == ms
+        assert mutagen_rs._fast_info(audio_file)["duration_ms"] == ms
+        assert mutagen_rs.batch_open([audio_file])[audio_file]["duration_ms"] == ms
+        assert mutagen_rs._fast_batch_read([audio_file])[audio_file]["duration_ms"] == ms
+        assert json.loads(mutagen_rs._fast_read_json(audio_file))["duration_ms"] == ms
+        native = mutagen_rs.mutagen_rs.File(audio_file)
+        assert native.info.duration_ms == ms
+        assert json.loads(native.to_json())["duration_ms"] == ms
+
+    def test_from_sample_counts(self):
+        for name in ("silence-44-s.flac", "multipagecomment.ogg", "xing.mp3"):
+            path = get_test_file(name)
+            if not os.path.exists(path):
+                pytest.skip("Test file not found")
+            info = mutagen_rs.File(path).info
+            rate = info.sample_rate
+            assert info.duration_ms == (info.length_samples * 1000 + rate // 2) // rate
+
+
+class TestFastReadNested:
+    """Flat `_fast_read` dicts namespace clashing tags; `nested=True` splits them out."""
+
+    @pytest.fixture(params=["silence-44-s.flac", "empty.ogg"])
+    def tagged(self, request, tmp_path):
+        path = copy_fixture(tmp_path, request.param, "clash" + os.path.splitext(request.param)[1])
+        f = mutagen_rs.File(path)
+        f["length"] = ["123456"]
+        f["bitrate"] = ["1", "2"]
+        f["_private"] = ["x"]
+        f["title"] = ["Title"]
+        f.save()
+        mutagen_rs.clear_cache()
+        return path
+
+    def test_flat_namespaces_clashes(self, tagged):
+        d = mutagen_rs._fast_read(tagged)
+        assert isinstance(d["length"], float)
+        assert isinstance(d["bitrate"], int)
+        assert d["tag:length"] == "123456"
+        assert d["tag:bitrate"] == ["1", "2"]
+        assert d["tag:_private"] == "x"
+        assert d["title"] == "Title"
+        assert {"tag:length", "tag:bitrate", "tag:_private", "title"} <= set(d["_keys"])
+        assert "length" not in d["_keys"]
+
+    def test_nested_shape(self, tagged):
+        d = mutagen_rs._fast_read(tagged, nested=True)
+        assert "_keys" not in d
+        assert isinstance(d["length"], float)
+        assert d["_format"] in ("flac", "ogg")
+        tags = d["tags"]
+        assert tags["length"] == "123456"
+        assert tags["bitrate"] == ["1", "2"]
+        assert tags["_private"] == "x"
+        assert tags["title"] == "Title"
+        assert not any(k.startswith("tag:") for k in tags)
+        assert "title" not in d
+
+    def test_nested_from_cache(self, tagged):
+        flat = mutagen_rs._fast_read(tagged)
+        nested = mutagen_rs._fast_read(tagged, nested=True, multi=True)
+        assert nested["tags"]["length"] == ["123456"]
+        # The cached flat dict is left as it was
+        assert mutagen_rs._fast_read(tagged) == flat
+
+    def test_seq(self, tagged):
+        [flat] = mutagen_rs._fast_read_seq([tagged])
+        [nested] = mutagen_rs._fast_read_seq([tagged], nested=True)
+        assert flat["tag:length"] == "123456"
+        assert nested["tags"]["length"] == "123456"
+        assert nested["length"] == flat["length"]
+
+    def test_facade_uses_real_names(self, tagged):
+        f = mutagen_rs.File(tagged)
+        assert f["length"] == ["123456"]
+        assert "length" in f.keys()
+        assert isinstance(f.info.length, float)
+
+
+class TestDuration:
+    """Test duration() returns the _fast_info length as a bare float or None."""
+
+    FILES = ["silence-44-s.mp3", "xing.mp3", "vbri.mp3", "silence-44-s.flac",
+             "variable-block.flac", "empty.ogg", "multipagecomment.ogg",
+             "has-tags.m4a", "alac.m4a", "silence-44-s.ac3", "mac-399.ape"]
+
+    def test_matches_fast_info(self):
+        mutagen_rs.clear_cache()
+        for name in self.FILES:
+            path = get_test_file(name)
+            length = mutagen_rs.duration(path)
+            assert type(length) is float, name
+            assert length == mutagen_rs._fast_info(path)["length"], name
+
+    def test_from_info_cache(self, tmp_path):
+        dst = copy_fixture(tmp_path, "silence-44-s.flac", "cached.flac")
+        length = mutagen_rs._fast_info(dst)["length"]
+        st = os.stat(dst)
+        with open(dst, "r+b") as f:
+            f.write(b"\x00" * st.st_size)
+        os.utime(dst, ns=(st.st_atime_ns, st.st_mtime_ns))
+        assert mutagen_rs.duration(dst) == length
+
+    def test_unparseable(self, tmp_path):
+        path = str(tmp_path / "junk.mp3")
+        with open(path, "wb") as f:
+            f.write(b"\x00" * 64)
+        with pytest.raises(ValueError):
+            mutagen_rs.duration(path)
+        with pytest.raises(OSError):
+            mutagen_rs.duration(str(tmp_path / "missing.flac"))
+
+
+class TestVerifyExtension:
+    """Test verify_extension() reports content that disagrees with the extension."""
+
+    def test_flac_renamed_ogg(self, tmp_path):
+        dst = copy_fixture(tmp_path, "silence-44-s.flac", "renamed.ogg")
+        assert mutagen_rs.verify_extension(dst) == "flac"
+
+    def test_mp3_renamed_m4a(self, tmp_path):
+        dst = copy_fixture(tmp_path, "silence-44-s.mp3", "renamed.m4a")
+        assert mutagen_rs.verify_extension(dst) == "mp3"
+
+    def test_matching(self):
+        for name in ["silence-44-s.flac", "silence-44-s.mp3", "empty.ogg",
+                     "has-tags.m4a", "mac-399.ape"]:
+            assert mutagen_rs.verify_extension(get_test_file(name)) is None, name
+
+    def test_unrecognized(self, tmp_path):
+        path = str(tmp_path / "junk.flac")
+        with open(path, "wb") as f:
+            f.write(b"\x00" * 64)
+        assert mutagen_rs.verify_extension(path) is None
+
+
+class TestReadCachedBytes:
+    """Test read_cached_bytes() serves file contents from the Rust file cache."""
+
+    def test_second_call_from_cache(self, tmp_path):
+        dst = copy_fixture(tmp_path, "silence-44-s.flac", "cached.flac")
+        with open(dst, "rb") as f:
+            original = f.read()
+        mutagen_rs.clear_all_caches()
+        assert mutagen_rs._cache_stats()["file"] == 0
+        assert mutagen_rs.read_cached_bytes(dst) == original
+        assert mutagen_rs._cache_stats()["file"] == 1
+        # Same size on disk, different content: a cache hit still sees the old bytes
+        with open(dst, "r+b") as f:
+            f.write(b"\x00" * len(original))
+        assert mutagen_rs.read_cached_bytes(dst) == original
+        assert mutagen_rs._cache_stats()["file"] == 1
+        mutagen_rs.clear_all_caches()
+        assert mutagen_rs.read_cached_bytes(dst) == b"\x00" * len(original)
+
+    def test_shared_with_native_open(self, tmp_path):
+        dst = copy_fixture(tmp_path, "silence-44-s.mp3", "shared.mp3")
+        mutagen_rs.clear_all_caches()
+        mutagen_rs._RustMP3(dst)
+        stats = mutagen_rs._cache_stats()["file"]
+        with open(dst, "rb") as f:
+            assert mutagen_rs.read_cached_bytes(dst) == f.read()
+        assert mutagen_rs._cache_stats()["file"] == stats
+
+    def test_missing_file(self, tmp_path):
+        with pytest.raises(IOError):
+            mutagen_rs.read_cached_bytes(str(tmp_path / "missing.flac"))
+
+
+class TestFastInfoCache:
+    """Test the _fast_info duration cache (keyed by path + mtime)."""
+
+    def _clobber(self, path, keep_mtime):
+        st = os.stat(path)
+        with open(path, "r+b") as f:
+            f.write(b"\x00" * st.st_size)
+        if keep_mtime:
+            os.utime(path, ns=(st.st_atime_ns, st.st_mtime_ns))
+        else:
+            os.utime(path, ns=(st.st_atime_ns, st.st_mtime_ns + 10**9))
+
+    def test_second_call_is_cache_hit(self, tmp_path):
+        path = copy_fixture(tmp_path, "silence-44-s.flac", "cached.flac")
+        first = mutagen_rs._fast_info(path)
+        # Same mtime and size: the file is not re-read, so the bogus bytes go unseen
+        self._clobber(path, keep_mtime=True)
+        second = mutagen_rs._fast_info(path)
+        assert second == first
+        assert second is not first
+
+    def test_mtime_change_invalidates(self, tmp_path):
+        path = copy_fixture(tmp_path, "silence-44-s.flac", "cached.flac")
+        mutagen_rs._fast_info(path)
+        self._clobber(path, keep_mtime=False)
+        with pytest.raises(Exception):
+            mutagen_rs._fast_info(path)
+
+    def test_clear_cache_drops_entries(self, tmp_path):
+        path = copy_fixture(tmp_path, "silence-44-s.flac", "cached.flac")
+        mutagen_rs._fast_info(path)
+        self._clobber(path, keep_mtime=True)
+        mutagen_rs.clear_cache()
+        with pytest.raises(Exception):
+            mutagen_rs._fast_info(path)
+
+    def test_mutating_result_does_not_touch_cache(self, tmp_path):
+        path = copy_fixture(tmp_path, "silence-44-s.flac", "cached.flac")
+        d = mutagen_rs._fast_info(path)
+        d["length"] = -1.0
+        assert mutagen_rs._fast_info(path)["length"] > 0
+
+    def test_info_scan_keeps_no_file_data(self, tmp_path):
+        paths = []
+        for name in ("silence-44-s.mp3", "silence-44-s.flac", "empty.ogg", "has-tags.m4a"):
+            for i in range(50):
+                paths.append(copy_fixture(tmp_path, name, f"{i}-{name}"))
+        mutagen_rs.clear_all_caches()
+        for p in paths:
+            mutagen_rs._fast_info(p)
+        stats = mutagen_rs._cache_stats()
+        assert stats["file"] == 0
+        assert stats["result"] == 0
+        assert stats["template"] == 0
+        assert stats["info"] == len(paths)
+
+    @pytest.mark.parametrize("name", ["silence-44-s.flac", "empty.ogg"])
+    def test_large_file_read_by_range(self, tmp_path, name):
+        src = get_test_file(name)
+        if not os.path.exists(src):
+            pytest.skip("Test file not found")
+        with open(src, "rb") as f:
+            data = f.read()
+        junk = b"\x00" * (4 * 1024 * 1024)
+        if name.endswith(".flac"):
+            data += junk
+        else:
+            # Keep the last page (and its granule position) at the end
+            last = data.rfind(b"OggS")
+            data = data[:last] + junk + data[last:]
+        path = str(tmp_path / ("big-" + name))
+        with open(path, "wb") as f:
+            f.write(data)
+        expected = mutagen_rs._fast_info(src)
+        d, n = TestMP4RangeRead._bytes_read(lambda: mutagen_rs._fast_info(path))
+        assert n < 256 * 1024 + 64 * 1024 + 4096
+        assert d["length"] == expected["length"]
+        assert d["sample_rate"] == expected["sample_rate"]
+
+
+class TestThreadedOpen:
+    """Single-file reads release the GIL; concurrent opens match serial ones."""
+
+    NAMES = ["silence-44-s.mp3", "silence-44-s.flac", "empty.ogg", "has-tags.m4a"]
+
+    def _copies(self, tmp_path, count=4):
+        paths = []
+        for name in self.NAMES:
+            base, ext = os.path.splitext(name)
+            for i in range(count):
+                paths.append(copy_fixture(tmp_path, name, f"{base}_{i}{ext}"))
+        return paths
+
+    def test_fast_read_from_threads(self, tmp_path):
+        from concurrent.futures import ThreadPoolExecutor
+        paths = self._copies(tmp_path)
+        mutagen_rs.clear_all_caches()
+        with ThreadPoolExecutor(max_workers=8) as pool:
+            threaded = list(pool.map(mutagen_rs._fast_read, paths))
+        mutagen_rs.clear_all_caches()
+        serial = [mutagen_rs._fast_read(p) for p in paths]
+        assert threaded == serial
+
+    def test_file_open_from_threads(self, tmp_path):
+        from concurrent.futures import ThreadPoolExecutor
+        paths = self._copies(tmp_path)
+        mutagen_rs.clear_all_caches()
+        with ThreadPoolExecutor(max_workers=8) as pool:
+            opened = list(pool.map(mutagen_rs.File, paths))
+        for path, f in zip(paths, opened):
+            expected = mutagen_rs.File(path)
+            assert type(f) is type(expected)
+            assert f.info.length == expected.info.length
+            assert sorted(f.keys()) == sorted(expected.keys())
+
+    def test_constructors_from_threads(self, tmp_path):
+        from concurrent.futures import ThreadPoolExecutor
+        classes = {".mp3": mutagen_rs.MP3, ".flac": mutagen_rs.FLAC,
+                   ".ogg": mutagen_rs.OggVorbis, ".m4a": mutagen_rs.MP4}
+        paths = self._copies(tmp_path)
+        mutagen_rs.clear_all_caches()
+        with ThreadPoolExecutor(max_workers=8) as pool:
+            opened = list(pool.map(lambda p: classes[os.path.splitext(p)[1]](p), paths))
+        for path, f in zip(paths, opened):
+            assert f.info.length == mutagen_rs.File(path).info.length
+
+    def test_interned_keys_shared_and_released(self, tmp_path):
+        import sys
+        from concurrent.futures import ThreadPoolExecutor
+        paths = [p for p in self._copies(tmp_path, count=8) if p.endswith(".mp3")]
+        for _ in range(3):
+            mutagen_rs.clear_all_caches()
+            with ThreadPoolExecutor(max_workers=4) as pool:
+                results = list(pool.map(mutagen_rs._fast_read, paths))
+            keys = [next(k for k in d if k == "TIT2") for d in results]
+            # Every thread gets the same string object from the shared cache
+            assert all(k is keys[0] for k in keys)
+            key = keys[0]
+            del results, keys
+            # Once the caches are cleared, nothing on the Rust side holds the key
+            mutagen_rs.clear_all_caches()
+            assert sys.getrefcount(key) == 2
+
+
+class TestTagPresence:
+    """Test has_tags / tag_summary / batch_tag_summary."""
+
+    def test_has_tags(self):
+        assert mutagen_rs.has_tags(get_test_file("silence-44-s.mp3"))
+        assert mutagen_rs.has_tags(get_test_file("silence-44-s.flac"))
+        assert mutagen_rs.has_tags(get_test_file("has-tags.m4a"))
+
+    def test_has_no_tags(self):
+        assert not mutagen_rs.has_tags(get_test_file("no-tags.flac"))
+        assert not mutagen_rs.has_tags(get_test_file("empty.ogg"))
+
+    def test_summary_matches_full_read(self):
+        for name in ["has-tags.m4a", "empty.ogg", "no-tags.flac"]:
+            path = get_test_file(name)
+            fmt, count, _ = mutagen_rs.tag_summary(path)
+            f = mutagen_rs.File(path)
+            assert fmt == f._format
+            assert count == len(f.keys())
+        # Vorbis comments count entries, so a repeated field counts twice
+        path = get_test_file("silence-44-s.flac")
+        f = mutagen_rs.File(path)
+        assert mutagen_rs.tag_summary(path)[1] == sum(len(f[k]) for k in f.keys())
+
+    def test_apev2_only_mp3(self):
+        path = get_test_file("audacious-trailing-id32-apev2.mp3")
+        f = mutagen_rs.File(path)
+        assert len(f.keys()) > 0
+        assert f.tags is not None
+        assert mutagen_rs.has_tags(path)
+        assert mutagen_rs.tag_summary(path) == ("mp3", len(f.keys()), False)
+        assert mutagen_rs._fast_read(path)["_has_tags"] is True
+
+    def test_summary_pictures(self):
+        assert mutagen_rs.tag_summary(get_test_file("has-tags.m4a"))[2] is True
+        assert mutagen_rs.tag_summary(get_test_file("silence-44-s.flac"))[2] is True
+        assert mutagen_rs.tag_summary(get_test_file("no-tags.flac"))[2] is False
+
+    def test_unrecognized_raises(self):
+        with pytest.raises(ValueError):
+            mutagen_rs.tag_summary(get_test_file("image.jpg"))
+
+    def test_batch_summary(self):
+        paths = [get_test_file("silence-44-s.mp3"), get_test_file("has-tags.m4a"),
+                 get_test_file("image.jpg"), get_test_file("does-not-exist.mp3")]
+        result = mutagen_rs.batch_tag_summary(paths)
+        assert set(result) == set(paths[:2])
+        assert result[paths[1]] == mutagen_rs.tag_summary(paths[1])
+
+    @staticmethod
+    def _flac_blocks(path):
+        """(type, body) of each metadata block of ``path``, and the audio after them."""
+        with open(path, "rb") as f:
+            data = f.read()
+        blocks, pos = [], 4
+        while True:
+            header, size = data[pos], int.from_bytes(data[pos + 1:pos + 4], "big")
+            blocks.append((header & 0x7F, data[pos + 4:pos + 4 + size]))
+            pos += 4 + size
+            if header & 0x80:
+                return blocks, data[pos:]
+
+    def _flac(self, tmp_path, name, keep):
+        """silence-44-s.flac with the blocks of the types in ``keep``, in that order."""
+        blocks, audio = self._flac_blocks(get_test_file("silence-44-s.flac"))
+        chosen = [b for t in keep for b in blocks if b[0] == t]
+        out = b"fLaC"
+        for i, (t, body) in enumerate(chosen):
+            last = 0x80 if i == len(chosen) - 1 else 0
+            out += bytes([t | last]) + len(body).to_bytes(3, "big") + body
+        path = str(tmp_path / name)
+        with open(path, "wb") as f:
+            f.write(out + audio)
+        return path
+
+    def test_flac_pictures_only(self, tmp_path):
+        path = self._flac(tmp_path, "pic.flac", [0, 6])
+        assert mutagen_rs.has_tags(path)
+        assert mutagen_rs.tag_summary(path) == ("flac", 0, True)
+
+    def test_large_files_read_by_range(self, tmp_path):
+        # Each file is well past the 256 KB whole-read limit, with its tags
+        # behind a big picture, spread over pages or next to a long audio part
+        flac = self._flac(tmp_path, "big.flac", [0, 6, 4])
+        with open(flac, "ab") as f:
+            f.write(b"\x00" * (1 << 20))
+        ogg = copy_fixture(tmp_path, "multipagecomment.ogg", "big.ogg")
+        f = mutagen_rs.OggVorbis(ogg)
+        f["comment"] = ["x" * 400000]
+        f.save()
+        opus = copy_fixture(tmp_path, "example.opus", "big.opus")
+        f = mutagen_rs.OggOpus(opus)
+        f["comment"] = ["x" * 400000]
+        f.save()
+        mp3 = str(tmp_path / "big.mp3")
+        with open(get_test_file("silence-44-s.mp3"), "rb") as src:
+            data = src.read()
+        with open(mp3, "wb") as out:
+            out.write(data[:-128] + b"\x00" * (1 << 20) + data[-128:])
+        mutagen_rs.clear_all_caches()
+        small = {flac: get_test_file("silence-44-s.flac"), mp3: get_test_file("silence-44-s.mp3")}
+        for path in (flac, ogg, opus, mp3):
+            assert os.path.getsize(path) > 256 * 1024
+            fmt, count, pictures = mutagen_rs.tag_summary(path)
+            if path in small:
+                assert (fmt, count) == mutagen_rs.tag_summary(small[path])[:2]
+            else:
+                g = mutagen_rs.File(path)
+                assert fmt == g._format
+                assert count == sum(len(g[k]) for k in g.keys())
+            assert mutagen_rs.batch_tag_summary([path])[path] == (fmt, count, pictures)
+        assert mutagen_rs.tag_summary(flac)[2] is True
+
+
+
+class TestRawAccess:
+    """Test get_raw / get_raw_atom / get_raw_block and set_raw passthrough."""
+
+    def test_id3_frame_bytes(self):
+        path = get_test_file("silence-44-s.mp3")
+        raw = mutagen_rs.MP3(path).get_raw("TIT2")
+        assert raw[:4] == b"TIT2"
+        assert int.from_bytes(raw[4:8], "big") == len(raw) - 10
+        with open(path, "rb") as f:
+            assert raw in f.read()
+
+    def test_mp4_atom_bytes(self):
+        raw = mutagen_rs.MP4(get_test_file("has-tags.m4a")).get_raw_atom("\xa9too")
+        assert int.from_bytes(raw[:4], "big") == len(raw)
+        assert raw[4:8] == b"\xa9too"
+
+    def test_flac_block_bytes(self):
+        f = mutagen_rs.FLAC(get_test_file("silence-44-s.flac"))
+        streaminfo = f.get_raw_block(0)
+        assert streaminfo[0] & 0x7F == 0
+        assert len(streaminfo) == 4 + 34
+        with pytest.raises(IndexError):
+            f.get_raw_block(1000)
+
+    def test_missing_key_raises(self):
+        with pytest.raises(KeyError):
+            mutagen_rs.MP3(get_test_file("silence-44-s.mp3")).get_raw("TXXX:nope")
+
+    def test_mp3_set_raw_round_trip(self, tmp_path):
+        path = copy_fixture(tmp_path, "silence-44-s.mp3")
+        frame = b"TIT2" + (8).to_bytes(4, "big") + b"\x00\x00" + b"\x00Raw set"
+        f = mutagen_rs.MP3(path)
+        f.set_raw("TIT2", frame)
+        assert f.get_raw("TIT2") == frame
+        f.save()
+        mutagen_rs.clear_cache()
+        g = mutagen_rs.MP3(path)
+        assert str(g["TIT2"]) == "Raw set"
+        assert g.get_raw("TIT2") == frame
+
+    def test_mp4_set_raw_round_trip(self, tmp_path):
+        path = copy_fixture(tmp_path, "has-tags.m4a")
+        text = b"Raw tool"
+        data = (16 + len(text)).to_bytes(4, "big") + b"data" + b"\x00\x00\x00\x01" + b"\x00" * 4 + text
+        atom = (8 + len(data)).to_bytes(4, "big") + b"\xa9too" + data
+        f = mutagen_rs.MP4(path)
+        f.set_raw("\xa9too", atom)
+        f.save()
+        mutagen_rs.clear_cache()
+        g = mutagen_rs.MP4(path)
+        assert g["\xa9too"] == ["Raw tool"]
+        assert g.get_raw_atom("\xa9too") == atom
+
+    def test_raw_reads_follow_saved_layout(self, tmp_path):
+        path = copy_fixture(tmp_path, "has-tags.m4a")
+        f = mutagen_rs.MP4(path)
+        before = f.get_raw_atom("\xa9too")
+        f["\xa9nam"] = ["x" * 5000]
+        f.save()
+        raw = f.get_raw_atom("\xa9too")
+        assert raw == before
+        with open(path, "rb") as fh:
+            assert raw in fh.read()
+
+        path = copy_fixture(tmp_path, "silence-44-s.flac")
+        f = mutagen_rs.FLAC(path)
+        f["title"] = ["x" * 5000]
+        f.save()
+        streaminfo = f.get_raw_block(0)
+        assert len(streaminfo) == 4 + 34
+        with open(path, "rb") as fh:
+            assert fh.read()[4:42] == streaminfo
+
+    def test_set_raw_rejects_bad_header(self):
+        f = mutagen_rs.MP3(get_test_file("silence-44-s.mp3"))
+        with pytest.raises(Exception):
+            f.set_raw("TIT2", b"TIT2" + (99).to_bytes(4, "big") + b"\x00\x00\x00abc")
+        with pytest.raises(Exception):
+            f.set_raw("TIT2", b"TPE1" + (4).to_bytes(4, "big") + b"\x00\x00\x00abc")
+
+
+class TestStrip:
+    """Test strip() removing frames / comments / atoms by key pattern."""
+
+    def _mp3(self, tmp_path):
+        return _mp3_with_frames(tmp_path, [
+            (b"TIT2", b"\x00Title"),
+            (b"TPE1", b"\x00Artist"),
+            (b"APIC", _PNG_APIC),
+            (b"PRIV", b"owner@example.com\x00" + b"\x01" * 32),
+            (b"COMM", b"\x00engnote\x00A comment"),
+        ], "strip.mp3")
+
+    def test_mp3_strip_apic_priv(self, tmp_path):
+        path = self._mp3(tmp_path)
+        f = mutagen_rs.MP3(path)
+        assert any(k.startswith("APIC") for k in f.keys())
+        removed = f.strip(["APIC", "PRIV"])
+        assert sorted(k[:4] for k in removed) == ["APIC", "PRIV"]
+        assert not any(k[:4] in ("APIC", "PRIV") for k in f.keys())
+        f.save()
+        mutagen_rs.clear_cache()
+        g = mutagen_rs.MP3(path)
+        keys = g.keys()
+        assert not any(k[:4] in ("APIC", "PRIV") for k in keys)
+        assert str(g["TIT2"]) == "Title"
+        assert str(g["TPE1"]) == "Artist"
+
+    def test_id3_prefix_pattern(self, tmp_path):
+        path = self._mp3(tmp_path)
+        tags = mutagen_rs.ID3(path)
+        tags.strip(["COMM*", "PRIV"])
+        tags.save(path)
+        mutagen_rs.clear_cache()
+        keys = list(mutagen_rs.ID3(path).keys())
+        assert not any(k.startswith(("COMM", "PRIV")) for k in keys)
+        assert "TIT2" in keys
+        assert any(k.startswith("APIC") for k in keys)
+
+    def test_flac_keys_ignore_case(self, tmp_path):
+        path = copy_fixture(tmp_path, "silence-44-s.flac", "strip.flac")
+        f = mutagen_rs.FLAC(path)
+        f["comment"] = ["drop me"]
+        f.save()
+        mutagen_rs.clear_cache()
+        f = mutagen_rs.FLAC(path)
+        f.strip(["COMMENT"])
+        assert "comment" not in f
+        f.save()
+        mutagen_rs.clear_cache()
+        g = mutagen_rs.FLAC(path)
+        assert "comment" not in g.keys()
+        assert "title" in g.keys()
+
+
+class TestCover:
+    """Test cover() returning the front cover across formats."""
+
+    @staticmethod
+    def _jpeg():
+        src = get_test_file("image.jpg")
+        if not os.path.exists(src):
+            pytest.skip("Test file not found")
+        with open(src, "rb") as f:
+            return f.read()
+
+    @staticmethod
+    def _picture_block(pic_type, mime, data):
+        mime = mime.encode()
+        return (struct.pack(">II", pic_type, len(mime)) + mime + struct.pack(">I", 0)
+                + struct.pack(">IIII", 0, 0, 0, 0) + struct.pack(">I", len(data)) + data)
+
+    def _mp3(self, tmp_path, pictures):
+        frames = [(b"APIC", b"\x00image/jpeg\x00" + bytes([pic_type]) + b"pic%d\x00" % i + image)
+                  for i, (pic_type, image) in enumerate(pictures)]
+        return _mp3_with_frames(tmp_path, frames, "cover.mp3")
+
+    def test_mp3_prefers_front_cover(self, tmp_path):
+        jpeg = self._jpeg()
+        path = self._mp3(tmp_path, [(0, b"other"), (3, jpeg)])
+        assert mutagen_rs.MP3(path).cover() == jpeg
+
+    def test_mp3_falls_back_to_first(self, tmp_path):
+        path = self._mp3(tmp_path, [(4, b"back"), (0, b"other")])
+        assert mutagen_rs.MP3(path).cover() == b"back"
+
+    def test_flac_picture_block(self):
+        f = mutagen_rs.FLAC(get_test_file("silence-44-s.flac"))
+        cover = f.cover()
+        assert cover.startswith(b"\x89PNG")
+        assert cover == f.pictures[0]["data"]
+
+    def test_ogg_metadata_block_picture(self, tmp_path):
+        import base64
+        path = copy_fixture(tmp_path, "empty.ogg", "cover.ogg")
+        jpeg = self._jpeg()
+        f = mutagen_rs.OggVorbis(path)
+        f["metadata_block_picture"] = [
+            base64.b64encode(self._picture_block(0, "image/png", b"other")).decode(),
+            base64.b64encode(self._picture_block(3, "image/jpeg", jpeg)).decode(),
+        ]
+        f.save()
+        mutagen_rs.clear_cache()
+        assert mutagen_rs.OggVorbis(path).cover() == jpeg
+
+    def test_mp4_first_covr(self):
+        path = get_test_file("has-tags.m4a")
+        cover = mutagen_rs.MP4(path).cover()
+        assert cover.startswith(b"\x89PNG")
+        assert cover in mutagen_rs.MP4(path).get_raw_atom("covr")
+
+    def test_no_cover(self):
+        assert mutagen_rs.File(get_test_file("no-tags.m4a")).cover() is None
+        assert mutagen_rs.File(get_test_file("empty.ogg")).cover() is None
+
+
+class TestLyrics:
+    """Test lyrics()/set_lyrics() across formats."""
+
+    TEXT = "First verse, line one\nline two\n\nSecond verse \u00e9\r\nend"
+
+    @pytest.fixture(params=["silence-44-s.mp3", "has-tags.m4a", "silence-44-s.flac", "empty.ogg"])
+    def audio_copy(self, request, tmp_path):
+        return copy_fixture(tmp_path, request.param)
+
+    def test_round_trip(self, audio_copy):
+        f = mutagen_rs.File(audio_copy)
+        f.set_lyrics(self.TEXT)
+        assert f.lyrics() == self.TEXT
+        f.save()
+        mutagen_rs.clear_cache()
+        assert mutagen_rs.File(audio_copy).lyrics() == self.TEXT
+
+    def test_replaces_existing(self, audio_copy):
+        f = mutagen_rs.File(audio_copy)
+        f.set_lyrics("old")
+        f.save()
+        mutagen_rs.clear_cache()
+        f = mutagen_rs.File(audio_copy)
+        f.set_lyrics(self.TEXT)
+        f.save()
+        mutagen_rs.clear_cache()
+        f = mutagen_rs.File(audio_copy)
+        assert f.lyrics() == self.TEXT
+        assert sum(k.lower().startswith(("uslt", "\xa9lyr", "lyrics")) for k in f.keys()) == 1
+
+    def test_mp3_uslt_frame(self, tmp_path):
+        path = copy_fixture(tmp_path, "silence-44-s.mp3", "lyrics.mp3")
+        f = mutagen_rs.MP3(path)
+        f.set_lyrics(self.TEXT)
+        f.save()
+        mutagen_rs.clear_cache()
+        assert mutagen_rs.ID3(path)["USLT::eng"] == self.TEXT
+        assert str(mutagen_rs.MP3(path)["USLT::eng"]) == self.TEXT
+
+    def test_vorbis_unsynced_fallback(self, tmp_path):
+        path = copy_fixture(tmp_path, "empty.ogg", "lyrics.ogg")
+        f = mutagen_rs.OggVorbis(path)
+        f["UNSYNCEDLYRICS"] = ["fallback"]
+        f.save()
+        mutagen_rs.clear_cache()
+        f = mutagen_rs.OggVorbis(path)
+        assert f.lyrics() == "fallback"
+        f.set_lyrics(self.TEXT)
+        f.save()
+        mutagen_rs.clear_cache()
+        f = mutagen_rs.OggVorbis(path)
+        assert "unsyncedlyrics" not in f
+        assert f["lyrics"] == [self.TEXT]
+
+    def test_no_lyrics(self):
+        assert mutagen_rs.File(get_test_file("no-tags.m4a")).lyrics() is None
+
+    @pytest.mark.parametrize("name,cls", [("silence-44-s.flac", "FLAC"), ("empty.ogg", "OggVorbis")])
+    def test_vorbis_legacy_locations(self, tmp_path, name, cls):
+        path = copy_fixture(tmp_path, name)
+        f = getattr(mutagen_rs, cls)(path)
+        f["LYRICS"] = ["from lyrics"]
+        f["COMMENT"] = ["one\ntwo\nthree"]
+        f.save()
+        mutagen_rs.clear_cache()
+        assert mutagen_rs.File(path).lyrics() == "from lyrics"
+        assert getattr(mutagen_rs, cls)(path).lyrics() == "from lyrics"
+
+        f = getattr(mutagen_rs, cls)(path)
+        f["UNSYNCEDLYRICS"] = ["unsynced"]
+        f.save()
+        mutagen_rs.clear_cache()
+        assert mutagen_rs.File(path).lyrics() == "unsynced"
+
+        f = getattr(mutagen_rs, cls)(path)
+        f.strip(["lyrics", "unsyncedlyrics"])
+        f.save()
+        mutagen_rs.clear_cache()
+        assert mutagen_rs.File(path).lyrics() == "one\ntwo\nthree"
+
+    def test_short_comment_is_not_lyrics(self, tmp_path):
+        path = copy_fixture(tmp_path, "silence-44-s.flac", "c.flac")
+        f = mutagen_rs.FLAC(path)
+        f.strip(["lyrics", "unsyncedlyrics"])
+        f["COMMENT"] = ["Ripped with care\nfrom CD"]
+        f.save()
+        mutagen_rs.clear_cache()
+        assert mutagen_rs.File(path).lyrics() is None
+
+    def test_mp3_comment_described_lyrics(self, tmp_path):
+        path = copy_fixture(tmp_path, "silence-44-s.mp3", "c.mp3")
+        tags = mutagen_rs.ID3(path)
+        tags["COMM:lyrics:eng"] = "la la la"
+        tags.save()
+        mutagen_rs.clear_cache()
+        assert mutagen_rs.File(path).lyrics() == "la la la"
+        f = mutagen_rs.MP3(path)
+        f.set_lyrics("real lyrics")
+        f.save()
+        mutagen_rs.clear_cache()
+        assert mutagen_rs.File(path).lyrics() == "real lyrics"
+
+
+class TestCopyTags:
+    """Test copy_tags() across formats."""
+
+    def test_flac_to_mp3(self, tmp_path):
+        src = get_test_file("silence-44-s.flac")
+        dst = copy_fixture(tmp_path, "no-tags.mp3", "dst_no-tags.mp3")
+        cover = mutagen_rs.FLAC(src).cover()
+        mutagen_rs.copy_tags(src, dst)
+        mutagen_rs.clear_all_caches()
+        f = mutagen_rs.MP3(dst)
+        assert f["TIT2"] == ["Silence"]
+        assert f["TPE1"] == ["piman", "jzig"]
+        assert f["TRCK"] == ["02/10"]
+        assert f.cover() == cover
+        assert f.getall("APIC")[0][0]["mime"] == "image/png"
+
+    def test_no_stale_cache(self, tmp_path):
+        src = get_test_file("silence-44-s.flac")
+        dst = copy_fixture(tmp_path, "no-tags.mp3", "dst_no-tags.mp3")
+        assert "TIT2" not in mutagen_rs.MP3(dst)
+        mutagen_rs.copy_tags(src, dst)
+        assert mutagen_rs.MP3(dst)["TIT2"] == ["Silence"]
+
+    @pytest.mark.parametrize("name", ["has-tags.m4a", "empty.ogg", "example.opus", "no-tags.flac"])
+    def test_flac_to_other_formats(self, tmp_path, name):
+        src = get_test_file("silence-44-s.flac")
+        dst = copy_fixture(tmp_path, name, "dst_" + name)
+        mutagen_rs.copy_tags(src, dst)
+        mutagen_rs.clear_all_caches()
+        f = mutagen_rs.File(dst)
+        assert f.artists() == ["piman", "jzig"]
+        assert f.genre() == "Silence"
+        assert f.cover() == mutagen_rs.FLAC(src).cover()
+
+    def test_mp4_track_pair(self, tmp_path):
+        dst = copy_fixture(tmp_path, "has-tags.m4a", "dst_has-tags.m4a")
+        mutagen_rs.copy_tags(get_test_file("silence-44-s.flac"), dst)
+        mutagen_rs.clear_all_caches()
+        assert mutagen_rs.MP4(dst)["trkn"] == [(2, 10)]
+
+    def test_keeps_other_destination_tags(self, tmp_path):
+        dst = copy_fixture(tmp_path, "silence-44-s.mp3", "dst_silence-44-s.mp3")
+        before = mutagen_rs.MP3(dst)["TIT1"]
+        mutagen_rs.copy_tags(get_test_file("no-tags.flac"), dst)
+        mutagen_rs.clear_all_caches()
+        assert mutagen_rs.MP3(dst)["TIT1"] == before
+
+    def test_mp3_comment_and_lyrics(self, tmp_path):
+        mp3 = copy_fixture(tmp_path, "no-tags.mp3", "dst_no-tags.mp3")
+        f = mutagen_rs.MP3(mp3)
+        f["COMM::eng"] = ["A comment"]
+        f.set_lyrics("la la\nla")
+        f.save()
+        dst = copy_fixture(tmp_path, "empty.ogg", "dst_empty.ogg")
+        mutagen_rs.copy_tags(mp3, dst)
+        mutagen_rs.clear_all_caches()
+        g = mutagen_rs.OggVorbis(dst)
+        assert g["comment"] == ["A comment"]
+        assert g.lyrics() == "la la\nla"
+
+    def test_unsupported_destination(self, tmp_path):
+        dst = copy_fixture(tmp_path, "empty.ogg", "dst_empty.ogg")
+        with open(dst, "wb") as f:
+            f.write(b"not audio")
+        with pytest.raises(mutagen_rs.MutagenError):
+            mutagen_rs.copy_tags(get_test_file("silence-44-s.flac"), dst)
+
+
+class TestJSONOutput:
+    """Test to_json() and the _fast_read_json API."""
+
+    @pytest.fixture(params=[
+        "silence-44-s.mp3",
+        "silence-44-s.flac",
+        "empty.ogg",
+        "has-tags.m4a",
+    ])
+    def audio_file(self, request):
+        path = get_test_file(request.param)
+        if not os.path.exists(path):
+            pytest.skip(f"Test file not found: {path}")
+        return path
+
+    def test_fast_read_json_parses(self, audio_file):
+        doc = json.loads(mutagen_rs._fast_read_json(audio_file))
+        d = mutagen_rs._fast_read(audio_file)
+        assert abs(doc["length"] - d["length"]) < 0.01
+        assert doc["sample_rate"] == d["sample_rate"]
+        assert isinstance(doc["tags"], dict)
+
+    def test_to_json_matches_fast_read_json(self, audio_file):
+        f = mutagen_rs.File(audio_file)
+        doc = json.loads(f.to_json())
+        fast = json.loads(mutagen_rs._fast_read_json(audio_file))
+        assert doc["sample_rate"] == fast["sample_rate"]
+        assert abs(doc["length"] - fast["length"]) < 0.01
+
+    def test_indent(self, audio_file):
+        f = mutagen_rs.File(audio_file)
+        assert "\n" in f.to_json(indent=2)
+        assert json.loads(f.to_json(indent=2)) == json.loads(f.to_json())
+
+    def test_include_pictures(self, audio_file):
+        f = mutagen_rs.File(audio_file)
+        doc = json.loads(f.to_json(include_pictures=True))
+        assert "tags" in doc
+
+    @pytest.mark.parametrize("name,fields", [
+        ("silence-44-s.mp3", {"version": "1.0", "layer": 3}),
+        ("has-tags.m4a", {"codec": "mp4a", "bits_per_sample": 16}),
+    ])
+    def test_format_fields(self, name, fields, tmp_path):
+        path = get_test_file(name)
+        if not os.path.exists(path):
+            pytest.skip(f"Test file not found: {path}")
+        out = tmp_path / "out.ndjson"
+        mutagen_rs.batch_export_ndjson([path], str(out))
+        docs = [
+            json.loads(mutagen_rs._fast_read_json(path)),
+            json.loads(mutagen_rs.File(path).to_json()),
+            json.loads(out.read_text()),
+        ]
+        for doc in docs:
+            for key, value in fields.items():
+                assert doc[key] == value
+
+
+class TestNDJSONExport:
+    """Test batch_export_ndjson streaming output."""
+
+    FILES = ["silence-44-s.mp3", "silence-44-s.flac", "empty.ogg", "has-tags.m4a"]
+
+    def _paths(self):
+        paths = [get_test_file(f) for f in self.FILES]
+        return [p for p in paths if os.path.exists(p)]
+
+    def test_one_line_per_file(self, tmp_path):
+        paths = self._paths()
+        out = tmp_path / "out.ndjson"
+        stats = mutagen_rs.batch_export_ndjson(paths, str(out))
+        lines = out.read_text().splitlines()
+        assert stats["written"] == len(paths) == len(lines)
+        assert stats["failed"] == 0
+        docs = [json.loads(line) for line in lines]
+        assert sorted(d["path"] for d in docs) == sorted(paths)
+
+    def test_gzip_output(self, tmp_path):
+        import gzip
+        out = tmp_path / "out.ndjson.gz"
+        stats = mutagen_rs.batch_export_ndjson(self._paths(), str(out), threads=2)
+        with gzip.open(out, "rt") as f:
+            assert len(f.read().splitlines()) == stats["written"]
+
+    def test_file_object_and_errors(self):
+        import io
+        buf = io.StringIO()
+        missing = os.path.join(TEST_DIR, "does-not-exist.mp3")
+        stats = mutagen_rs.batch_export_ndjson(self._paths() + [missing], buf)
+        assert stats["failed"] == 1
+        assert stats["errors"][0][0] == missing
+        assert len(buf.getvalue().splitlines()) == stats["written"]
+
+    def test_directory_scan(self, tmp_path):
+        out = tmp_path / "out.ndjson"
+        stats = mutagen_rs.batch_export_ndjson(TEST_DIR, str(out))
+        assert stats["written"] > 0
+        assert len(out.read_text().splitlines()) == stats["written"]
+
+    def test_easy_keys(self):
+        import io
+        buf = io.BytesIO()
+        mutagen_rs.batch_export_ndjson(self._paths(), buf, easy=True)
+        for line in buf.getvalue().decode().splitlines():
+            for key, value in json.loads(line)["tags"].items():
+                assert key == key.lower()
+                assert isinstance(value, list)
+
+
+class TestMsgpackOutput:
+    """Test _fast_batch_read_msgpack against the JSON schema."""
+
+    FILES = ["silence-44-s.mp3", "silence-44-s.flac", "empty.ogg", "has-tags.m4a"]
+
+    def test_matches_json_schema(self):
+        msgpack = pytest.importorskip("msgpack")
+        paths = [get_test_file(f) for f in self.FILES]
+        paths = [p for p in paths if os.path.exists(p)]
+        result = msgpack.unpackb(mutagen_rs._fast_batch_read_msgpack(paths))
+        assert sorted(result) == sorted(paths)
+        for path in paths:
+            doc = json.loads(mutagen_rs._fast_read_json(path))
+            packed = result[path]
+            assert set(packed) == set(doc)
+            for key, value in doc["tags"].items():
+                assert packed["tags"][key] == value
+
+    def test_covers_are_bytes(self):
+        msgpack = pytest.importorskip("msgpack")
+        path = get_test_file("has-tags.m4a")
+        if not os.path.exists(path):
+            pytest.skip("Test file not found")
+        packed = msgpack.unpackb(mutagen_rs._fast_batch_read_msgpack([path]))[path]
+        assert isinstance(packed["tags"]["covr"][0]["data"], bytes)
+
+    def test_repeated_paths_packed_once(self):
+        msgpack = pytest.importorskip("msgpack")
+        path = get_test_file("silence-44-s.flac")
+        packed = mutagen_rs._fast_batch_read_msgpack([path, path])
+        assert packed == mutagen_rs._fast_batch_read_msgpack([path])
+        assert list(msgpack.unpackb(packed)) == [path]
+
+    def test_batch_result_includes_edits(self, tmp_path):
+        msgpack = pytest.importorskip("msgpack")
+        flac = copy_fixture(tmp_path, "silence-44-s.flac", "a.flac")
+        m4a = get_test_file("has-tags.m4a")
+        result = mutagen_rs.batch_open([flac, m4a], editable=True)
+        result.set_tag(flac, "artist", "Packed")
+        unpacked = msgpack.unpackb(result.to_msgpack())
+        assert list(unpacked) == [flac, m4a]
+        assert unpacked[flac]["tags"]["artist"] == ["Packed"]
+        assert unpacked[flac]["length"] == result[flac]["length"]
+        assert isinstance(unpacked[m4a]["tags"]["covr"][0]["data"], bytes)
+
+    def test_batch_result_fields_match_fast_msgpack(self):
+        msgpack = pytest.importorskip("msgpack")
+        paths = [get_test_file(f) for f in ("silence-44-s.mp3", "silence-44-s.flac", "has-tags.m4a")]
+        result = mutagen_rs.batch_open(paths, editable=True)
+        packed = msgpack.unpackb(result.to_msgpack())
+        fast = msgpack.unpackb(mutagen_rs._fast_batch_read_msgpack(paths))
+        for path in paths:
+            assert set(packed[path]) == set(fast[path])
+        assert packed[paths[0]]["bitrate_mode"] == fast[paths[0]]["bitrate_mode"]
+        assert packed[paths[1]]["bits_per_sample"] == 16
+        assert packed[paths[2]]["codec"] == "mp4a"
+
+
+class TestArrowOutput:
+    """Test batch_open_arrow via the Arrow PyCapsule stream interface."""
+
+    FILES = ["silence-44-s.mp3", "silence-44-s.flac", "empty.ogg", "has-tags.m4a"]
+
+    def _paths(self):
+        paths = [get_test_file(f) for f in self.FILES]
+        return [p for p in paths if os.path.exists(p)]
+
+    def test_table_shape(self):
+        pa = pytest.importorskip("pyarrow")
+        paths = self._paths()
+        table = pa.table(mutagen_rs.batch_open_arrow(paths, columns=["title", "artist"]))
+        assert table.num_rows == len(paths)
+        assert table.column_names == [
+            "path", "length", "sample_rate", "channels", "bitrate", "title", "artist"]
+        assert pa.types.is_large_list(table.schema.field("artist").type)
+        assert pa.types.is_large_string(table.schema.field("title").type)
+
+    def test_schema_uses_64bit_offsets(self):
+        import ctypes
+
+        class Schema(ctypes.Structure):
+            pass
+        Schema._fields_ = [
+            ("format", ctypes.c_char_p), ("name", ctypes.c_char_p),
+            ("metadata", ctypes.c_char_p), ("flags", ctypes.c_int64),
+            ("n_children", ctypes.c_int64),
+            ("children", ctypes.POINTER(ctypes.POINTER(Schema))),
+            ("dictionary", ctypes.c_void_p), ("release", ctypes.c_void_p),
+            ("private_data", ctypes.c_void_p)]
+        get_schema = ctypes.CFUNCTYPE(ctypes.c_int, ctypes.c_void_p, ctypes.POINTER(Schema))
+        release_schema = ctypes.CFUNCTYPE(None, ctypes.POINTER(Schema))
+        release_stream = ctypes.CFUNCTYPE(None, ctypes.c_void_p)
+
+        class Stream(ctypes.Structure):
+            _fields_ = [
+                ("get_schema", get_schema), ("get_next", ctypes.c_void_p),
+                ("get_last_error", ctypes.c_void_p), ("release", release_stream),
+                ("private_data", ctypes.c_void_p)]
+
+        capsule = mutagen_rs.batch_open_arrow(
+            self._paths(), columns=["title", "artist"]).__arrow_c_stream__()
+        get_pointer = ctypes.pythonapi.PyCapsule_GetPointer
+        get_pointer.restype = ctypes.c_void_p
+        get_pointer.argtypes = [ctypes.py_object, ctypes.c_char_p]
+        stream = Stream.from_address(get_pointer(capsule, b"arrow_array_stream"))
+        schema = Schema()
+        assert stream.get_schema(ctypes.addressof(stream), ctypes.byref(schema)) == 0
+        fields = {schema.children[i].contents.name: schema.children[i].contents
+                  for i in range(schema.n_children)}
+        assert fields[b"path"].format == b"U"
+        assert fields[b"title"].format == b"U"
+        assert fields[b"artist"].format == b"+L"
+        assert fields[b"artist"].children[0].contents.format == b"U"
+        release_schema(schema.release)(ctypes.byref(schema))
+
+    def test_values_match_fast_read(self):
+        pa = pytest.importorskip("pyarrow")
+        path = get_test_file("silence-44-s.flac")
+        if not os.path.exists(path):
+            pytest.skip("Test file not found")
+        row = pa.table(mutagen_rs.batch_open_arrow([path])).to_pylist()[0]
+        d = mutagen_rs._fast_read(path)
+        assert row["path"] == path
+        assert row["sample_rate"] == d["sample_rate"]
+        assert row["title"] == "Silence"
+        assert row["artist"] == d["artist"]
+        assert row["discnumber"] is None
+
+    def test_unknown_column_raises(self):
+        with pytest.raises(ValueError):
+            mutagen_rs.batch_open_arrow(self._paths(), columns=["title", "not-a-tag"])
+
+
+# ──────────────────────────────────────────────────────────────
+# batch_open API tests
+# ──────────────────────────────────────────────────────────────
+
+class TestBatchOpen:
+    """Test the batch_open parallel processing API."""
+
+    def test_batch_returns_dict(self):
+        paths = [
+            get_test_file("silence-44-s.mp3"),
+            get_test_file("silence-44-s.flac"),
+            get_test_file("empty.ogg"),
+            get_test_file("has-tags.m4a"),
+        ]
+        paths = [p for p in paths if os.path.exists(p)]
         if not paths:
             pytest.skip("No test files found")
         result = mutagen_rs.batch_open(paths)
@@ -628,6 +4620,471 @@ class TestBatchOpen:
             assert "length" in d
             assert "sample_rate" in d
 
+    def _large_copies(self, tmp_path):
+        """Copies padded past the range-read threshold without changing their metadata."""
+        filler = b"\x00\x01" * (200 * 1024)
+        out = []
+        for name in ["silence-44-s.flac", "multipagecomment.ogg", "has-tags.m4a", "silence-44-s.mp3"]:
+            src = get_test_file(name)
+            if not os.path.exists(src):
+                pytest.skip("Test file not found")
+            with open(src, "rb") as f:
+                data = f.read()
+            if name.endswith(".ogg"):
+                # Keep the last page at the end, where the final granule is read from
+                last = data.rfind(b"OggS")
+                data = data[:last] + filler + data[last:]
+            elif name.endswith(".m4a"):
+                data += (8 + len(filler)).to_bytes(4, "big") + b"free" + filler
+            else:
+                data += filler
+            dst = str(tmp_path / ("large-" + name))
+            with open(dst, "wb") as f:
+                f.write(data)
+            out.append(dst)
+        return out
+
+    @pytest.mark.parametrize("io_depth", [1, 4])
+    def test_io_depth_matches_default(self, tmp_path, io_depth):
+        paths = self._large_copies(tmp_path) + [
+            get_test_file("silence-44-s.flac"),
+            get_test_file("empty.ogg"),
+            get_test_file("no-tags.m4a"),
+        ]
+        expected = mutagen_rs.batch_open(list(paths))
+        mutagen_rs.clear_cache()
+        result = mutagen_rs.batch_open(list(paths), io_depth=io_depth)
+        assert set(result) == set(expected) == set(paths)
+        for p in paths:
+            assert result[p] == expected[p], p
+
+    def test_io_depth_skips_missing(self, tmp_path):
+        paths = [get_test_file("silence-44-s.flac"), str(tmp_path / "missing.flac")]
+        result = mutagen_rs.batch_open(paths, io_depth=2)
+        assert list(result) == paths[:1]
+
+    def _spellings(self, tmp_path):
+        sub = tmp_path / "sub"
+        sub.mkdir()
+        path = copy_fixture(tmp_path, "silence-44-s.flac", "a.flac")
+        link = str(tmp_path / "link.flac")
+        os.symlink(path, link)
+        return path, str(sub / ".." / "." / "a.flac"), link
+
+    def test_duplicate_spellings_share_entry(self, tmp_path):
+        path, dotted, link = self._spellings(tmp_path)
+        result = mutagen_rs.batch_open([path, dotted, path, link])
+        assert list(result) == [path, dotted, link]
+        assert result[path] is result[dotted]
+        assert result[link] == result[path]
+
+    @pytest.mark.parametrize("mode", ["realpath", "inode"])
+    def test_symlink_dedupe(self, tmp_path, mode):
+        path, dotted, link = self._spellings(tmp_path)
+        result = mutagen_rs.batch_open([link, path, dotted], dedupe=mode)
+        assert result[path] is result[link] is result[dotted]
+
+    def test_dedupe_none(self, tmp_path):
+        path, dotted, _ = self._spellings(tmp_path)
+        result = mutagen_rs.batch_open([path, dotted], dedupe="none")
+        assert list(result) == [path, dotted]
+        assert result[path] == result[dotted]
+
+    def test_cached_call_honours_dedupe(self, tmp_path):
+        path, dotted, _ = self._spellings(tmp_path)
+        paths = [path, dotted]
+        assert mutagen_rs.batch_open(paths, dedupe="none")[path] is not None
+        result = mutagen_rs.batch_open(paths)
+        assert result[path] is result[dotted]
+        assert mutagen_rs.batch_total_length(result) == result[path]["length"]
+
+    def _same_size_pair(self, tmp_path):
+        src = get_test_file("silence-44-s.flac")
+        if not os.path.exists(src):
+            pytest.skip("Test file not found")
+        with open(src, "rb") as f:
+            data = f.read()
+        a, b = str(tmp_path / "a.flac"), str(tmp_path / "b.flac")
+        with open(a, "wb") as f:
+            f.write(data)
+        with open(b, "wb") as f:
+            f.write(data.replace(b"title=Silence", b"title=Quietly"))
+        return a, b
+
+    @pytest.mark.parametrize("mode", ["lexical", "none", "realpath", "inode"])
+    def test_same_size_files_kept_apart(self, tmp_path, mode):
+        a, b = self._same_size_pair(tmp_path)
+        result = mutagen_rs.batch_open([a, b], dedupe=mode)
+        assert result[a]["tags"]["title"] == ["Silence"]
+        assert result[b]["tags"]["title"] == ["Quietly"]
+        mutagen_rs.clear_cache()
+        assert mutagen_rs.batch_open([a, b], io_depth=2)[b]["tags"]["title"] == ["Quietly"]
+
+    def test_unknown_dedupe_mode(self):
+        with pytest.raises(ValueError):
+            mutagen_rs.batch_open([get_test_file("silence-44-s.flac")], dedupe="bogus")
+
+    def test_binary_payloads_intact(self, tmp_path):
+        # Picture and cover bytes are sliced from the parsed buffer, not copied
+        path = _mp3_with_frames(tmp_path, [(b"APIC", _PNG_APIC)])
+        m4a = get_test_file("has-tags.m4a")
+        result = mutagen_rs.batch_open([path, m4a])
+        pic = result[path]["tags"]["APIC:cover"]
+        pic = pic[0] if isinstance(pic, list) else pic
+        assert pic["mime"] == "image/png"
+        assert pic["type"] == 3
+        assert pic["desc"] == "cover"
+        assert pic["data"] == b"\x89PNG" * 64
+        covers = result[m4a]["tags"]["covr"]
+        assert [c["data"] for c in covers] == [c["data"] for c in mutagen_rs._RustMP4(m4a)["covr"]]
+
+
+class TestBatchIter:
+    """Test the streaming batch_iter API."""
+
+    def _paths(self):
+        paths = [
+            get_test_file("silence-44-s.mp3"),
+            get_test_file("silence-44-s.flac"),
+            get_test_file("empty.ogg"),
+            get_test_file("has-tags.m4a"),
+            get_test_file("no-tags.flac"),
+        ]
+        return [p for p in paths if os.path.exists(p)]
+
+    def test_matches_batch_open(self):
+        paths = self._paths()
+        expected = mutagen_rs.batch_open(paths)
+        got = dict(mutagen_rs.batch_iter(paths))
+        assert got.keys() == expected.keys()
+        for p in paths:
+            assert got[p] == expected[p], p
+
+    def test_ordered(self):
+        paths = self._paths() * 20
+        got = [p for p, _ in mutagen_rs.batch_iter(paths, threads=4, buffer=2, ordered=True)]
+        assert got == paths
+
+    def test_skips_unreadable(self, tmp_path):
+        paths = self._paths()
+        with_missing = [paths[0], str(tmp_path / "missing.mp3"), get_test_file("image.jpg")] + paths[1:]
+        got = [p for p, _ in mutagen_rs.batch_iter(with_missing, ordered=True)]
+        assert got == paths
+
+    def test_early_stop(self):
+        paths = self._paths() * 200
+        it = mutagen_rs.batch_iter(paths, buffer=1)
+        first = next(it)
+        assert first[0] in paths
+        it.close()
+
+    def test_empty(self):
+        assert list(mutagen_rs.batch_iter([])) == []
+
+
+class TestBatchOpenBuffers:
+    """Test batch_open_buffers over in-memory (path, bytes) pairs."""
+
+    def _items(self, names):
+        items = []
+        for name in names:
+            path = get_test_file(name)
+            if not os.path.exists(path):
+                pytest.skip("Test file not found")
+            with open(path, "rb") as f:
+                items.append((path, f.read()))
+        return items
+
+    def test_matches_batch_open(self):
+        items = self._items(["silence-44-s.mp3", "silence-44-s.flac"])
+        got = mutagen_rs.batch_open_buffers(items)
+        expected = mutagen_rs.batch_open([p for p, _ in items])
+        assert got.keys() == expected.keys()
+        for p, _ in items:
+            assert got[p] == expected[p], p
+
+    def test_tags_without_file(self):
+        (mp3, mp3_data), (flac, flac_data) = self._items(["silence-44-s.mp3", "silence-44-s.flac"])
+        result = mutagen_rs.batch_open_buffers([("mem/a.mp3", mp3_data), ("mem/b.flac", flac_data)])
+        assert result["mem/a.mp3"]["tags"]["TIT2"] == mutagen_rs.MP3(mp3)["TIT2"].text
+        assert result["mem/b.flac"]["tags"]["title"] == mutagen_rs.FLAC(flac)["title"]
+
+    def test_easy(self):
+        (_, data), = self._items(["silence-44-s.flac"])
+        result = mutagen_rs.batch_open_buffers([("x.flac", data)], easy=True)
+        assert result["x.flac"]["tags"]["title"] == mutagen_rs.batch_open(
+            [get_test_file("silence-44-s.flac")], easy=True)[get_test_file("silence-44-s.flac")]["tags"]["title"]
+
+    def test_unparseable_left_out(self):
+        (_, data), = self._items(["silence-44-s.flac"])
+        result = mutagen_rs.batch_open_buffers([("junk.flac", b"\x00" * 64), ("ok.flac", data)])
+        assert list(result) == ["ok.flac"]
+
+    def test_empty(self):
+        assert mutagen_rs.batch_open_buffers([]) == {}
+
+
+class TestBatchDiag:
+    """Test the structured batch_diag report."""
+
+    def _paths(self):
+        return TestBatchIter._paths(self)
+
+    def test_structure(self):
+        paths = self._paths()
+        diag = mutagen_rs.batch_diag(paths)
+        assert diag["files"] == len(paths)
+        assert diag["parsed"] == len(paths)
+        assert diag["threads"] >= 1
+        assert diag["bytes_read"] == sum(os.path.getsize(p) for p in paths)
+        for key in ("seq_read_us", "seq_parse_us", "par_parse_us", "full_par_us", "build_py_us"):
+            assert isinstance(diag[key], int) and diag[key] >= 0, key
+        assert 0.0 <= diag["io_fraction"] <= 1.0
+
+    def test_per_format(self, tmp_path):
+        paths = self._paths()
+        junk = str(tmp_path / "junk.flac")
+        with open(junk, "wb") as f:
+            f.write(b"\x00" * 100)
+        formats = mutagen_rs.batch_diag(paths + [junk])["formats"]
+        assert set(formats) == {"mp3", "flac", "ogg", "mp4"}
+        assert formats["flac"]["files"] == 3
+        assert formats["flac"]["failed"] == 1
+        assert formats["mp4"]["bytes"] == os.path.getsize(get_test_file("has-tags.m4a"))
+        assert formats["mp3"]["failed"] == 0
+
+    def test_cache_and_read_modes(self):
+        paths = self._paths()
+        mutagen_rs.clear_all_caches()
+        assert mutagen_rs.batch_diag(paths)["cache"] == {"file_hit_rate": 0.0, "result_hit_rate": 0.0}
+        mutagen_rs._fast_read(paths[0])
+        diag = mutagen_rs.batch_diag(paths)
+        assert diag["cache"]["result_hit_rate"] == 1 / len(paths)
+        modes = diag["read_modes"]
+        assert modes["<=32K"]["mode"] == "buffered"
+        assert modes[">=4M"]["mode"] == "mmap"
+        assert sum(m["files"] for m in modes.values()) == len(paths)
+
+    def test_pretty(self):
+        out = mutagen_rs.batch_diag(self._paths(), pretty=True)
+        assert isinstance(out, str)
+        assert out.startswith("n=5 ")
+        assert "build_py=" in out and "\n  flac: files=2" in out
+
+
+class TestBatchEdit:
+    """Test editing batch_open results and saving them with save_all."""
+
+    def _copies(self, tmp_path):
+        names = ("silence-44-s.flac", "no-tags.flac", "silence-44-s.mp3", "has-tags.m4a")
+        return [copy_fixture(tmp_path, name) for name in names]
+
+    def test_set_artist_on_every_flac(self, tmp_path):
+        paths = self._copies(tmp_path)
+        result = mutagen_rs.batch_open(paths, editable=True)
+        assert isinstance(result, mutagen_rs.BatchResult)
+        flacs = [p for p in paths if p.endswith(".flac")]
+        for p in flacs:
+            result.set_tag(p, "ARTIST", "Batch Artist")
+            assert result[p]["tags"]["artist"] == ["Batch Artist"]
+        assert result.dirty() == flacs
+        assert result.save_all() == 2
+        assert result.dirty() == []
+        mutagen_rs.clear_cache()
+        for p in flacs:
+            assert mutagen_rs.FLAC(p)["artist"] == ["Batch Artist"]
+        # Other tags survive the rewrite
+        orig = mutagen_rs.FLAC(get_test_file("silence-44-s.flac"))
+        assert mutagen_rs.FLAC(flacs[0])["title"] == orig["title"]
+
+    def test_other_formats(self, tmp_path):
+        paths = self._copies(tmp_path)
+        mp3, m4a = paths[2], paths[3]
+        result = mutagen_rs.batch_open(paths, editable=True)
+        result.set_tag(mp3, "TPE1", ["One", "Two"])
+        result.set_tag(m4a, "\xa9ART", "Someone")
+        result.del_tag(m4a, "\xa9too")
+        assert "\xa9too" not in result[m4a]["tags"]
+        assert result.save_all() == 2
+        mutagen_rs.clear_cache()
+        assert list(mutagen_rs.MP3(mp3)["TPE1"]) == ["One", "Two"]
+        tags = mutagen_rs.MP4(m4a)
+        assert tags["\xa9ART"] == ["Someone"]
+        assert "\xa9too" not in tags
+        assert "covr" in tags
+
+    def test_mp3_qualified_keys(self, tmp_path):
+        mp3 = self._copies(tmp_path)[2]
+        result = mutagen_rs.batch_open([mp3], editable=True)
+        result.set_tag(mp3, "TIT2", "New Title")
+        result.set_tag(mp3, "TXXX:MyKey", "custom")
+        result.set_tag(mp3, "COMM::eng", "a comment")
+        for key in ("APIC:", "POPM:someone", "TXX", "Tit2"):
+            with pytest.raises(ValueError):
+                result.set_tag(mp3, key, "x")
+        assert result.save_all() == 1
+        mutagen_rs.clear_cache()
+        f = mutagen_rs.MP3(mp3)
+        assert list(f["TIT2"]) == ["New Title"]
+        assert list(f["TXXX:MyKey"]) == ["custom"]
+        assert str(f["COMM::eng"]) == "a comment"
+
+    def test_mp4_typed_atoms(self, tmp_path):
+        m4a = self._copies(tmp_path)[3]
+        result = mutagen_rs.batch_open([m4a], editable=True)
+        result.set_tag(m4a, "trkn", "3/10")
+        result.set_tag(m4a, "tmpo", "120")
+        result.set_tag(m4a, "cpil", "1")
+        assert result[m4a]["tags"]["trkn"] == (3, 10)
+        assert result[m4a]["tags"]["tmpo"] == 120
+        for key, value in (("covr", "x"), ("gnre", "Rock"), ("tmpo", "fast"), ("trkn", "x/y")):
+            with pytest.raises(ValueError):
+                result.set_tag(m4a, key, value)
+        assert result.save_all() == 1
+        mutagen_rs.clear_cache()
+        tags = mutagen_rs.MP4(m4a)
+        assert tags["trkn"] == [(3, 10)]
+        assert tags["tmpo"] == [120]
+        assert tags["cpil"] == [1]
+        assert "covr" in tags
+
+    def test_same_size_file_untouched(self, tmp_path):
+        src = get_test_file("silence-44-s.flac")
+        with open(src, "rb") as f:
+            data = f.read()
+        a, b = str(tmp_path / "a.flac"), str(tmp_path / "b.flac")
+        with open(a, "wb") as f:
+            f.write(data)
+        with open(b, "wb") as f:
+            f.write(data.replace(b"title=Silence", b"title=Quietly"))
+        result = mutagen_rs.batch_open([a, b, a], editable=True)
+        result.set_tag(a, "artist", "Only A")
+        assert result[a]["tags"]["artist"] == ["Only A"]
+        assert result[b]["tags"]["artist"] != ["Only A"]
+        assert result[b]["tags"]["title"] == ["Quietly"]
+        assert result.dirty() == [a]
+        assert result.save_all() == 1
+        mutagen_rs.clear_cache()
+        assert mutagen_rs.FLAC(a)["artist"] == ["Only A"]
+        assert mutagen_rs.FLAC(b)["title"] == ["Quietly"]
+        assert mutagen_rs.FLAC(b)["artist"] != ["Only A"]
+
+    def test_errors(self, tmp_path):
+        paths = self._copies(tmp_path)
+        result = mutagen_rs.batch_open(paths, editable=True)
+        with pytest.raises(KeyError):
+            result.set_tag(str(tmp_path / "missing.flac"), "artist", "x")
+        with pytest.raises(KeyError):
+            result.del_tag(paths[1], "nosuchkey")
+        with pytest.raises(ValueError):
+            mutagen_rs.batch_open(paths, easy=True, editable=True)
+        result.set_tag(paths[0], "artist", "x")
+        os.remove(paths[0])
+        with pytest.raises(IOError):
+            result.save_all()
+        assert result.dirty() == [paths[0]]
+
+
+class TestBatchAggregates:
+    """Test the BatchResult total_length and count_by_format reductions."""
+
+    def _paths(self):
+        names = ("silence-44-s.mp3", "silence-44-s.flac", "no-tags.flac", "empty.ogg", "has-tags.m4a")
+        return [get_test_file(n) for n in names if os.path.exists(get_test_file(n))]
+
+    def test_total_length_is_sum(self):
+        paths = self._paths()
+        result = mutagen_rs.batch_open(paths, editable=True)
+        expected = sum(result[p]["length"] or 0.0 for p in paths)
+        assert abs(result.total_length() - expected) < 1e-9
+
+    def test_same_file_counted_once(self):
+        paths = self._paths()
+        respelled = ["./" + os.path.relpath(p) for p in paths]
+        result = mutagen_rs.batch_open(paths + respelled, editable=True)
+        assert len(result) == 2 * len(paths)
+        single = mutagen_rs.batch_open(paths, editable=True)
+        assert abs(result.total_length() - single.total_length()) < 1e-9
+        assert result.count_by_format() == single.count_by_format()
+
+    def test_same_size_files_counted_apart(self, tmp_path):
+        with open(get_test_file("silence-44-s.flac"), "rb") as f:
+            data = f.read()
+        a, b = str(tmp_path / "a.flac"), str(tmp_path / "b.flac")
+        with open(a, "wb") as f:
+            f.write(data)
+        with open(b, "wb") as f:
+            f.write(data.replace(b"title=Silence", b"title=Quietly"))
+        result = mutagen_rs.batch_open([a, b], editable=True)
+        assert result.count_by_format() == {"flac": 2}
+        assert abs(result.total_length() - 2 * result[a]["length"]) < 1e-9
+
+    def test_plain_dict_result(self):
+        paths = self._paths()
+        respelled = ["./" + os.path.relpath(p) for p in paths]
+        plain = mutagen_rs.batch_open(paths + respelled)
+        assert isinstance(plain, dict)
+        editable = mutagen_rs.batch_open(paths, editable=True)
+        assert abs(mutagen_rs.batch_total_length(plain) - editable.total_length()) < 1e-9
+        assert mutagen_rs.batch_count_by_format(plain) == editable.count_by_format()
+        assert mutagen_rs.batch_count_by_format(editable) == editable.count_by_format()
+        assert mutagen_rs.batch_total_length({}) == 0.0
+
+    def test_count_by_format(self):
+        result = mutagen_rs.batch_open(self._paths(), editable=True)
+        assert result.count_by_format() == {"mp3": 1, "flac": 2, "ogg": 1, "mp4": 1}
+
+    def test_empty(self):
+        result = mutagen_rs.batch_open([], editable=True)
+        assert result.total_length() == 0.0
+        assert result.count_by_format() == {}
+
+
+class TestBatchEasy:
+    """Test easy-key normalized output from batch_open / _fast_batch_read."""
+
+    def _paths(self):
+        paths = [
+            get_test_file("silence-44-s.mp3"),
+            get_test_file("silence-44-s.flac"),
+            get_test_file("has-tags.m4a"),
+        ]
+        paths = [p for p in paths if os.path.exists(p)]
+        if not paths:
+            pytest.skip("No test files found")
+        return paths
+
+    def test_same_keys_across_formats(self):
+        mp3 = get_test_file("silence-44-s.mp3")
+        flac = get_test_file("silence-44-s.flac")
+        result = mutagen_rs.batch_open([mp3, flac], easy=True)
+        for p in (mp3, flac):
+            tags = result[p]["tags"]
+            assert tags["artist"][0] == "piman"
+            assert tags["album"] == ["Quod Libet Test Data"]
+            assert "TPE1" not in tags
+
+    def test_values_are_string_lists(self):
+        result = mutagen_rs.batch_open(self._paths(), easy=True)
+        for d in result.values():
+            for value in d["tags"].values():
+                assert isinstance(value, list)
+                assert all(isinstance(v, str) for v in value)
+
+    def test_extra_only_when_requested(self):
+        path = get_test_file("has-tags.m4a")
+        plain = mutagen_rs.batch_open([path], easy=True)[path]["tags"]
+        assert "extra" not in plain
+        tags = mutagen_rs.batch_open([path], easy=True, extra=True)[path]["tags"]
+        assert "covr" in tags["extra"]
+        assert tags["artist"] == ["Test Artist"]
+
+    def test_flat_info_fields_not_shadowed(self):
+        path = get_test_file("silence-44-s.mp3")
+        d = mutagen_rs._fast_batch_read([path], easy=True)[path]
+        assert isinstance(d["length"], float)
+        assert d["title"] == ["Silence"]
+
 
 # ──────────────────────────────────────────────────────────────
 # Write/Save tests
@@ -675,6 +5132,97 @@ class TestWriteSupport:
         else:
             assert str(vals) == "Test Title"
 
+    @pytest.mark.parametrize("name", ["silence-44-s.flac", "empty.ogg"])
+    def test_custom_vendor_persists(self, tmp_path, name):
+        """A vendor string set on tags should be written on save."""
+        import shutil
+        src = get_test_file(name)
+        if not os.path.exists(src):
+            pytest.skip("Test file not found")
+        dst = str(tmp_path / name)
+        shutil.copy2(src, dst)
+        f = mutagen_rs.File(dst)
+        original = f.tags.vendor
+        assert original
+        f.tags.vendor = "mutagen-rs test encoder"
+        f.save()
+        mutagen_rs.clear_cache()
+        f2 = mutagen_rs.File(dst)
+        assert f2.tags.vendor == "mutagen-rs test encoder"
+
+    def test_mp3_small_edit_rewrites_tag_in_place(self, tmp_path):
+        """A tag that still fits in the old tag + padding leaves the audio bytes untouched."""
+        src = get_test_file("silence-44-s.mp3")
+        if not os.path.exists(src):
+            pytest.skip("Test file not found")
+        dst = str(tmp_path / "big.mp3")
+        with open(src, "rb") as f:
+            data = f.read()
+        filler = bytes(range(256)) * (50 * 1024 * 1024 // 256)
+        with open(dst, "wb") as f:
+            f.write(data)
+            f.write(filler)
+        tag_size = mutagen_rs.ID3(dst).size
+        size_before = os.path.getsize(dst)
+
+        f = mutagen_rs.MP3(dst)
+        f["TIT2"] = "Edited"
+        f.save()
+
+        assert os.path.getsize(dst) == size_before
+        assert mutagen_rs.ID3(dst).size == tag_size
+        with open(dst, "rb") as f:
+            after = f.read()
+        assert after[tag_size:] == data[tag_size:] + filler
+        mutagen_rs.clear_cache()
+        assert str(mutagen_rs.MP3(dst)["TIT2"]) == "Edited"
+
+    @pytest.mark.parametrize("name,key,tool", [
+        ("has-tags.m4a", "\xa9too", "Lavf58"),
+        ("silence-44-s.mp3", "TSSE", "LAME3.100"),
+        ("silence-44-s.flac", "ENCODER", "reference libFLAC 1.4.3"),
+    ])
+    def test_encoder_tool(self, tmp_path, name, key, tool):
+        """encoder_tool() reads the format-specific encoder field."""
+        import shutil
+        src = get_test_file(name)
+        if not os.path.exists(src):
+            pytest.skip("Test file not found")
+        dst = str(tmp_path / name)
+        shutil.copy2(src, dst)
+        f = mutagen_rs.File(dst)
+        f[key] = tool
+        f.save()
+        mutagen_rs.clear_cache()
+        assert mutagen_rs.File(dst).encoder_tool() == tool
+
+    def test_encoder_tool_missing(self):
+        assert mutagen_rs.File(get_test_file("silence-44-s.flac")).encoder_tool() is None
+
+    def test_mp4_track_disc_from_string_and_tuple(self, tmp_path):
+        """trkn/disk accept "N/M", "N" and (N, M) and round-trip as int pairs."""
+        import shutil
+        src = get_test_file("has-tags.m4a")
+        if not os.path.exists(src):
+            pytest.skip("Test file not found")
+        dst = str(tmp_path / "test.m4a")
+        shutil.copy2(src, dst)
+        f = mutagen_rs.MP4(dst)
+        f["trkn"] = "3/12"
+        f["disk"] = (1, 2)
+        f.save()
+        mutagen_rs.clear_cache()
+        f2 = mutagen_rs.MP4(dst)
+        assert f2["trkn"] == [(3, 12)]
+        assert f2["disk"] == [(1, 2)]
+        assert mutagen_rs.EasyMP4(dst)["tracknumber"] == ["3/12"]
+
+        f2["trkn"] = "7"
+        f2.save()
+        mutagen_rs.clear_cache()
+        assert mutagen_rs.MP4(dst)["trkn"] == [(7, 0)]
+        assert mutagen_rs.EasyMP4(dst)["tracknumber"] == ["7"]
+
 
 # ──────────────────────────────────────────────────────────────
 # Generated file tests (ground truth comparison)
@@ -121,6 +121,24 @@ def benchmark_rust_warm(name, paths, iterations=ITERATIONS):
     return min(times)
 
 
+def benchmark_rust_construct(open_fn, paths, iterations=ITERATIONS):
+    """Benchmark constructing Rust file objects (info + tag dict) with `open_fn`."""
+    if not paths:
+        return None
+    for p in paths:
+        open_fn(p)
+    times = []
+    for _ in range(iterations):
+        start = time.perf_counter()
+        for p in paths:
+            f = open_fn(p)
+            _ = f.info.length
+            for k in f.keys():
+                _ = f[k]
+        times.append(time.perf_counter() - start)
+    return min(times)
+
+
 def filter_valid_paths(orig_cls, paths):
     valid = []
     for p in paths:
@@ -278,6 +296,23 @@ def main():
             "speedup_warm": warm_speedup,
         }
 
+    # ---- Read-only vs full class construction ----
+    if valid_auto:
+        print(f"READ-ONLY vs FULL CLASS ({len(valid_auto)} files):")
+        full_time = benchmark_rust_construct(mutagen_rs._rust_file_open, valid_auto)
+        ro_time = benchmark_rust_construct(mutagen_rs.open_readonly, valid_auto)
+        n = len(valid_auto)
+        speedup = full_time / ro_time if ro_time > 0 else float('inf')
+        print(f"  File():          {(full_time / n) * 1000:.4f} ms/file")
+        print(f"  open_readonly(): {(ro_time / n) * 1000:.4f} ms/file  ({speedup:.2f}x)")
+        print()
+        results["readonly"] = {
+            "files": n,
+            "full_ms_per_file": (full_time / n) * 1000,
+            "readonly_ms_per_file": (ro_time / n) * 1000,
+            "speedup": speedup,
+        }
+
     # ---- Batch API benchmark ----
     import shutil
     import tempfile